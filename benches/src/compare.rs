@@ -0,0 +1,133 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::report::BenchmarkReport;
+
+/// Gas used by a function in the baseline and in the current report.
+#[derive(Debug)]
+pub struct GasDiff {
+    sig: String,
+    baseline: u128,
+    current: u128,
+}
+
+impl GasDiff {
+    /// Relative change of the gas usage, in percent.
+    pub fn change(&self) -> f64 {
+        if self.baseline == 0 {
+            return 0.0;
+        }
+        (self.current as f64 - self.baseline as f64) / self.baseline as f64
+            * 100.0
+    }
+
+    fn diff(&self) -> String {
+        if self.current >= self.baseline {
+            format!("+{}", self.current - self.baseline)
+        } else {
+            format!("-{}", self.baseline - self.current)
+        }
+    }
+}
+
+impl Display for GasDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:+.2}%)", self.sig, self.change())
+    }
+}
+
+/// Per-function comparison of the non cached gas usage of two benchmark
+/// reports.
+///
+/// Functions missing from either report are ignored.
+#[derive(Debug, Default)]
+pub struct ComparisonReport(Vec<GasDiff>);
+
+impl ComparisonReport {
+    pub fn new(baseline: &BenchmarkReport, current: &BenchmarkReport) -> Self {
+        let baseline: HashMap<_, _> = baseline.gas_by_signature().collect();
+
+        let diffs = current
+            .gas_by_signature()
+            .filter_map(|(sig, current)| {
+                baseline.get(&sig).map(|&baseline| GasDiff {
+                    sig,
+                    baseline,
+                    current,
+                })
+            })
+            .collect();
+
+        ComparisonReport(diffs)
+    }
+
+    /// Returns the functions whose gas usage increased by more than
+    /// `threshold` percent.
+    pub fn regressions(&self, threshold: f64) -> Vec<&GasDiff> {
+        self.0.iter().filter(|diff| diff.change() > threshold).collect()
+    }
+}
+
+impl Display for ComparisonReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const HEADER_SIG: &str = "Contract::function";
+        const HEADER_BASELINE: &str = "Baseline";
+        const HEADER_CURRENT: &str = "Current";
+        const HEADER_DIFF: &str = "Diff";
+        const HEADER_CHANGE: &str = "Change";
+
+        let rows: Vec<_> = self
+            .0
+            .iter()
+            .map(|diff| {
+                (
+                    &*diff.sig,
+                    diff.baseline.to_string(),
+                    diff.current.to_string(),
+                    diff.diff(),
+                    format!("{:+.2}%", diff.change()),
+                )
+            })
+            .collect();
+
+        // Calculating the width of table columns.
+        let width1 = column_width(HEADER_SIG, rows.iter().map(|row| row.0));
+        let width2 =
+            column_width(HEADER_BASELINE, rows.iter().map(|row| &*row.1));
+        let width3 =
+            column_width(HEADER_CURRENT, rows.iter().map(|row| &*row.2));
+        let width4 = column_width(HEADER_DIFF, rows.iter().map(|row| &*row.3));
+        let width5 =
+            column_width(HEADER_CHANGE, rows.iter().map(|row| &*row.4));
+
+        // Print headers for the table columns.
+        writeln!(
+            f,
+            "| {HEADER_SIG:<width1$} | {HEADER_BASELINE:>width2$} | {HEADER_CURRENT:>width3$} | {HEADER_DIFF:>width4$} | {HEADER_CHANGE:>width5$} |"
+        )?;
+        writeln!(
+            f,
+            "| {:->width1$} | {:->width2$} | {:->width3$} | {:->width4$} | {:->width5$} |",
+            "", "", "", "", "",
+        )?;
+
+        for (sig, baseline, current, diff, change) in &rows {
+            writeln!(
+                f,
+                "| {sig:<width1$} | {baseline:>width2$} | {current:>width3$} | {diff:>width4$} | {change:>width5$} |"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn column_width<'a>(
+    header: &str,
+    values: impl Iterator<Item = &'a str>,
+) -> usize {
+    values
+        .map(str::len)
+        .chain(std::iter::once(header.len()))
+        .max()
+        .unwrap_or_default()
+}
@@ -27,9 +27,15 @@ sol!(
         function mintBatch(address to, uint256[] memory ids, uint256[] memory amounts) external;
         function burn(address from, uint256 id, uint256 amount) external;
         function burnBatch(address from, uint256[] memory ids, uint256[] memory amounts) external;
+        function cleanup(address owner, address spender, uint256[] memory ids) external returns (bool status);
     }
 );
 
+/// Label of `transferFrom` calls spending the caller's allowance, as opposed
+/// to calls by an operator.
+const TRANSFER_FROM_WITH_ALLOWANCE: &str =
+    "transferFrom(address,address,uint256,uint256) (allowance)";
+
 pub async fn bench() -> eyre::Result<ContractReport> {
     ContractReport::generate("Erc6909", run).await
 }
@@ -78,6 +84,9 @@ pub async fn run(cache_opt: Opt) -> eyre::Result<Vec<FunctionReport>> {
     let ids = vec![token_1, token_2, token_3, token_4];
     let values = vec![value_1, value_2, value_3, value_4];
 
+    // Leave a stale allowance for `cleanup` to clear.
+    receipt!(contract.approve(bob_addr, token_3, value_3))?;
+
     // IMPORTANT: Order matters!
     use Erc6909::*;
     #[rustfmt::skip]
@@ -85,12 +94,14 @@ pub async fn run(cache_opt: Opt) -> eyre::Result<Vec<FunctionReport>> {
         (mintCall::SIGNATURE, receipt!(contract.mint(alice_addr, token_1, value_1))?),
         (mintBatchCall::SIGNATURE, receipt!(contract.mintBatch(alice_addr, ids.clone(), values.clone()))?),
         (balanceOfCall::SIGNATURE, receipt!(contract.balanceOf(alice_addr, token_1))?),
-        (approveCall::SIGNATURE, receipt!(contract.approve(bob_addr, token_1, value_1))?),
-        (allowanceCall::SIGNATURE, receipt!(contract.allowance(alice_addr, bob_addr, token_1))?),
+        (approveCall::SIGNATURE, receipt!(contract.approve(bob_addr, token_2, value_2))?),
+        (allowanceCall::SIGNATURE, receipt!(contract.allowance(alice_addr, bob_addr, token_2))?),
         (setOperatorCall::SIGNATURE, receipt!(contract.setOperator(charlie_addr, true))?),
         (isOperatorCall::SIGNATURE, receipt!(contract.isOperator(alice_addr, charlie_addr))?),
         (transferCall::SIGNATURE, receipt!(contract.transfer(bob_addr, token_1, value_1))?),
         (transferFromCall::SIGNATURE, receipt!(contract_charlie.transferFrom(alice_addr, bob_addr, token_1, value_1))?),
+        (TRANSFER_FROM_WITH_ALLOWANCE, receipt!(contract_bob.transferFrom(alice_addr, bob_addr, token_2, value_2))?),
+        (cleanupCall::SIGNATURE, receipt!(contract.cleanup(alice_addr, bob_addr, vec![token_2, token_3]))?),
         (burnCall::SIGNATURE, receipt!(contract_bob.burn(bob_addr, token_1, value_1))?),
         (burnBatchCall::SIGNATURE, receipt!(contract_bob.burnBatch(bob_addr, ids, values))?),
     ];
@@ -0,0 +1,92 @@
+use alloy::{
+    network::{AnyNetwork, EthereumWallet},
+    primitives::{Address, U256},
+    providers::ProviderBuilder,
+    sol,
+};
+use e2e::{receipt, Account};
+use openzeppelin_stylus::utils::test_utils::token_ids_from;
+
+use crate::{
+    report::{ContractReport, FunctionReport},
+    Opt,
+};
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909 {
+        function mintBatch(address to, uint256[] memory ids, uint256[] memory amounts) external;
+    }
+);
+
+/// Batch sizes whose gas usage is always reported.
+const BATCH_SIZES: [usize; 5] = [1, 10, 100, 500, 1_000];
+
+/// Largest batch size probed when looking for the largest batch that fits in
+/// a block.
+const MAX_PROBED_BATCH_SIZE: usize = 16_000;
+
+pub async fn bench() -> eyre::Result<ContractReport> {
+    ContractReport::generate("Erc6909Batch", run).await
+}
+
+/// Mints batches of increasing sizes, each with fresh token ids.
+///
+/// After [`BATCH_SIZES`], the batch size keeps doubling until minting fails,
+/// e.g. because the batch doesn't fit in a block anymore. The largest
+/// `mintBatch(N ids)` entry of the report is then the largest batch that can
+/// be minted at once, within a factor of two.
+pub async fn run(cache_opt: Opt) -> eyre::Result<Vec<FunctionReport>> {
+    let alice = Account::new().await?;
+    let alice_addr = alice.address();
+    let alice_wallet = ProviderBuilder::new()
+        .network::<AnyNetwork>()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(alice.signer.clone()))
+        .on_http(alice.url().parse()?);
+
+    let contract_addr = deploy(&alice, cache_opt).await?;
+
+    let contract = Erc6909::new(contract_addr, &alice_wallet);
+
+    let mut receipts = vec![];
+    let mut next_id = 0;
+    for size in BATCH_SIZES {
+        let (ids, amounts) = batch(&mut next_id, size);
+        let receipt = receipt!(contract.mintBatch(alice_addr, ids, amounts))?;
+        receipts.push((format!("mintBatch({size} ids)"), receipt));
+    }
+
+    let mut size = BATCH_SIZES[BATCH_SIZES.len() - 1] * 2;
+    while size <= MAX_PROBED_BATCH_SIZE {
+        let (ids, amounts) = batch(&mut next_id, size);
+        let Ok(pending) =
+            contract.mintBatch(alice_addr, ids, amounts).send().await
+        else {
+            break;
+        };
+        let Ok(receipt) = pending.get_receipt().await else {
+            break;
+        };
+        receipts.push((format!("mintBatch({size} ids)"), receipt));
+        size *= 2;
+    }
+
+    receipts
+        .into_iter()
+        .map(|(sig, receipt)| FunctionReport::new((&sig, receipt)))
+        .collect::<eyre::Result<Vec<_>>>()
+}
+
+/// Returns `size` fresh token ids starting at `next_id`, along with the
+/// amounts to mint for each of them.
+fn batch(next_id: &mut usize, size: usize) -> (Vec<U256>, Vec<U256>) {
+    let ids = token_ids_from(*next_id, size);
+    *next_id += size;
+    let amounts = vec![U256::from(1); size];
+    (ids, amounts)
+}
+
+async fn deploy(account: &Account, cache_opt: Opt) -> eyre::Result<Address> {
+    crate::deploy(account, "erc6909", None, cache_opt).await
+}
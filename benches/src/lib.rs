@@ -1,4 +1,4 @@
-use std::process::Command;
+use std::{path::PathBuf, process::Command};
 
 use alloy::primitives::Address;
 use alloy_primitives::U128;
@@ -7,11 +7,13 @@ use eyre::WrapErr;
 use serde::Deserialize;
 
 pub mod access_control;
+pub mod compare;
 pub mod erc1155;
 pub mod erc1155_metadata_uri;
 pub mod erc1155_supply;
 pub mod erc20;
 pub mod erc6909;
+pub mod erc6909_batch;
 pub mod erc6909_supply;
 pub mod erc721;
 pub mod merkle_proofs;
@@ -48,23 +50,16 @@ async fn deploy(
     constructor: Option<Constructor>,
     opt: Opt,
 ) -> eyre::Result<Address> {
-    let manifest_dir =
-        std::env::current_dir().context("should get current dir from env")?;
-
     let contract_type = match opt {
         Opt::CacheWasmOpt => "example_opt",
         Opt::None | Opt::Cache => "example",
     };
 
-    let wasm_path = manifest_dir
-        .join("target")
-        .join("wasm32-unknown-unknown")
-        .join("release")
-        .join(format!(
-            "{}_{}.wasm",
-            contract_name.replace('-', "_"),
-            contract_type
-        ));
+    let wasm_path = artifacts_dir()?.join(format!(
+        "{}_{}.wasm",
+        contract_name.replace('-', "_"),
+        contract_type
+    ));
 
     let deployer = match constructor {
         Some(constructor) => {
@@ -85,6 +80,26 @@ async fn deploy(
     Ok(address)
 }
 
+/// Returns the directory containing the compiled wasm binaries of the
+/// examples.
+///
+/// Defaults to the release output of the current workspace, and can be
+/// overridden with the `BENCH_ARTIFACTS_DIR` environment variable, e.g. to
+/// benchmark the binaries built from a baseline revision.
+fn artifacts_dir() -> eyre::Result<PathBuf> {
+    if let Ok(dir) = std::env::var("BENCH_ARTIFACTS_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+
+    let manifest_dir =
+        std::env::current_dir().context("should get current dir from env")?;
+
+    Ok(manifest_dir
+        .join("target")
+        .join("wasm32-unknown-unknown")
+        .join("release"))
+}
+
 /// Try to cache a contract on the stylus network.
 /// Already cached contracts won't be cached, and this function will not return
 /// an error.
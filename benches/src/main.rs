@@ -1,32 +1,113 @@
+use std::path::PathBuf;
+
 use benches::{
-    access_control, erc1155, erc1155_metadata_uri, erc20, erc721,
-    merkle_proofs, ownable, pedersen, poseidon, poseidon_asm_sol, poseidon_sol,
-    report::BenchmarkReport,
+    access_control,
+    compare::ComparisonReport,
+    erc1155, erc1155_metadata_uri, erc20, erc6909, erc6909_batch,
+    erc6909_supply, erc721, merkle_proofs, ownable, pedersen, poseidon,
+    poseidon_asm_sol, poseidon_sol,
+    report::{BenchmarkReport, ContractReport},
 };
-use futures::FutureExt;
+use eyre::{bail, WrapErr};
+use futures::{future::BoxFuture, FutureExt};
 use itertools::Itertools;
 
+/// Default maximum gas increase, in percent, tolerated by `--baseline`.
+const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Default directory of the per-contract gas artifacts, relative to the
+/// workspace root.
+const DEFAULT_ARTIFACTS_DIR: &str = "target/gas-report";
+
+/// Command line options.
+///
+/// - `--only <name,...>`: only run the given benchmarks, e.g. `--only
+///   erc6909,erc6909_supply`.
+/// - `--save <path>`: store the report as JSON, to be used as a baseline.
+/// - `--baseline <path>`: compare the report with a stored baseline, and fail
+///   if the gas usage of a function increased by more than the threshold.
+/// - `--threshold <percent>`: maximum tolerated gas increase, defaults to
+///   [`DEFAULT_THRESHOLD`].
+/// - `--artifacts <dir>`: directory of the per-contract gas artifacts, defaults
+///   to [`DEFAULT_ARTIFACTS_DIR`].
+#[derive(Default)]
+struct Args {
+    only: Option<Vec<String>>,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: Option<f64>,
+    artifacts: Option<PathBuf>,
+}
+
+impl Args {
+    fn parse() -> eyre::Result<Self> {
+        let mut args = Args::default();
+        let mut iter = std::env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            let Some(value) = iter.next() else {
+                bail!("missing value for `{arg}`");
+            };
+            match arg.as_str() {
+                "--only" => {
+                    args.only =
+                        Some(value.split(',').map(str::to_owned).collect());
+                }
+                "--save" => args.save = Some(value.into()),
+                "--baseline" => args.baseline = Some(value.into()),
+                "--threshold" => {
+                    args.threshold = Some(
+                        value
+                            .parse()
+                            .wrap_err("threshold should be a number")?,
+                    );
+                }
+                "--artifacts" => args.artifacts = Some(value.into()),
+                _ => bail!("unknown argument `{arg}`"),
+            }
+        }
+        Ok(args)
+    }
+
+    fn should_run(&self, name: &str) -> bool {
+        self.only
+            .as_ref()
+            .is_none_or(|only| only.iter().any(|selected| selected == name))
+    }
+}
+
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
-    let benchmarks = [
-        access_control::bench().boxed(),
-        erc20::bench().boxed(),
-        erc721::bench().boxed(),
-        merkle_proofs::bench().boxed(),
-        ownable::bench().boxed(),
-        erc1155::bench().boxed(),
-        erc1155_metadata_uri::bench().boxed(),
-        pedersen::bench().boxed(),
-        poseidon_sol::bench().boxed(),
-        poseidon_asm_sol::bench().boxed(),
-        poseidon::bench().boxed(),
+    let args = Args::parse()?;
+
+    let benchmarks: Vec<(
+        &str,
+        BoxFuture<'static, eyre::Result<ContractReport>>,
+    )> = vec![
+        ("access_control", access_control::bench().boxed()),
+        ("erc20", erc20::bench().boxed()),
+        ("erc721", erc721::bench().boxed()),
+        ("merkle_proofs", merkle_proofs::bench().boxed()),
+        ("ownable", ownable::bench().boxed()),
+        ("erc1155", erc1155::bench().boxed()),
+        ("erc1155_metadata_uri", erc1155_metadata_uri::bench().boxed()),
+        ("erc6909", erc6909::bench().boxed()),
+        ("erc6909_batch", erc6909_batch::bench().boxed()),
+        ("erc6909_supply", erc6909_supply::bench().boxed()),
+        ("pedersen", pedersen::bench().boxed()),
+        ("poseidon_sol", poseidon_sol::bench().boxed()),
+        ("poseidon_asm_sol", poseidon_asm_sol::bench().boxed()),
+        ("poseidon", poseidon::bench().boxed()),
     ];
+    let benchmarks = benchmarks
+        .into_iter()
+        .filter(|(name, _)| args.should_run(name))
+        .map(|(_, bench)| bench);
 
     // Run benchmarks max 3 at the same time.
     // Otherwise, nitro test node can overload and revert transaction.
     const MAX_PARALLEL: usize = 3;
     let mut report = BenchmarkReport::default();
-    for chunk in &benchmarks.into_iter().chunks(MAX_PARALLEL) {
+    for chunk in &benchmarks.chunks(MAX_PARALLEL) {
         report = futures::future::try_join_all(chunk)
             .await?
             .into_iter()
@@ -36,5 +117,28 @@ async fn main() -> eyre::Result<()> {
     println!();
     println!("{report}");
 
+    let artifacts =
+        args.artifacts.unwrap_or_else(|| DEFAULT_ARTIFACTS_DIR.into());
+    report.write_gas_artifacts(&artifacts)?;
+
+    if let Some(path) = &args.save {
+        report.save(path)?;
+    }
+
+    if let Some(path) = &args.baseline {
+        let baseline = BenchmarkReport::load(path)?;
+        let comparison = ComparisonReport::new(&baseline, &report);
+        println!("{comparison}");
+
+        let threshold = args.threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let regressions = comparison.regressions(threshold);
+        if !regressions.is_empty() {
+            bail!(
+                "gas usage increased by more than {threshold}%: {}",
+                regressions.iter().join(", ")
+            );
+        }
+    }
+
     Ok(())
 }
@@ -1,12 +1,19 @@
-use std::{collections::HashMap, fmt::Display, future::Future};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    future::Future,
+    path::Path,
+};
 
 use alloy::network::AnyTransactionReceipt;
+use eyre::WrapErr;
+use serde::{Deserialize, Serialize};
 
 use crate::{ArbOtherFields, Opt};
 
 const SEPARATOR: &str = "::";
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FunctionReport {
     sig: String,
     gas: u128,
@@ -23,7 +30,7 @@ impl FunctionReport {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ContractReport {
     contract: String,
     functions: Vec<FunctionReport>,
@@ -120,7 +127,38 @@ impl ContractReport {
     }
 }
 
-#[derive(Debug, Default)]
+/// Machine-readable gas report of a contract, mapping each function to its
+/// median non cached gas usage.
+///
+/// Functions are sorted by signature, so that artifacts of different runs can
+/// be diffed line by line.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GasArtifact {
+    contract: String,
+    functions: BTreeMap<String, u128>,
+}
+
+impl ContractReport {
+    /// Returns the [`GasArtifact`] of the contract.
+    ///
+    /// Functions called several times during a run are reported with the
+    /// median of their gas usages.
+    pub fn gas_artifact(&self) -> GasArtifact {
+        let mut samples: BTreeMap<&str, Vec<u128>> = BTreeMap::new();
+        for func in &self.functions {
+            samples.entry(&func.sig).or_default().push(func.gas);
+        }
+
+        let functions = samples
+            .into_iter()
+            .map(|(sig, gas)| (sig.to_owned(), median(gas)))
+            .collect();
+
+        GasArtifact { contract: self.contract.clone(), functions }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct BenchmarkReport(Vec<ContractReport>);
 
 impl BenchmarkReport {
@@ -129,6 +167,65 @@ impl BenchmarkReport {
         self
     }
 
+    /// Loads a report previously stored with [`BenchmarkReport::save`].
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).wrap_err(format!(
+            "failed to read benchmark report {}",
+            path.display()
+        ))?;
+        serde_json::from_str(&json).wrap_err("failed to parse benchmark report")
+    }
+
+    /// Stores the report as JSON, e.g. to be used as a baseline later on.
+    pub fn save(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).wrap_err(format!(
+            "failed to write benchmark report {}",
+            path.display()
+        ))
+    }
+
+    /// Writes the [`GasArtifact`] of every contract as JSON to
+    /// `<dir>/<Contract>.json`, creating `dir` if needed.
+    pub fn write_gas_artifacts(
+        &self,
+        dir: impl AsRef<Path>,
+    ) -> eyre::Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).wrap_err(format!(
+            "failed to create gas artifacts directory {}",
+            dir.display()
+        ))?;
+
+        for report in &self.0 {
+            let path = dir.join(format!("{}.json", report.contract));
+            let json = serde_json::to_string_pretty(&report.gas_artifact())?;
+            std::fs::write(&path, json).wrap_err(format!(
+                "failed to write gas artifact {}",
+                path.display()
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the non cached gas usage of every function, keyed by
+    /// `Contract::function`.
+    pub(crate) fn gas_by_signature(
+        &self,
+    ) -> impl Iterator<Item = (String, u128)> + '_ {
+        self.0.iter().flat_map(|report| {
+            report.functions.iter().map(move |func| {
+                (
+                    format!("{}{SEPARATOR}{}", report.contract, func.sig),
+                    func.gas,
+                )
+            })
+        })
+    }
+
     pub fn column_width(
         &self,
         column_value: impl FnMut(&ContractReport) -> usize,
@@ -214,6 +311,17 @@ impl Display for BenchmarkReport {
     }
 }
 
+/// Returns the median of `samples`, rounded down when their number is even.
+fn median(mut samples: Vec<u128>) -> u128 {
+    samples.sort_unstable();
+    let mid = samples.len() / 2;
+    match samples.len() {
+        0 => 0,
+        len if len % 2 == 0 => (samples[mid - 1] + samples[mid]) / 2,
+        _ => samples[mid],
+    }
+}
+
 const BASE_GAS_FEE: u128 = 21_000;
 
 fn get_l2_gas_used(receipt: &AnyTransactionReceipt) -> eyre::Result<u128> {
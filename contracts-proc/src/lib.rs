@@ -17,6 +17,7 @@ macro_rules! error {
 }
 
 mod interface_id;
+mod solidity_error;
 
 /// Computes the interface id as an associated constant `INTERFACE_ID` for the
 /// trait that describes contract's abi.
@@ -61,3 +62,25 @@ mod interface_id;
 pub fn interface_id(attr: TokenStream, input: TokenStream) -> TokenStream {
     interface_id::interface_id(&attr, input)
 }
+
+/// Derives the conversion of an error enum into revert data, as well as the
+/// conversions of each wrapped Solidity error into the enum.
+///
+/// Drop-in replacement of `stylus_sdk`'s `#[derive(SolidityError)]` that
+/// encodes errors through `crate::utils::revert::encode`, so that the way
+/// errors are reverted with can be configured in a single place. Hence, it is
+/// only meant to be used within the `openzeppelin-stylus` crate.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(SolidityError, Debug)]
+/// pub enum Error {
+///     EnforcedPause(EnforcedPause),
+///     ExpectedPause(ExpectedPause),
+/// }
+/// ```
+#[proc_macro_derive(SolidityError)]
+pub fn solidity_error(input: TokenStream) -> TokenStream {
+    solidity_error::solidity_error(input)
+}
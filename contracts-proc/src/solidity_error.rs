@@ -0,0 +1,65 @@
+//! Defines the `#[derive(SolidityError)]` procedural macro.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Fields, ItemEnum};
+
+/// Implements the conversions of an error enum, whose variants wrap a single
+/// Solidity error each, into revert data.
+pub(crate) fn solidity_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemEnum);
+    let name = &input.ident;
+
+    let mut variants = Vec::new();
+    let mut types = Vec::new();
+    for variant in &input.variants {
+        let Fields::Unnamed(fields) = &variant.fields else {
+            error!(variant, "variant should wrap a single error");
+        };
+        let Some(field) =
+            fields.unnamed.first().filter(|_| fields.unnamed.len() == 1)
+        else {
+            error!(variant, "variant should wrap a single error");
+        };
+        variants.push(&variant.ident);
+        types.push(&field.ty);
+    }
+
+    quote! {
+        #(
+            impl From<#types> for #name {
+                fn from(value: #types) -> Self {
+                    #name::#variants(value)
+                }
+            }
+        )*
+
+        impl From<#name> for alloc::vec::Vec<u8> {
+            fn from(err: #name) -> Self {
+                match err {
+                    #( #name::#variants(e) => crate::utils::revert::encode(e), )*
+                }
+            }
+        }
+
+        #[cfg(feature = "export-abi")]
+        impl stylus_sdk::abi::export::internal::InnerTypes for #name {
+            fn inner_types() -> alloc::vec::Vec<stylus_sdk::abi::export::internal::InnerType> {
+                use alloc::{format, vec};
+                use core::any::TypeId;
+                use stylus_sdk::abi::export::internal::InnerType;
+                use stylus_sdk::alloy_sol_types::SolError;
+
+                vec![
+                    #(
+                        InnerType {
+                            name: format!("error {};", <#types as SolError>::SIGNATURE.replace(',', ", ")),
+                            id: TypeId::of::<#types>(),
+                        }
+                    ),*
+                ]
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,520 @@
+//! Extension of [`AccessControl`] that allows enumerating the members of each
+//! role.
+//!
+//! Role members are stored in an unordered list per role: their order may
+//! change whenever a member is removed, so they should be enumerated within a
+//! single block, or through [`AccessControlEnumerable::get_role_members`].
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256, StorageVec},
+};
+
+use crate::{
+    access::control::{
+        self, AccessControl, AccessControlBadConfirmation,
+        AccessControlUnauthorizedAccount, IAccessControl,
+    },
+    impl_sol_struct,
+    utils::{introspection::erc165::IErc165, Pagination},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// A page of the accounts that have a role.
+        ///
+        /// * `members` - Accounts of the page.
+        /// * `nextCursor` - Cursor of the next page, or zero if there is none.
+        #[derive(Debug, Default, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        struct RoleMembersPage {
+            address[] members;
+            uint256 nextCursor;
+        }
+    }
+
+    sol! {
+        /// Indicates that `role` has no member at `index`.
+        ///
+        /// * `role` - The role identifier.
+        /// * `index` - Index of the requested member.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error AccessControlOutOfBoundsIndex(bytes32 role, uint256 index);
+    }
+}
+
+impl_sol_struct!(RoleMembersPage, (Vec<Address>, U256));
+
+/// An [`AccessControlEnumerable`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The caller account is missing a role.
+    UnauthorizedAccount(AccessControlUnauthorizedAccount),
+    /// The caller of a function is not the expected one.
+    BadConfirmation(AccessControlBadConfirmation),
+    /// The requested member index is out of bounds.
+    OutOfBoundsIndex(AccessControlOutOfBoundsIndex),
+}
+
+impl From<control::Error> for Error {
+    fn from(value: control::Error) -> Self {
+        match value {
+            control::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            control::Error::BadConfirmation(e) => Error::BadConfirmation(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`AccessControlEnumerable`] contract.
+#[storage]
+pub struct AccessControlEnumerable {
+    /// [`AccessControl`] contract.
+    pub access_control: AccessControl,
+    /// Role identifier -> Accounts that have been granted the role.
+    pub(crate) role_members: StorageMap<B256, StorageVec<StorageAddress>>,
+    /// Role identifier -> Position (starting at one) of each member in
+    /// `role_members`. Zero for accounts without the role.
+    pub(crate) role_member_positions:
+        StorageMap<B256, StorageMap<Address, StorageU256>>,
+}
+
+/// Interface for an [`AccessControlEnumerable`] contract.
+#[interface_id]
+pub trait IAccessControlEnumerable {
+    /// The error type associated with this interface implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns one of the accounts that have `role`. `index` must be lower
+    /// than [`Self::get_role_member_count`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `role` - The role identifier.
+    /// * `index` - Index of the member.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfBoundsIndex`] - If `role` has no member at `index`.
+    fn get_role_member(
+        &self,
+        role: B256,
+        index: U256,
+    ) -> Result<Address, Self::Error>;
+
+    /// Returns the number of accounts that have `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `role` - The role identifier.
+    fn get_role_member_count(&self, role: B256) -> U256;
+}
+
+#[public]
+#[implements(
+    IAccessControl<Error = Error>,
+    IAccessControlEnumerable<Error = Error>,
+    IErc165
+)]
+impl AccessControlEnumerable {
+    /// Returns at most `limit` accounts that have `role`, starting at
+    /// `cursor`, and the cursor of the next page, or zero if there is none,
+    /// as a [`RoleMembersPage`] struct.
+    ///
+    /// `limit` is capped to
+    /// [`MAX_PAGE_SIZE`](crate::utils::pagination::MAX_PAGE_SIZE).
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `role` - The role identifier.
+    /// * `cursor` - Index of the first member to return.
+    /// * `limit` - Maximum number of members to return.
+    #[must_use]
+    pub fn get_role_members(
+        &self,
+        role: B256,
+        cursor: U256,
+        limit: U256,
+    ) -> RoleMembersPage {
+        let members = self.role_members.getter(role);
+        let page = Pagination::new(cursor, limit, members.len());
+        let accounts = page.range().filter_map(|i| members.get(i)).collect();
+        RoleMembersPage { members: accounts, nextCursor: page.next_cursor() }
+    }
+}
+
+#[public]
+impl IAccessControlEnumerable for AccessControlEnumerable {
+    type Error = Error;
+
+    fn get_role_member(
+        &self,
+        role: B256,
+        index: U256,
+    ) -> Result<Address, Self::Error> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.role_members.getter(role).get(i))
+            .ok_or(Error::OutOfBoundsIndex(AccessControlOutOfBoundsIndex {
+                role,
+                index,
+            }))
+    }
+
+    fn get_role_member_count(&self, role: B256) -> U256 {
+        U256::from(self.role_members.getter(role).len())
+    }
+}
+
+#[public]
+impl IAccessControl for AccessControlEnumerable {
+    type Error = Error;
+
+    fn has_role(&self, role: B256, account: Address) -> bool {
+        self.access_control.has_role(role, account)
+    }
+
+    fn only_role(&self, role: B256) -> Result<(), Self::Error> {
+        Ok(self.access_control.only_role(role)?)
+    }
+
+    fn get_role_admin(&self, role: B256) -> B256 {
+        self.access_control.get_role_admin(role)
+    }
+
+    fn grant_role(
+        &mut self,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        let admin_role = self.get_role_admin(role);
+        self.only_role(admin_role)?;
+        self._grant_role(role, account);
+        Ok(())
+    }
+
+    fn revoke_role(
+        &mut self,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        let admin_role = self.get_role_admin(role);
+        self.only_role(admin_role)?;
+        self._revoke_role(role, account);
+        Ok(())
+    }
+
+    fn renounce_role(
+        &mut self,
+        role: B256,
+        confirmation: Address,
+    ) -> Result<(), Self::Error> {
+        if msg::sender() != confirmation {
+            return Err(Error::BadConfirmation(
+                AccessControlBadConfirmation {},
+            ));
+        }
+
+        self._revoke_role(role, confirmation);
+        Ok(())
+    }
+}
+
+impl AccessControlEnumerable {
+    /// Sets `admin_role` as `role`'s admin role.
+    ///
+    /// Re-export of [`AccessControl::_set_role_admin`].
+    pub fn _set_role_admin(&mut self, role: B256, new_admin_role: B256) {
+        self.access_control._set_role_admin(role, new_admin_role);
+    }
+
+    /// Checks if `account` has been granted `role`.
+    ///
+    /// Re-export of [`AccessControl::_check_role`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _check_role(
+        &self,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Error> {
+        Ok(self.access_control._check_role(role, account)?)
+    }
+
+    /// Attempts to grant `role` to `account` and returns a boolean indicating
+    /// if `role` was granted. Tracks `account` as a member of `role`.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `role` - The role identifier.
+    /// * `account` - The account which will be granted the role.
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleGranted`].
+    pub fn _grant_role(&mut self, role: B256, account: Address) -> bool {
+        let granted = self.access_control._grant_role(role, account);
+        if granted {
+            let mut members = self.role_members.setter(role);
+            members.push(account);
+            let position = U256::from(members.len());
+            self.role_member_positions
+                .setter(role)
+                .setter(account)
+                .set(position);
+        }
+        granted
+    }
+
+    /// Attempts to revoke `role` from `account` and returns a boolean
+    /// indicating if `role` was revoked. Stops tracking `account` as a
+    /// member of `role`.
+    ///
+    /// To avoid gaps, the last member of the role is moved to the position of
+    /// the removed one.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `role` - The role identifier.
+    /// * `account` - The account which will be revoked the role.
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleRevoked`].
+    ///
+    /// # Panics
+    ///
+    /// * If the members of `role` are inconsistent with its granted accounts.
+    pub fn _revoke_role(&mut self, role: B256, account: Address) -> bool {
+        let revoked = self.access_control._revoke_role(role, account);
+        if revoked {
+            let position = self.role_member_positions.get(role).get(account);
+            let mut members = self.role_members.setter(role);
+            let last_member = members.pop().expect("should contain `account`");
+            if last_member != account {
+                let index =
+                    usize::try_from(position).expect("should fit `usize`") - 1;
+                members
+                    .setter(index)
+                    .expect("should be a valid position")
+                    .set(last_member);
+                self.role_member_positions
+                    .setter(role)
+                    .setter(last_member)
+                    .set(position);
+            }
+            self.role_member_positions.setter(role).delete(account);
+        }
+        revoked
+    }
+}
+
+#[public]
+impl IErc165 for AccessControlEnumerable {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IAccessControlEnumerable>::interface_id() == interface_id
+            || self.access_control.supports_interface(interface_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256,
+    };
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const ROLE: B256 = fixed_bytes!(
+        "0xed9ea7bc2a13bc59432ab07436e7f7f5450f82d4b48c401bed177bfaf36b1873"
+    );
+    const DEFAULT_ADMIN_ROLE: B256 =
+        B256::new(AccessControl::DEFAULT_ADMIN_ROLE);
+
+    unsafe impl TopLevelStorage for AccessControlEnumerable {}
+
+    #[motsu::test]
+    fn granted_accounts_are_enumerable(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._grant_role(DEFAULT_ADMIN_ROLE, alice);
+        contract
+            .sender(alice)
+            .grant_role(ROLE, bob)
+            .motsu_expect("admin should grant role");
+        contract
+            .sender(alice)
+            .grant_role(ROLE, charlie)
+            .motsu_expect("admin should grant role");
+        contract
+            .sender(alice)
+            .grant_role(ROLE, bob)
+            .motsu_expect("admin should grant role again");
+
+        assert_eq!(
+            contract.sender(alice).get_role_member_count(ROLE),
+            uint!(2_U256)
+        );
+        assert_eq!(
+            contract
+                .sender(alice)
+                .get_role_member(ROLE, U256::ZERO)
+                .motsu_expect("should return member"),
+            bob
+        );
+        assert_eq!(
+            contract
+                .sender(alice)
+                .get_role_member(ROLE, uint!(1_U256))
+                .motsu_expect("should return member"),
+            charlie
+        );
+    }
+
+    #[motsu::test]
+    fn revoked_accounts_are_removed(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._grant_role(ROLE, alice);
+        contract.sender(alice)._grant_role(ROLE, bob);
+        contract.sender(alice)._grant_role(ROLE, charlie);
+
+        assert!(contract.sender(alice)._revoke_role(ROLE, alice));
+        contract
+            .sender(bob)
+            .renounce_role(ROLE, bob)
+            .motsu_expect("bearer should renounce role");
+
+        assert_eq!(
+            contract.sender(alice).get_role_member_count(ROLE),
+            uint!(1_U256)
+        );
+        assert_eq!(
+            contract
+                .sender(alice)
+                .get_role_member(ROLE, U256::ZERO)
+                .motsu_expect("should return member"),
+            charlie
+        );
+        assert!(!contract.sender(alice)._revoke_role(ROLE, alice));
+    }
+
+    #[motsu::test]
+    fn get_role_member_errors_when_out_of_bounds(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._grant_role(ROLE, alice);
+
+        let err = contract
+            .sender(alice)
+            .get_role_member(ROLE, uint!(1_U256))
+            .motsu_expect_err("should not return missing member");
+
+        assert!(matches!(
+            err,
+            Error::OutOfBoundsIndex(AccessControlOutOfBoundsIndex {
+                role,
+                index,
+            }) if role == ROLE && index == uint!(1_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn get_role_members_paginates(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._grant_role(ROLE, alice);
+        contract.sender(alice)._grant_role(ROLE, bob);
+        contract.sender(alice)._grant_role(ROLE, charlie);
+
+        let page = contract.sender(alice).get_role_members(
+            ROLE,
+            U256::ZERO,
+            uint!(2_U256),
+        );
+        assert_eq!(page.members, vec![alice, bob]);
+        assert_eq!(page.nextCursor, uint!(2_U256));
+
+        let page = contract.sender(alice).get_role_members(
+            ROLE,
+            page.nextCursor,
+            uint!(2_U256),
+        );
+        assert_eq!(page.members, vec![charlie]);
+        assert!(page.nextCursor.is_zero());
+    }
+
+    #[motsu::test]
+    fn non_admin_cannot_grant_role(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .grant_role(ROLE, bob)
+            .motsu_expect_err("non admin should not grant role");
+
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+        assert!(contract.sender(alice).get_role_member_count(ROLE).is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <AccessControlEnumerable as IAccessControlEnumerable>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0x5a05180f");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<AccessControlEnumerable>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <AccessControlEnumerable as IAccessControlEnumerable>::interface_id(
+            )
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <AccessControl as IAccessControl>::interface_id()
+        ));
+    }
+}
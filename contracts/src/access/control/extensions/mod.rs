@@ -0,0 +1,4 @@
+//! Common extensions to the [`AccessControl`](super::AccessControl) contract.
+pub mod enumerable;
+
+pub use enumerable::{AccessControlEnumerable, IAccessControlEnumerable};
@@ -42,7 +42,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, FixedBytes, B256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError,
@@ -53,6 +53,8 @@ use stylus_sdk::{
 
 use crate::utils::introspection::erc165::IErc165;
 
+pub mod extensions;
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
     use alloy_sol_macro::sol;
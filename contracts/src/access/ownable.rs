@@ -11,7 +11,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, FixedBytes};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError, evm, msg, prelude::*, storage::StorageAddress,
@@ -231,6 +231,18 @@ impl IErc165 for Ownable {
     }
 }
 
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Ownable {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        vec![event::<OwnershipTransferred>(
+            "event OwnershipTransferred(address indexed previous_owner, \
+             address indexed new_owner)",
+        )]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use motsu::prelude::Contract;
@@ -32,7 +32,7 @@ use alloc::{
 };
 
 use alloy_primitives::{Address, FixedBytes, U256, U64};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     block,
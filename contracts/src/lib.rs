@@ -87,7 +87,11 @@ impl IErc20 for MyContract {
     clippy::used_underscore_items,
     deprecated
 )]
-#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+#![cfg_attr(
+    not(any(test, feature = "export-abi", feature = "std")),
+    no_std,
+    no_main
+)]
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 #![deny(rustdoc::broken_intra_doc_links)]
 extern crate alloc;
@@ -118,10 +118,12 @@ mod tests {
     use motsu::prelude::Contract;
 
     use super::IErc1155Burnable;
-    use crate::token::erc1155::{
-        tests::{random_token_ids, random_values},
-        ERC1155InsufficientBalance, ERC1155InvalidSender,
-        ERC1155MissingApprovalForAll, Erc1155, Error, IErc1155,
+    use crate::{
+        token::erc1155::{
+            ERC1155InsufficientBalance, ERC1155InvalidSender,
+            ERC1155MissingApprovalForAll, Erc1155, Error, IErc1155,
+        },
+        utils::test_utils::{random_token_ids, random_values},
     };
 
     fn init(
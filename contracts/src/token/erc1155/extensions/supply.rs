@@ -396,9 +396,9 @@ mod tests {
     };
 
     use super::*;
-    use crate::token::erc1155::{
-        tests::{random_token_ids, random_values},
-        ERC1155InvalidReceiver, ERC1155InvalidSender,
+    use crate::{
+        token::erc1155::{ERC1155InvalidReceiver, ERC1155InvalidSender},
+        utils::test_utils::{random_token_ids, random_values},
     };
 
     unsafe impl TopLevelStorage for Erc1155Supply {}
@@ -6,7 +6,7 @@ use alloc::{
 };
 
 use alloy_primitives::{Address, FixedBytes, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 use stylus_sdk::{
     abi::Bytes,
     call::{self, Call, MethodError},
@@ -1157,15 +1157,10 @@ mod tests {
         IErc1155, Transfer, BATCH_TRANSFER_FN_SELECTOR,
         SINGLE_TRANSFER_FN_SELECTOR,
     };
-    use crate::utils::introspection::erc165::IErc165;
-
-    pub(crate) fn random_token_ids(size: usize) -> Vec<U256> {
-        (0..size).map(U256::from).collect()
-    }
-
-    pub(crate) fn random_values(size: usize) -> Vec<U256> {
-        (1..=size).map(U256::from).collect()
-    }
+    use crate::utils::{
+        introspection::erc165::IErc165,
+        test_utils::{random_token_ids, random_values},
+    };
 
     fn init(
         contract: &mut Erc1155,
@@ -8,7 +8,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::U256;
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{call::MethodError, prelude::*, storage::StorageU256};
 
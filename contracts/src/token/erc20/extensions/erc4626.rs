@@ -10,7 +10,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{uint, Address, U256, U8};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     call::{Call, MethodError},
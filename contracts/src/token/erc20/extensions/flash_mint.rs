@@ -20,7 +20,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 use stylus_sdk::{
     abi::Bytes,
     call::{Call, MethodError},
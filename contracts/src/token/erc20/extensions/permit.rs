@@ -16,6 +16,7 @@ use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256, U8};
 use alloy_sol_types::SolType;
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{block, call::MethodError, function_selector, prelude::*};
 
 use crate::{
@@ -0,0 +1,200 @@
+//! Solidity ABI of the ERC-6909 token: its events, errors and functions.
+//!
+//! The contracts of this crate emit and revert with these types, and
+//! host-side code, like benches and end-to-end tests, can reuse them to
+//! decode logs and reverts, or to encode calls, instead of declaring them
+//! again. Enable the `std` feature to use them outside of a contract.
+#![allow(missing_docs)]
+#![cfg_attr(coverage_nightly, coverage(off))]
+
+use alloy_sol_macro::sol;
+
+sol! {
+    /// Emitted when a `caller` transfers an `amount` of token `id`
+    /// from a `sender` to a receiver.
+    ///
+    /// * `caller` - Address of the initiator of the transfer.
+    /// * `sender` - Address of the sender.
+    /// * `receiver` - Address of the receiver.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of token transferred.
+    #[derive(Debug, PartialEq)]
+    event Transfer(
+        address caller,
+        address indexed sender,
+        address indexed receiver,
+        uint256 indexed id,
+        uint256 amount,
+    );
+
+    /// Emitted when a token `owner` sets the `approved` status of
+    /// a `spender`.
+    ///
+    /// * `owner` - Address of the owner of the token.
+    /// * `spender` - Address of the spender.
+    /// * `approved` - Approved status as a boolean.
+    #[derive(Debug, PartialEq)]
+    event OperatorSet(
+        address indexed owner,
+        address indexed spender,
+        bool approved,
+    );
+
+    /// Emitted when a token `owner` has approved a `spender` to
+    /// transfer an `amount` of a token `id` to be transferred
+    /// on the owner's behalf.
+    ///
+    /// * `owner` - Address of the owner of the token.
+    /// * `spender` - Address of the spender.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of token approved to be transferred.
+    #[derive(Debug, PartialEq)]
+    event Approval(
+        address indexed owner,
+        address indexed spender,
+        uint256 indexed id,
+        uint256 amount,
+    );
+
+    /// Emitted when `amount` of tokens of type `id` are
+    /// transferred from `from` to `to` by `caller`.
+    #[derive(Debug, PartialEq)]
+    event TransferSingle(
+        address indexed caller,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 amount
+    );
+
+    /// Equivalent to multiple [`TransferSingle`] events, where `caller`
+    /// `from` and `to` are the same for all transfers.
+    #[derive(Debug, PartialEq)]
+    event TransferBatch(
+        address indexed caller,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] amounts
+    );
+}
+
+sol! {
+    /// Thrown when `owner`'s balance for `id` is insufficient.
+    ///
+    /// * `owner` - Address of the owner of the token.
+    /// * `id` - Token id as a number.
+    #[derive(Debug)]
+    error Erc6909InsufficientBalance(
+        address sender,
+        uint256 balance,
+        uint256 needed,
+        uint256 id,
+    );
+
+    /// Thrown when the spender does not have permission to
+    /// spend the token.
+    ///
+    /// * `spender` - Address of the spender
+    /// * `id` - Token id as a number
+    #[derive(Debug)]
+    error Erc6909InsufficientPermission(
+        address spender,
+        uint256 id
+    );
+
+    /// Thrown when a `spender`'s allowance for a token type
+    /// of `id` is insufficient.
+    ///
+    /// * `owner` - Address of the owner of the token.
+    /// * `id` - Token id as a number.
+    #[derive(Debug)]
+    error Erc6909InsufficientAllowance(
+        address spender,
+        uint256 allowance,
+        uint256 needed,
+        uint256 id,
+    );
+
+    /// Indicates a failure with the token `sender`.
+    /// Used in transfers.
+    ///
+    /// * `approver` - Approver of the token.
+    #[derive(Debug)]
+    error ERC6909InvalidApprover(address approver);
+
+    /// Indicates a failure with the token `sender`.
+    /// Used in transfers.
+    ///
+    /// * `sender` - Address whose tokens are being transferred.
+    #[derive(Debug)]
+    error ERC6909InvalidSender(address sender);
+
+    /// Indicates a failure with the token `spender`.
+    /// Used in transfers.
+    ///
+    /// * `spender` - Address attempting to spend tokens on behalf
+    /// of another address.
+    #[derive(Debug)]
+    error ERC6909InvalidSpender(address spender);
+
+    /// Indicates a failure with the `approver` of an allowance over the
+    /// token `id`.
+    /// Used in approvals.
+    ///
+    /// * `approver` - Approver of the token.
+    /// * `id` - Token id as a number.
+    #[derive(Debug)]
+    error ERC6909InvalidApproverForId(address approver, uint256 id);
+
+    /// Indicates a failure with the `spender` of an allowance over the
+    /// token `id`.
+    /// Used in approvals.
+    ///
+    /// * `spender` - Address allowed to spend tokens on behalf of another
+    ///   address.
+    /// * `id` - Token id as a number.
+    #[derive(Debug)]
+    error ERC6909InvalidSpenderForId(address spender, uint256 id);
+
+    /// Indicates a failure with the token `receiver`.
+    /// Used in transfers.
+    ///
+    /// * `receiver` - Address to which tokens are being transferred.
+    #[derive(Debug)]
+    error ERC6909InvalidReceiver(address receiver);
+
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    ///
+    /// * `ids_length` - Length of the array of token identifiers.
+    /// * `values_length` - Length of the array of token amounts.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    error ERC6909InvalidArrayLength(
+        uint256 ids_length,
+        uint256 values_length
+    );
+
+    /// Indicates that the token contract itself was used as the receiver of
+    /// a transfer or the spender of an approval, which would strand the
+    /// tokens. Only raised with the `self-target-guard` feature.
+    ///
+    /// * `target` - Address of the token contract.
+    #[derive(Debug)]
+    error ERC6909SelfTarget(address target);
+}
+
+sol! {
+    /// Functions of the ERC-6909 token.
+    interface IErc6909 {
+        function balanceOf(address owner, uint256 id) external view returns (uint256);
+        function allowance(address owner, address spender, uint256 id) external view returns (uint256);
+        function isOperator(address owner, address spender) external view returns (bool);
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool);
+        function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 id, uint256 amount) external returns (bool);
+        function setOperator(address spender, bool approved) external returns (bool);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
@@ -0,0 +1,144 @@
+//! Forwarding of [`IErc6909`] to an embedded ERC-6909 implementation.
+//!
+//! Contracts embedding [`Erc6909`] or one of its extensions usually implement
+//! [`IErc6909`] by forwarding each function to the embedded field.
+//! [`delegate_ierc6909!`] generates that implementation:
+//!
+//! ```rust,ignore
+//! #[entrypoint]
+//! #[storage]
+//! struct Erc6909SupplyExample {
+//!     erc6909_supply: Erc6909Supply,
+//! }
+//!
+//! delegate_ierc6909!(Erc6909SupplyExample => erc6909_supply, supply::Error);
+//!
+//! #[public]
+//! #[implements(IErc6909<Error = supply::Error>, IErc165)]
+//! impl Erc6909SupplyExample {}
+//! ```
+//!
+//! The errors of the embedded field are converted into the given error type
+//! with [`From`], so the field may use a narrower error type than the
+//! contract, e.g. a plain [`Erc6909`] in a contract reverting with the errors
+//! of one of its extensions.
+//!
+//! [`IErc6909`]: crate::token::erc6909::IErc6909
+//! [`Erc6909`]: crate::token::erc6909::Erc6909
+//! [`delegate_ierc6909!`]: crate::delegate_ierc6909
+
+/// Implements [`IErc6909`] for the contract `$contract`, forwarding every
+/// function to its field `$field`, and reverting with `$error`.
+///
+/// The field can be any type implementing [`IErc6909`], whose error converts
+/// into `$error`. The generated implementation is a `#[public]` one, so the
+/// contract still has to list `IErc6909<Error = $error>` in its
+/// `#[implements]` attribute.
+///
+/// [`IErc6909`]: crate::token::erc6909::IErc6909
+#[macro_export]
+macro_rules! delegate_ierc6909 {
+    ($contract:ident => $field:ident, $error:ty) => {
+        // Errors are converted with `?`, which is a no-op when `$error` is
+        // the error type of `$field`.
+        #[allow(clippy::needless_question_mark)]
+        #[stylus_sdk::prelude::public]
+        impl $crate::token::erc6909::IErc6909 for $contract {
+            type Error = $error;
+
+            fn transfer(
+                &mut self,
+                receiver: stylus_sdk::alloy_primitives::Address,
+                id: stylus_sdk::alloy_primitives::U256,
+                amount: stylus_sdk::alloy_primitives::U256,
+            ) -> Result<bool, Self::Error> {
+                Ok($crate::token::erc6909::IErc6909::transfer(
+                    &mut self.$field,
+                    receiver,
+                    id,
+                    amount,
+                )?)
+            }
+
+            fn transfer_from(
+                &mut self,
+                sender: stylus_sdk::alloy_primitives::Address,
+                receiver: stylus_sdk::alloy_primitives::Address,
+                id: stylus_sdk::alloy_primitives::U256,
+                amount: stylus_sdk::alloy_primitives::U256,
+            ) -> Result<bool, Self::Error> {
+                Ok($crate::token::erc6909::IErc6909::transfer_from(
+                    &mut self.$field,
+                    sender,
+                    receiver,
+                    id,
+                    amount,
+                )?)
+            }
+
+            fn approve(
+                &mut self,
+                spender: stylus_sdk::alloy_primitives::Address,
+                id: stylus_sdk::alloy_primitives::U256,
+                amount: stylus_sdk::alloy_primitives::U256,
+            ) -> Result<bool, Self::Error> {
+                Ok($crate::token::erc6909::IErc6909::approve(
+                    &mut self.$field,
+                    spender,
+                    id,
+                    amount,
+                )?)
+            }
+
+            fn set_operator(
+                &mut self,
+                spender: stylus_sdk::alloy_primitives::Address,
+                approved: bool,
+            ) -> Result<bool, Self::Error> {
+                Ok($crate::token::erc6909::IErc6909::set_operator(
+                    &mut self.$field,
+                    spender,
+                    approved,
+                )?)
+            }
+
+            fn balance_of(
+                &self,
+                owner: stylus_sdk::alloy_primitives::Address,
+                id: stylus_sdk::alloy_primitives::U256,
+            ) -> stylus_sdk::alloy_primitives::U256 {
+                $crate::token::erc6909::IErc6909::balance_of(
+                    &self.$field,
+                    owner,
+                    id,
+                )
+            }
+
+            fn allowance(
+                &self,
+                owner: stylus_sdk::alloy_primitives::Address,
+                spender: stylus_sdk::alloy_primitives::Address,
+                id: stylus_sdk::alloy_primitives::U256,
+            ) -> stylus_sdk::alloy_primitives::U256 {
+                $crate::token::erc6909::IErc6909::allowance(
+                    &self.$field,
+                    owner,
+                    spender,
+                    id,
+                )
+            }
+
+            fn is_operator(
+                &self,
+                owner: stylus_sdk::alloy_primitives::Address,
+                spender: stylus_sdk::alloy_primitives::Address,
+            ) -> bool {
+                $crate::token::erc6909::IErc6909::is_operator(
+                    &self.$field,
+                    owner,
+                    spender,
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,499 @@
+//! Extension of ERC-6909 that tracks how much of each allowance has been
+//! spent.
+//!
+//! Every time a spender moves tokens on behalf of an owner through
+//! [`IErc6909::transfer_from`], the amount spent out of its allowance is
+//! added to a cumulative counter per owner, spender and token id, so that
+//! e.g. risk dashboards can compare the part of a grant that was actually
+//! used with the part still outstanding. Transfers made by operators don't
+//! spend any allowance, and are not tracked.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    evm, msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidSender, Erc6909, Error, IErc6909,
+        IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `spender` spends `amount` out of its allowance over
+        /// the `owner`'s `id` tokens.
+        ///
+        /// * `owner` - Account of the token's owner.
+        /// * `spender` - Account spending the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of allowance spent.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event AllowanceSpent(
+            address indexed owner,
+            address indexed spender,
+            uint256 indexed id,
+            uint256 amount
+        );
+    }
+}
+
+/// State of an [`Erc6909AllowanceTracking`] contract.
+#[storage]
+pub struct Erc6909AllowanceTracking {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from owner to spender to token id to the cumulative amount of
+    /// allowance spent.
+    pub(crate) spent_allowances:
+        StorageMap<Address, StorageMap<Address, StorageMap<U256, StorageU256>>>,
+}
+
+impl_as_erc6909!(Erc6909AllowanceTracking, erc6909);
+
+/// Required interface of an [`Erc6909AllowanceTracking`] contract.
+#[interface_id]
+pub trait IErc6909AllowanceTracking: IErc165 {
+    /// Returns the cumulative amount `spender` has spent out of its
+    /// allowances over the `owner`'s `id` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `spender` - Account spending the tokens.
+    /// * `id` - Token id as a number.
+    fn spent_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+    ) -> U256;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909AllowanceTracking, IErc165)]
+impl Erc6909AllowanceTracking {}
+
+#[public]
+impl IErc6909AllowanceTracking for Erc6909AllowanceTracking {
+    fn spent_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+    ) -> U256 {
+        self.spent_allowances.get(owner).get(spender).get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909AllowanceTracking {
+    type Error = erc6909::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.transfer(receiver, id, amount)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        if sender.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender { sender }));
+        }
+
+        if sender != caller && !self.erc6909.is_operator(sender, caller) {
+            self._spend_allowance(sender, caller, id, amount)?;
+        }
+
+        self.erc6909._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909AllowanceTracking {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909AllowanceTracking>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909AllowanceTracking {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._mint(to, id, amount)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._mint_batch(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._burn(from, id, amount)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._burn_batch(from, ids, amounts)
+    }
+}
+
+impl Erc6909AllowanceTracking {
+    /// Extended version of [`Erc6909::_spend_allowance`] that adds `amount`
+    /// to the allowance spent by `spender`.
+    ///
+    /// The cumulative amount saturates at [`U256::MAX`], so that tracking
+    /// never prevents a transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `spender` - Account spending the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens spent.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientAllowance`] - If `spender` does not have enough
+    ///   allowance to spend `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`AllowanceSpent`].
+    fn _spend_allowance(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._spend_allowance(owner, spender, id, amount)?;
+
+        let mut spenders = self.spent_allowances.setter(owner);
+        let mut ids = spenders.setter(spender);
+        let mut spent = ids.setter(id);
+        let total = spent.get().saturating_add(amount);
+        spent.set(total);
+
+        evm::log(AllowanceSpent { owner, spender, id, amount });
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909AllowanceTracking {
+    type Error = erc6909::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909AllowanceTracking {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+    const ALLOWANCE: U256 = uint!(60_U256);
+
+    fn mint_and_approve(
+        contract: &Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            .approve(bob, ID, ALLOWANCE)
+            .motsu_expect("should approve Bob");
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_sender_is_zero_address(
+        contract: Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(bob)
+            .transfer_from(Address::ZERO, alice, ID, U256::ZERO)
+            .motsu_expect_err("should not transfer from the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSender(ERC6909InvalidSender { sender })
+                if sender.is_zero()
+        ));
+        assert!(contract
+            .sender(alice)
+            .spent_allowance(Address::ZERO, bob, ID)
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn transfer_from_tracks_spent_allowance(
+        contract: Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_approve(&contract, alice, bob);
+        let spent = uint!(20_U256);
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, spent)
+            .motsu_expect("should spend allowance");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, spent)
+            .motsu_expect("should spend allowance again");
+
+        assert_eq!(
+            spent * uint!(2_U256),
+            contract.sender(alice).spent_allowance(alice, bob, ID)
+        );
+        assert_eq!(
+            ALLOWANCE - spent * uint!(2_U256),
+            contract.sender(alice).allowance(alice, bob, ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .spent_allowance(bob, alice, ID)
+            .is_zero());
+
+        contract.assert_emitted(&AllowanceSpent {
+            owner: alice,
+            spender: bob,
+            id: ID,
+            amount: spent,
+        });
+    }
+
+    #[motsu::test]
+    fn spent_allowance_accumulates_across_approvals(
+        contract: Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_approve(&contract, alice, bob);
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, ALLOWANCE)
+            .motsu_expect("should spend the whole allowance");
+        contract
+            .sender(alice)
+            .approve(bob, ID, ALLOWANCE)
+            .motsu_expect("should approve Bob again");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, U256::ONE)
+            .motsu_expect("should spend the new allowance");
+
+        assert_eq!(
+            ALLOWANCE + U256::ONE,
+            contract.sender(alice).spent_allowance(alice, bob, ID)
+        );
+    }
+
+    #[motsu::test]
+    fn failed_transfer_from_does_not_track(
+        contract: Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_approve(&contract, alice, bob);
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, ALLOWANCE + U256::ONE)
+            .motsu_expect_err("should not exceed allowance");
+
+        assert!(matches!(err, Error::InsufficientAllowance(_)));
+        assert!(contract
+            .sender(alice)
+            .spent_allowance(alice, bob, ID)
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn operator_and_owner_transfers_are_not_tracked(
+        contract: Contract<Erc6909AllowanceTracking>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_approve(&contract, alice, bob);
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set Bob as operator");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, ALLOWANCE)
+            .motsu_expect("should transfer as operator");
+        contract
+            .sender(alice)
+            .transfer_from(alice, bob, ID, ALLOWANCE)
+            .motsu_expect("should transfer own tokens");
+
+        assert!(contract
+            .sender(alice)
+            .spent_allowance(alice, bob, ID)
+            .is_zero());
+        assert!(contract
+            .sender(alice)
+            .spent_allowance(alice, alice, ID)
+            .is_zero());
+        assert_eq!(ALLOWANCE, contract.sender(alice).allowance(alice, bob, ID));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909AllowanceTracking as IErc6909AllowanceTracking>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xc3ea32d7");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,779 @@
+//! Extension of ERC-6909 that caps the balance an account can hold in a
+//! specific token id.
+//!
+//! Each token id can be given a maximum balance per account, which receivers
+//! can't exceed through transfers or mints, e.g. to prevent a single wallet
+//! from accumulating a large share of the supply. Accounts such as
+//! treasuries, pools or bridges can be exempted from the cap.
+//!
+//! Note that [`Erc6909BalanceCap::_set_max_balance`] and
+//! [`Erc6909BalanceCap::_set_balance_cap_exemption`] are not exposed by
+//! default. You should expose them manually in your contract's abi, guarded
+//! by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self,
+        extensions::supply::{self, ERC6909InsufficientSupply},
+        impl_as_erc6909, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the maximum balance per account of token `id` changes
+        /// to `max_balance`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `max_balance` - Maximum balance per account, zero if uncapped.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MaxBalanceSet(uint256 indexed id, uint256 max_balance);
+
+        /// Emitted when `account` is exempted from, or made subject to, the
+        /// balance caps.
+        ///
+        /// * `account` - Account whose exemption changes.
+        /// * `exempt` - Whether `account` is exempt from the balance caps.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event BalanceCapExemptionSet(address indexed account, bool exempt);
+    }
+
+    sol! {
+        /// Indicates that `receiver` would hold `balance` tokens of type
+        /// `id`, more than the maximum balance per account `max_balance`.
+        ///
+        /// * `receiver` - Account receiving the tokens.
+        /// * `id` - Token id as a number.
+        /// * `balance` - Balance of `receiver` after the transfer.
+        /// * `max_balance` - Maximum balance per account.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ReceiverBalanceCapExceeded(
+            address receiver,
+            uint256 id,
+            uint256 balance,
+            uint256 max_balance
+        );
+    }
+}
+
+/// An [`Erc6909BalanceCap`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a receiver would exceed the maximum balance of a token
+    /// id.
+    ReceiverBalanceCapExceeded(ERC6909ReceiverBalanceCapExceeded),
+    /// Indicates that more tokens of an id would be burnt than its recorded
+    /// total supply, when wrapping an [`Erc6909Supply`].
+    ///
+    /// [`Erc6909Supply`]: super::Erc6909Supply
+    InsufficientSupply(ERC6909InsufficientSupply),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<supply::Error> for Error {
+    fn from(value: supply::Error) -> Self {
+        match value {
+            supply::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            supply::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            supply::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            supply::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            supply::Error::InvalidSender(e) => Error::InvalidSender(e),
+            supply::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            supply::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            supply::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            supply::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            supply::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            supply::Error::SelfTarget(e) => Error::SelfTarget(e),
+            supply::Error::InsufficientSupply(e) => {
+                Error::InsufficientSupply(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909BalanceCap`] contract.
+#[storage]
+pub struct Erc6909BalanceCap<T: IErc6909Internal = Erc6909> {
+    /// Wrapped ERC-6909 token, a plain [`Erc6909`] by default.
+    pub erc6909: T,
+    /// Mapping from token id to its maximum balance per account, zero if
+    /// uncapped.
+    pub(crate) max_balances: StorageMap<U256, StorageU256>,
+    /// Mapping from account to whether it is exempt from the balance caps.
+    pub(crate) balance_cap_exemptions: StorageMap<Address, StorageBool>,
+}
+
+impl_as_erc6909!(Erc6909BalanceCap<T: IErc6909Internal>, erc6909);
+
+/// Required interface of an [`Erc6909BalanceCap`] contract.
+#[interface_id]
+pub trait IErc6909BalanceCap: IErc165 {
+    /// Returns the maximum balance per account of token `id`, zero if
+    /// uncapped.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn max_balance(&self, id: U256) -> U256;
+
+    /// Returns whether `account` is exempt from the balance caps.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    fn is_balance_cap_exempt(&self, account: Address) -> bool;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909BalanceCap, IErc165)]
+impl<T: IErc6909Internal> Erc6909BalanceCap<T> where Error: From<T::Error> {}
+
+#[public]
+impl<T: IErc6909Internal> IErc6909BalanceCap for Erc6909BalanceCap<T> {
+    fn max_balance(&self, id: U256) -> U256 {
+        self.max_balances.get(id)
+    }
+
+    fn is_balance_cap_exempt(&self, account: Address) -> bool {
+        self.balance_cap_exemptions.get(account)
+    }
+}
+
+#[public]
+impl<T: IErc6909Internal> IErc6909 for Erc6909BalanceCap<T>
+where
+    Error: From<T::Error>,
+{
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909
+            .as_mut()
+            ._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.as_mut().approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.as_mut().set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.as_ref().balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.as_ref().allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.as_ref().is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl<T: IErc6909Internal> IErc165 for Erc6909BalanceCap<T> {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909BalanceCap>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl<T: IErc6909Internal> Erc6909BalanceCap<T>
+where
+    Error: From<T::Error>,
+{
+    /// Sets the maximum balance per account of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Lowering the cap doesn't affect existing balances, but accounts above
+    /// it can't receive tokens of type `id` anymore. Setting it to zero
+    /// removes the cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `max_balance` - Maximum balance per account, zero if uncapped.
+    ///
+    /// # Events
+    ///
+    /// * [`MaxBalanceSet`].
+    pub fn _set_max_balance(&mut self, id: U256, max_balance: U256) {
+        self.max_balances.setter(id).set(max_balance);
+        evm::log(MaxBalanceSet { id, max_balance });
+    }
+
+    /// Exempts `account` from the balance caps, or makes it subject to them
+    /// again.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account whose exemption changes.
+    /// * `exempt` - Whether `account` is exempt from the balance caps.
+    ///
+    /// # Events
+    ///
+    /// * [`BalanceCapExemptionSet`].
+    pub fn _set_balance_cap_exemption(
+        &mut self,
+        account: Address,
+        exempt: bool,
+    ) {
+        self.balance_cap_exemptions.setter(account).set(exempt);
+        evm::log(BalanceCapExemptionSet { account, exempt });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl<T: IErc6909Internal> Erc6909BalanceCap<T>
+where
+    Error: From<T::Error>,
+{
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that prevents `to` from
+    /// holding more than the maximum balance of any of `ids`, including
+    /// through mints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ReceiverBalanceCapExceeded`] - If `to` isn't exempt and its
+    ///   balance of one of `ids` exceeds the maximum balance of that id.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.erc6909._update(from, to, ids.clone(), amounts)?;
+
+        if to.is_zero() || self.is_balance_cap_exempt(to) {
+            return Ok(());
+        }
+
+        for id in ids {
+            let max_balance = self.max_balance(id);
+            let balance = self.erc6909.as_ref().balance_of(to, id);
+            if !max_balance.is_zero() && balance > max_balance {
+                return Err(Error::ReceiverBalanceCapExceeded(
+                    ERC6909ReceiverBalanceCapExceeded {
+                        receiver: to,
+                        id,
+                        balance,
+                        max_balance,
+                    },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: IErc6909Internal> IErc6909Internal for Erc6909BalanceCap<T>
+where
+    Error: From<T::Error>,
+{
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909.as_mut()._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909.as_mut()._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::extensions::{Erc6909Supply, IErc6909Supply};
+
+    unsafe impl TopLevelStorage for Erc6909BalanceCap {}
+    unsafe impl TopLevelStorage for Erc6909BalanceCap<Erc6909Supply> {}
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const MAX_BALANCE: U256 = uint!(100_U256);
+
+    fn mint_and_cap(contract: &Contract<Erc6909BalanceCap>, alice: Address) {
+        contract.sender(alice)._set_balance_cap_exemption(alice, true);
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![ID, OTHER_ID],
+                vec![MAX_BALANCE * uint!(2_U256), MAX_BALANCE * uint!(2_U256)],
+            )
+            .motsu_expect("should mint tokens");
+        contract.sender(alice)._set_max_balance(ID, MAX_BALANCE);
+    }
+
+    #[motsu::test]
+    fn setters_emit_events(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_max_balance(ID, MAX_BALANCE);
+
+        assert_eq!(MAX_BALANCE, contract.sender(alice).max_balance(ID));
+        assert!(contract.sender(alice).max_balance(OTHER_ID).is_zero());
+        contract.assert_emitted(&MaxBalanceSet {
+            id: ID,
+            max_balance: MAX_BALANCE,
+        });
+
+        contract.sender(alice)._set_balance_cap_exemption(alice, true);
+
+        assert!(contract.sender(alice).is_balance_cap_exempt(alice));
+        contract.assert_emitted(&BalanceCapExemptionSet {
+            account: alice,
+            exempt: true,
+        });
+    }
+
+    #[motsu::test]
+    fn transfer_up_to_max_balance_succeeds(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_cap(&contract, alice);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, MAX_BALANCE)
+            .motsu_expect("should transfer up to the cap");
+
+        assert_eq!(MAX_BALANCE, contract.sender(alice).balance_of(bob, ID));
+    }
+
+    #[motsu::test]
+    fn transfer_reverts_when_receiver_exceeds_max_balance(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_cap(&contract, alice);
+        contract
+            .sender(alice)
+            .transfer(bob, ID, MAX_BALANCE)
+            .motsu_expect("should transfer up to the cap");
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, ID, U256::ONE)
+            .motsu_expect_err("should not exceed the cap");
+
+        assert!(matches!(
+            err,
+            Error::ReceiverBalanceCapExceeded(
+                ERC6909ReceiverBalanceCapExceeded {
+                    receiver,
+                    id,
+                    balance,
+                    max_balance,
+                }
+            ) if receiver == bob
+                && id == ID
+                && balance == MAX_BALANCE + U256::ONE
+                && max_balance == MAX_BALANCE
+        ));
+        assert_eq!(MAX_BALANCE, contract.sender(alice).balance_of(bob, ID));
+
+        contract
+            .sender(alice)
+            .transfer(bob, OTHER_ID, MAX_BALANCE * uint!(2_U256))
+            .motsu_expect("should transfer uncapped tokens");
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_receiver_exceeds_max_balance(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_max_balance(ID, MAX_BALANCE);
+
+        let err = contract
+            .sender(alice)
+            ._mint(bob, ID, MAX_BALANCE + U256::ONE)
+            .motsu_expect_err("should not mint above the cap");
+
+        assert!(matches!(err, Error::ReceiverBalanceCapExceeded(_)));
+    }
+
+    #[motsu::test]
+    fn exempt_receiver_can_exceed_max_balance(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_cap(&contract, alice);
+        contract.sender(alice)._set_balance_cap_exemption(bob, true);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, MAX_BALANCE * uint!(2_U256))
+            .motsu_expect("should transfer to an exempt account");
+
+        assert_eq!(
+            MAX_BALANCE * uint!(2_U256),
+            contract.sender(alice).balance_of(bob, ID)
+        );
+    }
+
+    #[motsu::test]
+    fn sender_above_max_balance_can_still_send(
+        contract: Contract<Erc6909BalanceCap>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_cap(&contract, alice);
+        contract.sender(alice)._set_balance_cap_exemption(alice, false);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, MAX_BALANCE)
+            .motsu_expect("should transfer from an account above the cap");
+    }
+
+    #[motsu::test]
+    fn caps_balances_on_top_of_supply(
+        contract: Contract<Erc6909BalanceCap<Erc6909Supply>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_max_balance(ID, MAX_BALANCE);
+        contract
+            .sender(alice)
+            ._mint(bob, ID, MAX_BALANCE)
+            .motsu_expect("should mint up to the cap");
+
+        assert_eq!(
+            MAX_BALANCE,
+            contract.sender(alice).erc6909.total_supply(ID)
+        );
+
+        let err = contract
+            .sender(alice)
+            ._mint(bob, ID, U256::ONE)
+            .motsu_expect_err("should not mint above the cap");
+
+        assert!(matches!(err, Error::ReceiverBalanceCapExceeded(_)));
+        assert_eq!(
+            MAX_BALANCE,
+            contract.sender(alice).erc6909.total_supply(ID)
+        );
+
+        contract
+            .sender(alice)
+            ._burn(bob, ID, MAX_BALANCE)
+            .motsu_expect("should burn tokens");
+
+        assert!(contract.sender(alice).erc6909.total_supply(ID).is_zero());
+
+        let supply_interface_id =
+            <Erc6909Supply as IErc6909Supply>::interface_id();
+        assert!(contract.sender(alice).supports_interface(supply_interface_id));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909BalanceCap as IErc6909BalanceCap>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x712b9cc4");
+        assert_eq!(actual, expected);
+    }
+}
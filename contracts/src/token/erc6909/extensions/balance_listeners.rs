@@ -0,0 +1,900 @@
+//! Extension of ERC-6909 that notifies external contracts of the balance
+//! changes of a token id.
+//!
+//! Up to [`MAX_BALANCE_LISTENERS`] listener contracts can be registered for
+//! each token id. After tokens of that id are minted, transferred or burned,
+//! each listener's [`IErc6909BalanceListener::on_balance_change`] function is
+//! called, so that e.g. staking or reward controllers can track balances
+//! without polling.
+//!
+//! Notifications are best-effort: each listener is called with at most
+//! [`BALANCE_LISTENER_GAS_LIMIT`] gas, and a listener that reverts or runs
+//! out of gas doesn't revert the balance change, but emits a
+//! [`BalanceListenerFailed`] event instead. A broken listener can therefore
+//! never block the movements of a token id.
+//!
+//! Note that [`Erc6909BalanceListeners::_add_balance_listener`] and
+//! [`Erc6909BalanceListeners::_remove_balance_listener`] are not exposed by
+//! default. You should expose them manually in your contract's abi, guarded
+//! by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+pub use listener::IErc6909BalanceListener;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageVec},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// Maximum number of listeners registered for a single token id.
+pub const MAX_BALANCE_LISTENERS: usize = 8;
+
+/// Maximum amount of gas forwarded to a listener for each notification.
+pub const BALANCE_LISTENER_GAS_LIMIT: u64 = 50_000;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `listener` is registered as a balance listener of
+        /// token `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `listener` - Address of the listener.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event BalanceListenerAdded(uint256 indexed id, address indexed listener);
+
+        /// Emitted when `listener` is no longer a balance listener of token
+        /// `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `listener` - Address of the listener.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event BalanceListenerRemoved(
+            uint256 indexed id,
+            address indexed listener
+        );
+
+        /// Emitted when `listener` couldn't be notified of a balance change
+        /// of token `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `listener` - Address of the listener.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event BalanceListenerFailed(
+            uint256 indexed id,
+            address indexed listener
+        );
+    }
+
+    sol! {
+        /// Indicates that `listener` can't be registered for token `id`,
+        /// because it is the zero address or is already registered.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `listener` - Address of the listener.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidBalanceListener(uint256 id, address listener);
+
+        /// Indicates that `listener` is not a balance listener of token `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `listener` - Address of the listener.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentBalanceListener(uint256 id, address listener);
+
+        /// Indicates that token `id` already has the maximum number of
+        /// balance listeners.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `max` - Maximum number of listeners per token id.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TooManyBalanceListeners(uint256 id, uint256 max);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(missing_docs)]
+mod listener {
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the contracts notified of the balance changes of
+        /// ERC-6909 tokens by an [`super::Erc6909BalanceListeners`]
+        /// contract.
+        interface IErc6909BalanceListener {
+            /// Notifies that `amount` tokens of type `id` were moved from
+            /// `from` to `to`.
+            ///
+            /// NOTE: This is called after the balances are updated, with a
+            /// limited amount of gas, and reverting doesn't revert the
+            /// movement.
+            ///
+            /// # Arguments
+            ///
+            /// * `from` - The address tokens were moved from, or the zero
+            ///   address for mints.
+            /// * `to` - The address tokens were moved to, or the zero address
+            ///   for burns.
+            /// * `id` - Token id as a number.
+            /// * `amount` - The amount of tokens moved.
+            #[allow(missing_docs)]
+            function onBalanceChange(
+                address from,
+                address to,
+                uint256 id,
+                uint256 amount
+            ) external;
+        }
+    }
+}
+
+/// An [`Erc6909BalanceListeners`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a balance listener can't be registered.
+    InvalidBalanceListener(ERC6909InvalidBalanceListener),
+    /// Indicates that a balance listener is not registered.
+    NonexistentBalanceListener(ERC6909NonexistentBalanceListener),
+    /// Indicates that a token id has too many balance listeners.
+    TooManyBalanceListeners(ERC6909TooManyBalanceListeners),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909BalanceListeners`] contract.
+#[storage]
+pub struct Erc6909BalanceListeners {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to its balance listeners.
+    pub(crate) balance_listeners: StorageMap<U256, StorageVec<StorageAddress>>,
+}
+
+impl_as_erc6909!(Erc6909BalanceListeners, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909BalanceListeners {}
+
+/// Required interface of an [`Erc6909BalanceListeners`] contract.
+#[interface_id]
+pub trait IErc6909BalanceListeners: IErc165 {
+    /// Returns the balance listeners of token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn balance_listeners(&self, id: U256) -> Vec<Address>;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909BalanceListeners, IErc165)]
+impl Erc6909BalanceListeners {}
+
+#[public]
+impl IErc6909BalanceListeners for Erc6909BalanceListeners {
+    fn balance_listeners(&self, id: U256) -> Vec<Address> {
+        let listeners = self.balance_listeners.get(id);
+        (0..listeners.len()).filter_map(|index| listeners.get(index)).collect()
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909BalanceListeners {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909BalanceListeners {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909BalanceListeners>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909BalanceListeners {
+    /// Registers `listener` as a balance listener of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `listener` - Address of the listener.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidBalanceListener`] - If `listener` is
+    ///   [`Address::ZERO`], or is already registered for `id`.
+    /// * [`Error::TooManyBalanceListeners`] - If `id` already has
+    ///   [`MAX_BALANCE_LISTENERS`] listeners.
+    ///
+    /// # Events
+    ///
+    /// * [`BalanceListenerAdded`].
+    pub fn _add_balance_listener(
+        &mut self,
+        id: U256,
+        listener: Address,
+    ) -> Result<(), Error> {
+        if listener.is_zero() || self._listener_index(id, listener).is_some() {
+            return Err(Error::InvalidBalanceListener(
+                ERC6909InvalidBalanceListener { id, listener },
+            ));
+        }
+        if self.balance_listeners.get(id).len() >= MAX_BALANCE_LISTENERS {
+            return Err(Error::TooManyBalanceListeners(
+                ERC6909TooManyBalanceListeners {
+                    id,
+                    max: U256::from(MAX_BALANCE_LISTENERS),
+                },
+            ));
+        }
+
+        self.balance_listeners.setter(id).push(listener);
+        evm::log(BalanceListenerAdded { id, listener });
+
+        Ok(())
+    }
+
+    /// Removes `listener` from the balance listeners of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `listener` - Address of the listener.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentBalanceListener`] - If `listener` is not
+    ///   registered for `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`BalanceListenerRemoved`].
+    pub fn _remove_balance_listener(
+        &mut self,
+        id: U256,
+        listener: Address,
+    ) -> Result<(), Error> {
+        let Some(index) = self._listener_index(id, listener) else {
+            return Err(Error::NonexistentBalanceListener(
+                ERC6909NonexistentBalanceListener { id, listener },
+            ));
+        };
+
+        // Swap the removed listener with the last one, then pop the last one.
+        let mut listeners = self.balance_listeners.setter(id);
+        if let Some(last) = listeners.pop() {
+            if let Some(mut slot) = listeners.setter(index) {
+                slot.set(last);
+            }
+        }
+        evm::log(BalanceListenerRemoved { id, listener });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909BalanceListeners {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that notifies the balance
+    /// listeners of each of `ids` once balances are updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    /// * [`BalanceListenerFailed`] - For each listener that couldn't be
+    ///   notified.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.erc6909._update(from, to, ids.clone(), amounts.clone())?;
+
+        for (id, amount) in ids.into_iter().zip(amounts) {
+            self._notify_balance_listeners(from, to, id, amount);
+        }
+
+        Ok(())
+    }
+
+    /// Calls [`IErc6909BalanceListener::on_balance_change`] on each balance
+    /// listener of token `id`, with at most [`BALANCE_LISTENER_GAS_LIMIT`]
+    /// gas. Failures are reported with a [`BalanceListenerFailed`] event.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens moved.
+    fn _notify_balance_listeners(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) {
+        for listener in self.balance_listeners(id) {
+            let result = IErc6909BalanceListener::new(listener)
+                .on_balance_change(
+                    Call::new_in(self).gas(BALANCE_LISTENER_GAS_LIMIT),
+                    from,
+                    to,
+                    id,
+                    amount,
+                );
+
+            if result.is_err() {
+                evm::log(BalanceListenerFailed { id, listener });
+            }
+        }
+    }
+
+    /// Returns the position of `listener` among the balance listeners of
+    /// token `id`, if registered.
+    fn _listener_index(&self, id: U256, listener: Address) -> Option<usize> {
+        let listeners = self.balance_listeners.get(id);
+        (0..listeners.len())
+            .find(|&index| listeners.get(index) == Some(listener))
+    }
+}
+
+impl IErc6909Internal for Erc6909BalanceListeners {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::storage::StorageU256;
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[storage]
+    struct MockBalanceListener {
+        from: StorageAddress,
+        to: StorageAddress,
+        id: StorageU256,
+        amount: StorageU256,
+    }
+
+    #[public]
+    impl MockBalanceListener {
+        fn on_balance_change(
+            &mut self,
+            from: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+        ) {
+            self.from.set(from);
+            self.to.set(to);
+            self.id.set(id);
+            self.amount.set(amount);
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockBalanceListener {}
+
+    #[storage]
+    struct RevertingBalanceListener;
+
+    #[public]
+    impl RevertingBalanceListener {
+        fn on_balance_change(
+            &mut self,
+            _from: Address,
+            _to: Address,
+            _id: U256,
+            _amount: U256,
+        ) -> Result<(), Vec<u8>> {
+            Err("listener failed".into())
+        }
+    }
+
+    unsafe impl TopLevelStorage for RevertingBalanceListener {}
+
+    #[motsu::test]
+    fn add_and_remove_balance_listeners(
+        contract: Contract<Erc6909BalanceListeners>,
+        alice: Address,
+        first: Address,
+        second: Address,
+        third: Address,
+    ) {
+        for listener in [first, second, third] {
+            contract
+                .sender(alice)
+                ._add_balance_listener(ID, listener)
+                .motsu_expect("should add listener");
+        }
+        contract
+            .assert_emitted(&BalanceListenerAdded { id: ID, listener: first });
+
+        contract
+            .sender(alice)
+            ._remove_balance_listener(ID, first)
+            .motsu_expect("should remove listener");
+
+        assert_eq!(
+            vec![third, second],
+            contract.sender(alice).balance_listeners(ID)
+        );
+        contract.assert_emitted(&BalanceListenerRemoved {
+            id: ID,
+            listener: first,
+        });
+
+        contract
+            .sender(alice)
+            ._remove_balance_listener(ID, second)
+            .motsu_expect("should remove last listener");
+
+        assert_eq!(vec![third], contract.sender(alice).balance_listeners(ID));
+    }
+
+    #[motsu::test]
+    fn add_balance_listener_reverts_when_invalid(
+        contract: Contract<Erc6909BalanceListeners>,
+        alice: Address,
+        listener: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._add_balance_listener(ID, Address::ZERO)
+            .motsu_expect_err("should not add the zero address");
+        assert!(matches!(err, Error::InvalidBalanceListener(_)));
+
+        contract
+            .sender(alice)
+            ._add_balance_listener(ID, listener)
+            .motsu_expect("should add listener");
+        let err = contract
+            .sender(alice)
+            ._add_balance_listener(ID, listener)
+            .motsu_expect_err("should not add a listener twice");
+
+        assert!(matches!(
+            err,
+            Error::InvalidBalanceListener(ERC6909InvalidBalanceListener {
+                id,
+                listener: l,
+            }) if id == ID && l == listener
+        ));
+    }
+
+    #[motsu::test]
+    fn add_balance_listener_reverts_when_full(
+        contract: Contract<Erc6909BalanceListeners>,
+        alice: Address,
+    ) {
+        for i in (1..=u8::MAX).take(MAX_BALANCE_LISTENERS) {
+            contract
+                .sender(alice)
+                ._add_balance_listener(ID, Address::with_last_byte(i))
+                .motsu_expect("should add listener");
+        }
+
+        let err = contract
+            .sender(alice)
+            ._add_balance_listener(ID, Address::with_last_byte(0xff))
+            .motsu_expect_err("should not exceed the maximum");
+
+        assert!(matches!(
+            err,
+            Error::TooManyBalanceListeners(ERC6909TooManyBalanceListeners {
+                id,
+                max,
+            }) if id == ID && max == U256::from(MAX_BALANCE_LISTENERS)
+        ));
+    }
+
+    #[motsu::test]
+    fn remove_balance_listener_reverts_when_nonexistent(
+        contract: Contract<Erc6909BalanceListeners>,
+        alice: Address,
+        listener: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._remove_balance_listener(ID, listener)
+            .motsu_expect_err("should not remove unknown listener");
+
+        assert!(matches!(
+            err,
+            Error::NonexistentBalanceListener(
+                ERC6909NonexistentBalanceListener { id, listener: l }
+            ) if id == ID && l == listener
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_notifies_balance_listeners(
+        contract: Contract<Erc6909BalanceListeners>,
+        listener: Contract<MockBalanceListener>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_balance_listener(ID, listener.address())
+            .motsu_expect("should add listener");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        assert_eq!(Address::ZERO, listener.sender(alice).from.get());
+        assert_eq!(alice, listener.sender(alice).to.get());
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer tokens");
+
+        assert_eq!(alice, listener.sender(alice).from.get());
+        assert_eq!(bob, listener.sender(alice).to.get());
+        assert_eq!(ID, listener.sender(alice).id.get());
+        assert_eq!(AMOUNT, listener.sender(alice).amount.get());
+    }
+
+    #[motsu::test]
+    fn failing_listener_does_not_block_transfers(
+        contract: Contract<Erc6909BalanceListeners>,
+        broken: Contract<RevertingBalanceListener>,
+        listener: Contract<MockBalanceListener>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_balance_listener(ID, broken.address())
+            .motsu_expect("should add broken listener");
+        contract
+            .sender(alice)
+            ._add_balance_listener(ID, listener.address())
+            .motsu_expect("should add listener");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint despite broken listener");
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer despite broken listener");
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(bob, ID));
+        assert_eq!(bob, listener.sender(alice).to.get());
+        contract.assert_emitted(&BalanceListenerFailed {
+            id: ID,
+            listener: broken.address(),
+        });
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909BalanceListeners as IErc6909BalanceListeners>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0x15b01d2e");
+        assert_eq!(actual, expected);
+    }
+}
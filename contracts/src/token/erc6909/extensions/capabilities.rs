@@ -0,0 +1,170 @@
+//! Feature detection for ERC-6909 contracts, beyond ERC-165 interface ids.
+//!
+//! [`IErc6909Capabilities::capabilities`] returns a bitmask of the optional
+//! features a contract supports, so that routers can feature-detect a token
+//! with a single call, without probing selectors.
+//!
+//! Each extension providing one of these features declares its bits through
+//! [`Erc6909Capability`]. A contract composing several extensions reports
+//! the union of their capabilities, which keeps the bitmask in sync with the
+//! extensions actually present:
+//!
+//! ```rust,ignore
+//! fn capabilities(&self) -> U256 {
+//!     Erc6909Supply::CAPABILITIES | Erc6909Metadata::CAPABILITIES
+//! }
+//! ```
+
+use alloy_primitives::{uint, U256};
+use openzeppelin_stylus_proc::interface_id;
+
+use crate::{
+    token::erc6909::{
+        extensions::{
+            Erc6909ContentUri, Erc6909Enumerable, Erc6909Metadata,
+            Erc6909Supply,
+        },
+        Erc6909,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// Tokens can be approved with an off-chain signature.
+pub const PERMIT: U256 = uint!(0x01_U256);
+/// Tokens of several ids can be moved in a single call.
+pub const BATCH: U256 = uint!(0x02_U256);
+/// Contracts receiving tokens are notified through a callback.
+pub const RECEIVER_CALLBACKS: U256 = uint!(0x04_U256);
+/// Token ids have a name, a symbol and decimals.
+pub const METADATA: U256 = uint!(0x08_U256);
+/// The contract and its token ids have a URI.
+pub const CONTENT_URI: U256 = uint!(0x10_U256);
+/// The total supply of each token id is tracked.
+pub const TOKEN_SUPPLY: U256 = uint!(0x20_U256);
+/// The token ids held by each account can be enumerated.
+pub const ENUMERABLE: U256 = uint!(0x40_U256);
+
+/// Optional features provided by an ERC-6909 implementation of this crate.
+pub trait Erc6909Capability {
+    /// Bitmask of the features provided by this implementation.
+    const CAPABILITIES: U256;
+}
+
+/// Interface for the feature detection of ERC-6909 contracts.
+#[interface_id]
+pub trait IErc6909Capabilities: IErc165 {
+    /// Returns the bitmask of the optional features supported by the
+    /// contract, e.g. [`PERMIT`], [`BATCH`] or [`RECEIVER_CALLBACKS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn capabilities(&self) -> U256;
+}
+
+impl Erc6909Capability for Erc6909 {
+    const CAPABILITIES: U256 = U256::ZERO;
+}
+
+impl Erc6909Capability for Erc6909Metadata {
+    const CAPABILITIES: U256 = METADATA;
+}
+
+impl Erc6909Capability for Erc6909ContentUri {
+    const CAPABILITIES: U256 = CONTENT_URI;
+}
+
+impl Erc6909Capability for Erc6909Supply {
+    const CAPABILITIES: U256 = TOKEN_SUPPLY;
+}
+
+impl Erc6909Capability for Erc6909Enumerable {
+    const CAPABILITIES: U256 = ENUMERABLE;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, Address, FixedBytes};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
+
+    use super::*;
+
+    #[storage]
+    struct Erc6909CapabilitiesExample {
+        supply: Erc6909Supply,
+        metadata: Erc6909Metadata,
+    }
+
+    #[public]
+    #[implements(IErc6909Capabilities, IErc165)]
+    impl Erc6909CapabilitiesExample {}
+
+    #[public]
+    impl IErc6909Capabilities for Erc6909CapabilitiesExample {
+        fn capabilities(&self) -> U256 {
+            Erc6909Supply::CAPABILITIES | Erc6909Metadata::CAPABILITIES
+        }
+    }
+
+    #[public]
+    impl IErc165 for Erc6909CapabilitiesExample {
+        fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+            <Self as IErc6909Capabilities>::interface_id() == interface_id
+                || self.supply.supports_interface(interface_id)
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909CapabilitiesExample {}
+
+    #[test]
+    fn capabilities_are_distinct_bits() {
+        let all = [
+            PERMIT,
+            BATCH,
+            RECEIVER_CALLBACKS,
+            METADATA,
+            CONTENT_URI,
+            TOKEN_SUPPLY,
+            ENUMERABLE,
+        ];
+
+        for (i, capability) in all.iter().enumerate() {
+            assert_eq!(1, capability.count_ones());
+            assert!(all[i + 1..].iter().all(|other| other != capability));
+        }
+    }
+
+    #[motsu::test]
+    fn capabilities_reflect_extensions(
+        contract: Contract<Erc6909CapabilitiesExample>,
+        alice: Address,
+    ) {
+        let capabilities = contract.sender(alice).capabilities();
+
+        assert_eq!(TOKEN_SUPPLY | METADATA, capabilities);
+        assert!(!(capabilities & METADATA).is_zero());
+        assert!((capabilities & PERMIT).is_zero());
+        assert!((capabilities & ENUMERABLE).is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909CapabilitiesExample as IErc6909Capabilities>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0x34a18fc3");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909CapabilitiesExample>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909CapabilitiesExample as IErc6909Capabilities>::interface_id(
+            )
+        ));
+    }
+}
@@ -1,15 +1,109 @@
 //! Extension of ERC-6909 that adds content uri request support.
+//!
+//! The contract URI is also exposed as `contractURI()`, and its updates are
+//! announced with [`ContractURIUpdated`], following [ERC-7572], so that
+//! marketplaces pick up collection-level metadata changes.
+//!
+//! [ERC-7572]: https://eips.ethereum.org/EIPS/eip-7572
+//!
+//! Drops can hide the URI of their token ids until they are revealed: once a
+//! placeholder URI is set with [`Erc6909ContentUri::_set_placeholder_uri`],
+//! [`IErc6909ContentUri::token_uri`] returns it for every token id that
+//! wasn't revealed with [`Erc6909ContentUri::_reveal`] yet.
+//!
+//! The URI of a token id can be frozen with
+//! [`Erc6909ContentUri::_freeze_metadata`], e.g. once the token is revealed,
+//! after which it can't be changed anymore. Frozen token ids are always
+//! revealed.
+//!
+//! [`IErc6909ContentUri::token_uri`] returns an empty string for unknown
+//! token ids. Contracts expecting it to revert with
+//! [`ERC6909NonexistentToken`] instead expose
+//! [`Erc6909ContentUri::checked_token_uri`].
+//!
+//! Note that [`Erc6909ContentUri::_set_contract_uri`],
+//! [`Erc6909ContentUri::_set_token_uri`],
+//! [`Erc6909ContentUri::_set_placeholder_uri`],
+//! [`Erc6909ContentUri::_reveal`], [`Erc6909ContentUri::_clear_token_uri`]
+//! and [`Erc6909ContentUri::_freeze_metadata`] are not exposed by default. You
+//! should expose them manually in your contract's abi, guarded by proper
+//! access control.
 
 use alloc::{string::String, vec, vec::Vec};
 
-use alloy_primitives::U256;
-use openzeppelin_stylus_proc::interface_id;
+use alloy_primitives::{FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
 use stylus_sdk::{
+    call::MethodError,
+    evm,
     prelude::*,
-    storage::{StorageMap, StorageString},
+    storage::{StorageBool, StorageMap, StorageString},
 };
 
-use crate::token::erc6909::Erc6909;
+use crate::{
+    token::erc6909::{
+        extensions::{
+            metadata::{
+                self, ERC6909FrozenMetadata, ERC6909NonexistentToken,
+                IErc6909MetadataFreeze, MetadataFrozen,
+            },
+            IErc6909Exists,
+        },
+        impl_as_erc6909, Erc6909,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the URI of token `id` changes to `value`.
+        ///
+        /// * `value` - New URI of the token.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event URI(string value, uint256 indexed id);
+
+        /// Emitted when the URI of the contract changes.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ContractURIUpdated();
+
+        /// Emitted when the placeholder URI of unrevealed token ids changes
+        /// to `value`.
+        ///
+        /// * `value` - New placeholder URI.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event PlaceholderUriUpdated(string value);
+
+        /// Emitted when token `ids` are revealed.
+        ///
+        /// * `ids` - Array of revealed token ids.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Revealed(uint256[] ids);
+    }
+}
+
+/// An [`Erc6909ContentUri`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that the URI of a token id is frozen.
+    FrozenMetadata(ERC6909FrozenMetadata),
+    /// Indicates that a token id doesn't exist.
+    NonexistentToken(ERC6909NonexistentToken),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
 
 /// State of an [`Erc6909ContentUri`] contract.
 #[storage]
@@ -17,12 +111,30 @@ pub struct Erc6909ContentUri {
     /// [`Erc6909`] contract.
     pub erc6909: Erc6909,
     /// URI of the contract.
-    pub(crate) _uri: StorageString,
+    pub(crate) uri: StorageString,
     /// Mapping from token id to token uri.
-    pub(crate) _token_uris: StorageMap<U256, StorageString>,
+    pub(crate) token_uris: StorageMap<U256, StorageString>,
+    /// Mapping from token id to whether its URI is frozen.
+    pub(crate) frozen: StorageMap<U256, StorageBool>,
+    /// URI of the token ids that are not revealed yet.
+    pub(crate) placeholder_uri: StorageString,
+    /// Mapping from token id to whether it is revealed.
+    pub(crate) revealed: StorageMap<U256, StorageBool>,
 }
 
-/// Interface for the optional ContentUri functions from the ERC-6909 standard.
+impl_as_erc6909!(Erc6909ContentUri, erc6909);
+
+#[public]
+#[implements(
+    IErc6909ContentUri,
+    IErc7572,
+    IErc6909Reveal,
+    IErc6909MetadataFreeze,
+    IErc165
+)]
+impl Erc6909ContentUri {}
+
+/// Interface for the optional content URI functions from the ERC-6909 standard.
 #[interface_id]
 pub trait IErc6909ContentUri {
     /// Returns the URI for the contract.
@@ -41,13 +153,593 @@ pub trait IErc6909ContentUri {
     fn token_uri(&self, id: U256) -> String;
 }
 
+/// Interface for contract-level metadata, as defined in [ERC-7572].
+///
+/// [ERC-7572]: https://eips.ethereum.org/EIPS/eip-7572
+#[interface_id]
+pub trait IErc7572 {
+    /// Returns the URI of the contract-level metadata JSON, under the
+    /// `contractURI()` selector expected by marketplaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "contractURI")]
+    fn contract_metadata_uri(&self) -> String;
+}
+
+/// Interface for contracts hiding the URI of their token ids until they are
+/// revealed.
+#[interface_id]
+pub trait IErc6909Reveal {
+    /// Returns the URI of the token ids that are not revealed yet, or an
+    /// empty string if token URIs are not hidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn placeholder_uri(&self) -> String;
+
+    /// Returns whether token `id` is revealed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn is_revealed(&self, id: U256) -> bool;
+}
+
 #[public]
 impl IErc6909ContentUri for Erc6909ContentUri {
     fn contract_uri(&self) -> String {
-        todo!()
+        self.uri.get_string()
+    }
+
+    fn token_uri(&self, id: U256) -> String {
+        if self.placeholder_uri.is_empty() || self.is_revealed(id) {
+            self.token_uris.get(id).get_string()
+        } else {
+            self.placeholder_uri.get_string()
+        }
+    }
+}
+
+#[public]
+impl IErc7572 for Erc6909ContentUri {
+    #[selector(name = "contractURI")]
+    fn contract_metadata_uri(&self) -> String {
+        self.uri.get_string()
+    }
+}
+
+#[public]
+impl IErc6909Reveal for Erc6909ContentUri {
+    fn placeholder_uri(&self) -> String {
+        self.placeholder_uri.get_string()
+    }
+
+    fn is_revealed(&self, id: U256) -> bool {
+        self.revealed.get(id) || self.frozen.get(id)
     }
+}
+
+#[public]
+impl IErc6909MetadataFreeze for Erc6909ContentUri {
+    fn metadata_frozen(&self, id: U256) -> bool {
+        self.frozen.get(id)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909ContentUri {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909ContentUri>::interface_id() == interface_id
+            || <Self as IErc7572>::interface_id() == interface_id
+            || <Self as IErc6909Reveal>::interface_id() == interface_id
+            || <Self as IErc6909MetadataFreeze>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909ContentUri {
+    /// Sets `uri` as the URI of the contract.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `uri` - New URI of the contract.
+    ///
+    /// # Events
+    ///
+    /// * [`ContractURIUpdated`].
+    pub fn _set_contract_uri(&mut self, uri: String) {
+        self.uri.set_str(uri);
+        evm::log(ContractURIUpdated {});
+    }
+
+    /// Sets `uri` as the URI of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `uri` - New URI of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the URI of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`URI`].
+    pub fn _set_token_uri(
+        &mut self,
+        id: U256,
+        uri: String,
+    ) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.token_uris.setter(id).set_str(&uri);
+        evm::log(URI { value: uri, id });
+        Ok(())
+    }
+
+    /// Clears the URI of token `id` and whether it is revealed, e.g. once all
+    /// its tokens are burned and the id is retired, so that their storage is
+    /// refunded.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the URI of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`URI`] with an empty URI.
+    pub fn _clear_token_uri(&mut self, id: U256) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.token_uris.delete(id);
+        self.revealed.delete(id);
+        evm::log(URI { value: String::new(), id });
+        Ok(())
+    }
+
+    /// Sets `uri` as the URI returned for the token ids that are not revealed
+    /// yet. An empty `uri` reveals every token id.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `uri` - New placeholder URI.
+    ///
+    /// # Events
+    ///
+    /// * [`PlaceholderUriUpdated`].
+    pub fn _set_placeholder_uri(&mut self, uri: String) {
+        self.placeholder_uri.set_str(&uri);
+        evm::log(PlaceholderUriUpdated { value: uri });
+    }
+
+    /// Reveals token `ids`, so that their own URI is returned instead of the
+    /// placeholder URI.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `ids` - Array of token ids to reveal.
+    ///
+    /// # Events
+    ///
+    /// * [`Revealed`].
+    pub fn _reveal(&mut self, ids: Vec<U256>) {
+        for &id in &ids {
+            self.revealed.setter(id).set(true);
+        }
+        evm::log(Revealed { ids });
+    }
+
+    /// Freezes the URI of token `id`, which can't be changed anymore
+    /// afterwards.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the URI of token `id` is already
+    ///   frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`MetadataFrozen`].
+    pub fn _freeze_metadata(&mut self, id: U256) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.frozen.setter(id).set(true);
+        evm::log(MetadataFrozen { id });
+        Ok(())
+    }
+
+    /// Returns the URI of token `id`, reverting if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `tokens` - Contract tracking the existence of token ids.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentToken`] - If token `id` doesn't exist.
+    pub fn checked_token_uri(
+        &self,
+        id: U256,
+        tokens: &impl IErc6909Exists,
+    ) -> Result<String, Error> {
+        metadata::_check_exists(id, tokens)?;
+        Ok(self.token_uri(id))
+    }
+
+    /// Checks that the URI of token `id` is not frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the URI of token `id` is frozen.
+    fn _check_not_frozen(&self, id: U256) -> Result<(), Error> {
+        if self.metadata_frozen(id) {
+            return Err(Error::FrozenMetadata(ERC6909FrozenMetadata { id }));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Erc6909ContentUri {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        let mut events = Erc6909::events();
+        events.extend([
+            event::<URI>("event URI(string value, uint256 indexed id)"),
+            event::<ContractURIUpdated>("event ContractURIUpdated()"),
+            event::<PlaceholderUriUpdated>(
+                "event PlaceholderUriUpdated(string value)",
+            ),
+            event::<Revealed>("event Revealed(uint256[] ids)"),
+            event::<MetadataFrozen>("event MetadataFrozen(uint256 indexed id)"),
+        ]);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::extensions::Erc6909Supply;
+
+    unsafe impl TopLevelStorage for Erc6909ContentUri {}
+
+    const ID: U256 = uint!(1_U256);
+    const CONTRACT_URI: &str = "https://example.com/contract.json";
+    const TOKEN_URI: &str = "https://example.com/1.json";
+
+    #[storage]
+    struct Erc6909ContentUriSupplyExample {
+        content_uri: Erc6909ContentUri,
+        supply: Erc6909Supply,
+    }
+
+    unsafe impl TopLevelStorage for Erc6909ContentUriSupplyExample {}
+
+    #[motsu::test]
+    fn checked_token_uri_reverts_for_nonexistent_token(
+        contract: Contract<Erc6909ContentUriSupplyExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .content_uri
+                ._set_token_uri(ID, TOKEN_URI.into())
+                .expect("should set token uri");
+        });
+
+        let example = contract.sender(alice);
+        let err = example
+            .content_uri
+            .checked_token_uri(ID, &example.supply)
+            .motsu_expect_err("should not read uri of nonexistent token");
+        assert!(matches!(
+            err,
+            Error::NonexistentToken(ERC6909NonexistentToken { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn checked_token_uri_returns_uri_of_minted_token(
+        contract: Contract<Erc6909ContentUriSupplyExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .content_uri
+                ._set_token_uri(ID, TOKEN_URI.into())
+                .expect("should set token uri");
+            contract.supply._mint(alice, ID, U256::ONE).expect("should mint");
+        });
+
+        let example = contract.sender(alice);
+        assert_eq!(
+            TOKEN_URI,
+            example
+                .content_uri
+                .checked_token_uri(ID, &example.supply)
+                .motsu_expect("should read token uri")
+        );
+    }
+
+    #[motsu::test]
+    fn uris_are_empty_by_default(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).contract_uri().is_empty());
+        assert!(contract.sender(alice).token_uri(ID).is_empty());
+    }
+
+    #[motsu::test]
+    fn set_contract_uri(contract: Contract<Erc6909ContentUri>, alice: Address) {
+        contract.sender(alice)._set_contract_uri(CONTRACT_URI.into());
+
+        assert_eq!(CONTRACT_URI, contract.sender(alice).contract_uri());
+        assert_eq!(
+            CONTRACT_URI,
+            contract.sender(alice).contract_metadata_uri()
+        );
+        contract.assert_emitted(&ContractURIUpdated {});
+    }
+
+    #[motsu::test]
+    fn set_token_uri(contract: Contract<Erc6909ContentUri>, alice: Address) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+        assert!(contract.sender(alice).token_uri(ID + U256::ONE).is_empty());
+        contract.assert_emitted(&URI { value: TOKEN_URI.into(), id: ID });
+    }
+
+    #[motsu::test]
+    fn set_token_uri_reverts_when_frozen(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze token uri");
+
+        assert!(contract.sender(alice).metadata_frozen(ID));
+        contract.assert_emitted(&MetadataFrozen { id: ID });
+
+        let err = contract
+            .sender(alice)
+            ._set_token_uri(ID, "https://example.com/2.json".into())
+            .motsu_expect_err("should not set uri of frozen token");
+        assert!(matches!(
+            err,
+            Error::FrozenMetadata(ERC6909FrozenMetadata { id }) if id == ID
+        ));
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+
+        let err = contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect_err("should not freeze token uri twice");
+        assert!(matches!(err, Error::FrozenMetadata(_)));
+
+        contract
+            .sender(alice)
+            ._set_token_uri(ID + U256::ONE, TOKEN_URI.into())
+            .motsu_expect("should set uri of another token");
+        contract.sender(alice)._set_contract_uri(CONTRACT_URI.into());
+        assert_eq!(CONTRACT_URI, contract.sender(alice).contract_uri());
+    }
+
+    const PLACEHOLDER_URI: &str = "https://example.com/hidden.json";
+
+    #[motsu::test]
+    fn clear_token_uri(contract: Contract<Erc6909ContentUri>, alice: Address) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract.sender(alice)._set_placeholder_uri(PLACEHOLDER_URI.into());
+        contract.sender(alice)._reveal(vec![ID]);
+
+        contract
+            .sender(alice)
+            ._clear_token_uri(ID)
+            .motsu_expect("should clear token uri");
+
+        assert!(!contract.sender(alice).is_revealed(ID));
+        assert_eq!(PLACEHOLDER_URI, contract.sender(alice).token_uri(ID));
+        contract.assert_emitted(&URI { value: String::new(), id: ID });
+    }
+
+    #[motsu::test]
+    fn clear_token_uri_reverts_when_frozen(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze token uri");
+
+        let err = contract
+            .sender(alice)
+            ._clear_token_uri(ID)
+            .motsu_expect_err("should not clear uri of frozen token");
+
+        assert!(matches!(
+            err,
+            Error::FrozenMetadata(ERC6909FrozenMetadata { id }) if id == ID
+        ));
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+    }
+
+    #[motsu::test]
+    fn token_uri_returns_placeholder_until_revealed(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        let other_id = ID + U256::ONE;
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract.sender(alice)._set_placeholder_uri(PLACEHOLDER_URI.into());
+
+        assert_eq!(PLACEHOLDER_URI, contract.sender(alice).placeholder_uri());
+        assert_eq!(PLACEHOLDER_URI, contract.sender(alice).token_uri(ID));
+        assert!(!contract.sender(alice).is_revealed(ID));
+        contract.assert_emitted(&PlaceholderUriUpdated {
+            value: PLACEHOLDER_URI.into(),
+        });
+
+        contract.sender(alice)._reveal(vec![ID]);
+
+        assert!(contract.sender(alice).is_revealed(ID));
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+        assert_eq!(PLACEHOLDER_URI, contract.sender(alice).token_uri(other_id));
+        contract.assert_emitted(&Revealed { ids: vec![ID] });
+    }
+
+    #[motsu::test]
+    fn reveal_batch(contract: Contract<Erc6909ContentUri>, alice: Address) {
+        let ids = vec![ID, ID + U256::ONE, ID + uint!(2_U256)];
+        contract.sender(alice)._set_placeholder_uri(PLACEHOLDER_URI.into());
+
+        contract.sender(alice)._reveal(ids.clone());
+
+        for &id in &ids {
+            assert!(contract.sender(alice).is_revealed(id));
+            assert!(contract.sender(alice).token_uri(id).is_empty());
+        }
+        assert!(!contract.sender(alice).is_revealed(ID + uint!(3_U256)));
+        contract.assert_emitted(&Revealed { ids });
+    }
+
+    #[motsu::test]
+    fn empty_placeholder_reveals_every_token(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract.sender(alice)._set_placeholder_uri(PLACEHOLDER_URI.into());
+        contract.sender(alice)._set_placeholder_uri(String::new());
+
+        assert!(!contract.sender(alice).is_revealed(ID));
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+    }
+
+    #[motsu::test]
+    fn frozen_token_is_revealed(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_token_uri(ID, TOKEN_URI.into())
+            .motsu_expect("should set token uri");
+        contract.sender(alice)._set_placeholder_uri(PLACEHOLDER_URI.into());
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze token uri");
+
+        assert!(contract.sender(alice).is_revealed(ID));
+        assert_eq!(TOKEN_URI, contract.sender(alice).token_uri(ID));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909ContentUri as IErc6909ContentUri>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xd697b90b");
+        assert_eq!(actual, expected);
+
+        let actual = <Erc6909ContentUri as IErc7572>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xe8a3d485");
+        assert_eq!(actual, expected);
+
+        let actual = <Erc6909ContentUri as IErc6909Reveal>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xac336856");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909ContentUri>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ContentUri as IErc6909ContentUri>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ContentUri as IErc6909MetadataFreeze>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ContentUri as IErc6909Reveal>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ContentUri as IErc7572>::interface_id()
+        ));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909ContentUri as IErc165>::interface_id()
+            )
+        );
 
-    fn token_uri(&self, _id: U256) -> String {
-        todo!()
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
     }
 }
@@ -0,0 +1,835 @@
+//! Extension of ERC-6909 that distributes dividends to the holders of a token
+//! id, pro-rata to their balances.
+//!
+//! Dividends are paid in a single ERC-20 reward token. Instead of iterating
+//! over holders or taking balance snapshots, the extension relies on the
+//! "magnified dividend per share" technique: every distribution increases a
+//! per-id accumulator, while every balance change records a correction for
+//! the accounts involved. This way each holder can pull exactly its share at
+//! any point in time with [`IErc6909Dividends::withdraw_dividend`].
+//!
+//! Note that [`Erc6909Dividends::_distribute_dividends`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{uint, Address, FixedBytes, I256, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    contract, evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageI256, StorageMap, StorageU256},
+};
+
+use crate::{
+    token::{
+        erc20::utils::{safe_erc20, ISafeErc20, SafeErc20},
+        erc6909::{
+            self,
+            extensions::{supply, Erc6909Supply, IErc6909Supply},
+            impl_as_erc6909, IErc6909, IErc6909Internal,
+        },
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        math::{
+            alloy::{Math, Rounding},
+            storage::AddAssignChecked,
+        },
+    },
+};
+
+/// Factor used to keep precision when dividing the distributed amount by the
+/// total supply of an id.
+pub const MAGNITUDE: U256 = uint!(340282366920938463463374607431768211456_U256);
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `amount` of reward tokens are distributed by `from`
+        /// to the holders of token `id`.
+        ///
+        /// * `from` - Address of the account funding the distribution.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of reward tokens distributed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DividendsDistributed(
+            address indexed from,
+            uint256 indexed id,
+            uint256 amount
+        );
+
+        /// Emitted when `to` withdraws `amount` of reward tokens earned by
+        /// holding token `id`.
+        ///
+        /// * `to` - Address of the account withdrawing its dividends.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of reward tokens withdrawn.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DividendWithdrawn(
+            address indexed to,
+            uint256 indexed id,
+            uint256 amount
+        );
+    }
+
+    sol! {
+        /// Indicates that dividends can't be distributed for token `id`,
+        /// because it has no supply.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909DividendsNoSupply(uint256 id);
+
+        /// Indicates that `token` can't be used to pay dividends.
+        ///
+        /// * `token` - Address of the invalid reward token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909DividendsInvalidRewardToken(address token);
+    }
+}
+
+/// An [`Erc6909Dividends`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that dividends can't be distributed for a token id without
+    /// supply.
+    NoSupply(ERC6909DividendsNoSupply),
+    /// Indicates that the reward token is not valid.
+    InvalidRewardToken(ERC6909DividendsInvalidRewardToken),
+    /// An operation with the reward token failed.
+    SafeErc20FailedOperation(safe_erc20::SafeErc20FailedOperation),
+    /// Indicates a failed [`ISafeErc20::safe_decrease_allowance`] request.
+    SafeErc20FailedDecreaseAllowance(
+        safe_erc20::SafeErc20FailedDecreaseAllowance,
+    ),
+}
+
+impl From<safe_erc20::Error> for Error {
+    fn from(value: safe_erc20::Error) -> Self {
+        match value {
+            safe_erc20::Error::SafeErc20FailedOperation(e) => {
+                Error::SafeErc20FailedOperation(e)
+            }
+            safe_erc20::Error::SafeErc20FailedDecreaseAllowance(e) => {
+                Error::SafeErc20FailedDecreaseAllowance(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Dividends`] contract.
+#[storage]
+pub struct Erc6909Dividends {
+    /// [`Erc6909Supply`] contract.
+    pub erc6909_supply: Erc6909Supply,
+    /// Address of the ERC-20 token dividends are paid in.
+    pub(crate) reward_token: StorageAddress,
+    /// Mapping from token id to the magnified amount of dividends per token.
+    pub(crate) magnified_dividend_per_share: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the magnified dividend correction of each
+    /// account.
+    pub(crate) magnified_dividend_corrections:
+        StorageMap<U256, StorageMap<Address, StorageI256>>,
+    /// Mapping from token id to the amount of dividends withdrawn by each
+    /// account.
+    pub(crate) withdrawn_dividends:
+        StorageMap<U256, StorageMap<Address, StorageU256>>,
+    /// [`SafeErc20`] contract.
+    safe_erc20: SafeErc20,
+}
+
+impl_as_erc6909!(Erc6909Dividends, erc6909_supply.erc6909);
+
+/// Required interface of an [`Erc6909Dividends`] contract.
+#[interface_id]
+pub trait IErc6909Dividends: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the address of the ERC-20 token dividends are paid in.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn reward_token(&self) -> Address;
+
+    /// Returns the total amount of dividends `owner` has earned by holding
+    /// token `id`, including the already withdrawn ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `owner` - Address of the token holder.
+    ///
+    /// # Panics
+    ///
+    /// * If the magnified dividends of `owner` exceed [`I256::MAX`].
+    fn accumulative_dividend_of(&self, id: U256, owner: Address) -> U256;
+
+    /// Returns the amount of dividends `owner` can currently withdraw for
+    /// token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `owner` - Address of the token holder.
+    fn withdrawable_dividend_of(&self, id: U256, owner: Address) -> U256;
+
+    /// Returns the amount of dividends `owner` has already withdrawn for
+    /// token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `owner` - Address of the token holder.
+    fn withdrawn_dividend_of(&self, id: U256, owner: Address) -> U256;
+
+    /// Withdraws the dividends the caller has earned by holding token `id`
+    /// and returns the withdrawn amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SafeErc20FailedOperation`] - If the transfer of the reward
+    ///   token fails.
+    ///
+    /// # Events
+    ///
+    /// * [`DividendWithdrawn`] - If the withdrawn amount is not zero.
+    fn withdraw_dividend(&mut self, id: U256) -> Result<U256, Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = supply::Error>,
+    IErc6909Supply,
+    IErc6909Dividends<Error = Error>,
+    IErc165
+)]
+impl Erc6909Dividends {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `reward_token` - Address of the ERC-20 token dividends are paid in.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidRewardToken`] - If `reward_token` is [`Address::ZERO`]
+    ///   or this contract.
+    #[constructor]
+    pub fn constructor(&mut self, reward_token: Address) -> Result<(), Error> {
+        if reward_token.is_zero() || reward_token == contract::address() {
+            return Err(Error::InvalidRewardToken(
+                ERC6909DividendsInvalidRewardToken { token: reward_token },
+            ));
+        }
+        self.reward_token.set(reward_token);
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909Dividends for Erc6909Dividends {
+    type Error = Error;
+
+    fn reward_token(&self) -> Address {
+        self.reward_token.get()
+    }
+
+    fn accumulative_dividend_of(&self, id: U256, owner: Address) -> U256 {
+        let magnified = self
+            .magnified_dividend_per_share
+            .get(id)
+            .checked_mul(self.erc6909_supply.balance_of(owner, id))
+            .and_then(|magnified| I256::try_from(magnified).ok())
+            .expect("should not exceed `I256::MAX` for magnified dividends");
+        let corrected =
+            magnified + self.magnified_dividend_corrections.get(id).get(owner);
+        corrected.into_raw() / MAGNITUDE
+    }
+
+    fn withdrawable_dividend_of(&self, id: U256, owner: Address) -> U256 {
+        self.accumulative_dividend_of(id, owner)
+            - self.withdrawn_dividend_of(id, owner)
+    }
+
+    fn withdrawn_dividend_of(&self, id: U256, owner: Address) -> U256 {
+        self.withdrawn_dividends.get(id).get(owner)
+    }
+
+    fn withdraw_dividend(&mut self, id: U256) -> Result<U256, Self::Error> {
+        let to = msg::sender();
+        let amount = self.withdrawable_dividend_of(id, to);
+        if amount.is_zero() {
+            return Ok(U256::ZERO);
+        }
+
+        self.withdrawn_dividends.setter(id).setter(to).add_assign_checked(
+            amount,
+            "should not exceed `U256::MAX` for `withdrawn_dividends`",
+        );
+        self.safe_erc20.safe_transfer(self.reward_token(), to, amount)?;
+
+        evm::log(DividendWithdrawn { to, id, amount });
+
+        Ok(amount)
+    }
+}
+
+#[public]
+impl IErc6909Supply for Erc6909Dividends {
+    fn total_supply(&self, id: U256) -> U256 {
+        self.erc6909_supply.total_supply(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Dividends {
+    type Error = supply::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+        let erc6909 = &mut self.erc6909_supply.erc6909;
+
+        erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909_supply.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909_supply.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909_supply.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909_supply.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909_supply.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Dividends {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Dividends>::interface_id() == interface_id
+            || self.erc6909_supply.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Dividends {
+    /// Distributes `amount` of reward tokens to the holders of token `id`,
+    /// pro-rata to their balances. The reward tokens are pulled from the
+    /// caller, who must have approved this contract beforehand.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of reward tokens to distribute.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NoSupply`] - If the total supply of `id` is zero.
+    /// * [`Error::SafeErc20FailedOperation`] - If the caller lacks sufficient
+    ///   balance or hasn't approved enough reward tokens to this contract.
+    ///
+    /// # Events
+    ///
+    /// * [`DividendsDistributed`] - If `amount` is not zero.
+    ///
+    /// # Panics
+    ///
+    /// * If the magnified dividend per share of `id` exceeds [`U256::MAX`].
+    pub fn _distribute_dividends(
+        &mut self,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let supply = self.erc6909_supply.total_supply(id);
+        if supply.is_zero() {
+            return Err(Error::NoSupply(ERC6909DividendsNoSupply { id }));
+        }
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let from = msg::sender();
+        self.safe_erc20.safe_transfer_from(
+            self.reward_token(),
+            from,
+            contract::address(),
+            amount,
+        )?;
+
+        self.magnified_dividend_per_share.setter(id).add_assign_checked(
+            amount.mul_div(MAGNITUDE, supply, Rounding::Floor),
+            "should not exceed `U256::MAX` for `magnified_dividend_per_share`",
+        );
+
+        evm::log(DividendsDistributed { from, id, amount });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909Supply::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), supply::Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909Supply::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), supply::Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909Supply::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), supply::Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909Supply::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), supply::Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909Dividends {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), supply::Error> {
+        if to.is_zero() {
+            return Err(supply::Error::InvalidReceiver(
+                erc6909::ERC6909InvalidReceiver { receiver: to },
+            ));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), supply::Error> {
+        if from.is_zero() {
+            return Err(supply::Error::InvalidSender(
+                erc6909::ERC6909InvalidSender { sender: from },
+            ));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), supply::Error> {
+        erc6909::Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909Supply::_update`] that keeps the
+    /// dividends earned before a balance change attributed to its previous
+    /// holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`supply::Error::InvalidArrayLength`] - If length of `ids` is not
+    ///   equal to length of `amounts`.
+    /// * [`supply::Error::InsufficientBalance`] - If `amount` is greater than
+    ///   the balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance and/or supply exceeds [`U256::MAX`].
+    /// * If a magnified dividend correction exceeds [`I256`] bounds.
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), supply::Error> {
+        self.erc6909_supply._update(from, to, ids.clone(), amounts.clone())?;
+
+        for (token_id, amount) in ids.into_iter().zip(amounts.into_iter()) {
+            let correction = self
+                .magnified_dividend_per_share
+                .get(token_id)
+                .checked_mul(amount)
+                .and_then(|magnified| I256::try_from(magnified).ok())
+                .expect("should not exceed `I256::MAX` for correction");
+
+            let mut corrections =
+                self.magnified_dividend_corrections.setter(token_id);
+
+            if !from.is_zero() {
+                let updated = corrections
+                    .get(from)
+                    .checked_add(correction)
+                    .expect("should not exceed `I256::MAX` for correction");
+                corrections.setter(from).set(updated);
+            }
+
+            if !to.is_zero() {
+                let updated = corrections
+                    .get(to)
+                    .checked_sub(correction)
+                    .expect("should not exceed `I256::MIN` for correction");
+                corrections.setter(to).set(updated);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909Dividends {
+    type Error = supply::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909_supply.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self
+            .erc6909_supply
+            .erc6909
+            ._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc20::{Erc20, IErc20};
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    unsafe impl TopLevelStorage for Erc6909Dividends {}
+
+    fn fund_and_distribute(
+        contract: &Contract<Erc6909Dividends>,
+        reward_token: &Contract<Erc20>,
+        funder: Address,
+        amount: U256,
+    ) {
+        reward_token.sender(funder)._mint(funder, amount).motsu_unwrap();
+        reward_token
+            .sender(funder)
+            .approve(contract.address(), amount)
+            .motsu_unwrap();
+        contract
+            .sender(funder)
+            ._distribute_dividends(TOKEN_ID, amount)
+            .motsu_expect("should distribute dividends");
+    }
+
+    #[motsu::test]
+    fn distribute_reverts_when_no_supply(
+        contract: Contract<Erc6909Dividends>,
+        reward_token: Contract<Erc20>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.reward_token.set(reward_token.address());
+        });
+
+        let err = contract
+            .sender(alice)
+            ._distribute_dividends(TOKEN_ID, uint!(100_U256))
+            .motsu_expect_err("should not distribute without supply");
+
+        assert!(matches!(
+            err,
+            Error::NoSupply(ERC6909DividendsNoSupply { id }) if id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn dividends_are_split_pro_rata(
+        contract: Contract<Erc6909Dividends>,
+        reward_token: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.reward_token.set(reward_token.address());
+        });
+        contract.sender(alice)._mint(bob, TOKEN_ID, uint!(100_U256)).unwrap();
+        contract
+            .sender(alice)
+            ._mint(charlie, TOKEN_ID, uint!(300_U256))
+            .unwrap();
+
+        fund_and_distribute(&contract, &reward_token, alice, uint!(400_U256));
+
+        assert_eq!(
+            contract.sender(bob).withdrawable_dividend_of(TOKEN_ID, bob),
+            uint!(100_U256)
+        );
+        assert_eq!(
+            contract
+                .sender(charlie)
+                .withdrawable_dividend_of(TOKEN_ID, charlie),
+            uint!(300_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_keeps_earned_dividends(
+        contract: Contract<Erc6909Dividends>,
+        reward_token: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.reward_token.set(reward_token.address());
+        });
+        contract.sender(alice)._mint(bob, TOKEN_ID, uint!(100_U256)).unwrap();
+        contract
+            .sender(alice)
+            ._mint(charlie, TOKEN_ID, uint!(100_U256))
+            .unwrap();
+
+        fund_and_distribute(&contract, &reward_token, alice, uint!(200_U256));
+
+        contract
+            .sender(bob)
+            .transfer(charlie, TOKEN_ID, uint!(100_U256))
+            .motsu_expect("should transfer tokens to Charlie");
+
+        assert_eq!(
+            contract.sender(bob).withdrawable_dividend_of(TOKEN_ID, bob),
+            uint!(100_U256)
+        );
+        assert_eq!(
+            contract
+                .sender(charlie)
+                .withdrawable_dividend_of(TOKEN_ID, charlie),
+            uint!(100_U256)
+        );
+
+        fund_and_distribute(&contract, &reward_token, alice, uint!(200_U256));
+
+        assert_eq!(
+            contract.sender(bob).withdrawable_dividend_of(TOKEN_ID, bob),
+            uint!(100_U256)
+        );
+        assert_eq!(
+            contract
+                .sender(charlie)
+                .withdrawable_dividend_of(TOKEN_ID, charlie),
+            uint!(300_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn withdraw_dividend_pays_reward_token(
+        contract: Contract<Erc6909Dividends>,
+        reward_token: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.reward_token.set(reward_token.address());
+        });
+        contract.sender(alice)._mint(bob, TOKEN_ID, uint!(10_U256)).unwrap();
+
+        fund_and_distribute(&contract, &reward_token, alice, uint!(50_U256));
+
+        let withdrawn = contract
+            .sender(bob)
+            .withdraw_dividend(TOKEN_ID)
+            .motsu_expect("should withdraw dividends");
+
+        assert_eq!(withdrawn, uint!(50_U256));
+        assert_eq!(reward_token.sender(bob).balance_of(bob), uint!(50_U256));
+        assert_eq!(
+            contract.sender(bob).withdrawable_dividend_of(TOKEN_ID, bob),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(bob).withdrawn_dividend_of(TOKEN_ID, bob),
+            uint!(50_U256)
+        );
+        contract.assert_emitted(&DividendWithdrawn {
+            to: bob,
+            id: TOKEN_ID,
+            amount: uint!(50_U256),
+        });
+
+        let withdrawn = contract
+            .sender(bob)
+            .withdraw_dividend(TOKEN_ID)
+            .motsu_expect("should be a no-op");
+        assert_eq!(withdrawn, U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Dividends as IErc6909Dividends>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x5eefe143");
+        assert_eq!(actual, expected);
+    }
+}
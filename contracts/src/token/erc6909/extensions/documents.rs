@@ -0,0 +1,544 @@
+//! Extension of ERC-6909 that attaches named documents to token ids, in the
+//! style of ERC-1643.
+//!
+//! Each document of a token id is identified by a `bytes32` name, and
+//! references a URI, the hash of its content and the timestamp of its last
+//! update, so that e.g. bond or security issuances can reference their
+//! prospectus on-chain.
+//!
+//! Note that [`Erc6909Documents::_set_document`] and
+//! [`Erc6909Documents::_remove_document`] are not exposed by default. You
+//! should expose them manually in your contract's abi, guarded by proper
+//! access control.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{
+        StorageFixedBytes, StorageMap, StorageString, StorageU256, StorageVec,
+    },
+};
+
+use crate::{
+    impl_sol_struct,
+    token::erc6909::{impl_as_erc6909, Erc6909},
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// A document attached to a token id.
+        ///
+        /// * `uri` - URI of the document, empty if it doesn't exist.
+        /// * `documentHash` - Hash of the content of the document.
+        /// * `lastModified` - Timestamp of the last update of the document.
+        #[derive(Debug, Default, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        struct Document {
+            string uri;
+            bytes32 documentHash;
+            uint256 lastModified;
+        }
+    }
+
+    sol! {
+        /// Emitted when the document `name` of token `id` is attached or
+        /// updated.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `name` - Name of the document.
+        /// * `uri` - URI of the document.
+        /// * `document_hash` - Hash of the content of the document.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DocumentUpdated(
+            uint256 indexed id,
+            bytes32 indexed name,
+            string uri,
+            bytes32 document_hash
+        );
+
+        /// Emitted when the document `name` of token `id` is removed.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `name` - Name of the document.
+        /// * `uri` - URI of the removed document.
+        /// * `document_hash` - Hash of the content of the removed document.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DocumentRemoved(
+            uint256 indexed id,
+            bytes32 indexed name,
+            string uri,
+            bytes32 document_hash
+        );
+    }
+
+    sol! {
+        /// Indicates that the document `name` of token `id` can't be attached,
+        /// because its name is zero or its URI is empty.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `name` - Name of the document.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidDocument(uint256 id, bytes32 name);
+
+        /// Indicates that token `id` has no document named `name`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `name` - Name of the document.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentDocument(uint256 id, bytes32 name);
+    }
+}
+
+impl_sol_struct!(Document, (String, FixedBytes<32>, U256));
+
+/// An [`Erc6909Documents`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that a document has a zero name or an empty URI.
+    InvalidDocument(ERC6909InvalidDocument),
+    /// Indicates that a document doesn't exist.
+    NonexistentDocument(ERC6909NonexistentDocument),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Storage of a document attached to a token id.
+#[storage]
+pub struct StoredDocument {
+    /// URI of the document.
+    uri: StorageString,
+    /// Hash of the content of the document.
+    document_hash: StorageFixedBytes<32>,
+    /// Timestamp of the last update of the document.
+    timestamp: StorageU256,
+}
+
+/// State of an [`Erc6909Documents`] contract.
+#[storage]
+pub struct Erc6909Documents {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to document name to document.
+    pub(crate) documents:
+        StorageMap<U256, StorageMap<FixedBytes<32>, StoredDocument>>,
+    /// Mapping from token id to the names of its documents.
+    pub(crate) document_names:
+        StorageMap<U256, StorageVec<StorageFixedBytes<32>>>,
+    /// Mapping from token id to document name to its position in
+    /// `document_names`, plus one. Zero if the document doesn't exist.
+    pub(crate) document_positions:
+        StorageMap<U256, StorageMap<FixedBytes<32>, StorageU256>>,
+}
+
+impl_as_erc6909!(Erc6909Documents, erc6909);
+
+#[public]
+#[implements(IErc6909Documents, IErc165)]
+impl Erc6909Documents {}
+
+/// Interface for documents attached to ERC-6909 token ids.
+#[interface_id]
+pub trait IErc6909Documents: IErc165 {
+    /// Returns the URI, content hash and timestamp of the last update of the
+    /// document `name` of token `id`, as a [`Document`] struct.
+    ///
+    /// Returns an empty URI if the document doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `name` - Name of the document.
+    fn get_document(&self, id: U256, name: FixedBytes<32>) -> Document;
+
+    /// Returns the names of all the documents of token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn get_all_documents(&self, id: U256) -> Vec<FixedBytes<32>>;
+}
+
+#[public]
+impl IErc6909Documents for Erc6909Documents {
+    fn get_document(&self, id: U256, name: FixedBytes<32>) -> Document {
+        let documents = self.documents.get(id);
+        let document = documents.get(name);
+        Document {
+            uri: document.uri.get_string(),
+            documentHash: document.document_hash.get(),
+            lastModified: document.timestamp.get(),
+        }
+    }
+
+    fn get_all_documents(&self, id: U256) -> Vec<FixedBytes<32>> {
+        let names = self.document_names.get(id);
+        (0..names.len()).filter_map(|index| names.get(index)).collect()
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Documents {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Documents>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909Documents {
+    /// Attaches the document `name` to token `id`, or updates it.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `name` - Name of the document.
+    /// * `uri` - URI of the document.
+    /// * `document_hash` - Hash of the content of the document.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidDocument`] - If `name` is zero or `uri` is empty.
+    ///
+    /// # Events
+    ///
+    /// * [`DocumentUpdated`].
+    pub fn _set_document(
+        &mut self,
+        id: U256,
+        name: FixedBytes<32>,
+        uri: String,
+        document_hash: FixedBytes<32>,
+    ) -> Result<(), Error> {
+        if name.is_zero() || uri.is_empty() {
+            return Err(Error::InvalidDocument(ERC6909InvalidDocument {
+                id,
+                name,
+            }));
+        }
+
+        if self.document_positions.get(id).get(name).is_zero() {
+            let mut names = self.document_names.setter(id);
+            names.push(name);
+            let position = U256::from(names.len());
+            self.document_positions.setter(id).setter(name).set(position);
+        }
+
+        let mut documents = self.documents.setter(id);
+        let mut document = documents.setter(name);
+        document.uri.set_str(&uri);
+        document.document_hash.set(document_hash);
+        document.timestamp.set(U256::from(block::timestamp()));
+
+        evm::log(DocumentUpdated { id, name, uri, document_hash });
+
+        Ok(())
+    }
+
+    /// Removes the document `name` from token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `name` - Name of the document.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentDocument`] - If token `id` has no document named
+    ///   `name`.
+    ///
+    /// # Panics
+    ///
+    /// * If the document names of token `id` are out of sync with their
+    ///   positions.
+    ///
+    /// # Events
+    ///
+    /// * [`DocumentRemoved`].
+    pub fn _remove_document(
+        &mut self,
+        id: U256,
+        name: FixedBytes<32>,
+    ) -> Result<(), Error> {
+        let position = self.document_positions.get(id).get(name);
+        if position.is_zero() {
+            return Err(Error::NonexistentDocument(
+                ERC6909NonexistentDocument { id, name },
+            ));
+        }
+
+        // Swap the removed name with the last one, then pop the last one.
+        let mut names = self.document_names.setter(id);
+        let index = position.to::<usize>() - 1;
+        let last_index = names.len() - 1;
+        if index != last_index {
+            let last_name =
+                names.get(last_index).expect("last document name should exist");
+            names
+                .setter(index)
+                .expect("document name should exist")
+                .set(last_name);
+            self.document_positions.setter(id).setter(last_name).set(position);
+        }
+        self.document_names.setter(id).pop();
+        self.document_positions.setter(id).delete(name);
+
+        let mut documents = self.documents.setter(id);
+        let mut document = documents.setter(name);
+        let uri = document.uri.get_string();
+        let document_hash = document.document_hash.get();
+        document.uri.erase();
+        document.document_hash.erase();
+        document.timestamp.erase();
+
+        evm::log(DocumentRemoved { id, name, uri, document_hash });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Documents {}
+
+    const ID: U256 = uint!(1_U256);
+    const PROSPECTUS: FixedBytes<32> = fixed_bytes!(
+        "0x70726f7370656374757300000000000000000000000000000000000000000000"
+    );
+    const TERM_SHEET: FixedBytes<32> = fixed_bytes!(
+        "0x7465726d2d736865657400000000000000000000000000000000000000000000"
+    );
+    const ANNUAL_REPORT: FixedBytes<32> = fixed_bytes!(
+        "0x616e6e75616c2d7265706f727400000000000000000000000000000000000000"
+    );
+    const URI: &str = "ipfs://prospectus";
+    const HASH: FixedBytes<32> = fixed_bytes!(
+        "0x1111111111111111111111111111111111111111111111111111111111111111"
+    );
+
+    #[motsu::test]
+    fn documents_are_empty_by_default(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        let document = contract.sender(alice).get_document(ID, PROSPECTUS);
+
+        assert_eq!(Document::default(), document);
+        assert!(contract.sender(alice).get_all_documents(ID).is_empty());
+    }
+
+    #[motsu::test]
+    fn set_document(contract: Contract<Erc6909Documents>, alice: Address) {
+        contract
+            .sender(alice)
+            ._set_document(ID, PROSPECTUS, URI.into(), HASH)
+            .motsu_expect("should set document");
+
+        let document = contract.sender(alice).get_document(ID, PROSPECTUS);
+
+        assert_eq!(
+            Document {
+                uri: URI.into(),
+                documentHash: HASH,
+                lastModified: U256::from(block::timestamp()),
+            },
+            document
+        );
+        assert_eq!(
+            vec![PROSPECTUS],
+            contract.sender(alice).get_all_documents(ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .get_all_documents(ID + U256::ONE)
+            .is_empty());
+
+        contract.assert_emitted(&DocumentUpdated {
+            id: ID,
+            name: PROSPECTUS,
+            uri: URI.into(),
+            document_hash: HASH,
+        });
+    }
+
+    #[motsu::test]
+    fn set_document_updates_existing_document(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        let new_uri = "ipfs://prospectus-v2";
+        let new_hash = fixed_bytes!(
+            "0x2222222222222222222222222222222222222222222222222222222222222222"
+        );
+
+        contract
+            .sender(alice)
+            ._set_document(ID, PROSPECTUS, URI.into(), HASH)
+            .motsu_expect("should set document");
+        contract
+            .sender(alice)
+            ._set_document(ID, PROSPECTUS, new_uri.into(), new_hash)
+            .motsu_expect("should update document");
+
+        let document = contract.sender(alice).get_document(ID, PROSPECTUS);
+
+        assert_eq!(new_uri, document.uri);
+        assert_eq!(new_hash, document.documentHash);
+        assert_eq!(
+            vec![PROSPECTUS],
+            contract.sender(alice).get_all_documents(ID)
+        );
+    }
+
+    #[motsu::test]
+    fn set_document_reverts_when_name_is_zero(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_document(ID, FixedBytes::ZERO, URI.into(), HASH)
+            .motsu_expect_err("should not set document with zero name");
+
+        assert!(matches!(
+            err,
+            Error::InvalidDocument(ERC6909InvalidDocument { id, name })
+                if id == ID && name.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn set_document_reverts_when_uri_is_empty(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_document(ID, PROSPECTUS, String::new(), HASH)
+            .motsu_expect_err("should not set document with empty uri");
+
+        assert!(matches!(
+            err,
+            Error::InvalidDocument(ERC6909InvalidDocument { id, name })
+                if id == ID && name == PROSPECTUS
+        ));
+    }
+
+    #[motsu::test]
+    fn remove_document(contract: Contract<Erc6909Documents>, alice: Address) {
+        for name in [PROSPECTUS, TERM_SHEET, ANNUAL_REPORT] {
+            contract
+                .sender(alice)
+                ._set_document(ID, name, URI.into(), HASH)
+                .motsu_expect("should set document");
+        }
+
+        contract
+            .sender(alice)
+            ._remove_document(ID, PROSPECTUS)
+            .motsu_expect("should remove document");
+
+        let document = contract.sender(alice).get_document(ID, PROSPECTUS);
+
+        assert_eq!(Document::default(), document);
+        assert_eq!(
+            vec![ANNUAL_REPORT, TERM_SHEET],
+            contract.sender(alice).get_all_documents(ID)
+        );
+
+        contract.assert_emitted(&DocumentRemoved {
+            id: ID,
+            name: PROSPECTUS,
+            uri: URI.into(),
+            document_hash: HASH,
+        });
+
+        // The moved document can still be removed.
+        contract
+            .sender(alice)
+            ._remove_document(ID, ANNUAL_REPORT)
+            .motsu_expect("should remove document");
+
+        assert_eq!(
+            vec![TERM_SHEET],
+            contract.sender(alice).get_all_documents(ID)
+        );
+    }
+
+    #[motsu::test]
+    fn remove_document_reverts_when_nonexistent(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_document(ID, PROSPECTUS, URI.into(), HASH)
+            .motsu_expect("should set document");
+
+        let err = contract
+            .sender(alice)
+            ._remove_document(ID + U256::ONE, PROSPECTUS)
+            .motsu_expect_err("should not remove nonexistent document");
+
+        assert!(matches!(
+            err,
+            Error::NonexistentDocument(ERC6909NonexistentDocument { id, name })
+                if id == ID + U256::ONE && name == PROSPECTUS
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Documents as IErc6909Documents>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x013a9978");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909Documents>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Documents as IErc6909Documents>::interface_id()
+        ));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Documents as IErc165>::interface_id()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,754 @@
+//! Extension of ERC-6909 that mints tokens on a schedule, at a fixed emission
+//! rate per token id.
+//!
+//! Each token id can be given an emission rate, in tokens per second, and a
+//! recipient, e.g. a treasury distributing rewards. Emissions accrue from the
+//! last drip of the token id, and anyone can call [`IErc6909Emissions::drip`]
+//! to mint them to the recipient, which makes the inflation of the token id
+//! predictable.
+//!
+//! Note that [`Erc6909Emissions::_set_emission`] is not exposed by default.
+//! You should expose it manually in your contract's abi, guarded by proper
+//! access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the emissions of token `id` are set to `rate` tokens
+        /// per second, minted to `recipient`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `recipient` - Account receiving the emissions.
+        /// * `rate` - Amount of tokens emitted per second.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event EmissionSet(
+            uint256 indexed id,
+            address indexed recipient,
+            uint256 rate
+        );
+
+        /// Emitted when `amount` tokens of type `id` accrued since the last
+        /// drip are minted to `recipient`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `recipient` - Account receiving the emissions.
+        /// * `amount` - Amount of tokens minted.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Dripped(
+            uint256 indexed id,
+            address indexed recipient,
+            uint256 amount
+        );
+    }
+
+    sol! {
+        /// Indicates that token `id` has no emissions to drip.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909EmissionNotSet(uint256 id);
+
+        /// Indicates that `recipient` can't receive the emissions of token
+        /// `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `recipient` - Account receiving the emissions.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidEmissionRecipient(uint256 id, address recipient);
+    }
+}
+
+/// An [`Erc6909Emissions`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a token id has no emissions.
+    EmissionNotSet(ERC6909EmissionNotSet),
+    /// Indicates that the recipient of emissions is invalid.
+    InvalidEmissionRecipient(ERC6909InvalidEmissionRecipient),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Emissions`] contract.
+#[storage]
+pub struct Erc6909Emissions {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to its amount of tokens emitted per second.
+    pub(crate) emission_rates: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the account receiving its emissions.
+    pub(crate) emission_recipients: StorageMap<U256, StorageAddress>,
+    /// Mapping from token id to the timestamp up to which its emissions have
+    /// been minted.
+    pub(crate) last_drips: StorageMap<U256, StorageU64>,
+}
+
+impl_as_erc6909!(Erc6909Emissions, erc6909);
+
+/// Required interface of an [`Erc6909Emissions`] contract.
+#[interface_id]
+pub trait IErc6909Emissions: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the amount of tokens of type `id` emitted per second.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn emission_rate(&self, id: U256) -> U256;
+
+    /// Returns the account receiving the emissions of token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn emission_recipient(&self, id: U256) -> Address;
+
+    /// Returns the timestamp up to which the emissions of token `id` have
+    /// been minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn last_drip(&self, id: U256) -> U64;
+
+    /// Returns the amount of tokens of type `id` accrued since its last drip.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Panics
+    ///
+    /// * If the accrued emissions exceed [`U256::MAX`].
+    fn pending_emissions(&self, id: U256) -> U256;
+
+    /// Mints the tokens of type `id` accrued since its last drip to its
+    /// emission recipient, and returns the amount minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmissionNotSet`] - If token `id` has no emission rate.
+    /// * [`Error::InvalidReceiver`] - If the emission recipient can't receive
+    ///   tokens.
+    ///
+    /// # Panics
+    ///
+    /// * If the accrued emissions exceed [`U256::MAX`].
+    /// * If the total supply of token `id` exceeds [`U256::MAX`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Dripped`].
+    fn drip(&mut self, id: U256) -> Result<U256, Self::Error>;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909Emissions<Error = Error>, IErc165)]
+impl Erc6909Emissions {}
+
+#[public]
+impl IErc6909Emissions for Erc6909Emissions {
+    type Error = Error;
+
+    fn emission_rate(&self, id: U256) -> U256 {
+        self.emission_rates.get(id)
+    }
+
+    fn emission_recipient(&self, id: U256) -> Address {
+        self.emission_recipients.get(id)
+    }
+
+    fn last_drip(&self, id: U256) -> U64 {
+        self.last_drips.get(id)
+    }
+
+    fn pending_emissions(&self, id: U256) -> U256 {
+        let elapsed =
+            U64::from(block::timestamp()).saturating_sub(self.last_drip(id));
+        self.emission_rate(id)
+            .checked_mul(U256::from(elapsed))
+            .expect("pending emissions should not exceed `U256::MAX`")
+    }
+
+    fn drip(&mut self, id: U256) -> Result<U256, Self::Error> {
+        if self.emission_rate(id).is_zero() {
+            return Err(Error::EmissionNotSet(ERC6909EmissionNotSet { id }));
+        }
+
+        self._drip(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Emissions {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Emissions {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Emissions>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Emissions {
+    /// Sets the emissions of token `id` to `rate` tokens per second, minted
+    /// to `recipient`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Emissions accrued at the previous rate are dripped to the previous
+    /// recipient first, so that a new rate only applies from now on. Setting
+    /// `rate` to zero stops the emissions of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `recipient` - Account receiving the emissions.
+    /// * `rate` - Amount of tokens emitted per second.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidEmissionRecipient`] - If `rate` is not zero and
+    ///   `recipient` is [`Address::ZERO`].
+    /// * [`Error::InvalidReceiver`] - If the previous recipient can't receive
+    ///   the accrued emissions.
+    ///
+    /// # Panics
+    ///
+    /// * If the accrued emissions exceed [`U256::MAX`].
+    /// * If the total supply of token `id` exceeds [`U256::MAX`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] and [`Dripped`], if emissions accrued at
+    ///   the previous rate.
+    /// * [`EmissionSet`].
+    pub fn _set_emission(
+        &mut self,
+        id: U256,
+        recipient: Address,
+        rate: U256,
+    ) -> Result<(), Error> {
+        if !rate.is_zero() && recipient.is_zero() {
+            return Err(Error::InvalidEmissionRecipient(
+                ERC6909InvalidEmissionRecipient { id, recipient },
+            ));
+        }
+
+        self._drip(id)?;
+
+        self.emission_rates.setter(id).set(rate);
+        self.emission_recipients.setter(id).set(recipient);
+        evm::log(EmissionSet { id, recipient, rate });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909Emissions {
+    /// Mints the tokens of type `id` accrued since its last drip to its
+    /// emission recipient, checkpoints the drip and returns the amount
+    /// minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If the emission recipient can't receive
+    ///   tokens.
+    ///
+    /// # Panics
+    ///
+    /// * If the accrued emissions exceed [`U256::MAX`].
+    /// * If the total supply of token `id` exceeds [`U256::MAX`].
+    fn _drip(&mut self, id: U256) -> Result<U256, Error> {
+        let amount = self.pending_emissions(id);
+        self.last_drips.setter(id).set(U64::from(block::timestamp()));
+
+        if amount.is_zero() {
+            return Ok(amount);
+        }
+
+        let recipient = self.emission_recipient(id);
+        self._mint(recipient, id, amount)?;
+        evm::log(Dripped { id, recipient, amount });
+
+        Ok(amount)
+    }
+}
+
+impl IErc6909Internal for Erc6909Emissions {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Emissions {}
+
+    const ID: U256 = uint!(1_U256);
+    const RATE: U256 = uint!(5_U256);
+    const ELAPSED: u64 = 10;
+
+    /// Moves the last drip of token `id` `ELAPSED` seconds back in time.
+    fn elapse(contract: &Contract<Erc6909Emissions>, account: Address) {
+        let last_drip = contract.sender(account).last_drip(ID);
+        contract
+            .sender(account)
+            .last_drips
+            .setter(ID)
+            .set(last_drip - U64::from(ELAPSED));
+    }
+
+    #[motsu::test]
+    fn set_emission_checkpoints_last_drip(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+        treasury: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_emission(ID, treasury, RATE)
+            .motsu_expect("should set emission");
+
+        assert_eq!(RATE, contract.sender(alice).emission_rate(ID));
+        assert_eq!(treasury, contract.sender(alice).emission_recipient(ID));
+        assert_eq!(
+            U64::from(block::timestamp()),
+            contract.sender(alice).last_drip(ID)
+        );
+        assert!(contract.sender(alice).pending_emissions(ID).is_zero());
+
+        contract.assert_emitted(&EmissionSet {
+            id: ID,
+            recipient: treasury,
+            rate: RATE,
+        });
+    }
+
+    #[motsu::test]
+    fn set_emission_reverts_on_zero_recipient(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_emission(ID, Address::ZERO, RATE)
+            .motsu_expect_err("should not emit to the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidEmissionRecipient(ERC6909InvalidEmissionRecipient {
+                id,
+                recipient,
+            }) if id == ID && recipient.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn drip_mints_accrued_emissions(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_emission(ID, treasury, RATE)
+            .motsu_expect("should set emission");
+        elapse(&contract, alice);
+
+        let expected = RATE * U256::from(ELAPSED);
+        assert_eq!(expected, contract.sender(bob).pending_emissions(ID));
+
+        let minted =
+            contract.sender(bob).drip(ID).motsu_expect("should drip emissions");
+
+        assert_eq!(expected, minted);
+        assert_eq!(expected, contract.sender(bob).balance_of(treasury, ID));
+        assert!(contract.sender(bob).pending_emissions(ID).is_zero());
+        assert_eq!(
+            U64::from(block::timestamp()),
+            contract.sender(bob).last_drip(ID)
+        );
+
+        contract.assert_emitted(&Dripped {
+            id: ID,
+            recipient: treasury,
+            amount: expected,
+        });
+    }
+
+    #[motsu::test]
+    fn drip_twice_in_same_block_mints_nothing(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+        treasury: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_emission(ID, treasury, RATE)
+            .motsu_expect("should set emission");
+        elapse(&contract, alice);
+
+        contract.sender(alice).drip(ID).motsu_expect("should drip emissions");
+        let minted =
+            contract.sender(alice).drip(ID).motsu_expect("should drip again");
+
+        assert!(minted.is_zero());
+        assert_eq!(
+            RATE * U256::from(ELAPSED),
+            contract.sender(alice).balance_of(treasury, ID)
+        );
+    }
+
+    #[motsu::test]
+    fn drip_reverts_when_emission_not_set(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .drip(ID)
+            .motsu_expect_err("should not drip without emissions");
+
+        assert!(matches!(
+            err,
+            Error::EmissionNotSet(ERC6909EmissionNotSet { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn set_emission_drips_at_previous_rate(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+        treasury: Address,
+        new_treasury: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_emission(ID, treasury, RATE)
+            .motsu_expect("should set emission");
+        elapse(&contract, alice);
+
+        contract
+            .sender(alice)
+            ._set_emission(ID, new_treasury, RATE * uint!(2_U256))
+            .motsu_expect("should update emission");
+
+        assert_eq!(
+            RATE * U256::from(ELAPSED),
+            contract.sender(alice).balance_of(treasury, ID)
+        );
+        assert!(contract.sender(alice).balance_of(new_treasury, ID).is_zero());
+        assert!(contract.sender(alice).pending_emissions(ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn set_emission_to_zero_stops_emissions(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+        treasury: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_emission(ID, treasury, RATE)
+            .motsu_expect("should set emission");
+        contract
+            .sender(alice)
+            ._set_emission(ID, Address::ZERO, U256::ZERO)
+            .motsu_expect("should stop emission");
+        elapse(&contract, alice);
+
+        assert!(contract.sender(alice).pending_emissions(ID).is_zero());
+        let err = contract
+            .sender(alice)
+            .drip(ID)
+            .motsu_expect_err("should not drip stopped emissions");
+        assert!(matches!(err, Error::EmissionNotSet(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Emissions as IErc6909Emissions>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xc1bd6345");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909Emissions>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Emissions as IErc6909Emissions>::interface_id()
+        ));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909Emissions as IErc6909>::interface_id()
+            )
+        );
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Emissions as IErc165>::interface_id()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
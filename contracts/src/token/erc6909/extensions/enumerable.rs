@@ -0,0 +1,549 @@
+//! Extension of ERC-6909 that keeps track of the token ids owned by each
+//! account, so that the portfolio of an account can be read from the contract
+//! rather than rebuilt from historical transfer logs.
+//!
+//! An id is added to the set of an account when its balance goes from zero to
+//! a non-zero value, and removed when it goes back to zero. The order of the
+//! ids is not guaranteed to stay the same after a removal.
+//!
+//! CAUTION: This extension should not be added in an upgrade to an already
+//! deployed contract.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256, StorageVec},
+};
+
+use crate::{
+    impl_sol_struct,
+    token::erc6909::{
+        self, impl_as_erc6909, Erc6909, Error, IErc6909, IErc6909Internal,
+    },
+    utils::{introspection::erc165::IErc165, pagination::Pagination},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// A page of the token ids held by an account.
+        ///
+        /// * `ids` - Token ids of the page.
+        /// * `nextCursor` - Cursor of the next page, or zero if there is none.
+        #[derive(Debug, Default, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        struct IdsPage {
+            uint256[] ids;
+            uint256 nextCursor;
+        }
+    }
+}
+
+impl_sol_struct!(IdsPage, (Vec<U256>, U256));
+
+/// State of an [`Erc6909Enumerable`] contract.
+#[storage]
+pub struct Erc6909Enumerable {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from owner to the list of token ids it holds.
+    pub(crate) owned_ids: StorageMap<Address, StorageVec<StorageU256>>,
+    /// Mapping from owner to the position of each of its token ids in
+    /// `owned_ids`, offset by one. Zero means the id is not owned.
+    pub(crate) owned_ids_index:
+        StorageMap<Address, StorageMap<U256, StorageU256>>,
+}
+
+impl_as_erc6909!(Erc6909Enumerable, erc6909);
+
+/// Required interface of an [`Erc6909Enumerable`] contract.
+#[interface_id]
+pub trait IErc6909Enumerable: IErc165 {
+    /// Returns the number of distinct token ids `owner` holds a non-zero
+    /// balance of.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Address of the token holder.
+    fn id_count_of(&self, owner: Address) -> U256;
+
+    /// Returns a page of at most `limit` token ids held by `owner`, starting
+    /// at `cursor`, along with the cursor of the next page, as an
+    /// [`IdsPage`] struct.
+    ///
+    /// Pages are capped to
+    /// [`MAX_PAGE_SIZE`](crate::utils::pagination::MAX_PAGE_SIZE) ids. The
+    /// returned cursor is zero once all ids have been read. See
+    /// [`Pagination`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Address of the token holder.
+    /// * `cursor` - Position of the first id to return.
+    /// * `limit` - Maximum number of ids to return.
+    fn ids_of(&self, owner: Address, cursor: U256, limit: U256) -> IdsPage;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909Enumerable, IErc165)]
+impl Erc6909Enumerable {}
+
+#[public]
+impl IErc6909Enumerable for Erc6909Enumerable {
+    fn id_count_of(&self, owner: Address) -> U256 {
+        U256::from(self.owned_ids.get(owner).len())
+    }
+
+    fn ids_of(&self, owner: Address, cursor: U256, limit: U256) -> IdsPage {
+        let ids = self.owned_ids.get(owner);
+        let page = Pagination::new(cursor, limit, ids.len());
+        let page_ids = page.range().filter_map(|i| ids.get(i)).collect();
+        IdsPage { ids: page_ids, nextCursor: page.next_cursor() }
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Enumerable {
+    type Error = erc6909::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Enumerable {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Enumerable>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Enumerable {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909Enumerable {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if to.is_zero() {
+            return Err(erc6909::Error::InvalidReceiver(
+                erc6909::ERC6909InvalidReceiver { receiver: to },
+            ));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if from.is_zero() {
+            return Err(erc6909::Error::InvalidSender(
+                erc6909::ERC6909InvalidSender { sender: from },
+            ));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that keeps the list of token
+    /// ids of `from` and `to` up to date.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc6909::Error::InvalidArrayLength`] - If length of `ids` is not
+    ///   equal to length of `amounts`.
+    /// * [`erc6909::Error::InsufficientBalance`] - If `amount` is greater than
+    ///   the balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._update(from, to, ids.clone(), amounts)?;
+
+        for id in ids {
+            if !from.is_zero() && self.erc6909.balance_of(from, id).is_zero() {
+                self._remove_id_from_owner(from, id);
+            }
+            if !to.is_zero() && !self.erc6909.balance_of(to, id).is_zero() {
+                self._add_id_to_owner(to, id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds `id` to the list of token ids of `owner`, if not already there.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Address of the token holder.
+    /// * `id` - Token id as a number.
+    fn _add_id_to_owner(&mut self, owner: Address, id: U256) {
+        if !self.owned_ids_index.get(owner).get(id).is_zero() {
+            return;
+        }
+
+        let mut owned_ids = self.owned_ids.setter(owner);
+        owned_ids.push(id);
+        let position = U256::from(owned_ids.len());
+        self.owned_ids_index.setter(owner).setter(id).set(position);
+    }
+
+    /// Removes `id` from the list of token ids of `owner`, if there.
+    ///
+    /// To avoid gaps, the last id of the list is moved to the position of
+    /// the removed one.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Address of the token holder.
+    /// * `id` - Token id as a number.
+    fn _remove_id_from_owner(&mut self, owner: Address, id: U256) {
+        let position = self.owned_ids_index.get(owner).get(id);
+        if position.is_zero() {
+            return;
+        }
+
+        let mut owned_ids = self.owned_ids.setter(owner);
+        let last_id = owned_ids.pop().expect("should contain `id`");
+        if last_id != id {
+            let index =
+                usize::try_from(position).expect("should fit `usize`") - 1;
+            owned_ids
+                .setter(index)
+                .expect("should be a valid position")
+                .set(last_id);
+            self.owned_ids_index.setter(owner).setter(last_id).set(position);
+        }
+        self.owned_ids_index.setter(owner).delete(id);
+    }
+}
+
+impl IErc6909Internal for Erc6909Enumerable {
+    type Error = erc6909::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Enumerable {}
+
+    fn ids(
+        contract: &Contract<Erc6909Enumerable>,
+        owner: Address,
+    ) -> Vec<U256> {
+        let IdsPage { mut ids, nextCursor } =
+            contract.sender(owner).ids_of(owner, U256::ZERO, U256::MAX);
+        assert!(nextCursor.is_zero());
+        ids.sort();
+        ids
+    }
+
+    #[motsu::test]
+    fn mint_adds_ids_once(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![uint!(1_U256), uint!(2_U256), uint!(1_U256)],
+                vec![uint!(10_U256), uint!(10_U256), uint!(10_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+
+        assert_eq!(contract.sender(alice).id_count_of(alice), uint!(2_U256));
+        assert_eq!(ids(&contract, alice), vec![uint!(1_U256), uint!(2_U256)]);
+    }
+
+    #[motsu::test]
+    fn transfer_moves_ids_between_owners(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![uint!(1_U256), uint!(2_U256), uint!(3_U256)],
+                vec![uint!(10_U256), uint!(10_U256), uint!(10_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            .transfer(bob, uint!(1_U256), uint!(10_U256))
+            .motsu_expect("should transfer to Bob");
+        contract
+            .sender(alice)
+            .transfer(bob, uint!(3_U256), uint!(5_U256))
+            .motsu_expect("should transfer to Bob");
+
+        assert_eq!(ids(&contract, alice), vec![uint!(2_U256), uint!(3_U256)]);
+        assert_eq!(ids(&contract, bob), vec![uint!(1_U256), uint!(3_U256)]);
+    }
+
+    #[motsu::test]
+    fn burn_removes_ids(contract: Contract<Erc6909Enumerable>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![uint!(1_U256), uint!(2_U256)],
+                vec![uint!(10_U256), uint!(10_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            ._burn(alice, uint!(1_U256), uint!(10_U256))
+            .motsu_expect("should burn Alice's tokens");
+
+        assert_eq!(ids(&contract, alice), vec![uint!(2_U256)]);
+    }
+
+    #[motsu::test]
+    fn ids_of_is_paginated(
+        contract: Contract<Erc6909Enumerable>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![uint!(1_U256), uint!(2_U256), uint!(3_U256)],
+                vec![uint!(10_U256), uint!(10_U256), uint!(10_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+
+        assert_eq!(
+            contract.sender(alice).ids_of(alice, U256::ZERO, uint!(2_U256)),
+            IdsPage {
+                ids: vec![uint!(1_U256), uint!(2_U256)],
+                nextCursor: uint!(2_U256),
+            }
+        );
+        assert_eq!(
+            contract.sender(alice).ids_of(alice, uint!(2_U256), uint!(2_U256)),
+            IdsPage { ids: vec![uint!(3_U256)], nextCursor: U256::ZERO }
+        );
+        assert_eq!(
+            contract.sender(alice).ids_of(alice, uint!(3_U256), uint!(2_U256)),
+            IdsPage::default()
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Enumerable as IErc6909Enumerable>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x39881786");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,477 @@
+//! Extension of ERC-6909 that discounts transfer fees based on how many
+//! tokens of a designated "membership" id the sender holds.
+//!
+//! Every token id can be configured with a list of rebate tiers. A tier is
+//! reached once the sender's balance of the membership id is at least the
+//! tier's threshold, and grants a discount expressed in basis points. When
+//! several tiers are reached, the one with the highest threshold applies.
+//!
+//! Note that the tiers will not be taken into account by simply including
+//! this module, but only once [`Erc6909FeeRebates::_apply_rebate`] is called
+//! from the fee hook of your contract.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageMap, StorageU256, StorageVec},
+};
+
+use crate::{
+    impl_sol_struct,
+    token::erc6909::{lib, Erc6909, IErc6909},
+    utils::math::bps::Bps,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Rebate tiers of a token id, ordered by increasing threshold.
+        ///
+        /// * `thresholds` - Balances of the membership id reaching each tier.
+        /// * `discounts` - Discounts granted by each tier, in basis points.
+        #[derive(Debug, Default, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        struct RebateTiers {
+            uint256[] thresholds;
+            uint256[] discounts;
+        }
+    }
+
+    sol! {
+        /// Emitted when the membership token id is set to `membership_id`.
+        ///
+        /// * `membership_id` - Token id whose balance determines the tier.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MembershipIdSet(uint256 indexed membership_id);
+
+        /// Emitted when the rebate tiers of token `id` are replaced.
+        ///
+        /// * `id` - Token id the tiers apply to.
+        /// * `thresholds` - Minimum membership balances of each tier.
+        /// * `discounts` - Discounts of each tier, in basis points.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event RebateTiersSet(
+            uint256 indexed id,
+            uint256[] thresholds,
+            uint256[] discounts
+        );
+    }
+
+    sol! {
+        /// Indicates an array length mismatch between tier thresholds and
+        /// discounts.
+        ///
+        /// * `thresholds_length` - Length of the array of thresholds.
+        /// * `discounts_length` - Length of the array of discounts.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidRebateTiersLength(
+            uint256 thresholds_length,
+            uint256 discounts_length
+        );
+
+        /// Indicates that tier thresholds are not strictly increasing.
+        ///
+        /// * `threshold` - First threshold that breaks the order.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnorderedRebateTiers(uint256 threshold);
+
+        /// Indicates that a tier discount exceeds 100%.
+        ///
+        /// * `discount` - Invalid discount, in basis points.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidRebateDiscount(uint256 discount);
+    }
+}
+
+impl_sol_struct!(RebateTiers, (Vec<U256>, Vec<U256>));
+
+/// An [`Erc6909FeeRebates`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an array length mismatch between tier thresholds and
+    /// discounts.
+    InvalidTiersLength(ERC6909InvalidRebateTiersLength),
+    /// Indicates that tier thresholds are not strictly increasing.
+    UnorderedTiers(ERC6909UnorderedRebateTiers),
+    /// Indicates that a tier discount exceeds [`Bps::MAX`].
+    InvalidDiscount(ERC6909InvalidRebateDiscount),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909FeeRebates`] contract.
+#[storage]
+pub struct Erc6909FeeRebates {
+    /// Token id whose balance determines the tier of an account.
+    pub(crate) membership_id: StorageU256,
+    /// Mapping from token id to the thresholds of its rebate tiers.
+    pub(crate) tier_thresholds: StorageMap<U256, StorageVec<StorageU256>>,
+    /// Mapping from token id to the discounts of its rebate tiers.
+    pub(crate) tier_discounts: StorageMap<U256, StorageVec<StorageU256>>,
+}
+
+/// Interface for the fee rebate tiers logic.
+#[interface_id]
+pub trait IErc6909FeeRebates {
+    /// Returns the token id whose balance determines the tier of an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn membership_id(&self) -> U256;
+
+    /// Returns the thresholds and discounts (in basis points) of the rebate
+    /// tiers configured for token `id`, as a [`RebateTiers`] struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn rebate_tiers(&self, id: U256) -> RebateTiers;
+}
+
+#[public]
+#[implements(IErc6909FeeRebates)]
+impl Erc6909FeeRebates {}
+
+#[public]
+impl IErc6909FeeRebates for Erc6909FeeRebates {
+    fn membership_id(&self) -> U256 {
+        self.membership_id.get()
+    }
+
+    fn rebate_tiers(&self, id: U256) -> RebateTiers {
+        let thresholds = self.tier_thresholds.get(id);
+        let discounts = self.tier_discounts.get(id);
+        let (thresholds, discounts) = (0..thresholds.len())
+            .map(|i| {
+                (
+                    thresholds.get(i).expect("should be in bounds"),
+                    discounts.get(i).expect("should be in bounds"),
+                )
+            })
+            .unzip();
+        RebateTiers { thresholds, discounts }
+    }
+}
+
+impl Erc6909FeeRebates {
+    /// Sets the token id whose balance determines the tier of an account.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `membership_id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * [`MembershipIdSet`].
+    pub fn _set_membership_id(&mut self, membership_id: U256) {
+        self.membership_id.set(membership_id);
+        evm::log(MembershipIdSet { membership_id });
+    }
+
+    /// Replaces the rebate tiers of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id the tiers apply to.
+    /// * `thresholds` - Strictly increasing minimum membership balances.
+    /// * `discounts` - Discounts of each tier, in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidTiersLength`] - If length of `thresholds` is not equal
+    ///   to length of `discounts`.
+    /// * [`Error::UnorderedTiers`] - If `thresholds` are not strictly
+    ///   increasing.
+    /// * [`Error::InvalidDiscount`] - If any of the `discounts` exceeds
+    ///   [`Bps::MAX`].
+    ///
+    /// # Events
+    ///
+    /// * [`RebateTiersSet`].
+    pub fn _set_rebate_tiers(
+        &mut self,
+        id: U256,
+        thresholds: Vec<U256>,
+        discounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if thresholds.len() != discounts.len() {
+            return Err(Error::InvalidTiersLength(
+                ERC6909InvalidRebateTiersLength {
+                    thresholds_length: U256::from(thresholds.len()),
+                    discounts_length: U256::from(discounts.len()),
+                },
+            ));
+        }
+
+        for pair in thresholds.windows(2) {
+            if pair[1] <= pair[0] {
+                return Err(Error::UnorderedTiers(
+                    ERC6909UnorderedRebateTiers { threshold: pair[1] },
+                ));
+            }
+        }
+
+        if let Some(&discount) =
+            discounts.iter().find(|&&discount| Bps::try_from(discount).is_err())
+        {
+            return Err(Error::InvalidDiscount(ERC6909InvalidRebateDiscount {
+                discount,
+            }));
+        }
+
+        let mut stored_thresholds = self.tier_thresholds.setter(id);
+        stored_thresholds.truncate(0);
+        for &threshold in &thresholds {
+            stored_thresholds.push(threshold);
+        }
+
+        let mut stored_discounts = self.tier_discounts.setter(id);
+        stored_discounts.truncate(0);
+        for &discount in &discounts {
+            stored_discounts.push(discount);
+        }
+
+        evm::log(RebateTiersSet { id, thresholds, discounts });
+
+        Ok(())
+    }
+
+    /// Returns the discount that `account` is entitled to when paying fees on
+    /// transfers of token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account paying the fee.
+    /// * `id` - Token id being transferred.
+    /// * `erc6909` - Read access to an [`Erc6909`] contract.
+    #[must_use]
+    pub fn discount_of(
+        &self,
+        account: Address,
+        id: U256,
+        erc6909: &Erc6909,
+    ) -> Bps {
+        let balance = erc6909.balance_of(account, self.membership_id());
+        let thresholds = self.tier_thresholds.get(id);
+
+        (0..thresholds.len())
+            .rev()
+            .find(|&i| thresholds.get(i).is_some_and(|t| t <= balance))
+            .and_then(|i| self.tier_discounts.get(id).get(i))
+            // Discounts are validated when set, so the conversion can't fail.
+            .and_then(|discount| Bps::try_from(discount).ok())
+            .unwrap_or_default()
+    }
+
+    /// Applies the rebate `sender` is entitled to on a `fee` charged for
+    /// transferring token `id`, and returns the discounted fee. Meant to be
+    /// called from the fee hook of the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `sender` - Account paying the fee.
+    /// * `id` - Token id being transferred.
+    /// * `fee` - Fee before the rebate.
+    /// * `erc6909` - Read access to an [`Erc6909`] contract.
+    #[must_use]
+    pub fn _apply_rebate(
+        &self,
+        sender: Address,
+        id: U256,
+        fee: U256,
+        erc6909: &Erc6909,
+    ) -> U256 {
+        let discount = self.discount_of(sender, id, erc6909);
+        if discount.is_zero() {
+            return fee;
+        }
+        let rebate = lib::fee_amount(fee, discount);
+        fee - rebate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const MEMBERSHIP_ID: U256 = uint!(7_U256);
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    #[storage]
+    struct Erc6909FeeRebatesExample {
+        erc6909: Erc6909,
+        rebates: Erc6909FeeRebates,
+    }
+
+    #[public]
+    impl Erc6909FeeRebatesExample {
+        fn fee_for(&self, sender: Address, fee: U256) -> U256 {
+            self.rebates._apply_rebate(sender, TOKEN_ID, fee, &self.erc6909)
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909FeeRebatesExample {}
+    unsafe impl TopLevelStorage for Erc6909FeeRebates {}
+
+    fn init(contract: &mut Erc6909FeeRebatesExample) {
+        contract.rebates._set_membership_id(MEMBERSHIP_ID);
+        contract
+            .rebates
+            ._set_rebate_tiers(
+                TOKEN_ID,
+                vec![uint!(100_U256), uint!(1000_U256)],
+                vec![uint!(1000_U256), uint!(5000_U256)],
+            )
+            .expect("should set rebate tiers");
+    }
+
+    #[motsu::test]
+    fn fee_is_not_discounted_below_first_tier(
+        contract: Contract<Erc6909FeeRebatesExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            init(contract);
+            contract
+                .erc6909
+                ._mint(alice, MEMBERSHIP_ID, uint!(99_U256))
+                .expect("should mint membership tokens");
+        });
+
+        assert_eq!(
+            contract.sender(alice).fee_for(alice, uint!(200_U256)),
+            uint!(200_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn fee_is_discounted_by_highest_reached_tier(
+        contract: Contract<Erc6909FeeRebatesExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            init(contract);
+            contract
+                .erc6909
+                ._mint(alice, MEMBERSHIP_ID, uint!(100_U256))
+                .expect("should mint membership tokens");
+            contract
+                .erc6909
+                ._mint(bob, MEMBERSHIP_ID, uint!(5000_U256))
+                .expect("should mint membership tokens");
+        });
+
+        assert_eq!(
+            contract.sender(alice).fee_for(alice, uint!(200_U256)),
+            uint!(180_U256)
+        );
+        assert_eq!(
+            contract.sender(bob).fee_for(bob, uint!(200_U256)),
+            uint!(100_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn set_rebate_tiers_reverts_when_unordered(
+        contract: Contract<Erc6909FeeRebates>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_rebate_tiers(
+                TOKEN_ID,
+                vec![uint!(1000_U256), uint!(100_U256)],
+                vec![uint!(1000_U256), uint!(5000_U256)],
+            )
+            .expect_err("should not accept unordered tiers");
+
+        assert!(matches!(
+            err,
+            Error::UnorderedTiers(ERC6909UnorderedRebateTiers { threshold })
+                if threshold == uint!(100_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn set_rebate_tiers_reverts_when_discount_too_high(
+        contract: Contract<Erc6909FeeRebates>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_rebate_tiers(
+                TOKEN_ID,
+                vec![uint!(100_U256)],
+                vec![uint!(10_001_U256)],
+            )
+            .expect_err("should not accept discounts above 100%");
+
+        assert!(matches!(
+            err,
+            Error::InvalidDiscount(ERC6909InvalidRebateDiscount { discount })
+                if discount == uint!(10_001_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn rebate_tiers_returns_configured_tiers(
+        contract: Contract<Erc6909FeeRebates>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_rebate_tiers(
+                TOKEN_ID,
+                vec![uint!(100_U256)],
+                vec![uint!(2500_U256)],
+            )
+            .expect("should set rebate tiers");
+
+        let tiers = contract.sender(alice).rebate_tiers(TOKEN_ID);
+        assert_eq!(
+            RebateTiers {
+                thresholds: vec![uint!(100_U256)],
+                discounts: vec![uint!(2500_U256)],
+            },
+            tiers
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909FeeRebates as IErc6909FeeRebates>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x46508f35");
+        assert_eq!(actual, expected);
+    }
+}
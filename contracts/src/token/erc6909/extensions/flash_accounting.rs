@@ -0,0 +1,997 @@
+//! Extension of ERC-6909 that implements flash accounting, as popularized by
+//! Uniswap v4.
+//!
+//! While an account holds the lock, balances are not moved eagerly: each
+//! operation records a delta per token id in a ledger, positive when tokens
+//! are owed to the locker and negative when the locker owes tokens. Deltas
+//! may go negative during the lock, e.g. when the locker [`take`]s tokens
+//! before paying for them, but they must all net to zero, by [`settle`]ing
+//! them, before the lock is released. Otherwise, the whole operation reverts.
+//!
+//! Accounts get the lock with [`IErc6909FlashAccounting::lock`], which calls
+//! them back with [`IUnlockCallback::unlock_callback`]. The callback runs
+//! their operations, e.g. [`take`] and [`settle`], on this contract, so
+//! contracts exposing `lock` must be built with the `reentrant` feature. The
+//! lock can't be nested, and only the locker can move deltas while it holds
+//! the lock.
+//!
+//! Contracts can also open the lock with [`Erc6909FlashAccounting::_lock`] to
+//! run their own operations, and record their effects with
+//! [`Erc6909FlashAccounting::_account_delta`].
+//!
+//! NOTE: The Stylus SDK doesn't expose transient storage yet, so the ledger is
+//! kept in regular storage. Since every delta is back to zero when the lock
+//! is released, the ledger is always cleared by the end of the transaction.
+//!
+//! [`take`]: IErc6909FlashAccounting::take
+//! [`settle`]: IErc6909FlashAccounting::settle
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, I256, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes,
+    call::{self, Call, MethodError},
+    msg,
+    prelude::*,
+    storage::{StorageAddress, StorageI256, StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that the lock was requested while `locker` holds it.
+        ///
+        /// * `locker` - Account holding the lock.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909AlreadyLocked(address locker);
+
+        /// Indicates that `account` tried to move deltas without holding the
+        /// lock.
+        ///
+        /// * `account` - Account that tried to move deltas.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NotLocker(address account);
+
+        /// Indicates that the lock was released while `count` token ids had
+        /// unsettled deltas.
+        ///
+        /// * `count` - Number of token ids with a non-zero delta.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnsettledDeltas(uint256 count);
+
+        /// Indicates that the unlock callback of `locker` failed.
+        ///
+        /// * `locker` - Account holding the lock.
+        /// * `reason` - Revert data of the callback, if any.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnlockCallbackFailed(address locker, bytes reason);
+    }
+}
+
+pub use callback::IUnlockCallback;
+mod callback {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the accounts getting the lock of an
+        /// [`super::Erc6909FlashAccounting`] contract.
+        interface IUnlockCallback {
+            /// Runs the operations of the locker, while it holds the lock.
+            ///
+            /// Every delta must be settled when it returns.
+            ///
+            /// # Arguments
+            ///
+            /// * `data` - Data passed to `lock`.
+            function unlockCallback(bytes calldata data) external returns (bytes memory);
+        }
+    }
+}
+
+/// An [`Erc6909FlashAccounting`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that the lock was requested while already held.
+    AlreadyLocked(ERC6909AlreadyLocked),
+    /// Indicates that an account tried to move deltas without holding the
+    /// lock.
+    NotLocker(ERC6909NotLocker),
+    /// Indicates that the lock was released with unsettled deltas.
+    UnsettledDeltas(ERC6909UnsettledDeltas),
+    /// Indicates that the unlock callback of the locker failed.
+    UnlockCallbackFailed(ERC6909UnlockCallbackFailed),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909FlashAccounting`] contract.
+#[storage]
+pub struct Erc6909FlashAccounting {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Account holding the lock, or [`Address::ZERO`] when unlocked.
+    pub(crate) locker: StorageAddress,
+    /// Maps token ids to the delta of the locker.
+    pub(crate) deltas: StorageMap<U256, StorageI256>,
+    /// Number of token ids with a non-zero delta.
+    pub(crate) nonzero_deltas: StorageU256,
+}
+
+impl_as_erc6909!(Erc6909FlashAccounting, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909FlashAccounting {}
+
+/// Required interface of an [`Erc6909FlashAccounting`] contract.
+#[interface_id]
+pub trait IErc6909FlashAccounting: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the account holding the lock, or [`Address::ZERO`] when
+    /// unlocked.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn locker(&self) -> Address;
+
+    /// Returns the delta of the locker in token `id`: positive when tokens
+    /// are owed to the locker, and negative when it owes tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn delta(&self, id: U256) -> I256;
+
+    /// Gives the lock to the caller, and calls it back with
+    /// [`IUnlockCallback::unlock_callback`] and `data`. The lock is released
+    /// once the callback returns, if every delta is settled.
+    ///
+    /// Returns the data returned by the callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `data` - Data passed to the callback.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AlreadyLocked`] - If the lock is already held, e.g. when
+    ///   called from a callback.
+    /// * [`Error::UnlockCallbackFailed`] - If the callback reverts, or the
+    ///   caller doesn't implement it.
+    /// * [`Error::UnsettledDeltas`] - If any delta is not zero once the
+    ///   callback returns.
+    fn lock(&mut self, data: Bytes) -> Result<Bytes, Self::Error>;
+
+    /// Mints `amount` tokens of type `id` to the locker, and records them as
+    /// owed by the locker.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to take.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotLocker`] - If the caller doesn't hold the lock.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    ///
+    /// # Panics
+    ///
+    /// * If the delta of `id` overflows.
+    fn take(&mut self, id: U256, amount: U256) -> Result<(), Self::Error>;
+
+    /// Settles the delta of the locker in token `id`, by burning the tokens
+    /// it owes, or by minting the tokens owed to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotLocker`] - If the caller doesn't hold the lock.
+    /// * [`Error::InsufficientBalance`] - If the locker owns fewer tokens than
+    ///   it owes.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the delta is not zero.
+    fn settle(&mut self, id: U256) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909FlashAccounting<Error = Error>,
+    IErc165
+)]
+impl Erc6909FlashAccounting {}
+
+#[public]
+impl IErc6909FlashAccounting for Erc6909FlashAccounting {
+    type Error = Error;
+
+    fn locker(&self) -> Address {
+        self.locker.get()
+    }
+
+    fn delta(&self, id: U256) -> I256 {
+        self.deltas.get(id)
+    }
+
+    fn lock(&mut self, data: Bytes) -> Result<Bytes, Self::Error> {
+        let locker = msg::sender();
+        self._lock(locker, |token| token._unlock_callback(locker, &data))
+    }
+
+    fn take(&mut self, id: U256, amount: U256) -> Result<(), Self::Error> {
+        let locker = self._only_locker()?;
+
+        let delta = I256::try_from(amount)
+            .ok()
+            .and_then(|amount| I256::ZERO.checked_sub(amount))
+            .expect("taken amount should not exceed `I256::MAX`");
+        self._account_delta(id, delta);
+
+        Ok(self.erc6909._mint(locker, id, amount)?)
+    }
+
+    fn settle(&mut self, id: U256) -> Result<(), Self::Error> {
+        let locker = self._only_locker()?;
+
+        let delta = self.deltas.get(id);
+        if delta.is_zero() {
+            return Ok(());
+        }
+
+        self._account_delta(id, -delta);
+
+        if delta.is_negative() {
+            Ok(self.erc6909._burn(locker, id, delta.unsigned_abs())?)
+        } else {
+            Ok(self.erc6909._mint(locker, id, delta.unsigned_abs())?)
+        }
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909FlashAccounting {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909FlashAccounting {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909FlashAccounting>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909FlashAccounting {
+    /// Gives the lock to `locker`, runs `operations`, and releases the lock
+    /// once every delta is settled.
+    ///
+    /// Internal function that contracts build their locked entry points on.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `locker` - Account getting the lock.
+    /// * `operations` - Operations to run while `locker` holds the lock.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AlreadyLocked`] - If the lock is already held.
+    /// * [`Error::UnsettledDeltas`] - If any delta is not zero once
+    ///   `operations` are run.
+    /// * Any error returned by `operations`.
+    pub fn _lock<R>(
+        &mut self,
+        locker: Address,
+        operations: impl FnOnce(&mut Self) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        let current = self.locker.get();
+        if !current.is_zero() {
+            return Err(Error::AlreadyLocked(ERC6909AlreadyLocked {
+                locker: current,
+            }));
+        }
+        self.locker.set(locker);
+
+        let result = operations(self)?;
+
+        let count = self.nonzero_deltas.get();
+        if !count.is_zero() {
+            return Err(Error::UnsettledDeltas(ERC6909UnsettledDeltas {
+                count,
+            }));
+        }
+        self.locker.set(Address::ZERO);
+
+        Ok(result)
+    }
+
+    /// Adds `delta` to the delta of the locker in token `id`, keeping track
+    /// of the number of token ids left to settle.
+    ///
+    /// Internal function that contracts use to record the effects of their
+    /// operations while the lock is held.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `delta` - Change of the delta, positive when tokens are owed to the
+    ///   locker.
+    ///
+    /// # Panics
+    ///
+    /// * If the delta of `id` overflows.
+    pub fn _account_delta(&mut self, id: U256, delta: I256) {
+        if delta.is_zero() {
+            return;
+        }
+
+        let previous = self.deltas.get(id);
+        let next = previous
+            .checked_add(delta)
+            .expect("delta should not exceed `I256` bounds");
+        self.deltas.setter(id).set(next);
+
+        let count = self.nonzero_deltas.get();
+        if previous.is_zero() {
+            self.nonzero_deltas.set(count + U256::from(1));
+        } else if next.is_zero() {
+            self.nonzero_deltas.set(count - U256::from(1));
+        }
+    }
+
+    /// Calls back `locker` with [`IUnlockCallback::unlock_callback`] and
+    /// `data`, and returns the data it returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `locker` - Account holding the lock.
+    /// * `data` - Data passed to the callback.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnlockCallbackFailed`] - If the callback reverts, or `locker`
+    ///   doesn't implement it.
+    fn _unlock_callback(
+        &mut self,
+        locker: Address,
+        data: &Bytes,
+    ) -> Result<Bytes, Error> {
+        let result = IUnlockCallback::new(locker)
+            .unlock_callback(Call::new_in(self), data.to_vec().into())
+            .map_err(|e| {
+                let reason = match e {
+                    call::Error::Revert(reason) => reason,
+                    call::Error::AbiDecodingFailed(_) => Vec::new(),
+                };
+                Error::UnlockCallbackFailed(ERC6909UnlockCallbackFailed {
+                    locker,
+                    reason: reason.into(),
+                })
+            })?;
+
+        Ok(result.to_vec().into())
+    }
+
+    /// Returns the caller if it holds the lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotLocker`] - If the caller doesn't hold the lock.
+    fn _only_locker(&self) -> Result<Address, Error> {
+        let account = msg::sender();
+        if account.is_zero() || account != self.locker.get() {
+            return Err(Error::NotLocker(ERC6909NotLocker { account }));
+        }
+        Ok(account)
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909FlashAccounting {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, I256, U256,
+    };
+    use alloy_sol_types::{SolError, SolValue};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const THIRD_ID: U256 = uint!(3_U256);
+    const AMOUNT: U256 = uint!(1_000_U256);
+
+    stylus_sdk::prelude::sol_interface! {
+        interface IFlashToken {
+            function lock(bytes calldata data) external returns (bytes memory);
+            function take(uint256 id, uint256 amount) external;
+            function settle(uint256 id) external;
+        }
+    }
+
+    /// Locker taking `amount` of each id of `take_ids`, and then settling
+    /// each id of `settle_ids`, in order, as encoded in the callback data.
+    #[storage]
+    struct MockLocker;
+
+    #[public]
+    impl MockLocker {
+        fn start(
+            &mut self,
+            token: Address,
+            data: Bytes,
+        ) -> Result<Bytes, Vec<u8>> {
+            let result = IFlashToken::new(token)
+                .lock(Call::new_in(self), data.to_vec().into())?;
+            Ok(result.to_vec().into())
+        }
+
+        fn unlock_callback(&mut self, data: Bytes) -> Result<Bytes, Vec<u8>> {
+            let (take_ids, settle_ids, amount) =
+                <(Vec<U256>, Vec<U256>, U256)>::abi_decode(&data, true)
+                    .map_err(|_| Vec::<u8>::new())?;
+
+            let token = IFlashToken::new(msg::sender());
+            for id in take_ids {
+                token.take(Call::new_in(self), id, amount)?;
+            }
+            for id in settle_ids {
+                token.settle(Call::new_in(self), id)?;
+            }
+
+            Ok(data)
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockLocker {}
+
+    /// Locker trying to get the lock again from its callback.
+    #[storage]
+    struct NestedLocker;
+
+    #[public]
+    impl NestedLocker {
+        fn start(
+            &mut self,
+            token: Address,
+            data: Bytes,
+        ) -> Result<Bytes, Vec<u8>> {
+            let result = IFlashToken::new(token)
+                .lock(Call::new_in(self), data.to_vec().into())?;
+            Ok(result.to_vec().into())
+        }
+
+        fn unlock_callback(&mut self, data: Bytes) -> Result<Bytes, Vec<u8>> {
+            let result = IFlashToken::new(msg::sender())
+                .lock(Call::new_in(self), data.to_vec().into())?;
+            Ok(result.to_vec().into())
+        }
+    }
+
+    unsafe impl TopLevelStorage for NestedLocker {}
+
+    /// Locker whose callback always reverts.
+    #[storage]
+    struct RevertingLocker;
+
+    #[public]
+    impl RevertingLocker {
+        fn start(
+            &mut self,
+            token: Address,
+            data: Bytes,
+        ) -> Result<Bytes, Vec<u8>> {
+            let result = IFlashToken::new(token)
+                .lock(Call::new_in(self), data.to_vec().into())?;
+            Ok(result.to_vec().into())
+        }
+
+        fn unlock_callback(&mut self, _data: Bytes) -> Result<Bytes, Vec<u8>> {
+            Err("callback failed".into())
+        }
+    }
+
+    unsafe impl TopLevelStorage for RevertingLocker {}
+
+    fn script(take_ids: &[U256], settle_ids: &[U256]) -> Bytes {
+        (take_ids.to_vec(), settle_ids.to_vec(), AMOUNT).abi_encode().into()
+    }
+
+    fn signed(amount: U256) -> I256 {
+        I256::try_from(amount).expect("should fit in `I256`")
+    }
+
+    #[motsu::test]
+    fn take_and_settle_net_to_zero(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._lock(alice, |token| {
+                token.take(ID, AMOUNT)?;
+                assert_eq!(-signed(AMOUNT), token.delta(ID));
+                assert_eq!(AMOUNT, token.balance_of(alice, ID));
+
+                token.settle(ID)?;
+                assert!(token.delta(ID).is_zero());
+                Ok(())
+            })
+            .motsu_expect("should net deltas to zero");
+
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+        assert!(contract.sender(alice).locker().is_zero());
+    }
+
+    #[motsu::test]
+    fn settle_mints_tokens_owed_to_locker(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._lock(alice, |token| {
+                token._account_delta(ID, signed(AMOUNT));
+                token.settle(ID)
+            })
+            .motsu_expect("should settle positive delta");
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn lock_reverts_with_unsettled_deltas(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._lock(alice, |token| {
+                token.take(ID, AMOUNT)?;
+                token.take(OTHER_ID, AMOUNT)
+            })
+            .motsu_expect_err("should not release lock with debts");
+
+        assert!(matches!(
+            err,
+            Error::UnsettledDeltas(ERC6909UnsettledDeltas { count })
+                if count == uint!(2_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn lock_reverts_when_already_locked(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._lock(alice, |token| token._lock(bob, |_| Ok(())))
+            .motsu_expect_err("should not lock twice");
+
+        assert!(matches!(
+            err,
+            Error::AlreadyLocked(ERC6909AlreadyLocked { locker })
+                if locker == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn take_reverts_when_not_locker(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .take(ID, AMOUNT)
+            .motsu_expect_err("should not take without the lock");
+
+        assert!(matches!(
+            err,
+            Error::NotLocker(ERC6909NotLocker { account }) if account == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn settle_reverts_when_balance_insufficient(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._lock(alice, |token| {
+                token.take(ID, AMOUNT)?;
+                token.erc6909._transfer(alice, bob, ID, AMOUNT)?;
+                token.settle(ID)
+            })
+            .motsu_expect_err("should not settle without the tokens");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(Erc6909InsufficientBalance {
+                sender,
+                needed,
+                ..
+            }) if sender == alice && needed == AMOUNT
+        ));
+    }
+
+    #[motsu::test]
+    fn account_delta_tracks_nonzero_deltas(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._account_delta(ID, signed(AMOUNT));
+        contract.sender(alice)._account_delta(OTHER_ID, -signed(AMOUNT));
+        assert_eq!(uint!(2_U256), contract.sender(alice).nonzero_deltas.get());
+
+        contract.sender(alice)._account_delta(ID, -signed(AMOUNT));
+        assert_eq!(uint!(1_U256), contract.sender(alice).nonzero_deltas.get());
+        assert!(contract.sender(alice).delta(ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn lock_settles_ids_in_any_order(
+        contract: Contract<Erc6909FlashAccounting>,
+        locker: Contract<MockLocker>,
+        alice: Address,
+    ) {
+        let data = script(&[ID, OTHER_ID, THIRD_ID], &[THIRD_ID, ID, OTHER_ID]);
+
+        let result = locker
+            .sender(alice)
+            .start(contract.address(), data.clone())
+            .motsu_expect("should settle every id");
+
+        assert_eq!(data, result);
+        assert!(contract.sender(alice).locker().is_zero());
+        for id in [ID, OTHER_ID, THIRD_ID] {
+            assert!(contract.sender(alice).delta(id).is_zero());
+            assert!(contract
+                .sender(alice)
+                .balance_of(locker.address(), id)
+                .is_zero());
+        }
+    }
+
+    #[motsu::test]
+    fn lock_reverts_when_an_id_is_unsettled(
+        contract: Contract<Erc6909FlashAccounting>,
+        locker: Contract<MockLocker>,
+        alice: Address,
+    ) {
+        let err = locker
+            .sender(alice)
+            .start(contract.address(), script(&[ID, OTHER_ID], &[OTHER_ID]))
+            .motsu_expect_err("should not release lock with a debt");
+
+        assert_eq!(
+            ERC6909UnsettledDeltas { count: uint!(1_U256) }.abi_encode(),
+            err
+        );
+    }
+
+    #[motsu::test]
+    fn settle_is_a_no_op_without_delta(
+        contract: Contract<Erc6909FlashAccounting>,
+        locker: Contract<MockLocker>,
+        alice: Address,
+    ) {
+        let data = script(&[], &[ID, OTHER_ID]);
+
+        let result = locker
+            .sender(alice)
+            .start(contract.address(), data.clone())
+            .motsu_expect("should settle zero deltas");
+
+        assert_eq!(data, result);
+        assert!(contract.sender(alice).locker().is_zero());
+    }
+
+    #[motsu::test]
+    fn lock_reverts_when_nested(
+        contract: Contract<Erc6909FlashAccounting>,
+        locker: Contract<NestedLocker>,
+        alice: Address,
+    ) {
+        let err = locker
+            .sender(alice)
+            .start(contract.address(), Bytes::default())
+            .motsu_expect_err("should not lock twice");
+
+        let reason =
+            ERC6909AlreadyLocked { locker: locker.address() }.abi_encode();
+        assert_eq!(
+            ERC6909UnlockCallbackFailed {
+                locker: locker.address(),
+                reason: reason.into(),
+            }
+            .abi_encode(),
+            err
+        );
+    }
+
+    #[motsu::test]
+    fn lock_reverts_when_callback_reverts(
+        contract: Contract<Erc6909FlashAccounting>,
+        locker: Contract<RevertingLocker>,
+        alice: Address,
+    ) {
+        let err = locker
+            .sender(alice)
+            .start(contract.address(), Bytes::default())
+            .motsu_expect_err("should bubble up the callback's revert");
+
+        assert_eq!(
+            ERC6909UnlockCallbackFailed {
+                locker: locker.address(),
+                reason: b"callback failed".to_vec().into(),
+            }
+            .abi_encode(),
+            err
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909FlashAccounting as IErc6909FlashAccounting>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xe2c9e3f9");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909FlashAccounting>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909FlashAccounting as IErc6909FlashAccounting>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909FlashAccounting as IErc165>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909FlashAccounting as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
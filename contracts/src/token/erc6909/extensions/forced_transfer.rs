@@ -0,0 +1,539 @@
+//! Extension of ERC-6909 that allows tokens to be moved from any account
+//! without its approval, e.g. by a regulator recovering the tokens of a lost
+//! wallet.
+//!
+//! Forced transfers are disabled unless explicitly enabled when the contract
+//! is initialized with [`Erc6909ForcedTransfer::constructor`], and can't be
+//! enabled afterwards.
+//!
+//! Note that [`Erc6909ForcedTransfer::_forced_transfer`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError, evm, msg, prelude::*, storage::StorageBool,
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `operator` forces the transfer of `amount` tokens of
+        /// type `id` from `from` to `to`.
+        ///
+        /// * `operator` - Account forcing the transfer.
+        /// * `from` - Account the tokens are taken from.
+        /// * `to` - Account receiving the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens transferred.
+        /// * `reason_code` - Code of the reason of the transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ForcedTransfer(
+            address indexed operator,
+            address indexed from,
+            address indexed to,
+            uint256 id,
+            uint256 amount,
+            bytes32 reason_code
+        );
+    }
+
+    sol! {
+        /// Indicates that forced transfers were not enabled when the contract
+        /// was initialized.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ForcedTransfersDisabled();
+    }
+}
+
+/// An [`Erc6909ForcedTransfer`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that forced transfers are disabled.
+    ForcedTransfersDisabled(ERC6909ForcedTransfersDisabled),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909ForcedTransfer`] contract.
+#[storage]
+pub struct Erc6909ForcedTransfer {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Whether forced transfers are enabled.
+    pub(crate) forced_transfers_enabled: StorageBool,
+}
+
+impl_as_erc6909!(Erc6909ForcedTransfer, erc6909);
+
+/// Required interface of an [`Erc6909ForcedTransfer`] contract.
+#[interface_id]
+pub trait IErc6909ForcedTransfer: IErc165 {
+    /// Returns whether tokens can be moved from any account with forced
+    /// transfers.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn forced_transfers_enabled(&self) -> bool;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909ForcedTransfer, IErc165)]
+impl Erc6909ForcedTransfer {}
+
+#[public]
+impl IErc6909ForcedTransfer for Erc6909ForcedTransfer {
+    fn forced_transfers_enabled(&self) -> bool {
+        self.forced_transfers_enabled.get()
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909ForcedTransfer {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909ForcedTransfer {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909ForcedTransfer>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909ForcedTransfer {
+    /// Initializes the contract, enabling forced transfers if
+    /// `forced_transfers_enabled` is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `forced_transfers_enabled` - Whether forced transfers are enabled.
+    pub fn constructor(&mut self, forced_transfers_enabled: bool) {
+        self.forced_transfers_enabled.set(forced_transfers_enabled);
+    }
+
+    /// Moves `amount` tokens of type `id` from `from` to `to`, without
+    /// checking the approval of `from`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account the tokens are taken from.
+    /// * `to` - Account receiving the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `reason_code` - Code of the reason of the transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ForcedTransfersDisabled`] - If forced transfers were not
+    ///   enabled when the contract was initialized.
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of `from`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`ForcedTransfer`].
+    pub fn _forced_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        reason_code: FixedBytes<32>,
+    ) -> Result<(), Error> {
+        if !self.forced_transfers_enabled() {
+            return Err(Error::ForcedTransfersDisabled(
+                ERC6909ForcedTransfersDisabled {},
+            ));
+        }
+
+        self.erc6909._transfer(from, to, id, amount)?;
+
+        evm::log(ForcedTransfer {
+            operator: msg::sender(),
+            from,
+            to,
+            id,
+            amount,
+            reason_code,
+        });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909ForcedTransfer {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256,
+    };
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909ForcedTransfer {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const REASON_CODE: B256 = fixed_bytes!(
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+
+    fn init(
+        contract: &Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+        forced_transfers_enabled: bool,
+    ) {
+        contract.init(alice, |contract| {
+            contract.constructor(forced_transfers_enabled);
+        });
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+    }
+
+    #[motsu::test]
+    fn forced_transfer_bypasses_allowance(
+        contract: Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+        bob: Address,
+        regulator: Address,
+    ) {
+        init(&contract, alice, true);
+        assert!(contract.sender(regulator).forced_transfers_enabled());
+
+        contract
+            .sender(regulator)
+            ._forced_transfer(alice, bob, ID, AMOUNT, REASON_CODE)
+            .motsu_expect("should force transfer");
+
+        assert!(contract.sender(regulator).balance_of(alice, ID).is_zero());
+        assert_eq!(AMOUNT, contract.sender(regulator).balance_of(bob, ID));
+        contract.assert_emitted(&ForcedTransfer {
+            operator: regulator,
+            from: alice,
+            to: bob,
+            id: ID,
+            amount: AMOUNT,
+            reason_code: REASON_CODE,
+        });
+    }
+
+    #[motsu::test]
+    fn forced_transfer_reverts_when_disabled(
+        contract: Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+        bob: Address,
+        regulator: Address,
+    ) {
+        init(&contract, alice, false);
+        assert!(!contract.sender(regulator).forced_transfers_enabled());
+
+        let err = contract
+            .sender(regulator)
+            ._forced_transfer(alice, bob, ID, AMOUNT, REASON_CODE)
+            .motsu_expect_err("should not force transfer when disabled");
+
+        assert!(matches!(err, Error::ForcedTransfersDisabled(_)));
+        assert_eq!(AMOUNT, contract.sender(regulator).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn forced_transfer_reverts_on_insufficient_balance(
+        contract: Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+        bob: Address,
+        regulator: Address,
+    ) {
+        init(&contract, alice, true);
+
+        let err = contract
+            .sender(regulator)
+            ._forced_transfer(alice, bob, ID, AMOUNT + U256::ONE, REASON_CODE)
+            .motsu_expect_err("should not force transfer more than balance");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[cfg(not(feature = "burn-on-zero-transfer"))]
+    #[motsu::test]
+    fn forced_transfer_reverts_on_zero_receiver(
+        contract: Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+        regulator: Address,
+    ) {
+        init(&contract, alice, true);
+
+        let err = contract
+            .sender(regulator)
+            ._forced_transfer(alice, Address::ZERO, ID, AMOUNT, REASON_CODE)
+            .motsu_expect_err("should not force transfer to zero address");
+
+        assert!(matches!(err, Error::InvalidReceiver(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909ForcedTransfer as IErc6909ForcedTransfer>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x47f9cb11");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909ForcedTransfer>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ForcedTransfer as IErc6909ForcedTransfer>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ForcedTransfer as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
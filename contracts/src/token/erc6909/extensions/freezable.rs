@@ -0,0 +1,615 @@
+//! Extension of ERC-6909 that allows the position of an account in a specific
+//! token id to be frozen.
+//!
+//! The balance of a frozen account in a token id can't change: its tokens of
+//! that id can't be transferred, minted or burned until it is unfrozen, which
+//! is required by securities-style issuances where a single investor's
+//! position must be immobilized.
+//!
+//! Note that [`Erc6909Freezable::_freeze`] and [`Erc6909Freezable::_unfreeze`]
+//! are not exposed by default. You should expose them manually in your
+//! contract's abi, guarded by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageMap},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the position of `account` in token `id` is frozen.
+        ///
+        /// * `account` - Frozen account.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event AccountFrozen(address indexed account, uint256 indexed id);
+
+        /// Emitted when the position of `account` in token `id` is unfrozen.
+        ///
+        /// * `account` - Unfrozen account.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event AccountUnfrozen(address indexed account, uint256 indexed id);
+    }
+
+    sol! {
+        /// Indicates that the balance of `account` in token `id` can't
+        /// change, because its position is frozen.
+        ///
+        /// * `account` - Frozen account.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909FrozenAccount(address account, uint256 id);
+    }
+}
+
+/// An [`Erc6909Freezable`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that the position of an account in a token id is frozen.
+    FrozenAccount(ERC6909FrozenAccount),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Freezable`] contract.
+#[storage]
+pub struct Erc6909Freezable {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from account to token id to whether its position is frozen.
+    pub(crate) frozen: StorageMap<Address, StorageMap<U256, StorageBool>>,
+}
+
+impl_as_erc6909!(Erc6909Freezable, erc6909);
+
+/// Required interface of an [`Erc6909Freezable`] contract.
+#[interface_id]
+pub trait IErc6909Freezable: IErc165 {
+    /// Returns whether the position of `account` in token `id` is frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account holding the tokens.
+    /// * `id` - Token id as a number.
+    fn is_frozen(&self, account: Address, id: U256) -> bool;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909Freezable, IErc165)]
+impl Erc6909Freezable {}
+
+#[public]
+impl IErc6909Freezable for Erc6909Freezable {
+    fn is_frozen(&self, account: Address, id: U256) -> bool {
+        self.frozen.get(account).get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Freezable {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Freezable {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Freezable>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Freezable {
+    /// Freezes the position of `account` in token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account holding the tokens.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * [`AccountFrozen`].
+    pub fn _freeze(&mut self, account: Address, id: U256) {
+        self.frozen.setter(account).setter(id).set(true);
+        evm::log(AccountFrozen { account, id });
+    }
+
+    /// Unfreezes the position of `account` in token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account holding the tokens.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * [`AccountUnfrozen`].
+    pub fn _unfreeze(&mut self, account: Address, id: U256) {
+        self.frozen.setter(account).setter(id).set(false);
+        evm::log(AccountUnfrozen { account, id });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909Freezable {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that prevents the balance of
+    /// frozen positions from changing, including through mints and burns.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenAccount`] - If the position of `from` or `to` in one of
+    ///   `ids` is frozen.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        for account in [from, to] {
+            if account.is_zero() {
+                continue;
+            }
+            if let Some(&id) =
+                ids.iter().find(|&&id| self.is_frozen(account, id))
+            {
+                return Err(Error::FrozenAccount(ERC6909FrozenAccount {
+                    account,
+                    id,
+                }));
+            }
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909Freezable {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Freezable {}
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    fn mint_and_freeze(contract: &Contract<Erc6909Freezable>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint_batch(alice, vec![ID, OTHER_ID], vec![AMOUNT, AMOUNT])
+            .motsu_expect("should mint tokens");
+        contract.sender(alice)._freeze(alice, ID);
+    }
+
+    #[motsu::test]
+    fn freeze_emits_event(
+        contract: Contract<Erc6909Freezable>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._freeze(alice, ID);
+
+        assert!(contract.sender(alice).is_frozen(alice, ID));
+        assert!(!contract.sender(alice).is_frozen(alice, OTHER_ID));
+        contract.assert_emitted(&AccountFrozen { account: alice, id: ID });
+
+        contract.sender(alice)._unfreeze(alice, ID);
+
+        assert!(!contract.sender(alice).is_frozen(alice, ID));
+        contract.assert_emitted(&AccountUnfrozen { account: alice, id: ID });
+    }
+
+    #[motsu::test]
+    fn transfer_reverts_when_sender_frozen(
+        contract: Contract<Erc6909Freezable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_freeze(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect_err("should not transfer frozen tokens");
+
+        assert!(matches!(
+            err,
+            Error::FrozenAccount(ERC6909FrozenAccount { account, id })
+                if account == alice && id == ID
+        ));
+
+        contract
+            .sender(alice)
+            .transfer(bob, OTHER_ID, AMOUNT)
+            .motsu_expect("should transfer tokens of another id");
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_receiver_frozen(
+        contract: Contract<Erc6909Freezable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract.sender(alice)._freeze(bob, ID);
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set Bob as operator");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, AMOUNT)
+            .motsu_expect_err("should not transfer to a frozen account");
+
+        assert!(matches!(
+            err,
+            Error::FrozenAccount(ERC6909FrozenAccount { account, id })
+                if account == bob && id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn mint_and_burn_revert_when_frozen(
+        contract: Contract<Erc6909Freezable>,
+        alice: Address,
+    ) {
+        mint_and_freeze(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect_err("should not mint to a frozen account");
+        assert!(matches!(err, Error::FrozenAccount(_)));
+
+        let err = contract
+            .sender(alice)
+            ._burn(alice, ID, AMOUNT)
+            .motsu_expect_err("should not burn from a frozen account");
+        assert!(matches!(err, Error::FrozenAccount(_)));
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn transfer_succeeds_when_unfrozen(
+        contract: Contract<Erc6909Freezable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_and_freeze(&contract, alice);
+        contract.sender(alice)._unfreeze(alice, ID);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer tokens");
+
+        assert_eq!(contract.sender(alice).balance_of(bob, ID), AMOUNT);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Freezable as IErc6909Freezable>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xb29f9a61");
+        assert_eq!(actual, expected);
+    }
+}
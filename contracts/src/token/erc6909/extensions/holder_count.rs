@@ -0,0 +1,488 @@
+//! Extension of ERC-6909 that tracks the number of accounts holding a
+//! non-zero balance of each token id.
+//!
+//! The count is increased when an account balance goes from zero to a
+//! non-zero value, and decreased when it goes back to zero.
+//!
+//! CAUTION: This extension should not be added in an upgrade to an already
+//! deployed contract.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+use stylus_sdk::{
+    msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, Erc6909, Error, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        math::storage::{AddAssignUnchecked, SubAssignUnchecked},
+    },
+};
+
+/// State of an [`Erc6909HolderCount`] contract.
+#[storage]
+pub struct Erc6909HolderCount {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to the number of accounts holding it.
+    pub(crate) holder_count: StorageMap<U256, StorageU256>,
+}
+
+impl_as_erc6909!(Erc6909HolderCount, erc6909);
+
+/// Required interface of an [`Erc6909HolderCount`] contract.
+#[interface_id]
+pub trait IErc6909HolderCount: IErc165 {
+    /// Returns the number of accounts holding a non-zero balance of token
+    /// `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn holder_count(&self, id: U256) -> U256;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909HolderCount, IErc165)]
+impl Erc6909HolderCount {}
+
+#[public]
+impl IErc6909HolderCount for Erc6909HolderCount {
+    fn holder_count(&self, id: U256) -> U256 {
+        self.holder_count.get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909HolderCount {
+    type Error = erc6909::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909HolderCount {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909HolderCount>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909HolderCount {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909HolderCount {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if to.is_zero() {
+            return Err(erc6909::Error::InvalidReceiver(
+                erc6909::ERC6909InvalidReceiver { receiver: to },
+            ));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if from.is_zero() {
+            return Err(erc6909::Error::InvalidSender(
+                erc6909::ERC6909InvalidSender { sender: from },
+            ));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that updates the number of
+    /// holders of each token id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc6909::Error::InvalidArrayLength`] - If length of `ids` is not
+    ///   equal to length of `amounts`.
+    /// * [`erc6909::Error::InsufficientBalance`] - If `amount` is greater than
+    ///   the balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if from == to {
+            return self.erc6909._update(from, to, ids, amounts);
+        }
+
+        // The same id may appear several times in a batch, so balances are
+        // compared only once per id, before and after the whole update.
+        let mut unique_ids: Vec<U256> = Vec::with_capacity(ids.len());
+        for &id in &ids {
+            if !unique_ids.contains(&id) {
+                unique_ids.push(id);
+            }
+        }
+        let balances_before: Vec<(U256, U256)> = unique_ids
+            .iter()
+            .map(|&id| {
+                (
+                    self.erc6909.balance_of(from, id),
+                    self.erc6909.balance_of(to, id),
+                )
+            })
+            .collect();
+
+        self.erc6909._update(from, to, ids, amounts)?;
+
+        for (id, (from_before, to_before)) in
+            unique_ids.into_iter().zip(balances_before)
+        {
+            if !from.is_zero()
+                && !from_before.is_zero()
+                && self.erc6909.balance_of(from, id).is_zero()
+            {
+                self.holder_count
+                    .setter(id)
+                    .sub_assign_unchecked(U256::from(1));
+            }
+
+            if !to.is_zero()
+                && to_before.is_zero()
+                && !self.erc6909.balance_of(to, id).is_zero()
+            {
+                self.holder_count
+                    .setter(id)
+                    .add_assign_unchecked(U256::from(1));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909HolderCount {
+    type Error = erc6909::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+
+    unsafe impl TopLevelStorage for Erc6909HolderCount {}
+
+    #[motsu::test]
+    fn mint_counts_new_holders_once(
+        contract: Contract<Erc6909HolderCount>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+        contract
+            .sender(alice)
+            ._mint(bob, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Bob");
+
+        assert_eq!(
+            contract.sender(alice).holder_count(TOKEN_ID),
+            uint!(2_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_updates_holder_count(
+        contract: Contract<Erc6909HolderCount>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, uint!(4_U256))
+            .motsu_expect("should transfer to Bob");
+        assert_eq!(
+            contract.sender(alice).holder_count(TOKEN_ID),
+            uint!(2_U256)
+        );
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, uint!(6_U256))
+            .motsu_expect("should transfer to Bob");
+        assert_eq!(
+            contract.sender(alice).holder_count(TOKEN_ID),
+            uint!(1_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn self_transfer_keeps_holder_count(
+        contract: Contract<Erc6909HolderCount>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            .transfer(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should transfer to herself");
+
+        assert_eq!(
+            contract.sender(alice).holder_count(TOKEN_ID),
+            uint!(1_U256)
+        );
+    }
+
+    #[motsu::test]
+    fn burn_batch_with_repeated_ids(
+        contract: Contract<Erc6909HolderCount>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![TOKEN_ID, TOKEN_ID],
+                vec![uint!(5_U256), uint!(5_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+        assert_eq!(
+            contract.sender(alice).holder_count(TOKEN_ID),
+            uint!(1_U256)
+        );
+
+        contract
+            .sender(alice)
+            ._burn_batch(
+                alice,
+                vec![TOKEN_ID, TOKEN_ID],
+                vec![uint!(5_U256), uint!(5_U256)],
+            )
+            .motsu_expect("should burn Alice's tokens");
+        assert_eq!(contract.sender(alice).holder_count(TOKEN_ID), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909HolderCount as IErc6909HolderCount>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x87a58d80");
+        assert_eq!(actual, expected);
+    }
+}
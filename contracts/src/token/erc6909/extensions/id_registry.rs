@@ -0,0 +1,1693 @@
+//! Extension of ERC-6909 that requires token ids to be registered before any
+//! of their tokens can be minted.
+//!
+//! Ids are registered by issuers through [`IErc6909IdRegistry::register_id`],
+//! optionally along with a metadata string describing them. Minting tokens of
+//! an id that was never registered fails, which protects against mints to
+//! mistyped or otherwise junk ids.
+//!
+//! Issuers that don't need to pick ids themselves can call
+//! [`IErc6909IdRegistry::create`] instead, which registers the next id of a
+//! sequence starting at [`FIRST_CREATED_ID`]. Ids from [`FIRST_CREATED_ID`]
+//! on are reserved for this sequence, and can't be registered by hand, so
+//! that created ids never collide with registered ones. The account that
+//! registered each id is recorded as its creator.
+//!
+//! Creators manage their own ids only: they alone can mint them through
+//! [`IErc6909IdRegistry::mint`], update their metadata, and cap the amount of
+//! tokens that can ever be minted of them. This allows a single deployment to
+//! be shared by several independent issuers. Other per-id settings, like
+//! royalties, can be scoped the same way with
+//! [`Erc6909IdRegistry::_check_creator`].
+//!
+//! Creators can hand over the management of one of their ids to another
+//! account in two steps: [`IErc6909IdRegistry::transfer_id_admin`] nominates
+//! the new admin, which becomes the creator of the id once it calls
+//! [`IErc6909IdRegistry::accept_id_admin`].
+//!
+//! Note that [`Erc6909IdRegistry::_set_issuer`] is not exposed by default. You
+//! should expose it manually in your contract's abi, guarded by proper access
+//! control.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{
+        StorageAddress, StorageBool, StorageMap, StorageString, StorageU256,
+    },
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        introspection::erc165::IErc165, math::storage::AddAssignUnchecked,
+    },
+};
+
+/// First id assigned by [`IErc6909IdRegistry::create`], i.e. `2^255`.
+///
+/// Ids from this one on are reserved for [`IErc6909IdRegistry::create`].
+pub const FIRST_CREATED_ID: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `account` is granted or revoked the right to register
+        /// token ids.
+        ///
+        /// * `account` - Account whose issuer status changed.
+        /// * `approved` - Whether `account` is now an issuer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IssuerSet(address indexed account, bool approved);
+
+        /// Emitted when token `id` is registered by `issuer`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `issuer` - Account that registered the id.
+        /// * `metadata` - Metadata describing the id, possibly empty.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IdRegistered(
+            uint256 indexed id,
+            address indexed issuer,
+            string metadata
+        );
+
+        /// Emitted when token `id` is created by `creator` through
+        /// [`super::IErc6909IdRegistry::create`].
+        ///
+        /// * `creator` - Account that created the id.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IdCreated(address indexed creator, uint256 indexed id);
+
+        /// Emitted when the metadata of token `id` is set to `metadata`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `metadata` - Metadata describing the id, possibly empty.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IdMetadataSet(uint256 indexed id, string metadata);
+
+        /// Emitted when the amount of tokens of type `id` that can ever be
+        /// minted is capped to `cap`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `cap` - Maximum amount of tokens minted, or zero if uncapped.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MintCapSet(uint256 indexed id, uint256 cap);
+
+        /// Emitted when the creator of token `id` starts handing over its
+        /// management to `new_admin`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `previous_admin` - Current creator of the id.
+        /// * `new_admin` - Account nominated to manage the id, or
+        ///   [`alloy_primitives::Address::ZERO`] if the handover is
+        ///   cancelled.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IdAdminTransferStarted(
+            uint256 indexed id,
+            address indexed previous_admin,
+            address indexed new_admin
+        );
+
+        /// Emitted when the management of token `id` is handed over from
+        /// `previous_admin` to `new_admin`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `previous_admin` - Previous creator of the id.
+        /// * `new_admin` - New creator of the id.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event IdAdminTransferred(
+            uint256 indexed id,
+            address indexed previous_admin,
+            address indexed new_admin
+        );
+    }
+
+    sol! {
+        /// Indicates that tokens of type `id` were minted before `id` was
+        /// registered.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnknownId(uint256 id);
+
+        /// Indicates an attempt to register token `id` twice.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909IdAlreadyRegistered(uint256 id);
+
+        /// Indicates an attempt to register token `id` by hand, while it is
+        /// reserved for created ids.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ReservedId(uint256 id);
+
+        /// Indicates that `account` is not allowed to register token ids.
+        ///
+        /// * `account` - Account trying to register an id.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedIssuer(address account);
+
+        /// Indicates that `account` is not the creator of token `id`.
+        ///
+        /// * `account` - Account trying to manage the id.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedCreator(address account, uint256 id);
+
+        /// Indicates that minting tokens of type `id` would bring the amount
+        /// of its tokens ever minted to `needed`, above its mint cap.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `cap` - Maximum amount of tokens minted.
+        /// * `needed` - Amount of tokens minted in total, including the
+        ///   tokens being minted.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909MintCapExceeded(uint256 id, uint256 cap, uint256 needed);
+
+        /// Indicates that `account` was not nominated to manage token `id`.
+        ///
+        /// * `account` - Account trying to accept the management of the id.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedPendingAdmin(address account, uint256 id);
+    }
+}
+
+/// An [`Erc6909IdRegistry`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that tokens of an unregistered id were minted.
+    UnknownId(ERC6909UnknownId),
+    /// Indicates that an id was registered twice.
+    IdAlreadyRegistered(ERC6909IdAlreadyRegistered),
+    /// Indicates that an id reserved for created ids was registered by hand.
+    ReservedId(ERC6909ReservedId),
+    /// Indicates that the caller is not allowed to register ids.
+    UnauthorizedIssuer(ERC6909UnauthorizedIssuer),
+    /// Indicates that the caller is not the creator of an id.
+    UnauthorizedCreator(ERC6909UnauthorizedCreator),
+    /// Indicates that a mint would exceed the mint cap of an id.
+    MintCapExceeded(ERC6909MintCapExceeded),
+    /// Indicates that the caller was not nominated to manage an id.
+    UnauthorizedPendingAdmin(ERC6909UnauthorizedPendingAdmin),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909IdRegistry`] contract.
+#[storage]
+pub struct Erc6909IdRegistry {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from account to whether it can register token ids.
+    pub(crate) issuers: StorageMap<Address, StorageBool>,
+    /// Mapping from token id to whether it was registered.
+    pub(crate) registered: StorageMap<U256, StorageBool>,
+    /// Mapping from token id to the metadata it was registered with.
+    pub(crate) id_metadata: StorageMap<U256, StorageString>,
+    /// Mapping from token id to the account that registered it.
+    pub(crate) creators: StorageMap<U256, StorageAddress>,
+    /// Number of ids assigned by [`IErc6909IdRegistry::create`], i.e.
+    /// offset of the next one from [`FIRST_CREATED_ID`].
+    pub(crate) next_id: StorageU256,
+    /// Mapping from token id to the maximum amount of tokens minted.
+    pub(crate) mint_caps: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the amount of tokens minted so far.
+    pub(crate) minted: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the account nominated to manage it.
+    pub(crate) pending_admins: StorageMap<U256, StorageAddress>,
+}
+
+impl_as_erc6909!(Erc6909IdRegistry, erc6909);
+
+/// Required interface of an [`Erc6909IdRegistry`] contract.
+#[interface_id]
+pub trait IErc6909IdRegistry: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns true if token `id` was registered, and its tokens can be
+    /// minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn is_registered(&self, id: U256) -> bool;
+
+    /// Returns the metadata token `id` was registered with, or an empty string
+    /// if it was registered without metadata or isn't registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn id_metadata(&self, id: U256) -> String;
+
+    /// Returns true if `account` is allowed to register token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    fn is_issuer(&self, account: Address) -> bool;
+
+    /// Returns the account that registered token `id`, or the last account
+    /// its management was handed over to, or [`Address::ZERO`] if `id` isn't
+    /// registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn creator(&self, id: U256) -> Address;
+
+    /// Returns the maximum amount of tokens of type `id` that can ever be
+    /// minted, or zero if it is uncapped.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn mint_cap(&self, id: U256) -> U256;
+
+    /// Returns the amount of tokens of type `id` minted so far. Burns don't
+    /// decrease it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn minted(&self, id: U256) -> U256;
+
+    /// Returns the account nominated to manage token `id`, or
+    /// [`Address::ZERO`] if no handover of `id` is pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn pending_id_admin(&self, id: U256) -> Address;
+
+    /// Registers token `id` with `metadata`, allowing its tokens to be
+    /// minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedIssuer`] - If the caller is not an issuer.
+    /// * [`Error::ReservedId`] - If `id` is reserved for created ids, i.e. not
+    ///   lower than [`FIRST_CREATED_ID`].
+    /// * [`Error::IdAlreadyRegistered`] - If `id` is already registered.
+    ///
+    /// # Events
+    ///
+    /// * [`IdRegistered`].
+    fn register_id(
+        &mut self,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Self::Error>;
+
+    /// Registers the next id of the sequence starting at
+    /// [`FIRST_CREATED_ID`] with `metadata`, and returns it.
+    ///
+    /// Ids registered through [`Self::register_id`] are lower than
+    /// [`FIRST_CREATED_ID`], so they never collide with created ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedIssuer`] - If the caller is not an issuer.
+    ///
+    /// # Events
+    ///
+    /// * [`IdRegistered`].
+    /// * [`IdCreated`].
+    fn create(&mut self, metadata: String) -> Result<U256, Self::Error>;
+
+    /// Sets the metadata of token `id` to `metadata`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedCreator`] - If the caller is not the creator of
+    ///   `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`IdMetadataSet`].
+    fn set_id_metadata(
+        &mut self,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Self::Error>;
+
+    /// Caps the amount of tokens of type `id` that can ever be minted to
+    /// `cap`. A `cap` of zero removes the cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `cap` - Maximum amount of tokens minted, or zero if uncapped.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedCreator`] - If the caller is not the creator of
+    ///   `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`MintCapSet`].
+    fn set_mint_cap(&mut self, id: U256, cap: U256) -> Result<(), Self::Error>;
+
+    /// Mints `amount` tokens of type `id` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedCreator`] - If the caller is not the creator of
+    ///   `id`.
+    /// * [`Error::MintCapExceeded`] - If the mint would exceed the mint cap of
+    ///   `id`.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Starts handing over the management of token `id` to `new_admin`,
+    /// which becomes its creator once it calls [`Self::accept_id_admin`].
+    /// Replaces the pending handover of `id` if there is one.
+    ///
+    /// Setting `new_admin` to [`Address::ZERO`] is allowed; this can be used
+    /// to cancel a pending handover.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `new_admin` - Account nominated to manage `id`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedCreator`] - If the caller is not the creator of
+    ///   `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`IdAdminTransferStarted`].
+    fn transfer_id_admin(
+        &mut self,
+        id: U256,
+        new_admin: Address,
+    ) -> Result<(), Self::Error>;
+
+    /// Accepts the management of token `id`, making the caller its creator.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedPendingAdmin`] - If the caller is not the pending
+    ///   admin of `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`IdAdminTransferred`].
+    fn accept_id_admin(&mut self, id: U256) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909IdRegistry<Error = Error>,
+    IErc165
+)]
+impl Erc6909IdRegistry {}
+
+#[public]
+impl IErc6909IdRegistry for Erc6909IdRegistry {
+    type Error = Error;
+
+    fn is_registered(&self, id: U256) -> bool {
+        self.registered.get(id)
+    }
+
+    fn id_metadata(&self, id: U256) -> String {
+        self.id_metadata.get(id).get_string()
+    }
+
+    fn is_issuer(&self, account: Address) -> bool {
+        self.issuers.get(account)
+    }
+
+    fn creator(&self, id: U256) -> Address {
+        self.creators.get(id)
+    }
+
+    fn mint_cap(&self, id: U256) -> U256 {
+        self.mint_caps.get(id)
+    }
+
+    fn minted(&self, id: U256) -> U256 {
+        self.minted.get(id)
+    }
+
+    fn pending_id_admin(&self, id: U256) -> Address {
+        self.pending_admins.get(id)
+    }
+
+    fn register_id(
+        &mut self,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Self::Error> {
+        let issuer = msg::sender();
+        self._check_issuer(issuer)?;
+        self._register_id(issuer, id, metadata)
+    }
+
+    fn create(&mut self, metadata: String) -> Result<U256, Self::Error> {
+        let creator = msg::sender();
+        self._check_issuer(creator)?;
+        self._create(creator, metadata)
+    }
+
+    fn set_id_metadata(
+        &mut self,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Self::Error> {
+        self._check_creator(msg::sender(), id)?;
+        self._set_id_metadata(id, metadata);
+        Ok(())
+    }
+
+    fn set_mint_cap(&mut self, id: U256, cap: U256) -> Result<(), Self::Error> {
+        self._check_creator(msg::sender(), id)?;
+        self._set_mint_cap(id, cap);
+        Ok(())
+    }
+
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._check_creator(msg::sender(), id)?;
+        self._mint(to, id, amount)
+    }
+
+    fn transfer_id_admin(
+        &mut self,
+        id: U256,
+        new_admin: Address,
+    ) -> Result<(), Self::Error> {
+        let previous_admin = msg::sender();
+        self._check_creator(previous_admin, id)?;
+        self.pending_admins.setter(id).set(new_admin);
+        evm::log(IdAdminTransferStarted { id, previous_admin, new_admin });
+        Ok(())
+    }
+
+    fn accept_id_admin(&mut self, id: U256) -> Result<(), Self::Error> {
+        let sender = msg::sender();
+        if self.pending_id_admin(id) != sender {
+            return Err(Error::UnauthorizedPendingAdmin(
+                ERC6909UnauthorizedPendingAdmin { account: sender, id },
+            ));
+        }
+        self._transfer_id_admin(id, sender);
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909IdRegistry {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909IdRegistry {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909IdRegistry>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909IdRegistry {
+    /// Grants or revokes the right of `account` to register token ids.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account whose issuer status changes.
+    /// * `approved` - Whether `account` can register ids.
+    ///
+    /// # Events
+    ///
+    /// * [`IssuerSet`].
+    pub fn _set_issuer(&mut self, account: Address, approved: bool) {
+        self.issuers.setter(account).set(approved);
+        evm::log(IssuerSet { account, approved });
+    }
+
+    /// Registers token `id` with `metadata` on behalf of `issuer`, without
+    /// checking that `issuer` is allowed to register ids.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `issuer` - Account registering the id.
+    /// * `id` - Token id as a number.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ReservedId`] - If `id` is reserved for created ids, i.e. not
+    ///   lower than [`FIRST_CREATED_ID`].
+    /// * [`Error::IdAlreadyRegistered`] - If `id` is already registered.
+    ///
+    /// # Events
+    ///
+    /// * [`IdRegistered`].
+    pub fn _register_id(
+        &mut self,
+        issuer: Address,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Error> {
+        if id >= FIRST_CREATED_ID {
+            return Err(Error::ReservedId(ERC6909ReservedId { id }));
+        }
+
+        self._record_id(issuer, id, metadata)
+    }
+
+    /// Records `issuer` as the creator of token `id`, registered with
+    /// `metadata`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `issuer` - Account registering the id.
+    /// * `id` - Token id as a number.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::IdAlreadyRegistered`] - If `id` is already registered.
+    ///
+    /// # Events
+    ///
+    /// * [`IdRegistered`].
+    fn _record_id(
+        &mut self,
+        issuer: Address,
+        id: U256,
+        metadata: String,
+    ) -> Result<(), Error> {
+        if self.is_registered(id) {
+            return Err(Error::IdAlreadyRegistered(
+                ERC6909IdAlreadyRegistered { id },
+            ));
+        }
+
+        self.registered.setter(id).set(true);
+        self.creators.setter(id).set(issuer);
+        if !metadata.is_empty() {
+            self.id_metadata.setter(id).set_str(&metadata);
+        }
+        evm::log(IdRegistered { id, issuer, metadata });
+
+        Ok(())
+    }
+
+    /// Registers the next id of the sequence starting at
+    /// [`FIRST_CREATED_ID`] with `metadata` on behalf of `creator`, without
+    /// checking that `creator` is allowed to register ids, and returns it.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `creator` - Account creating the id.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::IdAlreadyRegistered`] - If the next id is already registered,
+    ///   which can only happen if the contract registers ids from
+    ///   [`FIRST_CREATED_ID`] on by other means.
+    ///
+    /// # Events
+    ///
+    /// * [`IdRegistered`].
+    /// * [`IdCreated`].
+    ///
+    /// # Panics
+    ///
+    /// * If every id from [`FIRST_CREATED_ID`] on was already created.
+    pub fn _create(
+        &mut self,
+        creator: Address,
+        metadata: String,
+    ) -> Result<U256, Error> {
+        let id = FIRST_CREATED_ID
+            .checked_add(self.next_id.get())
+            .expect("created ids should not exceed `U256::MAX`");
+        self._record_id(creator, id, metadata)?;
+        // Overflow not possible: `next_id` is lower than `id`.
+        self.next_id.add_assign_unchecked(U256::ONE);
+        evm::log(IdCreated { creator, id });
+
+        Ok(id)
+    }
+
+    /// Sets the metadata of token `id` to `metadata`, without checking the
+    /// caller.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `metadata` - Metadata describing the id, possibly empty.
+    ///
+    /// # Events
+    ///
+    /// * [`IdMetadataSet`].
+    pub fn _set_id_metadata(&mut self, id: U256, metadata: String) {
+        self.id_metadata.setter(id).set_str(&metadata);
+        evm::log(IdMetadataSet { id, metadata });
+    }
+
+    /// Caps the amount of tokens of type `id` that can ever be minted to
+    /// `cap`, without checking the caller. A `cap` of zero removes the cap.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `cap` - Maximum amount of tokens minted, or zero if uncapped.
+    ///
+    /// # Events
+    ///
+    /// * [`MintCapSet`].
+    pub fn _set_mint_cap(&mut self, id: U256, cap: U256) {
+        self.mint_caps.setter(id).set(cap);
+        evm::log(MintCapSet { id, cap });
+    }
+
+    /// Hands over the management of token `id` to `new_admin`, making it the
+    /// creator of `id`, and cancels the pending handover of `id` if there is
+    /// one, without checking the caller.
+    ///
+    /// Internal function that can be exposed with access control if desired,
+    /// e.g. to recover ids whose creator lost its keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `new_admin` - New creator of `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`IdAdminTransferred`].
+    pub fn _transfer_id_admin(&mut self, id: U256, new_admin: Address) {
+        let previous_admin = self.creator(id);
+        self.pending_admins.delete(id);
+        self.creators.setter(id).set(new_admin);
+        evm::log(IdAdminTransferred { id, previous_admin, new_admin });
+    }
+
+    /// Checks that `account` is the creator of token `id`.
+    ///
+    /// Can be used to restrict other per-id settings to the creator of the
+    /// id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedCreator`] - If `account` is not the creator of
+    ///   `id`, or if `id` isn't registered.
+    pub fn _check_creator(
+        &self,
+        account: Address,
+        id: U256,
+    ) -> Result<(), Error> {
+        if self.is_registered(id) && self.creator(id) == account {
+            Ok(())
+        } else {
+            Err(Error::UnauthorizedCreator(ERC6909UnauthorizedCreator {
+                account,
+                id,
+            }))
+        }
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909IdRegistry {
+    fn _check_issuer(&self, account: Address) -> Result<(), Error> {
+        if self.is_issuer(account) {
+            Ok(())
+        } else {
+            Err(Error::UnauthorizedIssuer(ERC6909UnauthorizedIssuer {
+                account,
+            }))
+        }
+    }
+
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    /// Extended version of [`Erc6909::_update`] that prevents tokens of
+    /// unregistered ids from being minted, and mints from exceeding the mint
+    /// cap of their id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnknownId`] - If `from` is [`Address::ZERO`] and one of `ids`
+    ///   is not registered.
+    /// * [`Error::MintCapExceeded`] - If `from` is [`Address::ZERO`] and the
+    ///   mint would exceed the mint cap of one of `ids`.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            if let Some(&id) = ids.iter().find(|&&id| !self.is_registered(id)) {
+                return Err(Error::UnknownId(ERC6909UnknownId { id }));
+            }
+
+            for (&id, &amount) in ids.iter().zip(amounts.iter()) {
+                let needed = self.minted(id).saturating_add(amount);
+                let cap = self.mint_cap(id);
+                if !cap.is_zero() && needed > cap {
+                    return Err(Error::MintCapExceeded(
+                        ERC6909MintCapExceeded { id, cap, needed },
+                    ));
+                }
+                self.minted.setter(id).set(needed);
+            }
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909IdRegistry {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909IdRegistry {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const METADATA: &str = "ipfs://metadata";
+
+    #[motsu::test]
+    fn register_id_emits_event(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract.assert_emitted(&IssuerSet { account: alice, approved: true });
+
+        contract
+            .sender(alice)
+            .register_id(ID, METADATA.into())
+            .motsu_expect("should register id");
+
+        assert!(contract.sender(alice).is_registered(ID));
+        assert!(!contract.sender(alice).is_registered(ID + U256::ONE));
+        assert_eq!(METADATA, contract.sender(alice).id_metadata(ID));
+        assert_eq!(alice, contract.sender(alice).creator(ID));
+        assert!(contract.sender(alice).creator(ID + U256::ONE).is_zero());
+        contract.assert_emitted(&IdRegistered {
+            id: ID,
+            issuer: alice,
+            metadata: METADATA.into(),
+        });
+    }
+
+    #[motsu::test]
+    fn register_id_reverts_when_not_issuer(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .register_id(ID, String::new())
+            .motsu_expect_err("should not register id without issuer role");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedIssuer(ERC6909UnauthorizedIssuer { account })
+                if account == alice
+        ));
+        assert!(!contract.sender(alice).is_registered(ID));
+    }
+
+    #[motsu::test]
+    fn register_id_reverts_when_issuer_revoked(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract.sender(alice)._set_issuer(alice, false);
+
+        let err = contract
+            .sender(alice)
+            .register_id(ID, String::new())
+            .motsu_expect_err("should not register id after revocation");
+
+        assert!(matches!(err, Error::UnauthorizedIssuer(_)));
+    }
+
+    #[motsu::test]
+    fn register_id_reverts_when_already_registered(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._register_id(alice, ID, METADATA.into())
+            .motsu_expect("should register id");
+
+        let err = contract
+            .sender(alice)
+            ._register_id(alice, ID, String::new())
+            .motsu_expect_err("should not register id twice");
+
+        assert!(matches!(
+            err,
+            Error::IdAlreadyRegistered(ERC6909IdAlreadyRegistered { id })
+                if id == ID
+        ));
+        assert_eq!(METADATA, contract.sender(alice).id_metadata(ID));
+    }
+
+    #[motsu::test]
+    fn create_assigns_sequential_ids(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract.sender(alice)._set_issuer(bob, true);
+
+        let first = contract
+            .sender(alice)
+            .create(METADATA.into())
+            .motsu_expect("should create id");
+        let second = contract
+            .sender(bob)
+            .create(String::new())
+            .motsu_expect("should create id");
+
+        assert_eq!(FIRST_CREATED_ID, first);
+        assert_eq!(FIRST_CREATED_ID + U256::ONE, second);
+        assert_eq!(alice, contract.sender(alice).creator(first));
+        assert_eq!(bob, contract.sender(alice).creator(second));
+        assert_eq!(METADATA, contract.sender(alice).id_metadata(first));
+        assert!(contract.sender(alice).is_registered(second));
+        contract.assert_emitted(&IdCreated { creator: alice, id: first });
+        contract.assert_emitted(&IdCreated { creator: bob, id: second });
+    }
+
+    #[motsu::test]
+    fn register_id_reverts_when_id_reserved(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract
+            .sender(alice)
+            .register_id(FIRST_CREATED_ID - U256::ONE, String::new())
+            .motsu_expect("should register the last id below created ids");
+
+        for reserved in [FIRST_CREATED_ID, U256::MAX] {
+            let err = contract
+                .sender(alice)
+                .register_id(reserved, String::new())
+                .motsu_expect_err("should not register a reserved id");
+
+            assert!(matches!(
+                err,
+                Error::ReservedId(ERC6909ReservedId { id }) if id == reserved
+            ));
+            assert!(!contract.sender(alice).is_registered(reserved));
+        }
+    }
+
+    #[motsu::test]
+    fn create_does_not_collide_with_registered_ids(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract.sender(alice)._set_issuer(bob, true);
+        for id in [U256::ZERO, U256::ONE, FIRST_CREATED_ID - U256::ONE] {
+            contract
+                .sender(bob)
+                .register_id(id, String::new())
+                .motsu_expect("should register id");
+        }
+
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+
+        assert_eq!(FIRST_CREATED_ID, id);
+        assert_eq!(alice, contract.sender(alice).creator(id));
+        assert_eq!(bob, contract.sender(alice).creator(U256::ZERO));
+        assert_eq!(
+            bob,
+            contract.sender(alice).creator(FIRST_CREATED_ID - U256::ONE)
+        );
+    }
+
+    #[motsu::test]
+    fn create_reverts_when_not_issuer(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect_err("should not create id without issuer role");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedIssuer(ERC6909UnauthorizedIssuer { account })
+                if account == alice
+        ));
+        assert!(!contract.sender(alice).is_registered(U256::ZERO));
+    }
+
+    #[motsu::test]
+    fn creator_mints_and_sets_metadata(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+
+        contract
+            .sender(alice)
+            .mint(bob, id, AMOUNT)
+            .motsu_expect("creator should mint");
+        contract
+            .sender(alice)
+            .set_id_metadata(id, METADATA.into())
+            .motsu_expect("creator should set metadata");
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(bob, id));
+        assert_eq!(AMOUNT, contract.sender(alice).minted(id));
+        assert_eq!(METADATA, contract.sender(alice).id_metadata(id));
+        contract
+            .assert_emitted(&IdMetadataSet { id, metadata: METADATA.into() });
+    }
+
+    #[motsu::test]
+    fn non_creator_cannot_manage_id(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        contract.sender(alice)._set_issuer(bob, true);
+        let id = contract
+            .sender(alice)
+            .create(METADATA.into())
+            .motsu_expect("should create id");
+
+        let err = contract
+            .sender(bob)
+            .mint(bob, id, AMOUNT)
+            .motsu_expect_err("should not mint another creator's id");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedCreator(ERC6909UnauthorizedCreator {
+                account,
+                id: actual_id,
+            }) if account == bob && actual_id == id
+        ));
+
+        let err = contract
+            .sender(bob)
+            .set_id_metadata(id, String::new())
+            .motsu_expect_err("should not set another creator's metadata");
+        assert!(matches!(err, Error::UnauthorizedCreator(_)));
+
+        let err = contract
+            .sender(bob)
+            .set_mint_cap(id, AMOUNT)
+            .motsu_expect_err("should not cap another creator's id");
+        assert!(matches!(err, Error::UnauthorizedCreator(_)));
+
+        assert!(contract.sender(alice).balance_of(bob, id).is_zero());
+        assert_eq!(METADATA, contract.sender(alice).id_metadata(id));
+        assert!(contract.sender(alice).mint_cap(id).is_zero());
+    }
+
+    #[motsu::test]
+    fn transfer_id_admin_hands_over_id_once_accepted(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+
+        contract
+            .sender(alice)
+            .transfer_id_admin(id, bob)
+            .motsu_expect("creator should start handover");
+
+        assert_eq!(bob, contract.sender(alice).pending_id_admin(id));
+        assert_eq!(alice, contract.sender(alice).creator(id));
+        contract.assert_emitted(&IdAdminTransferStarted {
+            id,
+            previous_admin: alice,
+            new_admin: bob,
+        });
+
+        contract
+            .sender(bob)
+            .accept_id_admin(id)
+            .motsu_expect("pending admin should accept handover");
+
+        assert_eq!(bob, contract.sender(alice).creator(id));
+        assert!(contract.sender(alice).pending_id_admin(id).is_zero());
+        contract.assert_emitted(&IdAdminTransferred {
+            id,
+            previous_admin: alice,
+            new_admin: bob,
+        });
+
+        contract
+            .sender(bob)
+            .mint(bob, id, AMOUNT)
+            .motsu_expect("new creator should mint");
+        contract
+            .sender(bob)
+            .set_id_metadata(id, METADATA.into())
+            .motsu_expect("new creator should set metadata");
+
+        let err = contract
+            .sender(alice)
+            .set_mint_cap(id, AMOUNT)
+            .motsu_expect_err("previous creator should not manage id");
+        assert!(matches!(err, Error::UnauthorizedCreator(_)));
+    }
+
+    #[motsu::test]
+    fn transfer_id_admin_reverts_when_not_creator(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+
+        let err = contract
+            .sender(bob)
+            .transfer_id_admin(id, bob)
+            .motsu_expect_err("should not hand over another creator's id");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedCreator(ERC6909UnauthorizedCreator {
+                account,
+                id: actual_id,
+            }) if account == bob && actual_id == id
+        ));
+        assert!(contract.sender(alice).pending_id_admin(id).is_zero());
+    }
+
+    #[motsu::test]
+    fn accept_id_admin_reverts_when_not_pending_admin(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+        contract
+            .sender(alice)
+            .transfer_id_admin(id, bob)
+            .motsu_expect("creator should start handover");
+
+        let err = contract
+            .sender(charlie)
+            .accept_id_admin(id)
+            .motsu_expect_err("should not accept without being nominated");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedPendingAdmin(ERC6909UnauthorizedPendingAdmin {
+                account,
+                id: actual_id,
+            }) if account == charlie && actual_id == id
+        ));
+        assert_eq!(alice, contract.sender(alice).creator(id));
+        assert_eq!(bob, contract.sender(alice).pending_id_admin(id));
+    }
+
+    #[motsu::test]
+    fn transfer_id_admin_to_zero_cancels_handover(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+        contract
+            .sender(alice)
+            .transfer_id_admin(id, bob)
+            .motsu_expect("creator should start handover");
+
+        contract
+            .sender(alice)
+            .transfer_id_admin(id, Address::ZERO)
+            .motsu_expect("creator should cancel handover");
+
+        let err = contract
+            .sender(bob)
+            .accept_id_admin(id)
+            .motsu_expect_err("should not accept a cancelled handover");
+
+        assert!(matches!(err, Error::UnauthorizedPendingAdmin(_)));
+        assert_eq!(alice, contract.sender(alice).creator(id));
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_id_not_created(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .mint(alice, ID, AMOUNT)
+            .motsu_expect_err("should not mint unregistered id");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedCreator(ERC6909UnauthorizedCreator {
+                account,
+                id,
+            }) if account == alice && id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_cap_exceeded(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_issuer(alice, true);
+        let id = contract
+            .sender(alice)
+            .create(String::new())
+            .motsu_expect("should create id");
+        contract
+            .sender(alice)
+            .set_mint_cap(id, AMOUNT)
+            .motsu_expect("creator should set mint cap");
+        contract.assert_emitted(&MintCapSet { id, cap: AMOUNT });
+
+        contract
+            .sender(alice)
+            .mint(bob, id, AMOUNT)
+            .motsu_expect("should mint up to the cap");
+        contract
+            .sender(alice)
+            ._burn(bob, id, AMOUNT)
+            .motsu_expect("should burn");
+
+        let err = contract
+            .sender(alice)
+            .mint(bob, id, U256::ONE)
+            .motsu_expect_err("should not mint above the cap");
+
+        assert!(matches!(
+            err,
+            Error::MintCapExceeded(ERC6909MintCapExceeded {
+                id: actual_id,
+                cap,
+                needed,
+            }) if actual_id == id
+                && cap == AMOUNT
+                && needed == AMOUNT + U256::ONE
+        ));
+        assert!(contract.sender(alice).balance_of(bob, id).is_zero());
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_id_unknown(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect_err("should not mint unregistered id");
+
+        assert!(matches!(
+            err,
+            Error::UnknownId(ERC6909UnknownId { id }) if id == ID
+        ));
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn mint_batch_reverts_when_one_id_unknown(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        let unknown_id = ID + U256::ONE;
+        contract
+            .sender(alice)
+            ._register_id(alice, ID, String::new())
+            .motsu_expect("should register id");
+
+        let err = contract
+            .sender(alice)
+            ._mint_batch(alice, vec![ID, unknown_id], vec![AMOUNT, AMOUNT])
+            .motsu_expect_err("should not mint unregistered id");
+
+        assert!(matches!(
+            err,
+            Error::UnknownId(ERC6909UnknownId { id }) if id == unknown_id
+        ));
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn registered_id_can_be_minted_transferred_and_burned(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._register_id(alice, ID, String::new())
+            .motsu_expect("should register id");
+
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint registered id");
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer registered id");
+        contract
+            .sender(alice)
+            ._burn(bob, ID, AMOUNT)
+            .motsu_expect("should burn registered id");
+
+        assert!(contract.sender(alice).balance_of(bob, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909IdRegistry as IErc6909IdRegistry>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x9f50bc3a");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909IdRegistry>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909IdRegistry as IErc6909IdRegistry>::interface_id()
+        ));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909IdRegistry as IErc165>::interface_id()
+            )
+        );
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909IdRegistry as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
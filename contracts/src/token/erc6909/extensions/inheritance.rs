@@ -0,0 +1,942 @@
+//! Extension of ERC-6909 that lets holders designate a beneficiary, who can
+//! claim their tokens once they have been inactive for long enough, e.g.
+//! after the loss of their keys or their death.
+//!
+//! An owner sets a beneficiary and an inactivity period, and designates the
+//! token ids the beneficiary can inherit. The outgoing transfers of the owner,
+//! as well as any change to their beneficiary, count as activity. If the
+//! owner performs none of them for the inactivity period, the beneficiary can
+//! claim the owner's whole balance of each designated token id.
+//!
+//! Activity is measured with the [`Clock`] of the contract, the block
+//! timestamp by default, or the L2 block number with [`BlockNumberClock`],
+//! in which case inactivity periods are expressed in L2 blocks rather than
+//! in seconds.
+//!
+//! [`BlockNumberClock`]: crate::utils::clock::BlockNumberClock
+//!
+//! Note that the activity of an account is only tracked while it has a
+//! beneficiary.
+
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        clock::{Clock, TimestampClock},
+        introspection::erc165::IErc165,
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `owner` sets `beneficiary` as the account inheriting
+        /// their tokens after `inactivity_period`.
+        ///
+        /// * `owner` - Account whose tokens are inherited.
+        /// * `beneficiary` - Account inheriting the tokens, or
+        ///   `Address::ZERO` to remove the beneficiary.
+        /// * `inactivity_period` - Delay without activity after which the
+        ///   tokens can be claimed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event BeneficiarySet(
+            address indexed owner,
+            address indexed beneficiary,
+            uint64 inactivity_period
+        );
+
+        /// Emitted when `owner` designates token `id` as inheritable or not.
+        ///
+        /// * `owner` - Account whose tokens are inherited.
+        /// * `id` - Token id as a number.
+        /// * `inheritable` - Whether the beneficiary can claim token `id`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event InheritableSet(
+            address indexed owner,
+            uint256 indexed id,
+            bool inheritable
+        );
+
+        /// Emitted when `beneficiary` claims `amount` tokens of type `id`
+        /// from `owner`.
+        ///
+        /// * `owner` - Account whose tokens are inherited.
+        /// * `beneficiary` - Account inheriting the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens claimed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event InheritanceClaimed(
+            address indexed owner,
+            address indexed beneficiary,
+            uint256 indexed id,
+            uint256 amount
+        );
+    }
+
+    sol! {
+        /// Indicates that a beneficiary can't be set with a zero inactivity
+        /// period.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidInactivityPeriod();
+
+        /// Indicates that `account` is not the beneficiary of `owner`.
+        ///
+        /// * `owner` - Account whose tokens are claimed.
+        /// * `account` - Account trying to claim the tokens.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedInheritanceClaim(
+            address owner,
+            address account
+        );
+
+        /// Indicates that the tokens of `owner` can't be claimed before
+        /// `claimable_at`, as `owner` is still active.
+        ///
+        /// * `owner` - Account whose tokens are claimed.
+        /// * `claimable_at` - Timestamp from which the tokens can be claimed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909OwnerStillActive(address owner, uint64 claimable_at);
+
+        /// Indicates that `owner` didn't designate token `id` as
+        /// inheritable.
+        ///
+        /// * `owner` - Account whose tokens are claimed.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonInheritableToken(address owner, uint256 id);
+    }
+}
+
+/// An [`Erc6909Inheritance`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a beneficiary can't be set with a zero inactivity
+    /// period.
+    InvalidInactivityPeriod(ERC6909InvalidInactivityPeriod),
+    /// Indicates that an account is not the beneficiary of an owner.
+    UnauthorizedInheritanceClaim(ERC6909UnauthorizedInheritanceClaim),
+    /// Indicates that an owner is still active.
+    OwnerStillActive(ERC6909OwnerStillActive),
+    /// Indicates that a token id is not inheritable.
+    NonInheritableToken(ERC6909NonInheritableToken),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Inheritance`] contract.
+#[storage]
+pub struct Erc6909Inheritance<C: Clock = TimestampClock> {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from owner to their beneficiary.
+    pub(crate) beneficiaries: StorageMap<Address, StorageAddress>,
+    /// Mapping from owner to the delay without activity after which their
+    /// tokens can be claimed.
+    pub(crate) inactivity_periods: StorageMap<Address, StorageU64>,
+    /// Mapping from owner to the timepoint of their last activity.
+    pub(crate) last_activities: StorageMap<Address, StorageU64>,
+    /// Mapping from owner to token id to whether it is inheritable.
+    pub(crate) inheritable: StorageMap<Address, StorageMap<U256, StorageBool>>,
+    /// [`Clock`] measuring activity, the block timestamp by default.
+    pub(crate) clock: PhantomData<C>,
+}
+
+impl_as_erc6909!(Erc6909Inheritance<C: Clock>, erc6909);
+
+/// Required interface of an [`Erc6909Inheritance`] contract.
+#[interface_id]
+pub trait IErc6909Inheritance: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the beneficiary of `owner`, or [`Address::ZERO`] if they have
+    /// none.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account whose tokens are inherited.
+    fn beneficiary(&self, owner: Address) -> Address;
+
+    /// Returns the delay without activity after which the beneficiary of
+    /// `owner` can claim their tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account whose tokens are inherited.
+    fn inactivity_period(&self, owner: Address) -> U64;
+
+    /// Returns the timestamp of the last activity of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account whose tokens are inherited.
+    fn last_activity(&self, owner: Address) -> U64;
+
+    /// Returns whether the beneficiary of `owner` can claim tokens of type
+    /// `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account whose tokens are inherited.
+    /// * `id` - Token id as a number.
+    fn is_inheritable(&self, owner: Address, id: U256) -> bool;
+
+    /// Sets `beneficiary` as the account inheriting the caller's tokens once
+    /// the caller has been inactive for `inactivity_period`, and records the
+    /// activity of the caller.
+    ///
+    /// Setting `beneficiary` to [`Address::ZERO`] removes the beneficiary of
+    /// the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `beneficiary` - Account inheriting the tokens.
+    /// * `inactivity_period` - Delay without activity after which the tokens
+    ///   can be claimed.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidInactivityPeriod`] - If `beneficiary` is set with a
+    ///   zero `inactivity_period`.
+    ///
+    /// # Events
+    ///
+    /// * [`BeneficiarySet`].
+    fn set_beneficiary(
+        &mut self,
+        beneficiary: Address,
+        inactivity_period: U64,
+    ) -> Result<(), Self::Error>;
+
+    /// Designates token `id` of the caller as inheritable or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `inheritable` - Whether the beneficiary can claim token `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`InheritableSet`].
+    fn set_inheritable(&mut self, id: U256, inheritable: bool);
+
+    /// Transfers the whole balance of `owner` in token `id` to the caller,
+    /// who must be the beneficiary of `owner`, and returns the amount of
+    /// tokens claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account whose tokens are claimed.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedInheritanceClaim`] - If the caller is not the
+    ///   beneficiary of `owner`.
+    /// * [`Error::NonInheritableToken`] - If `owner` didn't designate token
+    ///   `id` as inheritable.
+    /// * [`Error::OwnerStillActive`] - If `owner` had activity within their
+    ///   inactivity period.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`InheritanceClaimed`].
+    fn claim_inheritance(
+        &mut self,
+        owner: Address,
+        id: U256,
+    ) -> Result<U256, Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Inheritance<Error = Error>,
+    IErc165
+)]
+impl<C: Clock> Erc6909Inheritance<C> {}
+
+#[public]
+impl<C: Clock> IErc6909Inheritance for Erc6909Inheritance<C> {
+    type Error = Error;
+
+    fn beneficiary(&self, owner: Address) -> Address {
+        self.beneficiaries.get(owner)
+    }
+
+    fn inactivity_period(&self, owner: Address) -> U64 {
+        self.inactivity_periods.get(owner)
+    }
+
+    fn last_activity(&self, owner: Address) -> U64 {
+        self.last_activities.get(owner)
+    }
+
+    fn is_inheritable(&self, owner: Address, id: U256) -> bool {
+        self.inheritable.get(owner).get(id)
+    }
+
+    fn set_beneficiary(
+        &mut self,
+        beneficiary: Address,
+        inactivity_period: U64,
+    ) -> Result<(), Self::Error> {
+        if !beneficiary.is_zero() && inactivity_period.is_zero() {
+            return Err(Error::InvalidInactivityPeriod(
+                ERC6909InvalidInactivityPeriod {},
+            ));
+        }
+
+        let owner = msg::sender();
+        self.beneficiaries.setter(owner).set(beneficiary);
+        self.inactivity_periods.setter(owner).set(inactivity_period);
+        self.last_activities.setter(owner).set(C::now().to::<U64>());
+
+        evm::log(BeneficiarySet {
+            owner,
+            beneficiary,
+            inactivity_period: inactivity_period.to(),
+        });
+
+        Ok(())
+    }
+
+    fn set_inheritable(&mut self, id: U256, inheritable: bool) {
+        let owner = msg::sender();
+        self.inheritable.setter(owner).setter(id).set(inheritable);
+        evm::log(InheritableSet { owner, id, inheritable });
+    }
+
+    fn claim_inheritance(
+        &mut self,
+        owner: Address,
+        id: U256,
+    ) -> Result<U256, Self::Error> {
+        let beneficiary = msg::sender();
+        if beneficiary.is_zero() || beneficiary != self.beneficiary(owner) {
+            return Err(Error::UnauthorizedInheritanceClaim(
+                ERC6909UnauthorizedInheritanceClaim {
+                    owner,
+                    account: beneficiary,
+                },
+            ));
+        }
+
+        if !self.is_inheritable(owner, id) {
+            return Err(Error::NonInheritableToken(
+                ERC6909NonInheritableToken { owner, id },
+            ));
+        }
+
+        let claimable_at = self
+            .last_activity(owner)
+            .saturating_add(self.inactivity_period(owner));
+        if C::now().to::<U64>() < claimable_at {
+            return Err(Error::OwnerStillActive(ERC6909OwnerStillActive {
+                owner,
+                claimable_at: claimable_at.to(),
+            }));
+        }
+
+        // The claim moves the tokens with the base contract, so it doesn't
+        // count as an activity of `owner`, and other designated ids can be
+        // claimed afterwards.
+        let amount = self.erc6909.balance_of(owner, id);
+        self.erc6909._transfer(owner, beneficiary, id, amount)?;
+
+        evm::log(InheritanceClaimed { owner, beneficiary, id, amount });
+
+        Ok(amount)
+    }
+}
+
+#[public]
+impl<C: Clock> IErc6909 for Erc6909Inheritance<C> {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl<C: Clock> IErc165 for Erc6909Inheritance<C> {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Inheritance>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl<C: Clock> Erc6909Inheritance<C> {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl<C: Clock> Erc6909Inheritance<C> {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that records the activity of
+    /// `from` when it transfers tokens and has a beneficiary.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if !from.is_zero() && !to.is_zero() && !self.beneficiary(from).is_zero()
+        {
+            self.last_activities.setter(from).set(C::now().to::<U64>());
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+}
+
+impl<C: Clock> IErc6909Internal for Erc6909Inheritance<C> {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256, U64};
+    use motsu::prelude::*;
+    use stylus_sdk::block;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Inheritance {}
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const PERIOD: U64 = uint!(3600_U64);
+
+    fn now() -> U64 {
+        U64::from(block::timestamp())
+    }
+
+    /// Mints tokens of `ID` and `OTHER_ID` to `alice`, sets `bob` as her
+    /// beneficiary and designates `ID` as inheritable.
+    fn init(
+        contract: &Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(alice, vec![ID, OTHER_ID], vec![AMOUNT, AMOUNT])
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            .set_beneficiary(bob, PERIOD)
+            .motsu_expect("should set beneficiary");
+        contract.sender(alice).set_inheritable(ID, true);
+    }
+
+    /// Moves the last activity of `owner` back by the inactivity period.
+    fn go_inactive(contract: &Contract<Erc6909Inheritance>, owner: Address) {
+        contract
+            .sender(owner)
+            .last_activities
+            .setter(owner)
+            .set(now() - PERIOD);
+    }
+
+    #[motsu::test]
+    fn set_beneficiary_records_activity(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+
+        assert_eq!(bob, contract.sender(alice).beneficiary(alice));
+        assert_eq!(PERIOD, contract.sender(alice).inactivity_period(alice));
+        assert_eq!(now(), contract.sender(alice).last_activity(alice));
+        assert!(contract.sender(alice).is_inheritable(alice, ID));
+        assert!(!contract.sender(alice).is_inheritable(alice, OTHER_ID));
+
+        contract.assert_emitted(&BeneficiarySet {
+            owner: alice,
+            beneficiary: bob,
+            inactivity_period: PERIOD.to(),
+        });
+        contract.assert_emitted(&InheritableSet {
+            owner: alice,
+            id: ID,
+            inheritable: true,
+        });
+    }
+
+    #[motsu::test]
+    fn set_beneficiary_reverts_on_zero_inactivity_period(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .set_beneficiary(bob, U64::ZERO)
+            .motsu_expect_err("should not set a zero inactivity period");
+
+        assert!(matches!(err, Error::InvalidInactivityPeriod(_)));
+    }
+
+    #[motsu::test]
+    fn transfer_records_activity(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, bob);
+        go_inactive(&contract, alice);
+
+        contract
+            .sender(alice)
+            .transfer(charlie, ID, U256::ONE)
+            .motsu_expect("should transfer tokens");
+
+        assert_eq!(now(), contract.sender(alice).last_activity(alice));
+        // Accounts without beneficiary are not tracked.
+        assert!(contract.sender(alice).last_activity(charlie).is_zero());
+    }
+
+    #[motsu::test]
+    fn beneficiary_claims_inheritable_tokens_after_inactivity(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        go_inactive(&contract, alice);
+
+        let claimed = contract
+            .sender(bob)
+            .claim_inheritance(alice, ID)
+            .motsu_expect("should claim inheritance");
+
+        assert_eq!(AMOUNT, claimed);
+        assert!(contract.sender(bob).balance_of(alice, ID).is_zero());
+        assert_eq!(AMOUNT, contract.sender(bob).balance_of(bob, ID));
+        contract.assert_emitted(&InheritanceClaimed {
+            owner: alice,
+            beneficiary: bob,
+            id: ID,
+            amount: AMOUNT,
+        });
+
+        let err = contract
+            .sender(bob)
+            .claim_inheritance(alice, OTHER_ID)
+            .motsu_expect_err("should not claim non-inheritable tokens");
+        assert!(matches!(
+            err,
+            Error::NonInheritableToken(ERC6909NonInheritableToken {
+                owner,
+                id,
+            }) if owner == alice && id == OTHER_ID
+        ));
+        assert_eq!(AMOUNT, contract.sender(bob).balance_of(alice, OTHER_ID));
+    }
+
+    #[motsu::test]
+    fn claim_reverts_when_owner_still_active(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+
+        let err = contract
+            .sender(bob)
+            .claim_inheritance(alice, ID)
+            .motsu_expect_err("should not claim from an active owner");
+
+        assert!(matches!(
+            err,
+            Error::OwnerStillActive(ERC6909OwnerStillActive {
+                owner,
+                claimable_at,
+            }) if owner == alice && U64::from(claimable_at) == now() + PERIOD
+        ));
+        assert_eq!(AMOUNT, contract.sender(bob).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn claim_reverts_when_not_beneficiary(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, bob);
+        go_inactive(&contract, alice);
+
+        let err = contract
+            .sender(charlie)
+            .claim_inheritance(alice, ID)
+            .motsu_expect_err("should only be claimed by the beneficiary");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedInheritanceClaim(
+                ERC6909UnauthorizedInheritanceClaim { owner, account }
+            ) if owner == alice && account == charlie
+        ));
+    }
+
+    #[motsu::test]
+    fn removed_beneficiary_cannot_claim(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        contract
+            .sender(alice)
+            .set_beneficiary(Address::ZERO, U64::ZERO)
+            .motsu_expect("should remove beneficiary");
+        go_inactive(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            .claim_inheritance(alice, ID)
+            .motsu_expect_err("should not claim once removed");
+
+        assert!(matches!(err, Error::UnauthorizedInheritanceClaim(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909Inheritance as IErc6909Inheritance>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x0624805d");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909Inheritance>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Inheritance as IErc6909Inheritance>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Inheritance as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,1012 @@
+//! Extension of ERC-6909 that adds a minimal order book where holders list
+//! tokens for native ETH, and settlements pay the [`Erc2981`] royalty.
+//!
+//! Listed tokens stay in the seller's balance until the order is filled, so an
+//! order can't be filled once its seller no longer holds the listed tokens.
+//!
+//! When an order is filled, the royalty owed for the token id is sent to its
+//! royalty receiver, and the rest of the payment to the seller.
+//!
+//! Note that [`Erc2981::fee_denominator`] must be set in your contract's
+//! constructor, and that the royalty setters of [`Erc6909Marketplace::erc2981`]
+//! are not exposed by default. You should expose them manually in your
+//! contract's abi, guarded by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256},
+};
+
+use crate::{
+    token::{
+        common::erc2981::{Erc2981, IErc2981},
+        erc6909::{
+            self, impl_as_erc6909, ERC6909InvalidApprover,
+            ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+            ERC6909InvalidReceiver, ERC6909InvalidSender,
+            ERC6909InvalidSpender, ERC6909InvalidSpenderForId,
+            ERC6909SelfTarget, Erc6909, Erc6909InsufficientAllowance,
+            Erc6909InsufficientBalance, Erc6909InsufficientPermission,
+            IErc6909, IErc6909Internal,
+        },
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `seller` lists `amount` tokens of type `id` at `price`
+        /// per token.
+        ///
+        /// * `order_id` - Id of the order.
+        /// * `seller` - Account selling the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens listed.
+        /// * `price` - Price of one token, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OrderListed(
+            uint256 indexed order_id,
+            address indexed seller,
+            uint256 indexed id,
+            uint256 amount,
+            uint256 price
+        );
+
+        /// Emitted when the order `order_id` is cancelled by its seller.
+        ///
+        /// * `order_id` - Id of the order.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OrderCancelled(uint256 indexed order_id);
+
+        /// Emitted when `buyer` buys `amount` tokens from the order
+        /// `order_id`, paying `cost`, of which `royalty` went to the royalty
+        /// receiver.
+        ///
+        /// * `order_id` - Id of the order.
+        /// * `buyer` - Account buying the tokens.
+        /// * `amount` - Amount of tokens bought.
+        /// * `cost` - Amount of ETH paid, in wei.
+        /// * `royalty` - Amount of ETH paid as royalty, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OrderFilled(
+            uint256 indexed order_id,
+            address indexed buyer,
+            uint256 amount,
+            uint256 cost,
+            uint256 royalty
+        );
+    }
+
+    sol! {
+        /// Indicates that the order `order_id` doesn't exist, or has been
+        /// cancelled or completely filled.
+        ///
+        /// * `order_id` - Id of the order.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentOrder(uint256 order_id);
+
+        /// Indicates that an order can't be listed for zero tokens.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909EmptyOrder();
+
+        /// Indicates that `amount` tokens can't be bought from the order
+        /// `order_id`, which has `available` tokens left.
+        ///
+        /// * `order_id` - Id of the order.
+        /// * `available` - Amount of tokens left in the order.
+        /// * `amount` - Amount of tokens requested.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidFillAmount(
+            uint256 order_id,
+            uint256 available,
+            uint256 amount
+        );
+
+        /// Indicates that `account` is not the seller of the order
+        /// `order_id`.
+        ///
+        /// * `order_id` - Id of the order.
+        /// * `account` - Account trying to cancel the order.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedOrderCancellation(
+            uint256 order_id,
+            address account
+        );
+
+        /// Indicates that the ETH sent doesn't match the cost of the fill.
+        ///
+        /// * `order_id` - Id of the order.
+        /// * `cost` - Cost of the fill, in wei.
+        /// * `value` - Amount of ETH sent, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909IncorrectOrderPayment(
+            uint256 order_id,
+            uint256 cost,
+            uint256 value
+        );
+
+        /// Indicates that a settlement payment couldn't be sent to
+        /// `receiver`.
+        ///
+        /// * `receiver` - Seller or royalty receiver.
+        /// * `amount` - Amount of ETH, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909SettlementFailed(address receiver, uint256 amount);
+    }
+}
+
+/// An [`Erc6909Marketplace`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that an order doesn't exist.
+    NonexistentOrder(ERC6909NonexistentOrder),
+    /// Indicates that an order can't be listed for zero tokens.
+    EmptyOrder(ERC6909EmptyOrder),
+    /// Indicates that an amount of tokens can't be bought from an order.
+    InvalidFillAmount(ERC6909InvalidFillAmount),
+    /// Indicates that an account is not the seller of an order.
+    UnauthorizedOrderCancellation(ERC6909UnauthorizedOrderCancellation),
+    /// Indicates that the ETH sent doesn't match the cost of a fill.
+    IncorrectOrderPayment(ERC6909IncorrectOrderPayment),
+    /// Indicates that a settlement payment couldn't be sent.
+    SettlementFailed(ERC6909SettlementFailed),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Struct for an order of an [`Erc6909Marketplace`] contract.
+#[storage]
+pub struct Order {
+    /// Account selling the tokens.
+    seller: StorageAddress,
+    /// Token id as a number.
+    id: StorageU256,
+    /// Amount of tokens left to be bought.
+    amount: StorageU256,
+    /// Price of one token, in wei.
+    price: StorageU256,
+}
+
+/// State of an [`Erc6909Marketplace`] contract.
+#[storage]
+pub struct Erc6909Marketplace {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// [`Erc2981`] contract.
+    pub erc2981: Erc2981,
+    /// Mapping from order id to order.
+    pub(crate) orders: StorageMap<U256, Order>,
+    /// Id of the next order to be listed.
+    pub(crate) next_order_id: StorageU256,
+}
+
+impl_as_erc6909!(Erc6909Marketplace, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909Marketplace {}
+
+/// Required interface of an [`Erc6909Marketplace`] contract.
+#[interface_id]
+pub trait IErc6909Marketplace: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the seller, token id, amount of tokens left and price per
+    /// token of the order `order_id`.
+    ///
+    /// Returns [`Address::ZERO`] as the seller if the order doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `order_id` - Id of the order.
+    fn order(&self, order_id: U256) -> (Address, U256, U256, U256);
+
+    /// Lists `amount` tokens of type `id` owned by the caller, at `price` per
+    /// token, and returns the id of the order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to sell.
+    /// * `price` - Price of one token, in wei.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyOrder`] - If `amount` is zero.
+    ///
+    /// # Events
+    ///
+    /// * [`OrderListed`].
+    fn list(
+        &mut self,
+        id: U256,
+        amount: U256,
+        price: U256,
+    ) -> Result<U256, Self::Error>;
+
+    /// Cancels the order `order_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `order_id` - Id of the order.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentOrder`] - If the order doesn't exist.
+    /// * [`Error::UnauthorizedOrderCancellation`] - If the caller is not the
+    ///   seller of the order.
+    ///
+    /// # Events
+    ///
+    /// * [`OrderCancelled`].
+    fn cancel(&mut self, order_id: U256) -> Result<(), Self::Error>;
+
+    /// Buys `amount` tokens from the order `order_id`, in exchange for the
+    /// ETH sent with the call, which is split between the royalty receiver and
+    /// the seller.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `order_id` - Id of the order.
+    /// * `amount` - Amount of tokens to buy.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentOrder`] - If the order doesn't exist.
+    /// * [`Error::InvalidFillAmount`] - If `amount` is zero or exceeds the
+    ///   amount of tokens left in the order.
+    /// * [`Error::IncorrectOrderPayment`] - If the ETH sent is not exactly
+    ///   `amount` times the price of the order.
+    /// * [`Error::InsufficientBalance`] - If the seller doesn't hold the tokens
+    ///   anymore.
+    /// * [`Error::SettlementFailed`] - If the ETH couldn't be sent to the
+    ///   royalty receiver or the seller.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`OrderFilled`].
+    ///
+    /// # Panics
+    ///
+    /// * If the cost of the fill exceeds [`U256::MAX`].
+    /// * If the royalty of the fill can't be computed.
+    fn fill(&mut self, order_id: U256, amount: U256)
+        -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Marketplace<Error = Error>,
+    IErc2981,
+    IErc165
+)]
+impl Erc6909Marketplace {}
+
+#[public]
+impl IErc6909Marketplace for Erc6909Marketplace {
+    type Error = Error;
+
+    fn order(&self, order_id: U256) -> (Address, U256, U256, U256) {
+        let order = self.orders.get(order_id);
+        (
+            order.seller.get(),
+            order.id.get(),
+            order.amount.get(),
+            order.price.get(),
+        )
+    }
+
+    fn list(
+        &mut self,
+        id: U256,
+        amount: U256,
+        price: U256,
+    ) -> Result<U256, Self::Error> {
+        if amount.is_zero() {
+            return Err(Error::EmptyOrder(ERC6909EmptyOrder {}));
+        }
+
+        let seller = msg::sender();
+        let order_id = self.next_order_id.get();
+        // Overflow not possible: one order is listed per call.
+        self.next_order_id.set(order_id + U256::ONE);
+
+        let mut order = self.orders.setter(order_id);
+        order.seller.set(seller);
+        order.id.set(id);
+        order.amount.set(amount);
+        order.price.set(price);
+
+        evm::log(OrderListed { order_id, seller, id, amount, price });
+
+        Ok(order_id)
+    }
+
+    fn cancel(&mut self, order_id: U256) -> Result<(), Self::Error> {
+        let (seller, ..) = self.order(order_id);
+        if seller.is_zero() {
+            return Err(Error::NonexistentOrder(ERC6909NonexistentOrder {
+                order_id,
+            }));
+        }
+
+        let account = msg::sender();
+        if account != seller {
+            return Err(Error::UnauthorizedOrderCancellation(
+                ERC6909UnauthorizedOrderCancellation { order_id, account },
+            ));
+        }
+
+        self._remove_order(order_id);
+        evm::log(OrderCancelled { order_id });
+
+        Ok(())
+    }
+
+    #[payable]
+    fn fill(
+        &mut self,
+        order_id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        let (seller, id, available, price) = self.order(order_id);
+        if seller.is_zero() {
+            return Err(Error::NonexistentOrder(ERC6909NonexistentOrder {
+                order_id,
+            }));
+        }
+
+        if amount.is_zero() || amount > available {
+            return Err(Error::InvalidFillAmount(ERC6909InvalidFillAmount {
+                order_id,
+                available,
+                amount,
+            }));
+        }
+
+        let cost = price
+            .checked_mul(amount)
+            .expect("order cost should not exceed `U256::MAX`");
+        let value = msg::value();
+        if value != cost {
+            return Err(Error::IncorrectOrderPayment(
+                ERC6909IncorrectOrderPayment { order_id, cost, value },
+            ));
+        }
+
+        // Underflow not possible: `amount` <= `available`.
+        let remaining = available - amount;
+        if remaining.is_zero() {
+            self._remove_order(order_id);
+        } else {
+            self.orders.setter(order_id).amount.set(remaining);
+        }
+
+        let buyer = msg::sender();
+        self.erc6909._transfer(seller, buyer, id, amount)?;
+
+        let (receiver, royalty) = self.erc2981.royalty_info(id, cost);
+        let royalty = if receiver.is_zero() { U256::ZERO } else { royalty };
+        // Underflow not possible: royalty fractions can't exceed the fee
+        // denominator, so `royalty` <= `cost`.
+        let proceeds = cost - royalty;
+
+        self._pay(receiver, royalty)?;
+        self._pay(seller, proceeds)?;
+
+        evm::log(OrderFilled { order_id, buyer, amount, cost, royalty });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Marketplace {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc2981 for Erc6909Marketplace {
+    fn royalty_info(
+        &self,
+        token_id: U256,
+        sale_price: U256,
+    ) -> (Address, U256) {
+        self.erc2981.royalty_info(token_id, sale_price)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Marketplace {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Marketplace>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+            || self.erc2981.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Marketplace {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909Marketplace {
+    /// Removes the order `order_id` from the order book.
+    fn _remove_order(&mut self, order_id: U256) {
+        let mut order = self.orders.setter(order_id);
+        order.seller.erase();
+        order.id.erase();
+        order.amount.erase();
+        order.price.erase();
+    }
+
+    /// Sends `amount` wei to `receiver`, if any.
+    fn _pay(&mut self, receiver: Address, amount: U256) -> Result<(), Error> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        call::call(Call::new_in(self).value(amount), receiver, &[]).map_err(
+            |_| {
+                Error::SettlementFailed(ERC6909SettlementFailed {
+                    receiver,
+                    amount,
+                })
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909Marketplace {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Erc6909Marketplace {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        let mut events = Erc6909::events();
+        events.extend([
+            event::<OrderListed>(
+                "event OrderListed(uint256 indexed order_id, address indexed \
+                 seller, uint256 indexed id, uint256 amount, uint256 price)",
+            ),
+            event::<OrderCancelled>(
+                "event OrderCancelled(uint256 indexed order_id)",
+            ),
+            event::<OrderFilled>(
+                "event OrderFilled(uint256 indexed order_id, address indexed \
+                 buyer, uint256 amount, uint256 cost, uint256 royalty)",
+            ),
+        ]);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        aliases::U96, fixed_bytes, uint, Address, FixedBytes, U256,
+    };
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const PRICE: U256 = uint!(1000_U256);
+    const FEE_DENOMINATOR: U96 = uint!(10000_U96);
+    const ROYALTY_FRACTION: U96 = uint!(500_U96);
+
+    fn init(
+        contract: &Contract<Erc6909Marketplace>,
+        seller: Address,
+        royalty_receiver: Address,
+    ) {
+        contract.init(seller, |contract| {
+            contract.erc2981.fee_denominator.set(FEE_DENOMINATOR);
+        });
+        contract
+            .sender(seller)
+            .erc2981
+            ._set_default_royalty(royalty_receiver, ROYALTY_FRACTION)
+            .motsu_expect("should set default royalty");
+        contract
+            .sender(seller)
+            ._mint(seller, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+    }
+
+    fn list(contract: &Contract<Erc6909Marketplace>, seller: Address) -> U256 {
+        contract
+            .sender(seller)
+            .list(ID, AMOUNT, PRICE)
+            .motsu_expect("should list tokens")
+    }
+
+    #[motsu::test]
+    fn list_creates_order(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+
+        let first = list(&contract, alice);
+        let second = list(&contract, alice);
+
+        assert_eq!(U256::ZERO, first);
+        assert_eq!(U256::ONE, second);
+        assert_eq!(
+            (alice, ID, AMOUNT, PRICE),
+            contract.sender(alice).order(first)
+        );
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+        contract.assert_emitted(&OrderListed {
+            order_id: first,
+            seller: alice,
+            id: ID,
+            amount: AMOUNT,
+            price: PRICE,
+        });
+    }
+
+    #[motsu::test]
+    fn list_reverts_on_zero_amount(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .list(ID, U256::ZERO, PRICE)
+            .motsu_expect_err("should not list zero tokens");
+
+        assert!(matches!(err, Error::EmptyOrder(_)));
+    }
+
+    #[motsu::test]
+    fn cancel_removes_order(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            .cancel(order_id)
+            .motsu_expect_err("should not cancel another seller's order");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedOrderCancellation(
+                ERC6909UnauthorizedOrderCancellation { order_id: o, account }
+            ) if o == order_id && account == bob
+        ));
+
+        contract
+            .sender(alice)
+            .cancel(order_id)
+            .motsu_expect("should cancel order");
+
+        assert_eq!(
+            (Address::ZERO, U256::ZERO, U256::ZERO, U256::ZERO),
+            contract.sender(alice).order(order_id)
+        );
+        contract.assert_emitted(&OrderCancelled { order_id });
+    }
+
+    #[motsu::test]
+    fn fill_transfers_tokens_and_splits_payment(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+
+        let amount = uint!(4_U256);
+        let cost = PRICE * amount;
+        let royalty =
+            cost * U256::from(ROYALTY_FRACTION) / U256::from(FEE_DENOMINATOR);
+        bob.fund(cost);
+
+        contract
+            .sender_and_value(bob, cost)
+            .fill(order_id, amount)
+            .motsu_expect("should fill order");
+
+        assert_eq!(amount, contract.sender(bob).balance_of(bob, ID));
+        assert_eq!(AMOUNT - amount, contract.sender(bob).balance_of(alice, ID));
+        assert_eq!(royalty, charlie.balance());
+        assert_eq!(cost - royalty, alice.balance());
+        assert!(contract.address().balance().is_zero());
+        assert_eq!(
+            (alice, ID, AMOUNT - amount, PRICE),
+            contract.sender(bob).order(order_id)
+        );
+        contract.assert_emitted(&OrderFilled {
+            order_id,
+            buyer: bob,
+            amount,
+            cost,
+            royalty,
+        });
+    }
+
+    #[motsu::test]
+    fn fill_removes_completely_filled_order(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+
+        let cost = PRICE * AMOUNT;
+        bob.fund(cost);
+
+        contract
+            .sender_and_value(bob, cost)
+            .fill(order_id, AMOUNT)
+            .motsu_expect("should fill order");
+
+        let err = contract
+            .sender(bob)
+            .fill(order_id, AMOUNT)
+            .motsu_expect_err("should not fill a completely filled order");
+
+        assert!(matches!(
+            err,
+            Error::NonexistentOrder(ERC6909NonexistentOrder { order_id: o })
+                if o == order_id
+        ));
+    }
+
+    #[motsu::test]
+    fn fill_reverts_when_amount_exceeds_order(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+
+        let amount = AMOUNT + U256::ONE;
+        let err = contract
+            .sender(bob)
+            .fill(order_id, amount)
+            .motsu_expect_err("should not fill more than listed");
+
+        assert!(matches!(
+            err,
+            Error::InvalidFillAmount(ERC6909InvalidFillAmount {
+                order_id: o,
+                available,
+                amount: a,
+            }) if o == order_id && available == AMOUNT && a == amount
+        ));
+    }
+
+    #[motsu::test]
+    fn fill_reverts_on_incorrect_payment(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+
+        let value = PRICE;
+        bob.fund(value);
+
+        let err = contract
+            .sender_and_value(bob, value)
+            .fill(order_id, AMOUNT)
+            .motsu_expect_err("should not fill with an incorrect payment");
+
+        assert!(matches!(
+            err,
+            Error::IncorrectOrderPayment(ERC6909IncorrectOrderPayment {
+                order_id: o,
+                cost,
+                value: v,
+            }) if o == order_id && cost == PRICE * AMOUNT && v == value
+        ));
+    }
+
+    #[motsu::test]
+    fn fill_reverts_when_seller_moved_tokens(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, charlie);
+        let order_id = list(&contract, alice);
+        contract
+            .sender(alice)
+            .transfer(charlie, ID, AMOUNT)
+            .motsu_expect("should transfer tokens");
+
+        let cost = PRICE * AMOUNT;
+        bob.fund(cost);
+
+        let err = contract
+            .sender_and_value(bob, cost)
+            .fill(order_id, AMOUNT)
+            .motsu_expect_err("should not fill without the seller's tokens");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909Marketplace as IErc6909Marketplace>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x4402f975");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909Marketplace>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Marketplace as IErc6909Marketplace>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Marketplace as IErc6909>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Marketplace as IErc2981>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Marketplace as IErc165>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -1,15 +1,121 @@
 //! Extension of ERC-6909 that adds metadata request support.
+//!
+//! The metadata of a token id can be frozen with
+//! [`Erc6909Metadata::_freeze_metadata`], e.g. once the token is revealed,
+//! after which it can't be changed anymore.
+//!
+//! [`IErc6909Metadata`] getters return empty values for unknown token ids.
+//! Contracts expecting ERC-721-style getters, reverting with
+//! [`ERC6909NonexistentToken`] for token ids that were never minted, expose
+//! [`Erc6909Metadata::checked_name`], [`Erc6909Metadata::checked_symbol`] and
+//! [`Erc6909Metadata::checked_decimals`] instead, checking existence against
+//! an [`IErc6909Exists`] contract such as
+//! [`crate::token::erc6909::extensions::Erc6909Supply`].
+//!
+//! Token ids whose decimals were never set report the default amount of
+//! decimals, which is `0` unless changed with
+//! [`Erc6909Metadata::with_default_decimals_18`] or
+//! [`Erc6909Metadata::_set_default_decimals`], e.g. in the constructor.
+//!
+//! Note that [`Erc6909Metadata::_set_name`], [`Erc6909Metadata::_set_symbol`],
+//! [`Erc6909Metadata::_set_decimals`], [`Erc6909Metadata::_clear_metadata`]
+//! and [`Erc6909Metadata::_freeze_metadata`] are not exposed by default. You
+//! should expose them manually in your contract's abi, guarded by proper
+//! access control.
 
 use alloc::{string::String, vec, vec::Vec};
 
 use alloy_primitives::{U256, U8};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
 use stylus_sdk::{
+    call::MethodError,
+    evm,
     prelude::*,
-    storage::{StorageMap, StorageString, StorageU8},
+    storage::{StorageBool, StorageMap, StorageString, StorageU8},
 };
 
-use crate::token::erc6909::Erc6909;
+use crate::token::erc6909::{
+    extensions::IErc6909Exists, impl_as_erc6909, Erc6909,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the name of token `id` changes to `new_name`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `new_name` - New name of the token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909NameUpdated(uint256 indexed id, string new_name);
+
+        /// Emitted when the symbol of token `id` changes to `new_symbol`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `new_symbol` - New symbol of the token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909SymbolUpdated(uint256 indexed id, string new_symbol);
+
+        /// Emitted when the decimals of token `id` change to `new_decimals`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `new_decimals` - New amount of decimals of the token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909DecimalsUpdated(uint256 indexed id, uint8 new_decimals);
+
+        /// Emitted when the metadata of token `id` is frozen.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MetadataFrozen(uint256 indexed id);
+
+        /// Emitted when the name, symbol and decimals of token `id` are
+        /// cleared.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC6909MetadataCleared(uint256 indexed id);
+    }
+
+    sol! {
+        /// Indicates that the metadata of token `id` can't change, because
+        /// it is frozen.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909FrozenMetadata(uint256 id);
+
+        /// Indicates that token `id` doesn't exist.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentToken(uint256 id);
+    }
+}
+
+/// An [`Erc6909Metadata`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that the metadata of a token id is frozen.
+    FrozenMetadata(ERC6909FrozenMetadata),
+    /// Indicates that a token id doesn't exist.
+    NonexistentToken(ERC6909NonexistentToken),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
 
 /// State of an [`Erc6909Metadata`] contract.
 #[storage]
@@ -17,13 +123,25 @@ pub struct Erc6909Metadata {
     /// [`Erc6909`] contract.
     pub erc6909: Erc6909,
     /// Mapping from token id to token name.
-    pub(crate) _name: StorageMap<U256, StorageString>,
+    pub(crate) name: StorageMap<U256, StorageString>,
     /// Mapping from token id to token symbol.
-    pub(crate) _symbol: StorageMap<U256, StorageString>,
+    pub(crate) symbol: StorageMap<U256, StorageString>,
     /// Mapping from token id to the amount of decimals a token has.
-    pub(crate) _decimals: StorageMap<U256, StorageU8>,
+    pub(crate) decimals: StorageMap<U256, StorageU8>,
+    /// Mapping from token id to whether its decimals were set.
+    pub(crate) decimals_set: StorageMap<U256, StorageBool>,
+    /// Amount of decimals of token ids whose decimals were never set.
+    pub(crate) default_decimals: StorageU8,
+    /// Mapping from token id to whether its metadata is frozen.
+    pub(crate) frozen: StorageMap<U256, StorageBool>,
 }
 
+impl_as_erc6909!(Erc6909Metadata, erc6909);
+
+#[public]
+#[implements(IErc6909Metadata, IErc6909MetadataFreeze)]
+impl Erc6909Metadata {}
+
 /// Interface for the optional metadata functions from the ERC-6909 standard.
 #[interface_id]
 pub trait IErc6909Metadata {
@@ -52,17 +170,633 @@ pub trait IErc6909Metadata {
     fn decimals(&self, id: U256) -> U8;
 }
 
+/// Interface for contracts whose token metadata can be frozen.
+#[interface_id]
+pub trait IErc6909MetadataFreeze {
+    /// Returns whether the metadata of token `id` is frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn metadata_frozen(&self, id: U256) -> bool;
+}
+
 #[public]
 impl IErc6909Metadata for Erc6909Metadata {
-    fn name(&self, _id: U256) -> String {
-        todo!()
+    fn name(&self, id: U256) -> String {
+        self.name.get(id).get_string()
+    }
+
+    fn symbol(&self, id: U256) -> String {
+        self.symbol.get(id).get_string()
+    }
+
+    fn decimals(&self, id: U256) -> U8 {
+        if self.decimals_set.get(id) {
+            self.decimals.get(id)
+        } else {
+            self.default_decimals.get()
+        }
+    }
+}
+
+#[public]
+impl IErc6909MetadataFreeze for Erc6909Metadata {
+    fn metadata_frozen(&self, id: U256) -> bool {
+        self.frozen.get(id)
+    }
+}
+
+impl Erc6909Metadata {
+    /// Sets `name` as the name of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `name` - New name of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909NameUpdated`].
+    pub fn _set_name(&mut self, id: U256, name: String) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.name.setter(id).set_str(&name);
+        evm::log(ERC6909NameUpdated { id, new_name: name });
+        Ok(())
+    }
+
+    /// Sets `symbol` as the symbol of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `symbol` - New symbol of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909SymbolUpdated`].
+    pub fn _set_symbol(
+        &mut self,
+        id: U256,
+        symbol: String,
+    ) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.symbol.setter(id).set_str(&symbol);
+        evm::log(ERC6909SymbolUpdated { id, new_symbol: symbol });
+        Ok(())
+    }
+
+    /// Sets `decimals` as the amount of decimals of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `decimals` - New amount of decimals of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909DecimalsUpdated`].
+    pub fn _set_decimals(
+        &mut self,
+        id: U256,
+        decimals: U8,
+    ) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.decimals.setter(id).set(decimals);
+        self.decimals_set.setter(id).set(true);
+        evm::log(ERC6909DecimalsUpdated {
+            id,
+            new_decimals: decimals.to::<u8>(),
+        });
+        Ok(())
+    }
+
+    /// Sets `decimals` as the amount of decimals of token ids whose decimals
+    /// were never set with [`Self::_set_decimals`].
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    /// Frozen token ids whose decimals were never set also report the new
+    /// default.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `decimals` - New default amount of decimals.
+    pub fn _set_default_decimals(&mut self, decimals: U8) {
+        self.default_decimals.set(decimals);
+    }
+
+    /// Makes token ids default to 18 decimals, as most fungible tokens do.
+    ///
+    /// Meant to be called in the constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    pub fn with_default_decimals_18(&mut self) {
+        self._set_default_decimals(U8::from(18));
+    }
+
+    /// Makes token ids default to 0 decimals, as most ticket-like tokens do.
+    ///
+    /// Meant to be called in the constructor. This is also the default when
+    /// no preset is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    pub fn with_default_decimals_0(&mut self) {
+        self._set_default_decimals(U8::ZERO);
+    }
+
+    /// Freezes the name, symbol and decimals of token `id`, which can't be
+    /// changed anymore afterwards.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is already
+    ///   frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`MetadataFrozen`].
+    pub fn _freeze_metadata(&mut self, id: U256) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.frozen.setter(id).set(true);
+        evm::log(MetadataFrozen { id });
+        Ok(())
     }
 
-    fn symbol(&self, _id: U256) -> String {
-        todo!()
+    /// Clears the name, symbol and decimals of token `id`, e.g. once all its
+    /// tokens are burned and the id is retired, so that their storage is
+    /// refunded. The decimals of token `id` fall back to the default amount
+    /// of decimals afterwards.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is frozen.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC6909MetadataCleared`].
+    pub fn _clear_metadata(&mut self, id: U256) -> Result<(), Error> {
+        self._check_not_frozen(id)?;
+        self.name.delete(id);
+        self.symbol.delete(id);
+        self.decimals.delete(id);
+        self.decimals_set.delete(id);
+        evm::log(ERC6909MetadataCleared { id });
+        Ok(())
+    }
+
+    /// Returns the name of token `id`, reverting if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `tokens` - Contract tracking the existence of token ids.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentToken`] - If token `id` doesn't exist.
+    pub fn checked_name(
+        &self,
+        id: U256,
+        tokens: &impl IErc6909Exists,
+    ) -> Result<String, Error> {
+        _check_exists(id, tokens)?;
+        Ok(self.name(id))
+    }
+
+    /// Returns the symbol of token `id`, reverting if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `tokens` - Contract tracking the existence of token ids.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentToken`] - If token `id` doesn't exist.
+    pub fn checked_symbol(
+        &self,
+        id: U256,
+        tokens: &impl IErc6909Exists,
+    ) -> Result<String, Error> {
+        _check_exists(id, tokens)?;
+        Ok(self.symbol(id))
+    }
+
+    /// Returns the amount of decimals of token `id`, reverting if it doesn't
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `tokens` - Contract tracking the existence of token ids.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentToken`] - If token `id` doesn't exist.
+    pub fn checked_decimals(
+        &self,
+        id: U256,
+        tokens: &impl IErc6909Exists,
+    ) -> Result<U8, Error> {
+        _check_exists(id, tokens)?;
+        Ok(self.decimals(id))
+    }
+
+    /// Checks that the metadata of token `id` is not frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FrozenMetadata`] - If the metadata of token `id` is frozen.
+    fn _check_not_frozen(&self, id: U256) -> Result<(), Error> {
+        if self.metadata_frozen(id) {
+            return Err(Error::FrozenMetadata(ERC6909FrozenMetadata { id }));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that token `id` exists in `tokens`.
+///
+/// # Arguments
+///
+/// * `id` - Token id as a number.
+/// * `tokens` - Contract tracking the existence of token ids.
+///
+/// # Errors
+///
+/// * [`ERC6909NonexistentToken`] - If token `id` doesn't exist.
+pub(crate) fn _check_exists(
+    id: U256,
+    tokens: &impl IErc6909Exists,
+) -> Result<(), ERC6909NonexistentToken> {
+    if tokens.exists(id) {
+        Ok(())
+    } else {
+        Err(ERC6909NonexistentToken { id })
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Erc6909Metadata {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        let mut events = Erc6909::events();
+        events.extend([
+            event::<ERC6909NameUpdated>(
+                "event ERC6909NameUpdated(uint256 indexed id, string new_name)",
+            ),
+            event::<ERC6909SymbolUpdated>(
+                "event ERC6909SymbolUpdated(uint256 indexed id, string \
+                 new_symbol)",
+            ),
+            event::<ERC6909DecimalsUpdated>(
+                "event ERC6909DecimalsUpdated(uint256 indexed id, uint8 \
+                 new_decimals)",
+            ),
+            event::<MetadataFrozen>("event MetadataFrozen(uint256 indexed id)"),
+            event::<ERC6909MetadataCleared>(
+                "event ERC6909MetadataCleared(uint256 indexed id)",
+            ),
+        ]);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::extensions::Erc6909Supply;
+
+    unsafe impl TopLevelStorage for Erc6909Metadata {}
+
+    const ID: U256 = uint!(1_U256);
+
+    #[storage]
+    struct Erc6909MetadataSupplyExample {
+        metadata: Erc6909Metadata,
+        supply: Erc6909Supply,
+    }
+
+    unsafe impl TopLevelStorage for Erc6909MetadataSupplyExample {}
+
+    #[motsu::test]
+    fn set_metadata(contract: Contract<Erc6909Metadata>, alice: Address) {
+        contract
+            .sender(alice)
+            ._set_name(ID, "Gold".into())
+            .motsu_expect("should set name");
+        contract
+            .sender(alice)
+            ._set_symbol(ID, "GLD".into())
+            .motsu_expect("should set symbol");
+        contract
+            .sender(alice)
+            ._set_decimals(ID, uint!(18_U8))
+            .motsu_expect("should set decimals");
+
+        assert_eq!("Gold", contract.sender(alice).name(ID));
+        assert_eq!("GLD", contract.sender(alice).symbol(ID));
+        assert_eq!(uint!(18_U8), contract.sender(alice).decimals(ID));
+        contract.assert_emitted(&ERC6909DecimalsUpdated {
+            id: ID,
+            new_decimals: 18,
+        });
+    }
+
+    #[motsu::test]
+    fn decimals_fall_back_to_default(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        assert_eq!(uint!(0_U8), contract.sender(alice).decimals(ID));
+
+        contract.sender(alice).with_default_decimals_18();
+        assert_eq!(uint!(18_U8), contract.sender(alice).decimals(ID));
+
+        contract.sender(alice).with_default_decimals_0();
+        assert_eq!(uint!(0_U8), contract.sender(alice).decimals(ID));
+    }
+
+    #[motsu::test]
+    fn set_decimals_overrides_default(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract.sender(alice).with_default_decimals_18();
+        contract
+            .sender(alice)
+            ._set_decimals(ID, uint!(0_U8))
+            .motsu_expect("should set decimals");
+
+        assert_eq!(uint!(0_U8), contract.sender(alice).decimals(ID));
+        assert_eq!(
+            uint!(18_U8),
+            contract.sender(alice).decimals(ID + U256::ONE)
+        );
+    }
+
+    #[motsu::test]
+    fn freeze_metadata(contract: Contract<Erc6909Metadata>, alice: Address) {
+        assert!(!contract.sender(alice).metadata_frozen(ID));
+
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze metadata");
+
+        assert!(contract.sender(alice).metadata_frozen(ID));
+        assert!(!contract.sender(alice).metadata_frozen(ID + U256::ONE));
+        contract.assert_emitted(&MetadataFrozen { id: ID });
+    }
+
+    #[motsu::test]
+    fn setters_revert_when_metadata_frozen(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_name(ID, "Gold".into())
+            .motsu_expect("should set name");
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze metadata");
+
+        let err = contract
+            .sender(alice)
+            ._set_name(ID, "Silver".into())
+            .motsu_expect_err("should not set name of frozen token");
+        assert!(matches!(
+            err,
+            Error::FrozenMetadata(ERC6909FrozenMetadata { id }) if id == ID
+        ));
+
+        let err = contract
+            .sender(alice)
+            ._set_symbol(ID, "SLV".into())
+            .motsu_expect_err("should not set symbol of frozen token");
+        assert!(matches!(err, Error::FrozenMetadata(_)));
+
+        let err = contract
+            .sender(alice)
+            ._set_decimals(ID, uint!(6_U8))
+            .motsu_expect_err("should not set decimals of frozen token");
+        assert!(matches!(err, Error::FrozenMetadata(_)));
+
+        assert_eq!("Gold", contract.sender(alice).name(ID));
+        assert!(contract.sender(alice).symbol(ID).is_empty());
+        assert_eq!(uint!(0_U8), contract.sender(alice).decimals(ID));
+
+        contract
+            .sender(alice)
+            ._set_name(ID + U256::ONE, "Silver".into())
+            .motsu_expect("should set name of another token");
+    }
+
+    #[motsu::test]
+    fn clear_metadata(contract: Contract<Erc6909Metadata>, alice: Address) {
+        contract.sender(alice).with_default_decimals_18();
+        contract
+            .sender(alice)
+            ._set_name(ID, "Gold".into())
+            .motsu_expect("should set name");
+        contract
+            .sender(alice)
+            ._set_symbol(ID, "GLD".into())
+            .motsu_expect("should set symbol");
+        contract
+            .sender(alice)
+            ._set_decimals(ID, uint!(6_U8))
+            .motsu_expect("should set decimals");
+
+        contract
+            .sender(alice)
+            ._clear_metadata(ID)
+            .motsu_expect("should clear metadata");
+
+        assert!(contract.sender(alice).name(ID).is_empty());
+        assert!(contract.sender(alice).symbol(ID).is_empty());
+        assert_eq!(uint!(18_U8), contract.sender(alice).decimals(ID));
+        contract.assert_emitted(&ERC6909MetadataCleared { id: ID });
+    }
+
+    #[motsu::test]
+    fn clear_metadata_reverts_when_frozen(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_name(ID, "Gold".into())
+            .motsu_expect("should set name");
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze metadata");
+
+        let err = contract
+            .sender(alice)
+            ._clear_metadata(ID)
+            .motsu_expect_err("should not clear metadata of frozen token");
+
+        assert!(matches!(
+            err,
+            Error::FrozenMetadata(ERC6909FrozenMetadata { id }) if id == ID
+        ));
+        assert_eq!("Gold", contract.sender(alice).name(ID));
+    }
+
+    #[motsu::test]
+    fn freeze_metadata_reverts_when_already_frozen(
+        contract: Contract<Erc6909Metadata>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect("should freeze metadata");
+
+        let err = contract
+            .sender(alice)
+            ._freeze_metadata(ID)
+            .motsu_expect_err("should not freeze metadata twice");
+        assert!(matches!(
+            err,
+            Error::FrozenMetadata(ERC6909FrozenMetadata { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn checked_getters_revert_for_nonexistent_token(
+        contract: Contract<Erc6909MetadataSupplyExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .metadata
+                ._set_name(ID, "Gold".into())
+                .expect("should set name");
+        });
+
+        let example = contract.sender(alice);
+        let err = example
+            .metadata
+            .checked_name(ID, &example.supply)
+            .motsu_expect_err("should not read name of nonexistent token");
+        assert!(matches!(
+            err,
+            Error::NonexistentToken(ERC6909NonexistentToken { id }) if id == ID
+        ));
+        assert!(matches!(
+            example.metadata.checked_symbol(ID, &example.supply),
+            Err(Error::NonexistentToken(_))
+        ));
+        assert!(matches!(
+            example.metadata.checked_decimals(ID, &example.supply),
+            Err(Error::NonexistentToken(_))
+        ));
+        assert_eq!("Gold", example.metadata.name(ID));
+    }
+
+    #[motsu::test]
+    fn checked_getters_return_metadata_of_minted_token(
+        contract: Contract<Erc6909MetadataSupplyExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .metadata
+                ._set_name(ID, "Gold".into())
+                .expect("should set name");
+            contract
+                .metadata
+                ._set_decimals(ID, uint!(18_U8))
+                .expect("should set decimals");
+            contract.supply._mint(alice, ID, U256::ONE).expect("should mint");
+            contract.supply._burn(alice, ID, U256::ONE).expect("should burn");
+        });
+
+        let example = contract.sender(alice);
+        assert_eq!(
+            "Gold",
+            example
+                .metadata
+                .checked_name(ID, &example.supply)
+                .motsu_expect("should read name")
+        );
+        assert!(example
+            .metadata
+            .checked_symbol(ID, &example.supply)
+            .motsu_expect("should read symbol")
+            .is_empty());
+        assert_eq!(
+            uint!(18_U8),
+            example
+                .metadata
+                .checked_decimals(ID, &example.supply)
+                .motsu_expect("should read decimals")
+        );
     }
 
-    fn decimals(&self, _id: U256) -> U8 {
-        todo!()
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909Metadata as IErc6909MetadataFreeze>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x504c9a5f");
+        assert_eq!(actual, expected);
     }
 }
@@ -0,0 +1,723 @@
+//! Extension of ERC-6909 that allows holders of a legacy ERC-1155 or ERC-6909
+//! token to migrate their balances to this contract.
+//!
+//! Migrating pulls the legacy tokens from the caller into
+//! [`MIGRATION_BURN_ADDRESS`], where they can't be moved anymore, and mints
+//! the same amount of the same token id here. Migration is enabled per legacy
+//! token and id, so that live deployments can be moved onto this contract
+//! progressively.
+//!
+//! Before migrating, holders must allow this contract to move their legacy
+//! tokens, with `setApprovalForAll` for ERC-1155 or `setOperator` /
+//! `approve` for ERC-6909.
+//!
+//! Note that [`Erc6909Migration::_set_migration_enabled`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{address, Address, FixedBytes, U256};
+pub use legacy::{ILegacyErc1155, ILegacyErc6909};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageMap, StorageU8},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// Address legacy tokens are sent to when migrated.
+pub const MIGRATION_BURN_ADDRESS: Address =
+    address!("0x000000000000000000000000000000000000dEaD");
+
+/// Token standard implemented by a legacy token contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LegacyStandard {
+    /// ERC-1155 multi token.
+    Erc1155,
+    /// ERC-6909 multi token.
+    Erc6909,
+}
+
+impl LegacyStandard {
+    fn to_u8(self) -> u8 {
+        match self {
+            LegacyStandard::Erc1155 => 1,
+            LegacyStandard::Erc6909 => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(LegacyStandard::Erc1155),
+            2 => Some(LegacyStandard::Erc6909),
+            _ => None,
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when migration of token `id` from `legacy_token` is
+        /// enabled or disabled.
+        ///
+        /// * `legacy_token` - Address of the legacy token contract.
+        /// * `id` - Token id as a number.
+        /// * `enabled` - Whether tokens of `id` can be migrated.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MigrationEnabledSet(
+            address indexed legacy_token,
+            uint256 indexed id,
+            bool enabled
+        );
+
+        /// Emitted when `account` migrates `amount` tokens of type `id` from
+        /// `legacy_token`.
+        ///
+        /// * `account` - Address of the migrating account.
+        /// * `legacy_token` - Address of the legacy token contract.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens migrated.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Migrated(
+            address indexed account,
+            address indexed legacy_token,
+            uint256 indexed id,
+            uint256 amount
+        );
+    }
+
+    sol! {
+        /// Indicates that tokens of type `id` can't be migrated from
+        /// `legacy_token`.
+        ///
+        /// * `legacy_token` - Address of the legacy token contract.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909MigrationDisabled(address legacy_token, uint256 id);
+
+        /// Indicates that the legacy tokens of type `id` couldn't be pulled
+        /// from `legacy_token`.
+        ///
+        /// * `legacy_token` - Address of the legacy token contract.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909MigrationFailedTransfer(address legacy_token, uint256 id);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(missing_docs)]
+mod legacy {
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Subset of the ERC-1155 interface used to pull legacy tokens.
+        interface ILegacyErc1155 {
+            function safeTransferFrom(
+                address from,
+                address to,
+                uint256 id,
+                uint256 value,
+                bytes calldata data
+            ) external;
+        }
+
+        /// Subset of the ERC-6909 interface used to pull legacy tokens.
+        interface ILegacyErc6909 {
+            function transferFrom(
+                address sender,
+                address receiver,
+                uint256 id,
+                uint256 amount
+            ) external returns (bool);
+        }
+    }
+}
+
+/// An [`Erc6909Migration`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that migration of a token id from a legacy token is not
+    /// enabled.
+    MigrationDisabled(ERC6909MigrationDisabled),
+    /// Indicates that legacy tokens couldn't be pulled from the caller.
+    MigrationFailedTransfer(ERC6909MigrationFailedTransfer),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Migration`] contract.
+#[storage]
+pub struct Erc6909Migration {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from legacy token to the standard it implements, encoded as
+    /// a [`LegacyStandard`].
+    pub(crate) legacy_standards: StorageMap<Address, StorageU8>,
+    /// Mapping from legacy token to whether each of its token ids can be
+    /// migrated.
+    pub(crate) migration_enabled:
+        StorageMap<Address, StorageMap<U256, StorageBool>>,
+}
+
+impl_as_erc6909!(Erc6909Migration, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909Migration {}
+
+/// Required interface of an [`Erc6909Migration`] contract.
+#[interface_id]
+pub trait IErc6909Migration: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns whether tokens of type `id` can be migrated from
+    /// `legacy_token`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `legacy_token` - Address of the legacy token contract.
+    /// * `id` - Token id as a number.
+    fn is_migration_enabled(&self, legacy_token: Address, id: U256) -> bool;
+
+    /// Pulls `amount` tokens of type `id` of the caller from `legacy_token`
+    /// into [`MIGRATION_BURN_ADDRESS`], and mints the same amount of `id` to
+    /// the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `legacy_token` - Address of the legacy token contract.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to migrate.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::MigrationDisabled`] - If migration of `id` from
+    ///   `legacy_token` is not enabled.
+    /// * [`Error::MigrationFailedTransfer`] - If the legacy tokens couldn't be
+    ///   pulled from the caller.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Migrated`].
+    fn migrate(
+        &mut self,
+        legacy_token: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Migration<Error = Error>,
+    IErc165
+)]
+impl Erc6909Migration {}
+
+#[public]
+impl IErc6909Migration for Erc6909Migration {
+    type Error = Error;
+
+    fn is_migration_enabled(&self, legacy_token: Address, id: U256) -> bool {
+        self.migration_enabled.get(legacy_token).get(id)
+    }
+
+    fn migrate(
+        &mut self,
+        legacy_token: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        let account = msg::sender();
+        let standard = LegacyStandard::from_u8(
+            self.legacy_standards.get(legacy_token).to(),
+        );
+
+        let Some(standard) =
+            standard.filter(|_| self.is_migration_enabled(legacy_token, id))
+        else {
+            return Err(Error::MigrationDisabled(ERC6909MigrationDisabled {
+                legacy_token,
+                id,
+            }));
+        };
+
+        self._pull_legacy_tokens(legacy_token, standard, account, id, amount)?;
+        self._mint(account, id, amount)?;
+
+        evm::log(Migrated { account, legacy_token, id, amount });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Migration {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Migration {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Migration>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Migration {
+    /// Enables or disables the migration of token `id` from `legacy_token`,
+    /// which implements `standard`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `legacy_token` - Address of the legacy token contract.
+    /// * `standard` - Token standard implemented by `legacy_token`.
+    /// * `id` - Token id as a number.
+    /// * `enabled` - Whether tokens of `id` can be migrated.
+    ///
+    /// # Events
+    ///
+    /// * [`MigrationEnabledSet`].
+    pub fn _set_migration_enabled(
+        &mut self,
+        legacy_token: Address,
+        standard: LegacyStandard,
+        id: U256,
+        enabled: bool,
+    ) {
+        self.legacy_standards
+            .setter(legacy_token)
+            .set(alloy_primitives::U8::from(standard.to_u8()));
+        self.migration_enabled.setter(legacy_token).setter(id).set(enabled);
+        evm::log(MigrationEnabledSet { legacy_token, id, enabled });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909Migration {
+    /// Moves `amount` legacy tokens of type `id` from `account` to
+    /// [`MIGRATION_BURN_ADDRESS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `legacy_token` - Address of the legacy token contract.
+    /// * `standard` - Token standard implemented by `legacy_token`.
+    /// * `account` - Address of the migrating account.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to pull.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::MigrationFailedTransfer`] - If the legacy token reverts, or
+    ///   an ERC-6909 legacy token returns `false`.
+    fn _pull_legacy_tokens(
+        &mut self,
+        legacy_token: Address,
+        standard: LegacyStandard,
+        account: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let succeeded = match standard {
+            LegacyStandard::Erc1155 => ILegacyErc1155::new(legacy_token)
+                .safe_transfer_from(
+                    Call::new_in(self),
+                    account,
+                    MIGRATION_BURN_ADDRESS,
+                    id,
+                    amount,
+                    alloy_primitives::Bytes::new(),
+                )
+                .is_ok(),
+            LegacyStandard::Erc6909 => ILegacyErc6909::new(legacy_token)
+                .transfer_from(
+                    Call::new_in(self),
+                    account,
+                    MIGRATION_BURN_ADDRESS,
+                    id,
+                    amount,
+                )
+                .unwrap_or(false),
+        };
+
+        if !succeeded {
+            return Err(Error::MigrationFailedTransfer(
+                ERC6909MigrationFailedTransfer { legacy_token, id },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909Migration {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::IErc6909;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[motsu::test]
+    fn set_migration_enabled_emits_event(
+        contract: Contract<Erc6909Migration>,
+        legacy: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_migration_enabled(
+            legacy.address(),
+            LegacyStandard::Erc6909,
+            ID,
+            true,
+        );
+
+        assert!(contract
+            .sender(alice)
+            .is_migration_enabled(legacy.address(), ID));
+        contract.assert_emitted(&MigrationEnabledSet {
+            legacy_token: legacy.address(),
+            id: ID,
+            enabled: true,
+        });
+    }
+
+    #[motsu::test]
+    fn migrate_reverts_when_disabled(
+        contract: Contract<Erc6909Migration>,
+        legacy: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .migrate(legacy.address(), ID, AMOUNT)
+            .motsu_expect_err("should not migrate disabled id");
+
+        assert!(matches!(
+            err,
+            Error::MigrationDisabled(ERC6909MigrationDisabled {
+                legacy_token,
+                id,
+            }) if legacy_token == legacy.address() && id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn migrate_moves_legacy_erc6909_tokens(
+        contract: Contract<Erc6909Migration>,
+        legacy: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        legacy
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint legacy tokens");
+        legacy
+            .sender(alice)
+            .set_operator(contract.address(), true)
+            .motsu_expect("should set migration contract as operator");
+        contract.sender(alice)._set_migration_enabled(
+            legacy.address(),
+            LegacyStandard::Erc6909,
+            ID,
+            true,
+        );
+
+        contract
+            .sender(alice)
+            .migrate(legacy.address(), ID, AMOUNT)
+            .motsu_expect("should migrate legacy tokens");
+
+        assert!(legacy.sender(alice).balance_of(alice, ID).is_zero());
+        assert_eq!(
+            legacy.sender(alice).balance_of(MIGRATION_BURN_ADDRESS, ID),
+            AMOUNT
+        );
+        assert_eq!(contract.sender(alice).balance_of(alice, ID), AMOUNT);
+        contract.assert_emitted(&Migrated {
+            account: alice,
+            legacy_token: legacy.address(),
+            id: ID,
+            amount: AMOUNT,
+        });
+    }
+
+    #[motsu::test]
+    fn migrate_reverts_without_legacy_approval(
+        contract: Contract<Erc6909Migration>,
+        legacy: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        legacy
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint legacy tokens");
+        contract.sender(alice)._set_migration_enabled(
+            legacy.address(),
+            LegacyStandard::Erc6909,
+            ID,
+            true,
+        );
+
+        let err = contract
+            .sender(alice)
+            .migrate(legacy.address(), ID, AMOUNT)
+            .motsu_expect_err("should not pull unapproved tokens");
+
+        assert!(matches!(err, Error::MigrationFailedTransfer(_)));
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Migration as IErc6909Migration>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x458f9c58");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,581 @@
+//! Extension of ERC-6909 that allows accounts to mint a capped amount of each
+//! token id.
+//!
+//! Each minter is granted a quota per token id, which is decreased by every
+//! mint it performs through [`IErc6909MinterQuota::mint_from_quota`]. This
+//! lets partners mint limited amounts of specific ids, without giving them
+//! unrestricted minting power.
+//!
+//! Note that [`Erc6909MinterQuota::_set_minter_quota`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the quota of `minter` for token `id` is set to
+        /// `quota`.
+        ///
+        /// * `minter` - Account allowed to mint.
+        /// * `id` - Token id as a number.
+        /// * `quota` - Amount of tokens `minter` can still mint.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MinterQuotaSet(
+            address indexed minter,
+            uint256 indexed id,
+            uint256 quota
+        );
+    }
+
+    sol! {
+        /// Indicates that `minter` can't mint `needed` tokens of type `id`,
+        /// because its remaining quota is lower.
+        ///
+        /// * `minter` - Account trying to mint.
+        /// * `quota` - Amount of tokens `minter` can still mint.
+        /// * `needed` - Amount of tokens being minted.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InsufficientMinterQuota(
+            address minter,
+            uint256 quota,
+            uint256 needed,
+            uint256 id
+        );
+    }
+}
+
+/// An [`Erc6909MinterQuota`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a minter's remaining quota is insufficient.
+    InsufficientMinterQuota(ERC6909InsufficientMinterQuota),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909MinterQuota`] contract.
+#[storage]
+pub struct Erc6909MinterQuota {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from minter to the amount of each token id it can still mint.
+    pub(crate) minter_quotas:
+        StorageMap<Address, StorageMap<U256, StorageU256>>,
+}
+
+impl_as_erc6909!(Erc6909MinterQuota, erc6909);
+
+/// Required interface of an [`Erc6909MinterQuota`] contract.
+#[interface_id]
+pub trait IErc6909MinterQuota: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the amount of tokens of type `id` `minter` can still mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `minter` - Account allowed to mint.
+    /// * `id` - Token id as a number.
+    fn minter_quota(&self, minter: Address, id: U256) -> U256;
+
+    /// Mints `amount` tokens of type `id` to `to`, and decreases the quota of
+    /// the caller for `id` by `amount`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientMinterQuota`] - If the remaining quota of the
+    ///   caller for `id` is lower than `amount`.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`MinterQuotaSet`].
+    fn mint_from_quota(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909MinterQuota<Error = Error>,
+    IErc165
+)]
+impl Erc6909MinterQuota {}
+
+#[public]
+impl IErc6909MinterQuota for Erc6909MinterQuota {
+    type Error = Error;
+
+    fn minter_quota(&self, minter: Address, id: U256) -> U256 {
+        self.minter_quotas.get(minter).get(id)
+    }
+
+    fn mint_from_quota(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        let minter = msg::sender();
+        let quota = self.minter_quota(minter, id);
+        let Some(remaining) = quota.checked_sub(amount) else {
+            return Err(Error::InsufficientMinterQuota(
+                ERC6909InsufficientMinterQuota {
+                    minter,
+                    quota,
+                    needed: amount,
+                    id,
+                },
+            ));
+        };
+
+        self._mint(to, id, amount)?;
+        self._set_minter_quota(minter, id, remaining);
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909MinterQuota {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909MinterQuota {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909MinterQuota>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909MinterQuota {
+    /// Sets the amount of tokens of type `id` `minter` can mint to `quota`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `minter` - Account allowed to mint.
+    /// * `id` - Token id as a number.
+    /// * `quota` - Amount of tokens `minter` can mint.
+    ///
+    /// # Events
+    ///
+    /// * [`MinterQuotaSet`].
+    pub fn _set_minter_quota(
+        &mut self,
+        minter: Address,
+        id: U256,
+        quota: U256,
+    ) {
+        self.minter_quotas.setter(minter).setter(id).set(quota);
+        evm::log(MinterQuotaSet { minter, id, quota });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909MinterQuota {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909MinterQuota {}
+
+    const ID: U256 = uint!(1_U256);
+    const QUOTA: U256 = uint!(10_U256);
+
+    #[motsu::test]
+    fn set_minter_quota_emits_event(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_minter_quota(bob, ID, QUOTA);
+
+        assert_eq!(QUOTA, contract.sender(alice).minter_quota(bob, ID));
+        assert!(contract
+            .sender(alice)
+            .minter_quota(bob, ID + U256::ONE)
+            .is_zero());
+        contract.assert_emitted(&MinterQuotaSet {
+            minter: bob,
+            id: ID,
+            quota: QUOTA,
+        });
+    }
+
+    #[motsu::test]
+    fn mint_from_quota_decreases_quota(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._set_minter_quota(bob, ID, QUOTA);
+        let amount = uint!(4_U256);
+
+        contract
+            .sender(bob)
+            .mint_from_quota(charlie, ID, amount)
+            .motsu_expect("should mint within quota");
+
+        assert_eq!(amount, contract.sender(alice).balance_of(charlie, ID));
+        assert_eq!(
+            QUOTA - amount,
+            contract.sender(alice).minter_quota(bob, ID)
+        );
+        contract.assert_emitted(&MinterQuotaSet {
+            minter: bob,
+            id: ID,
+            quota: QUOTA - amount,
+        });
+    }
+
+    #[motsu::test]
+    fn mint_from_quota_reverts_when_quota_exceeded(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_minter_quota(bob, ID, QUOTA);
+        let needed = QUOTA + U256::ONE;
+
+        let err = contract
+            .sender(bob)
+            .mint_from_quota(bob, ID, needed)
+            .motsu_expect_err("should not mint above quota");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientMinterQuota(ERC6909InsufficientMinterQuota {
+                minter,
+                quota,
+                needed: actual_needed,
+                id,
+            }) if minter == bob
+                && quota == QUOTA
+                && actual_needed == needed
+                && id == ID
+        ));
+        assert_eq!(QUOTA, contract.sender(alice).minter_quota(bob, ID));
+        assert!(contract.sender(alice).balance_of(bob, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn mint_from_quota_reverts_without_quota(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .mint_from_quota(alice, ID, U256::ONE)
+            .motsu_expect_err("should not mint without quota");
+
+        assert!(matches!(err, Error::InsufficientMinterQuota(_)));
+    }
+
+    #[motsu::test]
+    fn mint_from_quota_reverts_on_invalid_receiver(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_minter_quota(bob, ID, QUOTA);
+
+        let err = contract
+            .sender(bob)
+            .mint_from_quota(Address::ZERO, ID, QUOTA)
+            .motsu_expect_err("should not mint to the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909MinterQuota as IErc6909MinterQuota>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xfd8ee465");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909MinterQuota>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909MinterQuota as IErc6909MinterQuota>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909MinterQuota as IErc165>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909MinterQuota as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
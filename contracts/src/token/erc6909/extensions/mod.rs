@@ -1,8 +1,102 @@
 //! Common extensions
+pub mod allowance_tracking;
+pub mod balance_cap;
+pub mod balance_listeners;
+pub mod capabilities;
 pub mod content_uri;
+pub mod dividends;
+pub mod documents;
+pub mod emissions;
+pub mod enumerable;
+pub mod fee_rebates;
+pub mod flash_accounting;
+pub mod forced_transfer;
+pub mod freezable;
+pub mod holder_count;
+pub mod id_registry;
+pub mod inheritance;
+pub mod marketplace;
 pub mod metadata;
+pub mod migration;
+pub mod minter_quota;
+pub mod native_wrapper;
+pub mod operator_filter;
+pub mod permit;
+pub mod redemption;
+pub mod reward_claims;
+pub mod sale;
+pub mod scheduled_transfers;
+pub mod session_operators;
+pub mod soulbound;
 pub mod supply;
+pub mod token_info;
+pub mod tranches;
+pub mod transfer_and_call;
+pub mod transfer_authorization;
+pub mod transfer_cooldown;
+pub mod transfer_memo;
+pub mod transfer_validator;
+pub mod transfer_volume;
+pub mod votes;
 
-pub use content_uri::{Erc6909ContentUri, IErc6909ContentUri};
-pub use metadata::{Erc6909Metadata, IErc6909Metadata};
-pub use supply::{Erc6909Supply, IErc6909Supply};
+pub use allowance_tracking::{
+    Erc6909AllowanceTracking, IErc6909AllowanceTracking,
+};
+pub use balance_cap::{Erc6909BalanceCap, IErc6909BalanceCap};
+pub use balance_listeners::{
+    Erc6909BalanceListeners, IErc6909BalanceListener, IErc6909BalanceListeners,
+};
+pub use capabilities::{Erc6909Capability, IErc6909Capabilities};
+pub use content_uri::{
+    Erc6909ContentUri, IErc6909ContentUri, IErc6909Reveal, IErc7572,
+};
+pub use dividends::{Erc6909Dividends, IErc6909Dividends};
+pub use documents::{Erc6909Documents, IErc6909Documents};
+pub use emissions::{Erc6909Emissions, IErc6909Emissions};
+pub use enumerable::{Erc6909Enumerable, IErc6909Enumerable};
+pub use fee_rebates::{Erc6909FeeRebates, IErc6909FeeRebates};
+pub use flash_accounting::{Erc6909FlashAccounting, IErc6909FlashAccounting};
+pub use forced_transfer::{Erc6909ForcedTransfer, IErc6909ForcedTransfer};
+pub use freezable::{Erc6909Freezable, IErc6909Freezable};
+pub use holder_count::{Erc6909HolderCount, IErc6909HolderCount};
+pub use id_registry::{Erc6909IdRegistry, IErc6909IdRegistry};
+pub use inheritance::{Erc6909Inheritance, IErc6909Inheritance};
+pub use marketplace::{Erc6909Marketplace, IErc6909Marketplace};
+pub use metadata::{Erc6909Metadata, IErc6909Metadata, IErc6909MetadataFreeze};
+pub use migration::{Erc6909Migration, IErc6909Migration, LegacyStandard};
+pub use minter_quota::{Erc6909MinterQuota, IErc6909MinterQuota};
+pub use native_wrapper::{Erc6909NativeWrapper, IErc6909NativeWrapper};
+pub use operator_filter::{
+    Erc6909OperatorFilter, IErc6909OperatorFilter, IOperatorFilterRegistry,
+};
+pub use permit::{Erc6909Permit, IErc6909Permit};
+pub use redemption::{Erc6909Redemption, IErc6909Redemption};
+pub use reward_claims::{Erc6909RewardClaims, IErc6909RewardClaims};
+pub use sale::{Erc6909Sale, IErc6909RefundableSale, IErc6909Sale};
+pub use scheduled_transfers::{
+    Erc6909ScheduledTransfers, IErc6909ScheduledTransfers,
+};
+pub use session_operators::{
+    Erc6909SessionOperators, IErc6909SessionOperators,
+};
+pub use soulbound::{Erc6909Soulbound, IErc6909Soulbound};
+pub use supply::{Erc6909Supply, IErc6909Exists, IErc6909Supply};
+pub use token_info::{Erc6909TokenInfoSource, IErc6909TokenInfo, TokenInfo};
+pub use tranches::{Erc6909Tranches, IErc6909Tranches};
+pub use transfer_and_call::{
+    Erc6909TransferAndCall, IErc6909Receiver, IErc6909Spender,
+    IErc6909TransferAndCall,
+};
+pub use transfer_authorization::{
+    Erc6909TransferAuthorization, IErc6909TransferAuthorization,
+};
+pub use transfer_cooldown::{
+    Erc6909TransferCooldown, IErc6909TransferCooldown,
+};
+pub use transfer_memo::{Erc6909TransferMemo, IErc6909TransferMemo};
+pub use transfer_validator::{
+    Erc6909TransferValidation, IErc6909TransferValidation,
+    IErc6909TransferValidator,
+};
+pub use transfer_volume::{Erc6909TransferVolume, IErc6909TransferVolume};
+pub use votes::{Erc6909Votes, IErc6909DelegateBySig, IErc6909Votes};
@@ -0,0 +1,582 @@
+//! Extension of ERC-6909 that wraps native ETH into a designated token id.
+//!
+//! Depositing ETH mints the same amount of the native token id to the caller,
+//! and withdrawing burns it and sends the ETH back, similarly to WETH. This
+//! keeps native ETH balances in the same ledger as the other token ids.
+//!
+//! Withdrawals burn tokens before sending ETH, and revert when re-entered.
+//!
+//! Note that [`Erc6909NativeWrapper::_set_native_id`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when token `id` is designated as wrapped native ETH.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event NativeIdSet(uint256 indexed id);
+
+        /// Emitted when `account` wraps `amount` of ETH.
+        ///
+        /// * `account` - Account depositing ETH.
+        /// * `amount` - Amount of ETH deposited, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Deposit(address indexed account, uint256 amount);
+
+        /// Emitted when `account` unwraps `amount` of ETH.
+        ///
+        /// * `account` - Account withdrawing ETH.
+        /// * `amount` - Amount of ETH withdrawn, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Withdrawal(address indexed account, uint256 amount);
+    }
+
+    sol! {
+        /// Indicates that `amount` of ETH couldn't be sent to `receiver`.
+        ///
+        /// * `receiver` - Account the ETH was sent to.
+        /// * `amount` - Amount of ETH, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NativeTransferFailed(address receiver, uint256 amount);
+
+        /// Indicates that a withdrawal was re-entered.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ReentrantCall();
+    }
+}
+
+/// An [`Erc6909NativeWrapper`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that ETH couldn't be sent to the withdrawing account.
+    NativeTransferFailed(ERC6909NativeTransferFailed),
+    /// Indicates that a withdrawal was re-entered.
+    ReentrantCall(ERC6909ReentrantCall),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909NativeWrapper`] contract.
+#[storage]
+pub struct Erc6909NativeWrapper {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Token id representing wrapped native ETH.
+    pub(crate) native_id: StorageU256,
+    /// Whether a withdrawal is in progress.
+    pub(crate) withdrawing: StorageBool,
+}
+
+impl_as_erc6909!(Erc6909NativeWrapper, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909NativeWrapper {}
+
+/// Required interface of an [`Erc6909NativeWrapper`] contract.
+#[interface_id]
+pub trait IErc6909NativeWrapper: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the token id representing wrapped native ETH.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn native_id(&self) -> U256;
+
+    /// Mints the amount of ETH sent with the call, in tokens of the native
+    /// id, to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If the caller is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Deposit`].
+    fn deposit(&mut self) -> Result<(), Self::Error>;
+
+    /// Burns `amount` tokens of the native id from the caller, and sends the
+    /// same amount of ETH to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `amount` - Amount of ETH to withdraw, in wei.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ReentrantCall`] - If called while a withdrawal is in
+    ///   progress.
+    /// * [`Error::InsufficientBalance`] - If the caller owns less than `amount`
+    ///   tokens of the native id.
+    /// * [`Error::NativeTransferFailed`] - If the ETH couldn't be sent to the
+    ///   caller.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Withdrawal`].
+    fn withdraw(&mut self, amount: U256) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909NativeWrapper<Error = Error>,
+    IErc165
+)]
+impl Erc6909NativeWrapper {}
+
+#[public]
+impl IErc6909NativeWrapper for Erc6909NativeWrapper {
+    type Error = Error;
+
+    fn native_id(&self) -> U256 {
+        self.native_id.get()
+    }
+
+    #[payable]
+    fn deposit(&mut self) -> Result<(), Self::Error> {
+        let account = msg::sender();
+        let amount = msg::value();
+
+        self._mint(account, self.native_id(), amount)?;
+
+        evm::log(Deposit { account, amount });
+
+        Ok(())
+    }
+
+    fn withdraw(&mut self, amount: U256) -> Result<(), Self::Error> {
+        if self.withdrawing.get() {
+            return Err(Error::ReentrantCall(ERC6909ReentrantCall {}));
+        }
+        self.withdrawing.set(true);
+
+        let account = msg::sender();
+        self._burn(account, self.native_id(), amount)?;
+
+        if call::call(Call::new_in(self).value(amount), account, &[]).is_err() {
+            return Err(Error::NativeTransferFailed(
+                ERC6909NativeTransferFailed { receiver: account, amount },
+            ));
+        }
+
+        self.withdrawing.set(false);
+        evm::log(Withdrawal { account, amount });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909NativeWrapper {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909NativeWrapper {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909NativeWrapper>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909NativeWrapper {
+    /// Designates token `id` as wrapped native ETH.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Changing the native id while tokens of the previous one are in
+    /// circulation leaves them unbacked, so it should only be done before the
+    /// first deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * [`NativeIdSet`].
+    pub fn _set_native_id(&mut self, id: U256) {
+        self.native_id.set(id);
+        evm::log(NativeIdSet { id });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909NativeWrapper {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const NATIVE_ID: U256 = uint!(7_U256);
+    const AMOUNT: U256 = uint!(1_000_U256);
+
+    #[motsu::test]
+    fn set_native_id_emits_event(
+        contract: Contract<Erc6909NativeWrapper>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_native_id(NATIVE_ID);
+
+        assert_eq!(NATIVE_ID, contract.sender(alice).native_id());
+        contract.assert_emitted(&NativeIdSet { id: NATIVE_ID });
+    }
+
+    #[motsu::test]
+    fn deposit_mints_native_id(
+        contract: Contract<Erc6909NativeWrapper>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_native_id(NATIVE_ID);
+        alice.fund(AMOUNT);
+
+        contract
+            .sender_and_value(alice, AMOUNT)
+            .deposit()
+            .motsu_expect("should deposit ETH");
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, NATIVE_ID));
+        assert_eq!(AMOUNT, contract.address().balance());
+        assert!(alice.balance().is_zero());
+        contract.assert_emitted(&Deposit { account: alice, amount: AMOUNT });
+    }
+
+    #[motsu::test]
+    fn withdraw_burns_and_sends_eth(
+        contract: Contract<Erc6909NativeWrapper>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_native_id(NATIVE_ID);
+        alice.fund(AMOUNT);
+        contract
+            .sender_and_value(alice, AMOUNT)
+            .deposit()
+            .motsu_expect("should deposit ETH");
+
+        let amount = uint!(400_U256);
+        contract
+            .sender(alice)
+            .withdraw(amount)
+            .motsu_expect("should withdraw ETH");
+
+        assert_eq!(
+            AMOUNT - amount,
+            contract.sender(alice).balance_of(alice, NATIVE_ID)
+        );
+        assert_eq!(AMOUNT - amount, contract.address().balance());
+        assert_eq!(amount, alice.balance());
+        contract.assert_emitted(&Withdrawal { account: alice, amount });
+    }
+
+    #[motsu::test]
+    fn withdraw_reverts_when_balance_insufficient(
+        contract: Contract<Erc6909NativeWrapper>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_native_id(NATIVE_ID);
+
+        let err = contract
+            .sender(alice)
+            .withdraw(AMOUNT)
+            .motsu_expect_err("should not withdraw without balance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientBalance(Erc6909InsufficientBalance {
+                sender,
+                balance,
+                needed,
+                id,
+            }) if sender == alice
+                && balance.is_zero()
+                && needed == AMOUNT
+                && id == NATIVE_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909NativeWrapper as IErc6909NativeWrapper>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x4f905da0");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909NativeWrapper>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909NativeWrapper as IErc6909NativeWrapper>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909NativeWrapper as IErc165>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909NativeWrapper as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
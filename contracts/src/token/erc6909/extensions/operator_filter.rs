@@ -0,0 +1,595 @@
+//! Extension of ERC-6909 that consults an operator filter registry before
+//! letting third parties manage tokens on behalf of their owners.
+//!
+//! Creators can register this contract in a registry compatible with
+//! [`IOperatorFilterRegistry`], and subscribe to a list of blocked operators
+//! there, e.g. marketplaces that don't enforce creator fees. Blocked operators
+//! can then neither be approved, nor be set as operators, nor move tokens of
+//! other accounts.
+//!
+//! When no registry is set, or the configured registry has no code (e.g. on
+//! chains where it is not deployed), every operator is allowed.
+//!
+//! Note that [`Erc6909OperatorFilter::_set_operator_filter_registry`] is not
+//! exposed by default. You should expose it manually in your contract's abi,
+//! guarded by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use registry::IOperatorFilterRegistry;
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    contract, evm, msg,
+    prelude::*,
+    storage::StorageAddress,
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `registry` is set as the operator filter registry.
+        ///
+        /// * `registry` - Address of the registry, or [`Address::ZERO`] if
+        ///   operators are no longer filtered.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OperatorFilterRegistrySet(address registry);
+    }
+
+    sol! {
+        /// Indicates that `operator` is blocked by the operator filter
+        /// registry.
+        ///
+        /// * `operator` - Address of the blocked operator.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909OperatorNotAllowed(address operator);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(missing_docs)]
+mod registry {
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the registries filtering the operators allowed to
+        /// manage tokens of an [`super::Erc6909OperatorFilter`] contract.
+        interface IOperatorFilterRegistry {
+            /// Returns true if `operator` is allowed to manage tokens of
+            /// `registrant`.
+            ///
+            /// # Arguments
+            ///
+            /// * `registrant` - Address of the filtered token contract.
+            /// * `operator` - Address of the operator.
+            #[allow(missing_docs)]
+            function isOperatorAllowed(
+                address registrant,
+                address operator
+            ) external view returns (bool);
+        }
+    }
+}
+
+/// An [`Erc6909OperatorFilter`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that an operator is blocked by the operator filter registry.
+    OperatorNotAllowed(ERC6909OperatorNotAllowed),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909OperatorFilter`] contract.
+#[storage]
+pub struct Erc6909OperatorFilter {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Address of the operator filter registry.
+    pub(crate) operator_filter_registry: StorageAddress,
+}
+
+impl_as_erc6909!(Erc6909OperatorFilter, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909OperatorFilter {}
+
+/// Required interface of an [`Erc6909OperatorFilter`] contract.
+#[interface_id]
+pub trait IErc6909OperatorFilter: IErc165 {
+    /// Returns the address of the operator filter registry, or
+    /// [`Address::ZERO`] if operators are not filtered.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn operator_filter_registry(&self) -> Address;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909OperatorFilter, IErc165)]
+impl Erc6909OperatorFilter {}
+
+#[public]
+impl IErc6909OperatorFilter for Erc6909OperatorFilter {
+    fn operator_filter_registry(&self) -> Address {
+        self.operator_filter_registry.get()
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909OperatorFilter {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+        if caller != sender {
+            self._check_operator_allowed(caller)?;
+        }
+
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        if !amount.is_zero() {
+            self._check_operator_allowed(spender)?;
+        }
+
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        if approved {
+            self._check_operator_allowed(spender)?;
+        }
+
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909OperatorFilter {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909OperatorFilter>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909OperatorFilter {
+    /// Sets `registry` as the operator filter registry. Setting
+    /// [`Address::ZERO`] disables the filtering of operators.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `registry` - Address of the operator filter registry.
+    ///
+    /// # Events
+    ///
+    /// * [`OperatorFilterRegistrySet`].
+    pub fn _set_operator_filter_registry(&mut self, registry: Address) {
+        self.operator_filter_registry.set(registry);
+        evm::log(OperatorFilterRegistrySet { registry });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909OperatorFilter {
+    /// Checks that `operator` is allowed by the operator filter registry,
+    /// if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Address of the operator.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OperatorNotAllowed`] - If the registry reverts, or doesn't
+    ///   allow `operator`.
+    fn _check_operator_allowed(
+        &mut self,
+        operator: Address,
+    ) -> Result<(), Error> {
+        let registry = self.operator_filter_registry.get();
+        if registry.is_zero() || !registry.has_code() {
+            return Ok(());
+        }
+
+        let allowed = IOperatorFilterRegistry::new(registry)
+            .is_operator_allowed(
+                Call::new_in(self),
+                contract::address(),
+                operator,
+            )
+            .unwrap_or(false);
+
+        if !allowed {
+            return Err(Error::OperatorNotAllowed(ERC6909OperatorNotAllowed {
+                operator,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909OperatorFilter {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::storage::{StorageBool, StorageMap};
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[storage]
+    struct MockOperatorFilterRegistry {
+        blocked: StorageMap<Address, StorageBool>,
+    }
+
+    #[public]
+    impl MockOperatorFilterRegistry {
+        fn block(&mut self, operator: Address) {
+            self.blocked.setter(operator).set(true);
+        }
+
+        fn is_operator_allowed(
+            &self,
+            _registrant: Address,
+            operator: Address,
+        ) -> bool {
+            !self.blocked.get(operator)
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockOperatorFilterRegistry {}
+
+    #[motsu::test]
+    fn set_operator_filter_registry_emits_event(
+        contract: Contract<Erc6909OperatorFilter>,
+        registry: Contract<MockOperatorFilterRegistry>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._set_operator_filter_registry(registry.address());
+
+        assert_eq!(
+            contract.sender(alice).operator_filter_registry(),
+            registry.address()
+        );
+        contract.assert_emitted(&OperatorFilterRegistrySet {
+            registry: registry.address(),
+        });
+    }
+
+    #[motsu::test]
+    fn set_operator_succeeds_without_registry(
+        contract: Contract<Erc6909OperatorFilter>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set operator when unfiltered");
+
+        assert!(contract.sender(alice).is_operator(alice, bob));
+    }
+
+    #[motsu::test]
+    fn set_operator_reverts_when_operator_blocked(
+        contract: Contract<Erc6909OperatorFilter>,
+        registry: Contract<MockOperatorFilterRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        registry.sender(alice).block(bob);
+        contract
+            .sender(alice)
+            ._set_operator_filter_registry(registry.address());
+
+        let err = contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect_err("should not set blocked operator");
+
+        assert!(matches!(
+            err,
+            Error::OperatorNotAllowed(ERC6909OperatorNotAllowed { operator })
+                if operator == bob
+        ));
+
+        contract
+            .sender(alice)
+            .set_operator(bob, false)
+            .motsu_expect("should always revoke operators");
+    }
+
+    #[motsu::test]
+    fn approve_reverts_when_spender_blocked(
+        contract: Contract<Erc6909OperatorFilter>,
+        registry: Contract<MockOperatorFilterRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        registry.sender(alice).block(bob);
+        contract
+            .sender(alice)
+            ._set_operator_filter_registry(registry.address());
+
+        let err = contract
+            .sender(alice)
+            .approve(bob, ID, AMOUNT)
+            .motsu_expect_err("should not approve blocked spender");
+
+        assert!(matches!(err, Error::OperatorNotAllowed(_)));
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_operator_blocked_later(
+        contract: Contract<Erc6909OperatorFilter>,
+        registry: Contract<MockOperatorFilterRegistry>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set operator when unfiltered");
+
+        registry.sender(alice).block(bob);
+        contract
+            .sender(alice)
+            ._set_operator_filter_registry(registry.address());
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, AMOUNT)
+            .motsu_expect_err("blocked operator should not transfer");
+
+        assert!(matches!(err, Error::OperatorNotAllowed(_)));
+        assert_eq!(contract.sender(alice).balance_of(alice, ID), AMOUNT);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("owner should still transfer");
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909OperatorFilter as IErc6909OperatorFilter>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xb0ccc31e");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,880 @@
+//! Extension of ERC-6909 allowing approvals to be made via signatures, in the
+//! style of [ERC-2612].
+//!
+//! Adds the `permit` method, which sets the allowance of a spender over the
+//! tokens of an `owner` of a token id, given a message signed by the
+//! `owner`. The `owner` doesn't need to send a transaction, and thus is not
+//! required to hold Ether at all.
+//!
+//! Relayers usually spend a permit right away, so `permit_transfer_from`
+//! consumes the permit and transfers the tokens in a single call, like
+//! Permit2's signature transfers. The caller must be the permitted spender,
+//! and the allowance of the spender is left untouched.
+//!
+//! Permits use the sequential nonces of [`Nonces`], so that each signature
+//! can only be used once.
+//!
+//! Smart contract wallets are supported too: when the signature isn't an ECDSA
+//! signature of the `owner`, it is checked with the ERC-1271
+//! `isValidSignature` function of the `owner`.
+//!
+//! [ERC-2612]: https://eips.ethereum.org/EIPS/eip-2612
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256};
+use alloy_sol_types::SolType;
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes, block, call::MethodError, function_selector, msg, prelude::*,
+};
+
+use super::transfer_authorization::{
+    split_signature, ERC6909InvalidSignatureLength,
+};
+use crate::{
+    token::erc6909::{
+        self, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909Internal,
+    },
+    utils::{
+        cryptography::{
+            ecdsa::{self, ECDSAInvalidSignature, ECDSAInvalidSignatureS},
+            eip712::IEip712,
+            signature_checker::is_valid_erc1271_signature_now,
+        },
+        nonces::{INonces, Nonces},
+    },
+};
+
+const PERMIT_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"Permit(address owner,address spender,uint256 id,uint256 amount,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    pub(crate) type StructHashTuple = sol! {
+        tuple(bytes32, address, address, uint256, uint256, uint256, uint256)
+    };
+
+    sol! {
+        /// Indicates that a permit was used after its `deadline`.
+        ///
+        /// * `deadline` - Timestamp after which the permit expires.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ExpiredSignature(uint256 deadline);
+
+        /// Indicates that a permit was signed by `signer` instead of
+        /// `owner`.
+        ///
+        /// * `signer` - Account that signed the permit.
+        /// * `owner` - Account that owns the tokens.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidPermitSigner(address signer, address owner);
+    }
+}
+
+/// An [`Erc6909Permit`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a permit was used after its deadline.
+    ExpiredSignature(ERC6909ExpiredSignature),
+    /// Indicates that a permit wasn't signed by the owner of the tokens.
+    InvalidSigner(ERC6909InvalidPermitSigner),
+    /// Indicates that a signature isn't made of 65 bytes.
+    InvalidSignatureLength(ERC6909InvalidSignatureLength),
+    /// The signature derives the [`Address::ZERO`].
+    InvalidSignature(ECDSAInvalidSignature),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(ECDSAInvalidSignatureS),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<ecdsa::Error> for Error {
+    fn from(value: ecdsa::Error) -> Self {
+        match value {
+            ecdsa::Error::InvalidSignature(e) => Error::InvalidSignature(e),
+            ecdsa::Error::InvalidSignatureS(e) => Error::InvalidSignatureS(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Permit`] contract.
+#[storage]
+pub struct Erc6909Permit<T: IEip712 + StorageType> {
+    /// Contract implementing [`IEip712`] trait.
+    pub(crate) eip712: T,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl<T: IEip712 + StorageType> TopLevelStorage for Erc6909Permit<T> {}
+
+/// Required interface of an [`Erc6909Permit`] contract.
+pub trait IErc6909Permit: INonces {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    // Calculated manually to include [`INonces::nonces`].
+    /// Solidity interface id associated with [`IErc6909Permit`] trait.
+    /// Computed as a XOR of selectors for each function in the trait.
+    #[must_use]
+    fn interface_id() -> FixedBytes<4>
+    where
+        Self: Sized,
+    {
+        FixedBytes::<4>::new(function_selector!("DOMAIN_SEPARATOR",))
+            ^ FixedBytes::<4>::new(function_selector!("nonces", Address,))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "permit", Address, Address, U256, U256, U256, Bytes
+            ))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "permitTransferFrom",
+                Address,
+                Address,
+                U256,
+                U256,
+                U256,
+                Bytes
+            ))
+    }
+
+    /// Returns the domain separator used in the encoding of the signature for
+    /// [`Self::permit`], as defined by EIP712.
+    ///
+    /// NOTE: The implementation should use `#[selector(name =
+    /// "DOMAIN_SEPARATOR")]` to match Solidity's camelCase naming
+    /// convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    fn domain_separator(&self) -> B256;
+
+    /// Sets `amount` as the allowance of `spender` over `owner`'s tokens of
+    /// type `id`, given `owner`'s signed permit.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    /// * `deadline` - Timestamp after which the permit expires.
+    /// * `signature` - `owner`'s signature, made of its `r`, `s` and `v`
+    ///   values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If the current timestamp is greater than
+    ///   `deadline`.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271.
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::Approval`]
+    fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error>;
+
+    /// Transfers `amount` tokens of type `id` from `owner` to `to`, given
+    /// `owner`'s permit allowing the caller to spend them.
+    ///
+    /// The permit is consumed by the transfer itself, so that the allowance
+    /// of the caller is left untouched, and no [`erc6909::Approval`] event is
+    /// emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account whose tokens are transferred.
+    /// * `to` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `deadline` - Timestamp after which the permit expires.
+    /// * `signature` - `owner`'s signature of a permit with the caller as
+    ///   spender, made of its `r`, `s` and `v` values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If the current timestamp is greater than
+    ///   `deadline`.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271, e.g. when the permit was
+    ///   made for another spender.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `owner` doesn't have enough tokens
+    ///   of type `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`]
+    fn permit_transfer_from(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: IEip712 + StorageType> Erc6909Permit<T> {
+    /// See [`IErc6909Permit::domain_separator`].
+    #[must_use]
+    pub fn domain_separator(&self) -> B256 {
+        self.eip712.domain_separator_v4()
+    }
+
+    /// See [`IErc6909Permit::permit`].
+    ///
+    /// `token` is the token whose allowance is set, e.g. an [`Erc6909`] or
+    /// one of its extensions, and `E` the error the contract reverts with.
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn permit<I, E>(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: &Bytes,
+        token: &mut I,
+        nonces: &mut Nonces,
+    ) -> Result<(), E>
+    where
+        I: IErc6909Internal,
+        E: From<Error> + From<I::Error>,
+    {
+        self._use_permit(
+            owner, spender, id, amount, deadline, signature, nonces,
+        )?;
+
+        Ok(token._approve(owner, spender, id, amount)?)
+    }
+
+    /// See [`IErc6909Permit::permit_transfer_from`].
+    ///
+    /// `token` is the token whose tokens are transferred, e.g. an
+    /// [`Erc6909`] or one of its extensions, and `E` the error the contract
+    /// reverts with. Tokens are moved through the `_update` of `token`, so
+    /// that the hooks of its extensions, like freezes or vote checkpoints,
+    /// apply to permit transfers too.
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn permit_transfer_from<I, E>(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: &Bytes,
+        token: &mut I,
+        nonces: &mut Nonces,
+    ) -> Result<(), E>
+    where
+        I: IErc6909Internal,
+        E: From<Error> + From<I::Error>,
+    {
+        let spender = msg::sender();
+        self._use_permit(
+            owner, spender, id, amount, deadline, signature, nonces,
+        )?;
+
+        Erc6909::_check_transfer_params(owner, to).map_err(Error::from)?;
+        Ok(token._update(owner, to, vec![id], vec![amount])?)
+    }
+
+    /// Checks that `signature` is `owner`'s permit allowing `spender` to
+    /// spend `amount` tokens of type `id`, and consumes its nonce.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    /// * `deadline` - Timestamp after which the permit expires.
+    /// * `signature` - Signature made of its `r`, `s` and `v` values.
+    /// * `nonces` - Write access to the nonces of the contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If the current timestamp is greater than
+    ///   `deadline`.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271.
+    #[allow(clippy::too_many_arguments)]
+    fn _use_permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: &[u8],
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(ERC6909ExpiredSignature { deadline }.into());
+        }
+
+        let struct_hash = keccak256(StructHashTuple::abi_encode(&(
+            PERMIT_TYPEHASH,
+            owner,
+            spender,
+            id,
+            amount,
+            nonces.nonces(owner),
+            deadline,
+        )));
+        let hash = self.eip712.hash_typed_data_v4(struct_hash);
+
+        let recovered = split_signature(signature)
+            .map_err(Error::from)
+            .and_then(|(v, r, s)| Ok(ecdsa::recover(self, hash, v, r, s)?));
+        match recovered {
+            Ok(signer) if signer == owner => {}
+            // Smart contract wallets can't sign with ECDSA, and validate the
+            // signatures made on their behalf themselves.
+            _ if is_valid_erc1271_signature_now(
+                self, owner, hash, signature,
+            ) => {}
+            Ok(signer) => {
+                return Err(ERC6909InvalidPermitSigner { signer, owner }.into())
+            }
+            Err(e) => return Err(e),
+        }
+
+        nonces.use_nonce(owner);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::Bytes, block, prelude::*, storage::StorageBytes};
+
+    use super::*;
+    use crate::{
+        token::erc6909::{
+            extensions::{freezable, Erc6909Freezable},
+            IErc6909,
+        },
+        utils::cryptography::signature_checker::ERC1271_MAGIC_VALUE,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+
+    #[storage]
+    struct Eip712;
+
+    impl IEip712 for Eip712 {
+        const NAME: &'static str = "ERC-6909 Permit Example";
+        const VERSION: &'static str = "1";
+    }
+
+    #[storage]
+    struct Erc6909PermitExample {
+        erc6909: Erc6909,
+        nonces: Nonces,
+        permit: Erc6909Permit<Eip712>,
+    }
+
+    #[public]
+    #[implements(INonces, IErc6909Permit<Error = Error>)]
+    impl Erc6909PermitExample {}
+
+    #[public]
+    impl INonces for Erc6909PermitExample {
+        fn nonces(&self, owner: Address) -> U256 {
+            self.nonces.nonces(owner)
+        }
+    }
+
+    #[public]
+    impl IErc6909Permit for Erc6909PermitExample {
+        type Error = Error;
+
+        #[selector(name = "DOMAIN_SEPARATOR")]
+        fn domain_separator(&self) -> B256 {
+            self.permit.domain_separator()
+        }
+
+        fn permit(
+            &mut self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+            amount: U256,
+            deadline: U256,
+            signature: Bytes,
+        ) -> Result<(), Self::Error> {
+            self.permit.permit(
+                owner,
+                spender,
+                id,
+                amount,
+                deadline,
+                &signature,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+
+        fn permit_transfer_from(
+            &mut self,
+            owner: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+            deadline: U256,
+            signature: Bytes,
+        ) -> Result<(), Self::Error> {
+            self.permit.permit_transfer_from(
+                owner,
+                to,
+                id,
+                amount,
+                deadline,
+                &signature,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909PermitExample {}
+
+    #[storage]
+    struct Erc6909FreezablePermitExample {
+        erc6909: Erc6909Freezable,
+        nonces: Nonces,
+        permit: Erc6909Permit<Eip712>,
+    }
+
+    #[public]
+    impl Erc6909FreezablePermitExample {
+        fn permit_transfer_from(
+            &mut self,
+            owner: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+            deadline: U256,
+            signature: Bytes,
+        ) -> Result<(), Vec<u8>> {
+            self.permit.permit_transfer_from(
+                owner,
+                to,
+                id,
+                amount,
+                deadline,
+                &signature,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909FreezablePermitExample {}
+
+    /// Smart contract wallet accepting a single signature.
+    #[storage]
+    struct MockErc1271Wallet {
+        signature: StorageBytes,
+    }
+
+    #[public]
+    impl MockErc1271Wallet {
+        fn is_valid_signature(
+            &self,
+            _hash: B256,
+            signature: Bytes,
+        ) -> FixedBytes<4> {
+            if signature.0 == self.signature.get_bytes() {
+                ERC1271_MAGIC_VALUE.into()
+            } else {
+                FixedBytes::ZERO
+            }
+        }
+    }
+
+    impl MockErc1271Wallet {
+        fn accept(&mut self, signature: &[u8]) {
+            self.signature.set_bytes(signature);
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockErc1271Wallet {}
+
+    fn init(contract: &Contract<Erc6909PermitExample>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(alice, TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+        });
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909PermitExample as IErc6909Permit>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xf91e1970");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn permit_transfer_from_reverts_when_expired(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let deadline = U256::from(block::timestamp()) - uint!(1_U256);
+        let err = contract
+            .sender(bob)
+            .permit_transfer_from(
+                alice,
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                deadline,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should be expired");
+
+        assert!(matches!(
+            err,
+            Error::ExpiredSignature(ERC6909ExpiredSignature { deadline: d })
+                if d == deadline
+        ));
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.balance_of(alice, TOKEN_ID)
+        );
+        assert!(contract.sender(alice).nonces(alice).is_zero());
+    }
+
+    #[motsu::test]
+    fn permit_reverts_when_signature_length_invalid(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        for length in [0, 64, 66] {
+            let err = contract
+                .sender(bob)
+                .permit(
+                    alice,
+                    bob,
+                    TOKEN_ID,
+                    AMOUNT,
+                    U256::MAX,
+                    vec![0; length].into(),
+                )
+                .motsu_expect_err("should reject the signature");
+
+            assert!(matches!(
+                err,
+                Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                    length: l
+                }) if l == U256::from(length)
+            ));
+        }
+        assert!(contract.sender(alice).nonces(alice).is_zero());
+        assert!(contract
+            .sender(alice)
+            .erc6909
+            .allowance(alice, bob, TOKEN_ID)
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn permit_transfer_from_reverts_when_signature_length_invalid(
+        contract: Contract<Erc6909PermitExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            .permit_transfer_from(
+                alice,
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::MAX,
+                vec![0; 64].into(),
+            )
+            .motsu_expect_err("should reject the signature");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                length
+            }) if length == uint!(64_U256)
+        ));
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn permit_accepts_signature_validated_by_wallet(
+        contract: Contract<Erc6909PermitExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        wallet.sender(alice).accept(b"wallet signature");
+
+        contract
+            .sender(bob)
+            .permit(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::MAX,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect("should accept the wallet's signature");
+
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.allowance(
+                wallet.address(),
+                bob,
+                TOKEN_ID
+            )
+        );
+        assert_eq!(
+            uint!(1_U256),
+            contract.sender(alice).nonces(wallet.address())
+        );
+    }
+
+    #[motsu::test]
+    fn permit_transfer_from_accepts_signature_validated_by_wallet(
+        contract: Contract<Erc6909PermitExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(wallet.address(), TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+        });
+        wallet.sender(alice).accept(b"wallet signature");
+
+        contract
+            .sender(bob)
+            .permit_transfer_from(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::MAX,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect("should accept the wallet's signature");
+
+        assert!(contract
+            .sender(alice)
+            .erc6909
+            .balance_of(wallet.address(), TOKEN_ID)
+            .is_zero());
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.balance_of(bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn permit_reverts_when_wallet_rejects_signature(
+        contract: Contract<Erc6909PermitExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        wallet.sender(alice).accept(b"wallet signature");
+
+        let err = contract
+            .sender(bob)
+            .permit(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::MAX,
+                b"forged signature".to_vec().into(),
+            )
+            .motsu_expect_err("should reject the signature");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                length
+            }) if length == uint!(16_U256)
+        ));
+        assert!(contract.sender(alice).nonces(wallet.address()).is_zero());
+    }
+
+    #[motsu::test]
+    fn permit_transfer_from_runs_extension_hooks(
+        contract: Contract<Erc6909FreezablePermitExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(wallet.address(), TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+            contract.erc6909._freeze(wallet.address(), TOKEN_ID);
+        });
+        wallet.sender(alice).accept(b"wallet signature");
+
+        let err = contract
+            .sender(bob)
+            .permit_transfer_from(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::MAX,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect_err("should run the freeze hook");
+
+        let expected: Vec<u8> =
+            freezable::Error::FrozenAccount(freezable::ERC6909FrozenAccount {
+                account: wallet.address(),
+                id: TOKEN_ID,
+            })
+            .into();
+        assert_eq!(expected, err);
+        assert_eq!(
+            AMOUNT,
+            contract
+                .sender(alice)
+                .erc6909
+                .balance_of(wallet.address(), TOKEN_ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .erc6909
+            .balance_of(bob, TOKEN_ID)
+            .is_zero());
+    }
+}
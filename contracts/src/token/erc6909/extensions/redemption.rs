@@ -0,0 +1,685 @@
+//! Extension of ERC-6909 that lets holders burn tokens of one id to redeem
+//! tokens of another id, at a fixed ratio.
+//!
+//! Each token id `a` can be made redeemable for a token id `b`, so that
+//! burning `amount` tokens of type `a` mints `amount * numerator /
+//! denominator` tokens of type `b` to the holder, rounded down, in a single
+//! call. This is useful for vouchers and upgrade mechanics.
+//!
+//! Note that [`Erc6909Redemption::_set_redemption`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        math::alloy::{Math, Rounding},
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when token `a` becomes redeemable for token `b`, at a
+        /// ratio of `numerator / denominator` tokens of type `b` per token of
+        /// type `a`.
+        ///
+        /// * `a` - Token id being redeemed.
+        /// * `b` - Token id received in exchange.
+        /// * `numerator` - Numerator of the redemption ratio.
+        /// * `denominator` - Denominator of the redemption ratio.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event RedemptionSet(
+            uint256 indexed a,
+            uint256 indexed b,
+            uint256 numerator,
+            uint256 denominator
+        );
+
+        /// Emitted when `account` burns `amount` tokens of type `a` to
+        /// receive `redeemed` tokens of type `b`.
+        ///
+        /// * `account` - Account redeeming the tokens.
+        /// * `a` - Token id being redeemed.
+        /// * `b` - Token id received in exchange.
+        /// * `amount` - Amount of tokens of type `a` burnt.
+        /// * `redeemed` - Amount of tokens of type `b` minted.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Redeemed(
+            address indexed account,
+            uint256 indexed a,
+            uint256 indexed b,
+            uint256 amount,
+            uint256 redeemed
+        );
+    }
+
+    sol! {
+        /// Indicates that the redemption ratio of token `a` has a zero
+        /// `denominator`.
+        ///
+        /// * `a` - Token id being redeemed.
+        /// * `numerator` - Numerator of the redemption ratio.
+        /// * `denominator` - Denominator of the redemption ratio.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidRedemptionRatio(
+            uint256 a,
+            uint256 numerator,
+            uint256 denominator
+        );
+
+        /// Indicates that token `a` can't be redeemed.
+        ///
+        /// * `a` - Token id being redeemed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909RedemptionNotSet(uint256 a);
+
+        /// Indicates that redeeming `amount` tokens of type `a` wouldn't mint
+        /// any token.
+        ///
+        /// * `a` - Token id being redeemed.
+        /// * `amount` - Amount of tokens of type `a` being redeemed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InsufficientRedemption(uint256 a, uint256 amount);
+    }
+}
+
+/// An [`Erc6909Redemption`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a redemption ratio is invalid.
+    InvalidRedemptionRatio(ERC6909InvalidRedemptionRatio),
+    /// Indicates that a token id can't be redeemed.
+    RedemptionNotSet(ERC6909RedemptionNotSet),
+    /// Indicates that a redemption wouldn't mint any token.
+    InsufficientRedemption(ERC6909InsufficientRedemption),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Redemption`] contract.
+#[storage]
+pub struct Erc6909Redemption {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to the token id it can be redeemed for.
+    pub(crate) redemption_targets: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the numerator of its redemption ratio.
+    pub(crate) redemption_numerators: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the denominator of its redemption ratio.
+    pub(crate) redemption_denominators: StorageMap<U256, StorageU256>,
+}
+
+impl_as_erc6909!(Erc6909Redemption, erc6909);
+
+/// Required interface of an [`Erc6909Redemption`] contract.
+#[interface_id]
+pub trait IErc6909Redemption: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the token id that token `a` can be redeemed for, and the
+    /// numerator and denominator of the redemption ratio.
+    ///
+    /// Returns a zero ratio if token `a` can't be redeemed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `a` - Token id being redeemed.
+    fn redemption(&self, a: U256) -> (U256, U256, U256);
+
+    /// Burns `amount` tokens of type `a` from the caller, and mints them the
+    /// tokens they can be redeemed for. Returns the amount of tokens minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `a` - Token id being redeemed.
+    /// * `amount` - Amount of tokens of type `a` to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::RedemptionNotSet`] - If token `a` can't be redeemed.
+    /// * [`Error::InsufficientRedemption`] - If redeeming `amount` tokens
+    ///   wouldn't mint any token.
+    /// * [`Error::InsufficientBalance`] - If the caller holds less than
+    ///   `amount` tokens of type `a`.
+    ///
+    /// # Panics
+    ///
+    /// * If the amount of tokens minted exceeds [`U256::MAX`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`], once for the burn and once for the mint.
+    /// * [`Redeemed`].
+    fn redeem(&mut self, a: U256, amount: U256) -> Result<U256, Self::Error>;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909Redemption<Error = Error>, IErc165)]
+impl Erc6909Redemption {}
+
+#[public]
+impl IErc6909Redemption for Erc6909Redemption {
+    type Error = Error;
+
+    fn redemption(&self, a: U256) -> (U256, U256, U256) {
+        (
+            self.redemption_targets.get(a),
+            self.redemption_numerators.get(a),
+            self.redemption_denominators.get(a),
+        )
+    }
+
+    fn redeem(&mut self, a: U256, amount: U256) -> Result<U256, Self::Error> {
+        let (b, numerator, denominator) = self.redemption(a);
+        if numerator.is_zero() {
+            return Err(Error::RedemptionNotSet(ERC6909RedemptionNotSet { a }));
+        }
+
+        let redeemed = amount.mul_div(numerator, denominator, Rounding::Floor);
+        if redeemed.is_zero() {
+            return Err(Error::InsufficientRedemption(
+                ERC6909InsufficientRedemption { a, amount },
+            ));
+        }
+
+        let account = msg::sender();
+        self._burn(account, a, amount)?;
+        self._mint(account, b, redeemed)?;
+
+        evm::log(Redeemed { account, a, b, amount, redeemed });
+
+        Ok(redeemed)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Redemption {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Redemption {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Redemption>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Redemption {
+    /// Makes token `a` redeemable for token `b`, at a ratio of `numerator /
+    /// denominator` tokens of type `b` per token of type `a`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Setting `numerator` to zero stops the redemptions of `a`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `a` - Token id being redeemed.
+    /// * `b` - Token id received in exchange.
+    /// * `numerator` - Numerator of the redemption ratio.
+    /// * `denominator` - Denominator of the redemption ratio.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidRedemptionRatio`] - If `denominator` is zero while
+    ///   `numerator` is not.
+    ///
+    /// # Events
+    ///
+    /// * [`RedemptionSet`].
+    pub fn _set_redemption(
+        &mut self,
+        a: U256,
+        b: U256,
+        numerator: U256,
+        denominator: U256,
+    ) -> Result<(), Error> {
+        if !numerator.is_zero() && denominator.is_zero() {
+            return Err(Error::InvalidRedemptionRatio(
+                ERC6909InvalidRedemptionRatio { a, numerator, denominator },
+            ));
+        }
+
+        self.redemption_targets.setter(a).set(b);
+        self.redemption_numerators.setter(a).set(numerator);
+        self.redemption_denominators.setter(a).set(denominator);
+        evm::log(RedemptionSet { a, b, numerator, denominator });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909Redemption {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Redemption {}
+
+    const VOUCHER: U256 = uint!(1_U256);
+    const ITEM: U256 = uint!(2_U256);
+    const NUMERATOR: U256 = uint!(2_U256);
+    const DENOMINATOR: U256 = uint!(3_U256);
+
+    fn set_redemption(contract: &Contract<Erc6909Redemption>, admin: Address) {
+        contract
+            .sender(admin)
+            ._set_redemption(VOUCHER, ITEM, NUMERATOR, DENOMINATOR)
+            .motsu_expect("should set redemption");
+    }
+
+    #[motsu::test]
+    fn set_redemption_emits_event(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        set_redemption(&contract, alice);
+
+        assert_eq!(
+            (ITEM, NUMERATOR, DENOMINATOR),
+            contract.sender(alice).redemption(VOUCHER)
+        );
+        contract.assert_emitted(&RedemptionSet {
+            a: VOUCHER,
+            b: ITEM,
+            numerator: NUMERATOR,
+            denominator: DENOMINATOR,
+        });
+    }
+
+    #[motsu::test]
+    fn set_redemption_reverts_on_zero_denominator(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_redemption(VOUCHER, ITEM, NUMERATOR, U256::ZERO)
+            .motsu_expect_err("should not set a zero denominator");
+
+        assert!(matches!(
+            err,
+            Error::InvalidRedemptionRatio(ERC6909InvalidRedemptionRatio {
+                a,
+                numerator,
+                denominator,
+            }) if a == VOUCHER
+                && numerator == NUMERATOR
+                && denominator.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn redeem_burns_and_mints_at_ratio(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let amount = uint!(10_U256);
+        set_redemption(&contract, alice);
+        contract
+            .sender(alice)
+            ._mint(bob, VOUCHER, amount)
+            .motsu_expect("should mint vouchers");
+
+        let redeemed = contract
+            .sender(bob)
+            .redeem(VOUCHER, amount)
+            .motsu_expect("should redeem vouchers");
+
+        // 10 * 2 / 3, rounded down.
+        assert_eq!(uint!(6_U256), redeemed);
+        assert!(contract.sender(bob).balance_of(bob, VOUCHER).is_zero());
+        assert_eq!(redeemed, contract.sender(bob).balance_of(bob, ITEM));
+
+        contract.assert_emitted(&Redeemed {
+            account: bob,
+            a: VOUCHER,
+            b: ITEM,
+            amount,
+            redeemed,
+        });
+    }
+
+    #[motsu::test]
+    fn redeem_reverts_when_redemption_not_set(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, VOUCHER, uint!(10_U256))
+            .motsu_expect("should mint vouchers");
+
+        let err = contract
+            .sender(alice)
+            .redeem(VOUCHER, uint!(10_U256))
+            .motsu_expect_err("should not redeem without redemption");
+
+        assert!(matches!(
+            err,
+            Error::RedemptionNotSet(ERC6909RedemptionNotSet { a })
+                if a == VOUCHER
+        ));
+    }
+
+    #[motsu::test]
+    fn redeem_reverts_when_nothing_is_minted(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        set_redemption(&contract, alice);
+        contract
+            .sender(alice)
+            ._mint(alice, VOUCHER, U256::ONE)
+            .motsu_expect("should mint vouchers");
+
+        let err = contract
+            .sender(alice)
+            .redeem(VOUCHER, U256::ONE)
+            .motsu_expect_err("should not redeem for nothing");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientRedemption(ERC6909InsufficientRedemption {
+                a,
+                amount,
+            }) if a == VOUCHER && amount == U256::ONE
+        ));
+        assert_eq!(
+            U256::ONE,
+            contract.sender(alice).balance_of(alice, VOUCHER)
+        );
+    }
+
+    #[motsu::test]
+    fn redeem_reverts_on_insufficient_balance(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        set_redemption(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .redeem(VOUCHER, uint!(10_U256))
+            .motsu_expect_err("should not redeem missing vouchers");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+        assert!(contract.sender(alice).balance_of(alice, ITEM).is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Redemption as IErc6909Redemption>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x36a1e3e1");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909Redemption>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Redemption as IErc6909Redemption>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Redemption as IErc6909>::interface_id()
+        ));
+        assert!(
+            contract.sender(alice).supports_interface(
+                <Erc6909Redemption as IErc165>::interface_id()
+            )
+        );
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
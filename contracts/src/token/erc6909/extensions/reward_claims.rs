@@ -0,0 +1,613 @@
+//! Extension of ERC-6909 that mints rewards computed off-chain, given a
+//! signature of a designated reward signer.
+//!
+//! The reward signer signs an EIP-712 `RewardClaim` of the rewards of a
+//! recipient, as arrays of token ids and amounts, with the recipient's next
+//! nonce and a deadline. Anyone holding the signature, usually the recipient
+//! itself, can submit it to [`IErc6909RewardClaims::claim`], which mints all
+//! the rewards to the recipient at once.
+//!
+//! Claims use the sequential nonces of [`Nonces`], so that each signature can
+//! only be used once, and the claims of a recipient must be submitted in
+//! order.
+//!
+//! Note that [`Erc6909RewardClaims::_set_reward_signer`] is not exposed by
+//! default. You should expose it manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, FixedBytes, U256};
+use alloy_sol_types::{sol_data, SolType};
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes, block, call::MethodError, evm, function_selector, prelude::*,
+    storage::StorageAddress,
+};
+
+use super::{
+    permit::ERC6909ExpiredSignature,
+    transfer_authorization::{split_signature, ERC6909InvalidSignatureLength},
+};
+use crate::{
+    token::erc6909::{
+        self, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission,
+    },
+    utils::{
+        cryptography::{
+            ecdsa::{self, ECDSAInvalidSignature, ECDSAInvalidSignatureS},
+            eip712::{hash_array, IEip712},
+        },
+        nonces::{self, INonces, InvalidAccountNonce, Nonces},
+    },
+};
+
+const REWARD_CLAIM_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"RewardClaim(address recipient,uint256[] ids,uint256[] amounts,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    pub(crate) type StructHashTuple = sol! {
+        tuple(bytes32, address, bytes32, bytes32, uint256, uint256)
+    };
+
+    sol! {
+        /// Emitted when the reward signer changes from `previous_signer` to
+        /// `new_signer`.
+        ///
+        /// * `previous_signer` - Previous reward signer.
+        /// * `new_signer` - New reward signer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event RewardSignerChanged(
+            address indexed previous_signer,
+            address indexed new_signer
+        );
+
+        /// Emitted when `recipient` claims the rewards signed with its
+        /// `nonce`.
+        ///
+        /// * `recipient` - Account receiving the rewards.
+        /// * `nonce` - Nonce of the claim.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event RewardsClaimed(address indexed recipient, uint256 nonce);
+    }
+
+    sol! {
+        /// Indicates that a reward claim was signed by `signer` instead of
+        /// the reward signer.
+        ///
+        /// * `signer` - Account that signed the claim.
+        /// * `reward_signer` - Account allowed to sign claims.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidRewardSigner(address signer, address reward_signer);
+    }
+}
+
+/// An [`Erc6909RewardClaims`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a claim was submitted after its deadline.
+    ExpiredSignature(ERC6909ExpiredSignature),
+    /// Indicates that a claim wasn't signed by the reward signer.
+    InvalidSigner(ERC6909InvalidRewardSigner),
+    /// Indicates that a claim doesn't use the recipient's next nonce.
+    InvalidAccountNonce(InvalidAccountNonce),
+    /// Indicates that a signature isn't made of 65 bytes.
+    InvalidSignatureLength(ERC6909InvalidSignatureLength),
+    /// The signature derives the [`Address::ZERO`].
+    InvalidSignature(ECDSAInvalidSignature),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(ECDSAInvalidSignatureS),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<ecdsa::Error> for Error {
+    fn from(value: ecdsa::Error) -> Self {
+        match value {
+            ecdsa::Error::InvalidSignature(e) => Error::InvalidSignature(e),
+            ecdsa::Error::InvalidSignatureS(e) => Error::InvalidSignatureS(e),
+        }
+    }
+}
+
+impl From<nonces::Error> for Error {
+    fn from(value: nonces::Error) -> Self {
+        match value {
+            nonces::Error::InvalidAccountNonce(e) => {
+                Error::InvalidAccountNonce(e)
+            }
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909RewardClaims`] contract.
+#[storage]
+pub struct Erc6909RewardClaims<T: IEip712 + StorageType> {
+    /// Contract implementing [`IEip712`] trait.
+    pub(crate) eip712: T,
+    /// Account allowed to sign reward claims.
+    pub(crate) reward_signer: StorageAddress,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl<T: IEip712 + StorageType> TopLevelStorage
+    for Erc6909RewardClaims<T>
+{
+}
+
+/// Required interface of an [`Erc6909RewardClaims`] contract.
+pub trait IErc6909RewardClaims: INonces {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    // Calculated manually to include [`INonces::nonces`].
+    /// Solidity interface id associated with [`IErc6909RewardClaims`] trait.
+    /// Computed as a XOR of selectors for each function in the trait.
+    #[must_use]
+    fn interface_id() -> FixedBytes<4>
+    where
+        Self: Sized,
+    {
+        FixedBytes::<4>::new(function_selector!("nonces", Address,))
+            ^ FixedBytes::<4>::new(function_selector!("rewardSigner",))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "claim",
+                Address,
+                Vec<U256>,
+                Vec<U256>,
+                U256,
+                U256,
+                Bytes
+            ))
+    }
+
+    /// Returns the account allowed to sign reward claims.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn reward_signer(&self) -> Address;
+
+    /// Mints `amounts` of tokens of type `ids` to `recipient`, given the
+    /// reward signer's signature of the claim.
+    ///
+    /// Anyone can submit the claim, but the rewards are always minted to
+    /// `recipient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `recipient` - Account receiving the rewards.
+    /// * `ids` - Token ids of the rewards.
+    /// * `amounts` - Amounts of the rewards, one per token id.
+    /// * `nonce` - Next nonce of `recipient`.
+    /// * `deadline` - Timestamp after which the claim expires.
+    /// * `signature` - Reward signer's signature of the claim, made of its `r`,
+    ///   `s` and `v` values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If the current timestamp is greater than
+    ///   `deadline`.
+    /// * [`Error::InvalidAccountNonce`] - If `nonce` is not the next nonce of
+    ///   `recipient`.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not the reward signer.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InvalidReceiver`] - If `recipient` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] or [`erc6909::TransferBatch`] event.
+    /// * [`RewardsClaimed`] event.
+    #[allow(clippy::too_many_arguments)]
+    fn claim(
+        &mut self,
+        recipient: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        nonce: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: IEip712 + StorageType> Erc6909RewardClaims<T> {
+    /// See [`IErc6909RewardClaims::reward_signer`].
+    #[must_use]
+    pub fn reward_signer(&self) -> Address {
+        self.reward_signer.get()
+    }
+
+    /// See [`IErc6909RewardClaims::claim`].
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn claim(
+        &mut self,
+        recipient: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        nonce: U256,
+        deadline: U256,
+        signature: &Bytes,
+        erc6909: &mut Erc6909,
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        self._use_claim(
+            recipient, &ids, &amounts, nonce, deadline, signature, nonces,
+        )?;
+
+        erc6909._mint_batch(recipient, ids, amounts)?;
+        evm::log(RewardsClaimed { recipient, nonce });
+
+        Ok(())
+    }
+
+    /// Sets the account allowed to sign reward claims.
+    ///
+    /// Setting it to [`Address::ZERO`] disables claims, as no signature
+    /// recovers it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `new_signer` - New reward signer.
+    ///
+    /// # Events
+    ///
+    /// * [`RewardSignerChanged`] event.
+    pub fn _set_reward_signer(&mut self, new_signer: Address) {
+        let previous_signer = self.reward_signer.get();
+        self.reward_signer.set(new_signer);
+        evm::log(RewardSignerChanged { previous_signer, new_signer });
+    }
+
+    /// Checks that `signature` is the reward signer's signature of the claim
+    /// of `amounts` of tokens of type `ids` by `recipient`, and consumes its
+    /// nonce.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `recipient` - Account receiving the rewards.
+    /// * `ids` - Token ids of the rewards.
+    /// * `amounts` - Amounts of the rewards, one per token id.
+    /// * `nonce` - Next nonce of `recipient`.
+    /// * `deadline` - Timestamp after which the claim expires.
+    /// * `signature` - Signature made of its `r`, `s` and `v` values.
+    /// * `nonces` - Write access to the nonces of the contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If the current timestamp is greater than
+    ///   `deadline`.
+    /// * [`Error::InvalidAccountNonce`] - If `nonce` is not the next nonce of
+    ///   `recipient`.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not the reward signer.
+    #[allow(clippy::too_many_arguments)]
+    fn _use_claim(
+        &mut self,
+        recipient: Address,
+        ids: &[U256],
+        amounts: &[U256],
+        nonce: U256,
+        deadline: U256,
+        signature: &[u8],
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        if U256::from(block::timestamp()) > deadline {
+            return Err(ERC6909ExpiredSignature { deadline }.into());
+        }
+
+        nonces.use_checked_nonce(recipient, nonce)?;
+        let (v, r, s) = split_signature(signature)?;
+
+        let struct_hash = keccak256(StructHashTuple::abi_encode(&(
+            REWARD_CLAIM_TYPEHASH,
+            recipient,
+            hash_array::<sol_data::Uint<256>>(ids),
+            hash_array::<sol_data::Uint<256>>(amounts),
+            nonce,
+            deadline,
+        )));
+        let hash = self.eip712.hash_typed_data_v4(struct_hash);
+
+        let signer = ecdsa::recover(self, hash, v, r, s)?;
+        let reward_signer = self.reward_signer.get();
+        if signer != reward_signer {
+            return Err(
+                ERC6909InvalidRewardSigner { signer, reward_signer }.into()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::Bytes, block, prelude::*};
+
+    use super::*;
+    use crate::token::erc6909::IErc6909;
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+
+    #[storage]
+    struct Eip712;
+
+    impl IEip712 for Eip712 {
+        const NAME: &'static str = "ERC-6909 Reward Claims Example";
+        const VERSION: &'static str = "1";
+    }
+
+    #[storage]
+    struct Erc6909RewardClaimsExample {
+        erc6909: Erc6909,
+        nonces: Nonces,
+        reward_claims: Erc6909RewardClaims<Eip712>,
+    }
+
+    #[public]
+    #[implements(INonces, IErc6909RewardClaims<Error = Error>)]
+    impl Erc6909RewardClaimsExample {}
+
+    #[public]
+    impl INonces for Erc6909RewardClaimsExample {
+        fn nonces(&self, owner: Address) -> U256 {
+            self.nonces.nonces(owner)
+        }
+    }
+
+    #[public]
+    impl IErc6909RewardClaims for Erc6909RewardClaimsExample {
+        type Error = Error;
+
+        fn reward_signer(&self) -> Address {
+            self.reward_claims.reward_signer()
+        }
+
+        fn claim(
+            &mut self,
+            recipient: Address,
+            ids: Vec<U256>,
+            amounts: Vec<U256>,
+            nonce: U256,
+            deadline: U256,
+            signature: Bytes,
+        ) -> Result<(), Self::Error> {
+            self.reward_claims.claim(
+                recipient,
+                ids,
+                amounts,
+                nonce,
+                deadline,
+                &signature,
+                &mut self.erc6909,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909RewardClaimsExample {}
+
+    fn init(contract: &Contract<Erc6909RewardClaimsExample>, signer: Address) {
+        contract.init(signer, |contract| {
+            contract.reward_claims._set_reward_signer(signer);
+        });
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909RewardClaimsExample as IErc6909RewardClaims>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0x2107a04c");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn set_reward_signer_emits_event(
+        contract: Contract<Erc6909RewardClaimsExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        contract.sender(alice).reward_claims._set_reward_signer(bob);
+
+        assert_eq!(bob, contract.sender(alice).reward_signer());
+        contract.assert_emitted(&RewardSignerChanged {
+            previous_signer: alice,
+            new_signer: bob,
+        });
+    }
+
+    #[motsu::test]
+    fn claim_reverts_when_expired(
+        contract: Contract<Erc6909RewardClaimsExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let deadline = U256::from(block::timestamp()) - uint!(1_U256);
+        let err = contract
+            .sender(bob)
+            .claim(
+                bob,
+                vec![TOKEN_ID],
+                vec![AMOUNT],
+                U256::ZERO,
+                deadline,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should be expired");
+
+        assert!(matches!(
+            err,
+            Error::ExpiredSignature(ERC6909ExpiredSignature { deadline: d })
+                if d == deadline
+        ));
+        assert!(contract.sender(bob).nonces(bob).is_zero());
+        assert!(contract
+            .sender(bob)
+            .erc6909
+            .balance_of(bob, TOKEN_ID)
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn claim_reverts_when_nonce_invalid(
+        contract: Contract<Erc6909RewardClaimsExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            .claim(
+                bob,
+                vec![TOKEN_ID],
+                vec![AMOUNT],
+                uint!(1_U256),
+                U256::MAX,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should not skip a nonce");
+
+        assert!(matches!(
+            err,
+            Error::InvalidAccountNonce(InvalidAccountNonce {
+                account,
+                current_nonce,
+            }) if account == bob && current_nonce.is_zero()
+        ));
+        assert!(contract.sender(bob).nonces(bob).is_zero());
+    }
+
+    #[motsu::test]
+    fn claim_reverts_when_signature_length_invalid(
+        contract: Contract<Erc6909RewardClaimsExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        for length in [0, 64, 66] {
+            let err = contract
+                .sender(bob)
+                .claim(
+                    bob,
+                    vec![TOKEN_ID],
+                    vec![AMOUNT],
+                    U256::ZERO,
+                    U256::MAX,
+                    vec![0; length].into(),
+                )
+                .motsu_expect_err("should reject the signature");
+
+            assert!(matches!(
+                err,
+                Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                    length: l
+                }) if l == U256::from(length)
+            ));
+        }
+        assert!(contract.sender(bob).nonces(bob).is_zero());
+        assert!(contract
+            .sender(bob)
+            .erc6909
+            .balance_of(bob, TOKEN_ID)
+            .is_zero());
+    }
+}
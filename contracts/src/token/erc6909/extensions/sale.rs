@@ -0,0 +1,1391 @@
+//! Extension of ERC-6909 that sells tokens for native ETH at a fixed price
+//! per token id.
+//!
+//! Each token id can be put on sale during a time window, at a price per
+//! token. Buyers pay the exact cost of their purchase, which is forwarded to
+//! the treasury once the tokens have been minted.
+//!
+//! A token id can also be sold in refundable mode by giving it a funding goal.
+//! The payments are then escrowed by the contract until the end of the sale.
+//! If the goal is reached, the proceeds can be swept to the treasury.
+//! Otherwise, buyers can claim a refund of their payments, burning the tokens
+//! they bought.
+//!
+//! Note that [`Erc6909Sale::_set_sale`], [`Erc6909Sale::_set_treasury`] and
+//! [`Erc6909Sale::_set_funding_goal`] are not exposed by default. You should
+//! expose them manually in your contract's abi, guarded by proper access
+//! control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::{self, Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        introspection::erc165::IErc165,
+        math::{
+            alloy::{Math, Rounding},
+            storage::{
+                AddAssignChecked, AddAssignUnchecked, SubAssignUnchecked,
+            },
+        },
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when token `id` is put on sale at `price` per token,
+        /// from `start` until `end`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `price` - Price of one token, in wei.
+        /// * `start` - Timestamp from which tokens can be bought.
+        /// * `end` - Timestamp from which tokens can't be bought anymore.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event SaleSet(uint256 indexed id, uint256 price, uint64 start, uint64 end);
+
+        /// Emitted when the treasury receiving the sale proceeds changes.
+        ///
+        /// * `treasury` - Account receiving the sale proceeds.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TreasurySet(address indexed treasury);
+
+        /// Emitted when `buyer` buys `amount` tokens of type `id` for `cost`.
+        ///
+        /// * `buyer` - Account buying the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens bought.
+        /// * `cost` - Amount of ETH paid, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Purchased(
+            address indexed buyer,
+            uint256 indexed id,
+            uint256 amount,
+            uint256 cost
+        );
+
+        /// Emitted when the funding goal of token `id` changes to `goal`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `goal` - Funding goal, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event FundingGoalSet(uint256 indexed id, uint256 goal);
+
+        /// Emitted when `buyer` burns `amount` tokens of type `id` to get
+        /// `refund` back.
+        ///
+        /// * `buyer` - Account claiming the refund.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens burnt.
+        /// * `refund` - Amount of ETH refunded, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Refunded(
+            address indexed buyer,
+            uint256 indexed id,
+            uint256 amount,
+            uint256 refund
+        );
+
+        /// Emitted when the escrowed proceeds of token `id` are sent to
+        /// `treasury`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `treasury` - Account receiving the sale proceeds.
+        /// * `amount` - Amount of ETH, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ProceedsSwept(
+            uint256 indexed id,
+            address indexed treasury,
+            uint256 amount
+        );
+    }
+
+    sol! {
+        /// Indicates that tokens of type `id` can't be bought at the current
+        /// timestamp.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909SaleNotActive(uint256 id);
+
+        /// Indicates that the ETH sent doesn't match the cost of the purchase.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `cost` - Cost of the purchase, in wei.
+        /// * `value` - Amount of ETH sent, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909IncorrectPayment(uint256 id, uint256 cost, uint256 value);
+
+        /// Indicates that the treasury is invalid, e.g. [`Address::ZERO`].
+        ///
+        /// * `treasury` - Account receiving the sale proceeds.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidTreasury(address treasury);
+
+        /// Indicates that the sale proceeds couldn't be sent to the treasury.
+        ///
+        /// * `treasury` - Account receiving the sale proceeds.
+        /// * `amount` - Amount of ETH, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TreasuryTransferFailed(address treasury, uint256 amount);
+
+        /// Indicates that refunds of token `id` can't be claimed, either
+        /// because its sale is not refundable, hasn't ended yet or has
+        /// reached its funding goal.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909RefundUnavailable(uint256 id);
+
+        /// Indicates that `account` has nothing to be refunded for token
+        /// `id`.
+        ///
+        /// * `account` - Account claiming the refund.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NothingToRefund(address account, uint256 id);
+
+        /// Indicates that the refund couldn't be sent to `account`.
+        ///
+        /// * `account` - Account claiming the refund.
+        /// * `amount` - Amount of ETH, in wei.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909RefundTransferFailed(address account, uint256 amount);
+
+        /// Indicates that the proceeds of token `id` can't be swept, either
+        /// because its sale hasn't ended yet, hasn't reached its funding goal
+        /// or has no escrowed proceeds left.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ProceedsUnavailable(uint256 id);
+    }
+}
+
+/// An [`Erc6909Sale`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a token id is not on sale.
+    SaleNotActive(ERC6909SaleNotActive),
+    /// Indicates that the ETH sent doesn't match the cost of the purchase.
+    IncorrectPayment(ERC6909IncorrectPayment),
+    /// Indicates that the treasury is invalid.
+    InvalidTreasury(ERC6909InvalidTreasury),
+    /// Indicates that the sale proceeds couldn't be sent to the treasury.
+    TreasuryTransferFailed(ERC6909TreasuryTransferFailed),
+    /// Indicates that refunds of a token id can't be claimed.
+    RefundUnavailable(ERC6909RefundUnavailable),
+    /// Indicates that an account has nothing to be refunded.
+    NothingToRefund(ERC6909NothingToRefund),
+    /// Indicates that a refund couldn't be sent.
+    RefundTransferFailed(ERC6909RefundTransferFailed),
+    /// Indicates that the proceeds of a token id can't be swept.
+    ProceedsUnavailable(ERC6909ProceedsUnavailable),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Sale`] contract.
+#[storage]
+pub struct Erc6909Sale {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Account receiving the sale proceeds.
+    pub(crate) treasury: StorageAddress,
+    /// Mapping from token id to the price of one token.
+    pub(crate) prices: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the timestamp from which it can be bought.
+    pub(crate) sale_starts: StorageMap<U256, StorageU64>,
+    /// Mapping from token id to the timestamp from which it can't be bought
+    /// anymore.
+    pub(crate) sale_ends: StorageMap<U256, StorageU64>,
+    /// Mapping from token id to its funding goal, zero if its sale is not
+    /// refundable.
+    pub(crate) funding_goals: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the ETH raised by its refundable sale, net of
+    /// refunds.
+    pub(crate) funds_raised: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to the ETH escrowed by its refundable sale.
+    pub(crate) escrowed_funds: StorageMap<U256, StorageU256>,
+    /// Mapping from buyer to token id to the ETH paid in its refundable sale.
+    pub(crate) contributions:
+        StorageMap<Address, StorageMap<U256, StorageU256>>,
+    /// Mapping from buyer to token id to the amount of tokens bought in its
+    /// refundable sale.
+    pub(crate) purchases: StorageMap<Address, StorageMap<U256, StorageU256>>,
+}
+
+impl_as_erc6909!(Erc6909Sale, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909Sale {}
+
+/// Required interface of an [`Erc6909Sale`] contract.
+#[interface_id]
+pub trait IErc6909Sale: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the price of one token of type `id`, in wei.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn price(&self, id: U256) -> U256;
+
+    /// Returns the timestamp from which tokens of type `id` can be bought.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn sale_start(&self, id: U256) -> U64;
+
+    /// Returns the timestamp from which tokens of type `id` can't be bought
+    /// anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn sale_end(&self, id: U256) -> U64;
+
+    /// Returns the account receiving the sale proceeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn treasury(&self) -> Address;
+
+    /// Mints `amount` tokens of type `id` to the caller, in exchange for the
+    /// ETH sent with the call, which is forwarded to the treasury.
+    ///
+    /// If the sale of `id` is refundable, the ETH is escrowed by the contract
+    /// instead, until the proceeds are swept or refunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to buy.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SaleNotActive`] - If the current timestamp is outside of the
+    ///   sale window of `id`.
+    /// * [`Error::IncorrectPayment`] - If the ETH sent is not exactly `amount`
+    ///   times the price of `id`.
+    /// * [`Error::TreasuryTransferFailed`] - If the ETH couldn't be forwarded
+    ///   to the treasury.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Purchased`].
+    ///
+    /// # Panics
+    ///
+    /// * If the cost of the purchase exceeds [`U256::MAX`].
+    fn buy(&mut self, id: U256, amount: U256) -> Result<(), Self::Error>;
+}
+
+/// Interface of the refundable mode of an [`Erc6909Sale`] contract.
+#[interface_id]
+pub trait IErc6909RefundableSale: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the funding goal of token `id`, in wei, zero if its sale is not
+    /// refundable.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn funding_goal(&self, id: U256) -> U256;
+
+    /// Returns the ETH raised by the refundable sale of token `id`, net of
+    /// refunds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn funds_raised(&self, id: U256) -> U256;
+
+    /// Returns the ETH paid by `account` in the refundable sale of token
+    /// `id`, that it can get back if the funding goal is not reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account that bought tokens.
+    /// * `id` - Token id as a number.
+    fn contribution(&self, account: Address, id: U256) -> U256;
+
+    /// Burns the tokens of type `id` bought by the caller and refunds the ETH
+    /// it paid for them.
+    ///
+    /// Bought tokens the caller doesn't hold anymore are not burned, and only
+    /// the share of its payment matching the burned tokens is refunded. The
+    /// rest can be claimed once the tokens are back in the caller's hands.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::RefundUnavailable`] - If the sale of `id` is not refundable,
+    ///   hasn't ended yet or has reached its funding goal.
+    /// * [`Error::NothingToRefund`] - If the caller hasn't paid anything for
+    ///   tokens of type `id`, or doesn't hold any of the tokens it bought
+    ///   anymore.
+    /// * [`Error::RefundTransferFailed`] - If the ETH couldn't be sent to the
+    ///   caller.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`Refunded`].
+    fn claim_refund(&mut self, id: U256) -> Result<(), Self::Error>;
+
+    /// Sends the escrowed proceeds of token `id` to the treasury.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ProceedsUnavailable`] - If the sale of `id` hasn't ended yet,
+    ///   hasn't reached its funding goal or has no escrowed proceeds left.
+    /// * [`Error::TreasuryTransferFailed`] - If the ETH couldn't be sent to the
+    ///   treasury.
+    ///
+    /// # Events
+    ///
+    /// * [`ProceedsSwept`].
+    fn sweep_proceeds(&mut self, id: U256) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Sale<Error = Error>,
+    IErc6909RefundableSale<Error = Error>,
+    IErc165
+)]
+impl Erc6909Sale {}
+
+#[public]
+impl IErc6909Sale for Erc6909Sale {
+    type Error = Error;
+
+    fn price(&self, id: U256) -> U256 {
+        self.prices.get(id)
+    }
+
+    fn sale_start(&self, id: U256) -> U64 {
+        self.sale_starts.get(id)
+    }
+
+    fn sale_end(&self, id: U256) -> U64 {
+        self.sale_ends.get(id)
+    }
+
+    fn treasury(&self) -> Address {
+        self.treasury.get()
+    }
+
+    #[payable]
+    fn buy(&mut self, id: U256, amount: U256) -> Result<(), Self::Error> {
+        let now = U64::from(block::timestamp());
+        if now < self.sale_start(id) || now >= self.sale_end(id) {
+            return Err(Error::SaleNotActive(ERC6909SaleNotActive { id }));
+        }
+
+        let cost = self
+            .price(id)
+            .checked_mul(amount)
+            .expect("sale cost should not exceed `U256::MAX`");
+        let value = msg::value();
+        if value != cost {
+            return Err(Error::IncorrectPayment(ERC6909IncorrectPayment {
+                id,
+                cost,
+                value,
+            }));
+        }
+
+        let buyer = msg::sender();
+        self._mint(buyer, id, amount)?;
+
+        if self._is_refundable(id) {
+            self._escrow(buyer, id, amount, cost);
+            evm::log(Purchased { buyer, id, amount, cost });
+            return Ok(());
+        }
+
+        let treasury = self.treasury();
+        if !cost.is_zero()
+            && call::call(Call::new_in(self).value(cost), treasury, &[])
+                .is_err()
+        {
+            return Err(Error::TreasuryTransferFailed(
+                ERC6909TreasuryTransferFailed { treasury, amount: cost },
+            ));
+        }
+
+        evm::log(Purchased { buyer, id, amount, cost });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909RefundableSale for Erc6909Sale {
+    type Error = Error;
+
+    fn funding_goal(&self, id: U256) -> U256 {
+        self.funding_goals.get(id)
+    }
+
+    fn funds_raised(&self, id: U256) -> U256 {
+        self.funds_raised.get(id)
+    }
+
+    fn contribution(&self, account: Address, id: U256) -> U256 {
+        self.contributions.get(account).get(id)
+    }
+
+    fn claim_refund(&mut self, id: U256) -> Result<(), Self::Error> {
+        if !self._is_refundable(id)
+            || !self._has_ended(id)
+            || self._is_goal_reached(id)
+        {
+            return Err(Error::RefundUnavailable(ERC6909RefundUnavailable {
+                id,
+            }));
+        }
+
+        let buyer = msg::sender();
+        let contribution = self.contribution(buyer, id);
+        let purchased = self.purchases.get(buyer).get(id);
+        // Tokens transferred away since the purchase can't be burned, so only
+        // the ones still held are refunded, pro rata of the contribution.
+        let amount = purchased.min(self.balance_of(buyer, id));
+        if contribution.is_zero() || amount.is_zero() {
+            return Err(Error::NothingToRefund(ERC6909NothingToRefund {
+                account: buyer,
+                id,
+            }));
+        }
+
+        // Refunding the whole purchase refunds the whole contribution, so no
+        // rounding dust is left behind.
+        let refund = contribution.mul_div(amount, purchased, Rounding::Floor);
+        // Overflow not possible: `refund` <= `contribution` and `amount` <=
+        // `purchased`.
+        self.contributions
+            .setter(buyer)
+            .setter(id)
+            .sub_assign_unchecked(refund);
+        self.purchases.setter(buyer).setter(id).sub_assign_unchecked(amount);
+        // Overflow not possible: both totals include `contribution`.
+        self.funds_raised.setter(id).sub_assign_unchecked(refund);
+        self.escrowed_funds.setter(id).sub_assign_unchecked(refund);
+        self._burn(buyer, id, amount)?;
+
+        if call::call(Call::new_in(self).value(refund), buyer, &[]).is_err() {
+            return Err(Error::RefundTransferFailed(
+                ERC6909RefundTransferFailed { account: buyer, amount: refund },
+            ));
+        }
+
+        evm::log(Refunded { buyer, id, amount, refund });
+
+        Ok(())
+    }
+
+    fn sweep_proceeds(&mut self, id: U256) -> Result<(), Self::Error> {
+        let amount = self.escrowed_funds.get(id);
+        if !self._has_ended(id)
+            || !self._is_goal_reached(id)
+            || amount.is_zero()
+        {
+            return Err(Error::ProceedsUnavailable(
+                ERC6909ProceedsUnavailable { id },
+            ));
+        }
+
+        self.escrowed_funds.setter(id).set(U256::ZERO);
+
+        let treasury = self.treasury();
+        if call::call(Call::new_in(self).value(amount), treasury, &[]).is_err()
+        {
+            return Err(Error::TreasuryTransferFailed(
+                ERC6909TreasuryTransferFailed { treasury, amount },
+            ));
+        }
+
+        evm::log(ProceedsSwept { id, treasury, amount });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Sale {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Sale {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Sale>::interface_id() == interface_id
+            || <Self as IErc6909RefundableSale>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Sale {
+    /// Puts token `id` on sale at `price` per token, from `start` until
+    /// `end`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Setting `end` to a timestamp lower than or equal to `start` closes the
+    /// sale of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `price` - Price of one token, in wei.
+    /// * `start` - Timestamp from which tokens can be bought.
+    /// * `end` - Timestamp from which tokens can't be bought anymore.
+    ///
+    /// # Events
+    ///
+    /// * [`SaleSet`].
+    pub fn _set_sale(&mut self, id: U256, price: U256, start: U64, end: U64) {
+        self.prices.setter(id).set(price);
+        self.sale_starts.setter(id).set(start);
+        self.sale_ends.setter(id).set(end);
+        evm::log(SaleSet { id, price, start: start.to(), end: end.to() });
+    }
+
+    /// Sets the account receiving the sale proceeds.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `treasury` - Account receiving the sale proceeds.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidTreasury`] - If `treasury` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`TreasurySet`].
+    pub fn _set_treasury(&mut self, treasury: Address) -> Result<(), Error> {
+        if treasury.is_zero() {
+            return Err(Error::InvalidTreasury(ERC6909InvalidTreasury {
+                treasury,
+            }));
+        }
+
+        self.treasury.set(treasury);
+        evm::log(TreasurySet { treasury });
+
+        Ok(())
+    }
+
+    /// Makes the sale of token `id` refundable, unless `goal` is zero.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Payments for `id` are escrowed until its sale ends. If `goal` is
+    /// reached by then, the proceeds can be swept to the treasury, otherwise
+    /// buyers can claim refunds.
+    ///
+    /// NOTE: Changing the funding goal of a token id that has already been
+    /// bought in refundable mode changes whether its buyers can claim
+    /// refunds. Setting it to zero leaves its escrowed funds locked.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `goal` - Funding goal, in wei.
+    ///
+    /// # Events
+    ///
+    /// * [`FundingGoalSet`].
+    pub fn _set_funding_goal(&mut self, id: U256, goal: U256) {
+        self.funding_goals.setter(id).set(goal);
+        evm::log(FundingGoalSet { id, goal });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909Sale {
+    /// Returns whether the sale of token `id` is refundable.
+    fn _is_refundable(&self, id: U256) -> bool {
+        !self.funding_goal(id).is_zero()
+    }
+
+    /// Returns whether the sale of token `id` has ended.
+    fn _has_ended(&self, id: U256) -> bool {
+        U64::from(block::timestamp()) >= self.sale_end(id)
+    }
+
+    /// Returns whether the refundable sale of token `id` has reached its
+    /// funding goal.
+    fn _is_goal_reached(&self, id: U256) -> bool {
+        self.funds_raised(id) >= self.funding_goal(id)
+    }
+
+    /// Records that `buyer` paid `cost` for `amount` tokens of type `id`, in
+    /// its refundable sale.
+    ///
+    /// # Panics
+    ///
+    /// * If the escrowed ETH exceeds [`U256::MAX`].
+    /// * If the tokens bought by `buyer` exceed [`U256::MAX`].
+    fn _escrow(&mut self, buyer: Address, id: U256, amount: U256, cost: U256) {
+        self.funds_raised.setter(id).add_assign_checked(
+            cost,
+            "escrowed funds should not exceed `U256::MAX`",
+        );
+        // Overflow not possible: `cost` is part of `funds_raised`.
+        self.escrowed_funds.setter(id).add_assign_unchecked(cost);
+        self.contributions.setter(buyer).setter(id).add_assign_unchecked(cost);
+        self.purchases.setter(buyer).setter(id).add_assign_checked(
+            amount,
+            "purchased tokens should not exceed `U256::MAX`",
+        );
+    }
+}
+
+impl IErc6909Internal for Erc6909Sale {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Erc6909Sale {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        let mut events = Erc6909::events();
+        events.extend([
+            event::<SaleSet>(
+                "event SaleSet(uint256 indexed id, uint256 price, uint64 \
+                 start, uint64 end)",
+            ),
+            event::<TreasurySet>("event TreasurySet(address indexed treasury)"),
+            event::<Purchased>(
+                "event Purchased(address indexed buyer, uint256 indexed id, \
+                 uint256 amount, uint256 cost)",
+            ),
+            event::<FundingGoalSet>(
+                "event FundingGoalSet(uint256 indexed id, uint256 goal)",
+            ),
+            event::<Refunded>(
+                "event Refunded(address indexed buyer, uint256 indexed id, \
+                 uint256 amount, uint256 refund)",
+            ),
+            event::<ProceedsSwept>(
+                "event ProceedsSwept(uint256 indexed id, address indexed \
+                 treasury, uint256 amount)",
+            ),
+        ]);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const PRICE: U256 = uint!(100_U256);
+    const AMOUNT: U256 = uint!(3_U256);
+
+    fn open_sale(contract: &Contract<Erc6909Sale>, admin: Address) {
+        contract.sender(admin)._set_sale(ID, PRICE, U64::ZERO, U64::MAX);
+    }
+
+    #[motsu::test]
+    fn set_sale_emits_event(contract: Contract<Erc6909Sale>, alice: Address) {
+        open_sale(&contract, alice);
+
+        assert_eq!(PRICE, contract.sender(alice).price(ID));
+        assert_eq!(U64::ZERO, contract.sender(alice).sale_start(ID));
+        assert_eq!(U64::MAX, contract.sender(alice).sale_end(ID));
+        contract.assert_emitted(&SaleSet {
+            id: ID,
+            price: PRICE,
+            start: 0,
+            end: u64::MAX,
+        });
+    }
+
+    #[motsu::test]
+    fn set_treasury_reverts_on_zero_address(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._set_treasury(Address::ZERO)
+            .motsu_expect_err("should not set the zero address as treasury");
+
+        assert!(matches!(
+            err,
+            Error::InvalidTreasury(ERC6909InvalidTreasury { treasury })
+                if treasury.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn buy_mints_and_forwards_payment(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_sale(&contract, alice);
+        contract
+            .sender(alice)
+            ._set_treasury(treasury)
+            .motsu_expect("should set treasury");
+        let cost = PRICE * AMOUNT;
+        bob.fund(cost);
+
+        contract
+            .sender_and_value(bob, cost)
+            .buy(ID, AMOUNT)
+            .motsu_expect("should buy tokens");
+
+        assert_eq!(AMOUNT, contract.sender(bob).balance_of(bob, ID));
+        assert_eq!(cost, treasury.balance());
+        assert!(contract.address().balance().is_zero());
+        contract.assert_emitted(&Purchased {
+            buyer: bob,
+            id: ID,
+            amount: AMOUNT,
+            cost,
+        });
+    }
+
+    #[motsu::test]
+    fn buy_reverts_on_incorrect_payment(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_sale(&contract, alice);
+        contract
+            .sender(alice)
+            ._set_treasury(treasury)
+            .motsu_expect("should set treasury");
+        let value = PRICE;
+        bob.fund(value);
+
+        let err = contract
+            .sender_and_value(bob, value)
+            .buy(ID, AMOUNT)
+            .motsu_expect_err("should not buy with an incorrect payment");
+
+        assert!(matches!(
+            err,
+            Error::IncorrectPayment(ERC6909IncorrectPayment {
+                id,
+                cost,
+                value: actual,
+            }) if id == ID && cost == PRICE * AMOUNT && actual == value
+        ));
+    }
+
+    #[motsu::test]
+    fn buy_reverts_when_sale_not_set(
+        contract: Contract<Erc6909Sale>,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(bob)
+            .buy(ID, AMOUNT)
+            .motsu_expect_err("should not buy tokens that are not on sale");
+
+        assert!(matches!(
+            err,
+            Error::SaleNotActive(ERC6909SaleNotActive { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn buy_reverts_before_sale_start(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_sale(
+            ID,
+            PRICE,
+            U64::MAX - U64::ONE,
+            U64::MAX,
+        );
+
+        let err = contract
+            .sender(bob)
+            .buy(ID, AMOUNT)
+            .motsu_expect_err("should not buy before the sale starts");
+
+        assert!(matches!(
+            err,
+            Error::SaleNotActive(ERC6909SaleNotActive { id }) if id == ID
+        ));
+    }
+
+    const GOAL: U256 = uint!(1000_U256);
+
+    fn open_refundable_sale(
+        contract: &Contract<Erc6909Sale>,
+        admin: Address,
+        treasury: Address,
+    ) {
+        open_sale(contract, admin);
+        contract.sender(admin)._set_funding_goal(ID, GOAL);
+        contract
+            .sender(admin)
+            ._set_treasury(treasury)
+            .motsu_expect("should set treasury");
+    }
+
+    fn close_sale(contract: &Contract<Erc6909Sale>, admin: Address) {
+        contract.sender(admin)._set_sale(ID, PRICE, U64::ZERO, U64::ZERO);
+    }
+
+    fn buy(contract: &Contract<Erc6909Sale>, buyer: Address, amount: U256) {
+        let cost = PRICE * amount;
+        buyer.fund(cost);
+        contract
+            .sender_and_value(buyer, cost)
+            .buy(ID, amount)
+            .motsu_expect("should buy tokens");
+    }
+
+    #[motsu::test]
+    fn refundable_buy_escrows_payment(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        contract.assert_emitted(&FundingGoalSet { id: ID, goal: GOAL });
+
+        buy(&contract, bob, AMOUNT);
+
+        let cost = PRICE * AMOUNT;
+        assert_eq!(AMOUNT, contract.sender(bob).balance_of(bob, ID));
+        assert_eq!(cost, contract.sender(bob).funds_raised(ID));
+        assert_eq!(cost, contract.sender(bob).contribution(bob, ID));
+        assert_eq!(cost, contract.address().balance());
+        assert!(treasury.balance().is_zero());
+    }
+
+    #[motsu::test]
+    fn claim_refund_burns_tokens_and_refunds_payment(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, AMOUNT);
+        close_sale(&contract, alice);
+
+        contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect("should claim refund");
+
+        let cost = PRICE * AMOUNT;
+        assert!(contract.sender(bob).balance_of(bob, ID).is_zero());
+        assert!(contract.sender(bob).funds_raised(ID).is_zero());
+        assert!(contract.sender(bob).contribution(bob, ID).is_zero());
+        assert!(contract.address().balance().is_zero());
+        assert_eq!(cost, bob.balance());
+        contract.assert_emitted(&Refunded {
+            buyer: bob,
+            id: ID,
+            amount: AMOUNT,
+            refund: cost,
+        });
+
+        let err = contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect_err("should not refund twice");
+
+        assert!(matches!(
+            err,
+            Error::NothingToRefund(ERC6909NothingToRefund { account, id })
+                if account == bob && id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn claim_refund_refunds_tokens_held_pro_rata(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, AMOUNT);
+        close_sale(&contract, alice);
+
+        let transferred = U256::ONE;
+        contract
+            .sender(bob)
+            .transfer(charlie, ID, transferred)
+            .motsu_expect("should transfer tokens");
+
+        contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect("should refund the tokens held");
+
+        let held = AMOUNT - transferred;
+        assert!(contract.sender(bob).balance_of(bob, ID).is_zero());
+        assert_eq!(PRICE * held, bob.balance());
+        assert_eq!(
+            PRICE * transferred,
+            contract.sender(bob).contribution(bob, ID)
+        );
+        assert_eq!(PRICE * transferred, contract.sender(bob).funds_raised(ID));
+        assert_eq!(PRICE * transferred, contract.address().balance());
+        contract.assert_emitted(&Refunded {
+            buyer: bob,
+            id: ID,
+            amount: held,
+            refund: PRICE * held,
+        });
+
+        let err = contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect_err("should not refund without tokens");
+
+        assert!(matches!(
+            err,
+            Error::NothingToRefund(ERC6909NothingToRefund { account, id })
+                if account == bob && id == ID
+        ));
+
+        contract
+            .sender(charlie)
+            .transfer(bob, ID, transferred)
+            .motsu_expect("should transfer tokens back");
+
+        contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect("should refund the rest");
+
+        assert!(contract.sender(bob).contribution(bob, ID).is_zero());
+        assert!(contract.sender(bob).funds_raised(ID).is_zero());
+        assert!(contract.address().balance().is_zero());
+        assert_eq!(PRICE * AMOUNT, bob.balance());
+    }
+
+    #[motsu::test]
+    fn claim_refund_reverts_before_sale_end(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, AMOUNT);
+
+        let err = contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect_err("should not refund before the sale ends");
+
+        assert!(matches!(
+            err,
+            Error::RefundUnavailable(ERC6909RefundUnavailable { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn claim_refund_reverts_when_goal_reached(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, GOAL / PRICE);
+        close_sale(&contract, alice);
+
+        let err = contract
+            .sender(bob)
+            .claim_refund(ID)
+            .motsu_expect_err("should not refund when the goal is reached");
+
+        assert!(matches!(
+            err,
+            Error::RefundUnavailable(ERC6909RefundUnavailable { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn sweep_proceeds_sends_escrow_to_treasury(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, GOAL / PRICE);
+        close_sale(&contract, alice);
+
+        contract
+            .sender(alice)
+            .sweep_proceeds(ID)
+            .motsu_expect("should sweep proceeds");
+
+        assert_eq!(GOAL, treasury.balance());
+        assert!(contract.address().balance().is_zero());
+        contract.assert_emitted(&ProceedsSwept {
+            id: ID,
+            treasury,
+            amount: GOAL,
+        });
+
+        let err = contract
+            .sender(alice)
+            .sweep_proceeds(ID)
+            .motsu_expect_err("should not sweep proceeds twice");
+
+        assert!(matches!(
+            err,
+            Error::ProceedsUnavailable(ERC6909ProceedsUnavailable { id })
+                if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn sweep_proceeds_reverts_when_goal_not_reached(
+        contract: Contract<Erc6909Sale>,
+        alice: Address,
+        bob: Address,
+        treasury: Address,
+    ) {
+        open_refundable_sale(&contract, alice, treasury);
+        buy(&contract, bob, AMOUNT);
+        close_sale(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .sweep_proceeds(ID)
+            .motsu_expect_err("should not sweep when the goal is not reached");
+
+        assert!(matches!(
+            err,
+            Error::ProceedsUnavailable(ERC6909ProceedsUnavailable { id })
+                if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Sale as IErc6909Sale>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x105ea5ca");
+        assert_eq!(actual, expected);
+
+        let actual = <Erc6909Sale as IErc6909RefundableSale>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x786f8184");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<Erc6909Sale>, alice: Address) {
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Sale as IErc6909Sale>::interface_id()));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Sale as IErc6909RefundableSale>::interface_id()
+        ));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Sale as IErc165>::interface_id()));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Sale as IErc6909>::interface_id()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,916 @@
+//! Extension of ERC-6909 that lets holders schedule transfers, e.g. to pay
+//! salaries at a given date.
+//!
+//! Scheduling a transfer escrows its tokens in the balance of the token
+//! contract itself. Anyone can execute the transfer once its execution time
+//! is reached, which releases the tokens to the receiver. As long as it hasn't
+//! been executed, the sender can cancel the transfer to get the tokens back.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    contract, evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap, StorageU256, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `sender` schedules the transfer of `amount` tokens of
+        /// type `id` to `receiver`, executable from `execute_after`.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        /// * `sender` - Account sending the tokens.
+        /// * `receiver` - Account receiving the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens escrowed.
+        /// * `execute_after` - Timestamp from which the transfer can be
+        ///   executed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TransferScheduled(
+            uint256 indexed transfer_id,
+            address indexed sender,
+            address indexed receiver,
+            uint256 id,
+            uint256 amount,
+            uint64 execute_after
+        );
+
+        /// Emitted when the scheduled transfer `transfer_id` is cancelled by
+        /// its sender.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ScheduledTransferCancelled(uint256 indexed transfer_id);
+
+        /// Emitted when the scheduled transfer `transfer_id` is executed by
+        /// `executor`.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        /// * `executor` - Account executing the transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ScheduledTransferExecuted(
+            uint256 indexed transfer_id,
+            address indexed executor
+        );
+    }
+
+    sol! {
+        /// Indicates that the scheduled transfer `transfer_id` doesn't exist,
+        /// or has been cancelled or executed.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentScheduledTransfer(uint256 transfer_id);
+
+        /// Indicates that a transfer can't be scheduled for zero tokens.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909EmptyScheduledTransfer();
+
+        /// Indicates that `account` is not the sender of the scheduled
+        /// transfer `transfer_id`.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        /// * `account` - Account trying to cancel the transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909UnauthorizedScheduledTransferCancellation(
+            uint256 transfer_id,
+            address account
+        );
+
+        /// Indicates that the scheduled transfer `transfer_id` can't be
+        /// executed before `execute_after`.
+        ///
+        /// * `transfer_id` - Id of the scheduled transfer.
+        /// * `execute_after` - Timestamp from which the transfer can be
+        ///   executed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ScheduledTransferNotReady(
+            uint256 transfer_id,
+            uint64 execute_after
+        );
+    }
+}
+
+/// An [`Erc6909ScheduledTransfers`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a scheduled transfer doesn't exist.
+    NonexistentScheduledTransfer(ERC6909NonexistentScheduledTransfer),
+    /// Indicates that a transfer can't be scheduled for zero tokens.
+    EmptyScheduledTransfer(ERC6909EmptyScheduledTransfer),
+    /// Indicates that an account is not the sender of a scheduled transfer.
+    UnauthorizedScheduledTransferCancellation(
+        ERC6909UnauthorizedScheduledTransferCancellation,
+    ),
+    /// Indicates that a scheduled transfer can't be executed yet.
+    ScheduledTransferNotReady(ERC6909ScheduledTransferNotReady),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Struct for a transfer scheduled in an [`Erc6909ScheduledTransfers`]
+/// contract.
+#[storage]
+pub struct ScheduledTransfer {
+    /// Account sending the tokens.
+    sender: StorageAddress,
+    /// Account receiving the tokens.
+    receiver: StorageAddress,
+    /// Token id as a number.
+    id: StorageU256,
+    /// Amount of tokens escrowed.
+    amount: StorageU256,
+    /// Timestamp from which the transfer can be executed.
+    execute_after: StorageU64,
+}
+
+/// State of an [`Erc6909ScheduledTransfers`] contract.
+#[storage]
+pub struct Erc6909ScheduledTransfers {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from scheduled transfer id to scheduled transfer.
+    pub(crate) scheduled_transfers: StorageMap<U256, ScheduledTransfer>,
+    /// Id of the next transfer to be scheduled.
+    pub(crate) next_transfer_id: StorageU256,
+}
+
+impl_as_erc6909!(Erc6909ScheduledTransfers, erc6909);
+
+/// Required interface of an [`Erc6909ScheduledTransfers`] contract.
+#[interface_id]
+pub trait IErc6909ScheduledTransfers: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the sender, receiver, token id, amount of tokens and execution
+    /// time of the scheduled transfer `transfer_id`.
+    ///
+    /// Returns [`Address::ZERO`] as the sender if the transfer doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `transfer_id` - Id of the scheduled transfer.
+    fn scheduled_transfer(
+        &self,
+        transfer_id: U256,
+    ) -> (Address, Address, U256, U256, U64);
+
+    /// Escrows `amount` tokens of type `id` owned by the caller, to be
+    /// transferred to `receiver` from `execute_after`, and returns the id of
+    /// the scheduled transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `execute_after` - Timestamp from which the transfer can be executed.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyScheduledTransfer`] - If `amount` is zero.
+    /// * [`Error::InvalidReceiver`] - If `receiver` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If the caller owns fewer than
+    ///   `amount` tokens of type `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`TransferScheduled`].
+    fn schedule_transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        execute_after: U64,
+    ) -> Result<U256, Self::Error>;
+
+    /// Cancels the scheduled transfer `transfer_id`, giving its escrowed
+    /// tokens back to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `transfer_id` - Id of the scheduled transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentScheduledTransfer`] - If the transfer doesn't
+    ///   exist.
+    /// * [`Error::UnauthorizedScheduledTransferCancellation`] - If the caller
+    ///   is not the sender of the transfer.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`ScheduledTransferCancelled`].
+    fn cancel_scheduled_transfer(
+        &mut self,
+        transfer_id: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Executes the scheduled transfer `transfer_id`, releasing its escrowed
+    /// tokens to its receiver.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `transfer_id` - Id of the scheduled transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentScheduledTransfer`] - If the transfer doesn't
+    ///   exist.
+    /// * [`Error::ScheduledTransferNotReady`] - If the execution time of the
+    ///   transfer is not reached.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`ScheduledTransferExecuted`].
+    fn execute_scheduled_transfer(
+        &mut self,
+        transfer_id: U256,
+    ) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909ScheduledTransfers<Error = Error>,
+    IErc165
+)]
+impl Erc6909ScheduledTransfers {}
+
+#[public]
+impl IErc6909ScheduledTransfers for Erc6909ScheduledTransfers {
+    type Error = Error;
+
+    fn scheduled_transfer(
+        &self,
+        transfer_id: U256,
+    ) -> (Address, Address, U256, U256, U64) {
+        let scheduled = self.scheduled_transfers.get(transfer_id);
+        (
+            scheduled.sender.get(),
+            scheduled.receiver.get(),
+            scheduled.id.get(),
+            scheduled.amount.get(),
+            scheduled.execute_after.get(),
+        )
+    }
+
+    fn schedule_transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        execute_after: U64,
+    ) -> Result<U256, Self::Error> {
+        if amount.is_zero() {
+            return Err(Error::EmptyScheduledTransfer(
+                ERC6909EmptyScheduledTransfer {},
+            ));
+        }
+        if receiver.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver,
+            }));
+        }
+
+        let sender = msg::sender();
+        self.erc6909._update_single(sender, contract::address(), id, amount)?;
+
+        let transfer_id = self.next_transfer_id.get();
+        // Overflow not possible: one transfer is scheduled per call.
+        self.next_transfer_id.set(transfer_id + U256::ONE);
+
+        let mut scheduled = self.scheduled_transfers.setter(transfer_id);
+        scheduled.sender.set(sender);
+        scheduled.receiver.set(receiver);
+        scheduled.id.set(id);
+        scheduled.amount.set(amount);
+        scheduled.execute_after.set(execute_after);
+
+        evm::log(TransferScheduled {
+            transfer_id,
+            sender,
+            receiver,
+            id,
+            amount,
+            execute_after: execute_after.to::<u64>(),
+        });
+
+        Ok(transfer_id)
+    }
+
+    fn cancel_scheduled_transfer(
+        &mut self,
+        transfer_id: U256,
+    ) -> Result<(), Self::Error> {
+        let (sender, _, id, amount, _) =
+            self._require_scheduled_transfer(transfer_id)?;
+
+        let account = msg::sender();
+        if account != sender {
+            return Err(Error::UnauthorizedScheduledTransferCancellation(
+                ERC6909UnauthorizedScheduledTransferCancellation {
+                    transfer_id,
+                    account,
+                },
+            ));
+        }
+
+        self._remove_scheduled_transfer(transfer_id);
+        self.erc6909._update_single(contract::address(), sender, id, amount)?;
+
+        evm::log(ScheduledTransferCancelled { transfer_id });
+
+        Ok(())
+    }
+
+    fn execute_scheduled_transfer(
+        &mut self,
+        transfer_id: U256,
+    ) -> Result<(), Self::Error> {
+        let (_, receiver, id, amount, execute_after) =
+            self._require_scheduled_transfer(transfer_id)?;
+
+        if U64::from(block::timestamp()) < execute_after {
+            return Err(Error::ScheduledTransferNotReady(
+                ERC6909ScheduledTransferNotReady {
+                    transfer_id,
+                    execute_after: execute_after.to::<u64>(),
+                },
+            ));
+        }
+
+        self._remove_scheduled_transfer(transfer_id);
+        self.erc6909._update_single(
+            contract::address(),
+            receiver,
+            id,
+            amount,
+        )?;
+
+        evm::log(ScheduledTransferExecuted {
+            transfer_id,
+            executor: msg::sender(),
+        });
+
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909ScheduledTransfers {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909ScheduledTransfers {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909ScheduledTransfers>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909ScheduledTransfers {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909ScheduledTransfers {
+    /// Returns the scheduled transfer `transfer_id`, reverting if it doesn't
+    /// exist.
+    fn _require_scheduled_transfer(
+        &self,
+        transfer_id: U256,
+    ) -> Result<(Address, Address, U256, U256, U64), Error> {
+        let scheduled = self.scheduled_transfer(transfer_id);
+        if scheduled.0.is_zero() {
+            return Err(Error::NonexistentScheduledTransfer(
+                ERC6909NonexistentScheduledTransfer { transfer_id },
+            ));
+        }
+        Ok(scheduled)
+    }
+
+    /// Removes the scheduled transfer `transfer_id`.
+    fn _remove_scheduled_transfer(&mut self, transfer_id: U256) {
+        let mut scheduled = self.scheduled_transfers.setter(transfer_id);
+        scheduled.sender.erase();
+        scheduled.receiver.erase();
+        scheduled.id.erase();
+        scheduled.amount.erase();
+        scheduled.execute_after.erase();
+    }
+}
+
+impl IErc6909Internal for Erc6909ScheduledTransfers {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256, U64};
+    use motsu::prelude::*;
+    use stylus_sdk::block;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909ScheduledTransfers {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    fn now() -> U64 {
+        U64::from(block::timestamp())
+    }
+
+    fn init(contract: &Contract<Erc6909ScheduledTransfers>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+    }
+
+    #[motsu::test]
+    fn schedule_transfer_escrows_tokens(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+        let execute_after = now() + U64::ONE;
+
+        let transfer_id = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT, execute_after)
+            .motsu_expect("should schedule transfer");
+
+        assert_eq!(U256::ZERO, transfer_id);
+        assert_eq!(
+            (alice, bob, ID, AMOUNT, execute_after),
+            contract.sender(alice).scheduled_transfer(transfer_id)
+        );
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).balance_of(contract.address(), ID)
+        );
+        contract.assert_emitted(&TransferScheduled {
+            transfer_id,
+            sender: alice,
+            receiver: bob,
+            id: ID,
+            amount: AMOUNT,
+            execute_after: execute_after.to::<u64>(),
+        });
+    }
+
+    #[motsu::test]
+    fn schedule_transfer_reverts_when_empty(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, U256::ZERO, now())
+            .motsu_expect_err("should not schedule an empty transfer");
+
+        assert!(matches!(err, Error::EmptyScheduledTransfer(_)));
+    }
+
+    #[motsu::test]
+    fn schedule_transfer_reverts_on_zero_receiver(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+    ) {
+        init(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .schedule_transfer(Address::ZERO, ID, AMOUNT, now())
+            .motsu_expect_err("should not schedule a transfer to zero");
+
+        assert!(matches!(err, Error::InvalidReceiver(_)));
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn schedule_transfer_reverts_on_insufficient_balance(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let err = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT + U256::ONE, now())
+            .motsu_expect_err("should not escrow more than balance");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[motsu::test]
+    fn anyone_executes_due_transfer(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice);
+        let transfer_id = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT, now())
+            .motsu_expect("should schedule transfer");
+
+        contract
+            .sender(charlie)
+            .execute_scheduled_transfer(transfer_id)
+            .motsu_expect("should execute due transfer");
+
+        assert_eq!(AMOUNT, contract.sender(charlie).balance_of(bob, ID));
+        assert!(contract
+            .sender(charlie)
+            .balance_of(contract.address(), ID)
+            .is_zero());
+        assert!(contract
+            .sender(charlie)
+            .scheduled_transfer(transfer_id)
+            .0
+            .is_zero());
+        contract.assert_emitted(&ScheduledTransferExecuted {
+            transfer_id,
+            executor: charlie,
+        });
+
+        let err = contract
+            .sender(charlie)
+            .execute_scheduled_transfer(transfer_id)
+            .motsu_expect_err("should not execute a transfer twice");
+        assert!(matches!(
+            err,
+            Error::NonexistentScheduledTransfer(
+                ERC6909NonexistentScheduledTransfer { transfer_id: id }
+            ) if id == transfer_id
+        ));
+    }
+
+    #[motsu::test]
+    fn execute_reverts_before_execution_time(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+        let execute_after = now() + U64::ONE;
+        let transfer_id = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT, execute_after)
+            .motsu_expect("should schedule transfer");
+
+        let err = contract
+            .sender(bob)
+            .execute_scheduled_transfer(transfer_id)
+            .motsu_expect_err("should not execute before execution time");
+
+        assert!(matches!(
+            err,
+            Error::ScheduledTransferNotReady(
+                ERC6909ScheduledTransferNotReady {
+                    transfer_id: id,
+                    execute_after: at,
+                }
+            ) if id == transfer_id && U64::from(at) == execute_after
+        ));
+        assert!(contract.sender(bob).balance_of(bob, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn sender_cancels_scheduled_transfer(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+        let transfer_id = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT, now())
+            .motsu_expect("should schedule transfer");
+
+        contract
+            .sender(alice)
+            .cancel_scheduled_transfer(transfer_id)
+            .motsu_expect("should cancel scheduled transfer");
+
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+        assert!(contract
+            .sender(alice)
+            .balance_of(contract.address(), ID)
+            .is_zero());
+        contract.assert_emitted(&ScheduledTransferCancelled { transfer_id });
+
+        let err = contract
+            .sender(bob)
+            .execute_scheduled_transfer(transfer_id)
+            .motsu_expect_err("should not execute a cancelled transfer");
+        assert!(matches!(err, Error::NonexistentScheduledTransfer(_)));
+    }
+
+    #[motsu::test]
+    fn cancel_reverts_when_not_sender(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+        let transfer_id = contract
+            .sender(alice)
+            .schedule_transfer(bob, ID, AMOUNT, now())
+            .motsu_expect("should schedule transfer");
+
+        let err = contract
+            .sender(bob)
+            .cancel_scheduled_transfer(transfer_id)
+            .motsu_expect_err("should only be cancelled by its sender");
+
+        assert!(matches!(
+            err,
+            Error::UnauthorizedScheduledTransferCancellation(
+                ERC6909UnauthorizedScheduledTransferCancellation {
+                    transfer_id: id,
+                    account,
+                }
+            ) if id == transfer_id && account == bob
+        ));
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).balance_of(contract.address(), ID)
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909ScheduledTransfers as IErc6909ScheduledTransfers>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x339e6aef");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909ScheduledTransfers>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ScheduledTransfers as IErc6909ScheduledTransfers>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909ScheduledTransfers as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,880 @@
+//! Extension of ERC-6909 that lets holders grant bounded operator rights to
+//! session keys, e.g. so that games can move small amounts of tokens without
+//! prompting their players for every move.
+//!
+//! A session lets an operator transfer up to a maximum cumulative amount of a
+//! token id on behalf of its owner through [`IErc6909::transfer_from`], until
+//! the session's expiry. Unlike an allowance, the spent amount is tracked
+//! against the cap, so that granting a new session is the only way to reset
+//! it. Once a session expires, its operator falls back to its regular
+//! allowance, if any.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `owner` grants `operator` a session over its `id`
+        /// tokens.
+        ///
+        /// * `owner` - Account of the token's owner.
+        /// * `operator` - Account allowed to move the tokens.
+        /// * `id` - Token id as a number.
+        /// * `max_amount` - Maximum cumulative amount of tokens `operator` can
+        ///   move.
+        /// * `expiry` - Timestamp after which the session expires.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event SessionGranted(
+            address indexed owner,
+            address indexed operator,
+            uint256 indexed id,
+            uint256 max_amount,
+            uint64 expiry
+        );
+
+        /// Emitted when `owner` revokes the session of `operator` over its
+        /// `id` tokens.
+        ///
+        /// * `owner` - Account of the token's owner.
+        /// * `operator` - Account that was allowed to move the tokens.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event SessionRevoked(
+            address indexed owner,
+            address indexed operator,
+            uint256 indexed id
+        );
+    }
+
+    sol! {
+        /// Indicates that a session can't be granted with an `expiry` in the
+        /// past.
+        ///
+        /// * `expiry` - Timestamp after which the session expires.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidSessionExpiry(uint64 expiry);
+
+        /// Indicates that `operator` can't move `needed` tokens of type `id`
+        /// within its session, as only `remaining` tokens are left.
+        ///
+        /// * `operator` - Account moving the tokens.
+        /// * `id` - Token id as a number.
+        /// * `remaining` - Amount of tokens left in the session.
+        /// * `needed` - Amount of tokens moved.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909SessionLimitExceeded(
+            address operator,
+            uint256 id,
+            uint256 remaining,
+            uint256 needed
+        );
+    }
+}
+
+/// An [`Erc6909SessionOperators`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a session can't be granted with an expiry in the past.
+    InvalidSessionExpiry(ERC6909InvalidSessionExpiry),
+    /// Indicates that a transfer exceeds the amount left in a session.
+    SessionLimitExceeded(ERC6909SessionLimitExceeded),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Struct for a session granted in an [`Erc6909SessionOperators`] contract.
+#[storage]
+pub struct Session {
+    /// Maximum cumulative amount of tokens the operator can move.
+    max_amount: StorageU256,
+    /// Amount of tokens the operator already moved.
+    spent: StorageU256,
+    /// Timestamp after which the session expires.
+    expiry: StorageU64,
+}
+
+/// State of an [`Erc6909SessionOperators`] contract.
+#[storage]
+pub struct Erc6909SessionOperators {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from owner to operator to token id to session.
+    pub(crate) sessions:
+        StorageMap<Address, StorageMap<Address, StorageMap<U256, Session>>>,
+}
+
+impl_as_erc6909!(Erc6909SessionOperators, erc6909);
+
+/// Required interface of an [`Erc6909SessionOperators`] contract.
+#[interface_id]
+pub trait IErc6909SessionOperators: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the maximum cumulative amount, the amount already moved and
+    /// the expiry of the session of `operator` over the `owner`'s `id`
+    /// tokens.
+    ///
+    /// Returns zeros if no session was granted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `operator` - Account allowed to move the tokens.
+    /// * `id` - Token id as a number.
+    fn session(
+        &self,
+        owner: Address,
+        operator: Address,
+        id: U256,
+    ) -> (U256, U256, U64);
+
+    /// Grants `operator` a session allowing it to move up to `max_amount`
+    /// tokens of type `id` owned by the caller, until `expiry`.
+    ///
+    /// Replaces any previous session of `operator` over these tokens, and
+    /// resets the amount it moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account allowed to move the tokens.
+    /// * `id` - Token id as a number.
+    /// * `max_amount` - Maximum cumulative amount of tokens `operator` can
+    ///   move.
+    /// * `expiry` - Timestamp after which the session expires.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSpenderForId`] - If `operator` is [`Address::ZERO`].
+    /// * [`Error::InvalidSessionExpiry`] - If `expiry` is in the past.
+    ///
+    /// # Events
+    ///
+    /// * [`SessionGranted`].
+    fn grant_session(
+        &mut self,
+        operator: Address,
+        id: U256,
+        max_amount: U256,
+        expiry: U64,
+    ) -> Result<(), Self::Error>;
+
+    /// Revokes the session of `operator` over the caller's `id` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account that was allowed to move the tokens.
+    /// * `id` - Token id as a number.
+    ///
+    /// # Events
+    ///
+    /// * [`SessionRevoked`].
+    fn revoke_session(&mut self, operator: Address, id: U256);
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909SessionOperators<Error = Error>,
+    IErc165
+)]
+impl Erc6909SessionOperators {}
+
+#[public]
+impl IErc6909SessionOperators for Erc6909SessionOperators {
+    type Error = Error;
+
+    fn session(
+        &self,
+        owner: Address,
+        operator: Address,
+        id: U256,
+    ) -> (U256, U256, U64) {
+        let operators = self.sessions.get(owner);
+        let ids = operators.get(operator);
+        let session = ids.get(id);
+        (session.max_amount.get(), session.spent.get(), session.expiry.get())
+    }
+
+    fn grant_session(
+        &mut self,
+        operator: Address,
+        id: U256,
+        max_amount: U256,
+        expiry: U64,
+    ) -> Result<(), Self::Error> {
+        if operator.is_zero() {
+            return Err(Error::InvalidSpenderForId(
+                ERC6909InvalidSpenderForId { spender: operator, id },
+            ));
+        }
+        if expiry < U64::from(block::timestamp()) {
+            return Err(Error::InvalidSessionExpiry(
+                ERC6909InvalidSessionExpiry { expiry: expiry.to::<u64>() },
+            ));
+        }
+
+        let owner = msg::sender();
+        let mut operators = self.sessions.setter(owner);
+        let mut ids = operators.setter(operator);
+        let mut session = ids.setter(id);
+        session.max_amount.set(max_amount);
+        session.spent.set(U256::ZERO);
+        session.expiry.set(expiry);
+
+        evm::log(SessionGranted {
+            owner,
+            operator,
+            id,
+            max_amount,
+            expiry: expiry.to::<u64>(),
+        });
+
+        Ok(())
+    }
+
+    fn revoke_session(&mut self, operator: Address, id: U256) {
+        let owner = msg::sender();
+        let mut operators = self.sessions.setter(owner);
+        let mut ids = operators.setter(operator);
+        let mut session = ids.setter(id);
+        session.max_amount.erase();
+        session.spent.erase();
+        session.expiry.erase();
+
+        evm::log(SessionRevoked { owner, operator, id });
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909SessionOperators {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        if sender.is_zero()
+            || self.erc6909._bypasses_allowance(sender, caller)
+            || !self._is_session_active(sender, caller, id)
+        {
+            return Ok(self
+                .erc6909
+                .transfer_from(sender, receiver, id, amount)?);
+        }
+
+        self._spend_session(sender, caller, id, amount)?;
+        self.erc6909._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909SessionOperators {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909SessionOperators>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909SessionOperators {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint_batch(to, ids, amounts)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn_batch(from, ids, amounts)?)
+    }
+}
+
+impl Erc6909SessionOperators {
+    /// Returns whether `operator` has an unexpired session over the `owner`'s
+    /// `id` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `operator` - Account moving the tokens.
+    /// * `id` - Token id as a number.
+    fn _is_session_active(
+        &self,
+        owner: Address,
+        operator: Address,
+        id: U256,
+    ) -> bool {
+        let (_, _, expiry) = self.session(owner, operator, id);
+        U64::from(block::timestamp()) <= expiry
+    }
+
+    /// Adds `amount` to the tokens moved by `operator` within its session
+    /// over the `owner`'s `id` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `operator` - Account moving the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SessionLimitExceeded`] - If `amount` exceeds the tokens left
+    ///   in the session.
+    fn _spend_session(
+        &mut self,
+        owner: Address,
+        operator: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let mut operators = self.sessions.setter(owner);
+        let mut ids = operators.setter(operator);
+        let mut session = ids.setter(id);
+
+        let spent = session.spent.get();
+        // Underflow not possible: `spent` never exceeds `max_amount`.
+        let remaining = session.max_amount.get() - spent;
+        if amount > remaining {
+            return Err(Error::SessionLimitExceeded(
+                ERC6909SessionLimitExceeded {
+                    operator,
+                    id,
+                    remaining,
+                    needed: amount,
+                },
+            ));
+        }
+
+        // Overflow not possible: the sum is at most `max_amount`.
+        session.spent.set(spent + amount);
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909SessionOperators {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256, U64};
+    use motsu::prelude::*;
+    use stylus_sdk::block;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909SessionOperators {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+    const MAX_AMOUNT: U256 = uint!(10_U256);
+
+    fn now() -> U64 {
+        U64::from(block::timestamp())
+    }
+
+    fn init(
+        contract: &Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            .grant_session(bob, ID, MAX_AMOUNT, now() + U64::ONE)
+            .motsu_expect("should grant Bob a session");
+    }
+
+    #[motsu::test]
+    fn grant_session_sets_session(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let expiry = now() + U64::ONE;
+
+        contract
+            .sender(alice)
+            .grant_session(bob, ID, MAX_AMOUNT, expiry)
+            .motsu_expect("should grant Bob a session");
+
+        assert_eq!(
+            (MAX_AMOUNT, U256::ZERO, expiry),
+            contract.sender(alice).session(alice, bob, ID)
+        );
+        contract.assert_emitted(&SessionGranted {
+            owner: alice,
+            operator: bob,
+            id: ID,
+            max_amount: MAX_AMOUNT,
+            expiry: expiry.to::<u64>(),
+        });
+    }
+
+    #[motsu::test]
+    fn grant_session_reverts_when_operator_is_zero_address(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .grant_session(Address::ZERO, ID, MAX_AMOUNT, now())
+            .motsu_expect_err("should not grant the zero address a session");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSpenderForId(ERC6909InvalidSpenderForId {
+                spender,
+                id
+            }) if spender.is_zero() && id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn grant_session_reverts_when_expired(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let expiry = now() - U64::ONE;
+
+        let err = contract
+            .sender(alice)
+            .grant_session(bob, ID, MAX_AMOUNT, expiry)
+            .motsu_expect_err("should not grant an expired session");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSessionExpiry(ERC6909InvalidSessionExpiry {
+                expiry: e
+            }) if e == expiry.to::<u64>()
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_from_spends_session(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, alice, bob);
+        let amount = uint!(4_U256);
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, ID, amount)
+            .motsu_expect("should transfer within the session");
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, ID, amount)
+            .motsu_expect("should transfer within the session again");
+
+        let (_, spent, _) = contract.sender(alice).session(alice, bob, ID);
+        assert_eq!(amount * uint!(2_U256), spent);
+        assert_eq!(
+            amount * uint!(2_U256),
+            contract.sender(alice).balance_of(charlie, ID)
+        );
+        assert!(contract.sender(alice).allowance(alice, bob, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_session_limit_exceeded(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        let amount = uint!(6_U256);
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, amount)
+            .motsu_expect("should transfer within the session");
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, amount)
+            .motsu_expect_err("should not exceed the session");
+
+        assert!(matches!(
+            err,
+            Error::SessionLimitExceeded(ERC6909SessionLimitExceeded {
+                operator,
+                id,
+                remaining,
+                needed,
+            }) if operator == bob
+                && id == ID
+                && remaining == MAX_AMOUNT - amount
+                && needed == amount
+        ));
+        assert_eq!(amount, contract.sender(alice).balance_of(bob, ID));
+    }
+
+    #[motsu::test]
+    fn session_is_limited_to_its_id(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        let other_id = uint!(2_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, other_id, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, other_id, U256::ONE)
+            .motsu_expect_err("should not transfer another id");
+
+        assert!(matches!(err, Error::InsufficientAllowance(_)));
+    }
+
+    #[motsu::test]
+    fn expired_session_falls_back_to_allowance(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        contract.init(alice, |contract| {
+            contract
+                .sessions
+                .setter(alice)
+                .setter(bob)
+                .setter(ID)
+                .expiry
+                .set(now() - U64::ONE);
+        });
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, U256::ONE)
+            .motsu_expect_err("should not transfer with an expired session");
+        assert!(matches!(err, Error::InsufficientAllowance(_)));
+
+        contract
+            .sender(alice)
+            .approve(bob, ID, U256::ONE)
+            .motsu_expect("should approve Bob");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, U256::ONE)
+            .motsu_expect("should transfer with the allowance");
+
+        assert!(contract.sender(alice).allowance(alice, bob, ID).is_zero());
+        let (_, spent, _) = contract.sender(alice).session(alice, bob, ID);
+        assert!(spent.is_zero());
+    }
+
+    #[motsu::test]
+    fn revoke_session_clears_session(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+
+        contract.sender(alice).revoke_session(bob, ID);
+
+        assert_eq!(
+            (U256::ZERO, U256::ZERO, U64::ZERO),
+            contract.sender(alice).session(alice, bob, ID)
+        );
+        contract.assert_emitted(&SessionRevoked {
+            owner: alice,
+            operator: bob,
+            id: ID,
+        });
+    }
+
+    #[motsu::test]
+    fn grant_session_resets_spent_amount(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, MAX_AMOUNT)
+            .motsu_expect("should exhaust the session");
+
+        contract
+            .sender(alice)
+            .grant_session(bob, ID, MAX_AMOUNT, now() + U64::ONE)
+            .motsu_expect("should grant Bob a new session");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, MAX_AMOUNT)
+            .motsu_expect("should transfer within the new session");
+
+        assert_eq!(
+            MAX_AMOUNT * uint!(2_U256),
+            contract.sender(alice).balance_of(bob, ID)
+        );
+    }
+
+    #[motsu::test]
+    fn operator_transfers_do_not_spend_session(
+        contract: Contract<Erc6909SessionOperators>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice, bob);
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set Bob as operator");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, AMOUNT)
+            .motsu_expect("should transfer as operator");
+
+        let (_, spent, _) = contract.sender(alice).session(alice, bob, ID);
+        assert!(spent.is_zero());
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909SessionOperators as IErc6909SessionOperators>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0xadba9167");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,562 @@
+//! Extension of ERC-6909 that allows specific token ids to be flagged as
+//! soulbound.
+//!
+//! Tokens of a soulbound id can be minted and burned, but never transferred
+//! between accounts, which makes them suitable for credentials, tickets or
+//! achievements bound to their holder.
+//!
+//! Note that [`Erc6909Soulbound::_set_soulbound`] is not exposed by default.
+//! You should expose it manually in your contract's abi, guarded by proper
+//! access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageBool, StorageMap},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when token `id` is flagged or unflagged as soulbound.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `soulbound` - Whether tokens of `id` are non-transferable.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event SoulboundSet(uint256 indexed id, bool soulbound);
+    }
+
+    sol! {
+        /// Indicates that tokens of type `id` can't be transferred, because
+        /// the id is soulbound.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonTransferable(uint256 id);
+    }
+}
+
+/// An [`Erc6909Soulbound`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a soulbound token id was about to be transferred.
+    NonTransferable(ERC6909NonTransferable),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Soulbound`] contract.
+#[storage]
+pub struct Erc6909Soulbound {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to whether it is soulbound.
+    pub(crate) soulbound: StorageMap<U256, StorageBool>,
+}
+
+impl_as_erc6909!(Erc6909Soulbound, erc6909);
+
+/// Required interface of an [`Erc6909Soulbound`] contract.
+#[interface_id]
+pub trait IErc6909Soulbound: IErc165 {
+    /// Returns whether tokens of type `id` are soulbound, i.e. can't be
+    /// transferred between accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn is_soulbound(&self, id: U256) -> bool;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909Soulbound, IErc165)]
+impl Erc6909Soulbound {}
+
+#[public]
+impl IErc6909Soulbound for Erc6909Soulbound {
+    fn is_soulbound(&self, id: U256) -> bool {
+        self.soulbound.get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909Soulbound {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Soulbound {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Soulbound>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909Soulbound {
+    /// Flags or unflags token `id` as soulbound.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `soulbound` - Whether tokens of `id` should be non-transferable.
+    ///
+    /// # Events
+    ///
+    /// * [`SoulboundSet`].
+    pub fn _set_soulbound(&mut self, id: U256, soulbound: bool) {
+        self.soulbound.setter(id).set(soulbound);
+        evm::log(SoulboundSet { id, soulbound });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909Soulbound {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that prevents soulbound
+    /// token ids from being transferred. Mints and burns are not
+    /// restricted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonTransferable`] - If tokens of a soulbound id are moved
+    ///   between two non-zero accounts.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if !from.is_zero() && !to.is_zero() {
+            if let Some(&id) = ids.iter().find(|&&id| self.soulbound.get(id)) {
+                return Err(Error::NonTransferable(ERC6909NonTransferable {
+                    id,
+                }));
+            }
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+}
+
+impl IErc6909Internal for Erc6909Soulbound {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Soulbound {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[motsu::test]
+    fn set_soulbound_emits_event(
+        contract: Contract<Erc6909Soulbound>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_soulbound(ID, true);
+
+        assert!(contract.sender(alice).is_soulbound(ID));
+        contract.assert_emitted(&SoulboundSet { id: ID, soulbound: true });
+    }
+
+    #[motsu::test]
+    fn transfer_reverts_when_soulbound(
+        contract: Contract<Erc6909Soulbound>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_soulbound(ID, true);
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint soulbound tokens");
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect_err("should not transfer soulbound tokens");
+
+        assert!(matches!(
+            err,
+            Error::NonTransferable(ERC6909NonTransferable { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_soulbound(
+        contract: Contract<Erc6909Soulbound>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_soulbound(ID, true);
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint soulbound tokens");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set Bob as operator");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, AMOUNT)
+            .motsu_expect_err("should not transfer soulbound tokens");
+
+        assert!(matches!(err, Error::NonTransferable(_)));
+    }
+
+    #[motsu::test]
+    fn burn_succeeds_when_soulbound(
+        contract: Contract<Erc6909Soulbound>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_soulbound(ID, true);
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint soulbound tokens");
+
+        contract
+            .sender(alice)
+            ._burn(alice, ID, AMOUNT)
+            .motsu_expect("should burn soulbound tokens");
+
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn transfer_succeeds_when_unflagged(
+        contract: Contract<Erc6909Soulbound>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_soulbound(ID, true);
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint soulbound tokens");
+        contract.sender(alice)._set_soulbound(ID, false);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer tokens");
+
+        assert_eq!(contract.sender(alice).balance_of(bob, ID), AMOUNT);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Soulbound as IErc6909Soulbound>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x911ec470");
+        assert_eq!(actual, expected);
+    }
+}
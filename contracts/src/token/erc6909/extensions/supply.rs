@@ -1,23 +1,126 @@
 //! Extension of ERC-6909 that adds tracking of total supply per token id.
+//!
+//! It also tracks which token ids were ever minted, exposed through
+//! [`IErc6909Exists::exists`], so that other extensions can tell unknown token
+//! ids apart, e.g. with
+//! [`crate::token::erc6909::extensions::Erc6909Metadata`]'s checked getters.
 
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, FixedBytes, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
 use stylus_sdk::{
+    call::MethodError,
     msg,
     prelude::*,
-    storage::{StorageMap, StorageU256},
+    storage::{StorageBool, StorageMap, StorageU256},
 };
 
 use crate::{
-    token::erc6909::{self, Erc6909, Error, IErc6909},
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
     utils::{
         introspection::erc165::IErc165,
         math::storage::{AddAssignChecked, SubAssignUnchecked},
     },
 };
 
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that `needed` tokens of type `id` would be burnt, more
+        /// than its recorded total `supply`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `supply` - Recorded total supply of `id`.
+        /// * `needed` - Amount of tokens being burnt.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InsufficientSupply(
+            uint256 id,
+            uint256 supply,
+            uint256 needed
+        );
+    }
+}
+
+/// An [`Erc6909Supply`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that more tokens of an id would be burnt than its recorded
+    /// total supply.
+    InsufficientSupply(ERC6909InsufficientSupply),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
 /// State of an [`Erc6909Supply`] contract.
 #[storage]
 pub struct Erc6909Supply {
@@ -25,10 +128,14 @@ pub struct Erc6909Supply {
     pub erc6909: Erc6909,
     /// Mapping from token id to token total_supply.
     pub(crate) total_supply: StorageMap<U256, StorageU256>,
+    /// Mapping from token id to whether it was ever minted.
+    pub(crate) minted: StorageMap<U256, StorageBool>,
 }
 
+impl_as_erc6909!(Erc6909Supply, erc6909);
+
 #[public]
-#[implements(IErc6909<Error = Error>, IErc6909Supply, IErc165)]
+#[implements(IErc6909<Error = Error>, IErc6909Supply, IErc6909Exists, IErc165)]
 impl Erc6909Supply {}
 
 /// Required interface of a [`Erc6909Supply`] contract.
@@ -43,10 +150,27 @@ pub trait IErc6909Supply: IErc165 {
     fn total_supply(&self, id: U256) -> U256;
 }
 
+/// Interface for contracts telling whether a token id exists.
+///
+/// Kept apart from [`IErc6909Supply`], whose interface id is defined by the
+/// ERC-6909 standard.
+#[interface_id]
+pub trait IErc6909Exists {
+    /// Returns whether token `id` exists, i.e. has a non-zero total supply or
+    /// was ever minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn exists(&self, id: U256) -> bool;
+}
+
 #[public]
 impl IErc165 for Erc6909Supply {
     fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
         <Self as IErc6909Supply>::interface_id() == interface_id
+            || <Self as IErc6909Exists>::interface_id() == interface_id
             || self.erc6909.supports_interface(interface_id)
             || <Self as IErc165>::interface_id() == interface_id
     }
@@ -59,9 +183,16 @@ impl IErc6909Supply for Erc6909Supply {
     }
 }
 
+#[public]
+impl IErc6909Exists for Erc6909Supply {
+    fn exists(&self, id: U256) -> bool {
+        self.minted.get(id) || !self.total_supply.get(id).is_zero()
+    }
+}
+
 #[public]
 impl IErc6909 for Erc6909Supply {
-    type Error = erc6909::Error;
+    type Error = Error;
 
     fn transfer(
         &mut self,
@@ -80,6 +211,10 @@ impl IErc6909 for Erc6909Supply {
         id: U256,
         amount: U256,
     ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
         self._transfer(sender, receiver, id, amount)
     }
 
@@ -89,7 +224,7 @@ impl IErc6909 for Erc6909Supply {
         id: U256,
         amount: U256,
     ) -> Result<bool, Self::Error> {
-        self.erc6909.approve(spender, id, amount)
+        Ok(self.erc6909.approve(spender, id, amount)?)
     }
 
     fn set_operator(
@@ -97,7 +232,7 @@ impl IErc6909 for Erc6909Supply {
         spender: Address,
         approved: bool,
     ) -> Result<bool, Self::Error> {
-        self.erc6909.set_operator(spender, approved)
+        Ok(self.erc6909.set_operator(spender, approved)?)
     }
 
     fn balance_of(&self, owner: Address, id: U256) -> U256 {
@@ -129,8 +264,13 @@ impl Erc6909Supply {
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), erc6909::Error> {
-        self._do_mint(to, vec![id], vec![amount])
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+        self._update_single(Address::ZERO, to, id, amount)
     }
 
     /// Batched version of [`Self::_mint`].
@@ -142,7 +282,7 @@ impl Erc6909Supply {
         to: Address,
         ids: Vec<U256>,
         values: Vec<U256>,
-    ) -> Result<(), erc6909::Error> {
+    ) -> Result<(), Error> {
         self._do_mint(to, ids, values)
     }
 
@@ -155,8 +295,13 @@ impl Erc6909Supply {
         from: Address,
         id: U256,
         amount: U256,
-    ) -> Result<(), erc6909::Error> {
-        self._do_burn(from, vec![id], vec![amount])
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+        self._update_single(from, Address::ZERO, id, amount)
     }
 
     /// Batched version of [`Self::_burn`].
@@ -168,7 +313,7 @@ impl Erc6909Supply {
         from: Address,
         ids: Vec<U256>,
         values: Vec<U256>,
-    ) -> Result<(), erc6909::Error> {
+    ) -> Result<(), Error> {
         self._do_burn(from, ids, values)
     }
 }
@@ -179,11 +324,11 @@ impl Erc6909Supply {
         to: Address,
         ids: Vec<U256>,
         amounts: Vec<U256>,
-    ) -> Result<(), erc6909::Error> {
+    ) -> Result<(), Error> {
         if to.is_zero() {
-            return Err(erc6909::Error::InvalidReceiver(
-                erc6909::ERC6909InvalidReceiver { receiver: to },
-            ));
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
         }
 
         self._update(Address::ZERO, to, ids, amounts)?;
@@ -196,11 +341,11 @@ impl Erc6909Supply {
         from: Address,
         ids: Vec<U256>,
         amounts: Vec<U256>,
-    ) -> Result<(), erc6909::Error> {
+    ) -> Result<(), Error> {
         if from.is_zero() {
-            return Err(erc6909::Error::InvalidSender(
-                erc6909::ERC6909InvalidSender { sender: from },
-            ));
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
         }
 
         self._update(from, Address::ZERO, ids, amounts)?;
@@ -220,10 +365,13 @@ impl Erc6909Supply {
     ///
     /// # Errors
     ///
-    /// * [`erc6909::Error::InvalidArrayLength`] - If length of `ids` is not
-    ///   equal to length of `amounts`.
-    /// * [`erc6909::Error::InsufficientBalance`] - If `amount` is greater than
-    ///   the balance of the `from` account.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    /// * [`Error::InsufficientSupply`] - If `amount` is greater than the
+    ///   recorded total supply of `id`, which means balances and supply
+    ///   diverged, e.g. through a hook minting without updating the supply.
     ///
     /// # Events
     ///
@@ -235,28 +383,84 @@ impl Erc6909Supply {
     ///
     /// * If updated balance and/or supply exceeds [`U256::MAX`], may happen
     ///   during the `mint` operation.
-    fn _update(
+    pub(crate) fn _update(
         &mut self,
         from: Address,
         to: Address,
         ids: Vec<U256>,
         amounts: Vec<U256>,
-    ) -> Result<(), erc6909::Error> {
+    ) -> Result<(), Error> {
         self.erc6909._update(from, to, ids.clone(), amounts.clone())?;
 
+        for (token_id, amount) in ids.into_iter().zip(amounts.into_iter()) {
+            self._update_supply(from, to, token_id, amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Single-id version of [`Self::_update`], which doesn't allocate arrays
+    /// of ids and amounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    /// * [`Error::InsufficientSupply`] - If `amount` is greater than the
+    ///   recorded total supply of `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance and/or supply exceeds [`U256::MAX`], may happen
+    ///   during the `mint` operation.
+    fn _update_single(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.erc6909._update_single(from, to, id, amount)?;
+        self._update_supply(from, to, id, amount)
+    }
+
+    /// Updates the total supply of `id` after `amount` of its tokens were
+    /// minted (or burned), if `from` (or `to`) is the zero address.
+    fn _update_supply(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
         if from.is_zero() {
-            for (&token_id, &amount) in ids.iter().zip(amounts.iter()) {
-                self.total_supply.setter(token_id).add_assign_checked(
-                    amount,
-                    "should not exceed `U256::MAX` for `total_supply`",
-                );
-            }
+            self.total_supply.setter(id).add_assign_checked(
+                amount,
+                "should not exceed `U256::MAX` for `total_supply`",
+            );
+            self.minted.setter(id).set(true);
         }
 
         if to.is_zero() {
-            for (token_id, amount) in ids.into_iter().zip(amounts.into_iter()) {
-                self.total_supply.setter(token_id).sub_assign_unchecked(amount);
+            let supply = self.total_supply.get(id);
+            if supply < amount {
+                return Err(Error::InsufficientSupply(
+                    ERC6909InsufficientSupply { id, supply, needed: amount },
+                ));
             }
+            self.total_supply.setter(id).sub_assign_unchecked(amount);
         }
 
         Ok(())
@@ -268,23 +472,65 @@ impl Erc6909Supply {
         to: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, erc6909::Error> {
-        if from.is_zero() {
-            return Err(Error::InvalidSender(erc6909::ERC6909InvalidSender {
-                sender: from,
-            }));
-        }
-        if to.is_zero() {
-            return Err(Error::InvalidReceiver(
-                erc6909::ERC6909InvalidReceiver { receiver: to },
-            ));
-        }
-        self._update(from, to, vec![id], vec![amount])?;
+    ) -> Result<bool, Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update_single(from, to, id, amount)?;
 
         Ok(true)
     }
 }
 
+impl IErc6909Internal for Erc6909Supply {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{fixed_bytes, Address, FixedBytes, U256};
@@ -294,19 +540,16 @@ mod tests {
     use crate::{
         token::erc6909::{
             extensions::{Erc6909Supply, IErc6909Supply},
+            invariant::{Erc6909Target, InvariantHarness, Operation},
             ERC6909InvalidReceiver, ERC6909InvalidSender,
+            Erc6909InsufficientAllowance,
+        },
+        utils::{
+            introspection::erc165::IErc165,
+            test_utils::{random_token_ids, random_values},
         },
-        utils::introspection::erc165::IErc165,
     };
 
-    pub(crate) fn random_token_ids(size: usize) -> Vec<U256> {
-        (0..size).map(U256::from).collect()
-    }
-
-    pub(crate) fn random_values(size: usize) -> Vec<U256> {
-        (1..=size).map(U256::from).collect()
-    }
-
     unsafe impl TopLevelStorage for Erc6909Supply {}
 
     fn init(
@@ -450,6 +693,78 @@ mod tests {
         }
     }
 
+    #[motsu::test]
+    fn exists_after_mint_and_burn(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let token_id = random_token_ids(1)[0];
+        assert!(!contract.sender(alice).exists(token_id));
+
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, bob, 1));
+        assert!(contract.sender(alice).exists(token_ids[0]));
+
+        contract
+            .sender(alice)
+            ._burn(bob, token_ids[0], values[0])
+            .expect("should burn");
+        assert!(contract.sender(alice).exists(token_ids[0]));
+        assert!(!contract.sender(alice).exists(token_ids[0] + U256::ONE));
+    }
+
+    #[cfg(feature = "self-target-guard")]
+    #[motsu::test]
+    fn transfer_reverts_when_receiver_is_token_contract(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, bob, 1));
+
+        let err = contract
+            .sender(bob)
+            .transfer(contract.address(), token_ids[0], values[0])
+            .expect_err("should not transfer to the token contract");
+
+        assert!(matches!(
+            err,
+            Error::SelfTarget(ERC6909SelfTarget { target })
+                if target == contract.address()
+        ));
+        assert_eq!(
+            values[0],
+            contract.sender(alice).balance_of(bob, token_ids[0])
+        );
+    }
+
+    #[cfg(feature = "burn-on-zero-transfer")]
+    #[motsu::test]
+    fn transfer_to_zero_address_burns_supply(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, bob, 1));
+
+        contract
+            .sender(bob)
+            .transfer(Address::ZERO, token_ids[0], values[0])
+            .expect("should burn Bob's tokens");
+
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(bob, token_ids[0])
+        );
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).total_supply(token_ids[0])
+        );
+    }
+
     #[motsu::test]
     fn burn_reverts_when_invalid_sender(
         contract: Contract<Erc6909Supply>,
@@ -473,11 +788,52 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn burn_reverts_when_exceeding_recorded_supply(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let token_id = U256::from(1);
+        let amount = U256::from(10);
+
+        // A buggy hook mints through the core contract, bypassing the
+        // supply accounting, so that balances and supply diverge.
+        contract
+            .sender(alice)
+            ._mint(bob, token_id, amount)
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            .erc6909
+            ._mint(bob, token_id, amount)
+            .motsu_expect("should mint tokens without supply");
+
+        let burnt = amount + U256::from(1);
+        let err = contract
+            .sender(alice)
+            ._burn(bob, token_id, burnt)
+            .motsu_expect_err("should not burn more than the supply");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientSupply(ERC6909InsufficientSupply {
+                id,
+                supply,
+                needed,
+            }) if id == token_id && supply == amount && needed == burnt
+        ));
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc6909Supply as IErc6909Supply>::interface_id();
         let expected: FixedBytes<4> = fixed_bytes!("0xbd85b039");
         assert_eq!(actual, expected);
+
+        let actual = <Erc6909Supply as IErc6909Exists>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x4f558e79");
+        assert_eq!(actual, expected);
     }
 
     #[motsu::test]
@@ -485,6 +841,9 @@ mod tests {
         assert!(contract.sender(alice).supports_interface(
             <Erc6909Supply as IErc6909Supply>::interface_id()
         ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Supply as IErc6909Exists>::interface_id()
+        ));
         assert!(contract
             .sender(alice)
             .supports_interface(<Erc6909Supply as IErc165>::interface_id()));
@@ -497,4 +856,163 @@ mod tests {
             .sender(alice)
             .supports_interface(fake_interface_id.into()));
     }
+
+    #[motsu::test]
+    fn internal_trait_goes_through_supply_hook(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+    ) {
+        let token_id = U256::from(1);
+        let amount = U256::from(10);
+
+        let result = IErc6909Internal::_mint(
+            &mut *contract.sender(alice),
+            alice,
+            token_id,
+            amount,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(contract.sender(alice).total_supply(token_id), amount);
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_without_allowance(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, alice, 1));
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, token_ids[0], values[0])
+            .motsu_expect_err("should revert with `InsufficientAllowance`");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(Erc6909InsufficientAllowance {
+                spender,
+                allowance,
+                needed,
+                id,
+            }) if spender == bob
+                && allowance.is_zero()
+                && needed == values[0]
+                && id == token_ids[0]
+        ));
+        assert_eq!(
+            values[0],
+            contract.sender(alice).balance_of(alice, token_ids[0])
+        );
+        assert_eq!(
+            values[0],
+            contract.sender(alice).total_supply(token_ids[0])
+        );
+    }
+
+    struct SupplyTarget<'a>(&'a Contract<Erc6909Supply>);
+
+    impl Erc6909Target for SupplyTarget<'_> {
+        fn execute(&mut self, operation: &Operation) -> bool {
+            let contract = self.0;
+            match *operation {
+                Operation::Mint { to, id, amount } => {
+                    contract.sender(to)._mint(to, id, amount).is_ok()
+                }
+                Operation::Burn { from, id, amount } => {
+                    contract.sender(from)._burn(from, id, amount).is_ok()
+                }
+                Operation::Transfer { caller, receiver, id, amount } => {
+                    contract
+                        .sender(caller)
+                        .transfer(receiver, id, amount)
+                        .is_ok()
+                }
+                Operation::TransferFrom {
+                    caller,
+                    sender,
+                    receiver,
+                    id,
+                    amount,
+                } => contract
+                    .sender(caller)
+                    .transfer_from(sender, receiver, id, amount)
+                    .is_ok(),
+                Operation::Approve { caller, spender, id, amount } => {
+                    contract.sender(caller).approve(spender, id, amount).is_ok()
+                }
+                Operation::SetOperator { caller, spender, approved } => {
+                    contract
+                        .sender(caller)
+                        .set_operator(spender, approved)
+                        .is_ok()
+                }
+            }
+        }
+
+        fn balance_of(&self, owner: Address, id: U256) -> U256 {
+            self.0.sender(owner).balance_of(owner, id)
+        }
+
+        fn allowance(
+            &self,
+            owner: Address,
+            spender: Address,
+            id: U256,
+        ) -> U256 {
+            self.0.sender(owner).allowance(owner, spender, id)
+        }
+
+        fn is_operator(&self, owner: Address, spender: Address) -> bool {
+            self.0.sender(owner).is_operator(owner, spender)
+        }
+
+        fn total_supply(&self, id: U256) -> U256 {
+            self.0.sender(Address::ZERO).total_supply(id)
+        }
+    }
+
+    #[motsu::test]
+    fn random_operations_keep_total_supply_invariant(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        let mut harness = InvariantHarness::new(
+            vec![alice, bob, charlie],
+            random_token_ids(3),
+            0x6909,
+        );
+
+        harness.run(&mut SupplyTarget(&contract), 500);
+    }
+
+    #[motsu::test]
+    fn transfer_from_zero_address_never_succeeds(
+        contract: Contract<Erc6909Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let token_id = random_token_ids(1)[0];
+        let harness =
+            InvariantHarness::new(vec![alice, bob], vec![token_id], 0);
+        let mut target = SupplyTarget(&contract);
+
+        for amount in [U256::ZERO, U256::from(1)] {
+            harness.check(
+                &mut target,
+                &Operation::TransferFrom {
+                    caller: alice,
+                    sender: Address::ZERO,
+                    receiver: bob,
+                    id: token_id,
+                    amount,
+                },
+                0,
+            );
+        }
+    }
 }
@@ -0,0 +1,264 @@
+//! Aggregated view of the information about an ERC-6909 token id.
+//!
+//! [`IErc6909TokenInfo::token_info`] returns the name, symbol, decimals,
+//! total supply, mint cap, paused status and URI of a token id in a single
+//! call, so that front-ends don't need a call per field.
+//!
+//! Each extension providing some of these fields fills them in through
+//! [`Erc6909TokenInfoSource`]. A contract composing several extensions fills a
+//! [`TokenInfo`] from each of them, and the fields of missing extensions keep
+//! their default value:
+//!
+//! ```rust,ignore
+//! fn token_info(&self, id: U256) -> TokenInfo {
+//!     let mut info = TokenInfo::default();
+//!     self.metadata.fill_token_info(id, &mut info);
+//!     self.supply.fill_token_info(id, &mut info);
+//!     info
+//! }
+//! ```
+
+use alloc::string::String;
+
+use alloy_primitives::U256;
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+
+use crate::{
+    impl_sol_struct,
+    token::erc6909::extensions::{
+        Erc6909ContentUri, Erc6909IdRegistry, Erc6909Metadata, Erc6909Supply,
+        IErc6909ContentUri, IErc6909IdRegistry, IErc6909Metadata,
+        IErc6909Supply,
+    },
+    utils::{introspection::erc165::IErc165, IPausable, Pausable},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Information about a token id, assembled from the extensions of a
+        /// contract.
+        ///
+        /// * `name` - Name of the token id.
+        /// * `symbol` - Symbol of the token id.
+        /// * `decimals` - Decimals of the token id.
+        /// * `totalSupply` - Amount of tokens of the id in existence.
+        /// * `cap` - Maximum amount of tokens of the id that can be minted,
+        ///   or zero if uncapped.
+        /// * `paused` - Whether the contract is paused.
+        /// * `uri` - URI of the token id.
+        #[derive(Debug, Default, PartialEq, Eq)]
+        #[allow(missing_docs)]
+        struct TokenInfo {
+            string name;
+            string symbol;
+            uint8 decimals;
+            uint256 totalSupply;
+            uint256 cap;
+            bool paused;
+            string uri;
+        }
+    }
+}
+
+impl_sol_struct!(TokenInfo, (String, String, u8, U256, U256, bool, String));
+
+/// Extension contributing some fields of a [`TokenInfo`].
+pub trait Erc6909TokenInfoSource {
+    /// Fills the fields of `info` this extension knows about for token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `info` - Information being assembled.
+    fn fill_token_info(&self, id: U256, info: &mut TokenInfo);
+}
+
+/// Interface for the aggregated view of the information about a token id.
+#[interface_id]
+pub trait IErc6909TokenInfo: IErc165 {
+    /// Returns the name, symbol, decimals, total supply, mint cap, paused
+    /// status and URI of token `id`.
+    ///
+    /// Fields not provided by the contract are left to their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn token_info(&self, id: U256) -> TokenInfo;
+}
+
+impl Erc6909TokenInfoSource for Erc6909Metadata {
+    fn fill_token_info(&self, id: U256, info: &mut TokenInfo) {
+        info.name = self.name(id);
+        info.symbol = self.symbol(id);
+        info.decimals = self.decimals(id).to();
+    }
+}
+
+impl Erc6909TokenInfoSource for Erc6909Supply {
+    fn fill_token_info(&self, id: U256, info: &mut TokenInfo) {
+        info.totalSupply = self.total_supply(id);
+    }
+}
+
+impl Erc6909TokenInfoSource for Erc6909IdRegistry {
+    fn fill_token_info(&self, id: U256, info: &mut TokenInfo) {
+        info.cap = self.mint_cap(id);
+    }
+}
+
+impl Erc6909TokenInfoSource for Erc6909ContentUri {
+    fn fill_token_info(&self, id: U256, info: &mut TokenInfo) {
+        info.uri = self.token_uri(id);
+    }
+}
+
+impl Erc6909TokenInfoSource for Pausable {
+    fn fill_token_info(&self, _id: U256, info: &mut TokenInfo) {
+        info.paused = self.paused();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U8};
+    use alloy_sol_types::{SolType, SolValue};
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::AbiType, prelude::*};
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[storage]
+    struct Erc6909TokenInfoExample {
+        supply: Erc6909Supply,
+        metadata: Erc6909Metadata,
+        pausable: Pausable,
+    }
+
+    #[public]
+    #[implements(IErc6909TokenInfo, IErc165)]
+    impl Erc6909TokenInfoExample {}
+
+    #[public]
+    impl IErc6909TokenInfo for Erc6909TokenInfoExample {
+        fn token_info(&self, id: U256) -> TokenInfo {
+            let mut info = TokenInfo::default();
+            self.metadata.fill_token_info(id, &mut info);
+            self.supply.fill_token_info(id, &mut info);
+            self.pausable.fill_token_info(id, &mut info);
+            info
+        }
+    }
+
+    #[public]
+    impl IErc165 for Erc6909TokenInfoExample {
+        fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+            <Self as IErc6909TokenInfo>::interface_id() == interface_id
+                || self.supply.supports_interface(interface_id)
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909TokenInfoExample {}
+
+    #[motsu::test]
+    fn token_info_assembles_extensions(
+        contract: Contract<Erc6909TokenInfoExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .metadata
+                ._set_name(ID, "Token".into())
+                .expect("should set name");
+            contract
+                .metadata
+                ._set_symbol(ID, "TKN".into())
+                .expect("should set symbol");
+            contract
+                .metadata
+                ._set_decimals(ID, U8::from(18))
+                .expect("should set decimals");
+            contract.supply._mint(alice, ID, AMOUNT).expect("should mint");
+        });
+
+        let info = contract.sender(alice).token_info(ID);
+
+        assert_eq!(
+            TokenInfo {
+                name: "Token".into(),
+                symbol: "TKN".into(),
+                decimals: 18,
+                totalSupply: AMOUNT,
+                ..TokenInfo::default()
+            },
+            info
+        );
+    }
+
+    #[motsu::test]
+    fn token_info_defaults_for_unknown_id(
+        contract: Contract<Erc6909TokenInfoExample>,
+        alice: Address,
+    ) {
+        let info = contract.sender(alice).token_info(ID);
+
+        assert_eq!(TokenInfo::default(), info);
+    }
+
+    #[test]
+    fn token_info_abi_is_tuple_of_fields() {
+        assert_eq!(
+            "(string,string,uint8,uint256,uint256,bool,string)",
+            <TokenInfo as AbiType>::ABI.as_str()
+        );
+        assert_eq!(
+            "TokenInfo memory",
+            <TokenInfo as AbiType>::EXPORT_ABI_RET.as_str()
+        );
+    }
+
+    #[test]
+    fn token_info_encodes_as_struct() {
+        let info = TokenInfo {
+            name: "Token".into(),
+            decimals: 18,
+            uri: "ipfs://token".into(),
+            ..TokenInfo::default()
+        };
+        let encoded = <TokenInfo as AbiType>::SolType::abi_encode(&info);
+
+        assert_eq!(info.abi_encode(), encoded);
+        assert_eq!(
+            info,
+            <TokenInfo as SolValue>::abi_decode(&encoded, true)
+                .expect("should decode")
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909TokenInfoExample as IErc6909TokenInfo>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xcc33c875");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909TokenInfoExample>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TokenInfoExample as IErc6909TokenInfo>::interface_id()
+        ));
+    }
+}
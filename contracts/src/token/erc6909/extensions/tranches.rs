@@ -0,0 +1,339 @@
+//! Extension of ERC-6909 that splits base assets into tranches, each with its
+//! own token id.
+//!
+//! The id of a tranche is derived deterministically from the id of its base
+//! asset and its tranche number with [`derive_tranche_id`], and the
+//! relationship between both ids is recorded on-chain, so that structured
+//! products can be navigated from either end.
+//!
+//! Note that [`Erc6909Tranches::_create_tranche`] is not exposed by default.
+//! You should expose it manually in your contract's abi, guarded by proper
+//! access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageBool, StorageMap, StorageU256, StorageVec},
+};
+
+use crate::{
+    token::erc6909::{impl_as_erc6909, lib::derive_tranche_id, Erc6909},
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `tranche` of the base asset `base_id` is created with
+        /// token id `id`.
+        ///
+        /// * `base_id` - Token id of the base asset.
+        /// * `tranche` - Number of the tranche within the base asset.
+        /// * `id` - Token id of the tranche.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TrancheCreated(
+            uint256 indexed base_id,
+            uint256 indexed tranche,
+            uint256 indexed id
+        );
+    }
+
+    sol! {
+        /// Indicates that `tranche` of the base asset `base_id` already
+        /// exists.
+        ///
+        /// * `base_id` - Token id of the base asset.
+        /// * `tranche` - Number of the tranche within the base asset.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TrancheExists(uint256 base_id, uint256 tranche);
+
+        /// Indicates that token `id` is not a tranche of any base asset.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909NonexistentTranche(uint256 id);
+    }
+}
+
+/// An [`Erc6909Tranches`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that a tranche already exists.
+    TrancheExists(ERC6909TrancheExists),
+    /// Indicates that a token id is not a tranche.
+    NonexistentTranche(ERC6909NonexistentTranche),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Tranches`] contract.
+#[storage]
+pub struct Erc6909Tranches {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from tranche id to whether it was created.
+    pub(crate) is_tranche: StorageMap<U256, StorageBool>,
+    /// Mapping from tranche id to the token id of its base asset.
+    pub(crate) parents: StorageMap<U256, StorageU256>,
+    /// Mapping from base asset id to the token ids of its tranches, in
+    /// creation order.
+    pub(crate) tranches: StorageMap<U256, StorageVec<StorageU256>>,
+}
+
+impl_as_erc6909!(Erc6909Tranches, erc6909);
+
+#[public]
+#[implements(IErc6909Tranches<Error = Error>, IErc165)]
+impl Erc6909Tranches {}
+
+/// Interface for ERC-6909 base assets split into tranches.
+#[interface_id]
+pub trait IErc6909Tranches: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the token id of the base asset of tranche `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id of the tranche.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentTranche`] - If token `id` is not a tranche.
+    fn parent_of(&self, id: U256) -> Result<U256, Self::Error>;
+
+    /// Returns the token ids of the tranches of the base asset `base_id`, in
+    /// creation order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `base_id` - Token id of the base asset.
+    fn tranches_of(&self, base_id: U256) -> Vec<U256>;
+}
+
+#[public]
+impl IErc6909Tranches for Erc6909Tranches {
+    type Error = Error;
+
+    fn parent_of(&self, id: U256) -> Result<U256, Self::Error> {
+        if !self.is_tranche.get(id) {
+            return Err(Error::NonexistentTranche(ERC6909NonexistentTranche {
+                id,
+            }));
+        }
+        Ok(self.parents.get(id))
+    }
+
+    fn tranches_of(&self, base_id: U256) -> Vec<U256> {
+        let tranches = self.tranches.get(base_id);
+        (0..tranches.len()).filter_map(|index| tranches.get(index)).collect()
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909Tranches {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Tranches>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+impl Erc6909Tranches {
+    /// Creates `tranche` of the base asset `base_id`, and returns its token
+    /// id.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `base_id` - Token id of the base asset.
+    /// * `tranche` - Number of the tranche within the base asset.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::TrancheExists`] - If `tranche` of `base_id` was already
+    ///   created.
+    ///
+    /// # Events
+    ///
+    /// * [`TrancheCreated`].
+    pub fn _create_tranche(
+        &mut self,
+        base_id: U256,
+        tranche: U256,
+    ) -> Result<U256, Error> {
+        let id = derive_tranche_id(base_id, tranche);
+        if self.is_tranche.get(id) {
+            return Err(Error::TrancheExists(ERC6909TrancheExists {
+                base_id,
+                tranche,
+            }));
+        }
+
+        self.is_tranche.setter(id).set(true);
+        self.parents.setter(id).set(base_id);
+        self.tranches.setter(base_id).push(id);
+
+        evm::log(TrancheCreated { base_id, tranche, id });
+
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909Tranches {}
+
+    const BASE_ID: U256 = uint!(1_U256);
+    const SENIOR: U256 = uint!(0_U256);
+    const JUNIOR: U256 = uint!(1_U256);
+
+    #[motsu::test]
+    fn create_tranche(contract: Contract<Erc6909Tranches>, alice: Address) {
+        let id = contract
+            .sender(alice)
+            ._create_tranche(BASE_ID, SENIOR)
+            .motsu_expect("should create tranche");
+
+        assert_eq!(derive_tranche_id(BASE_ID, SENIOR), id);
+        assert_eq!(
+            BASE_ID,
+            contract
+                .sender(alice)
+                .parent_of(id)
+                .motsu_expect("should return parent")
+        );
+        assert_eq!(vec![id], contract.sender(alice).tranches_of(BASE_ID));
+
+        contract.assert_emitted(&TrancheCreated {
+            base_id: BASE_ID,
+            tranche: SENIOR,
+            id,
+        });
+    }
+
+    #[motsu::test]
+    fn tranches_of_returns_tranches_in_creation_order(
+        contract: Contract<Erc6909Tranches>,
+        alice: Address,
+    ) {
+        let junior = contract
+            .sender(alice)
+            ._create_tranche(BASE_ID, JUNIOR)
+            .motsu_expect("should create junior tranche");
+        let senior = contract
+            .sender(alice)
+            ._create_tranche(BASE_ID, SENIOR)
+            .motsu_expect("should create senior tranche");
+
+        assert_eq!(
+            vec![junior, senior],
+            contract.sender(alice).tranches_of(BASE_ID)
+        );
+        assert!(contract.sender(alice).tranches_of(junior).is_empty());
+    }
+
+    #[motsu::test]
+    fn tranche_of_base_id_zero_has_parent(
+        contract: Contract<Erc6909Tranches>,
+        alice: Address,
+    ) {
+        let id = contract
+            .sender(alice)
+            ._create_tranche(U256::ZERO, SENIOR)
+            .motsu_expect("should create tranche");
+
+        assert_eq!(
+            U256::ZERO,
+            contract
+                .sender(alice)
+                .parent_of(id)
+                .motsu_expect("should return parent")
+        );
+    }
+
+    #[motsu::test]
+    fn create_tranche_reverts_when_tranche_exists(
+        contract: Contract<Erc6909Tranches>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._create_tranche(BASE_ID, SENIOR)
+            .motsu_expect("should create tranche");
+
+        let err = contract
+            .sender(alice)
+            ._create_tranche(BASE_ID, SENIOR)
+            .motsu_expect_err("should not create tranche twice");
+
+        assert!(matches!(
+            err,
+            Error::TrancheExists(ERC6909TrancheExists { base_id, tranche })
+                if base_id == BASE_ID && tranche == SENIOR
+        ));
+    }
+
+    #[motsu::test]
+    fn parent_of_reverts_when_not_a_tranche(
+        contract: Contract<Erc6909Tranches>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .parent_of(BASE_ID)
+            .motsu_expect_err("base asset should not have a parent");
+
+        assert!(matches!(
+            err,
+            Error::NonexistentTranche(ERC6909NonexistentTranche { id })
+                if id == BASE_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Tranches as IErc6909Tranches>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x8113e001");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<Erc6909Tranches>, alice: Address) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909Tranches as IErc6909Tranches>::interface_id()
+        ));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<Erc6909Tranches as IErc165>::interface_id()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,951 @@
+//! Extension of ERC-6909 that calls the receiver of a transfer, or the
+//! spender of an approval, back in the same transaction, in the style of
+//! [ERC-1363](https://eips.ethereum.org/EIPS/eip-1363).
+//!
+//! [`IErc6909TransferAndCall::transfer_and_call`] transfers tokens and then
+//! calls [`IErc6909Receiver::on_transfer_received`] on the receiver, and
+//! [`IErc6909TransferAndCall::approve_and_call`] sets an allowance and then
+//! calls [`IErc6909Spender::on_approval_received`] on the spender. Staking or
+//! vault contracts can thus account for a deposit without a separate
+//! `approve` and `transferFrom` round trip.
+//!
+//! The callback must return its own function selector, or the whole
+//! operation reverts. Callbacks usually call back into the token, e.g. to
+//! pull the approved tokens with `transferFrom`, so contracts exposing this
+//! extension must be built with the `reentrant` feature.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+pub use callback::{IErc6909Receiver, IErc6909Spender};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes,
+    call::{self, Call, MethodError},
+    function_selector, msg,
+    prelude::*,
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// The expected value returned from
+/// [`IErc6909Receiver::on_transfer_received`].
+pub const TRANSFER_RECEIVED_FN_SELECTOR: [u8; 4] = function_selector!(
+    "onTransferReceived",
+    Address,
+    Address,
+    U256,
+    U256,
+    Bytes,
+);
+
+/// The expected value returned from
+/// [`IErc6909Spender::on_approval_received`].
+pub const APPROVAL_RECEIVED_FN_SELECTOR: [u8; 4] =
+    function_selector!("onApprovalReceived", Address, U256, U256, Bytes,);
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that the transfer callback of `receiver` failed, or
+        /// didn't return its function selector.
+        ///
+        /// * `receiver` - Account receiving the tokens.
+        /// * `reason` - Revert data of the callback, if any.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TransferCallbackFailed(address receiver, bytes reason);
+
+        /// Indicates that the approval callback of `spender` failed, or
+        /// didn't return its function selector.
+        ///
+        /// * `spender` - Account allowed to spend the tokens.
+        /// * `reason` - Revert data of the callback, if any.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ApprovalCallbackFailed(address spender, bytes reason);
+    }
+}
+
+mod callback {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the contracts receiving tokens through
+        /// [`super::IErc6909TransferAndCall::transfer_and_call`].
+        interface IErc6909Receiver {
+            /// Handles the receipt of `amount` tokens of type `id`.
+            ///
+            /// It must return its function selector to accept the transfer.
+            /// If any other value is returned, or the callback reverts, the
+            /// transfer is reverted.
+            ///
+            /// # Arguments
+            ///
+            /// * `operator` - Account that initiated the transfer.
+            /// * `from` - Account the tokens were transferred from.
+            /// * `id` - Token id as a number.
+            /// * `amount` - Amount of tokens transferred.
+            /// * `data` - Additional data with no specified format.
+            function onTransferReceived(
+                address operator,
+                address from,
+                uint256 id,
+                uint256 amount,
+                bytes calldata data
+            ) external returns (bytes4);
+        }
+
+        /// Interface of the contracts approved through
+        /// [`super::IErc6909TransferAndCall::approve_and_call`].
+        interface IErc6909Spender {
+            /// Handles the approval of `amount` tokens of type `id`.
+            ///
+            /// It must return its function selector to accept the approval.
+            /// If any other value is returned, or the callback reverts, the
+            /// approval is reverted.
+            ///
+            /// # Arguments
+            ///
+            /// * `owner` - Account that owns the tokens.
+            /// * `id` - Token id as a number.
+            /// * `amount` - Amount of tokens allowed to be spent.
+            /// * `data` - Additional data with no specified format.
+            function onApprovalReceived(
+                address owner,
+                uint256 id,
+                uint256 amount,
+                bytes calldata data
+            ) external returns (bytes4);
+        }
+    }
+}
+
+/// An [`Erc6909TransferAndCall`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that the transfer callback of the receiver failed.
+    TransferCallbackFailed(ERC6909TransferCallbackFailed),
+    /// Indicates that the approval callback of the spender failed.
+    ApprovalCallbackFailed(ERC6909ApprovalCallbackFailed),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909TransferAndCall`] contract.
+#[storage]
+pub struct Erc6909TransferAndCall {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909TransferAndCall {}
+
+/// Required interface of an [`Erc6909TransferAndCall`] contract.
+#[interface_id]
+pub trait IErc6909TransferAndCall: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Transfers `amount` tokens of type `id` from the caller to `receiver`,
+    /// and then calls [`IErc6909Receiver::on_transfer_received`] on
+    /// `receiver`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `data` - Additional data with no specified format, passed to the
+    ///   callback.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `receiver` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If the caller owns fewer than
+    ///   `amount` tokens of type `id`.
+    /// * [`Error::TransferCallbackFailed`] - If `receiver` is not a contract,
+    ///   or its callback reverts or doesn't return its function selector.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    fn transfer_and_call(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, Self::Error>;
+
+    /// Sets `amount` as the allowance of `spender` over the caller's tokens
+    /// of type `id`, and then calls [`IErc6909Spender::on_approval_received`]
+    /// on `spender`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account to approve.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    /// * `data` - Additional data with no specified format, passed to the
+    ///   callback.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is [`Address::ZERO`].
+    /// * [`Error::ApprovalCallbackFailed`] - If `spender` is not a contract, or
+    ///   its callback reverts or doesn't return its function selector.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::Approval`].
+    fn approve_and_call(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, Self::Error>;
+}
+
+impl_as_erc6909!(Erc6909TransferAndCall, erc6909);
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909TransferAndCall<Error = Error>,
+    IErc165
+)]
+impl Erc6909TransferAndCall {}
+
+#[public]
+impl IErc6909TransferAndCall for Erc6909TransferAndCall {
+    type Error = Error;
+
+    fn transfer_and_call(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self.erc6909.transfer(receiver, id, amount)?;
+        self._check_on_transfer_received(
+            sender, sender, receiver, id, amount, &data,
+        )?;
+        Ok(true)
+    }
+
+    fn approve_and_call(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<bool, Self::Error> {
+        let owner = msg::sender();
+        self.erc6909.approve(spender, id, amount)?;
+        self._check_on_approval_received(owner, spender, id, amount, &data)?;
+        Ok(true)
+    }
+}
+
+impl Erc6909TransferAndCall {
+    /// Calls [`IErc6909Receiver::on_transfer_received`] on `receiver`, and
+    /// checks that it returns [`TRANSFER_RECEIVED_FN_SELECTOR`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account that initiated the transfer.
+    /// * `from` - Account the tokens were transferred from.
+    /// * `receiver` - Account the tokens were transferred to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens transferred.
+    /// * `data` - Additional data with no specified format.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::TransferCallbackFailed`] - If `receiver` is not a contract,
+    ///   or its callback reverts or doesn't return its function selector.
+    fn _check_on_transfer_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        let failed = |reason: Vec<u8>| {
+            Error::TransferCallbackFailed(ERC6909TransferCallbackFailed {
+                receiver,
+                reason: reason.into(),
+            })
+        };
+
+        if !receiver.has_code() {
+            return Err(failed(Vec::new()));
+        }
+
+        let selector = IErc6909Receiver::new(receiver)
+            .on_transfer_received(
+                Call::new_in(self),
+                operator,
+                from,
+                id,
+                amount,
+                data.to_vec().into(),
+            )
+            .map_err(|e| failed(revert_reason(e)))?;
+
+        if selector != TRANSFER_RECEIVED_FN_SELECTOR {
+            return Err(failed(Vec::new()));
+        }
+
+        Ok(())
+    }
+
+    /// Calls [`IErc6909Spender::on_approval_received`] on `spender`, and
+    /// checks that it returns [`APPROVAL_RECEIVED_FN_SELECTOR`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens allowed to be spent.
+    /// * `data` - Additional data with no specified format.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ApprovalCallbackFailed`] - If `spender` is not a contract, or
+    ///   its callback reverts or doesn't return its function selector.
+    fn _check_on_approval_received(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        let failed = |reason: Vec<u8>| {
+            Error::ApprovalCallbackFailed(ERC6909ApprovalCallbackFailed {
+                spender,
+                reason: reason.into(),
+            })
+        };
+
+        if !spender.has_code() {
+            return Err(failed(Vec::new()));
+        }
+
+        let selector = IErc6909Spender::new(spender)
+            .on_approval_received(
+                Call::new_in(self),
+                owner,
+                id,
+                amount,
+                data.to_vec().into(),
+            )
+            .map_err(|e| failed(revert_reason(e)))?;
+
+        if selector != APPROVAL_RECEIVED_FN_SELECTOR {
+            return Err(failed(Vec::new()));
+        }
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Ok(self.erc6909._burn(from, id, amount)?)
+    }
+}
+
+/// Returns the revert data of a failed callback, or empty data when it
+/// returned a value that couldn't be decoded.
+fn revert_reason(error: call::Error) -> Vec<u8> {
+    match error {
+        call::Error::Revert(reason) => reason,
+        call::Error::AbiDecodingFailed(_) => Vec::new(),
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909TransferAndCall {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer(receiver, id, amount)?)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.transfer_from(sender, receiver, id, amount)?)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909TransferAndCall {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferAndCall>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl IErc6909Internal for Erc6909TransferAndCall {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use alloy_sol_types::SolError;
+    use motsu::prelude::*;
+    use stylus_sdk::storage::{StorageAddress, StorageU256};
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(1_000_U256);
+
+    stylus_sdk::prelude::sol_interface! {
+        interface IToken {
+            function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool);
+        }
+    }
+
+    /// Vault crediting deposits received through its callbacks: transfers
+    /// are credited as is, and approvals are pulled with `transferFrom`.
+    #[storage]
+    struct MockVault {
+        depositor: StorageAddress,
+        deposited: StorageU256,
+    }
+
+    #[public]
+    impl MockVault {
+        fn on_transfer_received(
+            &mut self,
+            _operator: Address,
+            from: Address,
+            _id: U256,
+            amount: U256,
+            _data: Bytes,
+        ) -> FixedBytes<4> {
+            self.depositor.set(from);
+            self.deposited.set(self.deposited.get() + amount);
+            TRANSFER_RECEIVED_FN_SELECTOR.into()
+        }
+
+        fn on_approval_received(
+            &mut self,
+            owner: Address,
+            id: U256,
+            amount: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Vec<u8>> {
+            let vault = stylus_sdk::contract::address();
+            IToken::new(msg::sender()).transfer_from(
+                Call::new_in(self),
+                owner,
+                vault,
+                id,
+                amount,
+            )?;
+            self.depositor.set(owner);
+            self.deposited.set(self.deposited.get() + amount);
+            Ok(APPROVAL_RECEIVED_FN_SELECTOR.into())
+        }
+
+        fn deposited(&self) -> U256 {
+            self.deposited.get()
+        }
+
+        fn depositor(&self) -> Address {
+            self.depositor.get()
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockVault {}
+
+    /// Receiver returning a wrong value from its callbacks.
+    #[storage]
+    struct RejectingReceiver;
+
+    #[public]
+    impl RejectingReceiver {
+        fn on_transfer_received(
+            &mut self,
+            _operator: Address,
+            _from: Address,
+            _id: U256,
+            _amount: U256,
+            _data: Bytes,
+        ) -> FixedBytes<4> {
+            FixedBytes::ZERO
+        }
+
+        fn on_approval_received(
+            &mut self,
+            _owner: Address,
+            _id: U256,
+            _amount: U256,
+            _data: Bytes,
+        ) -> FixedBytes<4> {
+            FixedBytes::ZERO
+        }
+    }
+
+    /// Receiver whose callbacks always revert.
+    #[storage]
+    struct RevertingReceiver;
+
+    #[public]
+    impl RevertingReceiver {
+        fn on_transfer_received(
+            &mut self,
+            _operator: Address,
+            _from: Address,
+            _id: U256,
+            _amount: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Vec<u8>> {
+            Err("transfer rejected".into())
+        }
+
+        fn on_approval_received(
+            &mut self,
+            _owner: Address,
+            _id: U256,
+            _amount: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<4>, Vec<u8>> {
+            Err("approval rejected".into())
+        }
+    }
+
+    #[motsu::test]
+    fn transfer_and_call_calls_receiver(
+        contract: Contract<Erc6909TransferAndCall>,
+        vault: Contract<MockVault>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let status = contract
+            .sender(alice)
+            .transfer_and_call(vault.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect("should transfer and call");
+
+        assert!(status);
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).balance_of(vault.address(), ID)
+        );
+        assert_eq!(AMOUNT, vault.sender(alice).deposited());
+        assert_eq!(alice, vault.sender(alice).depositor());
+    }
+
+    #[motsu::test]
+    fn approve_and_call_lets_spender_pull_tokens(
+        contract: Contract<Erc6909TransferAndCall>,
+        vault: Contract<MockVault>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let status = contract
+            .sender(alice)
+            .approve_and_call(vault.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect("should approve and call");
+
+        assert!(status);
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).balance_of(vault.address(), ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .allowance(alice, vault.address(), ID)
+            .is_zero());
+        assert_eq!(AMOUNT, vault.sender(alice).deposited());
+    }
+
+    #[motsu::test]
+    fn transfer_and_call_reverts_for_account_without_code(
+        contract: Contract<Erc6909TransferAndCall>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(alice)
+            .transfer_and_call(bob, ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should reject receiver without code");
+
+        assert!(matches!(
+            err,
+            Error::TransferCallbackFailed(ERC6909TransferCallbackFailed {
+                receiver,
+                ref reason,
+            }) if receiver == bob && reason.is_empty()
+        ));
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn transfer_and_call_reverts_when_receiver_rejects(
+        contract: Contract<Erc6909TransferAndCall>,
+        receiver: Contract<RejectingReceiver>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(alice)
+            .transfer_and_call(receiver.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should reject wrong selector");
+
+        assert!(matches!(
+            err,
+            Error::TransferCallbackFailed(ERC6909TransferCallbackFailed {
+                receiver: failed,
+                ref reason,
+            }) if failed == receiver.address() && reason.is_empty()
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_and_call_bubbles_up_revert_reason(
+        contract: Contract<Erc6909TransferAndCall>,
+        receiver: Contract<RevertingReceiver>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(alice)
+            .transfer_and_call(receiver.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should bubble up the callback's revert");
+
+        assert_eq!(
+            ERC6909TransferCallbackFailed {
+                receiver: receiver.address(),
+                reason: b"transfer rejected".to_vec().into(),
+            }
+            .abi_encode(),
+            Vec::<u8>::from(err)
+        );
+    }
+
+    #[motsu::test]
+    fn approve_and_call_reverts_when_spender_rejects(
+        contract: Contract<Erc6909TransferAndCall>,
+        spender: Contract<RejectingReceiver>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve_and_call(spender.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should reject wrong selector");
+
+        assert!(matches!(
+            err,
+            Error::ApprovalCallbackFailed(ERC6909ApprovalCallbackFailed {
+                spender: failed,
+                ref reason,
+            }) if failed == spender.address() && reason.is_empty()
+        ));
+    }
+
+    #[motsu::test]
+    fn approve_and_call_bubbles_up_revert_reason(
+        contract: Contract<Erc6909TransferAndCall>,
+        spender: Contract<RevertingReceiver>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve_and_call(spender.address(), ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should bubble up the callback's revert");
+
+        assert_eq!(
+            ERC6909ApprovalCallbackFailed {
+                spender: spender.address(),
+                reason: b"approval rejected".to_vec().into(),
+            }
+            .abi_encode(),
+            Vec::<u8>::from(err)
+        );
+    }
+
+    #[motsu::test]
+    fn approve_and_call_reverts_for_zero_spender(
+        contract: Contract<Erc6909TransferAndCall>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve_and_call(Address::ZERO, ID, AMOUNT, Bytes::default())
+            .motsu_expect_err("should reject zero spender");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSpenderForId(ERC6909InvalidSpenderForId {
+                spender,
+                id
+            }) if spender.is_zero() && id == ID
+        ));
+    }
+
+    #[test]
+    fn callback_selectors() {
+        assert_eq!(
+            fixed_bytes!("0x3657256a"),
+            FixedBytes::from(TRANSFER_RECEIVED_FN_SELECTOR)
+        );
+        assert_eq!(
+            fixed_bytes!("0x86cb03f2"),
+            FixedBytes::from(APPROVAL_RECEIVED_FN_SELECTOR)
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909TransferAndCall as IErc6909TransferAndCall>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xe9d14257");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909TransferAndCall>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferAndCall as IErc6909TransferAndCall>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferAndCall as IErc165>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferAndCall as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
@@ -0,0 +1,1119 @@
+//! Extension of ERC-6909 that allows tokens to be transferred with a signed
+//! authorization, in the style of [ERC-3009].
+//!
+//! Adds the `transfer_with_authorization` method, which moves tokens of an
+//! `owner` to a recipient given a message signed by the `owner`. Unlike a
+//! permit, which only grants an allowance, the authorization is the transfer
+//! itself, so a relayer can settle it in a single transaction, and the
+//! `owner` doesn't need to hold Ether at all.
+//!
+//! Authorizations use random 32-byte nonces instead of sequential ones, so an
+//! `owner` can sign several authorizations at once, and have them submitted
+//! in any order. Each authorization is only valid within the time window
+//! between its `valid_after` and `valid_before` timestamps, and can be
+//! canceled by its signer as long as it wasn't used.
+//!
+//! Smart contract wallets are supported too: when the signature isn't an ECDSA
+//! signature of the `owner`, it is checked with the ERC-1271
+//! `isValidSignature` function of the `owner`.
+//!
+//! [ERC-3009]: https://eips.ethereum.org/EIPS/eip-3009
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_sol_types::SolType;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    abi::Bytes,
+    block,
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageBool, StorageMap},
+};
+
+use crate::{
+    token::erc6909::{
+        self, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909Internal,
+    },
+    utils::cryptography::{
+        ecdsa::{self, ECDSAInvalidSignature, ECDSAInvalidSignatureS},
+        eip712::IEip712,
+        signature_checker::is_valid_erc1271_signature_now,
+    },
+};
+
+const TRANSFER_WITH_AUTHORIZATION_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"TransferWithAuthorization(address owner,address to,uint256 id,uint256 amount,uint256 validAfter,uint256 validBefore,bytes32 nonce)")
+        .finalize();
+
+const CANCEL_AUTHORIZATION_TYPEHASH: [u8; 32] = keccak_const::Keccak256::new()
+    .update(b"CancelAuthorization(address owner,bytes32 nonce)")
+    .finalize();
+
+/// Length of a signature made of its `r`, `s` and `v` values.
+const SIGNATURE_LENGTH: usize = 65;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    pub(crate) type StructHashTuple = sol! {
+        tuple(bytes32, address, address, uint256, uint256, uint256, uint256, bytes32)
+    };
+
+    pub(crate) type CancelStructHashTuple = sol! {
+        tuple(bytes32, address, bytes32)
+    };
+
+    sol! {
+        /// Emitted when the authorization of `authorizer` with `nonce` is
+        /// used.
+        ///
+        /// * `authorizer` - Account that signed the authorization.
+        /// * `nonce` - Unique nonce of the authorization.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event AuthorizationUsed(address indexed authorizer, bytes32 indexed nonce);
+
+        /// Emitted when the authorization of `authorizer` with `nonce` is
+        /// canceled.
+        ///
+        /// * `authorizer` - Account that signed the authorization.
+        /// * `nonce` - Unique nonce of the authorization.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event AuthorizationCanceled(address indexed authorizer, bytes32 indexed nonce);
+    }
+
+    sol! {
+        /// Indicates that an authorization was used before `valid_after`.
+        ///
+        /// * `valid_after` - Timestamp after which the authorization is valid.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909AuthorizationNotYetValid(uint256 valid_after);
+
+        /// Indicates that an authorization was used from `valid_before`.
+        ///
+        /// * `valid_before` - Timestamp before which the authorization is
+        ///   valid.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909AuthorizationExpired(uint256 valid_before);
+
+        /// Indicates that the authorization of `authorizer` with `nonce` was
+        /// already used or canceled.
+        ///
+        /// * `authorizer` - Account that signed the authorization.
+        /// * `nonce` - Unique nonce of the authorization.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909AuthorizationUsed(address authorizer, bytes32 nonce);
+
+        /// Indicates that an authorization was signed by `signer` instead of
+        /// `owner`.
+        ///
+        /// * `signer` - Account that signed the authorization.
+        /// * `owner` - Account whose tokens are transferred.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidAuthorizationSigner(address signer, address owner);
+
+        /// Indicates that a signature isn't made of 65 bytes.
+        ///
+        /// * `length` - Length of the signature.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidSignatureLength(uint256 length);
+    }
+}
+
+/// An [`Erc6909TransferAuthorization`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that an authorization was used before its time window.
+    AuthorizationNotYetValid(ERC6909AuthorizationNotYetValid),
+    /// Indicates that an authorization was used after its time window.
+    AuthorizationExpired(ERC6909AuthorizationExpired),
+    /// Indicates that an authorization was already used or canceled.
+    AuthorizationUsed(ERC6909AuthorizationUsed),
+    /// Indicates that an authorization wasn't signed by the owner of the
+    /// tokens.
+    InvalidSigner(ERC6909InvalidAuthorizationSigner),
+    /// Indicates that a signature isn't made of 65 bytes.
+    InvalidSignatureLength(ERC6909InvalidSignatureLength),
+    /// The signature derives the [`Address::ZERO`].
+    InvalidSignature(ECDSAInvalidSignature),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(ECDSAInvalidSignatureS),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<ecdsa::Error> for Error {
+    fn from(value: ecdsa::Error) -> Self {
+        match value {
+            ecdsa::Error::InvalidSignature(e) => Error::InvalidSignature(e),
+            ecdsa::Error::InvalidSignatureS(e) => Error::InvalidSignatureS(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909TransferAuthorization`] contract.
+#[storage]
+pub struct Erc6909TransferAuthorization<T: IEip712 + StorageType> {
+    /// Contract implementing [`IEip712`] trait.
+    pub(crate) eip712: T,
+    /// Mapping from authorizer to nonce to whether the authorization was
+    /// used.
+    pub(crate) authorization_states:
+        StorageMap<Address, StorageMap<B256, StorageBool>>,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl<T: IEip712 + StorageType> TopLevelStorage
+    for Erc6909TransferAuthorization<T>
+{
+}
+
+/// Required interface of an [`Erc6909TransferAuthorization`] contract.
+#[interface_id]
+pub trait IErc6909TransferAuthorization {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the domain separator used in the encoding of the signature for
+    /// [`Self::transfer_with_authorization`], as defined by EIP712.
+    ///
+    /// NOTE: The implementation should use `#[selector(name =
+    /// "DOMAIN_SEPARATOR")]` to match Solidity's camelCase naming
+    /// convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    #[must_use]
+    fn domain_separator(&self) -> B256;
+
+    /// Returns whether the authorization of `authorizer` with `nonce` was
+    /// already used or canceled.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `authorizer` - Account that signed the authorization.
+    /// * `nonce` - Unique nonce of the authorization.
+    #[must_use]
+    fn authorization_state(&self, authorizer: Address, nonce: B256) -> bool;
+
+    /// Transfers `amount` tokens of type `id` from `owner` to `to`, given
+    /// `owner`'s signed authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account whose tokens are transferred.
+    /// * `to` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `valid_after` - Timestamp after which the authorization is valid.
+    /// * `valid_before` - Timestamp before which the authorization is valid.
+    /// * `nonce` - Unique nonce of the authorization.
+    /// * `signature` - `owner`'s signature, made of its `r`, `s` and `v`
+    ///   values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AuthorizationNotYetValid`] - If the current timestamp is not
+    ///   greater than `valid_after`.
+    /// * [`Error::AuthorizationExpired`] - If the current timestamp is not less
+    ///   than `valid_before`.
+    /// * [`Error::AuthorizationUsed`] - If the authorization of `owner` with
+    ///   `nonce` was already used or canceled.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271.
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `owner` doesn't have enough tokens
+    ///   of type `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`AuthorizationUsed`]
+    /// * [`erc6909::Transfer`]
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_with_authorization(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        valid_after: U256,
+        valid_before: U256,
+        nonce: B256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error>;
+
+    /// Cancels the authorization of `owner` with `nonce`, given `owner`'s
+    /// signed cancellation, so that it can't be used anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that signed the authorization.
+    /// * `nonce` - Unique nonce of the authorization.
+    /// * `signature` - `owner`'s signature of the cancellation, made of its
+    ///   `r`, `s` and `v` values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AuthorizationUsed`] - If the authorization of `owner` with
+    ///   `nonce` was already used or canceled.
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271.
+    ///
+    /// # Events
+    ///
+    /// * [`AuthorizationCanceled`]
+    fn cancel_authorization(
+        &mut self,
+        owner: Address,
+        nonce: B256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: IEip712 + StorageType> Erc6909TransferAuthorization<T> {
+    /// See [`IErc6909TransferAuthorization::domain_separator`].
+    #[must_use]
+    pub fn domain_separator(&self) -> B256 {
+        self.eip712.domain_separator_v4()
+    }
+
+    /// See [`IErc6909TransferAuthorization::authorization_state`].
+    #[must_use]
+    pub fn authorization_state(
+        &self,
+        authorizer: Address,
+        nonce: B256,
+    ) -> bool {
+        self.authorization_states.get(authorizer).get(nonce)
+    }
+
+    /// See [`IErc6909TransferAuthorization::transfer_with_authorization`].
+    ///
+    /// `token` is the token whose tokens are transferred, e.g. an
+    /// [`Erc6909`] or one of its extensions, and `E` the error the contract
+    /// reverts with. Tokens are moved through the `_update` of `token`, so
+    /// that the hooks of its extensions, like freezes or vote checkpoints,
+    /// apply to authorized transfers too.
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn transfer_with_authorization<I, E>(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        valid_after: U256,
+        valid_before: U256,
+        nonce: B256,
+        signature: &Bytes,
+        token: &mut I,
+    ) -> Result<(), E>
+    where
+        I: IErc6909Internal,
+        E: From<Error> + From<I::Error>,
+    {
+        let now = U256::from(block::timestamp());
+        if now <= valid_after {
+            return Err(Error::from(ERC6909AuthorizationNotYetValid {
+                valid_after,
+            })
+            .into());
+        }
+        if now >= valid_before {
+            return Err(Error::from(ERC6909AuthorizationExpired {
+                valid_before,
+            })
+            .into());
+        }
+
+        self._require_unused_authorization(owner, nonce)?;
+
+        let struct_hash = keccak256(StructHashTuple::abi_encode(&(
+            TRANSFER_WITH_AUTHORIZATION_TYPEHASH,
+            owner,
+            to,
+            id,
+            amount,
+            valid_after,
+            valid_before,
+            nonce,
+        )));
+        self._check_signer(owner, struct_hash, signature)?;
+
+        self.authorization_states.setter(owner).setter(nonce).set(true);
+        evm::log(AuthorizationUsed { authorizer: owner, nonce });
+
+        Erc6909::_check_transfer_params(owner, to).map_err(Error::from)?;
+        Ok(token._update(owner, to, vec![id], vec![amount])?)
+    }
+
+    /// See [`IErc6909TransferAuthorization::cancel_authorization`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn cancel_authorization(
+        &mut self,
+        owner: Address,
+        nonce: B256,
+        signature: &Bytes,
+    ) -> Result<(), Error> {
+        self._require_unused_authorization(owner, nonce)?;
+
+        let struct_hash = keccak256(CancelStructHashTuple::abi_encode(&(
+            CANCEL_AUTHORIZATION_TYPEHASH,
+            owner,
+            nonce,
+        )));
+        self._check_signer(owner, struct_hash, signature)?;
+
+        self.authorization_states.setter(owner).setter(nonce).set(true);
+        evm::log(AuthorizationCanceled { authorizer: owner, nonce });
+
+        Ok(())
+    }
+
+    /// Checks that the authorization of `authorizer` with `nonce` was neither
+    /// used nor canceled.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `authorizer` - Account that signed the authorization.
+    /// * `nonce` - Unique nonce of the authorization.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AuthorizationUsed`] - If the authorization was already used
+    ///   or canceled.
+    fn _require_unused_authorization(
+        &self,
+        authorizer: Address,
+        nonce: B256,
+    ) -> Result<(), Error> {
+        if self.authorization_state(authorizer, nonce) {
+            return Err(ERC6909AuthorizationUsed { authorizer, nonce }.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that `signature` of the EIP-712 struct hashed as `struct_hash`
+    /// was made by `owner`, either with ECDSA or, for smart contract wallets,
+    /// as validated by the ERC-1271 `isValidSignature` function of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account expected to have signed the struct.
+    /// * `struct_hash` - Hash of the signed struct.
+    /// * `signature` - Signature made of its `r`, `s` and `v` values.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSignatureLength`] - If `signature` isn't made of 65
+    ///   bytes.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is grater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidSigner`] - If the signer is not `owner`, and `owner`
+    ///   doesn't validate the signature with ERC-1271.
+    fn _check_signer(
+        &mut self,
+        owner: Address,
+        struct_hash: B256,
+        signature: &[u8],
+    ) -> Result<(), Error> {
+        let hash = self.eip712.hash_typed_data_v4(struct_hash);
+
+        let recovered = split_signature(signature)
+            .map_err(Error::from)
+            .and_then(|(v, r, s)| Ok(ecdsa::recover(self, hash, v, r, s)?));
+        match recovered {
+            Ok(signer) if signer == owner => Ok(()),
+            // Smart contract wallets can't sign with ECDSA, and validate the
+            // signatures made on their behalf themselves.
+            _ if is_valid_erc1271_signature_now(
+                self, owner, hash, signature,
+            ) =>
+            {
+                Ok(())
+            }
+            Ok(signer) => {
+                Err(ERC6909InvalidAuthorizationSigner { signer, owner }.into())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Splits a 65-byte `signature` into its `v`, `r` and `s` values.
+///
+/// # Arguments
+///
+/// * `signature` - Signature made of its `r`, `s` and `v` values.
+///
+/// # Errors
+///
+/// * [`ERC6909InvalidSignatureLength`] - If `signature` isn't made of 65 bytes.
+pub(crate) fn split_signature(
+    signature: &[u8],
+) -> Result<(u8, B256, B256), ERC6909InvalidSignatureLength> {
+    if signature.len() != SIGNATURE_LENGTH {
+        return Err(ERC6909InvalidSignatureLength {
+            length: U256::from(signature.len()),
+        });
+    }
+
+    let r = B256::from_slice(&signature[..32]);
+    let s = B256::from_slice(&signature[32..64]);
+    Ok((signature[64], r, s))
+}
+
+#[cfg(feature = "export-abi")]
+impl<T: IEip712 + StorageType> crate::utils::abi::Events
+    for Erc6909TransferAuthorization<T>
+{
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        vec![
+            event::<AuthorizationUsed>(
+                "event AuthorizationUsed(address indexed authorizer, bytes32 \
+                 indexed nonce)",
+            ),
+            event::<AuthorizationCanceled>(
+                "event AuthorizationCanceled(address indexed authorizer, \
+                 bytes32 indexed nonce)",
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256,
+    };
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::Bytes, block, prelude::*, storage::StorageBytes};
+
+    use super::*;
+    use crate::{
+        token::erc6909::{
+            extensions::{freezable, Erc6909Freezable},
+            IErc6909,
+        },
+        utils::cryptography::signature_checker::ERC1271_MAGIC_VALUE,
+    };
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+    const NONCE: B256 = B256::repeat_byte(1);
+
+    #[storage]
+    struct Eip712;
+
+    impl IEip712 for Eip712 {
+        const NAME: &'static str = "ERC-6909 Transfer Authorization Example";
+        const VERSION: &'static str = "1";
+    }
+
+    #[storage]
+    struct Erc6909TransferAuthorizationExample {
+        erc6909: Erc6909,
+        authorization: Erc6909TransferAuthorization<Eip712>,
+    }
+
+    #[public]
+    #[implements(IErc6909TransferAuthorization<Error = Error>)]
+    impl Erc6909TransferAuthorizationExample {}
+
+    #[public]
+    impl IErc6909TransferAuthorization for Erc6909TransferAuthorizationExample {
+        type Error = Error;
+
+        #[selector(name = "DOMAIN_SEPARATOR")]
+        fn domain_separator(&self) -> B256 {
+            self.authorization.domain_separator()
+        }
+
+        fn authorization_state(
+            &self,
+            authorizer: Address,
+            nonce: B256,
+        ) -> bool {
+            self.authorization.authorization_state(authorizer, nonce)
+        }
+
+        fn transfer_with_authorization(
+            &mut self,
+            owner: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+            valid_after: U256,
+            valid_before: U256,
+            nonce: B256,
+            signature: Bytes,
+        ) -> Result<(), Self::Error> {
+            self.authorization.transfer_with_authorization(
+                owner,
+                to,
+                id,
+                amount,
+                valid_after,
+                valid_before,
+                nonce,
+                &signature,
+                &mut self.erc6909,
+            )
+        }
+
+        fn cancel_authorization(
+            &mut self,
+            owner: Address,
+            nonce: B256,
+            signature: Bytes,
+        ) -> Result<(), Self::Error> {
+            self.authorization.cancel_authorization(owner, nonce, &signature)
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909TransferAuthorizationExample {}
+
+    #[storage]
+    struct Erc6909FreezableTransferAuthorizationExample {
+        erc6909: Erc6909Freezable,
+        authorization: Erc6909TransferAuthorization<Eip712>,
+    }
+
+    #[public]
+    impl Erc6909FreezableTransferAuthorizationExample {
+        #[allow(clippy::too_many_arguments)]
+        fn transfer_with_authorization(
+            &mut self,
+            owner: Address,
+            to: Address,
+            id: U256,
+            amount: U256,
+            valid_after: U256,
+            valid_before: U256,
+            nonce: B256,
+            signature: Bytes,
+        ) -> Result<(), Vec<u8>> {
+            self.authorization.transfer_with_authorization(
+                owner,
+                to,
+                id,
+                amount,
+                valid_after,
+                valid_before,
+                nonce,
+                &signature,
+                &mut self.erc6909,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc6909FreezableTransferAuthorizationExample {}
+
+    /// Smart contract wallet accepting a single signature.
+    #[storage]
+    struct MockErc1271Wallet {
+        signature: StorageBytes,
+    }
+
+    #[public]
+    impl MockErc1271Wallet {
+        fn is_valid_signature(
+            &self,
+            _hash: B256,
+            signature: Bytes,
+        ) -> FixedBytes<4> {
+            if signature.0 == self.signature.get_bytes() {
+                ERC1271_MAGIC_VALUE.into()
+            } else {
+                FixedBytes::ZERO
+            }
+        }
+    }
+
+    impl MockErc1271Wallet {
+        fn accept(&mut self, signature: &[u8]) {
+            self.signature.set_bytes(signature);
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockErc1271Wallet {}
+
+    fn now() -> U256 {
+        U256::from(block::timestamp())
+    }
+
+    fn init(
+        contract: &Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(alice, TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+        });
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909TransferAuthorizationExample as IErc6909TransferAuthorization>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xd45daee0");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn authorization_state_is_false_by_default(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+    ) {
+        assert!(!contract.sender(alice).authorization_state(alice, NONCE));
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_reverts_when_not_yet_valid(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let valid_after = now();
+        let err = contract
+            .sender(bob)
+            .transfer_with_authorization(
+                alice,
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                valid_after,
+                U256::MAX,
+                NONCE,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should not be valid yet");
+
+        assert!(matches!(
+            err,
+            Error::AuthorizationNotYetValid(ERC6909AuthorizationNotYetValid {
+                valid_after: v
+            }) if v == valid_after
+        ));
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_reverts_when_expired(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        let valid_before = now();
+        let err = contract
+            .sender(bob)
+            .transfer_with_authorization(
+                alice,
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::ZERO,
+                valid_before,
+                NONCE,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should be expired");
+
+        assert!(matches!(
+            err,
+            Error::AuthorizationExpired(ERC6909AuthorizationExpired {
+                valid_before: v
+            }) if v == valid_before
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_reverts_when_nonce_used(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+        contract.init(alice, |contract| {
+            contract
+                .authorization
+                .authorization_states
+                .setter(alice)
+                .setter(NONCE)
+                .set(true);
+        });
+
+        let err = contract
+            .sender(bob)
+            .transfer_with_authorization(
+                alice,
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::ZERO,
+                U256::MAX,
+                NONCE,
+                Bytes::default(),
+            )
+            .motsu_expect_err("should not replay an authorization");
+
+        assert!(matches!(
+            err,
+            Error::AuthorizationUsed(ERC6909AuthorizationUsed {
+                authorizer,
+                nonce
+            }) if authorizer == alice && nonce == NONCE
+        ));
+        assert!(contract.sender(alice).authorization_state(alice, NONCE));
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_reverts_when_signature_length_invalid(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, alice);
+
+        for length in [0, 64, 66] {
+            let err = contract
+                .sender(bob)
+                .transfer_with_authorization(
+                    alice,
+                    bob,
+                    TOKEN_ID,
+                    AMOUNT,
+                    U256::ZERO,
+                    U256::MAX,
+                    NONCE,
+                    vec![0; length].into(),
+                )
+                .motsu_expect_err("should reject the signature");
+
+            assert!(matches!(
+                err,
+                Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                    length: l
+                }) if l == U256::from(length)
+            ));
+        }
+        assert!(!contract.sender(alice).authorization_state(alice, NONCE));
+    }
+
+    #[motsu::test]
+    fn cancel_authorization_reverts_when_nonce_used(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .authorization
+                .authorization_states
+                .setter(alice)
+                .setter(NONCE)
+                .set(true);
+        });
+
+        let err = contract
+            .sender(alice)
+            .cancel_authorization(alice, NONCE, Bytes::default())
+            .motsu_expect_err("should not cancel a used authorization");
+
+        assert!(matches!(
+            err,
+            Error::AuthorizationUsed(ERC6909AuthorizationUsed {
+                authorizer,
+                nonce
+            }) if authorizer == alice && nonce == NONCE
+        ));
+    }
+
+    #[motsu::test]
+    fn cancel_authorization_reverts_when_signature_length_invalid(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .cancel_authorization(alice, NONCE, vec![0; 64].into())
+            .motsu_expect_err("should reject the signature");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                length
+            }) if length == uint!(64_U256)
+        ));
+        assert!(!contract.sender(alice).authorization_state(alice, NONCE));
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_accepts_signature_validated_by_wallet(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(wallet.address(), TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+        });
+        wallet.sender(alice).accept(b"wallet signature");
+
+        contract
+            .sender(bob)
+            .transfer_with_authorization(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::ZERO,
+                U256::MAX,
+                NONCE,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect("should accept the wallet's signature");
+
+        assert!(contract
+            .sender(alice)
+            .erc6909
+            .balance_of(wallet.address(), TOKEN_ID)
+            .is_zero());
+        assert_eq!(
+            AMOUNT,
+            contract.sender(alice).erc6909.balance_of(bob, TOKEN_ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .authorization_state(wallet.address(), NONCE));
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_runs_extension_hooks(
+        contract: Contract<Erc6909FreezableTransferAuthorizationExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(wallet.address(), TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+            contract.erc6909._freeze(wallet.address(), TOKEN_ID);
+        });
+        wallet.sender(alice).accept(b"wallet signature");
+
+        let err = contract
+            .sender(bob)
+            .transfer_with_authorization(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::ZERO,
+                U256::MAX,
+                NONCE,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect_err("should run the freeze hook");
+
+        let expected: Vec<u8> =
+            freezable::Error::FrozenAccount(freezable::ERC6909FrozenAccount {
+                account: wallet.address(),
+                id: TOKEN_ID,
+            })
+            .into();
+        assert_eq!(expected, err);
+        assert_eq!(
+            AMOUNT,
+            contract
+                .sender(alice)
+                .erc6909
+                .balance_of(wallet.address(), TOKEN_ID)
+        );
+        assert!(contract
+            .sender(alice)
+            .erc6909
+            .balance_of(bob, TOKEN_ID)
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn transfer_with_authorization_reverts_when_wallet_rejects_signature(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract
+                .erc6909
+                ._mint(wallet.address(), TOKEN_ID, AMOUNT)
+                .motsu_expect("should mint tokens");
+        });
+        wallet.sender(alice).accept(b"wallet signature");
+
+        let err = contract
+            .sender(bob)
+            .transfer_with_authorization(
+                wallet.address(),
+                bob,
+                TOKEN_ID,
+                AMOUNT,
+                U256::ZERO,
+                U256::MAX,
+                NONCE,
+                b"forged signature".to_vec().into(),
+            )
+            .motsu_expect_err("should reject the signature");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSignatureLength(ERC6909InvalidSignatureLength {
+                length
+            }) if length == uint!(16_U256)
+        ));
+        assert_eq!(
+            AMOUNT,
+            contract
+                .sender(alice)
+                .erc6909
+                .balance_of(wallet.address(), TOKEN_ID)
+        );
+        assert!(!contract
+            .sender(alice)
+            .authorization_state(wallet.address(), NONCE));
+    }
+
+    #[motsu::test]
+    fn cancel_authorization_accepts_signature_validated_by_wallet(
+        contract: Contract<Erc6909TransferAuthorizationExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+    ) {
+        wallet.sender(alice).accept(b"wallet signature");
+
+        contract
+            .sender(alice)
+            .cancel_authorization(
+                wallet.address(),
+                NONCE,
+                b"wallet signature".to_vec().into(),
+            )
+            .motsu_expect("should accept the wallet's signature");
+
+        assert!(contract
+            .sender(alice)
+            .authorization_state(wallet.address(), NONCE));
+    }
+}
@@ -0,0 +1,677 @@
+//! Extension of ERC-6909 that enforces a minimum delay between the outgoing
+//! transfers of an account in a specific token id.
+//!
+//! Each token id can be given a transfer cooldown, in seconds. Once an
+//! account has transferred tokens of that id, it can't transfer them again
+//! until the cooldown has elapsed, which damps bot-driven churn in e.g. game
+//! economies. Mints and burns are not subject to the cooldown.
+//!
+//! Cooldowns are measured with the [`Clock`] of the contract, the block
+//! timestamp by default, or the L2 block number with [`BlockNumberClock`],
+//! in which case cooldowns are expressed in L2 blocks rather than in
+//! seconds.
+//!
+//! [`BlockNumberClock`]: crate::utils::clock::BlockNumberClock
+//!
+//! Note that [`Erc6909TransferCooldown::_set_transfer_cooldown`] is not
+//! exposed by default. You should expose it manually in your contract's abi,
+//! guarded by proper access control.
+
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm, msg,
+    prelude::*,
+    storage::{StorageMap, StorageU64},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        clock::{Clock, TimestampClock},
+        introspection::erc165::IErc165,
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the transfer cooldown of token `id` changes to
+        /// `cooldown`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `cooldown` - Minimum delay between transfers, in seconds.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TransferCooldownSet(uint256 indexed id, uint64 cooldown);
+    }
+
+    sol! {
+        /// Indicates that `account` can't transfer tokens of type `id` before
+        /// `allowed_at`.
+        ///
+        /// * `account` - Account sending the tokens.
+        /// * `id` - Token id as a number.
+        /// * `allowed_at` - Timestamp from which `account` can transfer again.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TransferCooldownActive(
+            address account,
+            uint256 id,
+            uint64 allowed_at
+        );
+    }
+}
+
+/// An [`Erc6909TransferCooldown`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that an account can't transfer a token id yet.
+    TransferCooldownActive(ERC6909TransferCooldownActive),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909TransferCooldown`] contract.
+#[storage]
+pub struct Erc6909TransferCooldown<C: Clock = TimestampClock> {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to the minimum delay between transfers, in
+    /// seconds.
+    pub(crate) transfer_cooldowns: StorageMap<U256, StorageU64>,
+    /// Mapping from account to token id to the timestamp of its last
+    /// transfer.
+    pub(crate) last_transfers:
+        StorageMap<Address, StorageMap<U256, StorageU64>>,
+    /// [`Clock`] measuring cooldowns, the block timestamp by default.
+    pub(crate) clock: PhantomData<C>,
+}
+
+impl_as_erc6909!(Erc6909TransferCooldown<C: Clock>, erc6909);
+
+/// Required interface of an [`Erc6909TransferCooldown`] contract.
+#[interface_id]
+pub trait IErc6909TransferCooldown: IErc165 {
+    /// Returns the minimum delay between transfers of token `id`, in seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn transfer_cooldown(&self, id: U256) -> U64;
+
+    /// Returns the timestamp from which `account` can transfer tokens of type
+    /// `id` again.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account sending the tokens.
+    /// * `id` - Token id as a number.
+    fn next_transfer_allowed_at(&self, account: Address, id: U256) -> U64;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909TransferCooldown, IErc165)]
+impl<C: Clock> Erc6909TransferCooldown<C> {}
+
+#[public]
+impl<C: Clock> IErc6909TransferCooldown for Erc6909TransferCooldown<C> {
+    fn transfer_cooldown(&self, id: U256) -> U64 {
+        self.transfer_cooldowns.get(id)
+    }
+
+    fn next_transfer_allowed_at(&self, account: Address, id: U256) -> U64 {
+        self.last_transfers
+            .get(account)
+            .get(id)
+            .saturating_add(self.transfer_cooldown(id))
+    }
+}
+
+#[public]
+impl<C: Clock> IErc6909 for Erc6909TransferCooldown<C> {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl<C: Clock> IErc165 for Erc6909TransferCooldown<C> {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferCooldown>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl<C: Clock> Erc6909TransferCooldown<C> {
+    /// Sets the minimum delay between transfers of token `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// Setting `cooldown` to zero removes the cooldown of `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `cooldown` - Minimum delay between transfers, in seconds.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferCooldownSet`].
+    pub fn _set_transfer_cooldown(&mut self, id: U256, cooldown: U64) {
+        self.transfer_cooldowns.setter(id).set(cooldown);
+        evm::log(TransferCooldownSet { id, cooldown: cooldown.to() });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl<C: Clock> Erc6909TransferCooldown<C> {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that prevents `from` from
+    /// transferring tokens of any of `ids` before their cooldown has elapsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::TransferCooldownActive`] - If the cooldown of `from` in one
+    ///   of `ids` hasn't elapsed.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if !from.is_zero() && !to.is_zero() {
+            let now = C::now().to::<U64>();
+            for &id in &ids {
+                if self.transfer_cooldown(id).is_zero() {
+                    continue;
+                }
+
+                let allowed_at = self.next_transfer_allowed_at(from, id);
+                if now < allowed_at {
+                    return Err(Error::TransferCooldownActive(
+                        ERC6909TransferCooldownActive {
+                            account: from,
+                            id,
+                            allowed_at: allowed_at.to(),
+                        },
+                    ));
+                }
+                self.last_transfers.setter(from).setter(id).set(now);
+            }
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+}
+
+impl<C: Clock> IErc6909Internal for Erc6909TransferCooldown<C> {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::block;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Erc6909TransferCooldown {}
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const COOLDOWN: U64 = uint!(60_U64);
+
+    fn mint_with_cooldown(
+        contract: &Contract<Erc6909TransferCooldown>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(alice, vec![ID, OTHER_ID], vec![AMOUNT, AMOUNT])
+            .motsu_expect("should mint tokens");
+        contract.sender(alice)._set_transfer_cooldown(ID, COOLDOWN);
+    }
+
+    #[motsu::test]
+    fn set_transfer_cooldown_emits_event(
+        contract: Contract<Erc6909TransferCooldown>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_transfer_cooldown(ID, COOLDOWN);
+
+        assert_eq!(COOLDOWN, contract.sender(alice).transfer_cooldown(ID));
+        assert!(contract.sender(alice).transfer_cooldown(OTHER_ID).is_zero());
+        contract.assert_emitted(&TransferCooldownSet {
+            id: ID,
+            cooldown: COOLDOWN.to(),
+        });
+    }
+
+    #[motsu::test]
+    fn transfer_starts_cooldown(
+        contract: Contract<Erc6909TransferCooldown>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_with_cooldown(&contract, alice);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, U256::ONE)
+            .motsu_expect("should transfer tokens");
+
+        let allowed_at = U64::from(block::timestamp()) + COOLDOWN;
+        assert_eq!(
+            allowed_at,
+            contract.sender(alice).next_transfer_allowed_at(alice, ID)
+        );
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, ID, U256::ONE)
+            .motsu_expect_err("should not transfer during cooldown");
+
+        assert!(matches!(
+            err,
+            Error::TransferCooldownActive(ERC6909TransferCooldownActive {
+                account,
+                id,
+                allowed_at: at,
+            }) if account == alice && id == ID && U64::from(at) == allowed_at
+        ));
+
+        contract
+            .sender(alice)
+            .transfer(bob, OTHER_ID, U256::ONE)
+            .motsu_expect("should transfer tokens without cooldown");
+        contract
+            .sender(bob)
+            .transfer(alice, ID, U256::ONE)
+            .motsu_expect("receiver should not be in cooldown");
+    }
+
+    #[motsu::test]
+    fn transfer_from_applies_cooldown_of_sender(
+        contract: Contract<Erc6909TransferCooldown>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_with_cooldown(&contract, alice);
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .motsu_expect("should set Bob as operator");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, U256::ONE)
+            .motsu_expect("should transfer tokens");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, ID, U256::ONE)
+            .motsu_expect_err("should not transfer during cooldown");
+
+        assert!(matches!(
+            err,
+            Error::TransferCooldownActive(ERC6909TransferCooldownActive {
+                account,
+                ..
+            }) if account == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_succeeds_after_cooldown(
+        contract: Contract<Erc6909TransferCooldown>,
+        alice: Address,
+        bob: Address,
+    ) {
+        mint_with_cooldown(&contract, alice);
+        contract
+            .sender(alice)
+            .transfer(bob, ID, U256::ONE)
+            .motsu_expect("should transfer tokens");
+
+        // Move the last transfer back in time, past the cooldown.
+        let elapsed = U64::from(block::timestamp()) - COOLDOWN;
+        contract
+            .sender(alice)
+            .last_transfers
+            .setter(alice)
+            .setter(ID)
+            .set(elapsed);
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, U256::ONE)
+            .motsu_expect("should transfer tokens after cooldown");
+    }
+
+    #[motsu::test]
+    fn mint_and_burn_ignore_cooldown(
+        contract: Contract<Erc6909TransferCooldown>,
+        alice: Address,
+    ) {
+        mint_with_cooldown(&contract, alice);
+
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract
+            .sender(alice)
+            ._burn(alice, ID, AMOUNT)
+            .motsu_expect("should burn tokens");
+        contract
+            .sender(alice)
+            ._burn(alice, ID, U256::ONE)
+            .motsu_expect("should burn tokens again");
+
+        let allowed_at =
+            contract.sender(alice).next_transfer_allowed_at(alice, ID);
+        assert!(allowed_at <= U64::from(block::timestamp()));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909TransferCooldown as IErc6909TransferCooldown>::interface_id(
+            );
+        let expected: FixedBytes<4> = fixed_bytes!("0x57f0943b");
+        assert_eq!(actual, expected);
+    }
+}
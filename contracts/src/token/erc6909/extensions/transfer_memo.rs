@@ -0,0 +1,436 @@
+//! Extension of ERC-6909 that attaches a memo to transfers, e.g. the
+//! reference a payment processor uses to reconcile a payment with an invoice.
+//!
+//! [`IErc6909TransferMemo::transfer_with_memo`] performs a regular transfer,
+//! and emits a [`TransferMemo`] event along with [`erc6909::TransferSingle`].
+//! The memo is not stored by the contract.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{evm, msg, prelude::*};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, Erc6909, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `amount` tokens of type `id` are transferred from
+        /// `from` to `to` with `memo` attached.
+        ///
+        /// * `from` - Account the tokens are taken from.
+        /// * `to` - Account receiving the tokens.
+        /// * `id` - Token id as a number.
+        /// * `amount` - Amount of tokens transferred.
+        /// * `memo` - Reference attached to the transfer.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TransferMemo(
+            address indexed from,
+            address indexed to,
+            uint256 id,
+            uint256 amount,
+            bytes32 indexed memo
+        );
+    }
+}
+
+/// State of an [`Erc6909TransferMemo`] contract.
+#[storage]
+pub struct Erc6909TransferMemo {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+}
+
+impl_as_erc6909!(Erc6909TransferMemo, erc6909);
+
+/// Required interface of an [`Erc6909TransferMemo`] contract.
+#[interface_id]
+pub trait IErc6909TransferMemo: IErc165 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Transfers `amount` tokens of type `id` from the caller to `receiver`,
+    /// attaching `memo` to the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `memo` - Reference attached to the transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc6909::Error::InvalidReceiver`] - If `receiver` is
+    ///   [`Address::ZERO`].
+    /// * [`erc6909::Error::InsufficientBalance`] - If the caller owns fewer
+    ///   than `amount` tokens of type `id`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`TransferMemo`].
+    fn transfer_with_memo(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        memo: FixedBytes<32>,
+    ) -> Result<bool, Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = erc6909::Error>,
+    IErc6909TransferMemo<Error = erc6909::Error>,
+    IErc165
+)]
+impl Erc6909TransferMemo {}
+
+#[public]
+impl IErc6909TransferMemo for Erc6909TransferMemo {
+    type Error = erc6909::Error;
+
+    fn transfer_with_memo(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+        memo: FixedBytes<32>,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer_with_memo(sender, receiver, id, amount, memo)?;
+        Ok(true)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909TransferMemo {
+    type Error = erc6909::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.transfer(receiver, id, amount)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.transfer_from(sender, receiver, id, amount)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909TransferMemo {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferMemo>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909TransferMemo {
+    /// Moves `amount` tokens of type `id` from `from` to `to`, attaching
+    /// `memo` to the transfer.
+    ///
+    /// Internal function that doesn't check the approval of `from`, e.g. to
+    /// build a `transfer_from` variant with a memo on top of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account the tokens are taken from.
+    /// * `to` - Account receiving the tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    /// * `memo` - Reference attached to the transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc6909::Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`erc6909::Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    /// * [`erc6909::Error::InsufficientBalance`] - If `amount` is greater than
+    ///   the balance of `from`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`].
+    /// * [`TransferMemo`].
+    pub fn _transfer_with_memo(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        memo: FixedBytes<32>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._transfer(from, to, id, amount)?;
+        evm::log(TransferMemo { from, to, id, amount, memo });
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._mint(to, id, amount)
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._mint_batch(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._burn(from, id, amount)
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._burn_batch(from, ids, amounts)
+    }
+}
+
+impl IErc6909Internal for Erc6909TransferMemo {
+    type Error = erc6909::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256,
+    };
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::{Error, TransferSingle};
+
+    unsafe impl TopLevelStorage for Erc6909TransferMemo {}
+
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+    const MEMO: B256 = fixed_bytes!(
+        "0x00000000000000000000000000000000000000000000000000000000000004d2"
+    );
+
+    #[motsu::test]
+    fn transfer_with_memo_emits_memo(
+        contract: Contract<Erc6909TransferMemo>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let transferred = contract
+            .sender(alice)
+            .transfer_with_memo(bob, ID, AMOUNT, MEMO)
+            .motsu_expect("should transfer with memo");
+
+        assert!(transferred);
+        assert!(contract.sender(alice).balance_of(alice, ID).is_zero());
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(bob, ID));
+        contract.assert_emitted(&TransferSingle {
+            caller: alice,
+            from: alice,
+            to: bob,
+            id: ID,
+            amount: AMOUNT,
+        });
+        contract.assert_emitted(&TransferMemo {
+            from: alice,
+            to: bob,
+            id: ID,
+            amount: AMOUNT,
+            memo: MEMO,
+        });
+    }
+
+    #[motsu::test]
+    fn transfer_with_memo_reverts_on_insufficient_balance(
+        contract: Contract<Erc6909TransferMemo>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .transfer_with_memo(bob, ID, AMOUNT, MEMO)
+            .motsu_expect_err("should not transfer more than balance");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[cfg(not(feature = "burn-on-zero-transfer"))]
+    #[motsu::test]
+    fn transfer_with_memo_reverts_on_zero_receiver(
+        contract: Contract<Erc6909TransferMemo>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(alice)
+            .transfer_with_memo(Address::ZERO, ID, AMOUNT, MEMO)
+            .motsu_expect_err("should not transfer to zero address");
+
+        assert!(matches!(err, Error::InvalidReceiver(_)));
+        assert_eq!(AMOUNT, contract.sender(alice).balance_of(alice, ID));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909TransferMemo as IErc6909TransferMemo>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x734cc353");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909TransferMemo>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferMemo as IErc6909TransferMemo>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferMemo as IErc6909>::interface_id()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
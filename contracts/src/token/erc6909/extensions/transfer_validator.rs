@@ -0,0 +1,689 @@
+//! Extension of ERC-6909 that gates the movements of a token id behind an
+//! external transfer validator contract.
+//!
+//! A validator can be registered for each token id. Before tokens of that id
+//! are minted, transferred or burned, the validator's
+//! [`IErc6909TransferValidator::validate_transfer`] function is called, and
+//! the operation reverts unless it returns
+//! [`VALIDATE_TRANSFER_FN_SELECTOR`]. This allows compliance rules to evolve
+//! by registering a new validator, without upgrading the token itself.
+//!
+//! Note that [`Erc6909TransferValidation::_set_transfer_validator`] is not
+//! exposed by default. You should expose it manually in your contract's abi,
+//! guarded by proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    evm, function_selector, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageMap},
+};
+pub use validator::IErc6909TransferValidator;
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, ERC6909InvalidApprover,
+        ERC6909InvalidApproverForId, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// The expected value returned from
+/// [`IErc6909TransferValidator::validate_transfer`].
+pub const VALIDATE_TRANSFER_FN_SELECTOR: [u8; 4] = function_selector!(
+    "validateTransfer",
+    Address,
+    Address,
+    Address,
+    U256,
+    U256
+);
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `validator` is registered as the transfer validator
+        /// of token `id`.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `validator` - Address of the validator, or [`Address::ZERO`]
+        ///   if transfers of `id` are no longer validated.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event TransferValidatorSet(uint256 indexed id, address validator);
+    }
+
+    sol! {
+        /// Indicates that the transfer validator of token `id` rejected the
+        /// movement of its tokens.
+        ///
+        /// * `validator` - Address of the transfer validator.
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TransferRejected(address validator, uint256 id);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[allow(missing_docs)]
+mod validator {
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the contracts validating the movements of ERC-6909
+        /// tokens on behalf of an [`super::Erc6909TransferValidation`]
+        /// contract.
+        interface IErc6909TransferValidator {
+            /// Validates the movement of `amount` tokens of type `id` from
+            /// `from` to `to`, initiated by `caller`.
+            ///
+            /// NOTE: To accept the movement, this must return
+            /// [`super::VALIDATE_TRANSFER_FN_SELECTOR`].
+            ///
+            /// # Arguments
+            ///
+            /// * `caller` - The address which initiated the movement.
+            /// * `from` - The address tokens are moved from, or the zero
+            ///   address for mints.
+            /// * `to` - The address tokens are moved to, or the zero address
+            ///   for burns.
+            /// * `id` - Token id as a number.
+            /// * `amount` - The amount of tokens being moved.
+            #[allow(missing_docs)]
+            function validateTransfer(
+                address caller,
+                address from,
+                address to,
+                uint256 id,
+                uint256 amount
+            ) external returns (bytes4);
+        }
+    }
+}
+
+/// An [`Erc6909TransferValidation`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that the transfer validator of a token id rejected the
+    /// movement of its tokens.
+    TransferRejected(ERC6909TransferRejected),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909TransferValidation`] contract.
+#[storage]
+pub struct Erc6909TransferValidation {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to the address of its transfer validator.
+    pub(crate) transfer_validators: StorageMap<U256, StorageAddress>,
+}
+
+impl_as_erc6909!(Erc6909TransferValidation, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc6909TransferValidation {}
+
+/// Required interface of an [`Erc6909TransferValidation`] contract.
+#[interface_id]
+pub trait IErc6909TransferValidation: IErc165 {
+    /// Returns the address of the transfer validator of token `id`, or
+    /// [`Address::ZERO`] if movements of `id` are not validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn transfer_validator(&self, id: U256) -> Address;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909TransferValidation, IErc165)]
+impl Erc6909TransferValidation {}
+
+#[public]
+impl IErc6909TransferValidation for Erc6909TransferValidation {
+    fn transfer_validator(&self, id: U256) -> Address {
+        self.transfer_validators.get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909TransferValidation {
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909TransferValidation {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferValidation>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909TransferValidation {
+    /// Registers `validator` as the transfer validator of token `id`.
+    /// Setting [`Address::ZERO`] disables the validation of `id`.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `validator` - Address of the transfer validator.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferValidatorSet`].
+    pub fn _set_transfer_validator(&mut self, id: U256, validator: Address) {
+        self.transfer_validators.setter(id).set(validator);
+        evm::log(TransferValidatorSet { id, validator });
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909TransferValidation {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that asks the transfer
+    /// validator of each token id, if any, to approve the movement of its
+    /// tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::TransferRejected`] - If a transfer validator reverts, or
+    ///   doesn't return [`VALIDATE_TRANSFER_FN_SELECTOR`].
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        erc6909::lib::require_equal_arrays_length(&ids, &amounts)?;
+
+        let caller = msg::sender();
+        for (&id, &amount) in ids.iter().zip(amounts.iter()) {
+            self._validate_transfer(caller, from, to, id, amount)?;
+        }
+
+        Ok(self.erc6909._update(from, to, ids, amounts)?)
+    }
+
+    /// Calls [`IErc6909TransferValidator::validate_transfer`] on the transfer
+    /// validator of token `id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `caller` - Account initiating the movement.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::TransferRejected`] - If the transfer validator reverts, or
+    ///   doesn't return [`VALIDATE_TRANSFER_FN_SELECTOR`].
+    fn _validate_transfer(
+        &mut self,
+        caller: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let validator = self.transfer_validators.get(id);
+        if validator.is_zero() {
+            return Ok(());
+        }
+
+        let result = IErc6909TransferValidator::new(validator)
+            .validate_transfer(
+                Call::new_in(self),
+                caller,
+                from,
+                to,
+                id,
+                amount,
+            );
+
+        match result {
+            Ok(magic) if magic == VALIDATE_TRANSFER_FN_SELECTOR => Ok(()),
+            _ => Err(Error::TransferRejected(ERC6909TransferRejected {
+                validator,
+                id,
+            })),
+        }
+    }
+}
+
+impl IErc6909Internal for Erc6909TransferValidation {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::storage::StorageU256;
+
+    use super::*;
+
+    const ID: U256 = uint!(1_U256);
+    const REJECTED_ID: U256 = uint!(2_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    #[storage]
+    struct MockTransferValidator {
+        rejected_id: StorageU256,
+    }
+
+    #[public]
+    impl MockTransferValidator {
+        #[constructor]
+        fn constructor(&mut self, rejected_id: U256) {
+            self.rejected_id.set(rejected_id);
+        }
+
+        fn validate_transfer(
+            &mut self,
+            _caller: Address,
+            _from: Address,
+            _to: Address,
+            id: U256,
+            _amount: U256,
+        ) -> FixedBytes<4> {
+            if id == self.rejected_id.get() {
+                FixedBytes::ZERO
+            } else {
+                VALIDATE_TRANSFER_FN_SELECTOR.into()
+            }
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockTransferValidator {}
+
+    #[motsu::test]
+    fn set_transfer_validator_emits_event(
+        contract: Contract<Erc6909TransferValidation>,
+        validator: Contract<MockTransferValidator>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_transfer_validator(ID, validator.address());
+
+        assert_eq!(
+            contract.sender(alice).transfer_validator(ID),
+            validator.address()
+        );
+        contract.assert_emitted(&TransferValidatorSet {
+            id: ID,
+            validator: validator.address(),
+        });
+    }
+
+    #[motsu::test]
+    fn transfer_succeeds_when_validated(
+        contract: Contract<Erc6909TransferValidation>,
+        validator: Contract<MockTransferValidator>,
+        alice: Address,
+        bob: Address,
+    ) {
+        validator.sender(alice).constructor(REJECTED_ID);
+        contract.sender(alice)._set_transfer_validator(ID, validator.address());
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint validated tokens");
+
+        contract
+            .sender(alice)
+            .transfer(bob, ID, AMOUNT)
+            .motsu_expect("should transfer validated tokens");
+
+        assert_eq!(contract.sender(alice).balance_of(bob, ID), AMOUNT);
+    }
+
+    #[motsu::test]
+    fn transfer_reverts_when_rejected(
+        contract: Contract<Erc6909TransferValidation>,
+        validator: Contract<MockTransferValidator>,
+        alice: Address,
+        bob: Address,
+    ) {
+        validator.sender(alice).constructor(REJECTED_ID);
+        contract
+            .sender(alice)
+            ._mint(alice, REJECTED_ID, AMOUNT)
+            .motsu_expect("should mint tokens without validator");
+        contract
+            .sender(alice)
+            ._set_transfer_validator(REJECTED_ID, validator.address());
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, REJECTED_ID, AMOUNT)
+            .motsu_expect_err("should reject transfer");
+
+        assert!(matches!(
+            err,
+            Error::TransferRejected(ERC6909TransferRejected { validator: v, id })
+                if v == validator.address() && id == REJECTED_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_validator_is_not_a_contract(
+        contract: Contract<Erc6909TransferValidation>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice)._set_transfer_validator(ID, bob);
+
+        let err = contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect_err("should reject mint");
+
+        assert!(matches!(err, Error::TransferRejected(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909TransferValidation as IErc6909TransferValidation>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x71a79c7b");
+        assert_eq!(actual, expected);
+    }
+}
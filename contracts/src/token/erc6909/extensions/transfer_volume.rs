@@ -0,0 +1,466 @@
+//! Extension of ERC-6909 that tracks the cumulative volume of each token id,
+//! i.e. the total amount of tokens ever moved.
+//!
+//! Every amount moved by [`Erc6909TransferVolume::_update`], including mints
+//! and burns, is added to the volume of its token id, so that analytics and
+//! reward distributors can read an activity proxy on-chain, without
+//! processing every transfer log.
+//!
+//! The volume only ever grows, and saturates at [`U256::MAX`] instead of
+//! reverting the movement that would overflow it.
+//!
+//! CAUTION: This extension should not be added in an upgrade to an already
+//! deployed contract, as the volume moved before the upgrade isn't counted.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+use stylus_sdk::{
+    msg,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::{
+    token::erc6909::{
+        self, impl_as_erc6909, Erc6909, Error, IErc6909, IErc6909Internal,
+    },
+    utils::introspection::erc165::IErc165,
+};
+
+/// State of an [`Erc6909TransferVolume`] contract.
+#[storage]
+pub struct Erc6909TransferVolume {
+    /// [`Erc6909`] contract.
+    pub erc6909: Erc6909,
+    /// Mapping from token id to the total amount of tokens ever moved.
+    pub(crate) total_volume: StorageMap<U256, StorageU256>,
+}
+
+impl_as_erc6909!(Erc6909TransferVolume, erc6909);
+
+/// Required interface of an [`Erc6909TransferVolume`] contract.
+#[interface_id]
+pub trait IErc6909TransferVolume: IErc165 {
+    /// Returns the total amount of tokens of type `id` ever moved, including
+    /// mints and burns, saturated at [`U256::MAX`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn total_volume(&self, id: U256) -> U256;
+}
+
+#[public]
+#[implements(IErc6909<Error = Error>, IErc6909TransferVolume, IErc165)]
+impl Erc6909TransferVolume {}
+
+#[public]
+impl IErc6909TransferVolume for Erc6909TransferVolume {
+    fn total_volume(&self, id: U256) -> U256 {
+        self.total_volume.get(id)
+    }
+}
+
+#[public]
+impl IErc6909 for Erc6909TransferVolume {
+    type Error = erc6909::Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.approve(spender, id, amount)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        self.erc6909.set_operator(spender, approved)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909TransferVolume {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferVolume>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl Erc6909TransferVolume {
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self._do_burn(from, ids, amounts)
+    }
+}
+
+impl Erc6909TransferVolume {
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if to.is_zero() {
+            return Err(erc6909::Error::InvalidReceiver(
+                erc6909::ERC6909InvalidReceiver { receiver: to },
+            ));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        if from.is_zero() {
+            return Err(erc6909::Error::InvalidSender(
+                erc6909::ERC6909InvalidSender { sender: from },
+            ));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), erc6909::Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that adds the moved amounts
+    /// to the volume of their token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc6909::Error::InvalidArrayLength`] - If length of `ids` is not
+    ///   equal to length of `amounts`.
+    /// * [`erc6909::Error::InsufficientBalance`] - If `amount` is greater than
+    ///   the balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), erc6909::Error> {
+        self.erc6909._update(from, to, ids.clone(), amounts.clone())?;
+
+        for (id, amount) in ids.into_iter().zip(amounts) {
+            let volume = self.total_volume.get(id);
+            self.total_volume.setter(id).set(volume.saturating_add(amount));
+        }
+
+        Ok(())
+    }
+}
+
+impl IErc6909Internal for Erc6909TransferVolume {
+    type Error = erc6909::Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self.erc6909._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+
+    const TOKEN_ID: U256 = uint!(1_U256);
+    const OTHER_TOKEN_ID: U256 = uint!(2_U256);
+
+    unsafe impl TopLevelStorage for Erc6909TransferVolume {}
+
+    #[motsu::test]
+    fn volume_counts_mints_transfers_and_burns(
+        contract: Contract<Erc6909TransferVolume>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).total_volume(TOKEN_ID)
+        );
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, uint!(4_U256))
+            .motsu_expect("should transfer to Bob");
+        assert_eq!(
+            uint!(14_U256),
+            contract.sender(alice).total_volume(TOKEN_ID)
+        );
+
+        contract
+            .sender(alice)
+            ._burn(bob, TOKEN_ID, uint!(4_U256))
+            .motsu_expect("should burn Bob's tokens");
+        assert_eq!(
+            uint!(18_U256),
+            contract.sender(alice).total_volume(TOKEN_ID)
+        );
+
+        assert!(contract.sender(alice).total_volume(OTHER_TOKEN_ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn volume_counts_each_id_of_a_batch(
+        contract: Contract<Erc6909TransferVolume>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint_batch(
+                alice,
+                vec![TOKEN_ID, OTHER_TOKEN_ID, TOKEN_ID],
+                vec![uint!(5_U256), uint!(7_U256), uint!(5_U256)],
+            )
+            .motsu_expect("should mint to Alice");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).total_volume(TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(7_U256),
+            contract.sender(alice).total_volume(OTHER_TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn failed_transfer_keeps_volume(
+        contract: Contract<Erc6909TransferVolume>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, uint!(11_U256))
+            .motsu_expect_err("should not transfer more than the balance");
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, uint!(1_U256))
+            .motsu_expect_err("should not transfer without allowance");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).total_volume(TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn volume_saturates(
+        contract: Contract<Erc6909TransferVolume>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, U256::MAX)
+            .motsu_expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            .transfer(bob, TOKEN_ID, U256::MAX)
+            .motsu_expect("should transfer past the saturated volume");
+
+        assert_eq!(U256::MAX, contract.sender(alice).total_volume(TOKEN_ID));
+        assert_eq!(U256::MAX, contract.sender(alice).balance_of(bob, TOKEN_ID));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <Erc6909TransferVolume as IErc6909TransferVolume>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x67ae5133");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(
+        contract: Contract<Erc6909TransferVolume>,
+        alice: Address,
+    ) {
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferVolume as IErc6909TransferVolume>::interface_id()
+        ));
+        assert!(contract.sender(alice).supports_interface(
+            <Erc6909TransferVolume as IErc6909>::interface_id()
+        ));
+    }
+}
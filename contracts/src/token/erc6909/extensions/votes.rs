@@ -0,0 +1,1832 @@
+//! Extension of ERC-6909 to support voting and delegation, as in ERC-5805,
+//! with separate voting power for each token id.
+//!
+//! Only the token ids registered as voting ids carry voting power. Each unit
+//! of a voting id held by an account counts as one vote of that id, which
+//! is credited to the account's delegate. An account delegates all its
+//! voting ids at once, and has to delegate to itself for its own votes to
+//! be counted.
+//!
+//! Each voting id is registered with a weight, so that governance power can
+//! aggregate across ids: with id `1` weighted `1` and id `2` weighted `10`,
+//! a unit of id `2` counts as much as ten units of id `1` in the aggregated
+//! votes of [`IErc6909Votes::get_weighted_votes`].
+//!
+//! Aggregated votes are not checkpointed themselves. They are computed on
+//! read as the weighted sum of the checkpoints of every voting id, i.e. one
+//! checkpoint read per voting id for the current votes, and one binary
+//! search over the history of each voting id for past votes. Their gas cost
+//! thus grows linearly with the number of voting ids, which is bounded by
+//! [`MAX_VOTING_IDS`], while transfers and delegations cost the same as with
+//! unweighted votes.
+//!
+//! The history of each delegate's votes and of each id's total supply is
+//! checkpointed by the [`Clock`] of the contract, so that they can be
+//! looked up at past timepoints, e.g. by a governor at the snapshot of a
+//! proposal. The clock is the block timestamp by default, can be switched
+//! to the L2 block number by passing [`BlockNumberClock`] as the `C`
+//! parameter of [`Erc6909Votes`], and is exposed with [`IErc6372`].
+//!
+//! [`BlockNumberClock`]: crate::utils::clock::BlockNumberClock
+//!
+//! Delegation can also be made gasless with [`Erc6909Votes::delegate_by_sig`],
+//! given a delegator's EIP-712 signature, by contracts exposing
+//! [`IErc6909DelegateBySig`] with [`Nonces`] and an [`IEip712`] domain.
+//!
+//! Note that [`Erc6909Votes::_add_voting_id`] is not exposed by default. You
+//! should expose it manually in your contract's abi, guarded by proper
+//! access control.
+
+use alloc::{string::String, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use alloy_primitives::{
+    aliases::{U208, U48},
+    keccak256, Address, FixedBytes, B256, U256, U32, U8,
+};
+use alloy_sol_types::SolType;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    evm, function_selector, msg,
+    prelude::*,
+    storage::{
+        StorageAddress, StorageMap, StorageU256, StorageU32, StorageVec,
+    },
+};
+
+use crate::{
+    token::erc6909::{
+        self,
+        extensions::supply::{self, ERC6909InsufficientSupply},
+        impl_as_erc6909, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+        ERC6909InvalidArrayLength, ERC6909InvalidReceiver,
+        ERC6909InvalidSender, ERC6909InvalidSpender,
+        ERC6909InvalidSpenderForId, ERC6909SelfTarget, Erc6909,
+        Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+        Erc6909InsufficientPermission, IErc6909, IErc6909Internal,
+    },
+    utils::{
+        clock::{Clock, IErc6372, TimestampClock},
+        cryptography::{
+            ecdsa::{self, ECDSAInvalidSignature, ECDSAInvalidSignatureS},
+            eip712::IEip712,
+        },
+        introspection::erc165::IErc165,
+        nonces::{self, INonces, InvalidAccountNonce, Nonces},
+        structs::checkpoints::{
+            self, CheckpointUnorderedInsertion, Trace, S208,
+        },
+    },
+};
+
+const DELEGATION_TYPEHASH: [u8; 32] = keccak_const::Keccak256::new()
+    .update(b"Delegation(address delegatee,uint256 nonce,uint256 expiry)")
+    .finalize();
+
+/// Maximum number of voting ids of an [`Erc6909Votes`] contract, bounding
+/// the cost of a delegation.
+pub const MAX_VOTING_IDS: usize = 16;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    pub(crate) type DelegationStructHashTuple = sol! {
+        tuple(bytes32, address, uint256, uint256)
+    };
+
+    sol! {
+        /// Emitted when `delegator` changes its delegate from `from_delegate`
+        /// to `to_delegate`.
+        ///
+        /// * `delegator` - Account whose votes are delegated.
+        /// * `from_delegate` - Previous delegate of `delegator`.
+        /// * `to_delegate` - New delegate of `delegator`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DelegateChanged(
+            address indexed delegator,
+            address indexed from_delegate,
+            address indexed to_delegate
+        );
+
+        /// Emitted when the votes of `delegate` for token `id` change from
+        /// `previous_votes` to `new_votes`.
+        ///
+        /// * `delegate` - Account whose votes change.
+        /// * `id` - Voting id as a number.
+        /// * `previous_votes` - Votes of `delegate` before the change.
+        /// * `new_votes` - Votes of `delegate` after the change.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event DelegateVotesChanged(
+            address indexed delegate,
+            uint256 indexed id,
+            uint256 previous_votes,
+            uint256 new_votes
+        );
+
+        /// Emitted when token `id` is registered as a voting id, counting
+        /// `weight` votes per unit in aggregated votes.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `weight` - Weight of each unit of `id`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event VotingIdAdded(uint256 indexed id, uint32 weight);
+    }
+
+    sol! {
+        /// Indicates that `timepoint` was looked up, although it is not yet
+        /// in the past of the current `clock`.
+        ///
+        /// * `timepoint` - Timepoint looked up.
+        /// * `clock` - Current clock.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC5805FutureLookup(uint256 timepoint, uint48 clock);
+
+        /// Indicates that the delegation signature expired at `expiry`.
+        ///
+        /// * `expiry` - Timestamp after which the signature is invalid.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error VotesExpiredSignature(uint256 expiry);
+
+        /// Indicates that the total supply of token `id` would reach
+        /// `increased_supply`, more than the `cap` that can be checkpointed.
+        ///
+        /// * `id` - Token id as a number.
+        /// * `increased_supply` - Total supply after the mint.
+        /// * `cap` - Maximum total supply of a token id.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909ExceededSafeSupply(
+            uint256 id,
+            uint256 increased_supply,
+            uint256 cap
+        );
+
+        /// Indicates that token `id` can't be registered as a voting id,
+        /// because it already is one, some of its tokens were minted, or its
+        /// weight is zero.
+        ///
+        /// * `id` - Token id as a number.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909InvalidVotingId(uint256 id);
+
+        /// Indicates that no more than `max` voting ids can be registered.
+        ///
+        /// * `max` - Maximum number of voting ids.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC6909TooManyVotingIds(uint256 max);
+    }
+}
+
+/// An [`Erc6909Votes`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates an owner's token balance is insufficient
+    InsufficientBalance(Erc6909InsufficientBalance),
+    /// Indicates the spender does not have permission to spend the token.
+    InsufficientPermission(Erc6909InsufficientPermission),
+    /// Indicates a spender's token allowance is insufficient
+    InsufficientAllowance(Erc6909InsufficientAllowance),
+    /// Indicates the approver is invalid.
+    InvalidApprover(ERC6909InvalidApprover),
+    /// Indicates the sender is invalid.
+    InvalidSender(ERC6909InvalidSender),
+    /// Indicates the sender is invalid.
+    InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
+    /// Indicates the receiver is invalid.
+    InvalidReceiver(ERC6909InvalidReceiver),
+    /// Indicates an array length mismatch between token ids and values in a
+    /// batch operation.
+    InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
+    /// Indicates that a timepoint was looked up, although it is not yet in
+    /// the past.
+    FutureLookup(ERC5805FutureLookup),
+    /// Indicates that the total supply of a token id would exceed the
+    /// maximum that can be checkpointed.
+    ExceededSafeSupply(ERC6909ExceededSafeSupply),
+    /// Indicates that a token id can't be registered as a voting id.
+    InvalidVotingId(ERC6909InvalidVotingId),
+    /// Indicates that the maximum number of voting ids is reached.
+    TooManyVotingIds(ERC6909TooManyVotingIds),
+    /// A value was attempted to be inserted into a past checkpoint.
+    CheckpointUnorderedInsertion(CheckpointUnorderedInsertion),
+    /// Indicates that a delegation signature expired.
+    ExpiredSignature(VotesExpiredSignature),
+    /// The nonce used for an account is not the expected current nonce.
+    InvalidAccountNonce(InvalidAccountNonce),
+    /// The signature derives the [`Address::ZERO`].
+    InvalidSignature(ECDSAInvalidSignature),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(ECDSAInvalidSignatureS),
+    /// Indicates that more tokens of an id would be burnt than its recorded
+    /// total supply, when wrapping an [`Erc6909Supply`].
+    ///
+    /// [`Erc6909Supply`]: super::Erc6909Supply
+    InsufficientSupply(ERC6909InsufficientSupply),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<supply::Error> for Error {
+    fn from(value: supply::Error) -> Self {
+        match value {
+            supply::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            supply::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            supply::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            supply::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            supply::Error::InvalidSender(e) => Error::InvalidSender(e),
+            supply::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            supply::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            supply::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            supply::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            supply::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            supply::Error::SelfTarget(e) => Error::SelfTarget(e),
+            supply::Error::InsufficientSupply(e) => {
+                Error::InsufficientSupply(e)
+            }
+        }
+    }
+}
+
+impl From<checkpoints::Error> for Error {
+    fn from(value: checkpoints::Error) -> Self {
+        match value {
+            checkpoints::Error::CheckpointUnorderedInsertion(e) => {
+                Error::CheckpointUnorderedInsertion(e)
+            }
+        }
+    }
+}
+
+impl From<nonces::Error> for Error {
+    fn from(value: nonces::Error) -> Self {
+        match value {
+            nonces::Error::InvalidAccountNonce(e) => {
+                Error::InvalidAccountNonce(e)
+            }
+        }
+    }
+}
+
+impl From<ecdsa::Error> for Error {
+    fn from(value: ecdsa::Error) -> Self {
+        match value {
+            ecdsa::Error::InvalidSignature(e) => Error::InvalidSignature(e),
+            ecdsa::Error::InvalidSignatureS(e) => Error::InvalidSignatureS(e),
+        }
+    }
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Erc6909Votes`] contract.
+#[storage]
+pub struct Erc6909Votes<
+    T: IErc6909Internal = Erc6909,
+    C: Clock = TimestampClock,
+> {
+    /// Wrapped ERC-6909 token, a plain [`Erc6909`] by default.
+    pub erc6909: T,
+    /// Mapping from account to its delegate.
+    pub(crate) delegatees: StorageMap<Address, StorageAddress>,
+    /// Mapping from delegate to the history of its votes, for each voting
+    /// id.
+    pub(crate) delegate_checkpoints:
+        StorageMap<Address, StorageMap<U256, Trace<S208>>>,
+    /// Mapping from token id to the history of its total supply.
+    pub(crate) total_checkpoints: StorageMap<U256, Trace<S208>>,
+    /// Registered voting ids.
+    pub(crate) voting_ids: StorageVec<StorageU256>,
+    /// Mapping from token id to its voting weight, zero if it is not a
+    /// voting id.
+    pub(crate) voting_weights: StorageMap<U256, StorageU32>,
+    /// [`Clock`] keying the checkpoints, the block timestamp by default.
+    pub(crate) clock: PhantomData<C>,
+}
+
+impl_as_erc6909!(Erc6909Votes<T: IErc6909Internal, C: Clock>, erc6909);
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl<T: IErc6909Internal, C: Clock> TopLevelStorage
+    for Erc6909Votes<T, C>
+{
+}
+
+/// Required interface of an [`Erc6909Votes`] contract.
+#[interface_id]
+pub trait IErc6909Votes: IErc165 {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns the current votes of `account` for token `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    /// * `id` - Token id as a number.
+    fn get_votes(&self, account: Address, id: U256) -> U256;
+
+    /// Returns the votes of `account` for token `id` at `timepoint`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    /// * `id` - Token id as a number.
+    /// * `timepoint` - Past timepoint to look up.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FutureLookup`] - If `timepoint` is not in the past.
+    fn get_past_votes(
+        &self,
+        account: Address,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error>;
+
+    /// Returns the total supply of token `id` at `timepoint`.
+    ///
+    /// NOTE: The total supply counts every token of type `id`, including
+    /// those of accounts that didn't delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `timepoint` - Past timepoint to look up.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FutureLookup`] - If `timepoint` is not in the past.
+    fn get_past_total_supply(
+        &self,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error>;
+
+    /// Returns the delegate of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    fn delegates(&self, account: Address) -> Address;
+
+    /// Delegates the votes of the caller, for every voting id, to
+    /// `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `delegatee` - Account receiving the votes.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::CheckpointUnorderedInsertion`] - If a checkpoint can't be
+    ///   written.
+    ///
+    /// # Events
+    ///
+    /// * [`DelegateChanged`].
+    /// * [`DelegateVotesChanged`] - For each voting id whose votes move.
+    fn delegate(&mut self, delegatee: Address) -> Result<(), Self::Error>;
+
+    /// Returns the registered voting ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn voting_ids(&self) -> Vec<U256>;
+
+    /// Returns the voting weight of token `id`, zero if it is not a voting
+    /// id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id as a number.
+    fn voting_weight(&self, id: U256) -> U32;
+
+    /// Returns the current votes of `account`, aggregated across voting ids
+    /// by their weights.
+    ///
+    /// NOTE: Reads one checkpoint per voting id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    #[selector(name = "getVotes")]
+    fn get_weighted_votes(&self, account: Address) -> U256;
+
+    /// Returns the votes of `account` at `timepoint`, aggregated across
+    /// voting ids by their weights.
+    ///
+    /// NOTE: Performs one checkpoint lookup per voting id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    /// * `timepoint` - Past timepoint to look up.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FutureLookup`] - If `timepoint` is not in the past.
+    #[selector(name = "getPastVotes")]
+    fn get_past_weighted_votes(
+        &self,
+        account: Address,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error>;
+
+    /// Returns the total supply of voting ids at `timepoint`, aggregated by
+    /// their weights.
+    ///
+    /// NOTE: Performs one checkpoint lookup per voting id.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `timepoint` - Past timepoint to look up.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FutureLookup`] - If `timepoint` is not in the past.
+    #[selector(name = "getPastTotalSupply")]
+    fn get_past_weighted_total_supply(
+        &self,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error>;
+}
+
+/// Interface for delegating the votes of an [`Erc6909Votes`] contract by
+/// signature.
+pub trait IErc6909DelegateBySig: INonces {
+    /// The error type associated to this interface.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    // Calculated manually to include [`INonces::nonces`].
+    /// Solidity interface id associated with [`IErc6909DelegateBySig`] trait.
+    /// Computed as a XOR of selectors for each function in the trait.
+    #[must_use]
+    fn interface_id() -> FixedBytes<4>
+    where
+        Self: Sized,
+    {
+        FixedBytes::<4>::new(function_selector!("DOMAIN_SEPARATOR",))
+            ^ FixedBytes::<4>::new(function_selector!("nonces", Address,))
+            ^ FixedBytes::<4>::new(function_selector!(
+                "delegateBySig",
+                Address,
+                U256,
+                U256,
+                U8,
+                B256,
+                B256
+            ))
+    }
+
+    /// Returns the domain separator used in the encoding of the signature for
+    /// [`Self::delegate_by_sig`], as defined by EIP712.
+    ///
+    /// NOTE: The implementation should use `#[selector(name =
+    /// "DOMAIN_SEPARATOR")]` to match Solidity's camelCase naming
+    /// convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    fn domain_separator(&self) -> B256;
+
+    /// Delegates the votes of the signer, for every voting id, to
+    /// `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `delegatee` - Account receiving the votes.
+    /// * `nonce` - Current nonce of the signer.
+    /// * `expiry` - Timestamp after which the signature is invalid.
+    /// * `v` - v value from the signature.
+    /// * `r` - r value from the signature.
+    /// * `s` - s value from the signature.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExpiredSignature`] - If `expiry` is in the past.
+    /// * [`Error::InvalidSignatureS`] - If the `s` value is greater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`Error::InvalidSignature`] - If the recovered address is
+    ///   [`Address::ZERO`].
+    /// * [`Error::InvalidAccountNonce`] - If `nonce` is not the current nonce
+    ///   of the signer.
+    ///
+    /// # Events
+    ///
+    /// * [`DelegateChanged`].
+    /// * [`DelegateVotesChanged`] - For each voting id whose votes move.
+    #[allow(clippy::too_many_arguments)]
+    fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Votes<Error = Error>,
+    IErc6372,
+    IErc165
+)]
+impl<T: IErc6909Internal, C: Clock> Erc6909Votes<T, C> where
+    Error: From<T::Error>
+{
+}
+
+#[public]
+impl<T: IErc6909Internal, C: Clock> IErc6909Votes for Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    type Error = Error;
+
+    fn get_votes(&self, account: Address, id: U256) -> U256 {
+        self.delegate_checkpoints.get(account).get(id).latest().to::<U256>()
+    }
+
+    fn get_past_votes(
+        &self,
+        account: Address,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        let timepoint = validate_timepoint::<C>(timepoint)?;
+        Ok(self
+            .delegate_checkpoints
+            .get(account)
+            .get(id)
+            .upper_lookup_recent(timepoint)
+            .to::<U256>())
+    }
+
+    fn get_past_total_supply(
+        &self,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        let timepoint = validate_timepoint::<C>(timepoint)?;
+        Ok(self
+            .total_checkpoints
+            .get(id)
+            .upper_lookup_recent(timepoint)
+            .to::<U256>())
+    }
+
+    fn delegates(&self, account: Address) -> Address {
+        self.delegatees.get(account)
+    }
+
+    fn delegate(&mut self, delegatee: Address) -> Result<(), Self::Error> {
+        self._delegate(msg::sender(), delegatee)
+    }
+
+    fn voting_ids(&self) -> Vec<U256> {
+        (0..self.voting_ids.len())
+            .filter_map(|i| self.voting_ids.get(i))
+            .collect()
+    }
+
+    fn voting_weight(&self, id: U256) -> U32 {
+        self.voting_weights.get(id)
+    }
+
+    #[selector(name = "getVotes")]
+    fn get_weighted_votes(&self, account: Address) -> U256 {
+        let checkpoints = self.delegate_checkpoints.get(account);
+        self.weighted_sum(|id| checkpoints.get(id).latest())
+    }
+
+    #[selector(name = "getPastVotes")]
+    fn get_past_weighted_votes(
+        &self,
+        account: Address,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        let timepoint = validate_timepoint::<C>(timepoint)?;
+        let checkpoints = self.delegate_checkpoints.get(account);
+        Ok(self.weighted_sum(|id| {
+            checkpoints.get(id).upper_lookup_recent(timepoint)
+        }))
+    }
+
+    #[selector(name = "getPastTotalSupply")]
+    fn get_past_weighted_total_supply(
+        &self,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        let timepoint = validate_timepoint::<C>(timepoint)?;
+        Ok(self.weighted_sum(|id| {
+            self.total_checkpoints.get(id).upper_lookup_recent(timepoint)
+        }))
+    }
+}
+
+#[public]
+impl<T: IErc6909Internal, C: Clock> IErc6909 for Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    type Error = Error;
+
+    fn transfer(
+        &mut self,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let sender = msg::sender();
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn transfer_from(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        let caller = msg::sender();
+
+        self.erc6909
+            .as_mut()
+            ._check_authorization(sender, caller, id, amount)?;
+
+        self._transfer(sender, receiver, id, amount)?;
+        Ok(true)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.as_mut().approve(spender, id, amount)?)
+    }
+
+    fn set_operator(
+        &mut self,
+        spender: Address,
+        approved: bool,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.erc6909.as_mut().set_operator(spender, approved)?)
+    }
+
+    fn balance_of(&self, owner: Address, id: U256) -> U256 {
+        self.erc6909.as_ref().balance_of(owner, id)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
+        self.erc6909.as_ref().allowance(owner, spender, id)
+    }
+
+    fn is_operator(&self, owner: Address, spender: Address) -> bool {
+        self.erc6909.as_ref().is_operator(owner, spender)
+    }
+}
+
+#[public]
+impl<T: IErc6909Internal, C: Clock> IErc6372 for Erc6909Votes<T, C> {
+    fn clock(&self) -> U48 {
+        C::now()
+    }
+
+    #[selector(name = "CLOCK_MODE")]
+    fn clock_mode(&self) -> String {
+        C::MODE.into()
+    }
+}
+
+#[public]
+impl<T: IErc6909Internal, C: Clock> IErc165 for Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Votes>::interface_id() == interface_id
+            || <Self as IErc6372>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+impl<T: IErc6909Internal, C: Clock> Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    /// Registers token `id` as a voting id, each unit of which counts
+    /// `weight` votes in aggregated votes.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// A token id can only become a voting id before any of its tokens are
+    /// minted, so that every unit of it is accounted for in the votes of
+    /// its holders' delegates. Voting ids can't be unregistered, nor can
+    /// their weight change, as it would rewrite the aggregated votes of
+    /// past timepoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Token id as a number.
+    /// * `weight` - Weight of each unit of `id`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidVotingId`] - If `id` is already a voting id, if some
+    ///   of its tokens were minted, or if `weight` is zero.
+    /// * [`Error::TooManyVotingIds`] - If [`MAX_VOTING_IDS`] voting ids are
+    ///   already registered.
+    ///
+    /// # Events
+    ///
+    /// * [`VotingIdAdded`].
+    pub fn _add_voting_id(
+        &mut self,
+        id: U256,
+        weight: U32,
+    ) -> Result<(), Error> {
+        if weight.is_zero()
+            || !self.voting_weights.get(id).is_zero()
+            || self.total_checkpoints.get(id).latest_checkpoint().is_some()
+        {
+            return Err(Error::InvalidVotingId(ERC6909InvalidVotingId { id }));
+        }
+
+        if self.voting_ids.len() >= MAX_VOTING_IDS {
+            return Err(Error::TooManyVotingIds(ERC6909TooManyVotingIds {
+                max: U256::from(MAX_VOTING_IDS),
+            }));
+        }
+
+        self.voting_ids.push(id);
+        self.voting_weights.setter(id).set(weight);
+
+        evm::log(VotingIdAdded { id, weight: weight.to::<u32>() });
+
+        Ok(())
+    }
+
+    /// Creates an `amount` of tokens of type `id`, and assigns
+    /// them to `to`.
+    ///
+    /// Re-export of [`Erc6909::_mint`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_mint(to, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_mint`].
+    ///
+    /// Re-export of [`Erc6909::_mint_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_mint(to, ids, amounts)
+    }
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// Re-export of [`Erc6909::_burn`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_burn(from, vec![id], vec![amount])
+    }
+
+    /// Batched version of [`Self::_burn`].
+    ///
+    /// Re-export of [`Erc6909::_burn_batch`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn _burn_batch(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self._do_burn(from, ids, amounts)
+    }
+
+    /// See [`IErc6909DelegateBySig::delegate_by_sig`].
+    ///
+    /// The signature is hashed with the domain of `eip712`, and the nonce of
+    /// the signer is consumed from `nonces`.
+    #[allow(clippy::too_many_arguments, clippy::missing_errors_doc)]
+    pub fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+        eip712: &impl IEip712,
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        if U256::from(block::timestamp()) > expiry {
+            return Err(Error::ExpiredSignature(VotesExpiredSignature {
+                expiry,
+            }));
+        }
+
+        let struct_hash = keccak256(DelegationStructHashTuple::abi_encode(&(
+            DELEGATION_TYPEHASH,
+            delegatee,
+            nonce,
+            expiry,
+        )));
+        let hash = eip712.hash_typed_data_v4(struct_hash);
+
+        let signer = ecdsa::recover(self, hash, v, r, s)?;
+        nonces.use_checked_nonce(signer, nonce)?;
+
+        self._delegate(signer, delegatee)
+    }
+
+    /// Delegates all the votes of `account` to `delegatee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account whose votes are delegated.
+    /// * `delegatee` - Account receiving the votes.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::CheckpointUnorderedInsertion`] - If a checkpoint can't be
+    ///   written.
+    ///
+    /// # Events
+    ///
+    /// * [`DelegateChanged`].
+    /// * [`DelegateVotesChanged`] - For each voting id whose votes move.
+    pub fn _delegate(
+        &mut self,
+        account: Address,
+        delegatee: Address,
+    ) -> Result<(), Error> {
+        let old_delegate = self.delegates(account);
+        self.delegatees.setter(account).set(delegatee);
+
+        evm::log(DelegateChanged {
+            delegator: account,
+            from_delegate: old_delegate,
+            to_delegate: delegatee,
+        });
+
+        for id in self.voting_ids() {
+            let units = self.erc6909.as_ref().balance_of(account, id);
+            self._move_delegate_votes(old_delegate, delegatee, id, units)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: IErc6909Internal, C: Clock> Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    fn _do_mint(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+
+        self._update(Address::ZERO, to, ids, amounts)
+    }
+
+    fn _do_burn(
+        &mut self,
+        from: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+
+        self._update(from, Address::ZERO, ids, amounts)
+    }
+
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        Erc6909::_check_transfer_params(from, to)?;
+        self._update(from, to, vec![id], vec![amount])
+    }
+
+    /// Extended version of [`Erc6909::_update`] that checkpoints the total
+    /// supply of minted and burnt ids, and moves the votes of voting ids
+    /// from the delegate of `from` to the delegate of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededSafeSupply`] - If the total supply of one of `ids`
+    ///   would exceed [`U208::MAX`].
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`erc6909::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc6909::TransferBatch`] - If the arrays contain more than one
+    ///   element.
+    /// * [`DelegateVotesChanged`] - For each delegate whose votes change.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds [`U256::MAX`].
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.erc6909._update(from, to, ids.clone(), amounts.clone())?;
+
+        for (id, amount) in ids.into_iter().zip(amounts) {
+            if from.is_zero() {
+                let supply = self.total_checkpoints.get(id).latest();
+                let increased_supply = supply.to::<U256>() + amount;
+                let cap = U208::MAX.to::<U256>();
+                if increased_supply > cap {
+                    return Err(Error::ExceededSafeSupply(
+                        ERC6909ExceededSafeSupply { id, increased_supply, cap },
+                    ));
+                }
+                self._push_total_supply(id, increased_supply)?;
+            }
+
+            if to.is_zero() {
+                let supply = self.total_checkpoints.get(id).latest();
+                self._push_total_supply(id, supply.to::<U256>() - amount)?;
+            }
+
+            if !self.voting_weights.get(id).is_zero() {
+                self._move_delegate_votes(
+                    self.delegates(from),
+                    self.delegates(to),
+                    id,
+                    amount,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sums the values returned by `votes_of` for every voting id,
+    /// multiplied by the id's weight.
+    ///
+    /// Can't overflow, as each value fits in [`U208`], each weight in
+    /// [`U32`], and there are at most [`MAX_VOTING_IDS`] voting ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `votes_of` - Function returning the votes of a voting id.
+    fn weighted_sum(&self, votes_of: impl Fn(U256) -> U208) -> U256 {
+        self.voting_ids()
+            .into_iter()
+            .map(|id| {
+                let weight = self.voting_weights.get(id).to::<U256>();
+                votes_of(id).to::<U256>() * weight
+            })
+            .fold(U256::ZERO, |sum, votes| sum + votes)
+    }
+
+    /// Moves `amount` votes of token `id` from delegate `from` to delegate
+    /// `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Delegate losing the votes, if not zero.
+    /// * `to` - Delegate gaining the votes, if not zero.
+    /// * `id` - Voting id as a number.
+    /// * `amount` - Number of votes moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::CheckpointUnorderedInsertion`] - If a checkpoint can't be
+    ///   written.
+    ///
+    /// # Events
+    ///
+    /// * [`DelegateVotesChanged`] - For each of `from` and `to` that is not
+    ///   zero.
+    fn _move_delegate_votes(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if from == to || amount.is_zero() {
+            return Ok(());
+        }
+
+        if !from.is_zero() {
+            let votes = self.get_votes(from, id);
+            self._push_delegate_votes(from, id, votes - amount)?;
+        }
+
+        if !to.is_zero() {
+            let votes = self.get_votes(to, id);
+            self._push_delegate_votes(to, id, votes + amount)?;
+        }
+
+        Ok(())
+    }
+
+    fn _push_delegate_votes(
+        &mut self,
+        delegate: Address,
+        id: U256,
+        votes: U256,
+    ) -> Result<(), Error> {
+        let (previous_votes, new_votes) = self
+            .delegate_checkpoints
+            .setter(delegate)
+            .setter(id)
+            .push(C::now(), votes.to::<U208>())?;
+
+        evm::log(DelegateVotesChanged {
+            delegate,
+            id,
+            previous_votes: previous_votes.to::<U256>(),
+            new_votes: new_votes.to::<U256>(),
+        });
+
+        Ok(())
+    }
+
+    fn _push_total_supply(
+        &mut self,
+        id: U256,
+        supply: U256,
+    ) -> Result<(), Error> {
+        self.total_checkpoints
+            .setter(id)
+            .push(C::now(), supply.to::<U208>())?;
+        Ok(())
+    }
+}
+
+/// Checks that `timepoint` is in the past, and returns it as a
+/// checkpoint key.
+///
+/// # Errors
+///
+/// * [`Error::FutureLookup`] - If `timepoint` is not in the past.
+fn validate_timepoint<C: Clock>(timepoint: U256) -> Result<U48, Error> {
+    let clock = C::now();
+    if timepoint >= clock.to::<U256>() {
+        return Err(Error::FutureLookup(ERC5805FutureLookup {
+            timepoint,
+            clock,
+        }));
+    }
+
+    Ok(timepoint.to::<U48>())
+}
+
+impl<T: IErc6909Internal, C: Clock> IErc6909Internal for Erc6909Votes<T, C>
+where
+    Error: From<T::Error>,
+{
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909.as_mut()._approve(owner, spender, id, amount)?)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        Ok(self.erc6909.as_mut()._set_operator(owner, spender, approved)?)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl<T: IErc6909Internal, C: Clock> crate::utils::abi::Events
+    for Erc6909Votes<T, C>
+{
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        let mut events = Erc6909::events();
+        events.extend([
+            event::<DelegateChanged>(
+                "event DelegateChanged(address indexed delegator, address \
+                 indexed from_delegate, address indexed to_delegate)",
+            ),
+            event::<DelegateVotesChanged>(
+                "event DelegateVotesChanged(address indexed delegate, \
+                 uint256 indexed id, uint256 previous_votes, uint256 \
+                 new_votes)",
+            ),
+            event::<VotingIdAdded>(
+                "event VotingIdAdded(uint256 indexed id, uint32 weight)",
+            ),
+        ]);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256, U32,
+    };
+    use motsu::prelude::*;
+    use stylus_sdk::{block, prelude::*};
+
+    use super::*;
+    use crate::{
+        token::erc6909::extensions::{Erc6909Supply, IErc6909Supply},
+        utils::{clock::BlockNumberClock, precompiles::mock},
+    };
+
+    const ID: U256 = uint!(1_U256);
+    const OTHER_ID: U256 = uint!(2_U256);
+    const AMOUNT: U256 = uint!(100_U256);
+    const WEIGHT: U32 = uint!(1_U32);
+    const OTHER_WEIGHT: U32 = uint!(10_U32);
+
+    #[storage]
+    struct Eip712;
+
+    impl IEip712 for Eip712 {
+        const NAME: &'static str = "ERC-6909 Votes Example";
+        const VERSION: &'static str = "1";
+    }
+
+    #[storage]
+    struct Erc6909VotesExample {
+        erc6909_votes: Erc6909Votes,
+        nonces: Nonces,
+        eip712: Eip712,
+    }
+
+    unsafe impl TopLevelStorage for Erc6909VotesExample {}
+
+    #[public]
+    #[implements(INonces, IErc6909DelegateBySig<Error = Error>)]
+    impl Erc6909VotesExample {}
+
+    #[public]
+    impl INonces for Erc6909VotesExample {
+        fn nonces(&self, owner: Address) -> U256 {
+            self.nonces.nonces(owner)
+        }
+    }
+
+    #[public]
+    impl IErc6909DelegateBySig for Erc6909VotesExample {
+        type Error = Error;
+
+        #[selector(name = "DOMAIN_SEPARATOR")]
+        fn domain_separator(&self) -> B256 {
+            self.eip712.domain_separator_v4()
+        }
+
+        fn delegate_by_sig(
+            &mut self,
+            delegatee: Address,
+            nonce: U256,
+            expiry: U256,
+            v: u8,
+            r: B256,
+            s: B256,
+        ) -> Result<(), Self::Error> {
+            self.erc6909_votes.delegate_by_sig(
+                delegatee,
+                nonce,
+                expiry,
+                v,
+                r,
+                s,
+                &self.eip712,
+                &mut self.nonces,
+            )
+        }
+    }
+
+    fn now() -> U256 {
+        U256::from(TimestampClock::now())
+    }
+
+    #[motsu::test]
+    fn add_voting_id_registers_id(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+
+        assert_eq!(vec![ID], contract.sender(alice).voting_ids());
+        assert_eq!(WEIGHT, contract.sender(alice).voting_weight(ID));
+        contract.assert_emitted(&VotingIdAdded { id: ID, weight: 1 });
+    }
+
+    #[motsu::test]
+    fn add_voting_id_reverts_when_weight_is_zero(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._add_voting_id(ID, U32::ZERO)
+            .motsu_expect_err("should not add a voting id without weight");
+
+        assert!(matches!(
+            err,
+            Error::InvalidVotingId(ERC6909InvalidVotingId { id }) if id == ID
+        ));
+        assert!(contract.sender(alice).voting_ids().is_empty());
+    }
+
+    #[motsu::test]
+    fn add_voting_id_reverts_when_already_voting(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+
+        let err = contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect_err("should not add voting id twice");
+
+        assert!(matches!(
+            err,
+            Error::InvalidVotingId(ERC6909InvalidVotingId { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn add_voting_id_reverts_when_minted(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let err = contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect_err("should not add minted id");
+
+        assert!(matches!(
+            err,
+            Error::InvalidVotingId(ERC6909InvalidVotingId { id }) if id == ID
+        ));
+    }
+
+    #[motsu::test]
+    fn add_voting_id_reverts_when_too_many(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        for id in 0..MAX_VOTING_IDS {
+            contract
+                .sender(alice)
+                ._add_voting_id(U256::from(id), WEIGHT)
+                .motsu_expect("should add voting id");
+        }
+
+        let err = contract
+            .sender(alice)
+            ._add_voting_id(U256::from(MAX_VOTING_IDS), WEIGHT)
+            .motsu_expect_err("should not exceed the voting ids limit");
+
+        assert!(matches!(
+            err,
+            Error::TooManyVotingIds(ERC6909TooManyVotingIds { max })
+                if max == U256::from(MAX_VOTING_IDS)
+        ));
+    }
+
+    #[motsu::test]
+    fn delegate_moves_votes(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        // Undelegated tokens carry no votes.
+        assert!(contract.sender(alice).get_votes(alice, ID).is_zero());
+
+        contract
+            .sender(alice)
+            .delegate(alice)
+            .motsu_expect("should delegate to self");
+
+        assert_eq!(alice, contract.sender(alice).delegates(alice));
+        assert_eq!(AMOUNT, contract.sender(alice).get_votes(alice, ID));
+        contract.assert_emitted(&DelegateChanged {
+            delegator: alice,
+            from_delegate: Address::ZERO,
+            to_delegate: alice,
+        });
+        contract.assert_emitted(&DelegateVotesChanged {
+            delegate: alice,
+            id: ID,
+            previous_votes: U256::ZERO,
+            new_votes: AMOUNT,
+        });
+
+        contract
+            .sender(alice)
+            .delegate(bob)
+            .motsu_expect("should delegate to bob");
+
+        assert!(contract.sender(alice).get_votes(alice, ID).is_zero());
+        assert_eq!(AMOUNT, contract.sender(alice).get_votes(bob, ID));
+    }
+
+    #[motsu::test]
+    fn transfer_moves_votes_between_delegates(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+        contract.sender(alice).delegate(alice).motsu_expect("should delegate");
+        contract.sender(bob).delegate(bob).motsu_expect("should delegate");
+
+        let sent = uint!(40_U256);
+        contract
+            .sender(alice)
+            .transfer(bob, ID, sent)
+            .motsu_expect("should transfer tokens");
+
+        assert_eq!(AMOUNT - sent, contract.sender(alice).get_votes(alice, ID));
+        assert_eq!(sent, contract.sender(alice).get_votes(bob, ID));
+
+        contract
+            .sender(bob)
+            ._burn(bob, ID, sent)
+            .motsu_expect("should burn tokens");
+
+        assert!(contract.sender(alice).get_votes(bob, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn non_voting_ids_carry_no_votes(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract.sender(alice).delegate(alice).motsu_expect("should delegate");
+        contract
+            .sender(alice)
+            ._mint(alice, OTHER_ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        assert!(contract.sender(alice).get_votes(alice, OTHER_ID).is_zero());
+        assert!(contract.sender(alice).get_votes(alice, ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn past_lookups_use_checkpoints(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract.sender(alice).delegate(alice).motsu_expect("should delegate");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        let before = now() - uint!(1_U256);
+        assert!(contract
+            .sender(alice)
+            .get_past_votes(alice, ID, before)
+            .motsu_expect("should look up past votes")
+            .is_zero());
+        assert!(contract
+            .sender(alice)
+            .get_past_total_supply(ID, before)
+            .motsu_expect("should look up past total supply")
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn weighted_votes_aggregate_voting_ids(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract
+            .sender(alice)
+            ._add_voting_id(OTHER_ID, OTHER_WEIGHT)
+            .motsu_expect("should add voting id");
+        contract.sender(alice).delegate(alice).motsu_expect("should delegate");
+        contract.sender(bob).delegate(bob).motsu_expect("should delegate");
+        contract
+            .sender(alice)
+            ._mint_batch(alice, vec![ID, OTHER_ID], vec![AMOUNT, AMOUNT])
+            .motsu_expect("should mint tokens");
+
+        // 100 * 1 + 100 * 10.
+        assert_eq!(
+            uint!(1100_U256),
+            contract.sender(alice).get_weighted_votes(alice)
+        );
+
+        contract
+            .sender(alice)
+            .transfer(bob, OTHER_ID, uint!(5_U256))
+            .motsu_expect("should transfer tokens");
+
+        // 100 * 1 + 95 * 10.
+        assert_eq!(
+            uint!(1050_U256),
+            contract.sender(alice).get_weighted_votes(alice)
+        );
+        assert_eq!(
+            uint!(50_U256),
+            contract.sender(alice).get_weighted_votes(bob)
+        );
+
+        let before = now() - uint!(1_U256);
+        assert!(contract
+            .sender(alice)
+            .get_past_weighted_votes(alice, before)
+            .motsu_expect("should look up past votes")
+            .is_zero());
+        assert!(contract
+            .sender(alice)
+            .get_past_weighted_total_supply(before)
+            .motsu_expect("should look up past total supply")
+            .is_zero());
+    }
+
+    #[motsu::test]
+    fn past_lookups_revert_for_future_timepoints(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .get_past_votes(alice, ID, now())
+            .motsu_expect_err("should not look up the current timepoint");
+
+        assert!(matches!(
+            err,
+            Error::FutureLookup(ERC5805FutureLookup { timepoint, clock })
+                if timepoint == now() && U256::from(clock) == now()
+        ));
+
+        let err = contract
+            .sender(alice)
+            .get_past_total_supply(ID, now() + uint!(1_U256))
+            .motsu_expect_err("should not look up a future timepoint");
+
+        assert!(matches!(err, Error::FutureLookup(_)));
+
+        let err = contract
+            .sender(alice)
+            .get_past_weighted_votes(alice, now())
+            .motsu_expect_err("should not look up the current timepoint");
+
+        assert!(matches!(err, Error::FutureLookup(_)));
+    }
+
+    #[motsu::test]
+    fn mint_reverts_when_exceeding_safe_supply(
+        contract: Contract<Erc6909Votes>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._mint(alice, ID, U256::MAX)
+            .motsu_expect_err("should not exceed the safe supply");
+
+        assert!(matches!(
+            err,
+            Error::ExceededSafeSupply(ERC6909ExceededSafeSupply {
+                id,
+                increased_supply,
+                ..
+            }) if id == ID && increased_supply == U256::MAX
+        ));
+    }
+
+    #[motsu::test]
+    fn moves_votes_on_top_of_supply(
+        contract: Contract<Erc6909Votes<Erc6909Supply>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract
+            .sender(alice)
+            .delegate(bob)
+            .motsu_expect("should delegate to bob");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        assert_eq!(AMOUNT, contract.sender(alice).get_votes(bob, ID));
+        assert_eq!(AMOUNT, contract.sender(alice).erc6909.total_supply(ID));
+
+        contract
+            .sender(alice)
+            ._burn(alice, ID, AMOUNT)
+            .motsu_expect("should burn tokens");
+
+        assert!(contract.sender(alice).get_votes(bob, ID).is_zero());
+        assert!(contract.sender(alice).erc6909.total_supply(ID).is_zero());
+    }
+
+    #[motsu::test]
+    fn delegation_typehash_is_pinned() {
+        let expected: B256 = fixed_bytes!(
+            "0xe48329057bfd03d55e49b547132e39cffd9c1820ad7b9d4c5307691425d15adf"
+        );
+        assert_eq!(expected, B256::from(DELEGATION_TYPEHASH));
+    }
+
+    #[motsu::test]
+    fn delegate_by_sig_reverts_when_expired(
+        contract: Contract<Erc6909VotesExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let expiry = now() - uint!(1_U256);
+
+        let err = contract
+            .sender(alice)
+            .delegate_by_sig(
+                bob,
+                U256::ZERO,
+                expiry,
+                27,
+                B256::ZERO,
+                B256::ZERO,
+            )
+            .motsu_expect_err("should not delegate with an expired signature");
+
+        assert!(matches!(
+            err,
+            Error::ExpiredSignature(VotesExpiredSignature { expiry: e })
+                if e == expiry
+        ));
+        assert!(contract.sender(alice).nonces(alice).is_zero());
+    }
+
+    #[motsu::test]
+    fn delegate_by_sig_interface_id() {
+        let actual =
+            <Erc6909VotesExample as IErc6909DelegateBySig>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0x8b47fe35");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <Erc6909Votes as IErc6909Votes>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xd9f9404b");
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn clock_is_exposed(contract: Contract<Erc6909Votes>, alice: Address) {
+        assert_eq!(now(), U256::from(contract.sender(alice).clock()));
+        assert_eq!(TimestampClock::MODE, contract.sender(alice).clock_mode());
+
+        let interface_id = <Erc6909Votes as IErc6372>::interface_id();
+        assert!(contract.sender(alice).supports_interface(interface_id));
+    }
+
+    #[motsu::test]
+    fn checkpoints_follow_block_number_clock(
+        contract: Contract<Erc6909Votes<Erc6909, BlockNumberClock>>,
+        alice: Address,
+    ) {
+        mock::set_block_number(10);
+        assert_eq!(BlockNumberClock::MODE, contract.sender(alice).clock_mode());
+
+        contract
+            .sender(alice)
+            ._add_voting_id(ID, WEIGHT)
+            .motsu_expect("should add voting id");
+        contract
+            .sender(alice)
+            ._mint(alice, ID, AMOUNT)
+            .motsu_expect("should mint tokens");
+
+        mock::set_block_number(11);
+        assert_eq!(U48::from(11), contract.sender(alice).clock());
+        assert_eq!(
+            AMOUNT,
+            contract
+                .sender(alice)
+                .get_past_total_supply(ID, uint!(10_U256))
+                .motsu_expect("should look up a past block")
+        );
+
+        let err = contract
+            .sender(alice)
+            .get_past_total_supply(ID, uint!(11_U256))
+            .motsu_expect_err("should not look up the current block");
+        assert!(matches!(err, Error::FutureLookup(_)));
+    }
+}
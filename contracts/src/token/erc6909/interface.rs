@@ -0,0 +1,22 @@
+//! Solidity Interface of the ERC-6909 token.
+pub use token::*;
+
+mod token {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+    sol_interface! {
+        interface Erc6909Interface {
+            function balanceOf(address owner, uint256 id) external view returns (uint256);
+            function allowance(address owner, address spender, uint256 id) external view returns (uint256);
+            function isOperator(address owner, address spender) external view returns (bool);
+            function transfer(address receiver, uint256 id, uint256 amount) external returns (bool);
+            function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool);
+            function approve(address spender, uint256 id, uint256 amount) external returns (bool);
+            function setOperator(address spender, bool approved) external returns (bool);
+        }
+    }
+}
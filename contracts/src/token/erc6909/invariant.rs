@@ -0,0 +1,319 @@
+//! Invariant testing harness for ERC-6909 contracts.
+//!
+//! [`InvariantHarness`] executes pseudo-random sequences of [`Operation`]s
+//! against any contract implementing [`Erc6909Target`], and checks after each
+//! of them that:
+//!
+//! * The operation succeeded if and only if it was authorized and funded.
+//!   Transfers on behalf of the zero address are never authorized, since no
+//!   allowance can be granted by it.
+//! * Balances of the touched accounts moved exactly by the operation's amount.
+//! * For every token id, the sum of the balances of all accounts equals its
+//!   total supply.
+//!
+//! Sequences are derived from a seed, so that failures can be reproduced.
+//!
+//! Available in tests, and to other crates through the `test-utils` feature.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, U256};
+
+use crate::utils::test_utils::Generator;
+
+/// An operation executed by [`InvariantHarness`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// Mints `amount` tokens of type `id` to `to`.
+    Mint {
+        /// Account of the recipient.
+        to: Address,
+        /// Token id as a number.
+        id: U256,
+        /// Amount of tokens.
+        amount: U256,
+    },
+    /// Burns `amount` tokens of type `id` from `from`.
+    Burn {
+        /// Account of the holder.
+        from: Address,
+        /// Token id as a number.
+        id: U256,
+        /// Amount of tokens.
+        amount: U256,
+    },
+    /// `caller` transfers `amount` of its tokens of type `id` to `receiver`.
+    Transfer {
+        /// Account calling the contract.
+        caller: Address,
+        /// Account of the recipient.
+        receiver: Address,
+        /// Token id as a number.
+        id: U256,
+        /// Amount of tokens.
+        amount: U256,
+    },
+    /// `caller` transfers `amount` tokens of type `id` from `sender` to
+    /// `receiver`.
+    TransferFrom {
+        /// Account calling the contract.
+        caller: Address,
+        /// Account of the holder.
+        sender: Address,
+        /// Account of the recipient.
+        receiver: Address,
+        /// Token id as a number.
+        id: U256,
+        /// Amount of tokens.
+        amount: U256,
+    },
+    /// `caller` allows `spender` to transfer `amount` of its tokens of type
+    /// `id`.
+    Approve {
+        /// Account calling the contract.
+        caller: Address,
+        /// Account of the spender.
+        spender: Address,
+        /// Token id as a number.
+        id: U256,
+        /// Amount of tokens.
+        amount: U256,
+    },
+    /// `caller` sets or unsets `spender` as its operator.
+    SetOperator {
+        /// Account calling the contract.
+        caller: Address,
+        /// Account of the operator.
+        spender: Address,
+        /// Whether `spender` is an operator of `caller`.
+        approved: bool,
+    },
+}
+
+/// A contract the [`InvariantHarness`] can run operations against.
+pub trait Erc6909Target {
+    /// Executes `operation`, and returns whether it succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the target.
+    /// * `operation` - The operation to execute.
+    fn execute(&mut self, operation: &Operation) -> bool;
+
+    /// Returns the amount of tokens of type `id` owned by `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the target.
+    /// * `owner` - Account of the holder.
+    /// * `id` - Token id as a number.
+    fn balance_of(&self, owner: Address, id: U256) -> U256;
+
+    /// Returns the amount of tokens of type `id` `spender` may transfer on
+    /// behalf of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the target.
+    /// * `owner` - Account of the holder.
+    /// * `spender` - Account of the spender.
+    /// * `id` - Token id as a number.
+    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256;
+
+    /// Returns whether `spender` is an operator of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the target.
+    /// * `owner` - Account of the holder.
+    /// * `spender` - Account of the operator.
+    fn is_operator(&self, owner: Address, spender: Address) -> bool;
+
+    /// Returns the total supply of tokens of type `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the target.
+    /// * `id` - Token id as a number.
+    fn total_supply(&self, id: U256) -> U256;
+}
+
+/// Maximum amount of tokens moved by a single generated operation.
+const MAX_AMOUNT: u64 = 100;
+
+/// Runs pseudo-random sequences of operations against an [`Erc6909Target`]
+/// and checks its invariants after each of them.
+#[derive(Debug)]
+pub struct InvariantHarness {
+    accounts: Vec<Address>,
+    ids: Vec<U256>,
+    generator: Generator,
+}
+
+impl InvariantHarness {
+    /// Creates a harness operating on `accounts` and token `ids`, generating
+    /// operations from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - Non-zero accounts operations are performed by and on.
+    /// * `ids` - Token ids operations are performed on.
+    /// * `seed` - Seed of the generated operations.
+    ///
+    /// # Panics
+    ///
+    /// * If `accounts` or `ids` is empty.
+    #[must_use]
+    pub fn new(accounts: Vec<Address>, ids: Vec<U256>, seed: u64) -> Self {
+        assert!(!accounts.is_empty(), "should have at least one account");
+        assert!(!ids.is_empty(), "should have at least one token id");
+        Self { accounts, ids, generator: Generator::new(seed) }
+    }
+
+    /// Executes `steps` generated operations against `target`, checking its
+    /// invariants after each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the harness.
+    /// * `target` - The contract under test.
+    /// * `steps` - Number of operations to execute.
+    ///
+    /// # Panics
+    ///
+    /// * If an invariant is broken.
+    pub fn run(&mut self, target: &mut impl Erc6909Target, steps: usize) {
+        for step in 0..steps {
+            let operation = self.next_operation();
+            self.check(target, &operation, step);
+        }
+    }
+
+    /// Executes `operation` against `target`, and checks its invariants.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the harness.
+    /// * `target` - The contract under test.
+    /// * `operation` - The operation to execute.
+    /// * `step` - Index of the operation in the sequence.
+    ///
+    /// # Panics
+    ///
+    /// * If an invariant is broken.
+    pub fn check(
+        &self,
+        target: &mut impl Erc6909Target,
+        operation: &Operation,
+        step: usize,
+    ) {
+        let (from, to, id, amount) = match *operation {
+            Operation::Mint { to, id, amount } => (None, Some(to), id, amount),
+            Operation::Burn { from, id, amount } => {
+                (Some(from), None, id, amount)
+            }
+            Operation::Transfer { caller, receiver, id, amount } => {
+                (Some(caller), Some(receiver), id, amount)
+            }
+            Operation::TransferFrom {
+                sender, receiver, id, amount, ..
+            } => (Some(sender), Some(receiver), id, amount),
+            Operation::Approve { id, .. } => (None, None, id, U256::ZERO),
+            Operation::SetOperator { .. } => {
+                (None, None, self.ids[0], U256::ZERO)
+            }
+        };
+
+        let expected = Self::is_allowed(target, operation)
+            && from.is_none_or(|from| target.balance_of(from, id) >= amount);
+        let from_before = from.map(|from| target.balance_of(from, id));
+        let to_before = to.map(|to| target.balance_of(to, id));
+
+        let succeeded = target.execute(operation);
+        assert_eq!(
+            succeeded,
+            expected,
+            "step {step}: {operation:?} should {}",
+            if expected { "succeed" } else { "fail" }
+        );
+
+        // Self transfers leave balances unchanged.
+        let moved = if succeeded && from != to { amount } else { U256::ZERO };
+        if let (Some(from), Some(before)) = (from, from_before) {
+            assert_eq!(
+                target.balance_of(from, id),
+                before - moved,
+                "step {step}: {operation:?} should debit {from}"
+            );
+        }
+        if let (Some(to), Some(before)) = (to, to_before) {
+            assert_eq!(
+                target.balance_of(to, id),
+                before + moved,
+                "step {step}: {operation:?} should credit {to}"
+            );
+        }
+
+        for &id in &self.ids {
+            let balances =
+                self.accounts.iter().fold(U256::ZERO, |sum, &account| {
+                    sum + target.balance_of(account, id)
+                });
+            assert_eq!(
+                balances,
+                target.total_supply(id),
+                "step {step}: {operation:?} should keep the total supply of \
+                 {id} equal to the sum of balances"
+            );
+        }
+    }
+
+    /// Returns whether the caller of `operation` is authorized to perform it.
+    fn is_allowed(target: &impl Erc6909Target, operation: &Operation) -> bool {
+        match *operation {
+            Operation::TransferFrom { caller, sender, id, amount, .. } => {
+                !sender.is_zero()
+                    && (caller == sender
+                        || target.is_operator(sender, caller)
+                        || target.allowance(sender, caller, id) >= amount)
+            }
+            _ => true,
+        }
+    }
+
+    /// Generates the next operation of the sequence.
+    fn next_operation(&mut self) -> Operation {
+        let caller = self.next_account();
+        let other = self.next_account();
+        let index = self.generator.next_index(self.ids.len());
+        let id = self.ids[index];
+        let amount = self.generator.next_amount(MAX_AMOUNT);
+
+        match self.generator.next_u64() % 7 {
+            0 | 1 => Operation::Mint { to: caller, id, amount },
+            2 => Operation::Burn { from: caller, id, amount },
+            3 => Operation::Transfer { caller, receiver: other, id, amount },
+            4 => {
+                let sender = self.next_account();
+                Operation::TransferFrom {
+                    caller,
+                    sender,
+                    receiver: other,
+                    id,
+                    amount,
+                }
+            }
+            5 => Operation::Approve { caller, spender: other, id, amount },
+            _ => Operation::SetOperator {
+                caller,
+                spender: other,
+                approved: self.generator.next_u64() % 2 == 0,
+            },
+        }
+    }
+
+    fn next_account(&mut self) -> Address {
+        let index = self.generator.next_index(self.accounts.len());
+        self.accounts[index]
+    }
+}
@@ -0,0 +1,240 @@
+//! Stateless helpers shared by [`Erc6909`] and its extensions.
+//!
+//! Functions in this module never touch storage, so other Stylus contracts
+//! can reuse the validation and calculation logic of ERC-6909 without
+//! embedding the full token state.
+//!
+//! [`Erc6909`]: super::Erc6909
+
+use alloy_primitives::{keccak256, uint, Address, U256};
+use alloy_sol_types::SolValue;
+
+use super::{ERC6909InvalidArrayLength, Erc6909InsufficientAllowance, Error};
+use crate::utils::math::{alloy::Rounding, bps::Bps};
+
+/// Denominator of amounts expressed in basis points.
+pub const BASIS_POINTS: U256 = uint!(10_000_U256);
+
+/// Checks if `ids` array has same length as `values` array.
+///
+/// # Arguments
+///
+/// * `ids` - array of `ids`.
+/// * `values` - array of `values`.
+///
+/// # Errors
+///
+/// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to length
+///   of `values`.
+pub fn require_equal_arrays_length<T, U>(
+    ids: &[T],
+    values: &[U],
+) -> Result<(), Error> {
+    if ids.len() != values.len() {
+        return Err(Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+            ids_length: U256::from(ids.len()),
+            values_length: U256::from(values.len()),
+        }));
+    }
+    Ok(())
+}
+
+/// Returns the allowance left to `spender` after spending `amount` out of
+/// `current_allowance` of token `id`.
+///
+/// # Arguments
+///
+/// * `spender` - Address of account spending the tokens.
+/// * `id` - Token id as a number.
+/// * `current_allowance` - Allowance of `spender` before spending.
+/// * `amount` - Amount of tokens being spent.
+///
+/// # Errors
+///
+/// * [`Error::InsufficientAllowance`] - If `amount` is greater than
+///   `current_allowance`.
+pub fn spend_allowance(
+    spender: Address,
+    id: U256,
+    current_allowance: U256,
+    amount: U256,
+) -> Result<U256, Error> {
+    current_allowance.checked_sub(amount).ok_or(Error::InsufficientAllowance(
+        Erc6909InsufficientAllowance {
+            spender,
+            allowance: current_allowance,
+            needed: amount,
+            id,
+        },
+    ))
+}
+
+/// Returns the fee charged on `amount` for a fee rate of `fee`, rounded down.
+///
+/// # Arguments
+///
+/// * `amount` - Amount the fee is charged on.
+/// * `fee` - Fee rate, in basis points.
+#[must_use]
+pub fn fee_amount(amount: U256, fee: Bps) -> U256 {
+    fee.apply(amount, Rounding::Floor)
+}
+
+/// Derives a token id from the address of its `creator` and a `salt`, as
+/// `keccak256(abi.encode(creator, salt))`.
+///
+/// # Arguments
+///
+/// * `creator` - Address of the account creating the token id.
+/// * `salt` - Arbitrary value chosen by the creator.
+#[must_use]
+pub fn derive_id(creator: Address, salt: U256) -> U256 {
+    U256::from_be_bytes(keccak256((creator, salt).abi_encode()).0)
+}
+
+/// Derives the token id of the `tranche` of the base asset `base_id`, as
+/// `keccak256(abi.encode(base_id, tranche))`.
+///
+/// # Arguments
+///
+/// * `base_id` - Token id of the base asset.
+/// * `tranche` - Number of the tranche within the base asset.
+#[must_use]
+pub fn derive_tranche_id(base_id: U256, tranche: U256) -> U256 {
+    U256::from_be_bytes(keccak256((base_id, tranche).abi_encode()).0)
+}
+
+/// Returns the token id of `currency`, under the convention of Uniswap v4
+/// of storing the address of a currency in the lower 160 bits of its id,
+/// as `uint256(uint160(currency))`.
+///
+/// # Arguments
+///
+/// * `currency` - Address of the currency, zero for the native currency.
+#[must_use]
+pub fn id_from_currency(currency: Address) -> U256 {
+    U256::from_be_slice(currency.as_slice())
+}
+
+/// Returns the currency of token `id`, under the convention of Uniswap v4
+/// of storing the address of a currency in the lower 160 bits of its id,
+/// as `address(uint160(id))`.
+///
+/// Like its Solidity counterpart, it ignores the upper 96 bits of `id`, so
+/// that ids not following the convention map to the currency of their lower
+/// 160 bits.
+///
+/// # Arguments
+///
+/// * `id` - Token id as a number.
+#[must_use]
+pub fn currency_from_id(id: U256) -> Address {
+    Address::from_word(id.to_be_bytes::<32>().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, uint, U256};
+
+    use super::*;
+
+    #[test]
+    fn require_equal_arrays_length_reverts_on_mismatch() {
+        let err = require_equal_arrays_length(&[U256::ZERO], &[])
+            .expect_err("should reject arrays of different length");
+
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+                ids_length,
+                values_length,
+            }) if ids_length == uint!(1_U256) && values_length.is_zero()
+        ));
+    }
+
+    #[test]
+    fn spend_allowance_returns_remaining_allowance() {
+        let spender = address!("0xA11CE00000000000000000000000000000000000");
+        let remaining = spend_allowance(
+            spender,
+            uint!(1_U256),
+            uint!(300_U256),
+            uint!(200_U256),
+        )
+        .expect("should spend allowance");
+
+        assert_eq!(remaining, uint!(100_U256));
+    }
+
+    #[test]
+    fn spend_allowance_reverts_when_insufficient() {
+        let spender = address!("0xA11CE00000000000000000000000000000000000");
+        let err = spend_allowance(
+            spender,
+            uint!(1_U256),
+            uint!(100_U256),
+            uint!(200_U256),
+        )
+        .expect_err("should not spend more than allowed");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(Erc6909InsufficientAllowance {
+                allowance,
+                needed,
+                ..
+            }) if allowance == uint!(100_U256) && needed == uint!(200_U256)
+        ));
+    }
+
+    #[test]
+    fn fee_amount_rounds_down() {
+        assert_eq!(
+            fee_amount(uint!(999_U256), Bps::from_const::<100>()),
+            uint!(9_U256)
+        );
+        assert_eq!(fee_amount(uint!(1000_U256), Bps::MAX), uint!(1000_U256));
+    }
+
+    #[test]
+    fn derive_id_depends_on_creator_and_salt() {
+        let alice = address!("0xA11CE00000000000000000000000000000000000");
+        let bob = address!("0xB0B0000000000000000000000000000000000000");
+
+        assert_eq!(derive_id(alice, U256::ZERO), derive_id(alice, U256::ZERO));
+        assert_ne!(derive_id(alice, U256::ZERO), derive_id(bob, U256::ZERO));
+        assert_ne!(
+            derive_id(alice, U256::ZERO),
+            derive_id(alice, uint!(1_U256))
+        );
+    }
+
+    #[test]
+    fn derive_tranche_id_depends_on_base_id_and_tranche() {
+        let id = derive_tranche_id(uint!(1_U256), uint!(2_U256));
+
+        assert_eq!(id, derive_tranche_id(uint!(1_U256), uint!(2_U256)));
+        assert_ne!(id, derive_tranche_id(uint!(2_U256), uint!(1_U256)));
+        assert_ne!(id, derive_tranche_id(uint!(1_U256), uint!(3_U256)));
+    }
+
+    #[test]
+    fn currency_id_round_trips() {
+        let currency = address!("0xA11CE00000000000000000000000000000000001");
+        let id = id_from_currency(currency);
+
+        assert_eq!(id, uint!(0xA11CE00000000000000000000000000000000001_U256));
+        assert_eq!(currency_from_id(id), currency);
+        assert_eq!(id_from_currency(Address::ZERO), U256::ZERO);
+        assert_eq!(currency_from_id(U256::ZERO), Address::ZERO);
+    }
+
+    #[test]
+    fn currency_from_id_ignores_upper_bits() {
+        let currency = address!("0xA11CE00000000000000000000000000000000001");
+        let id = id_from_currency(currency) | (U256::MAX << 160_usize);
+
+        assert_eq!(currency_from_id(id), currency);
+        assert_eq!(currency_from_id(U256::MAX), Address::repeat_byte(0xff));
+    }
+}
@@ -1,13 +1,20 @@
 //! Implementation of the ERC-6909 token standard.
 use alloc::{vec, vec::Vec};
 
+pub use abi::{
+    Approval, ERC6909InvalidApprover, ERC6909InvalidApproverForId,
+    ERC6909InvalidArrayLength, ERC6909InvalidReceiver, ERC6909InvalidSender,
+    ERC6909InvalidSpender, ERC6909InvalidSpenderForId, ERC6909SelfTarget,
+    Erc6909InsufficientAllowance, Erc6909InsufficientBalance,
+    Erc6909InsufficientPermission, OperatorSet, Transfer, TransferBatch,
+    TransferSingle,
+};
 use alloy_primitives::{Address, FixedBytes, U256};
-use openzeppelin_stylus_proc::interface_id;
-pub use sol::*;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 use stylus_sdk::{
     evm, msg,
     prelude::*,
-    storage::{StorageBool, StorageMap, StorageU256},
+    storage::{StorageBool, StorageMap, StorageType, StorageU256},
 };
 
 use crate::utils::{
@@ -15,161 +22,15 @@ use crate::utils::{
     math::storage::{AddAssignChecked, SubAssignUnchecked},
 };
 
+pub mod abi;
+pub mod delegate;
 /// Extensions to the ERC-6909 contract.
 pub mod extensions;
-
-mod sol {
-    use alloy_sol_macro::sol;
-
-    sol! {
-        /// Emitted when a `caller` transfers an `amount` of token `id`
-        /// from a `sender` to a receiver.
-        ///
-        /// * `caller` - Address of the initiator of the transfer.
-        /// * `sender` - Address of the sender.
-        /// * `receiver` - Address of the receiver.
-        /// * `id` - Token id as a number.
-        /// * `amount` - Amount of token transferred.
-        #[derive(Debug)]
-        event Transfer(
-            address caller,
-            address indexed sender,
-            address indexed receiver,
-            uint256 indexed id,
-            uint256 amount,
-        );
-
-        /// Emitted when a token `owner` sets the `approved` status of
-        /// a `spender`.
-        ///
-        /// * `owner` - Address of the owner of the token.
-        /// * `spender` - Address of the spender.
-        /// * `approved` - Approved status as a boolean.
-        #[derive(Debug)]
-        event OperatorSet(
-            address indexed owner,
-            address indexed spender,
-            bool approved,
-        );
-
-        /// Emitted when a token `owner` has approved a `spender` to
-        /// transfer an `amount` of a token `id` to be transferred
-        /// on the owner's behalf.
-        ///
-        /// * `owner` - Address of the owner of the token.
-        /// * `spender` - Address of the spender.
-        /// * `id` - Token id as a number.
-        /// * `amount` - Amount of token approved to be transferred.
-        #[derive(Debug)]
-        event Approval(
-            address indexed owner,
-            address indexed spender,
-            uint256 indexed id,
-            uint256 amount,
-        );
-
-        /// Emitted when `amount` of tokens of type `id` are
-        /// transferred from `from` to `to` by `caller`.
-        #[derive(Debug)]
-        event TransferSingle(
-            address indexed caller,
-            address indexed from,
-            address indexed to,
-            uint256 id,
-            uint256 amount
-        );
-
-        /// Equivalent to multiple [`TransferSingle`] events, where `caller`
-        /// `from` and `to` are the same for all transfers.
-        #[derive(Debug)]
-        event TransferBatch(
-            address indexed caller,
-            address indexed from,
-            address indexed to,
-            uint256[] ids,
-            uint256[] amounts
-        );
-    }
-
-    sol! {
-        /// Thrown when `owner`'s balance for `id` is insufficient.
-        ///
-        /// * `owner` - Address of the owner of the token.
-        /// * `id` - Token id as a number.
-        #[derive(Debug)]
-        error Erc6909InsufficientBalance(
-            address sender,
-            uint256 balance,
-            uint256 needed,
-            uint256 id,
-        );
-
-        /// Thrown when the spender does not have permission to
-        /// spend the token.
-        ///
-        /// * `spender` - Address of the spender
-        /// * `id` - Token id as a number
-        #[derive(Debug)]
-        error Erc6909InsufficientPermission(
-            address spender,
-            uint256 id
-        );
-
-        /// Thrown when a `spender`'s allowance for a token type
-        /// of `id` is insufficient.
-        ///
-        /// * `owner` - Address of the owner of the token.
-        /// * `id` - Token id as a number.
-        #[derive(Debug)]
-        error Erc6909InsufficientAllowance(
-            address spender,
-            uint256 allowance,
-            uint256 needed,
-            uint256 id,
-        );
-
-        /// Indicates a failure with the token `sender`.
-        /// Used in transfers.
-        ///
-        /// * `approver` - Approver of the token.
-        #[derive(Debug)]
-        error ERC6909InvalidApprover(address approver);
-
-        /// Indicates a failure with the token `sender`.
-        /// Used in transfers.
-        ///
-        /// * `sender` - Address whose tokens are being transferred.
-        #[derive(Debug)]
-        error ERC6909InvalidSender(address sender);
-
-        /// Indicates a failure with the token `spender`.
-        /// Used in transfers.
-        ///
-        /// * `spender` - Address attempting to spend tokens on behalf
-        /// of another address.
-        #[derive(Debug)]
-        error ERC6909InvalidSpender(address spender);
-
-        /// Indicates a failure with the token `receiver`.
-        /// Used in transfers.
-        ///
-        /// * `receiver` - Address to which tokens are being transferred.
-        #[derive(Debug)]
-        error ERC6909InvalidReceiver(address receiver);
-
-        /// Indicates an array length mismatch between token ids and values in a
-        /// batch operation.
-        ///
-        /// * `ids_length` - Length of the array of token identifiers.
-        /// * `values_length` - Length of the array of token amounts.
-        #[derive(Debug)]
-        #[allow(missing_docs)]
-        error ERC6909InvalidArrayLength(
-            uint256 ids_length,
-            uint256 values_length
-        );
-    }
-}
+pub mod interface;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod invariant;
+pub mod lib;
+pub mod utils;
 
 /// An [`Erc6909`] error.
 #[derive(SolidityError, Debug)]
@@ -186,11 +47,18 @@ pub enum Error {
     InvalidSender(ERC6909InvalidSender),
     /// Indicates the sender is invalid.
     InvalidSpender(ERC6909InvalidSpender),
+    /// Indicates the approver of an allowance over a token id is invalid.
+    InvalidApproverForId(ERC6909InvalidApproverForId),
+    /// Indicates the spender of an allowance over a token id is invalid.
+    InvalidSpenderForId(ERC6909InvalidSpenderForId),
     /// Indicates the receiver is invalid.
     InvalidReceiver(ERC6909InvalidReceiver),
     /// Indicates an array length mismatch between token ids and values in a
     /// batch operation.
     InvalidArrayLength(ERC6909InvalidArrayLength),
+    /// Indicates the token contract itself is the receiver of a transfer or
+    /// the spender of an approval.
+    SelfTarget(ERC6909SelfTarget),
 }
 
 /// State of an [`Erc6909`] token.
@@ -206,9 +74,73 @@ pub struct Erc6909 {
         StorageMap<Address, StorageMap<Address, StorageMap<U256, StorageU256>>>,
 }
 
-/// Implementation of [`TopLevelStorage`]
+/// Implementation of [`TopLevelStorage`], so that an [`Erc6909`] can be
+/// tested on its own.
+///
+/// Only available in tests, and to other crates through the `test-utils`
+/// feature: [`Erc6909`] is meant to be embedded in the entrypoint of a
+/// contract, which is the actual top-level storage.
+#[cfg(any(test, feature = "test-utils"))]
 unsafe impl TopLevelStorage for Erc6909 {}
 
+impl AsRef<Erc6909> for Erc6909 {
+    fn as_ref(&self) -> &Erc6909 {
+        self
+    }
+}
+
+impl AsMut<Erc6909> for Erc6909 {
+    fn as_mut(&mut self) -> &mut Erc6909 {
+        self
+    }
+}
+
+/// Implements [`AsRef<Erc6909>`] and [`AsMut<Erc6909>`] for the extension
+/// `$name`, whose [`Erc6909`] is reached through the fields `$field`, or for
+/// the generic extension `$name<T: Bound, ..>`, whose [`Erc6909`] is reached
+/// through the [`AsRef<Erc6909>`] of its fields `$field`, e.g. of the
+/// [`IErc6909Internal`] token it wraps.
+///
+/// Composed contracts can then reach the base token of any extension, or of
+/// a plain [`Erc6909`], the same way. Mutating the base token directly
+/// bypasses the hooks of the extension, e.g. supply tracking, so
+/// [`AsMut::as_mut`] is meant for the functions the extension doesn't
+/// override.
+macro_rules! impl_as_erc6909 {
+    ($name:ident, $($field:ident).+) => {
+        impl AsRef<$crate::token::erc6909::Erc6909> for $name {
+            fn as_ref(&self) -> &$crate::token::erc6909::Erc6909 {
+                &self.$($field).+
+            }
+        }
+
+        impl AsMut<$crate::token::erc6909::Erc6909> for $name {
+            fn as_mut(&mut self) -> &mut $crate::token::erc6909::Erc6909 {
+                &mut self.$($field).+
+            }
+        }
+    };
+    ($name:ident<$($param:ident: $bound:path),+>, $($field:ident).+) => {
+        impl<$($param: $bound),+> AsRef<$crate::token::erc6909::Erc6909>
+            for $name<$($param),+>
+        {
+            fn as_ref(&self) -> &$crate::token::erc6909::Erc6909 {
+                self.$($field).+.as_ref()
+            }
+        }
+
+        impl<$($param: $bound),+> AsMut<$crate::token::erc6909::Erc6909>
+            for $name<$($param),+>
+        {
+            fn as_mut(&mut self) -> &mut $crate::token::erc6909::Erc6909 {
+                self.$($field).+.as_mut()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_as_erc6909;
+
 /// Required interface of an [`Erc6909`] compliant contract.
 #[interface_id]
 pub trait IErc6909: IErc165 {
@@ -228,7 +160,8 @@ pub trait IErc6909: IErc165 {
     /// # Errors
     ///
     /// * [`Error::InvalidSender`] - If `from` is zero address.
-    /// * [`Error::InvalidReceiver`] - If `to` is zero address.
+    /// * [`Error::InvalidReceiver`] - If `to` is zero address, without the
+    ///   `burn-on-zero-transfer` feature.
     /// * [`Error::InsufficientBalance`] - If `from` address's balaance is less
     ///   that `amount`.
     ///
@@ -247,6 +180,10 @@ pub trait IErc6909: IErc165 {
     /// Transfers `amount` tokens of token type `id` from `sender` to
     /// `receiver`.
     ///
+    /// The caller's allowance is only spent when the caller is neither
+    /// `sender` nor one of its operators. Operators don't need an allowance,
+    /// and their allowance, if any, is left untouched.
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -258,7 +195,8 @@ pub trait IErc6909: IErc165 {
     /// # Errors
     ///
     /// * [`Error::InvalidSender`] - If `from` is zero address.
-    /// * [`Error::InvalidReceiver`] - If `to` is zero address.
+    /// * [`Error::InvalidReceiver`] - If `to` is zero address, without the
+    ///   `burn-on-zero-transfer` feature.
     /// * [`Error::InsufficientBalance`] - If `from` address's balaance is less
     ///   that `amount`.
     /// * [`Error::InsufficientAllowance`] - If the caller does not have enough
@@ -288,8 +226,8 @@ pub trait IErc6909: IErc165 {
     ///
     /// # Errors
     ///
-    /// * [`Error::InvalidApprover`] - If `owner` is zero address
-    /// * [`Error::InvalidSpender`] - If `spender` is zero address
+    /// * [`Error::InvalidApproverForId`] - If `owner` is zero address
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is zero address
     ///
     /// # Events
     ///
@@ -358,6 +296,157 @@ pub trait IErc6909: IErc165 {
     fn is_operator(&self, owner: Address, spender: Address) -> bool;
 }
 
+/// Internal surface of an ERC-6909 implementation.
+///
+/// Implemented by [`Erc6909`] and by the extensions wrapping it, so that
+/// generic code (routers, factories, test kits) can mint, burn and move tokens
+/// of any ERC-6909 implementation of this crate, going through the hooks of
+/// the extension being used.
+///
+/// Extensions generic over the token they wrap, such as
+/// [`extensions::Erc6909BalanceCap`] and [`extensions::Erc6909Votes`], can
+/// thus be stacked on top of any of them, e.g. to cap balances while tracking
+/// the total supply of each token id. The wrapped token is reached through
+/// [`AsRef<Erc6909>`] and [`AsMut<Erc6909>`] for the functions it doesn't
+/// override, like approvals.
+pub trait IErc6909Internal:
+    StorageType + IErc165 + AsRef<Erc6909> + AsMut<Erc6909>
+{
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Transfers `amounts` of tokens `ids` from `from` to `to`, or
+    /// alternatively mints (or burns) if `from` (or `to`) is the zero
+    /// address.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all token ids.
+    /// * `amounts` - Array of all amounts of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `amounts`.
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`] - If the arrays contain one element.
+    /// * [`TransferBatch`] - If the arrays contain more than one element.
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error>;
+
+    /// Creates an `amount` of tokens of type `id`, and assigns them to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be minted.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `to` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`].
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Destroys an `amount` of tokens of type `id` from `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to burn tokens from.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to be burned.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `from` is [`Address::ZERO`].
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of `from`.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`].
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Sets `amount` as the allowance of `spender` over the `owner`'s `id`
+    /// tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `spender` - Account allowed to spend `owner`'s tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApproverForId`] - If `owner` is [`Address::ZERO`].
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`].
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Grants or revokes `spender` operator privileges over the `owner`'s
+    /// account.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account of the token's owner.
+    /// * `spender` - Account whose operator status is updated.
+    /// * `approved` - Whether `spender` is an operator of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApprover`] - If `owner` is [`Address::ZERO`].
+    /// * [`Error::InvalidSpender`] - If `spender` is [`Address::ZERO`].
+    ///
+    /// # Events
+    ///
+    /// * [`OperatorSet`].
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error>;
+}
+
 #[public]
 #[implements(IErc6909<Error = Error>, IErc165)]
 impl Erc6909 {}
@@ -385,11 +474,7 @@ impl IErc6909 for Erc6909 {
         amount: U256,
     ) -> Result<bool, Self::Error> {
         let caller = msg::sender();
-
-        if !self.is_operator(sender, caller) && sender != caller {
-            self._spend_allowance(sender, caller, id, amount)?;
-        }
-
+        self._check_authorization(sender, caller, id, amount)?;
         self._transfer(sender, receiver, id, amount)?;
         Ok(true)
     }
@@ -437,11 +522,146 @@ impl IErc165 for Erc6909 {
 }
 
 impl Erc6909 {
+    /// Batched version of [`IErc6909::set_operator`]: sets or removes each
+    /// of `spenders` as an operator for the caller, with the matching status
+    /// of `approvals`.
+    ///
+    /// Not part of [`IErc6909`], so contracts need to expose it explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spenders` - Addresses of the spenders.
+    /// * `approvals` - Approval statuses, one per spender.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidArrayLength`] - If length of `spenders` is not equal
+    ///   to length of `approvals`.
+    /// * [`Error::InvalidApprover`] - If the caller is zero address.
+    /// * [`Error::InvalidSpender`] - If any of `spenders` is zero address.
+    ///
+    /// # Events
+    ///
+    /// * [`OperatorSet`] event for each spender.
+    ///
+    /// Returns a boolean value indicating success or failure.
+    pub fn set_operators(
+        &mut self,
+        spenders: Vec<Address>,
+        approvals: Vec<bool>,
+    ) -> Result<bool, Error> {
+        lib::require_equal_arrays_length(&spenders, &approvals)?;
+
+        let caller = msg::sender();
+        for (spender, approved) in spenders.into_iter().zip(approvals) {
+            self._set_operator(caller, spender, approved)?;
+        }
+        Ok(true)
+    }
+
+    /// Clears the allowances of `spender` over `owner`'s tokens of each of
+    /// `ids`, so that long-running accounts, e.g. market makers, can reclaim
+    /// the storage of stale approvals in a single call.
+    ///
+    /// Allowances that are already zero are skipped.
+    ///
+    /// Not part of [`IErc6909`], so contracts need to expose it explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Address of the account whose allowances are cleared.
+    /// * `spender` - Address of the account whose allowances are cleared.
+    /// * `ids` - Token ids whose allowances are cleared.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApprover`] - If the caller is neither `owner` nor one
+    ///   of its operators.
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`] event with a zero amount, for each cleared allowance.
+    ///
+    /// Returns a boolean value indicating success or failure.
+    pub fn cleanup(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        ids: Vec<U256>,
+    ) -> Result<bool, Error> {
+        let caller = msg::sender();
+        if owner.is_zero()
+            || (caller != owner && !self.is_operator(owner, caller))
+        {
+            return Err(Error::InvalidApprover(ERC6909InvalidApprover {
+                approver: caller,
+            }));
+        }
+
+        for id in ids {
+            let mut owner_allowances = self.allowances.setter(owner);
+            let mut spender_allowances = owner_allowances.setter(spender);
+            let mut allowance = spender_allowances.setter(id);
+            if allowance.get().is_zero() {
+                continue;
+            }
+
+            allowance.erase();
+            evm::log(Approval { owner, spender, id, amount: U256::ZERO });
+        }
+
+        Ok(true)
+    }
+
+    /// Returns whether `spender` may transfer `amount` of token `id` on
+    /// behalf of `owner` through [`IErc6909::transfer_from`], i.e. whether it
+    /// is `owner`, one of its operators, or has enough allowance.
+    ///
+    /// Runs the same checks as [`IErc6909::transfer_from`], without spending
+    /// any allowance, so that routers can pre-verify the legs of a batch and
+    /// only submit the viable ones. It does not check `owner`'s balance.
+    ///
+    /// Not part of [`IErc6909`], so contracts need to expose it explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Address of the account whose tokens would be transferred.
+    /// * `spender` - Address of the account that would transfer them.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens that would be transferred.
+    #[must_use]
+    pub fn check_authorization(
+        &self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> bool {
+        if owner.is_zero() {
+            return false;
+        }
+
+        self._bypasses_allowance(owner, spender)
+            || lib::spend_allowance(
+                spender,
+                id,
+                self.allowance(owner, spender, id),
+                amount,
+            )
+            .is_ok()
+    }
+
     /// Moves `amount` of token `id` from `from` to `to` without checking for
     /// approvals. This function verifies that neither the sender nor the
     /// receiver are address(0), which means it cannot mint or burn tokens.
     /// Relies on the `_update` function.
     ///
+    /// With the `burn-on-zero-transfer` feature, a zero `to` burns the tokens
+    /// instead, like [`Self::_burn`].
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -453,7 +673,10 @@ impl Erc6909 {
     /// # Errors
     ///
     /// * [`Error::InvalidSender`] - If `from` is zero address.
-    /// * [`Error::InvalidReceiver`] - If `to` is zero address.
+    /// * [`Error::InvalidReceiver`] - If `to` is zero address, without the
+    ///   `burn-on-zero-transfer` feature.
+    /// * [`Error::SelfTarget`] - If `to` is the token contract, with the
+    ///   `self-target-guard` feature.
     ///
     /// # Events
     ///
@@ -464,22 +687,74 @@ impl Erc6909 {
         to: Address,
         id: U256,
         amount: U256,
+    ) -> Result<(), Error> {
+        Self::_check_transfer_params(from, to)?;
+        self._update_single(from, to, id, amount)
+    }
+
+    /// Checks the sender and receiver of a transfer, before any balance is
+    /// moved.
+    ///
+    /// Extensions overriding `_transfer` must call it too, so that
+    /// transfers of every token reject the same parameters, whatever the
+    /// features enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Address whose tokens are being transferred.
+    /// * `to` - Address to which tokens are being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `from` is zero address.
+    /// * [`Error::InvalidReceiver`] - If `to` is zero address, without the
+    ///   `burn-on-zero-transfer` feature.
+    /// * [`Error::SelfTarget`] - If `to` is the token contract, with the
+    ///   `self-target-guard` feature.
+    #[cfg_attr(
+        all(
+            feature = "burn-on-zero-transfer",
+            not(feature = "self-target-guard")
+        ),
+        allow(unused_variables)
+    )]
+    pub fn _check_transfer_params(
+        from: Address,
+        to: Address,
     ) -> Result<(), Error> {
         if from.is_zero() {
             return Err(Error::InvalidSender(ERC6909InvalidSender {
                 sender: from,
             }));
         }
+        #[cfg(not(feature = "burn-on-zero-transfer"))]
         if to.is_zero() {
             return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
                 receiver: to,
             }));
         }
-        self._update(from, to, vec![id], vec![amount])?;
+        #[cfg(feature = "self-target-guard")]
+        Self::_check_self_target(to)?;
         Ok(())
     }
 
-    /// Transfers `amount` of token `id` from `from` to `to`
+    /// Variant of [`Self::_transfer`] that moves balances without emitting a
+    /// [`TransferSingle`] event.
+    ///
+    /// Meant for protocols rebalancing their own accounts, which emit their
+    /// own domain events and would otherwise pay for both logs. Indexers
+    /// relying on transfer events won't see these moves, so this function
+    /// must never back a user-facing transfer.
+    ///
+    /// It also bypasses the `_update` of the extensions wrapping
+    /// [`Erc6909`], so it must not be used for token ids whose transfers are
+    /// tracked by one of them, e.g. votes or holder counts.
+    ///
+    /// With the `burn-on-zero-transfer` feature, a zero `to` burns the tokens
+    /// instead, like [`Self::_transfer`].
+    ///
+    /// Internal function, only available with the `silent-transfers`
+    /// feature.
     ///
     /// # Arguments
     ///
@@ -491,24 +766,55 @@ impl Erc6909 {
     ///
     /// # Errors
     ///
-    /// * [`Error::InsufficientBalance`] - If `from` address's balaance is less
-    ///   that `amount`.
-    ///
-    /// # Events
-    ///
-    /// * [`Transfer`] event.
-    fn _update(
+    /// * [`Error::InvalidSender`] - If `from` is zero address.
+    /// * [`Error::InvalidReceiver`] - If `to` is zero address, without the
+    ///   `burn-on-zero-transfer` feature.
+    /// * [`Error::SelfTarget`] - If `to` is the token contract, with the
+    ///   `self-target-guard` feature.
+    /// * [`Error::InsufficientBalance`] - If `from` address's balance is less
+    ///   than `amount`.
+    #[cfg(feature = "silent-transfers")]
+    pub fn _transfer_silent(
         &mut self,
         from: Address,
         to: Address,
-        ids: Vec<U256>,
-        amounts: Vec<U256>,
+        id: U256,
+        amount: U256,
     ) -> Result<(), Error> {
-        Self::require_equal_arrays_length(&ids, &amounts)?;
-
-        let caller = msg::sender();
+        Self::_check_transfer_params(from, to)?;
+        self._do_update(from, to, id, amount)
+    }
 
-        for (&token_id, &amount) in ids.iter().zip(amounts.iter()) {
+    /// Transfers `amount` of token `id` from `from` to `to`
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Address whose tokens are being transferred.
+    /// * `to` - Address to which tokens are being transferred.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of token transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `from` address's balaance is less
+    ///   that `amount`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`] event.
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        lib::require_equal_arrays_length(&ids, &amounts)?;
+
+        let caller = msg::sender();
+
+        for (&token_id, &amount) in ids.iter().zip(amounts.iter()) {
             self._do_update(from, to, token_id, amount)?;
         }
 
@@ -522,6 +828,39 @@ impl Erc6909 {
         Ok(())
     }
 
+    /// Single-id version of [`Self::_update`], used by single transfers,
+    /// mints and burns so that they don't allocate arrays of ids and amounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens being moved.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If `amount` is greater than the
+    ///   balance of the `from` account.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`] event.
+    fn _update_single(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self._do_update(from, to, id, amount)?;
+
+        let caller = msg::sender();
+        evm::log(TransferSingle { caller, from, to, id, amount });
+        Ok(())
+    }
+
     /// Sets `amount` as the allowance of `spender` over the `owner`'s `id`
     /// tokens.
     ///
@@ -538,8 +877,8 @@ impl Erc6909 {
     ///
     /// # Errors
     ///
-    /// * [`Error::InvalidApprover`] - If `owner` is zero address
-    /// * [`Error::InvalidSpender`] - If `spender` is zero address
+    /// * [`Error::InvalidApproverForId`] - If `owner` is zero address
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is zero address
     ///
     /// # Events
     ///
@@ -550,20 +889,87 @@ impl Erc6909 {
         spender: Address,
         id: U256,
         amount: U256,
+    ) -> Result<(), Error> {
+        self._approve_with_event(owner, spender, id, amount, true)
+    }
+
+    /// Variant of [`Self::_approve`] that only emits an [`Approval`] event
+    /// when `emit_event` is true.
+    ///
+    /// Signature-based approvals, like permits, that are consumed within the
+    /// same transaction, e.g. by permit-and-transfer routers, can skip the
+    /// event of their ephemeral allowance to cut log costs. Approvals made
+    /// through [`IErc6909::approve`] must always emit it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Address of account whose tokens a `spender` is approved to
+    ///   spend.
+    /// * `spender` - Address of account that will be allowed to spend an
+    ///   `amount` of `owner`'s tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is allowed to spend on behalf of
+    ///   `owner`.
+    /// * `emit_event` - Emit an [`Approval`] event flag.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApproverForId`] - If `owner` is zero address
+    /// * [`Error::InvalidSpenderForId`] - If `spender` is zero address
+    /// * [`Error::SelfTarget`] - If `spender` is the token contract, with the
+    ///   `self-target-guard` feature.
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`] event, if `emit_event` is true.
+    fn _approve_with_event(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        emit_event: bool,
     ) -> Result<(), Error> {
         if owner.is_zero() {
-            return Err(Error::InvalidApprover(ERC6909InvalidApprover {
-                approver: owner,
-            }));
+            return Err(Error::InvalidApproverForId(
+                ERC6909InvalidApproverForId { approver: owner, id },
+            ));
         }
         if spender.is_zero() {
-            return Err(Error::InvalidSpender(ERC6909InvalidSpender {
-                spender,
-            }));
+            return Err(Error::InvalidSpenderForId(
+                ERC6909InvalidSpenderForId { spender, id },
+            ));
         }
+        #[cfg(feature = "self-target-guard")]
+        Self::_check_self_target(spender)?;
 
         self.allowances.setter(owner).setter(spender).setter(id).set(amount);
-        evm::log(Approval { owner, spender, id, amount });
+        if emit_event {
+            evm::log(Approval { owner, spender, id, amount });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `target` is not the token contract itself, as tokens
+    /// transferred to it, or allowances granted to it, can't be moved
+    /// anymore.
+    ///
+    /// Only available with the `self-target-guard` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Receiver of a transfer or spender of an approval.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SelfTarget`] - If `target` is the token contract.
+    #[cfg(feature = "self-target-guard")]
+    fn _check_self_target(target: Address) -> Result<(), Error> {
+        if target == stylus_sdk::contract::address() {
+            return Err(Error::SelfTarget(ERC6909SelfTarget { target }));
+        }
 
         Ok(())
     }
@@ -624,6 +1030,8 @@ impl Erc6909 {
     ///
     /// # Errors
     ///
+    /// * [`Error::InvalidApproverForId`] - If `owner` is zero address, as no
+    ///   allowance can be granted by it.
     /// * [`Error::InsufficientAllowance`] - If `spender` does not have enough
     ///   allowance to spend `amount`
     fn _spend_allowance(
@@ -633,28 +1041,89 @@ impl Erc6909 {
         id: U256,
         amount: U256,
     ) -> Result<(), Error> {
-        let current_allowance = self.allowance(owner, spender, id);
-
-        if amount > current_allowance {
-            return Err(Error::InsufficientAllowance(
-                Erc6909InsufficientAllowance {
-                    spender,
-                    allowance: current_allowance,
-                    needed: current_allowance,
-                    id,
-                },
+        // Unreachable through `_check_authorization`, but guards hooks
+        // spending allowances directly.
+        if owner.is_zero() {
+            return Err(Error::InvalidApproverForId(
+                ERC6909InvalidApproverForId { approver: owner, id },
             ));
         }
 
-        self.allowances
-            .setter(owner)
-            .setter(spender)
-            .setter(id)
-            .sub_assign_unchecked(amount);
+        // Keep a single handle on the allowance's slot, so that its keys are
+        // hashed once for both the read and the write.
+        let mut owner_allowances = self.allowances.setter(owner);
+        let mut spender_allowances = owner_allowances.setter(spender);
+        let mut allowance = spender_allowances.setter(id);
+        let remaining =
+            lib::spend_allowance(spender, id, allowance.get(), amount)?;
+
+        // Clear exhausted allowances explicitly, so that their slot is
+        // refunded.
+        if remaining.is_zero() {
+            allowance.erase();
+        } else {
+            allowance.set(remaining);
+        }
 
         Ok(())
     }
 
+    /// Checks that `spender` may transfer `amount` of token `id` on behalf
+    /// of `owner`, and spends its allowance when it is neither `owner` nor
+    /// one of its operators.
+    ///
+    /// See [`Self::check_authorization`] for the read-only counterpart.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Address of account whose tokens a `spender` is attempting to
+    ///   transfer.
+    /// * `spender` - Address of account transferring an `amount` of `owner`'s
+    ///   tokens.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens `spender` is attempting to transfer on
+    ///   behalf of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `owner` is zero address, before any
+    ///   allowance is looked up.
+    /// * [`Error::InsufficientAllowance`] - If `spender` is neither `owner` nor
+    ///   one of its operators, and does not have enough allowance to spend
+    ///   `amount`.
+    fn _check_authorization(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        if owner.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: owner,
+            }));
+        }
+
+        if self._bypasses_allowance(owner, spender) {
+            return Ok(());
+        }
+
+        self._spend_allowance(owner, spender, id, amount)
+    }
+
+    /// Returns whether `spender` may move `owner`'s tokens without an
+    /// allowance, i.e. whether it is `owner` or one of its operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Address of the account whose tokens are moved.
+    /// * `spender` - Address of the account moving them.
+    fn _bypasses_allowance(&self, owner: Address, spender: Address) -> bool {
+        owner == spender || self.is_operator(owner, spender)
+    }
+
     /// Creates an `amount` amount of tokens of type `id`, and assigns
     /// them to `to`.
     ///
@@ -682,7 +1151,12 @@ impl Erc6909 {
         id: U256,
         amount: U256,
     ) -> Result<(), Error> {
-        self._do_mint(to, vec![id], vec![amount])
+        if to.is_zero() {
+            return Err(Error::InvalidReceiver(ERC6909InvalidReceiver {
+                receiver: to,
+            }));
+        }
+        self._update_single(Address::ZERO, to, id, amount)
     }
 
     /// Batched version of [`Self::_mint`].
@@ -743,7 +1217,12 @@ impl Erc6909 {
         id: U256,
         amount: U256,
     ) -> Result<(), Error> {
-        self._do_burn(from, vec![id], vec![amount])
+        if from.is_zero() {
+            return Err(Error::InvalidSender(ERC6909InvalidSender {
+                sender: from,
+            }));
+        }
+        self._update_single(from, Address::ZERO, id, amount)
     }
 
     /// Batched version of [`Self::_burn`].
@@ -857,30 +1336,6 @@ impl Erc6909 {
         Ok(())
     }
 
-    /// Checks if `ids` array has same length as `values` array.
-    ///
-    /// # Arguments
-    ///
-    /// * `ids` - array of `ids`.
-    /// * `values` - array of `values`.
-    ///
-    /// # Errors
-    ///
-    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
-    ///   length of `values`.
-    fn require_equal_arrays_length<T, U>(
-        ids: &[T],
-        values: &[U],
-    ) -> Result<(), Error> {
-        if ids.len() != values.len() {
-            return Err(Error::InvalidArrayLength(ERC6909InvalidArrayLength {
-                ids_length: U256::from(ids.len()),
-                values_length: U256::from(values.len()),
-            }));
-        }
-        Ok(())
-    }
-
     /// Transfers a `amount` amount of `id` from `from` to
     /// `to`. Will mint (or burn) if `from` (or `to`) is the [`Address::ZERO`].
     ///
@@ -919,7 +1374,15 @@ impl Erc6909 {
                     },
                 ));
             }
-            self.balances.setter(from).setter(id).sub_assign_unchecked(amount);
+            let mut from_balances = self.balances.setter(from);
+            let mut balance = from_balances.setter(id);
+            if from_balance == amount {
+                // Clear emptied balances explicitly, so that their slot is
+                // refunded.
+                balance.erase();
+            } else {
+                balance.sub_assign_unchecked(amount);
+            }
         }
 
         if !to.is_zero() {
@@ -933,16 +1396,139 @@ impl Erc6909 {
     }
 }
 
+impl IErc6909Internal for Erc6909 {
+    type Error = Error;
+
+    fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Self::Error> {
+        self._update(from, to, ids, amounts)
+    }
+
+    fn _mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._mint(to, id, amount)
+    }
+
+    fn _burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._burn(from, id, amount)
+    }
+
+    fn _approve(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self._approve(owner, spender, id, amount)
+    }
+
+    fn _set_operator(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        approved: bool,
+    ) -> Result<(), Self::Error> {
+        self._set_operator(owner, spender, approved)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl crate::utils::abi::Events for Erc6909 {
+    fn events() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use crate::utils::abi::event;
+
+        vec![
+            event::<Transfer>(
+                "event Transfer(address caller, address indexed sender, \
+                 address indexed receiver, uint256 indexed id, uint256 \
+                 amount)",
+            ),
+            event::<OperatorSet>(
+                "event OperatorSet(address indexed owner, address indexed \
+                 spender, bool approved)",
+            ),
+            event::<Approval>(
+                "event Approval(address indexed owner, address indexed \
+                 spender, uint256 indexed id, uint256 amount)",
+            ),
+            event::<TransferSingle>(
+                "event TransferSingle(address indexed caller, address \
+                 indexed from, address indexed to, uint256 id, uint256 \
+                 amount)",
+            ),
+            event::<TransferBatch>(
+                "event TransferBatch(address indexed caller, address \
+                 indexed from, address indexed to, uint256[] ids, uint256[] \
+                 amounts)",
+            ),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U256};
+    use alloy_primitives::{
+        fixed_bytes, uint, Address, FixedBytes, B256, U256,
+    };
+    use alloy_sol_types::{SolCall, SolEvent};
     use motsu::prelude::*;
 
-    use super::{Erc6909, IErc6909};
+    #[cfg(feature = "self-target-guard")]
+    use super::ERC6909SelfTarget;
+    use super::{
+        Approval, ERC6909InvalidApprover, ERC6909InvalidArrayLength,
+        ERC6909InvalidReceiver, ERC6909InvalidSender, ERC6909InvalidSpender,
+        Erc6909, Erc6909InsufficientAllowance, Error, IErc6909,
+        IErc6909Internal, OperatorSet, TransferSingle,
+    };
     use crate::utils::introspection::erc165::IErc165;
 
     const TOKEN_ID: U256 = uint!(1_U256);
 
+    fn mint_and_burn<T: IErc6909Internal>(
+        token: &mut T,
+        account: Address,
+        minted: U256,
+        burned: U256,
+    ) -> Result<(), T::Error> {
+        token._mint(account, TOKEN_ID, minted)?;
+        token._burn(account, TOKEN_ID, burned)
+    }
+
+    #[motsu::test]
+    fn internal_trait_mints_and_burns(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let result = mint_and_burn(
+            &mut *contract.sender(alice),
+            alice,
+            uint!(10_U256),
+            uint!(4_U256),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(
+            contract.sender(alice).balance_of(alice, TOKEN_ID),
+            uint!(6_U256)
+        );
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc6909 as IErc6909>::interface_id();
@@ -950,6 +1536,26 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn abi_matches_interface_id() {
+        use super::abi::IErc6909::*;
+
+        let selectors = [
+            balanceOfCall::SELECTOR,
+            allowanceCall::SELECTOR,
+            isOperatorCall::SELECTOR,
+            transferCall::SELECTOR,
+            transferFromCall::SELECTOR,
+            approveCall::SELECTOR,
+            setOperatorCall::SELECTOR,
+        ];
+        let actual = selectors.into_iter().fold([0u8; 4], |id, selector| {
+            core::array::from_fn(|i| id[i] ^ selector[i])
+        });
+
+        assert_eq!(<Erc6909 as IErc6909>::interface_id(), FixedBytes(actual));
+    }
+
     #[motsu::test]
     fn supports_interface(contract: Contract<Erc6909>, alice: Address) {
         assert!(contract
@@ -1042,51 +1648,236 @@ mod tests {
     }
 
     #[motsu::test]
-    fn approve(
+    fn single_operations_emit_transfer_single(
         contract: Contract<Erc6909>,
         alice: Address,
         bob: Address,
-        charlie: Address,
     ) {
+        let amount = uint!(1000_U256);
         contract
             .sender(alice)
-            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            ._mint(alice, TOKEN_ID, amount)
             .expect("should mint a token to Alice");
-
+        contract.assert_emitted(&TransferSingle {
+            caller: alice,
+            from: Address::ZERO,
+            to: alice,
+            id: TOKEN_ID,
+            amount,
+        });
+
+        let amount = uint!(400_U256);
         contract
             .sender(alice)
-            .approve(bob, TOKEN_ID, uint!(300_U256))
-            .expect("Bob should be able to spend to 300 of Alice's tokens");
+            .transfer(bob, TOKEN_ID, amount)
+            .expect("should transfer tokens from Alice to Bob");
+        contract.assert_emitted(&TransferSingle {
+            caller: alice,
+            from: alice,
+            to: bob,
+            id: TOKEN_ID,
+            amount,
+        });
 
         contract
             .sender(bob)
-            .transfer_from(alice, charlie, TOKEN_ID, uint!(200_U256))
-            .expect("should transfer 200 tokens from Alice to Charlie");
+            ._burn(bob, TOKEN_ID, amount)
+            .expect("should burn Bob's tokens");
+        contract.assert_emitted(&TransferSingle {
+            caller: bob,
+            from: bob,
+            to: Address::ZERO,
+            id: TOKEN_ID,
+            amount,
+        });
+
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+    }
 
-        let alice_balance = contract.sender(alice).balance_of(alice, TOKEN_ID);
-        let charlie_balance =
-            contract.sender(alice).balance_of(charlie, TOKEN_ID);
+    #[motsu::test]
+    fn mint_reverts_when_receiver_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._mint(Address::ZERO, TOKEN_ID, uint!(1000_U256))
+            .expect_err("should not mint to the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+    }
 
-        assert_eq!(alice_balance, uint!(800_U256));
-        assert_eq!(charlie_balance, uint!(200_U256));
+    #[cfg(not(feature = "burn-on-zero-transfer"))]
+    #[motsu::test]
+    fn transfer_reverts_when_receiver_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let amount = uint!(1000_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, amount)
+            .expect("should mint to Alice");
+
+        let err = contract
+            .sender(alice)
+            .transfer(Address::ZERO, TOKEN_ID, amount)
+            .expect_err("should not transfer to the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC6909InvalidReceiver { receiver })
+                if receiver.is_zero()
+        ));
+        assert_eq!(amount, contract.sender(alice).balance_of(alice, TOKEN_ID));
     }
 
+    #[cfg(feature = "burn-on-zero-transfer")]
     #[motsu::test]
-    fn set_operator(
+    fn transfer_to_zero_address_burns(
         contract: Contract<Erc6909>,
         alice: Address,
-        bob: Address,
-        charlie: Address,
     ) {
         contract
             .sender(alice)
             ._mint(alice, TOKEN_ID, uint!(1000_U256))
-            .expect("should mint a token to Alice");
+            .expect("should mint to Alice");
 
+        let amount = uint!(400_U256);
         contract
             .sender(alice)
-            .set_operator(bob, true)
-            .expect("Bob should become an operator of Alice's account'");
+            .transfer(Address::ZERO, TOKEN_ID, amount)
+            .expect("should burn Alice's tokens");
+
+        contract.assert_emitted(&TransferSingle {
+            caller: alice,
+            from: alice,
+            to: Address::ZERO,
+            id: TOKEN_ID,
+            amount,
+        });
+        assert_eq!(
+            uint!(600_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[cfg(feature = "burn-on-zero-transfer")]
+    #[motsu::test]
+    fn transfer_from_to_zero_address_spends_allowance_and_burns(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+
+        let amount = uint!(100_U256);
+        contract
+            .sender(bob)
+            .transfer_from(alice, Address::ZERO, TOKEN_ID, amount)
+            .expect("should burn Alice's tokens");
+
+        contract.assert_emitted(&TransferSingle {
+            caller: bob,
+            from: alice,
+            to: Address::ZERO,
+            id: TOKEN_ID,
+            amount,
+        });
+        assert_eq!(
+            uint!(900_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(200_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, Address::ZERO, TOKEN_ID, uint!(300_U256))
+            .expect_err("should not burn more than Bob's allowance");
+
+        assert!(matches!(err, Error::InsufficientAllowance(_)));
+    }
+
+    #[motsu::test]
+    fn burn_reverts_when_sender_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._burn(Address::ZERO, TOKEN_ID, uint!(1000_U256))
+            .expect_err("should not burn from the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSender(ERC6909InvalidSender { sender })
+                if sender.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn approve(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("Bob should be able to spend to 300 of Alice's tokens");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(200_U256))
+            .expect("should transfer 200 tokens from Alice to Charlie");
+
+        let alice_balance = contract.sender(alice).balance_of(alice, TOKEN_ID);
+        let charlie_balance =
+            contract.sender(alice).balance_of(charlie, TOKEN_ID);
+
+        assert_eq!(alice_balance, uint!(800_U256));
+        assert_eq!(charlie_balance, uint!(200_U256));
+    }
+
+    #[motsu::test]
+    fn set_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("Bob should become an operator of Alice's account'");
 
         contract
             .sender(bob)
@@ -1100,4 +1891,780 @@ mod tests {
         assert_eq!(alice_balance, uint!(900_U256));
         assert_eq!(charlie_balance, uint!(100_U256));
     }
+
+    #[motsu::test]
+    fn set_operators_sets_each_status(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            .set_operator(charlie, true)
+            .expect("Charlie should become an operator of Alice's account");
+
+        contract
+            .sender(alice)
+            .set_operators(vec![bob, charlie], vec![true, false])
+            .expect("should rotate Alice's operators");
+
+        assert!(contract.sender(alice).is_operator(alice, bob));
+        assert!(!contract.sender(alice).is_operator(alice, charlie));
+        contract.assert_emitted(&OperatorSet {
+            owner: alice,
+            spender: bob,
+            approved: true,
+        });
+        contract.assert_emitted(&OperatorSet {
+            owner: alice,
+            spender: charlie,
+            approved: false,
+        });
+    }
+
+    #[motsu::test]
+    fn set_operators_reverts_when_arrays_length_mismatch(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .set_operators(vec![bob], vec![true, false])
+            .expect_err("should not set operators");
+
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC6909InvalidArrayLength {
+                ids_length,
+                values_length,
+            }) if ids_length == uint!(1_U256) && values_length == uint!(2_U256)
+        ));
+        assert!(!contract.sender(alice).is_operator(alice, bob));
+    }
+
+    #[motsu::test]
+    fn set_operators_reverts_when_spender_invalid(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .set_operators(vec![bob, Address::ZERO], vec![true, true])
+            .expect_err("should not set the zero address as operator");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSpender(ERC6909InvalidSpender { spender })
+                if spender.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn cleanup_clears_allowances(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let other_id = uint!(2_U256);
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(10_U256))
+            .expect("should approve Bob");
+
+        contract
+            .sender(alice)
+            .cleanup(alice, bob, vec![TOKEN_ID, other_id])
+            .expect("should clean Bob's allowances up");
+
+        assert_eq!(
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID),
+            U256::ZERO
+        );
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: U256::ZERO,
+        });
+    }
+
+    #[motsu::test]
+    fn cleanup_works_for_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(10_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(alice)
+            .set_operator(charlie, true)
+            .expect("Charlie should become an operator of Alice's account");
+
+        contract
+            .sender(charlie)
+            .cleanup(alice, bob, vec![TOKEN_ID])
+            .expect("Charlie should clean Bob's allowance up");
+
+        assert_eq!(
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID),
+            U256::ZERO
+        );
+    }
+
+    #[motsu::test]
+    fn cleanup_reverts_when_caller_not_authorized(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let allowance = uint!(10_U256);
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, allowance)
+            .expect("should approve Bob");
+
+        let err = contract
+            .sender(bob)
+            .cleanup(alice, bob, vec![TOKEN_ID])
+            .expect_err("Bob should not clean Alice's allowances up");
+
+        assert!(matches!(
+            err,
+            Error::InvalidApprover(ERC6909InvalidApprover { approver })
+                if approver == bob
+        ));
+        assert_eq!(
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID),
+            allowance
+        );
+    }
+
+    #[motsu::test]
+    fn spending_whole_allowance_clears_it(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let amount = uint!(10_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, amount)
+            .expect("should mint to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, amount)
+            .expect("should approve Bob");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, amount)
+            .expect("Bob should spend his whole allowance");
+
+        assert_eq!(
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(alice).balance_of(alice, TOKEN_ID),
+            U256::ZERO
+        );
+        assert_eq!(contract.sender(alice).balance_of(bob, TOKEN_ID), amount);
+    }
+
+    #[motsu::test]
+    fn check_authorization_allows_owner_and_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let amount = uint!(10_U256);
+        assert!(contract
+            .sender(alice)
+            .check_authorization(alice, alice, TOKEN_ID, amount));
+        assert!(!contract
+            .sender(alice)
+            .check_authorization(alice, bob, TOKEN_ID, amount));
+
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("Bob should become an operator of Alice's account");
+
+        assert!(contract
+            .sender(alice)
+            .check_authorization(alice, bob, TOKEN_ID, amount));
+    }
+
+    #[motsu::test]
+    fn check_authorization_matches_transfer_from(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let allowance = uint!(10_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(100_U256))
+            .expect("should mint to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, allowance)
+            .expect("should approve Bob");
+
+        let exceeding = allowance + uint!(1_U256);
+        assert!(!contract
+            .sender(alice)
+            .check_authorization(alice, bob, TOKEN_ID, exceeding));
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, exceeding)
+            .expect_err("Bob should not exceed his allowance");
+
+        assert!(contract
+            .sender(alice)
+            .check_authorization(alice, bob, TOKEN_ID, allowance));
+        contract
+            .sender(bob)
+            .transfer_from(alice, bob, TOKEN_ID, allowance)
+            .expect("Bob should spend his allowance");
+
+        // The view spends nothing, but the transfer did.
+        assert!(!contract.sender(alice).check_authorization(
+            alice,
+            bob,
+            TOKEN_ID,
+            U256::ONE
+        ));
+    }
+
+    #[motsu::test]
+    fn check_authorization_rejects_zero_owner(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        assert!(!contract.sender(alice).check_authorization(
+            Address::ZERO,
+            Address::ZERO,
+            TOKEN_ID,
+            U256::ZERO
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_sender_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        for amount in [U256::ZERO, uint!(1_U256)] {
+            let err = contract
+                .sender(alice)
+                .transfer_from(Address::ZERO, bob, TOKEN_ID, amount)
+                .expect_err("should not transfer from the zero address");
+
+            assert!(matches!(
+                err,
+                Error::InvalidSender(ERC6909InvalidSender { sender })
+                    if sender.is_zero()
+            ));
+        }
+    }
+
+    #[motsu::test]
+    fn spend_allowance_reverts_when_owner_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._spend_allowance(Address::ZERO, alice, TOKEN_ID, U256::ZERO)
+            .expect_err("should not spend an allowance of the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidApproverForId(ERC6909InvalidApproverForId {
+                approver,
+                id
+            }) if approver.is_zero() && id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_from_succeeds_for_operator_without_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("Bob should become an operator of Alice's account");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(100_U256))
+            .expect("operator should transfer without allowance");
+
+        assert_eq!(
+            uint!(100_U256),
+            contract.sender(alice).balance_of(charlie, TOKEN_ID)
+        );
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_from_decrements_allowance_of_non_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(100_U256))
+            .expect("should transfer within allowance");
+
+        assert!(!contract.sender(alice).is_operator(alice, bob));
+        assert_eq!(
+            uint!(200_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_from_reverts_when_non_operator_exceeds_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(50_U256))
+            .expect("should approve Bob");
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(100_U256))
+            .expect_err("should not transfer beyond allowance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(Erc6909InsufficientAllowance {
+                spender,
+                allowance,
+                needed,
+                id,
+            }) if spender == bob
+                && allowance == uint!(50_U256)
+                && needed == uint!(100_U256)
+                && id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn transfer_from_does_not_decrement_allowance_of_operator(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(alice)
+            .set_operator(bob, true)
+            .expect("Bob should become an operator of Alice's account");
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(500_U256))
+            .expect("operator should transfer beyond allowance");
+
+        assert_eq!(
+            uint!(300_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(500_U256),
+            contract.sender(alice).balance_of(charlie, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_from_own_balance_does_not_touch_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .approve(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should approve Alice");
+
+        contract
+            .sender(alice)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(100_U256))
+            .expect("should transfer own tokens without allowance");
+
+        assert_eq!(
+            uint!(10_U256),
+            contract.sender(alice).allowance(alice, alice, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(900_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn approve_overwrites_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(100_U256))
+            .expect("should approve Bob again");
+
+        assert_eq!(
+            uint!(100_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[motsu::test]
+    fn approve_reverts_when_spender_is_zero_address(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve(Address::ZERO, TOKEN_ID, uint!(100_U256))
+            .expect_err("should not approve the zero address");
+
+        assert!(matches!(
+            err,
+            Error::InvalidSpenderForId(ERC6909InvalidSpenderForId {
+                spender,
+                id
+            }) if spender.is_zero() && id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn approve_zero_clears_allowance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(1000_U256))
+            .expect("should mint a token to Alice");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, U256::ZERO)
+            .expect("should clear Bob's allowance");
+
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, charlie, TOKEN_ID, uint!(1_U256))
+            .expect_err("should not transfer without allowance");
+
+        assert!(matches!(
+            err,
+            Error::InsufficientAllowance(Erc6909InsufficientAllowance {
+                spender,
+                allowance,
+                ..
+            }) if spender == bob && allowance.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn approve_emits_approval_for_each_call(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, uint!(300_U256))
+            .expect("should approve Bob");
+
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: uint!(300_U256),
+        });
+
+        contract
+            .sender(alice)
+            .approve(bob, TOKEN_ID, U256::ZERO)
+            .expect("should clear Bob's allowance");
+
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            id: TOKEN_ID,
+            amount: U256::ZERO,
+        });
+    }
+
+    #[motsu::test]
+    fn approve_with_event_sets_allowance_without_event(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._approve_with_event(alice, bob, TOKEN_ID, uint!(300_U256), false)
+            .expect("should approve Bob");
+
+        assert_eq!(
+            uint!(300_U256),
+            contract.sender(alice).allowance(alice, bob, TOKEN_ID)
+        );
+    }
+
+    #[cfg(feature = "silent-transfers")]
+    #[motsu::test]
+    fn transfer_silent_moves_balance(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            ._transfer_silent(alice, bob, TOKEN_ID, uint!(4_U256))
+            .expect("should transfer to Bob");
+
+        assert_eq!(
+            uint!(6_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+        assert_eq!(
+            uint!(4_U256),
+            contract.sender(alice).balance_of(bob, TOKEN_ID)
+        );
+    }
+
+    #[cfg(feature = "silent-transfers")]
+    #[motsu::test]
+    fn transfer_silent_reverts_when_balance_insufficient(
+        contract: Contract<Erc6909>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._transfer_silent(alice, bob, TOKEN_ID, U256::ONE)
+            .expect_err("should not transfer more than Alice's balance");
+
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[cfg(all(
+        feature = "silent-transfers",
+        not(feature = "burn-on-zero-transfer")
+    ))]
+    #[motsu::test]
+    fn transfer_silent_reverts_when_receiver_invalid(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._transfer_silent(alice, Address::ZERO, TOKEN_ID, U256::ZERO)
+            .expect_err("should not transfer to the zero address");
+
+        assert!(matches!(err, Error::InvalidReceiver(_)));
+    }
+
+    #[cfg(all(
+        feature = "silent-transfers",
+        feature = "burn-on-zero-transfer"
+    ))]
+    #[motsu::test]
+    fn transfer_silent_to_zero_address_burns(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, uint!(10_U256))
+            .expect("should mint to Alice");
+
+        contract
+            .sender(alice)
+            ._transfer_silent(alice, Address::ZERO, TOKEN_ID, uint!(4_U256))
+            .expect("should burn Alice's tokens");
+
+        assert_eq!(
+            uint!(6_U256),
+            contract.sender(alice).balance_of(alice, TOKEN_ID)
+        );
+    }
+
+    #[cfg(all(feature = "silent-transfers", feature = "self-target-guard"))]
+    #[motsu::test]
+    fn transfer_silent_reverts_when_receiver_is_token_contract(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let amount = uint!(10_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, amount)
+            .expect("should mint to Alice");
+
+        let err = contract
+            .sender(alice)
+            ._transfer_silent(alice, contract.address(), TOKEN_ID, amount)
+            .expect_err("should not transfer to the token contract");
+
+        assert!(matches!(
+            err,
+            Error::SelfTarget(ERC6909SelfTarget { target })
+                if target == contract.address()
+        ));
+        assert_eq!(contract.sender(alice).balance_of(alice, TOKEN_ID), amount);
+    }
+
+    #[cfg(feature = "self-target-guard")]
+    #[motsu::test]
+    fn transfer_reverts_when_receiver_is_token_contract(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let amount = uint!(10_U256);
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID, amount)
+            .expect("should mint to Alice");
+
+        let err = contract
+            .sender(alice)
+            .transfer(contract.address(), TOKEN_ID, amount)
+            .expect_err("should not transfer to the token contract");
+
+        assert!(matches!(
+            err,
+            Error::SelfTarget(ERC6909SelfTarget { target })
+                if target == contract.address()
+        ));
+        assert_eq!(contract.sender(alice).balance_of(alice, TOKEN_ID), amount);
+    }
+
+    #[cfg(feature = "self-target-guard")]
+    #[motsu::test]
+    fn approve_reverts_when_spender_is_token_contract(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .approve(contract.address(), TOKEN_ID, uint!(10_U256))
+            .expect_err("should not approve the token contract");
+
+        assert!(matches!(
+            err,
+            Error::SelfTarget(ERC6909SelfTarget { target })
+                if target == contract.address()
+        ));
+        assert_eq!(
+            contract.sender(alice).allowance(
+                alice,
+                contract.address(),
+                TOKEN_ID
+            ),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn approval_topics_decode_to_owner_spender_and_id() {
+        let owner = Address::repeat_byte(0x11);
+        let spender = Address::repeat_byte(0x22);
+        let approval =
+            Approval { owner, spender, id: TOKEN_ID, amount: uint!(300_U256) };
+
+        let log = approval.encode_log_data();
+        let topics = log.topics();
+
+        assert_eq!(4, topics.len());
+        assert_eq!(Approval::SIGNATURE_HASH, topics[0]);
+        assert_eq!(owner.into_word(), topics[1]);
+        assert_eq!(spender.into_word(), topics[2]);
+        assert_eq!(B256::from(TOKEN_ID), topics[3]);
+
+        let decoded = Approval::decode_log_data(&log, true)
+            .expect("should decode Approval");
+
+        assert_eq!(owner, decoded.owner);
+        assert_eq!(spender, decoded.spender);
+        assert_eq!(TOKEN_ID, decoded.id);
+        assert_eq!(uint!(300_U256), decoded.amount);
+    }
 }
@@ -0,0 +1,4 @@
+//! Utilities for the ERC-6909 standard.
+pub mod safe_erc6909;
+
+pub use safe_erc6909::{ISafeErc6909, SafeErc6909};
@@ -0,0 +1,273 @@
+//! Wrappers around ERC-6909 operations that throw on failure (when the token
+//! contract returns false).
+//!
+//! Tokens that return no value (and instead revert or throw on failure) are
+//! also supported, non-reverting calls are assumed to be successful.
+//!
+//! To use this library, you can add a `#[implements(ISafeErc6909<Error =
+//! Error>)]` attribute to your contract, which allows you to call the safe
+//! operations as `contract.safe_transfer(token_addr, ...)`, etc.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_sol_types::SolCall;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
+pub use sol::*;
+use stylus_sdk::{
+    call::{MethodError, RawCall},
+    function_selector,
+    prelude::*,
+    types::AddressVM,
+};
+
+use crate::utils::introspection::erc165::IErc165;
+
+const BOOL_TYPE_SIZE: usize = 32;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// An operation with an ERC-6909 token failed.
+        ///
+        /// * `token` - Address of the ERC-6909 token.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SafeErc6909FailedOperation(address token);
+    }
+}
+
+/// A [`SafeErc6909`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// An operation with an ERC-6909 token failed.
+    SafeErc6909FailedOperation(SafeErc6909FailedOperation),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+pub use token::*;
+mod token {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    alloy_sol_types::sol! {
+        /// Interface of the ERC-6909 token.
+        interface IErc6909 {
+            function transfer(address receiver, uint256 id, uint256 amount) external returns (bool);
+            function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool);
+        }
+    }
+}
+
+/// State of a [`SafeErc6909`] Contract.
+#[storage]
+pub struct SafeErc6909;
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for SafeErc6909 {}
+
+/// Required interface of a [`SafeErc6909`] utility contract.
+#[interface_id]
+pub trait ISafeErc6909 {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Transfer `amount` of tokens of type `id` of `token` from the calling
+    /// contract to `to`. If `token` returns no value, non-reverting calls are
+    /// assumed to be successful.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-6909 token contract.
+    /// * `to` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SafeErc6909FailedOperation`] - If the `token` address is not
+    ///   a contract, the contract fails to execute the call or the call returns
+    ///   value that is not `true`.
+    fn safe_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+
+    /// Transfer `amount` of tokens of type `id` of `token` from `from` to
+    /// `to`, as an operator of `from` or spending the allowance given by
+    /// `from` to the calling contract. If `token` returns no value,
+    /// non-reverting calls are assumed to be successful.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-6909 token contract.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `id` - Token id as a number.
+    /// * `amount` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SafeErc6909FailedOperation`] - If the `token` address is not
+    ///   a contract, the contract fails to execute the call or the call returns
+    ///   value that is not `true`.
+    fn safe_transfer_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error>;
+}
+
+#[public]
+#[implements(ISafeErc6909<Error = Error>)]
+impl SafeErc6909 {}
+
+#[public]
+impl ISafeErc6909 for SafeErc6909 {
+    type Error = Error;
+
+    fn safe_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        let call = IErc6909::transferCall { receiver: to, id, amount };
+
+        Self::call_optional_return(token, &call)
+    }
+
+    fn safe_transfer_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        let call = IErc6909::transferFromCall {
+            sender: from,
+            receiver: to,
+            id,
+            amount,
+        };
+
+        Self::call_optional_return(token, &call)
+    }
+}
+
+impl SafeErc6909 {
+    /// Imitates a Stylus high-level call, relaxing the requirement on the
+    /// return value: if data is returned, it must not be `false`, otherwise
+    /// calls are assumed to be successful.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Address of the ERC-6909 token contract.
+    /// * `call` - [`IErc6909`] call that implements [`SolCall`] trait.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::SafeErc6909FailedOperation`] - If the `token` address is not
+    ///   a contract, the contract fails to execute the call or the call returns
+    ///   value that is not `true`.
+    fn call_optional_return(
+        token: Address,
+        call: &impl SolCall,
+    ) -> Result<(), Error> {
+        if !Address::has_code(&token) {
+            return Err(SafeErc6909FailedOperation { token }.into());
+        }
+
+        unsafe {
+            match RawCall::new()
+                .limit_return_data(0, BOOL_TYPE_SIZE)
+                .flush_storage_cache()
+                .call(token, &call.abi_encode())
+            {
+                Ok(data) if data.is_empty() || Self::encodes_true(&data) => {
+                    Ok(())
+                }
+                _ => Err(SafeErc6909FailedOperation { token }.into()),
+            }
+        }
+    }
+
+    /// Returns true if a slice of bytes is an ABI encoded `true` value.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Slice of bytes.
+    fn encodes_true(data: &[u8]) -> bool {
+        data.split_last().is_some_and(|(last, rest)| {
+            *last == 1 && rest.iter().all(|&byte| byte == 0)
+        })
+    }
+}
+
+impl IErc165 for SafeErc6909 {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as ISafeErc6909>::interface_id() == interface_id
+            || <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use motsu::prelude::Contract;
+    use stylus_sdk::alloy_primitives::{Address, FixedBytes};
+
+    use super::{ISafeErc6909, SafeErc6909, BOOL_TYPE_SIZE};
+    use crate::utils::introspection::erc165::IErc165;
+
+    #[test]
+    fn encodes_true() {
+        let mut data = [0; BOOL_TYPE_SIZE];
+        assert!(!SafeErc6909::encodes_true(&data));
+        data[BOOL_TYPE_SIZE - 1] = 1;
+        assert!(SafeErc6909::encodes_true(&data));
+
+        assert!(!SafeErc6909::encodes_true(&[]));
+        assert!(SafeErc6909::encodes_true(&[1]));
+        assert!(!SafeErc6909::encodes_true(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <SafeErc6909 as ISafeErc6909>::interface_id();
+        let expected: FixedBytes<4> = 0x7f8fdb7d_u32.into();
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface(contract: Contract<SafeErc6909>, alice: Address) {
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<SafeErc6909 as IErc165>::interface_id()));
+        assert!(contract
+            .sender(alice)
+            .supports_interface(<SafeErc6909 as ISafeErc6909>::interface_id()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!contract
+            .sender(alice)
+            .supports_interface(fake_interface_id.into()));
+    }
+}
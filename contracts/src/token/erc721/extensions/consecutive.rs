@@ -27,6 +27,7 @@ use alloc::{vec, vec::Vec};
 use core::ops::{Deref, DerefMut};
 
 use alloy_primitives::{aliases::U96, uint, Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{abi::Bytes, call::MethodError, evm, msg, prelude::*};
 
 use crate::{
@@ -12,7 +12,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{uint, Address, FixedBytes, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError,
@@ -10,7 +10,7 @@ use alloc::{
 };
 
 use alloy_primitives::{Address, FixedBytes, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 pub use sol::*;
 use stylus_sdk::{
     abi::Bytes,
@@ -409,13 +409,9 @@ mod tests {
     use super::*;
     use crate::{
         token::erc721::{self, IErc721},
-        utils::introspection::erc165::IErc165,
+        utils::{introspection::erc165::IErc165, test_utils::random_token_ids},
     };
 
-    pub(crate) fn random_token_ids(size: usize) -> Vec<U256> {
-        (0..size).map(U256::from).collect()
-    }
-
     #[storage]
     struct Erc721WrapperTestExample {
         wrapper: Erc721Wrapper,
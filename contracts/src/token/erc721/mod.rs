@@ -6,7 +6,7 @@ use alloc::{
 };
 
 use alloy_primitives::{uint, Address, FixedBytes, U128, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, SolidityError};
 use stylus_sdk::{
     abi::Bytes,
     call::{self, Call, MethodError},
@@ -0,0 +1,213 @@
+//! Export of the custom errors and events of a contract along with its abi.
+//!
+//! The Solidity interface generated by `cargo stylus export-abi` only
+//! declares the custom errors returned by the public functions of the
+//! contract's own `impl` block, and none of the events it emits. Contracts
+//! implementing [`ExportAbi`] can be exported with [`print_from_args`]
+//! instead, so that the interface also declares every error and event needed
+//! to decode the contract's reverts and logs.
+//!
+//! ```rust,ignore
+//! #[cfg(feature = "export-abi")]
+//! impl ExportAbi for MyContract {
+//!     fn inner_types() -> Vec<InnerType> {
+//!         let mut types = <erc6909::Error as InnerTypes>::inner_types();
+//!         types.extend(Erc6909::events());
+//!         types
+//!     }
+//! }
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::{any::TypeId, fmt, marker::PhantomData};
+
+use alloy_sol_types::{SolEvent, SolStruct, SolType, TopicList};
+use stylus_sdk::abi::{export::internal::InnerType, GenerateAbi};
+
+/// A contract whose abi is exported along with the errors and events it may
+/// revert with or emit.
+pub trait ExportAbi: GenerateAbi {
+    /// Returns the Solidity declarations of the custom errors and events of
+    /// the contract.
+    fn inner_types() -> Vec<InnerType>;
+}
+
+/// A contract that emits events.
+pub trait Events {
+    /// Returns the Solidity declarations of the events emitted by the
+    /// contract.
+    fn events() -> Vec<InnerType>;
+}
+
+/// Returns the Solidity `declaration` of the event `E`, e.g.
+/// `event OperatorSet(address indexed owner, address indexed spender, bool
+/// approved)`.
+///
+/// # Arguments
+///
+/// * `declaration` - Solidity declaration of the event, without the trailing
+///   semicolon.
+///
+/// # Panics
+///
+/// * If `declaration` doesn't match the signature of `E`, or doesn't index the
+///   same parameters as `E`.
+#[must_use]
+pub fn event<E: SolEvent + 'static>(declaration: &str) -> InnerType {
+    let (signature, indexed, data) = parse_event(declaration);
+    let topics =
+        <E::TopicList as TopicList>::COUNT - usize::from(!E::ANONYMOUS);
+
+    assert_eq!(
+        signature,
+        E::SIGNATURE,
+        "declaration `{declaration}` should match the signature of the event"
+    );
+    assert!(
+        indexed == topics && data == <E::DataTuple<'_> as SolType>::SOL_NAME,
+        "declaration `{declaration}` should index the parameters of the event"
+    );
+
+    InnerType { name: format!("{declaration};"), id: TypeId::of::<E>() }
+}
+
+/// Returns the Solidity declaration of the struct `S`, e.g.
+/// `struct Position { uint256 id; uint256 amount; }`.
+#[must_use]
+pub fn sol_struct<S: SolStruct + 'static>() -> InnerType {
+    let root = S::eip712_root_type();
+    let fields = root
+        .strip_prefix(S::NAME)
+        .unwrap_or_default()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+    let fields: String = fields
+        .split(',')
+        .filter(|field| !field.is_empty())
+        .map(|field| format!(" {field};"))
+        .collect();
+
+    InnerType {
+        name: format!("struct {} {{{fields} }}", S::NAME),
+        id: TypeId::of::<S>(),
+    }
+}
+
+/// Returns the signature, the number of indexed parameters and the tuple of
+/// non-indexed parameter types of the event `declaration`.
+fn parse_event(declaration: &str) -> (String, usize, String) {
+    let declaration = declaration.trim();
+    let declaration =
+        declaration.strip_prefix("event ").unwrap_or(declaration).trim();
+    let (name, params) = declaration
+        .trim_end_matches(')')
+        .split_once('(')
+        .unwrap_or((declaration, ""));
+
+    let mut types = Vec::new();
+    let mut data = Vec::new();
+    let mut indexed = 0;
+    for param in params.split(',').filter(|param| !param.trim().is_empty()) {
+        let mut words = param.split_whitespace();
+        let ty = words.next().unwrap_or_default();
+        if words.next() == Some("indexed") {
+            indexed += 1;
+        } else {
+            data.push(ty);
+        }
+        types.push(ty);
+    }
+
+    (
+        format!("{}({})", name.trim(), types.join(",")),
+        indexed,
+        format!("({})", data.join(",")),
+    )
+}
+
+/// Prints the abi of contract `T` given the CLI options, declaring its
+/// custom errors and events.
+pub fn print_from_args<T: ExportAbi>() {
+    stylus_sdk::abi::export::print_from_args::<Complete<T>>();
+}
+
+/// Abi of contract `T`, completed with its custom errors and events.
+struct Complete<T>(PhantomData<T>);
+
+/// Formats the abi of contract `T` as generated by `stylus_sdk`.
+struct Generated<T>(PhantomData<T>);
+
+impl<T: GenerateAbi> fmt::Display for Generated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_abi(f)
+    }
+}
+
+impl<T: ExportAbi> GenerateAbi for Complete<T> {
+    const NAME: &'static str = T::NAME;
+
+    fn fmt_abi(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abi = format!("{}", Generated::<T>(PhantomData));
+        let (interface, end) = abi.rsplit_once('}').unwrap_or((&abi, ""));
+
+        write!(f, "{interface}")?;
+        let mut seen = Vec::new();
+        for item in T::inner_types() {
+            if !seen.contains(&item.id) && !interface.contains(&item.name) {
+                writeln!(f, "\n    {}", item.name)?;
+            }
+            seen.push(item.id);
+        }
+        write!(f, "}}{end}")
+    }
+
+    fn fmt_constructor_signature(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_constructor_signature(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::erc6909::{extensions::TokenInfo, OperatorSet, Transfer};
+
+    #[test]
+    fn event_declaration() {
+        let declaration = "event Transfer(address caller, address indexed \
+                           sender, address indexed receiver, uint256 indexed \
+                           id, uint256 amount)";
+        let event = event::<Transfer>(declaration);
+
+        assert_eq!(format!("{declaration};"), event.name);
+        assert_eq!(TypeId::of::<Transfer>(), event.id);
+    }
+
+    #[test]
+    #[should_panic = "should match the signature of the event"]
+    fn event_declaration_panics_on_mismatched_signature() {
+        let _ = event::<OperatorSet>(
+            "event OperatorSet(address indexed owner, bool approved)",
+        );
+    }
+
+    #[test]
+    #[should_panic = "should index the parameters of the event"]
+    fn event_declaration_panics_on_mismatched_indexed_parameters() {
+        let _ = event::<OperatorSet>(
+            "event OperatorSet(address indexed owner, address spender, bool \
+             approved)",
+        );
+    }
+
+    #[test]
+    fn struct_declaration() {
+        let declaration = sol_struct::<TokenInfo>();
+
+        assert_eq!(
+            "struct TokenInfo { string name; string symbol; uint8 decimals; \
+             uint256 totalSupply; uint256 cap; bool paused; string uri; }",
+            declaration.name
+        );
+        assert_eq!(TypeId::of::<TokenInfo>(), declaration.id);
+    }
+}
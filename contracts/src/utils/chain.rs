@@ -0,0 +1,20 @@
+//! Helpers to read the state of the Arbitrum chain a contract runs on.
+//!
+//! On Arbitrum, `block.number` returns an approximation of the L1 block
+//! number, which can stay the same across many L2 blocks. The number of the
+//! L2 block is only available through the `ArbSys` precompile, with
+//! [`block_number_l2`].
+
+use crate::utils::precompiles::ArbSys;
+
+/// Returns the number of the current L2 block, as returned by
+/// `ArbSys.arbBlockNumber()`.
+///
+/// # Panics
+///
+/// * If the `ArbSys` precompile fails to execute, e.g. on a chain other than
+///   Arbitrum.
+#[must_use]
+pub fn block_number_l2() -> u64 {
+    ArbSys::block_number()
+}
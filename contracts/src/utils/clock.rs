@@ -0,0 +1,126 @@
+//! Clock of contracts keeping checkpoints, as in [ERC-6372].
+//!
+//! Checkpointed contracts, like the votes extensions, key their history by
+//! the timepoints of a [`Clock`], and expose it with [`IErc6372`] so that
+//! governors and off-chain tooling know how to interpret timepoints. Other
+//! time-dependent contracts, like the transfer cooldown extension, measure
+//! time with a [`Clock`] as well.
+//!
+//! The clock is picked per contract, with a type parameter defaulting to
+//! [`TimestampClock`], the block timestamp. On Arbitrum, `block.number`
+//! returns an approximation of the L1 block number, which can stay the same
+//! across many L2 blocks, so it is an unreliable clock. Contracts can use
+//! [`BlockNumberClock`] instead, keyed by the L2 block number read with
+//! [`chain::block_number_l2`]. Their schedules and durations are then
+//! expressed in L2 blocks rather than in seconds.
+//!
+//! [ERC-6372]: https://eips.ethereum.org/EIPS/eip-6372
+
+use alloc::string::String;
+
+use alloy_primitives::aliases::U48;
+use openzeppelin_stylus_proc::interface_id;
+use stylus_sdk::block;
+
+use crate::utils::chain;
+
+/// Source of the timepoints of a contract.
+pub trait Clock {
+    /// Description of the clock, as returned by [`IErc6372::clock_mode`].
+    const MODE: &'static str;
+
+    /// Returns the current timepoint.
+    fn now() -> U48;
+}
+
+/// [`Clock`] keyed by the block timestamp, in seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimestampClock;
+
+impl Clock for TimestampClock {
+    const MODE: &'static str = "mode=timestamp";
+
+    fn now() -> U48 {
+        U48::from(block::timestamp())
+    }
+}
+
+/// [`Clock`] keyed by the L2 block number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockNumberClock;
+
+impl Clock for BlockNumberClock {
+    const MODE: &'static str = "mode=blocknumber&from=default";
+
+    /// Returns the L2 block number, read with [`chain::block_number_l2`].
+    ///
+    /// # Panics
+    ///
+    /// * If the `ArbSys` precompile fails to execute.
+    fn now() -> U48 {
+        U48::from(chain::block_number_l2())
+    }
+}
+
+/// Interface of contracts exposing their clock, as defined in [ERC-6372].
+///
+/// [ERC-6372]: https://eips.ethereum.org/EIPS/eip-6372
+#[interface_id]
+pub trait IErc6372 {
+    /// Returns the current timepoint of the contract's clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn clock(&self) -> U48;
+
+    /// Returns a machine-readable description of the contract's clock.
+    ///
+    /// NOTE: The implementation should use `#[selector(name =
+    /// "CLOCK_MODE")]` to match Solidity's camelCase naming convention.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "CLOCK_MODE")]
+    fn clock_mode(&self) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{fixed_bytes, FixedBytes};
+
+    use super::*;
+
+    #[motsu::test]
+    fn timestamp_clock_is_block_timestamp() {
+        assert_eq!(TimestampClock::MODE, "mode=timestamp");
+        assert_eq!(U48::from(block::timestamp()), TimestampClock::now());
+    }
+
+    #[motsu::test]
+    fn block_number_clock_is_l2_block_number() {
+        crate::utils::precompiles::mock::set_block_number(1_000);
+        assert_eq!(BlockNumberClock::MODE, "mode=blocknumber&from=default");
+        assert_eq!(U48::from(1_000), BlockNumberClock::now());
+    }
+
+    #[test]
+    fn interface_id() {
+        struct ClockContract;
+
+        impl IErc6372 for ClockContract {
+            fn clock(&self) -> U48 {
+                TimestampClock::now()
+            }
+
+            fn clock_mode(&self) -> String {
+                TimestampClock::MODE.into()
+            }
+        }
+
+        let actual = <ClockContract as IErc6372>::interface_id();
+        let expected: FixedBytes<4> = fixed_bytes!("0xda287a1d");
+        assert_eq!(actual, expected);
+    }
+}
@@ -6,6 +6,7 @@ use alloc::vec::Vec;
 
 use alloy_primitives::{address, uint, Address, B256, U256};
 use alloy_sol_types::SolType;
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{
     call::{self, Call, MethodError},
     prelude::*,
@@ -57,6 +57,23 @@ pub fn to_typed_data_hash(
     keccak256(preimage)
 }
 
+/// Returns the EIP-712 encoding of an array of `T` values, to be used as a
+/// member of a [hashed struct].
+///
+/// Arrays are encoded as the keccak256 hash of the concatenated encodings of
+/// their elements, e.g. for a `uint256[] ids` member, the hash of the
+/// big-endian words of the ids.
+///
+/// [hashed struct]: https://eips.ethereum.org/EIPS/eip-712#definition-of-encodedata
+#[must_use]
+pub fn hash_array<T: SolType>(values: &[T::RustType]) -> B256 {
+    let mut encoded = Vec::with_capacity(values.len() * 32);
+    for value in values {
+        encoded.extend_from_slice(T::eip712_data_word(value).as_slice());
+    }
+    keccak256(encoded)
+}
+
 /// EIP-712 Contract interface.
 pub trait IEip712 {
     /// Immutable name of EIP-712 instance.
@@ -138,8 +155,9 @@ pub trait IEip712 {
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{address, b256, uint, Address, U256};
+    use alloy_sol_types::sol_data;
 
-    use super::{to_typed_data_hash, IEip712, FIELDS, SALT};
+    use super::{hash_array, to_typed_data_hash, IEip712, FIELDS, SALT};
 
     const CHAIN_ID: U256 = uint!(42161_U256);
 
@@ -194,4 +212,27 @@ mod tests {
             to_typed_data_hash(&domain_separator, &struct_hash),
         );
     }
+
+    #[test]
+    fn hash_array_hashes_concatenated_words() {
+        // keccak256(abi.encodePacked(uint256(1), uint256(2)))
+        let expected = b256!(
+            "e90b7bceb6e7df5418fb78d8ee546e97c83a08bbccc01a0644d599ccd2a7c2e0"
+        );
+
+        assert_eq!(
+            expected,
+            hash_array::<sol_data::Uint<256>>(&[uint!(1_U256), uint!(2_U256)])
+        );
+    }
+
+    #[test]
+    fn hash_array_of_empty_array() {
+        // keccak256("")
+        let expected = b256!(
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        assert_eq!(expected, hash_array::<sol_data::Uint<256>>(&[]));
+    }
 }
@@ -1,3 +1,4 @@
 //! Smart Contracts with cryptography.
 pub mod ecdsa;
 pub mod eip712;
+pub mod signature_checker;
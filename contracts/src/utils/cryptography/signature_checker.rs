@@ -0,0 +1,230 @@
+//! Signature verification for smart contract wallets, as defined in
+//! [ERC-1271].
+//!
+//! Smart contract wallets can't sign messages with ECDSA, so they validate
+//! signatures made on their behalf themselves. Contracts checking signatures
+//! should fall back to [`is_valid_erc1271_signature_now`] when ECDSA recovery
+//! fails, so that such wallets are supported too.
+//!
+//! [ERC-1271]: https://eips.ethereum.org/EIPS/eip-1271
+
+use alloy_primitives::{Address, B256};
+pub use interface::IErc1271;
+use stylus_sdk::{abi::Bytes, call::Call, function_selector, prelude::*};
+
+/// The value returned by [`IErc1271::is_valid_signature`] for valid
+/// signatures.
+pub const ERC1271_MAGIC_VALUE: [u8; 4] =
+    function_selector!("isValidSignature", B256, Bytes);
+
+mod interface {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Interface of the smart contract wallets validating signatures.
+        interface IErc1271 {
+            /// Returns [`super::ERC1271_MAGIC_VALUE`] if `signature` is a
+            /// valid signature of `hash` on behalf of the wallet.
+            ///
+            /// # Arguments
+            ///
+            /// * `hash` - Hash of the signed data.
+            /// * `signature` - Signature of `hash`.
+            function isValidSignature(
+                bytes32 hash,
+                bytes calldata signature
+            ) external view returns (bytes4);
+        }
+    }
+}
+
+/// Returns whether `signature` is a valid signature of `hash` on behalf of
+/// `signer`, according to its ERC-1271 [`IErc1271::is_valid_signature`]
+/// function.
+///
+/// Returns false if `signer` is not a contract, or if its function reverts or
+/// doesn't return [`ERC1271_MAGIC_VALUE`].
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `signer` - Smart contract wallet expected to have signed `hash`.
+/// * `hash` - Hash of the signed data.
+/// * `signature` - Signature of `hash`.
+pub fn is_valid_erc1271_signature_now(
+    storage: &mut impl TopLevelStorage,
+    signer: Address,
+    hash: B256,
+    signature: &[u8],
+) -> bool {
+    if !signer.has_code() {
+        return false;
+    }
+
+    IErc1271::new(signer)
+        .is_valid_signature(
+            Call::new_in(storage),
+            hash,
+            signature.to_vec().into(),
+        )
+        .is_ok_and(|magic_value| magic_value == ERC1271_MAGIC_VALUE)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use alloy_primitives::{b256, fixed_bytes, Address, FixedBytes, B256};
+    use motsu::prelude::*;
+    use stylus_sdk::{
+        abi::Bytes,
+        prelude::*,
+        storage::{StorageBool, StorageBytes},
+    };
+
+    use super::*;
+
+    const HASH: B256 = b256!(
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+
+    /// Smart contract wallet accepting a single signature, whatever the
+    /// signed hash.
+    #[storage]
+    struct MockErc1271Wallet {
+        signature: StorageBytes,
+        reverts: StorageBool,
+    }
+
+    #[public]
+    impl MockErc1271Wallet {
+        fn is_valid_signature(
+            &self,
+            _hash: B256,
+            signature: Bytes,
+        ) -> Result<FixedBytes<4>, Vec<u8>> {
+            if self.reverts.get() {
+                return Err("signature check reverted".into());
+            }
+
+            if signature.0 == self.signature.get_bytes() {
+                Ok(ERC1271_MAGIC_VALUE.into())
+            } else {
+                Ok(FixedBytes::ZERO)
+            }
+        }
+    }
+
+    impl MockErc1271Wallet {
+        /// Makes the wallet accept `signature`.
+        fn accept(&mut self, signature: &[u8]) {
+            self.signature.set_bytes(signature);
+        }
+
+        /// Makes the signature checks of the wallet revert.
+        fn revert(&mut self) {
+            self.reverts.set(true);
+        }
+    }
+
+    unsafe impl TopLevelStorage for MockErc1271Wallet {}
+
+    #[storage]
+    struct SignatureCheckerExample;
+
+    #[public]
+    impl SignatureCheckerExample {
+        fn is_valid(
+            &mut self,
+            signer: Address,
+            hash: B256,
+            signature: Bytes,
+        ) -> bool {
+            is_valid_erc1271_signature_now(self, signer, hash, &signature)
+        }
+    }
+
+    unsafe impl TopLevelStorage for SignatureCheckerExample {}
+
+    #[motsu::test]
+    fn magic_value() {
+        let expected: FixedBytes<4> = fixed_bytes!("0x1626ba7e");
+        assert_eq!(expected, ERC1271_MAGIC_VALUE);
+    }
+
+    #[motsu::test]
+    fn accepts_signature_validated_by_wallet(
+        contract: Contract<SignatureCheckerExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+    ) {
+        wallet.sender(alice).accept(b"signature");
+
+        assert!(contract.sender(alice).is_valid(
+            wallet.address(),
+            HASH,
+            b"signature".to_vec().into()
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_signature_not_validated_by_wallet(
+        contract: Contract<SignatureCheckerExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+    ) {
+        wallet.sender(alice).accept(b"signature");
+
+        assert!(!contract.sender(alice).is_valid(
+            wallet.address(),
+            HASH,
+            b"forged".to_vec().into()
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_signature_when_wallet_reverts(
+        contract: Contract<SignatureCheckerExample>,
+        wallet: Contract<MockErc1271Wallet>,
+        alice: Address,
+    ) {
+        wallet.sender(alice).accept(b"signature");
+        wallet.sender(alice).revert();
+
+        assert!(!contract.sender(alice).is_valid(
+            wallet.address(),
+            HASH,
+            b"signature".to_vec().into()
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_signature_of_account_without_code(
+        contract: Contract<SignatureCheckerExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        assert!(!contract.sender(alice).is_valid(
+            bob,
+            HASH,
+            b"signature".to_vec().into()
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_signature_of_contract_without_erc1271(
+        contract: Contract<SignatureCheckerExample>,
+        other: Contract<SignatureCheckerExample>,
+        alice: Address,
+    ) {
+        assert!(!contract.sender(alice).is_valid(
+            other.address(),
+            HASH,
+            b"signature".to_vec().into()
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+//! Emission of raw EVM logs.
+//!
+//! Contracts usually declare their events with `sol!` and emit them with
+//! `evm::log`, which derives their topics and data from the Solidity ABI.
+//! Extensions with a non-standard indexing scheme, e.g. a topic committing to
+//! a whole batch instead of an event signature, can emit their logs with
+//! [`emit_raw`] instead.
+
+use alloy_primitives::B256;
+use stylus_sdk::evm;
+
+/// Maximum number of topics of an EVM log.
+pub const MAX_TOPICS: usize = 4;
+
+/// Emits an EVM log with `topics` and `data`, as is.
+///
+/// # Safety
+///
+/// Nothing is encoded nor validated. Indexers and ABI decoders identify a log
+/// by its first topic, and decode the rest according to the event whose
+/// selector it is:
+///
+/// * A first topic equal to the selector of another event, e.g.
+///   [`crate::token::erc6909::TransferSingle`], makes the log decoded as that
+///   event. Derive topics from inputs that can't be chosen to match one.
+/// * Logs that don't follow the ABI encoding of any event are ignored by
+///   off-chain tooling relying on the contract's abi, so the scheme must be
+///   documented for its consumers.
+///
+/// # Arguments
+///
+/// * `topics` - Topics of the log.
+/// * `data` - Data of the log.
+///
+/// # Panics
+///
+/// * If `topics` contains more than [`MAX_TOPICS`] topics.
+pub fn emit_raw(topics: &[B256], data: &[u8]) {
+    assert!(
+        topics.len() <= MAX_TOPICS,
+        "should not emit more than `MAX_TOPICS` topics"
+    );
+    evm::raw_log(topics, data).expect("should emit log");
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use alloy_primitives::{Address, B256, U256};
+    use alloy_sol_types::SolEvent;
+    use motsu::prelude::*;
+    use stylus_sdk::{abi::Bytes, prelude::*};
+
+    use super::*;
+    use crate::token::erc6909::TransferSingle;
+
+    #[storage]
+    struct RawLogExample;
+
+    #[public]
+    impl RawLogExample {
+        fn emit(&mut self, topics: Vec<B256>, data: Bytes) {
+            emit_raw(&topics, &data);
+        }
+    }
+
+    unsafe impl TopLevelStorage for RawLogExample {}
+
+    #[motsu::test]
+    fn emits_log_with_topics_and_data(
+        contract: Contract<RawLogExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let event = TransferSingle {
+            caller: alice,
+            from: alice,
+            to: bob,
+            id: U256::ONE,
+            amount: U256::from(10),
+        };
+        let topics =
+            event.encode_topics().into_iter().map(|topic| topic.0).collect();
+
+        contract.sender(alice).emit(topics, event.encode_data().into());
+
+        contract.assert_emitted(&event);
+    }
+
+    #[motsu::test]
+    #[should_panic = "should not emit more than `MAX_TOPICS` topics"]
+    fn panics_when_too_many_topics(
+        contract: Contract<RawLogExample>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .emit(vec![B256::ZERO; MAX_TOPICS + 1], Vec::new().into());
+    }
+}
@@ -2,7 +2,7 @@
 use alloy_primitives::{uint, U256, U512};
 
 /// Trait for standard math utilities missing in `alloy_primitives`.
-pub trait Math {
+pub trait Math: Sized {
     /// Returns the square root of a number. If the number is not a perfect
     /// square, the value is rounded towards zero.
     /// This method is based on Newton's method for computing square roots; the
@@ -32,8 +32,34 @@ pub trait Math {
     /// * `y` - second value to compute the result.
     /// * `denominator` - denominator of the division.
     /// * `rounding` - rounding technique to use in calculation.
+    ///
+    /// # Panics
+    ///
+    /// * If `denominator` is zero.
+    /// * If the result doesn't fit into `Self`.
     #[must_use]
     fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Self;
+
+    /// Calculates `self` * `y` / `denominator` with full precision, following
+    /// the selected `rounding` direction, or returns `None` if `denominator`
+    /// is zero or the result doesn't fit into `Self`.
+    ///
+    /// The product `self` * `y` never overflows, so the result is defined as
+    /// long as it fits into `Self`, even when the product itself doesn't.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - first value to compute the result.
+    /// * `y` - second value to compute the result.
+    /// * `denominator` - denominator of the division.
+    /// * `rounding` - rounding technique to use in calculation.
+    #[must_use]
+    fn checked_mul_div(
+        self,
+        y: Self,
+        denominator: Self,
+        rounding: Rounding,
+    ) -> Option<Self>;
 }
 
 /// Enum representing many rounding techniques.
@@ -172,27 +198,33 @@ impl Math for U256 {
             "division by U256::ZERO in `Math::mul_div`"
         );
 
-        let prod = U512::from(self)
-            .checked_mul(U512::from(y))
-            .expect("should not panic with `U256` * `U256`");
+        self.checked_mul_div(y, denominator, rounding)
+            .expect("should fit into `U256` in `Math::mul_div`")
+    }
+
+    fn checked_mul_div(
+        self,
+        y: Self,
+        denominator: Self,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        // The product of two `U256` values always fits into a `U512`.
+        let prod = U512::from(self).checked_mul(U512::from(y))?;
 
         // Adjust for rounding if needed.
         let adjusted = match rounding {
             Rounding::Floor => prod, // No adjustment for Rounding::Floor
-            Rounding::Ceil => prod
-                .checked_add(U512::from(denominator) - U512::from(1))
-                .expect("should not exceed `U512`"),
+            Rounding::Ceil => {
+                prod.checked_add(U512::from(denominator) - U512::from(1))?
+            }
         };
 
-        let result = adjusted
-            .checked_div(U512::from(denominator))
-            .expect("should not panic with `U512` / `U512`");
-
-        if result > U512::from(U256::MAX) {
-            panic!("should fit into `U256` in `Math::mul_div`");
-        } else {
-            U256::from(result)
-        }
+        let result = adjusted.checked_div(U512::from(denominator))?;
+        (result <= U512::from(U256::MAX)).then(|| U256::from(result))
     }
 }
 
@@ -266,4 +298,92 @@ mod tests {
             _ = x.mul_div(y, U256::from(1), Rounding::Floor);
         })
     }
+
+    #[test]
+    fn mul_div_known_vectors() {
+        let half = U256::from(1) << 255;
+        let vectors = [
+            // (x, y, denominator, floor, ceil)
+            (
+                U256::from(3),
+                U256::from(5),
+                U256::from(2),
+                U256::from(7),
+                U256::from(8),
+            ),
+            (U256::ZERO, U256::MAX, U256::from(7), U256::ZERO, U256::ZERO),
+            (U256::MAX, U256::MAX, U256::MAX, U256::MAX, U256::MAX),
+            (
+                U256::MAX - U256::from(1),
+                U256::MAX - U256::from(1),
+                U256::MAX,
+                U256::MAX - U256::from(2),
+                U256::MAX - U256::from(1),
+            ),
+            (half, U256::from(2), half, U256::from(2), U256::from(2)),
+            (
+                U256::MAX,
+                U256::from(2),
+                U256::from(4),
+                half - U256::from(1),
+                half,
+            ),
+            (U256::MAX, half, U256::MAX, half, half),
+        ];
+
+        for (x, y, denominator, floor, ceil) in vectors {
+            assert_eq!(x.mul_div(y, denominator, Rounding::Floor), floor);
+            assert_eq!(x.mul_div(y, denominator, Rounding::Ceil), ceil);
+            assert_eq!(y.mul_div(x, denominator, Rounding::Floor), floor);
+            assert_eq!(y.mul_div(x, denominator, Rounding::Ceil), ceil);
+        }
+    }
+
+    #[test]
+    fn checked_mul_div_returns_none_when_denominator_is_zero() {
+        proptest!(|(x: U256, y: U256)| {
+            assert_eq!(x.checked_mul_div(y, U256::ZERO, Rounding::Floor), None);
+            assert_eq!(x.checked_mul_div(y, U256::ZERO, Rounding::Ceil), None);
+        })
+    }
+
+    #[test]
+    fn checked_mul_div_returns_none_when_result_overflows() {
+        assert_eq!(
+            U256::MAX.checked_mul_div(
+                U256::from(2),
+                U256::from(1),
+                Rounding::Floor
+            ),
+            None
+        );
+
+        // Rounding up alone can overflow.
+        let x = U256::MAX - U256::from(1);
+        let half = U256::from(1) << 255;
+        assert_eq!(
+            x.checked_mul_div(half + U256::from(1), half, Rounding::Floor),
+            Some(U256::MAX)
+        );
+        assert_eq!(
+            x.checked_mul_div(half + U256::from(1), half, Rounding::Ceil),
+            None
+        );
+    }
+
+    #[test]
+    fn check_checked_mul_div_matches_mul_div() {
+        proptest!(|(x: U256, y: U256, denominator: U256)| {
+            prop_assume!(denominator != U256::ZERO, "division by U256::ZERO in `Math::mul_div`.");
+            prop_assume!(denominator > y, "result should fit into `U256` in `Math::mul_div`.");
+            assert_eq!(
+                x.checked_mul_div(y, denominator, Rounding::Floor),
+                Some(x.mul_div(y, denominator, Rounding::Floor))
+            );
+            assert_eq!(
+                x.checked_mul_div(y, denominator, Rounding::Ceil),
+                Some(x.mul_div(y, denominator, Rounding::Ceil))
+            );
+        })
+    }
 }
@@ -0,0 +1,263 @@
+//! Rates expressed in basis points, i.e. hundredths of a percent.
+//!
+//! A [`Bps`] value is always within `0..=10_000`, so functions taking one
+//! don't need to validate it again. Raw values coming from calldata or
+//! storage are validated once, when converted with [`Bps::new`] or
+//! [`Bps::try_from`], while constants are checked at compile time by
+//! [`Bps::from_const`].
+
+use alloy_primitives::U256;
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::call::MethodError;
+
+use crate::utils::math::alloy::{Math, Rounding};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that `value` exceeds 10 000 basis points, i.e. 100%.
+        ///
+        /// * `value` - Invalid value, in basis points.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error BpsOutOfRange(uint256 value);
+    }
+}
+
+/// A [`Bps`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that a value exceeds [`Bps::MAX`].
+    OutOfRange(BpsOutOfRange),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// A rate between 0% and 100%, in basis points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bps(u16);
+
+impl Bps {
+    /// Denominator of rates expressed in basis points.
+    pub const DENOMINATOR: u16 = 10_000;
+    /// A rate of 100%.
+    pub const MAX: Self = Self(Self::DENOMINATOR);
+    /// A rate of 0%.
+    pub const ZERO: Self = Self(0);
+
+    /// Returns a rate of `value` basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Rate, in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfRange`] - If `value` exceeds [`Bps::DENOMINATOR`].
+    pub const fn new(value: u16) -> Result<Self, Error> {
+        if value > Self::DENOMINATOR {
+            return Err(Error::OutOfRange(BpsOutOfRange {
+                value: U256::from_limbs([value as u64, 0, 0, 0]),
+            }));
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns a rate of `VALUE` basis points, checking at compile time that
+    /// it doesn't exceed [`Bps::DENOMINATOR`].
+    ///
+    /// ```rust
+    /// use openzeppelin_stylus::utils::math::bps::Bps;
+    ///
+    /// const FEE: Bps = Bps::from_const::<250>();
+    /// assert_eq!(FEE.get(), 250);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use openzeppelin_stylus::utils::math::bps::Bps;
+    ///
+    /// const FEE: Bps = Bps::from_const::<10_001>();
+    /// ```
+    #[must_use]
+    pub const fn from_const<const VALUE: u16>() -> Self {
+        const {
+            assert!(
+                VALUE <= Bps::DENOMINATOR,
+                "rate should not exceed 10 000 basis points"
+            );
+        }
+        Self(VALUE)
+    }
+
+    /// Returns the rate, in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Rate to read.
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if the rate is 0%.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Rate to check.
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the rate left after applying `self`, i.e. 100% - `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Rate to complement.
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(Self::DENOMINATOR - self.0)
+    }
+
+    /// Returns the sum of `self` and `rhs`, or `None` if it exceeds 100%.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - First rate.
+    /// * `rhs` - Second rate.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let sum = self.0 + rhs.0;
+        if sum > Self::DENOMINATOR {
+            return None;
+        }
+        Some(Self(sum))
+    }
+
+    /// Returns the share of `amount` represented by the rate, rounded
+    /// following the `rounding` direction.
+    ///
+    /// Never overflows, since the share can't exceed `amount`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Rate to apply.
+    /// * `amount` - Amount the rate is applied to.
+    /// * `rounding` - Rounding technique to use in calculation.
+    #[must_use]
+    pub fn apply(self, amount: U256, rounding: Rounding) -> U256 {
+        amount.mul_div(
+            U256::from(self.0),
+            U256::from(Self::DENOMINATOR),
+            rounding,
+        )
+    }
+}
+
+impl From<Bps> for U256 {
+    fn from(value: Bps) -> Self {
+        U256::from(value.0)
+    }
+}
+
+impl TryFrom<u16> for Bps {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<U256> for Bps {
+    type Error = Error;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u16::try_from(value)
+            .ok()
+            .and_then(|value| Self::new(value).ok())
+            .ok_or(Error::OutOfRange(BpsOutOfRange { value }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{private::proptest::proptest, uint, U256};
+
+    use super::{Bps, BpsOutOfRange, Error};
+    use crate::utils::math::alloy::Rounding;
+
+    #[test]
+    fn new_accepts_values_up_to_denominator() {
+        proptest!(|(value in 0..=Bps::DENOMINATOR)| {
+            assert_eq!(Bps::new(value).map(Bps::get).ok(), Some(value));
+        });
+        assert_eq!(Bps::new(Bps::DENOMINATOR).ok(), Some(Bps::MAX));
+    }
+
+    #[test]
+    fn new_reverts_above_denominator() {
+        proptest!(|(value in Bps::DENOMINATOR + 1..)| {
+            let err = Bps::new(value).expect_err("should exceed 100%");
+            assert!(matches!(
+                err,
+                Error::OutOfRange(BpsOutOfRange { value: v })
+                    if v == U256::from(value)
+            ));
+        });
+    }
+
+    #[test]
+    fn try_from_u256_validates_range() {
+        assert_eq!(Bps::try_from(uint!(2500_U256)).ok(), Bps::new(2500).ok());
+
+        for value in [uint!(10_001_U256), uint!(65_536_U256), U256::MAX] {
+            let err = Bps::try_from(value).expect_err("should exceed 100%");
+            assert!(matches!(
+                err,
+                Error::OutOfRange(BpsOutOfRange { value: v }) if v == value
+            ));
+        }
+    }
+
+    #[test]
+    fn from_const() {
+        const FEE: Bps = Bps::from_const::<250>();
+        assert_eq!(FEE.get(), 250);
+        assert_eq!(U256::from(FEE), uint!(250_U256));
+    }
+
+    #[test]
+    fn complement_and_checked_add() {
+        let rate = Bps::from_const::<2500>();
+
+        assert_eq!(rate.complement(), Bps::from_const::<7500>());
+        assert_eq!(Bps::ZERO.complement(), Bps::MAX);
+        assert_eq!(rate.checked_add(rate.complement()), Some(Bps::MAX));
+        assert_eq!(Bps::MAX.checked_add(Bps::from_const::<1>()), None);
+    }
+
+    #[test]
+    fn apply_rounds_following_direction() {
+        let rate = Bps::from_const::<100>();
+
+        assert_eq!(rate.apply(uint!(999_U256), Rounding::Floor), uint!(9_U256));
+        assert_eq!(rate.apply(uint!(999_U256), Rounding::Ceil), uint!(10_U256));
+        assert_eq!(Bps::MAX.apply(U256::MAX, Rounding::Floor), U256::MAX);
+        assert_eq!(Bps::ZERO.apply(U256::MAX, Rounding::Ceil), U256::ZERO);
+    }
+
+    #[test]
+    fn apply_never_exceeds_amount() {
+        proptest!(|(value in 0..=Bps::DENOMINATOR, amount: U256)| {
+            let rate = Bps::new(value).expect("should be a valid rate");
+            assert!(rate.apply(amount, Rounding::Ceil) <= amount);
+        });
+    }
+}
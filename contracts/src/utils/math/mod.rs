@@ -1,3 +1,5 @@
 //! Math helpers for `alloy` and Solidity storage types.
 pub mod alloy;
+pub mod bps;
+pub mod safe_cast;
 pub mod storage;
@@ -0,0 +1,160 @@
+//! Checked downcasts of [`U256`] values into smaller unsigned integers.
+//!
+//! Unlike [`U256::to`], which panics when the value doesn't fit into the
+//! target type, the conversions of [`SafeCast`] return an [`Error`] that can
+//! be reverted with as a Solidity custom error.
+
+use alloy_primitives::U256;
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::call::MethodError;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that `value` doesn't fit in an unsigned integer of
+        /// `bits` bits.
+        ///
+        /// * `bits` - Size of the target type, in bits.
+        /// * `value` - Value that was downcast.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SafeCastOverflowedUintDowncast(uint8 bits, uint256 value);
+    }
+}
+
+/// A [`SafeCast`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that a value doesn't fit in the target type.
+    OverflowedUintDowncast(SafeCastOverflowedUintDowncast),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Trait for checked downcasts of [`U256`] values.
+pub trait SafeCast {
+    /// Returns `self` as a [`u128`].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Value to downcast.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OverflowedUintDowncast`] - If `self` is greater than
+    ///   [`u128::MAX`].
+    fn to_u128(self) -> Result<u128, Error>;
+
+    /// Returns `self` as a [`u64`].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Value to downcast.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OverflowedUintDowncast`] - If `self` is greater than
+    ///   [`u64::MAX`].
+    fn to_u64(self) -> Result<u64, Error>;
+
+    /// Returns `self` as a [`u32`].
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Value to downcast.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OverflowedUintDowncast`] - If `self` is greater than
+    ///   [`u32::MAX`].
+    fn to_u32(self) -> Result<u32, Error>;
+}
+
+impl SafeCast for U256 {
+    fn to_u128(self) -> Result<u128, Error> {
+        downcast(self, 128)
+    }
+
+    fn to_u64(self) -> Result<u64, Error> {
+        downcast(self, 64)
+    }
+
+    fn to_u32(self) -> Result<u32, Error> {
+        downcast(self, 32)
+    }
+}
+
+/// Converts `value` into an unsigned integer of `bits` bits.
+fn downcast<T: TryFrom<U256>>(value: U256, bits: u8) -> Result<T, Error> {
+    T::try_from(value).map_err(|_| {
+        Error::OverflowedUintDowncast(SafeCastOverflowedUintDowncast {
+            bits,
+            value,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{private::proptest::proptest, U256};
+
+    use super::{Error, SafeCast, SafeCastOverflowedUintDowncast};
+
+    #[test]
+    fn downcasts_values_that_fit() {
+        proptest!(|(value: u32)| {
+            assert_eq!(U256::from(value).to_u128().ok(), Some(u128::from(value)));
+            assert_eq!(U256::from(value).to_u64().ok(), Some(u64::from(value)));
+            assert_eq!(U256::from(value).to_u32().ok(), Some(value));
+        });
+    }
+
+    #[test]
+    fn downcasts_max_values() {
+        assert_eq!(U256::from(u128::MAX).to_u128().ok(), Some(u128::MAX));
+        assert_eq!(U256::from(u64::MAX).to_u64().ok(), Some(u64::MAX));
+        assert_eq!(U256::from(u32::MAX).to_u32().ok(), Some(u32::MAX));
+    }
+
+    #[test]
+    fn reverts_when_value_overflows() {
+        let value = U256::from(u64::MAX) + U256::from(1);
+
+        let err = value.to_u64().expect_err("should not fit in a `u64`");
+        assert!(matches!(
+            err,
+            Error::OverflowedUintDowncast(SafeCastOverflowedUintDowncast {
+                bits: 64,
+                value: v,
+            }) if v == value
+        ));
+        assert_eq!(value.to_u128().ok(), Some(u128::from(u64::MAX) + 1));
+
+        let err = U256::MAX.to_u128().expect_err("should not fit in a `u128`");
+        assert!(matches!(
+            err,
+            Error::OverflowedUintDowncast(SafeCastOverflowedUintDowncast {
+                bits: 128,
+                value,
+            }) if value == U256::MAX
+        ));
+
+        let err = U256::from(u64::from(u32::MAX) + 1)
+            .to_u32()
+            .expect_err("should not fit in a `u32`");
+        assert!(matches!(
+            err,
+            Error::OverflowedUintDowncast(SafeCastOverflowedUintDowncast {
+                bits: 32,
+                ..
+            })
+        ));
+    }
+}
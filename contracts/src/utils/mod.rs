@@ -1,11 +1,28 @@
 //! Common Smart Contracts utilities.
+#[cfg(feature = "export-abi")]
+pub mod abi;
+pub mod chain;
+pub mod clock;
 pub mod cryptography;
 pub mod introspection;
+pub mod log;
 pub mod math;
 pub mod metadata;
 pub mod nonces;
+pub mod pagination;
 pub mod pausable;
+pub mod precompiles;
+pub mod revert;
+pub mod sol_struct;
+pub mod storage_layout;
 pub mod structs;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod timelock;
 
+pub use clock::IErc6372;
 pub use metadata::Metadata;
+pub use pagination::Pagination;
 pub use pausable::{IPausable, Pausable};
+pub use storage_layout::{IStorageLayout, StorageLayout};
+pub use timelock::{ITimelock, Timelock};
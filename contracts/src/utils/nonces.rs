@@ -5,6 +5,7 @@
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{uint, Address, U256};
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{
     call::MethodError,
     prelude::*,
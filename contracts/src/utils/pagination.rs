@@ -0,0 +1,96 @@
+//! Helper for paginating views that enumerate storage collections.
+//!
+//! Enumerable sets can grow without bound, so views returning their elements
+//! must not iterate over the whole collection in a single call. [`Pagination`]
+//! caps the size of each page to [`MAX_PAGE_SIZE`] and returns a cursor to
+//! resume from, so that callers can walk any collection without running out
+//! of gas.
+//!
+//! A cursor is the position of the first element of a page. Enumeration
+//! starts with a cursor of zero, and a returned cursor of zero means there
+//! are no more elements to read.
+use core::ops::Range;
+
+use alloy_primitives::U256;
+
+/// Maximum number of elements returned by a single page.
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Bounds of a page within a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    start: usize,
+    end: usize,
+    len: usize,
+}
+
+impl Pagination {
+    /// Computes the page of at most `limit` elements starting at `cursor`,
+    /// within a collection of `len` elements.
+    ///
+    /// `limit` is capped to [`MAX_PAGE_SIZE`], and `cursor` is capped to
+    /// `len`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Position of the first element of the page.
+    /// * `limit` - Maximum number of elements of the page.
+    /// * `len` - Number of elements of the collection.
+    #[must_use]
+    pub fn new(cursor: U256, limit: U256, len: usize) -> Self {
+        let limit =
+            usize::try_from(limit).unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+        let start = usize::try_from(cursor).unwrap_or(len).min(len);
+        let end = start.saturating_add(limit).min(len);
+        Self { start, end, len }
+    }
+
+    /// Returns the positions of the elements of the page.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the cursor of the next page, or zero if this page reaches the
+    /// end of the collection.
+    #[must_use]
+    pub fn next_cursor(&self) -> U256 {
+        if self.end < self.len {
+            U256::from(self.end)
+        } else {
+            U256::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, U256};
+
+    use super::*;
+
+    #[test]
+    fn returns_cursor_of_next_page() {
+        let page = Pagination::new(U256::ZERO, uint!(2_U256), 5);
+        assert_eq!(page.range(), 0..2);
+        assert_eq!(page.next_cursor(), uint!(2_U256));
+
+        let page = Pagination::new(uint!(4_U256), uint!(2_U256), 5);
+        assert_eq!(page.range(), 4..5);
+        assert_eq!(page.next_cursor(), U256::ZERO);
+    }
+
+    #[test]
+    fn caps_limit_to_max_page_size() {
+        let page = Pagination::new(U256::ZERO, U256::MAX, 250);
+        assert_eq!(page.range(), 0..MAX_PAGE_SIZE);
+        assert_eq!(page.next_cursor(), U256::from(MAX_PAGE_SIZE));
+    }
+
+    #[test]
+    fn returns_empty_page_past_the_end() {
+        let page = Pagination::new(U256::MAX, uint!(2_U256), 5);
+        assert!(page.range().is_empty());
+        assert_eq!(page.next_cursor(), U256::ZERO);
+    }
+}
@@ -16,6 +16,7 @@
 
 use alloc::{vec, vec::Vec};
 
+use openzeppelin_stylus_proc::SolidityError;
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError, evm, msg, prelude::*, storage::StorageBool,
@@ -0,0 +1,301 @@
+//! Typed wrappers of the Arbitrum precompiles used by this crate's contracts.
+//!
+//! [`ArbSys`] exposes the state of the chain, like its id and the number of
+//! the current L2 block, and [`ArbGasInfo`] the current gas prices, e.g. to
+//! estimate fees in view functions.
+//!
+//! Precompiles are not available in unit tests. Their results can be set
+//! with the `mock` module instead, available in tests, and to other crates
+//! through the `test-utils` feature.
+
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::SolCall;
+use stylus_sdk::call::RawCall;
+
+/// Address of the `ArbSys` precompile.
+pub const ARB_SYS_ADDR: Address =
+    address!("0000000000000000000000000000000000000064");
+
+/// Address of the `ArbGasInfo` precompile.
+pub const ARB_GAS_INFO_ADDR: Address =
+    address!("000000000000000000000000000000000000006c");
+
+pub use interfaces::*;
+mod interfaces {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+    alloy_sol_types::sol! {
+        /// Interface of the `ArbSys` precompile.
+        interface IArbSys {
+            function arbBlockNumber() external view returns (uint256);
+            function arbChainID() external view returns (uint256);
+        }
+
+        /// Interface of the `ArbGasInfo` precompile.
+        interface IArbGasInfo {
+            function getPricesInWei() external view returns (uint256, uint256, uint256, uint256, uint256, uint256);
+            function getL1BaseFeeEstimate() external view returns (uint256);
+        }
+    }
+}
+
+/// Gas prices in wei, as returned by [`ArbGasInfo::prices_in_wei`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PricesInWei {
+    /// Price of the L2 transaction itself.
+    pub per_l2_tx: U256,
+    /// Price of each byte of calldata posted to L1.
+    pub per_l1_calldata_byte: U256,
+    /// Price of each storage slot allocated.
+    pub per_storage_allocation: U256,
+    /// Base price of each unit of `ArbGas`.
+    pub per_arb_gas_base: U256,
+    /// Congestion price of each unit of `ArbGas`.
+    pub per_arb_gas_congestion: U256,
+    /// Total price of each unit of `ArbGas`.
+    pub per_arb_gas_total: U256,
+}
+
+/// Wrapper of the `ArbSys` precompile.
+pub struct ArbSys;
+
+impl ArbSys {
+    /// Returns the id of the chain.
+    ///
+    /// # Panics
+    ///
+    /// * If the `ArbSys` precompile fails to execute, e.g. on a chain other
+    ///   than Arbitrum.
+    #[must_use]
+    pub fn chain_id() -> u64 {
+        static_call(ARB_SYS_ADDR, &IArbSys::arbChainIDCall {})._0.to::<u64>()
+    }
+
+    /// Returns the number of the current L2 block.
+    ///
+    /// # Panics
+    ///
+    /// * If the `ArbSys` precompile fails to execute, e.g. on a chain other
+    ///   than Arbitrum.
+    #[must_use]
+    pub fn block_number() -> u64 {
+        static_call(ARB_SYS_ADDR, &IArbSys::arbBlockNumberCall {})
+            ._0
+            .to::<u64>()
+    }
+}
+
+/// Wrapper of the `ArbGasInfo` precompile.
+pub struct ArbGasInfo;
+
+impl ArbGasInfo {
+    /// Returns the current gas prices in wei.
+    ///
+    /// # Panics
+    ///
+    /// * If the `ArbGasInfo` precompile fails to execute, e.g. on a chain other
+    ///   than Arbitrum.
+    #[must_use]
+    pub fn prices_in_wei() -> PricesInWei {
+        let prices =
+            static_call(ARB_GAS_INFO_ADDR, &IArbGasInfo::getPricesInWeiCall {});
+        PricesInWei {
+            per_l2_tx: prices._0,
+            per_l1_calldata_byte: prices._1,
+            per_storage_allocation: prices._2,
+            per_arb_gas_base: prices._3,
+            per_arb_gas_congestion: prices._4,
+            per_arb_gas_total: prices._5,
+        }
+    }
+
+    /// Returns the current estimate of the L1 base fee, in wei.
+    ///
+    /// # Panics
+    ///
+    /// * If the `ArbGasInfo` precompile fails to execute, e.g. on a chain other
+    ///   than Arbitrum.
+    #[must_use]
+    pub fn l1_base_fee_estimate() -> U256 {
+        static_call(
+            ARB_GAS_INFO_ADDR,
+            &IArbGasInfo::getL1BaseFeeEstimateCall {},
+        )
+        ._0
+    }
+}
+
+/// Calls `precompile` with `call`, and decodes its result.
+///
+/// In tests, the result set with `mock` is returned if there is one.
+///
+/// # Panics
+///
+/// * If `precompile` fails to execute, or returns data that can't be decoded.
+fn static_call<C: SolCall>(precompile: Address, call: &C) -> C::Return {
+    let calldata = call.abi_encode();
+
+    #[cfg(any(test, feature = "test-utils"))]
+    let mocked = mock::result(precompile, &calldata);
+    #[cfg(not(any(test, feature = "test-utils")))]
+    let mocked: Option<alloc::vec::Vec<u8>> = None;
+
+    let result = match mocked {
+        Some(result) => result,
+        None => unsafe { RawCall::new_static().call(precompile, &calldata) }
+            .expect("should call precompile"),
+    };
+
+    C::abi_decode_returns(&result, true)
+        .expect("should decode precompile result")
+}
+
+/// Mocks of the precompile wrappers, for unit tests.
+///
+/// Mocks are kept per thread, so every test starts without any when run on
+/// its own thread, as by default. Wrappers that are not mocked call the
+/// actual precompiles, which panics in unit tests.
+///
+/// Only available in tests, and to other crates through the `test-utils`
+/// feature.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock {
+    extern crate std;
+
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use core::cell::RefCell;
+
+    use alloy_primitives::{Address, U256};
+    use alloy_sol_types::SolCall;
+
+    use super::{
+        IArbGasInfo, IArbSys, PricesInWei, ARB_GAS_INFO_ADDR, ARB_SYS_ADDR,
+    };
+
+    /// Mocked results, by precompile address and call data.
+    type MockResults = BTreeMap<(Address, Vec<u8>), Vec<u8>>;
+
+    std::thread_local! {
+        static RESULTS: RefCell<MockResults> =
+            const { RefCell::new(BTreeMap::new()) };
+    }
+
+    /// Makes [`super::ArbSys::chain_id`] return `chain_id`.
+    pub fn set_chain_id(chain_id: u64) {
+        set(
+            ARB_SYS_ADDR,
+            &IArbSys::arbChainIDCall {},
+            IArbSys::arbChainIDCall::abi_encode_returns(&(U256::from(
+                chain_id,
+            ),)),
+        );
+    }
+
+    /// Makes [`super::ArbSys::block_number`] return `block_number`.
+    pub fn set_block_number(block_number: u64) {
+        set(
+            ARB_SYS_ADDR,
+            &IArbSys::arbBlockNumberCall {},
+            IArbSys::arbBlockNumberCall::abi_encode_returns(&(U256::from(
+                block_number,
+            ),)),
+        );
+    }
+
+    /// Makes [`super::ArbGasInfo::prices_in_wei`] return `prices`.
+    pub fn set_prices_in_wei(prices: PricesInWei) {
+        set(
+            ARB_GAS_INFO_ADDR,
+            &IArbGasInfo::getPricesInWeiCall {},
+            IArbGasInfo::getPricesInWeiCall::abi_encode_returns(&(
+                prices.per_l2_tx,
+                prices.per_l1_calldata_byte,
+                prices.per_storage_allocation,
+                prices.per_arb_gas_base,
+                prices.per_arb_gas_congestion,
+                prices.per_arb_gas_total,
+            )),
+        );
+    }
+
+    /// Makes [`super::ArbGasInfo::l1_base_fee_estimate`] return `fee`.
+    pub fn set_l1_base_fee_estimate(fee: U256) {
+        set(
+            ARB_GAS_INFO_ADDR,
+            &IArbGasInfo::getL1BaseFeeEstimateCall {},
+            IArbGasInfo::getL1BaseFeeEstimateCall::abi_encode_returns(&(fee,)),
+        );
+    }
+
+    /// Removes every mock of the current thread.
+    pub fn clear() {
+        RESULTS.with(|results| results.borrow_mut().clear());
+    }
+
+    /// Returns the mocked result of calling `precompile` with `calldata`, if
+    /// any.
+    pub(super) fn result(
+        precompile: Address,
+        calldata: &[u8],
+    ) -> Option<Vec<u8>> {
+        RESULTS.with(|results| {
+            results.borrow().get(&(precompile, calldata.to_vec())).cloned()
+        })
+    }
+
+    fn set(precompile: Address, call: &impl SolCall, result: Vec<u8>) {
+        RESULTS.with(|results| {
+            results
+                .borrow_mut()
+                .insert((precompile, call.abi_encode()), result);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::uint;
+
+    use super::*;
+
+    #[test]
+    fn arb_sys_returns_mocked_results() {
+        mock::set_chain_id(42_161);
+        mock::set_block_number(1_000);
+
+        assert_eq!(42_161, ArbSys::chain_id());
+        assert_eq!(1_000, ArbSys::block_number());
+
+        mock::set_block_number(1_001);
+        assert_eq!(1_001, ArbSys::block_number());
+    }
+
+    #[test]
+    fn arb_gas_info_returns_mocked_results() {
+        let prices = PricesInWei {
+            per_l2_tx: uint!(1_U256),
+            per_l1_calldata_byte: uint!(2_U256),
+            per_storage_allocation: uint!(3_U256),
+            per_arb_gas_base: uint!(4_U256),
+            per_arb_gas_congestion: uint!(5_U256),
+            per_arb_gas_total: uint!(9_U256),
+        };
+        mock::set_prices_in_wei(prices);
+        mock::set_l1_base_fee_estimate(uint!(7_U256));
+
+        assert_eq!(prices, ArbGasInfo::prices_in_wei());
+        assert_eq!(uint!(7_U256), ArbGasInfo::l1_base_fee_estimate());
+    }
+
+    #[test]
+    fn clear_removes_mocks() {
+        mock::set_block_number(1_000);
+        mock::clear();
+
+        assert!(mock::result(
+            ARB_SYS_ADDR,
+            &IArbSys::arbBlockNumberCall {}.abi_encode()
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,63 @@
+//! Encoding of the errors returned by this crate's contracts into revert
+//! data.
+//!
+//! By default, errors are encoded as Solidity custom errors. Older tooling
+//! that can't decode custom errors can enable the `legacy-revert-strings`
+//! feature instead, which makes every error revert with an ABI-encoded
+//! `Error(string)` message describing it.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use stylus_sdk::call::MethodError;
+
+/// Encodes `error` into revert data.
+///
+/// With the `legacy-revert-strings` feature enabled, the revert data is the
+/// ABI encoding of `Error(string)`, with the debug representation of `error`
+/// as message, e.g. `EnforcedPause`. Otherwise, `error` is encoded as is.
+///
+/// # Arguments
+///
+/// * `error` - The error to encode.
+// `error` is only formatted with `legacy-revert-strings`, but is consumed
+// otherwise, so it is taken by value in both cases.
+#[cfg_attr(
+    feature = "legacy-revert-strings",
+    allow(clippy::needless_pass_by_value)
+)]
+pub fn encode<E: MethodError + Debug>(error: E) -> Vec<u8> {
+    #[cfg(feature = "legacy-revert-strings")]
+    {
+        use alloc::format;
+
+        use alloy_sol_types::{Revert, SolError};
+
+        Revert::from(format!("{error:?}")).abi_encode()
+    }
+    #[cfg(not(feature = "legacy-revert-strings"))]
+    {
+        error.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::{Revert, SolError};
+
+    use super::encode;
+    use crate::utils::pausable::EnforcedPause;
+
+    #[test]
+    fn encodes_errors() {
+        let encoded = encode(EnforcedPause {});
+
+        if cfg!(feature = "legacy-revert-strings") {
+            let revert = Revert::abi_decode(&encoded, true)
+                .expect("should decode `Error(string)`");
+            assert_eq!(revert.reason, "EnforcedPause");
+        } else {
+            assert_eq!(encoded, EnforcedPause {}.abi_encode());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Solidity structs in the public abi of contracts.
+//!
+//! Structs declared with `sol!` can't be returned from `#[public]` functions
+//! as is, because `stylus_sdk` only knows how to encode primitive types,
+//! arrays and tuples. [`impl_sol_struct!`] makes such a struct usable as an
+//! argument or a return value, encoded as the tuple of its fields, and
+//! declares it in the exported abi:
+//!
+//! ```rust,ignore
+//! sol! {
+//!     struct Position { uint256 id; uint256 amount; }
+//! }
+//!
+//! impl_sol_struct!(Position, (U256, U256));
+//! ```
+//!
+//! The tuple passed along with the struct lists the Rust types of its fields,
+//! in declaration order, and determines the function selectors taking the
+//! struct as an argument.
+//!
+//! Returning a struct rather than a tuple matters as soon as one of its
+//! fields is dynamic, e.g. a `string` or an array: `stylus_sdk` encodes a
+//! returned tuple as a single value, which Solidity callers only decode as a
+//! struct, not as several return values.
+//!
+//! The macro can be used by contracts outside of this crate as well, which
+//! must declare `extern crate alloc`. The struct is declared in the exported
+//! abi whenever the `export-abi` feature of this crate is enabled.
+//!
+//! [`impl_sol_struct!`]: crate::impl_sol_struct
+
+/// Implements [`stylus_sdk::abi::AbiType`] for the `sol!` struct `$name`,
+/// whose fields have the Rust types of the tuple `$fields`, and declares it
+/// in the exported abi.
+#[macro_export]
+macro_rules! impl_sol_struct {
+    ($name:ident, $fields:ty) => {
+        impl stylus_sdk::abi::AbiType for $name {
+            type SolType = Self;
+
+            const ABI: stylus_sdk::abi::ConstString =
+                <$fields as stylus_sdk::abi::AbiType>::ABI;
+            const CAN_BE_CALLDATA: bool = false;
+            const EXPORT_ABI_ARG: stylus_sdk::abi::ConstString =
+                Self::EXPORT_ABI_RET;
+            const EXPORT_ABI_RET: stylus_sdk::abi::ConstString =
+                stylus_sdk::abi::ConstString::new(concat!(
+                    stringify!($name),
+                    " memory"
+                ));
+        }
+
+        $crate::__impl_sol_struct_inner_types!($name);
+    };
+}
+
+/// Declares the `sol!` struct `$name` in the exported abi.
+///
+/// Defined according to the `export-abi` feature of this crate rather than
+/// the one of the crate calling [`impl_sol_struct!`].
+///
+/// [`impl_sol_struct!`]: crate::impl_sol_struct
+#[cfg(feature = "export-abi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_sol_struct_inner_types {
+    ($name:ident) => {
+        impl stylus_sdk::abi::export::internal::InnerTypes for $name {
+            fn inner_types(
+            ) -> alloc::vec::Vec<stylus_sdk::abi::export::internal::InnerType>
+            {
+                alloc::vec![$crate::utils::abi::sol_struct::<$name>()]
+            }
+        }
+    };
+}
+
+/// Declares the `sol!` struct `$name` in the exported abi.
+///
+/// Defined according to the `export-abi` feature of this crate rather than
+/// the one of the crate calling [`impl_sol_struct!`].
+///
+/// [`impl_sol_struct!`]: crate::impl_sol_struct
+#[cfg(not(feature = "export-abi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_sol_struct_inner_types {
+    ($name:ident) => {};
+}
@@ -0,0 +1,456 @@
+//! Versioning of the storage layout of upgradeable contracts.
+//!
+//! The fields of a `#[storage]` struct are assigned storage slots in
+//! declaration order, recursively through the structs it embeds. Renaming a
+//! field keeps its slot, but changing its type, removing it or inserting a
+//! field before it moves every following field, so that an upgraded
+//! implementation reads the state of the previous one at the wrong slots.
+//!
+//! Upgrading the implementation of a live contract whose layout changed
+//! therefore goes as follows:
+//!
+//! 1. Keep the fields of the previous layout in place, including those that are
+//!    no longer used, e.g. by embedding the previous layout as the first field
+//!    of the new one.
+//! 2. Append a [`StorageLayout`] and the fields of the new layout after them.
+//! 3. Move the state from the previous fields to the new ones in a migration
+//!    function, guarded by [`StorageLayout::_migrate`] so that it runs once,
+//!    and by proper access control. State stored in mappings can't be
+//!    enumerated on-chain: it is moved for a list of keys provided by the
+//!    caller, or lazily when each key is first accessed.
+//!
+//! ```rust,ignore
+//! #[storage]
+//! struct TokenV2 {
+//!     v1: TokenV1,
+//!     layout: StorageLayout,
+//!     balances: StorageMap<Address, StorageMap<U256, StorageU256>>,
+//! }
+//!
+//! pub fn migrate(&mut self, holders: Vec<Address>) -> Result<(), Error> {
+//!     self.ownable.only_owner()?;
+//!     self.layout._migrate(U64::ZERO, uint!(2_U64))?;
+//!     for holder in holders {
+//!         let mut balances = self.balances.setter(holder);
+//!         self.v1.balances.setter(holder).move_to(&mut balances.setter(ID));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Note that the contracts of this crate keep the layout of their fields
+//! within a major version. Layout changes between major versions are listed
+//! in the changelog.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::U64;
+use alloy_sol_types::sol_data::{
+    ByteCount, IntBitCount, SupportedFixedBytes, SupportedInt,
+};
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{
+        Erase, StorageAddress, StorageBool, StorageBytes, StorageFixedBytes,
+        StorageSigned, StorageString, StorageU64, StorageUint,
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the storage layout is migrated from `from_version`
+        /// to `to_version`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event StorageLayoutMigrated(uint64 from_version, uint64 to_version);
+    }
+
+    sol! {
+        /// Indicates that the storage layout is at version `current`, while
+        /// version `expected` is required.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error StorageLayoutUnexpectedVersion(uint64 current, uint64 expected);
+
+        /// Indicates an attempt to migrate the storage layout from version
+        /// `current` to a version `target` that isn't greater.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error StorageLayoutInvalidVersion(uint64 current, uint64 target);
+    }
+}
+
+/// A [`StorageLayout`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that the storage layout is not at the expected version.
+    UnexpectedVersion(StorageLayoutUnexpectedVersion),
+    /// Indicates that the target version of a migration is not greater than
+    /// the current one.
+    InvalidVersion(StorageLayoutInvalidVersion),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of a [`StorageLayout`] contract.
+#[storage]
+pub struct StorageLayout {
+    /// Version of the storage layout. Zero until the first migration.
+    pub(crate) version: StorageU64,
+}
+
+/// Interface for [`StorageLayout`].
+pub trait IStorageLayout {
+    /// Returns the version of the storage layout, zero until the first
+    /// migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn storage_layout_version(&self) -> U64;
+}
+
+#[public]
+#[implements(IStorageLayout)]
+impl StorageLayout {}
+
+#[public]
+impl IStorageLayout for StorageLayout {
+    fn storage_layout_version(&self) -> U64 {
+        self.version.get()
+    }
+}
+
+impl StorageLayout {
+    /// Checks that the storage layout is at `version`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `version` - Required version of the storage layout.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnexpectedVersion`] - If the storage layout is not at
+    ///   `version`.
+    pub fn _require_version(&self, version: U64) -> Result<(), Error> {
+        let current = self.version.get();
+        if current == version {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedVersion(StorageLayoutUnexpectedVersion {
+                current: current.to(),
+                expected: version.to(),
+            }))
+        }
+    }
+
+    /// Marks the storage layout as migrated from version `from` to version
+    /// `to`. Should be called right before moving the state of the previous
+    /// layout, so that the migration runs once.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Version the storage layout is migrated from.
+    /// * `to` - Version the storage layout is migrated to.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnexpectedVersion`] - If the storage layout is not at `from`.
+    /// * [`Error::InvalidVersion`] - If `to` is not greater than `from`.
+    ///
+    /// # Events
+    ///
+    /// * [`StorageLayoutMigrated`].
+    pub fn _migrate(&mut self, from: U64, to: U64) -> Result<(), Error> {
+        self._require_version(from)?;
+        if to <= from {
+            return Err(Error::InvalidVersion(StorageLayoutInvalidVersion {
+                current: from.to(),
+                target: to.to(),
+            }));
+        }
+
+        self.version.set(to);
+        evm::log(StorageLayoutMigrated {
+            from_version: from.to(),
+            to_version: to.to(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Storage value that can be moved to another field of the same type.
+pub trait MoveValue {
+    /// Copies this value to `to`, and erases it, so that the slots of the
+    /// previous layout are cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the value being moved.
+    /// * `to` - Write access to its new location.
+    fn move_to(&mut self, to: &mut Self);
+}
+
+impl<const B: usize, const L: usize> MoveValue for StorageUint<B, L>
+where
+    IntBitCount<B>: SupportedInt,
+{
+    fn move_to(&mut self, to: &mut Self) {
+        to.set(self.get());
+        self.erase();
+    }
+}
+
+impl<const B: usize, const L: usize> MoveValue for StorageSigned<B, L>
+where
+    IntBitCount<B>: SupportedInt,
+{
+    fn move_to(&mut self, to: &mut Self) {
+        to.set(self.get());
+        self.erase();
+    }
+}
+
+impl<const N: usize> MoveValue for StorageFixedBytes<N>
+where
+    ByteCount<N>: SupportedFixedBytes,
+{
+    fn move_to(&mut self, to: &mut Self) {
+        to.set(self.get());
+        self.erase();
+    }
+}
+
+impl MoveValue for StorageBool {
+    fn move_to(&mut self, to: &mut Self) {
+        to.set(self.get());
+        self.erase();
+    }
+}
+
+impl MoveValue for StorageAddress {
+    fn move_to(&mut self, to: &mut Self) {
+        to.set(self.get());
+        self.erase();
+    }
+}
+
+impl MoveValue for StorageString {
+    fn move_to(&mut self, to: &mut Self) {
+        to.set_str(self.get_string());
+        self.erase();
+    }
+}
+
+impl MoveValue for StorageBytes {
+    fn move_to(&mut self, to: &mut Self) {
+        to.set_bytes(self.get_bytes());
+        self.erase();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, Address, U256, U64};
+    use motsu::prelude::*;
+    use stylus_sdk::{
+        prelude::*,
+        storage::{StorageMap, StorageString, StorageU256},
+    };
+
+    use super::*;
+
+    const V1: U64 = uint!(1_U64);
+    const V2: U64 = uint!(2_U64);
+    const ID: U256 = uint!(1_U256);
+    const AMOUNT: U256 = uint!(10_U256);
+
+    /// Layout of a single token deployed before layouts were versioned.
+    #[storage]
+    struct TokenV0 {
+        symbol: StorageString,
+        balances: StorageMap<Address, StorageU256>,
+    }
+
+    /// Layout of the upgraded token, tracking balances per token id.
+    #[storage]
+    struct TokenV1 {
+        v0: TokenV0,
+        layout: StorageLayout,
+        symbols: StorageMap<U256, StorageString>,
+        balances: StorageMap<Address, StorageMap<U256, StorageU256>>,
+    }
+
+    unsafe impl TopLevelStorage for StorageLayout {}
+    unsafe impl TopLevelStorage for TokenV1 {}
+
+    impl TokenV1 {
+        fn migrate(&mut self, holders: &[Address]) -> Result<(), Error> {
+            self.layout._migrate(U64::ZERO, V1)?;
+            self.v0.symbol.move_to(&mut self.symbols.setter(ID));
+            for &holder in holders {
+                let mut balances = self.balances.setter(holder);
+                self.v0
+                    .balances
+                    .setter(holder)
+                    .move_to(&mut balances.setter(ID));
+            }
+            Ok(())
+        }
+    }
+
+    #[motsu::test]
+    fn migrate_works(contract: Contract<StorageLayout>, alice: Address) {
+        contract
+            .sender(alice)
+            ._migrate(U64::ZERO, V1)
+            .motsu_expect("should migrate layout");
+
+        assert_eq!(V1, contract.sender(alice).storage_layout_version());
+        contract.assert_emitted(&StorageLayoutMigrated {
+            from_version: 0,
+            to_version: 1,
+        });
+
+        contract
+            .sender(alice)
+            ._migrate(V1, V2)
+            .motsu_expect("should migrate layout again");
+        assert_eq!(V2, contract.sender(alice).storage_layout_version());
+    }
+
+    #[motsu::test]
+    fn migrate_errors_when_run_twice(
+        contract: Contract<StorageLayout>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._migrate(U64::ZERO, V1)
+            .motsu_expect("should migrate layout");
+
+        let err = contract
+            .sender(alice)
+            ._migrate(U64::ZERO, V1)
+            .motsu_expect_err("should not migrate layout twice");
+
+        assert!(matches!(
+            err,
+            Error::UnexpectedVersion(StorageLayoutUnexpectedVersion {
+                current: 1,
+                expected: 0,
+            })
+        ));
+    }
+
+    #[motsu::test]
+    fn migrate_errors_when_version_not_increasing(
+        contract: Contract<StorageLayout>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._migrate(U64::ZERO, U64::ZERO)
+            .motsu_expect_err("should not migrate to the same version");
+
+        assert!(matches!(
+            err,
+            Error::InvalidVersion(StorageLayoutInvalidVersion {
+                current: 0,
+                target: 0,
+            })
+        ));
+        assert!(contract.sender(alice).storage_layout_version().is_zero());
+    }
+
+    #[motsu::test]
+    fn require_version_checks_current_version(
+        contract: Contract<StorageLayout>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._require_version(U64::ZERO)
+            .motsu_expect("should be at version zero");
+
+        let err = contract
+            .sender(alice)
+            ._require_version(V1)
+            .motsu_expect_err("should not be at version one");
+
+        assert!(matches!(
+            err,
+            Error::UnexpectedVersion(StorageLayoutUnexpectedVersion {
+                current: 0,
+                expected: 1,
+            })
+        ));
+    }
+
+    #[motsu::test]
+    fn migration_reads_old_layout_and_writes_new_one(
+        contract: Contract<TokenV1>,
+        alice: Address,
+        bob: Address,
+    ) {
+        // State written by the previous implementation, whose fields keep
+        // their slots in the new layout.
+        contract.init(alice, |token| {
+            token.v0.symbol.set_str("TKN");
+            token.v0.balances.setter(alice).set(AMOUNT);
+            token.v0.balances.setter(bob).set(AMOUNT + U256::ONE);
+        });
+
+        contract
+            .sender(alice)
+            .migrate(&[alice, bob])
+            .motsu_expect("should migrate state");
+
+        let token = contract.sender(alice);
+        assert_eq!("TKN", token.symbols.get(ID).get_string());
+        assert_eq!(AMOUNT, token.balances.get(alice).get(ID));
+        assert_eq!(AMOUNT + U256::ONE, token.balances.get(bob).get(ID));
+        assert!(token.v0.symbol.get_string().is_empty());
+        assert!(token.v0.balances.get(alice).is_zero());
+        assert!(token.v0.balances.get(bob).is_zero());
+        assert_eq!(V1, token.layout.storage_layout_version());
+    }
+
+    #[motsu::test]
+    fn migration_errors_when_run_twice(
+        contract: Contract<TokenV1>,
+        alice: Address,
+    ) {
+        contract.init(alice, |token| {
+            token.v0.balances.setter(alice).set(AMOUNT);
+        });
+        contract
+            .sender(alice)
+            .migrate(&[alice])
+            .motsu_expect("should migrate state");
+
+        let err = contract
+            .sender(alice)
+            .migrate(&[alice])
+            .motsu_expect_err("should not migrate state twice");
+
+        assert!(matches!(err, Error::UnexpectedVersion(_)));
+        assert_eq!(AMOUNT, contract.sender(alice).balances.get(alice).get(ID));
+    }
+}
@@ -13,6 +13,7 @@ use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{uint, U256, U32};
 pub use generic_size::{Size, S160, S208, S224};
+use openzeppelin_stylus_proc::SolidityError;
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError,
@@ -1,3 +1,4 @@
 //! Solidity storage types used by other contracts.
 pub mod bitmap;
 pub mod checkpoints;
+pub mod sorted_list;
@@ -0,0 +1,517 @@
+//! Contract module for keeping nodes sorted by key in storage, e.g. the orders
+//! of an order book sorted by price.
+//!
+//! A [`SortedList`] is a doubly linked list of nodes, identified by non-zero
+//! `U256` ids, and sorted in ascending order of their `U256` keys. Nodes with
+//! the same key are kept in insertion order.
+//!
+//! Removing a node and walking to its neighbours take constant time. Inserting
+//! a node walks the list to find its position, so the walk is bounded by a
+//! `max_steps` argument, and can start from a hint node already known to be
+//! placed before the new one.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::U256;
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    prelude::*,
+    storage::{StorageMap, StorageU256},
+};
+
+use crate::utils::math::storage::{AddAssignUnchecked, SubAssignUnchecked};
+
+/// Id of the sentinel node, linking the last node of the list to the first
+/// one.
+const SENTINEL: U256 = U256::ZERO;
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// The node `id` can't be inserted, because it is zero or already in
+        /// the list.
+        ///
+        /// * `id` - Id of the node.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SortedListInvalidNode(uint256 id);
+
+        /// The node `id` is not in the list.
+        ///
+        /// * `id` - Id of the node.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SortedListNonexistentNode(uint256 id);
+
+        /// The node `hint` is not in the list, or its key is greater than
+        /// the key of the node to insert.
+        ///
+        /// * `hint` - Id of the node to start walking from.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SortedListInvalidHint(uint256 hint);
+
+        /// Finding the position of a node takes more than `max_steps`.
+        ///
+        /// * `max_steps` - Maximum number of nodes to walk through.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error SortedListMaxStepsExceeded(uint32 max_steps);
+    }
+}
+
+/// An error that occurred while updating a [`SortedList`].
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The node can't be inserted, because it is zero or already in the list.
+    InvalidNode(SortedListInvalidNode),
+    /// The node is not in the list.
+    NonexistentNode(SortedListNonexistentNode),
+    /// The hint is not in the list, or placed after the node to insert.
+    InvalidHint(SortedListInvalidHint),
+    /// Finding the position of a node takes too many steps.
+    MaxStepsExceeded(SortedListMaxStepsExceeded),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of a [`SortedList`] contract.
+#[storage]
+pub struct SortedList {
+    /// Mapping from node id to its key.
+    pub(crate) keys: StorageMap<U256, StorageU256>,
+    /// Mapping from node id to the id of the next node, or [`SENTINEL`] for
+    /// the last node. The sentinel maps to the first node.
+    pub(crate) next: StorageMap<U256, StorageU256>,
+    /// Mapping from node id to the id of the previous node, or [`SENTINEL`]
+    /// for the first node. The sentinel maps to the last node.
+    pub(crate) prev: StorageMap<U256, StorageU256>,
+    /// Number of nodes in the list.
+    pub(crate) length: StorageU256,
+}
+
+impl SortedList {
+    /// Returns the number of nodes in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn len(&self) -> U256 {
+        self.length.get()
+    }
+
+    /// Returns whether the list has no nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len().is_zero()
+    }
+
+    /// Returns whether the node `id` is in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `id` - Id of the node.
+    #[must_use]
+    pub fn contains(&self, id: U256) -> bool {
+        // Only the first node has the sentinel as its previous node.
+        id != SENTINEL
+            && (!self.prev.get(id).is_zero() || self.next.get(SENTINEL) == id)
+    }
+
+    /// Returns the key of the node `id`, or zero if it is not in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `id` - Id of the node.
+    #[must_use]
+    pub fn key(&self, id: U256) -> U256 {
+        self.keys.get(id)
+    }
+
+    /// Returns the id of the node with the lowest key, or zero if the list is
+    /// empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn first(&self) -> U256 {
+        self.next.get(SENTINEL)
+    }
+
+    /// Returns the id of the node with the highest key, or zero if the list is
+    /// empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn last(&self) -> U256 {
+        self.prev.get(SENTINEL)
+    }
+
+    /// Returns the id of the node after `id`, or zero if `id` is the last
+    /// node or is not in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `id` - Id of the node.
+    #[must_use]
+    pub fn next(&self, id: U256) -> U256 {
+        if id == SENTINEL {
+            return SENTINEL;
+        }
+        self.next.get(id)
+    }
+
+    /// Returns the id of the node before `id`, or zero if `id` is the first
+    /// node or is not in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `id` - Id of the node.
+    #[must_use]
+    pub fn prev(&self, id: U256) -> U256 {
+        if id == SENTINEL {
+            return SENTINEL;
+        }
+        self.prev.get(id)
+    }
+
+    /// Returns the ids of at most `limit` nodes, in ascending order of their
+    /// keys, starting after the node `after`, or from the first node if
+    /// `after` is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `after` - Id of the node to start after.
+    /// * `limit` - Maximum number of ids to return.
+    #[must_use]
+    pub fn range(&self, after: U256, limit: u32) -> Vec<U256> {
+        let mut ids = vec![];
+        if after != SENTINEL && !self.contains(after) {
+            return ids;
+        }
+
+        let mut id = self.next.get(after);
+        while id != SENTINEL && ids.len() < limit as usize {
+            ids.push(id);
+            id = self.next.get(id);
+        }
+        ids
+    }
+
+    /// Inserts the node `id` with `key`, after the nodes with a lower or
+    /// equal key.
+    ///
+    /// Walks the list from its first node, through at most `max_steps` nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `id` - Id of the node.
+    /// * `key` - Key of the node.
+    /// * `max_steps` - Maximum number of nodes to walk through.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidNode`] - If `id` is zero or already in the list.
+    /// * [`Error::MaxStepsExceeded`] - If finding the position of the node
+    ///   takes more than `max_steps`.
+    pub fn insert(
+        &mut self,
+        id: U256,
+        key: U256,
+        max_steps: u32,
+    ) -> Result<(), Error> {
+        self.insert_after(SENTINEL, id, key, max_steps)
+    }
+
+    /// Inserts the node `id` with `key`, after the nodes with a lower or
+    /// equal key.
+    ///
+    /// Walks the list from the node `hint`, or from its first node if `hint`
+    /// is zero, through at most `max_steps` nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `hint` - Id of the node to start walking from.
+    /// * `id` - Id of the node.
+    /// * `key` - Key of the node.
+    /// * `max_steps` - Maximum number of nodes to walk through.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidNode`] - If `id` is zero or already in the list.
+    /// * [`Error::InvalidHint`] - If `hint` is not in the list, or its key is
+    ///   greater than `key`.
+    /// * [`Error::MaxStepsExceeded`] - If finding the position of the node
+    ///   takes more than `max_steps`.
+    pub fn insert_after(
+        &mut self,
+        hint: U256,
+        id: U256,
+        key: U256,
+        max_steps: u32,
+    ) -> Result<(), Error> {
+        if id == SENTINEL || self.contains(id) {
+            return Err(Error::InvalidNode(SortedListInvalidNode { id }));
+        }
+
+        if hint != SENTINEL && (!self.contains(hint) || self.key(hint) > key) {
+            return Err(Error::InvalidHint(SortedListInvalidHint { hint }));
+        }
+
+        let mut prev = hint;
+        let mut next = self.next.get(prev);
+        let mut steps = 0;
+        while next != SENTINEL && self.key(next) <= key {
+            if steps == max_steps {
+                return Err(Error::MaxStepsExceeded(
+                    SortedListMaxStepsExceeded { max_steps },
+                ));
+            }
+            steps += 1;
+            prev = next;
+            next = self.next.get(next);
+        }
+
+        self.keys.setter(id).set(key);
+        self.prev.setter(id).set(prev);
+        self.next.setter(id).set(next);
+        self.next.setter(prev).set(id);
+        self.prev.setter(next).set(id);
+        // Overflow not possible: node ids are distinct `U256` values, other
+        // than the sentinel.
+        self.length.add_assign_unchecked(U256::ONE);
+
+        Ok(())
+    }
+
+    /// Removes the node `id` from the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `id` - Id of the node.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NonexistentNode`] - If `id` is not in the list.
+    pub fn remove(&mut self, id: U256) -> Result<(), Error> {
+        if !self.contains(id) {
+            return Err(Error::NonexistentNode(SortedListNonexistentNode {
+                id,
+            }));
+        }
+
+        let prev = self.prev.get(id);
+        let next = self.next.get(id);
+        self.next.setter(prev).set(next);
+        self.prev.setter(next).set(prev);
+
+        self.keys.delete(id);
+        self.prev.delete(id);
+        self.next.delete(id);
+        // Underflow not possible: `id` is in the list.
+        self.length.sub_assign_unchecked(U256::ONE);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{
+        private::proptest::{prop_assert, prop_assert_eq, proptest},
+        uint, Address, U256,
+    };
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::{public, TopLevelStorage};
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for SortedList {}
+
+    #[public]
+    impl SortedList {}
+
+    const MAX_STEPS: u32 = 64;
+
+    fn insert_all(list: &Contract<SortedList>, alice: Address, keys: &[u64]) {
+        for (index, &key) in keys.iter().enumerate() {
+            list.sender(alice)
+                .insert(U256::from(index + 1), U256::from(key), MAX_STEPS)
+                .motsu_expect("should insert node");
+        }
+    }
+
+    #[motsu::test]
+    fn insert_keeps_nodes_sorted(list: Contract<SortedList>, alice: Address) {
+        insert_all(&list, alice, &[30, 10, 20, 10]);
+
+        // Node 4 has the same key as node 2, so it is placed after it.
+        let expected =
+            vec![uint!(2_U256), uint!(4_U256), uint!(3_U256), uint!(1_U256)];
+        assert_eq!(expected, list.sender(alice).range(U256::ZERO, MAX_STEPS));
+        assert_eq!(uint!(4_U256), list.sender(alice).len());
+        assert_eq!(uint!(2_U256), list.sender(alice).first());
+        assert_eq!(uint!(1_U256), list.sender(alice).last());
+        assert_eq!(uint!(3_U256), list.sender(alice).next(uint!(4_U256)));
+        assert_eq!(uint!(2_U256), list.sender(alice).prev(uint!(4_U256)));
+        assert_eq!(uint!(20_U256), list.sender(alice).key(uint!(3_U256)));
+    }
+
+    #[motsu::test]
+    fn insert_reverts_on_invalid_node(
+        list: Contract<SortedList>,
+        alice: Address,
+    ) {
+        insert_all(&list, alice, &[10]);
+
+        for id in [U256::ZERO, U256::ONE] {
+            let err = list
+                .sender(alice)
+                .insert(id, U256::ONE, MAX_STEPS)
+                .motsu_expect_err("should not insert invalid node");
+            assert!(matches!(
+                err,
+                Error::InvalidNode(SortedListInvalidNode { id: i }) if i == id
+            ));
+        }
+    }
+
+    #[motsu::test]
+    fn insert_reverts_when_max_steps_exceeded(
+        list: Contract<SortedList>,
+        alice: Address,
+    ) {
+        insert_all(&list, alice, &[10, 20, 30]);
+
+        let err = list
+            .sender(alice)
+            .insert(uint!(4_U256), uint!(40_U256), 2)
+            .motsu_expect_err("should not walk more than two nodes");
+
+        assert!(matches!(
+            err,
+            Error::MaxStepsExceeded(SortedListMaxStepsExceeded {
+                max_steps: 2
+            })
+        ));
+
+        list.sender(alice)
+            .insert(uint!(4_U256), uint!(40_U256), 3)
+            .motsu_expect("should insert within three steps");
+    }
+
+    #[motsu::test]
+    fn insert_after_walks_from_hint(
+        list: Contract<SortedList>,
+        alice: Address,
+    ) {
+        insert_all(&list, alice, &[10, 20, 30]);
+
+        list.sender(alice)
+            .insert_after(uint!(2_U256), uint!(4_U256), uint!(25_U256), 1)
+            .motsu_expect("should insert from hint");
+
+        let expected =
+            vec![uint!(1_U256), uint!(2_U256), uint!(4_U256), uint!(3_U256)];
+        assert_eq!(expected, list.sender(alice).range(U256::ZERO, MAX_STEPS));
+
+        let err = list
+            .sender(alice)
+            .insert_after(uint!(3_U256), uint!(5_U256), uint!(15_U256), 1)
+            .motsu_expect_err("should not insert before hint");
+
+        assert!(matches!(
+            err,
+            Error::InvalidHint(SortedListInvalidHint { hint })
+                if hint == uint!(3_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn remove_unlinks_node(list: Contract<SortedList>, alice: Address) {
+        insert_all(&list, alice, &[10, 20, 30]);
+
+        for id in [uint!(2_U256), uint!(1_U256), uint!(3_U256)] {
+            list.sender(alice).remove(id).motsu_expect("should remove node");
+            assert!(!list.sender(alice).contains(id));
+            assert!(list.sender(alice).key(id).is_zero());
+        }
+
+        assert!(list.sender(alice).is_empty());
+        assert!(list.sender(alice).first().is_zero());
+        assert!(list.sender(alice).last().is_zero());
+
+        let err = list
+            .sender(alice)
+            .remove(U256::ONE)
+            .motsu_expect_err("should not remove nonexistent node");
+
+        assert!(matches!(
+            err,
+            Error::NonexistentNode(SortedListNonexistentNode { id })
+                if id == U256::ONE
+        ));
+    }
+
+    #[motsu::test]
+    fn range_is_paginated(list: Contract<SortedList>, alice: Address) {
+        insert_all(&list, alice, &[10, 20, 30]);
+
+        let page = list.sender(alice).range(U256::ZERO, 2);
+        assert_eq!(vec![uint!(1_U256), uint!(2_U256)], page);
+
+        let page = list.sender(alice).range(uint!(2_U256), 2);
+        assert_eq!(vec![uint!(3_U256)], page);
+
+        assert!(list.sender(alice).range(uint!(9_U256), 2).is_empty());
+    }
+
+    #[motsu::test]
+    fn random_inserts_and_removes_keep_nodes_sorted() {
+        proptest!(|(keys: Vec<u64>, alice: Address)| {
+            let list = Contract::<SortedList>::new();
+            let keys = &keys[..keys.len().min(32)];
+            insert_all(&list, alice, keys);
+
+            for id in (1..=keys.len()).step_by(3) {
+                list.sender(alice)
+                    .remove(U256::from(id))
+                    .motsu_expect("should remove node");
+            }
+
+            let ids = list.sender(alice).range(U256::ZERO, MAX_STEPS);
+            prop_assert_eq!(U256::from(ids.len()), list.sender(alice).len());
+            for pair in ids.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                prop_assert!(
+                    list.sender(alice).key(a) < list.sender(alice).key(b)
+                        || (list.sender(alice).key(a) == list.sender(alice).key(b)
+                            && a < b)
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,265 @@
+//! Deterministic fixtures shared by unit tests, end-to-end tests and
+//! benchmarks.
+//!
+//! Token ids, amounts and accounts are derived from their index or from a
+//! seed, so that failures can be reproduced, and so that every context works
+//! with the same values:
+//!
+//! ```rust,ignore
+//! let batch = MintBatch::new(ALICE, 4);
+//!
+//! // In unit tests.
+//! batch.mint(&mut *contract.sender(alice))?;
+//! // In end-to-end tests and benchmarks.
+//! receipt!(contract.mintBatch(batch.to, batch.ids, batch.values))?;
+//! ```
+//!
+//! Available in tests, and to other crates through the `test-utils` feature.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, Address, U256};
+
+use crate::token::erc6909::IErc6909Internal;
+
+/// Account of Alice.
+pub const ALICE: Address =
+    address!("0xA11CE00000000000000000000000000000000000");
+/// Account of Bob.
+pub const BOB: Address = address!("0xB0B0000000000000000000000000000000000000");
+/// Account of Charlie.
+pub const CHARLIE: Address =
+    address!("0xC4A12E0000000000000000000000000000000000");
+
+/// Returns `count` distinct non-zero accounts, the `i`-th one being the
+/// address `i + 1`.
+///
+/// # Arguments
+///
+/// * `count` - Number of accounts.
+#[must_use]
+pub fn accounts(count: usize) -> Vec<Address> {
+    (1..=count as u64)
+        .map(|index| Address::left_padding_from(&index.to_be_bytes()))
+        .collect()
+}
+
+/// Returns `size` consecutive token ids, starting at `start`.
+///
+/// # Arguments
+///
+/// * `start` - First token id.
+/// * `size` - Number of token ids.
+#[must_use]
+pub fn token_ids_from(start: usize, size: usize) -> Vec<U256> {
+    (start..start + size).map(U256::from).collect()
+}
+
+/// Returns the `size` token ids `0..size`.
+///
+/// # Arguments
+///
+/// * `size` - Number of token ids.
+#[must_use]
+pub fn random_token_ids(size: usize) -> Vec<U256> {
+    token_ids_from(0, size)
+}
+
+/// Returns the `size` amounts `1..=size`, none of them zero.
+///
+/// # Arguments
+///
+/// * `size` - Number of amounts.
+#[must_use]
+pub fn random_values(size: usize) -> Vec<U256> {
+    (1..=size).map(U256::from).collect()
+}
+
+/// Generator of pseudo-random values, following the `SplitMix64` sequence
+/// of its seed.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Creates a generator of the sequence of `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed of the sequence.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next number of the sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next index of the sequence, lower than `len`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    /// * `len` - Upper bound of the index, exclusive.
+    ///
+    /// # Panics
+    ///
+    /// * If `len` is zero.
+    pub fn next_index(&mut self, len: usize) -> usize {
+        // Truncation is fine, since the result is lower than `len`.
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (self.next_u64() % len as u64) as usize;
+        index
+    }
+
+    /// Returns the next amount of the sequence, up to `max` included.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    /// * `max` - Largest amount returned.
+    pub fn next_amount(&mut self, max: u64) -> U256 {
+        U256::from(self.next_u64() % (max.saturating_add(1)))
+    }
+
+    /// Returns `size` distinct pseudo-random token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    /// * `size` - Number of token ids.
+    pub fn token_ids(&mut self, size: usize) -> Vec<U256> {
+        let mut ids: Vec<U256> = Vec::with_capacity(size);
+        while ids.len() < size {
+            let id = U256::from(self.next_u64());
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// Returns `size` non-zero pseudo-random amounts, up to `max` included.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    /// * `size` - Number of amounts.
+    /// * `max` - Largest amount returned.
+    pub fn values(&mut self, size: usize, max: u64) -> Vec<U256> {
+        let max = max.max(1);
+        (0..size).map(|_| U256::from(self.next_u64() % max + 1)).collect()
+    }
+}
+
+/// Tokens minted to an account in a single batch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MintBatch {
+    /// Account of the recipient.
+    pub to: Address,
+    /// Token ids minted.
+    pub ids: Vec<U256>,
+    /// Amount minted of each token id.
+    pub values: Vec<U256>,
+}
+
+impl MintBatch {
+    /// Creates a batch minting [`random_values`] of [`random_token_ids`] of
+    /// the given `size` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Account of the recipient.
+    /// * `size` - Number of token ids.
+    #[must_use]
+    pub fn new(to: Address, size: usize) -> Self {
+        Self { to, ids: random_token_ids(size), values: random_values(size) }
+    }
+
+    /// Mints the batch on `token`, one token id at a time, through its
+    /// [`IErc6909Internal`] implementation, so that the hooks of extensions
+    /// run.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the batch.
+    /// * `token` - Write access to the token.
+    ///
+    /// # Errors
+    ///
+    /// * If minting any of the token ids fails.
+    pub fn mint<T: IErc6909Internal>(
+        &self,
+        token: &mut T,
+    ) -> Result<(), T::Error> {
+        for (&id, &value) in self.ids.iter().zip(&self.values) {
+            token._mint(self.to, id, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc6909::{Erc6909, IErc6909};
+
+    #[test]
+    fn generators_are_deterministic() {
+        assert_eq!(random_token_ids(3), token_ids_from(0, 3));
+        assert_eq!(
+            random_values(3),
+            [U256::from(1), U256::from(2), U256::from(3)]
+        );
+
+        let mut generator = Generator::new(0x6909);
+        let ids = generator.token_ids(8);
+        assert_eq!(ids, Generator::new(0x6909).token_ids(8));
+        assert_ne!(ids, Generator::new(0x1155).token_ids(8));
+
+        let values = generator.values(8, 10);
+        assert!(values
+            .iter()
+            .all(|&value| !value.is_zero() && value <= U256::from(10)));
+    }
+
+    #[test]
+    fn accounts_are_distinct_and_non_zero() {
+        let accounts = accounts(4);
+
+        assert_eq!(accounts.len(), 4);
+        assert!(accounts.iter().all(|account| !account.is_zero()));
+        for (index, account) in accounts.iter().enumerate() {
+            assert!(!accounts[index + 1..].contains(account));
+        }
+    }
+
+    #[motsu::test]
+    fn mint_batch_mints_each_token_id(
+        contract: Contract<Erc6909>,
+        alice: Address,
+    ) {
+        let batch = MintBatch::new(alice, 3);
+
+        batch
+            .mint(&mut *contract.sender(alice))
+            .motsu_expect("should mint batch");
+
+        for (&id, &value) in batch.ids.iter().zip(&batch.values) {
+            assert_eq!(value, contract.sender(alice).balance_of(alice, id));
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Timelock Contract.
+//!
+//! Contract module which allows delaying sensitive administrative actions,
+//! e.g. pausing a contract forever, changing a fee recipient or setting an
+//! oracle, so that users have time to react before they take effect.
+//!
+//! An action is identified by an operation id, usually the hash of its
+//! arguments computed with [`Timelock::hash_operation`]. It must first be
+//! queued with [`Timelock::queue`], and can only be executed with
+//! [`Timelock::execute`] once [`ITimelock::min_delay`] seconds have passed.
+//! Queued operations can be cancelled at any time with [`Timelock::cancel`].
+//!
+//! ```rust,ignore
+//! pub fn set_fee_recipient(&mut self, recipient: Address) -> Result<(), Error> {
+//!   self.ownable.only_owner()?;
+//!   let id = Timelock::hash_operation(&(SET_FEE_RECIPIENT, recipient).abi_encode());
+//!   self.timelock.execute(id)?;
+//!   self.fee_recipient.set(recipient);
+//!   Ok(())
+//! }
+//! ```
+//!
+//! Note that [`Timelock::queue`], [`Timelock::execute`],
+//! [`Timelock::cancel`] and [`Timelock::_set_min_delay`] are not exposed by
+//! default. You should expose them manually in your contract's abi, guarded by
+//! proper access control.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{keccak256, B256, U256, U64};
+use openzeppelin_stylus_proc::SolidityError;
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageMap, StorageU64},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when operation `id` is queued, and can be executed from
+        /// `ready_at` on.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OperationQueued(bytes32 indexed id, uint64 ready_at);
+
+        /// Emitted when operation `id` is executed.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OperationExecuted(bytes32 indexed id);
+
+        /// Emitted when operation `id` is cancelled.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event OperationCancelled(bytes32 indexed id);
+
+        /// Emitted when the minimum delay for future operations is modified.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event MinDelayChanged(uint64 old_delay, uint64 new_delay);
+    }
+
+    sol! {
+        /// Indicates that operation `id` is already queued.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error TimelockAlreadyQueued(bytes32 id);
+
+        /// Indicates that operation `id` is not queued.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error TimelockNotQueued(bytes32 id);
+
+        /// Indicates that operation `id` can't be executed before `ready_at`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error TimelockNotReady(bytes32 id, uint64 ready_at);
+    }
+}
+
+/// A Timelock error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that the operation is already queued.
+    AlreadyQueued(TimelockAlreadyQueued),
+    /// Indicates that the operation is not queued.
+    NotQueued(TimelockNotQueued),
+    /// Indicates that the operation's delay has not passed yet.
+    NotReady(TimelockNotReady),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of a [`Timelock`] Contract.
+#[storage]
+pub struct Timelock {
+    /// Minimum delay, in seconds, between queuing and executing an operation.
+    pub(crate) min_delay: StorageU64,
+    /// Mapping from operation id to the timestamp from which it can be
+    /// executed. Zero for operations that are not queued.
+    pub(crate) ready_at: StorageMap<B256, StorageU64>,
+}
+
+/// Interface for [`Timelock`].
+pub trait ITimelock {
+    /// Returns the minimum delay, in seconds, between queuing and executing
+    /// an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn min_delay(&self) -> U256;
+
+    /// Returns the timestamp from which operation `id` can be executed, or
+    /// zero if it is not queued.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Operation id.
+    fn ready_at(&self, id: B256) -> U256;
+
+    /// Returns true if operation `id` is queued, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Operation id.
+    fn is_operation_pending(&self, id: B256) -> bool;
+
+    /// Returns true if operation `id` is queued and its delay has passed,
+    /// and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Operation id.
+    fn is_operation_ready(&self, id: B256) -> bool;
+}
+
+#[public]
+#[implements(ITimelock)]
+impl Timelock {}
+
+#[public]
+impl ITimelock for Timelock {
+    fn min_delay(&self) -> U256 {
+        U256::from(self.min_delay.get())
+    }
+
+    fn ready_at(&self, id: B256) -> U256 {
+        U256::from(self.ready_at.get(id))
+    }
+
+    fn is_operation_pending(&self, id: B256) -> bool {
+        !self.ready_at.get(id).is_zero()
+    }
+
+    fn is_operation_ready(&self, id: B256) -> bool {
+        let ready_at = self.ready_at.get(id);
+        !ready_at.is_zero() && ready_at <= U64::from(block::timestamp())
+    }
+}
+
+impl Timelock {
+    /// Returns the id of the operation described by `data`, e.g. the abi
+    /// encoding of an action's selector and arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Encoded operation.
+    #[must_use]
+    pub fn hash_operation(data: &[u8]) -> B256 {
+        keccak256(data)
+    }
+
+    /// Queues operation `id`, that can be executed once the minimum delay
+    /// has passed. Returns the timestamp from which it can be executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Operation id.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AlreadyQueued`] - If the operation is already queued.
+    ///
+    /// # Events
+    ///
+    /// * [`OperationQueued`].
+    ///
+    /// # Panics
+    ///
+    /// * If the ready timestamp exceeds [`u64::MAX`].
+    pub fn queue(&mut self, id: B256) -> Result<U64, Error> {
+        if self.is_operation_pending(id) {
+            return Err(Error::AlreadyQueued(TimelockAlreadyQueued { id }));
+        }
+
+        let ready_at = U64::from(block::timestamp())
+            .checked_add(self.min_delay.get())
+            .expect("ready timestamp should not exceed `u64::MAX`");
+        self.ready_at.setter(id).set(ready_at);
+
+        evm::log(OperationQueued { id, ready_at: ready_at.to() });
+
+        Ok(ready_at)
+    }
+
+    /// Marks operation `id` as executed. Should be called right before
+    /// performing the delayed action.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Operation id.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotQueued`] - If the operation is not queued.
+    /// * [`Error::NotReady`] - If the operation's delay has not passed yet.
+    ///
+    /// # Events
+    ///
+    /// * [`OperationExecuted`].
+    pub fn execute(&mut self, id: B256) -> Result<(), Error> {
+        let ready_at = self.ready_at.get(id);
+        if ready_at.is_zero() {
+            return Err(Error::NotQueued(TimelockNotQueued { id }));
+        }
+        if !self.is_operation_ready(id) {
+            return Err(Error::NotReady(TimelockNotReady {
+                id,
+                ready_at: ready_at.to(),
+            }));
+        }
+
+        self.ready_at.delete(id);
+
+        evm::log(OperationExecuted { id });
+
+        Ok(())
+    }
+
+    /// Cancels queued operation `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `id` - Operation id.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotQueued`] - If the operation is not queued.
+    ///
+    /// # Events
+    ///
+    /// * [`OperationCancelled`].
+    pub fn cancel(&mut self, id: B256) -> Result<(), Error> {
+        if !self.is_operation_pending(id) {
+            return Err(Error::NotQueued(TimelockNotQueued { id }));
+        }
+
+        self.ready_at.delete(id);
+
+        evm::log(OperationCancelled { id });
+
+        Ok(())
+    }
+
+    /// Sets the minimum delay, in seconds, of operations queued from now on.
+    ///
+    /// Internal function that can be exposed with access control if desired.
+    /// Consider making changes of the delay itself go through the timelock.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `new_delay` - New minimum delay, in seconds.
+    ///
+    /// # Events
+    ///
+    /// * [`MinDelayChanged`].
+    pub fn _set_min_delay(&mut self, new_delay: U64) {
+        let old_delay = self.min_delay.get();
+        self.min_delay.set(new_delay);
+        evm::log(MinDelayChanged {
+            old_delay: old_delay.to(),
+            new_delay: new_delay.to(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{uint, Address, B256, U256, U64};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
+
+    use super::*;
+
+    unsafe impl TopLevelStorage for Timelock {}
+
+    const DELAY: U64 = uint!(3600_U64);
+
+    fn operation() -> B256 {
+        Timelock::hash_operation(b"set_fee_recipient")
+    }
+
+    #[motsu::test]
+    fn set_min_delay_works(contract: Contract<Timelock>, alice: Address) {
+        contract.sender(alice)._set_min_delay(DELAY);
+
+        assert_eq!(contract.sender(alice).min_delay(), U256::from(DELAY));
+        contract.assert_emitted(&MinDelayChanged {
+            old_delay: 0,
+            new_delay: DELAY.to(),
+        });
+    }
+
+    #[motsu::test]
+    fn queue_works(contract: Contract<Timelock>, alice: Address) {
+        contract.sender(alice)._set_min_delay(DELAY);
+
+        let ready_at = contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect("should queue operation");
+
+        assert!(contract.sender(alice).is_operation_pending(operation()));
+        assert!(!contract.sender(alice).is_operation_ready(operation()));
+        assert_eq!(
+            contract.sender(alice).ready_at(operation()),
+            U256::from(ready_at)
+        );
+        contract.assert_emitted(&OperationQueued {
+            id: operation(),
+            ready_at: ready_at.to(),
+        });
+    }
+
+    #[motsu::test]
+    fn queue_errors_when_already_queued(
+        contract: Contract<Timelock>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect("should queue operation");
+
+        let err = contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect_err("should not queue operation twice");
+
+        assert!(matches!(
+            err,
+            Error::AlreadyQueued(TimelockAlreadyQueued { id })
+                if id == operation()
+        ));
+    }
+
+    #[motsu::test]
+    fn execute_works_when_ready(contract: Contract<Timelock>, alice: Address) {
+        contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect("should queue operation");
+
+        contract
+            .sender(alice)
+            .execute(operation())
+            .motsu_expect("should execute operation");
+
+        assert!(!contract.sender(alice).is_operation_pending(operation()));
+        contract.assert_emitted(&OperationExecuted { id: operation() });
+    }
+
+    #[motsu::test]
+    fn execute_errors_when_not_ready(
+        contract: Contract<Timelock>,
+        alice: Address,
+    ) {
+        contract.sender(alice)._set_min_delay(DELAY);
+        contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect("should queue operation");
+
+        let err = contract
+            .sender(alice)
+            .execute(operation())
+            .motsu_expect_err("should not execute operation before delay");
+
+        assert!(matches!(
+            err,
+            Error::NotReady(TimelockNotReady { id, .. }) if id == operation()
+        ));
+        assert!(contract.sender(alice).is_operation_pending(operation()));
+    }
+
+    #[motsu::test]
+    fn execute_errors_when_not_queued(
+        contract: Contract<Timelock>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .execute(operation())
+            .motsu_expect_err("should not execute unknown operation");
+
+        assert!(matches!(
+            err,
+            Error::NotQueued(TimelockNotQueued { id }) if id == operation()
+        ));
+    }
+
+    #[motsu::test]
+    fn cancel_works(contract: Contract<Timelock>, alice: Address) {
+        contract.sender(alice)._set_min_delay(DELAY);
+        contract
+            .sender(alice)
+            .queue(operation())
+            .motsu_expect("should queue operation");
+
+        contract
+            .sender(alice)
+            .cancel(operation())
+            .motsu_expect("should cancel operation");
+
+        assert!(!contract.sender(alice).is_operation_pending(operation()));
+        contract.assert_emitted(&OperationCancelled { id: operation() });
+    }
+
+    #[motsu::test]
+    fn cancel_errors_when_not_queued(
+        contract: Contract<Timelock>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .cancel(operation())
+            .motsu_expect_err("should not cancel unknown operation");
+
+        assert!(matches!(err, Error::NotQueued(_)));
+    }
+}
@@ -4,18 +4,11 @@ use abi::Erc1155Supply;
 use alloy::primitives::{Address, U256};
 use e2e::{receipt, send, watch, Account, EventExt, Panic, PanicCode};
 use mock::{receiver, receiver::ERC1155ReceiverMock};
+use openzeppelin_stylus::utils::test_utils::{random_token_ids, random_values};
 
 mod abi;
 mod mock;
 
-fn random_token_ids(size: usize) -> Vec<U256> {
-    (0..size).map(U256::from).collect()
-}
-
-fn random_values(size: usize) -> Vec<U256> {
-    (1..=size).map(U256::from).collect()
-}
-
 // ============================================================================
 // Integration Tests: ERC-1155 Supply Extension
 // ============================================================================
@@ -7,18 +7,11 @@ use alloy::{
 };
 use e2e::{receipt, send, watch, Account, EventExt, PanicCode, Revert};
 use mock::{receiver, receiver::ERC1155ReceiverMock};
+use openzeppelin_stylus::utils::test_utils::{random_token_ids, random_values};
 
 mod abi;
 mod mock;
 
-fn random_token_ids(size: usize) -> Vec<U256> {
-    (0..size).map(U256::from).collect()
-}
-
-fn random_values(size: usize) -> Vec<U256> {
-    (1..=size).map(U256::from).collect()
-}
-
 trait EncodeAsStr {
     fn encode_as_str(&self) -> String;
 }
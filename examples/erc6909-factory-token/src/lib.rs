@@ -0,0 +1,211 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U8};
+use openzeppelin_stylus::{
+    access::ownable::{self, IOwnable, Ownable},
+    delegate_ierc6909,
+    token::erc6909::{self, extensions::IErc6909Metadata, Erc6909, IErc6909},
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::{
+    alloy_sol_types::sol,
+    prelude::*,
+    storage::{StorageBool, StorageString, StorageU8},
+};
+
+sol! {
+    #[derive(Debug)]
+    error Erc6909FactoryTokenAlreadyInitialized();
+}
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    InsufficientBalance(erc6909::Erc6909InsufficientBalance),
+    InsufficientPermission(erc6909::Erc6909InsufficientPermission),
+    InsufficientAllowance(erc6909::Erc6909InsufficientAllowance),
+    InvalidApprover(erc6909::ERC6909InvalidApprover),
+    InvalidSender(erc6909::ERC6909InvalidSender),
+    InvalidSpender(erc6909::ERC6909InvalidSpender),
+    InvalidApproverForId(erc6909::ERC6909InvalidApproverForId),
+    InvalidSpenderForId(erc6909::ERC6909InvalidSpenderForId),
+    InvalidReceiver(erc6909::ERC6909InvalidReceiver),
+    InvalidArrayLength(erc6909::ERC6909InvalidArrayLength),
+    SelfTarget(erc6909::ERC6909SelfTarget),
+    UnauthorizedAccount(ownable::OwnableUnauthorizedAccount),
+    InvalidOwner(ownable::OwnableInvalidOwner),
+    AlreadyInitialized(Erc6909FactoryTokenAlreadyInitialized),
+}
+
+impl From<erc6909::Error> for Error {
+    fn from(value: erc6909::Error) -> Self {
+        match value {
+            erc6909::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            erc6909::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            erc6909::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            erc6909::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            erc6909::Error::InvalidSender(e) => Error::InvalidSender(e),
+            erc6909::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            erc6909::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            erc6909::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            erc6909::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            erc6909::Error::InvalidArrayLength(e) => {
+                Error::InvalidArrayLength(e)
+            }
+            erc6909::Error::SelfTarget(e) => Error::SelfTarget(e),
+        }
+    }
+}
+
+impl From<ownable::Error> for Error {
+    fn from(value: ownable::Error) -> Self {
+        match value {
+            ownable::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            ownable::Error::InvalidOwner(e) => Error::InvalidOwner(e),
+        }
+    }
+}
+
+/// ERC-6909 token meant to be used as the implementation of the clones
+/// deployed by the `erc6909-factory` example.
+///
+/// Clones don't run constructors, so the token is configured through
+/// `initialize` instead, which can only be called once per instance.
+#[entrypoint]
+#[storage]
+struct Erc6909FactoryTokenExample {
+    erc6909: Erc6909,
+    ownable: Ownable,
+    initialized: StorageBool,
+    name: StorageString,
+    symbol: StorageString,
+    decimals: StorageU8,
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Metadata,
+    IOwnable<Error = Error>,
+    IErc165
+)]
+impl Erc6909FactoryTokenExample {
+    /// Locks the implementation itself, so that only its clones can be
+    /// initialized.
+    #[constructor]
+    fn constructor(&mut self) {
+        self.initialized.set(true);
+    }
+
+    fn initialize(
+        &mut self,
+        owner: Address,
+        name: String,
+        symbol: String,
+        decimals: U8,
+    ) -> Result<(), Error> {
+        if self.initialized.get() {
+            return Err(Error::AlreadyInitialized(
+                Erc6909FactoryTokenAlreadyInitialized {},
+            ));
+        }
+        self.initialized.set(true);
+
+        self.ownable.constructor(owner)?;
+        self.name.set_str(name);
+        self.symbol.set_str(symbol);
+        self.decimals.set(decimals);
+        Ok(())
+    }
+
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+}
+
+delegate_ierc6909!(Erc6909FactoryTokenExample => erc6909, Error);
+
+#[public]
+impl IErc6909Metadata for Erc6909FactoryTokenExample {
+    fn name(&self, _id: U256) -> String {
+        self.name.get_string()
+    }
+
+    fn symbol(&self, _id: U256) -> String {
+        self.symbol.get_string()
+    }
+
+    fn decimals(&self, _id: U256) -> U8 {
+        self.decimals.get()
+    }
+}
+
+#[public]
+impl IOwnable for Erc6909FactoryTokenExample {
+    type Error = Error;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn transfer_ownership(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<(), Self::Error> {
+        Ok(self.ownable.transfer_ownership(new_owner)?)
+    }
+
+    fn renounce_ownership(&mut self) -> Result<(), Self::Error> {
+        Ok(self.ownable.renounce_ownership()?)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909FactoryTokenExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        self.erc6909.supports_interface(interface_id)
+            || self.ownable.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909FactoryTokenExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = Error::inner_types();
+        types.extend(Erc6909::events());
+        types.extend(Ownable::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<
+        Erc6909FactoryTokenExample,
+    >();
+}
@@ -0,0 +1,214 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err, deprecated)]
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, B256, U256, U8};
+use openzeppelin_stylus::{impl_sol_struct, utils::Pagination};
+use stylus_sdk::{
+    alloy_sol_types::{sol, SolValue},
+    call::Call,
+    contract, evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageBool, StorageMap, StorageVec},
+};
+
+sol! {
+    /// A page of the tokens deployed by the factory.
+    ///
+    /// * `tokens` - Addresses of the tokens, in deployment order.
+    /// * `nextCursor` - Cursor of the next page, or zero if there is none.
+    #[derive(Debug)]
+    struct DeployedTokensPage {
+        address[] tokens;
+        uint256 nextCursor;
+    }
+
+    /// Emitted when `token` is deployed by `creator`, and initialized with
+    /// `owner` as its owner.
+    #[derive(Debug)]
+    event Erc6909Deployed(
+        address indexed token,
+        address indexed creator,
+        address indexed owner,
+        bytes32 salt
+    );
+
+    /// Indicates that `implementation` can't be cloned, because it has no
+    /// code.
+    #[derive(Debug)]
+    error Erc6909FactoryInvalidImplementation(address implementation);
+
+    /// Indicates that the deployment of a clone failed, e.g. because `salt`
+    /// was already used by the same creator.
+    #[derive(Debug)]
+    error Erc6909FactoryFailedDeployment(bytes32 salt);
+
+    /// Indicates that the freshly deployed `token` couldn't be initialized.
+    #[derive(Debug)]
+    error Erc6909FactoryFailedInitialization(address token);
+}
+
+impl_sol_struct!(DeployedTokensPage, (Vec<Address>, U256));
+
+sol_interface! {
+    interface IErc6909FactoryToken {
+        function initialize(
+            address owner,
+            string name,
+            string symbol,
+            uint8 decimals
+        ) external;
+    }
+}
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    InvalidImplementation(Erc6909FactoryInvalidImplementation),
+    FailedDeployment(Erc6909FactoryFailedDeployment),
+    FailedInitialization(Erc6909FactoryFailedInitialization),
+}
+
+/// Factory deploying preconfigured ERC-6909 tokens at deterministic
+/// addresses.
+///
+/// Each token is an [EIP-1167] minimal proxy delegating to a single
+/// implementation contract (see the `erc6909-factory-token` example), deployed
+/// with `CREATE2` and initialized in the same transaction. The `CREATE2` salt
+/// is derived from the creator's address and a salt of its choice, so that
+/// nobody can front-run a deployment to a predicted address.
+///
+/// [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+#[entrypoint]
+#[storage]
+struct Erc6909FactoryExample {
+    implementation: StorageAddress,
+    deployed_tokens: StorageVec<StorageAddress>,
+    is_deployed: StorageMap<Address, StorageBool>,
+}
+
+#[public]
+impl Erc6909FactoryExample {
+    #[constructor]
+    fn constructor(&mut self, implementation: Address) -> Result<(), Error> {
+        if !implementation.has_code() {
+            return Err(Error::InvalidImplementation(
+                Erc6909FactoryInvalidImplementation { implementation },
+            ));
+        }
+        self.implementation.set(implementation);
+        Ok(())
+    }
+
+    fn implementation(&self) -> Address {
+        self.implementation.get()
+    }
+
+    fn create_token(
+        &mut self,
+        owner: Address,
+        name: String,
+        symbol: String,
+        decimals: U8,
+        salt: B256,
+    ) -> Result<Address, Error> {
+        let creator = msg::sender();
+        let code = self.clone_code();
+
+        // SAFETY: the init code of a minimal proxy doesn't call other
+        // contracts, so the deployment can't reenter this contract.
+        let token = unsafe {
+            self.vm().deploy(
+                &code,
+                U256::ZERO,
+                Some(Self::create2_salt(creator, salt)),
+            )
+        }
+        .map_err(|_| {
+            Error::FailedDeployment(Erc6909FactoryFailedDeployment { salt })
+        })?;
+
+        IErc6909FactoryToken::new(token)
+            .initialize(Call::new_in(self), owner, name, symbol, decimals.to())
+            .map_err(|_| {
+                Error::FailedInitialization(
+                    Erc6909FactoryFailedInitialization { token },
+                )
+            })?;
+
+        self.deployed_tokens.push(token);
+        self.is_deployed.setter(token).set(true);
+        evm::log(Erc6909Deployed { token, creator, owner, salt });
+
+        Ok(token)
+    }
+
+    fn predict_address(&self, creator: Address, salt: B256) -> Address {
+        contract::address().create2(
+            Self::create2_salt(creator, salt),
+            keccak256(self.clone_code()),
+        )
+    }
+
+    fn is_deployed(&self, token: Address) -> bool {
+        self.is_deployed.get(token)
+    }
+
+    fn deployed_count(&self) -> U256 {
+        U256::from(self.deployed_tokens.len())
+    }
+
+    fn deployed_tokens(&self, cursor: U256, limit: U256) -> DeployedTokensPage {
+        let page = Pagination::new(cursor, limit, self.deployed_tokens.len());
+        let tokens =
+            page.range().filter_map(|i| self.deployed_tokens.get(i)).collect();
+        DeployedTokensPage { tokens, nextCursor: page.next_cursor() }
+    }
+}
+
+impl Erc6909FactoryExample {
+    /// Returns the init code of an [EIP-1167] minimal proxy delegating to
+    /// the implementation.
+    ///
+    /// [EIP-1167]: https://eips.ethereum.org/EIPS/eip-1167
+    fn clone_code(&self) -> Vec<u8> {
+        let mut code = vec![
+            0x3d, 0x60, 0x2d, 0x80, 0x60, 0x0a, 0x3d, 0x39, 0x81, 0xf3, 0x36,
+            0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73,
+        ];
+        code.extend_from_slice(self.implementation.get().as_slice());
+        code.extend_from_slice(&[
+            0x5a, 0xf4, 0x3d, 0x82, 0x80, 0x3e, 0x90, 0x3d, 0x91, 0x60, 0x2b,
+            0x57, 0xfd, 0x5b, 0xf3,
+        ]);
+        code
+    }
+
+    /// Returns the `CREATE2` salt of a token deployed by `creator` with
+    /// `salt`.
+    fn create2_salt(creator: Address, salt: B256) -> B256 {
+        keccak256((creator, salt).abi_encode())
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909FactoryExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::event;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = Error::inner_types();
+        types.push(event::<Erc6909Deployed>(
+            "event Erc6909Deployed(address indexed token, address indexed \
+             creator, address indexed owner, bytes32 salt)",
+        ));
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909FactoryExample>();
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Factory {
+        #[derive(Debug, PartialEq)]
+        struct DeployedTokensPage {
+            address[] tokens;
+            uint256 nextCursor;
+        }
+
+        function implementation() external view returns (address implementation);
+        function createToken(address owner, string memory name, string memory symbol, uint8 decimals, bytes32 salt) external returns (address token);
+        function predictAddress(address creator, bytes32 salt) external view returns (address token);
+        function isDeployed(address token) external view returns (bool deployed);
+        function deployedCount() external view returns (uint256 count);
+        function deployedTokens(uint256 cursor, uint256 limit) external view returns (DeployedTokensPage memory page);
+
+        error Erc6909FactoryInvalidImplementation(address implementation);
+        error Erc6909FactoryFailedDeployment(bytes32 salt);
+        error Erc6909FactoryFailedInitialization(address token);
+
+        #[derive(Debug, PartialEq)]
+        event Erc6909Deployed(address indexed token, address indexed creator, address indexed owner, bytes32 salt);
+    }
+
+    #[sol(rpc)]
+    contract Erc6909FactoryToken {
+        function initialize(address owner, string memory name, string memory symbol, uint8 decimals) external;
+        function mint(address to, uint256 id, uint256 amount) external;
+        function owner() external view returns (address owner);
+        function name(uint256 id) external view returns (string memory name);
+        function symbol(uint256 id) external view returns (string memory symbol);
+        function decimals(uint256 id) external view returns (uint8 decimals);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool status);
+
+        error Erc6909FactoryTokenAlreadyInitialized();
+        error OwnableUnauthorizedAccount(address account);
+    }
+);
@@ -0,0 +1,233 @@
+#![cfg(feature = "e2e")]
+
+use abi::{Erc6909Factory, Erc6909FactoryToken};
+use alloy::primitives::{b256, uint, Address, B256, U256};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor, EventExt, Revert,
+};
+use eyre::Result;
+
+mod abi;
+
+const TOKEN_NAME: &str = "Launchpad Token";
+const TOKEN_SYMBOL: &str = "LPT";
+const TOKEN_DECIMALS: u8 = 18;
+const SALT: B256 =
+    b256!("0x0000000000000000000000000000000000000000000000000000000000000001");
+
+fn ctr(implementation: Address) -> Constructor {
+    constructor!(implementation)
+}
+
+async fn deploy(account: &Account) -> Result<(Address, Address)> {
+    let implementation = account
+        .as_deployer()
+        .with_constructor(constructor!())
+        .deploy_crate("erc6909-factory-token-example")
+        .await?
+        .contract_address;
+    let factory = account
+        .as_deployer()
+        .with_constructor(ctr(implementation))
+        .deploy()
+        .await?
+        .contract_address;
+    Ok((factory, implementation))
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Factory
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let (factory_addr, implementation) = deploy(&alice).await?;
+    let factory = Erc6909Factory::new(factory_addr, &alice.wallet);
+
+    let Erc6909Factory::implementationReturn { implementation: actual } =
+        factory.implementation().call().await?;
+    assert_eq!(actual, implementation);
+
+    let Erc6909Factory::deployedCountReturn { count } =
+        factory.deployedCount().call().await?;
+    assert_eq!(count, U256::ZERO);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn creates_token_at_predicted_address(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (factory_addr, _) = deploy(&alice).await?;
+    let factory = Erc6909Factory::new(factory_addr, &alice.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let Erc6909Factory::predictAddressReturn { token } =
+        factory.predictAddress(alice_addr, SALT).call().await?;
+
+    let receipt = receipt!(factory.createToken(
+        bob_addr,
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))?;
+
+    assert!(receipt.emits(Erc6909Factory::Erc6909Deployed {
+        token,
+        creator: alice_addr,
+        owner: bob_addr,
+        salt: SALT,
+    }));
+
+    let Erc6909Factory::isDeployedReturn { deployed } =
+        factory.isDeployed(token).call().await?;
+    assert!(deployed);
+
+    let Erc6909Factory::deployedTokensReturn { page } =
+        factory.deployedTokens(U256::ZERO, uint!(10_U256)).call().await?;
+    assert_eq!(page.tokens, vec![token]);
+    assert_eq!(page.nextCursor, U256::ZERO);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn created_token_is_initialized(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (factory_addr, _) = deploy(&alice).await?;
+    let factory = Erc6909Factory::new(factory_addr, &alice.wallet);
+    let bob_addr = bob.address();
+    let id = uint!(1_U256);
+    let amount = uint!(100_U256);
+
+    let Erc6909Factory::predictAddressReturn { token } =
+        factory.predictAddress(alice.address(), SALT).call().await?;
+    watch!(factory.createToken(
+        bob_addr,
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))?;
+
+    let token_alice = Erc6909FactoryToken::new(token, &alice.wallet);
+    let token_bob = Erc6909FactoryToken::new(token, &bob.wallet);
+
+    let Erc6909FactoryToken::ownerReturn { owner } =
+        token_alice.owner().call().await?;
+    assert_eq!(owner, bob_addr);
+
+    let Erc6909FactoryToken::nameReturn { name } =
+        token_alice.name(id).call().await?;
+    assert_eq!(name, TOKEN_NAME);
+
+    let Erc6909FactoryToken::symbolReturn { symbol } =
+        token_alice.symbol(id).call().await?;
+    assert_eq!(symbol, TOKEN_SYMBOL);
+
+    let Erc6909FactoryToken::decimalsReturn { decimals } =
+        token_alice.decimals(id).call().await?;
+    assert_eq!(decimals, TOKEN_DECIMALS);
+
+    watch!(token_bob.mint(bob_addr, id, amount))?;
+
+    let Erc6909FactoryToken::balanceOfReturn { balance } =
+        token_alice.balanceOf(bob_addr, id).call().await?;
+    assert_eq!(balance, amount);
+
+    let err = send!(token_alice.mint(alice.address(), id, amount))
+        .expect_err("should not mint when not owner");
+    assert!(err.reverted_with(
+        Erc6909FactoryToken::OwnableUnauthorizedAccount {
+            account: alice.address()
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn created_token_cannot_be_reinitialized(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (factory_addr, implementation) = deploy(&alice).await?;
+    let factory = Erc6909Factory::new(factory_addr, &alice.wallet);
+
+    let Erc6909Factory::predictAddressReturn { token } =
+        factory.predictAddress(alice.address(), SALT).call().await?;
+    watch!(factory.createToken(
+        alice.address(),
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))?;
+
+    for target in [token, implementation] {
+        let contract = Erc6909FactoryToken::new(target, &bob.wallet);
+        let err = send!(contract.initialize(
+            bob.address(),
+            TOKEN_NAME.to_string(),
+            TOKEN_SYMBOL.to_string(),
+            TOKEN_DECIMALS,
+        ))
+        .expect_err("should not initialize twice");
+        assert!(err.reverted_with(
+            Erc6909FactoryToken::Erc6909FactoryTokenAlreadyInitialized {}
+        ));
+    }
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn create_token_reverts_when_salt_is_reused(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (factory_addr, _) = deploy(&alice).await?;
+    let factory_alice = Erc6909Factory::new(factory_addr, &alice.wallet);
+    let factory_bob = Erc6909Factory::new(factory_addr, &bob.wallet);
+
+    watch!(factory_alice.createToken(
+        alice.address(),
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))?;
+
+    let err = send!(factory_alice.createToken(
+        alice.address(),
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))
+    .expect_err("should not deploy twice with the same salt");
+    assert!(err.reverted_with(
+        Erc6909Factory::Erc6909FactoryFailedDeployment { salt: SALT }
+    ));
+
+    // The same salt is still available to other creators.
+    watch!(factory_bob.createToken(
+        bob.address(),
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        SALT,
+    ))?;
+
+    let Erc6909Factory::deployedCountReturn { count } =
+        factory_alice.deployedCount().call().await?;
+    assert_eq!(count, uint!(2_U256));
+
+    Ok(())
+}
@@ -0,0 +1,386 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "metadata")]
+use alloy_primitives::U8;
+use alloy_primitives::{Address, FixedBytes, U256};
+#[cfg(feature = "uri")]
+use openzeppelin_stylus::token::erc6909::extensions::{
+    content_uri, Erc6909ContentUri, IErc6909ContentUri, IErc6909Reveal,
+    IErc7572,
+};
+#[cfg(feature = "metadata")]
+use openzeppelin_stylus::token::erc6909::extensions::{
+    metadata, Erc6909Metadata, IErc6909Metadata, IErc6909MetadataFreeze,
+};
+#[cfg(feature = "supply")]
+use openzeppelin_stylus::token::erc6909::extensions::{
+    supply::Error, Erc6909Supply, IErc6909Supply,
+};
+#[cfg(not(feature = "supply"))]
+use openzeppelin_stylus::token::erc6909::{Erc6909, Error};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::erc6909::{
+        extensions::{
+            Erc6909Capability, Erc6909TokenInfoSource, IErc6909Capabilities,
+            IErc6909TokenInfo, TokenInfo,
+        },
+        IErc6909,
+    },
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::prelude::*;
+
+#[cfg(not(feature = "supply"))]
+use crate::Disabled as SupplyRoute;
+// `#[implements]` can't be configured per feature, so it lists an alias of
+// each optional interface, importing `Disabled` instead when the extension
+// is left out of the build.
+#[cfg(feature = "supply")]
+use crate::IErc6909Supply as SupplyRoute;
+#[cfg(not(feature = "metadata"))]
+use crate::{
+    Disabled as MetadataAdminRoute, Disabled as MetadataFreezeRoute,
+    Disabled as MetadataRoute,
+};
+#[cfg(not(feature = "uri"))]
+use crate::{
+    Disabled as ContentUriAdminRoute, Disabled as ContentUriRoute,
+    Disabled as RevealRoute, Disabled as Erc7572Route,
+};
+#[cfg(feature = "uri")]
+use crate::{
+    IContentUriAdmin as ContentUriAdminRoute,
+    IErc6909ContentUri as ContentUriRoute, IErc6909Reveal as RevealRoute,
+    IErc7572 as Erc7572Route,
+};
+#[cfg(feature = "metadata")]
+use crate::{
+    IErc6909Metadata as MetadataRoute,
+    IErc6909MetadataFreeze as MetadataFreezeRoute,
+    IMetadataAdmin as MetadataAdminRoute,
+};
+
+/// Base token, tracking the total supply of each token id with the `supply`
+/// feature.
+#[cfg(feature = "supply")]
+type Token = Erc6909Supply;
+#[cfg(not(feature = "supply"))]
+type Token = Erc6909;
+
+#[cfg(feature = "uri")]
+type ContentUri = Erc6909ContentUri;
+#[cfg(not(feature = "uri"))]
+type ContentUri = Empty;
+
+#[cfg(feature = "metadata")]
+type Metadata = Erc6909Metadata;
+#[cfg(not(feature = "metadata"))]
+type Metadata = Empty;
+
+#[entrypoint]
+#[storage]
+struct Erc6909FullExample {
+    erc6909: Token,
+    content_uri: ContentUri,
+    metadata: Metadata,
+}
+
+/// Storage of the extensions left out of the build.
+#[storage]
+struct Empty;
+
+impl Erc6909Capability for Empty {
+    const CAPABILITIES: U256 = U256::ZERO;
+}
+
+impl Erc6909TokenInfoSource for Empty {
+    fn fill_token_info(&self, _id: U256, _info: &mut TokenInfo) {}
+}
+
+impl IErc165 for Empty {
+    fn supports_interface(&self, _interface_id: FixedBytes<4>) -> bool {
+        false
+    }
+}
+
+/// Interface without functions, routing the extensions left out of the build.
+trait Disabled {}
+
+#[public]
+impl Disabled for Erc6909FullExample {}
+
+/// Administration of the content URIs, not part of any standard.
+#[cfg(feature = "uri")]
+trait IContentUriAdmin {
+    fn set_contract_uri(&mut self, uri: String);
+
+    fn set_token_uri(
+        &mut self,
+        id: U256,
+        uri: String,
+    ) -> Result<(), content_uri::Error>;
+
+    fn set_placeholder_uri(&mut self, uri: String);
+
+    fn reveal(&mut self, ids: Vec<U256>);
+
+    fn clear_token_uri(&mut self, id: U256) -> Result<(), content_uri::Error>;
+}
+
+/// Administration of the metadata, not part of any standard.
+#[cfg(feature = "metadata")]
+trait IMetadataAdmin {
+    fn set_metadata(
+        &mut self,
+        id: U256,
+        name: String,
+        symbol: String,
+        decimals: U8,
+    ) -> Result<(), metadata::Error>;
+
+    fn freeze_metadata(&mut self, id: U256) -> Result<(), metadata::Error>;
+
+    fn clear_metadata(&mut self, id: U256) -> Result<(), metadata::Error>;
+}
+
+delegate_ierc6909!(Erc6909FullExample => erc6909, Error);
+
+#[cfg(feature = "supply")]
+#[public]
+impl IErc6909Supply for Erc6909FullExample {
+    fn total_supply(&self, id: U256) -> U256 {
+        self.erc6909.total_supply(id)
+    }
+}
+
+#[cfg(feature = "uri")]
+#[public]
+impl IErc6909ContentUri for Erc6909FullExample {
+    fn contract_uri(&self) -> String {
+        self.content_uri.contract_uri()
+    }
+
+    fn token_uri(&self, id: U256) -> String {
+        self.content_uri.token_uri(id)
+    }
+}
+
+#[cfg(feature = "uri")]
+#[public]
+impl IErc7572 for Erc6909FullExample {
+    #[selector(name = "contractURI")]
+    fn contract_metadata_uri(&self) -> String {
+        self.content_uri.contract_metadata_uri()
+    }
+}
+
+#[cfg(feature = "uri")]
+#[public]
+impl IErc6909Reveal for Erc6909FullExample {
+    fn placeholder_uri(&self) -> String {
+        self.content_uri.placeholder_uri()
+    }
+
+    fn is_revealed(&self, id: U256) -> bool {
+        self.content_uri.is_revealed(id)
+    }
+}
+
+#[cfg(feature = "uri")]
+#[public]
+impl IContentUriAdmin for Erc6909FullExample {
+    fn set_contract_uri(&mut self, uri: String) {
+        self.content_uri._set_contract_uri(uri);
+    }
+
+    fn set_token_uri(
+        &mut self,
+        id: U256,
+        uri: String,
+    ) -> Result<(), content_uri::Error> {
+        self.content_uri._set_token_uri(id, uri)
+    }
+
+    fn set_placeholder_uri(&mut self, uri: String) {
+        self.content_uri._set_placeholder_uri(uri);
+    }
+
+    fn reveal(&mut self, ids: Vec<U256>) {
+        self.content_uri._reveal(ids);
+    }
+
+    fn clear_token_uri(&mut self, id: U256) -> Result<(), content_uri::Error> {
+        self.content_uri._clear_token_uri(id)
+    }
+}
+
+#[cfg(feature = "metadata")]
+#[public]
+impl IErc6909Metadata for Erc6909FullExample {
+    fn name(&self, id: U256) -> String {
+        self.metadata.name(id)
+    }
+
+    fn symbol(&self, id: U256) -> String {
+        self.metadata.symbol(id)
+    }
+
+    fn decimals(&self, id: U256) -> U8 {
+        self.metadata.decimals(id)
+    }
+}
+
+#[cfg(feature = "metadata")]
+#[public]
+impl IErc6909MetadataFreeze for Erc6909FullExample {
+    fn metadata_frozen(&self, id: U256) -> bool {
+        self.metadata.metadata_frozen(id)
+    }
+}
+
+#[cfg(feature = "metadata")]
+#[public]
+impl IMetadataAdmin for Erc6909FullExample {
+    fn set_metadata(
+        &mut self,
+        id: U256,
+        name: String,
+        symbol: String,
+        decimals: U8,
+    ) -> Result<(), metadata::Error> {
+        self.metadata._set_name(id, name)?;
+        self.metadata._set_symbol(id, symbol)?;
+        self.metadata._set_decimals(id, decimals)
+    }
+
+    fn freeze_metadata(&mut self, id: U256) -> Result<(), metadata::Error> {
+        self.metadata._freeze_metadata(id)?;
+        #[cfg(feature = "uri")]
+        self.content_uri._freeze_metadata(id).map_err(|e| match e {
+            content_uri::Error::FrozenMetadata(e) => {
+                metadata::Error::FrozenMetadata(e)
+            }
+            content_uri::Error::NonexistentToken(e) => {
+                metadata::Error::NonexistentToken(e)
+            }
+        })?;
+        Ok(())
+    }
+
+    fn clear_metadata(&mut self, id: U256) -> Result<(), metadata::Error> {
+        self.metadata._clear_metadata(id)
+    }
+}
+
+#[public]
+impl IErc6909Capabilities for Erc6909FullExample {
+    fn capabilities(&self) -> U256 {
+        Token::CAPABILITIES | ContentUri::CAPABILITIES | Metadata::CAPABILITIES
+    }
+}
+
+#[public]
+impl IErc6909TokenInfo for Erc6909FullExample {
+    fn token_info(&self, id: U256) -> TokenInfo {
+        let mut info = TokenInfo::default();
+        self.metadata.fill_token_info(id, &mut info);
+        #[cfg(feature = "supply")]
+        self.erc6909.fill_token_info(id, &mut info);
+        self.content_uri.fill_token_info(id, &mut info);
+        info
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909FullExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Capabilities>::interface_id() == interface_id
+            || <Self as IErc6909TokenInfo>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+            || self.content_uri.supports_interface(interface_id)
+    }
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    SupplyRoute,
+    ContentUriRoute,
+    Erc7572Route,
+    RevealRoute,
+    ContentUriAdminRoute,
+    MetadataRoute,
+    MetadataFreezeRoute,
+    MetadataAdminRoute,
+    IErc6909Capabilities,
+    IErc6909TokenInfo,
+    IErc165
+)]
+impl Erc6909FullExample {
+    /// `contract_uri` is ignored without the `uri` feature, so that all the
+    /// builds of the example share a constructor.
+    #[constructor]
+    fn constructor(&mut self, contract_uri: String) {
+        #[cfg(feature = "uri")]
+        self.content_uri._set_contract_uri(contract_uri);
+        #[cfg(not(feature = "uri"))]
+        let _ = contract_uri;
+    }
+
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.erc6909._mint(to, id, amount)
+    }
+
+    fn mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.erc6909._mint_batch(to, ids, amounts)
+    }
+
+    fn burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.erc6909._burn(from, id, amount)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909FullExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        #[cfg(any(feature = "uri", feature = "metadata"))]
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        #[allow(unused_mut)]
+        let mut types = Error::inner_types();
+        #[cfg(feature = "metadata")]
+        types.extend(metadata::Error::inner_types());
+        #[cfg(feature = "uri")]
+        types.extend(Erc6909ContentUri::events());
+        #[cfg(feature = "metadata")]
+        types.extend(Erc6909Metadata::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909FullExample>();
+}
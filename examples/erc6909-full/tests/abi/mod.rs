@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Full {
+        #[derive(Debug, PartialEq)]
+        struct TokenInfo {
+            string name;
+            string symbol;
+            uint8 decimals;
+            uint256 totalSupply;
+            uint256 cap;
+            bool paused;
+            string uri;
+        }
+
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool status);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function mint(address to, uint256 id, uint256 amount) external;
+        function burn(address from, uint256 id, uint256 amount) external;
+        function totalSupply(uint256 id) external view returns (uint256);
+        function contractUri() external view returns (string memory uri);
+        function contractURI() external view returns (string memory uri);
+        function tokenUri(uint256 id) external view returns (string memory uri);
+        function name(uint256 id) external view returns (string memory name);
+        function symbol(uint256 id) external view returns (string memory symbol);
+        function decimals(uint256 id) external view returns (uint8 decimals);
+        function setContractUri(string memory uri) external;
+        function setTokenUri(uint256 id, string memory uri) external;
+        function placeholderUri() external view returns (string memory uri);
+        function isRevealed(uint256 id) external view returns (bool revealed);
+        function setPlaceholderUri(string memory uri) external;
+        function reveal(uint256[] memory ids) external;
+        function clearTokenUri(uint256 id) external;
+        function setMetadata(uint256 id, string memory name, string memory symbol, uint8 decimals) external;
+        function freezeMetadata(uint256 id) external;
+        function clearMetadata(uint256 id) external;
+        function metadataFrozen(uint256 id) external view returns (bool frozen);
+        function capabilities() external view returns (uint256);
+        function tokenInfo(uint256 id) external view returns (TokenInfo memory info);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error ERC6909FrozenMetadata(uint256 id);
+
+        #[derive(Debug, PartialEq)]
+        event URI(string value, uint256 indexed id);
+        #[derive(Debug, PartialEq)]
+        event ContractURIUpdated();
+        #[derive(Debug, PartialEq)]
+        event PlaceholderUriUpdated(string value);
+        #[derive(Debug, PartialEq)]
+        event Revealed(uint256[] ids);
+        #[derive(Debug, PartialEq)]
+        event MetadataFrozen(uint256 indexed id);
+        #[derive(Debug, PartialEq)]
+        event ERC6909MetadataCleared(uint256 indexed id);
+    }
+);
@@ -0,0 +1,486 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909Full;
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{fixed_bytes, Address, Bytes, FixedBytes, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol_types::{SolCall, SolValue},
+};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor, EventExt, Revert,
+};
+
+mod abi;
+
+const CONTRACT_URI: &str = "https://example.com/contract.json";
+const TOKEN_URI: &str = "https://example.com/1.json";
+const PLACEHOLDER_URI: &str = "https://example.com/hidden.json";
+
+/// Selectors of the metadata functions, as published in the ERC-6909 spec.
+const NAME_SELECTOR: FixedBytes<4> = fixed_bytes!("0x00ad800c");
+const SYMBOL_SELECTOR: FixedBytes<4> = fixed_bytes!("0x4e41a1fb");
+const DECIMALS_SELECTOR: FixedBytes<4> = fixed_bytes!("0x3f47e662");
+
+fn ctr(contract_uri: &str) -> Constructor {
+    constructor!(contract_uri.to_string())
+}
+
+/// Calls the function of `contract_addr` with `selector` for the token `id`,
+/// bypassing the generated bindings, and returns the raw return data.
+async fn call_selector(
+    account: &Account,
+    contract_addr: Address,
+    selector: FixedBytes<4>,
+    id: U256,
+) -> eyre::Result<Bytes> {
+    let input = [selector.as_slice(), &id.to_be_bytes::<32>()].concat();
+    let tx =
+        TransactionRequest::default().with_to(contract_addr).with_input(input);
+    Ok(account.wallet.call(&tx).await?)
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Content URI Extension
+// ============================================================================
+
+#[e2e::test]
+async fn contract_uri_returns_constructor_uri(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let uri = contract.contractUri().call().await?.uri;
+
+    assert_eq!(CONTRACT_URI, uri);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_contract_uri(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(""))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let receipt = receipt!(contract.setContractUri(CONTRACT_URI.to_owned()))?;
+
+    assert!(receipt.emits(Erc6909Full::ContractURIUpdated {}));
+
+    let uri = contract.contractUri().call().await?.uri;
+
+    assert_eq!(CONTRACT_URI, uri);
+
+    let uri = contract.contractURI().call().await?.uri;
+
+    assert_eq!(CONTRACT_URI, uri);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn token_uri_returns_empty_string_when_not_set(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let uri = contract.tokenUri(U256::ONE).call().await?.uri;
+
+    assert_eq!("", uri);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_token_uri(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+
+    let receipt =
+        receipt!(contract.setTokenUri(token_id, TOKEN_URI.to_owned()))?;
+
+    assert!(receipt
+        .emits(Erc6909Full::URI { value: TOKEN_URI.to_owned(), id: token_id }));
+
+    let uri = contract.tokenUri(token_id).call().await?.uri;
+    assert_eq!(TOKEN_URI, uri);
+
+    let uri = contract.tokenUri(token_id + U256::ONE).call().await?.uri;
+    assert_eq!("", uri);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn token_uri_returns_placeholder_until_revealed(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+    let other_id = U256::from(2);
+
+    watch!(contract.setTokenUri(token_id, TOKEN_URI.to_owned()))?;
+    watch!(contract.setTokenUri(other_id, TOKEN_URI.to_owned()))?;
+
+    let receipt =
+        receipt!(contract.setPlaceholderUri(PLACEHOLDER_URI.to_owned()))?;
+
+    assert!(receipt.emits(Erc6909Full::PlaceholderUriUpdated {
+        value: PLACEHOLDER_URI.to_owned()
+    }));
+    assert_eq!(PLACEHOLDER_URI, contract.placeholderUri().call().await?.uri);
+    assert_eq!(PLACEHOLDER_URI, contract.tokenUri(token_id).call().await?.uri);
+
+    let receipt = receipt!(contract.reveal(vec![token_id]))?;
+
+    assert!(receipt.emits(Erc6909Full::Revealed { ids: vec![token_id] }));
+    assert!(contract.isRevealed(token_id).call().await?.revealed);
+    assert!(!contract.isRevealed(other_id).call().await?.revealed);
+    assert_eq!(TOKEN_URI, contract.tokenUri(token_id).call().await?.uri);
+    assert_eq!(PLACEHOLDER_URI, contract.tokenUri(other_id).call().await?.uri);
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Metadata and Supply Extensions
+// ============================================================================
+
+#[e2e::test]
+async fn composes_metadata_and_supply(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+    let amount = U256::from(10);
+
+    watch!(contract.setMetadata(
+        token_id,
+        "Gold".to_owned(),
+        "GLD".to_owned(),
+        18
+    ))?;
+    watch!(contract.mint(alice.address(), token_id, amount))?;
+
+    assert_eq!("Gold", contract.name(token_id).call().await?.name);
+    assert_eq!("GLD", contract.symbol(token_id).call().await?.symbol);
+    assert_eq!(18, contract.decimals(token_id).call().await?.decimals);
+    assert_eq!(amount, contract.totalSupply(token_id).call().await?._0);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn metadata_selectors_match_spec(alice: Account) -> eyre::Result<()> {
+    assert_eq!(NAME_SELECTOR, Erc6909Full::nameCall::SELECTOR);
+    assert_eq!(SYMBOL_SELECTOR, Erc6909Full::symbolCall::SELECTOR);
+    assert_eq!(DECIMALS_SELECTOR, Erc6909Full::decimalsCall::SELECTOR);
+
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+
+    watch!(contract.setMetadata(
+        token_id,
+        "Gold".to_owned(),
+        "GLD".to_owned(),
+        18
+    ))?;
+
+    let name =
+        call_selector(&alice, contract_addr, NAME_SELECTOR, token_id).await?;
+    assert_eq!("Gold", String::abi_decode(&name, true)?);
+
+    let symbol =
+        call_selector(&alice, contract_addr, SYMBOL_SELECTOR, token_id).await?;
+    assert_eq!("GLD", String::abi_decode(&symbol, true)?);
+
+    let decimals =
+        call_selector(&alice, contract_addr, DECIMALS_SELECTOR, token_id)
+            .await?;
+    assert_eq!(18, u8::abi_decode(&decimals, true)?);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn freeze_metadata_locks_metadata_and_uri(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+
+    watch!(contract.setMetadata(
+        token_id,
+        "Gold".to_owned(),
+        "GLD".to_owned(),
+        18
+    ))?;
+    watch!(contract.setTokenUri(token_id, TOKEN_URI.to_owned()))?;
+    assert!(!contract.metadataFrozen(token_id).call().await?.frozen);
+
+    let receipt = receipt!(contract.freezeMetadata(token_id))?;
+
+    assert!(receipt.emits(Erc6909Full::MetadataFrozen { id: token_id }));
+    assert!(contract.metadataFrozen(token_id).call().await?.frozen);
+
+    let err = send!(contract.setMetadata(
+        token_id,
+        "Silver".to_owned(),
+        "SLV".to_owned(),
+        6
+    ))
+    .expect_err("should not set metadata of frozen token");
+    assert!(
+        err.reverted_with(Erc6909Full::ERC6909FrozenMetadata { id: token_id })
+    );
+
+    let err = send!(contract.setTokenUri(token_id, "".to_owned()))
+        .expect_err("should not set uri of frozen token");
+    assert!(
+        err.reverted_with(Erc6909Full::ERC6909FrozenMetadata { id: token_id })
+    );
+
+    let err = send!(contract.freezeMetadata(token_id))
+        .expect_err("should not freeze metadata twice");
+    assert!(
+        err.reverted_with(Erc6909Full::ERC6909FrozenMetadata { id: token_id })
+    );
+
+    assert_eq!("Gold", contract.name(token_id).call().await?.name);
+    assert_eq!(TOKEN_URI, contract.tokenUri(token_id).call().await?.uri);
+
+    watch!(contract.setTokenUri(token_id + U256::ONE, TOKEN_URI.to_owned()))?;
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn clear_metadata_and_uri_of_burned_token(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+    let amount = U256::from(10);
+
+    watch!(contract.setMetadata(
+        token_id,
+        "Gold".to_owned(),
+        "GLD".to_owned(),
+        18
+    ))?;
+    watch!(contract.setTokenUri(token_id, TOKEN_URI.to_owned()))?;
+    watch!(contract.mint(alice.address(), token_id, amount))?;
+    watch!(contract.burn(alice.address(), token_id, amount))?;
+
+    let receipt = receipt!(contract.clearMetadata(token_id))?;
+    assert!(receipt.emits(Erc6909Full::ERC6909MetadataCleared { id: token_id }));
+
+    let receipt = receipt!(contract.clearTokenUri(token_id))?;
+    assert!(
+        receipt.emits(Erc6909Full::URI { value: "".to_owned(), id: token_id })
+    );
+
+    assert_eq!("", contract.name(token_id).call().await?.name);
+    assert_eq!("", contract.symbol(token_id).call().await?.symbol);
+    assert_eq!(0, contract.decimals(token_id).call().await?.decimals);
+    assert_eq!("", contract.tokenUri(token_id).call().await?.uri);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn capabilities_reflect_extensions(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    // METADATA | CONTENT_URI | TOKEN_SUPPLY.
+    let expected = U256::from(0x08 | 0x10 | 0x20);
+    assert_eq!(expected, contract.capabilities().call().await?._0);
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-165 Support Interface
+// ============================================================================
+
+#[e2e::test]
+async fn supports_interface(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let invalid_interface_id: u32 = 0xffffffff;
+    let supports_interface = contract
+        .supportsInterface(invalid_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(!supports_interface);
+
+    let erc6909_interface_id: u32 = 0xbd85b039;
+    let supports_interface = contract
+        .supportsInterface(erc6909_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let content_uri_interface_id: u32 = 0xd697b90b;
+    let supports_interface = contract
+        .supportsInterface(content_uri_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let erc7572_interface_id: u32 = 0xe8a3d485;
+    let supports_interface = contract
+        .supportsInterface(erc7572_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let reveal_interface_id: u32 = 0xac336856;
+    let supports_interface =
+        contract.supportsInterface(reveal_interface_id.into()).call().await?._0;
+
+    assert!(supports_interface);
+
+    let metadata_freeze_interface_id: u32 = 0x504c9a5f;
+    let supports_interface = contract
+        .supportsInterface(metadata_freeze_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let capabilities_interface_id: u32 = 0x34a18fc3;
+    let supports_interface = contract
+        .supportsInterface(capabilities_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let erc165_interface_id: u32 = 0x01ffc9a7;
+    let supports_interface =
+        contract.supportsInterface(erc165_interface_id.into()).call().await?._0;
+
+    assert!(supports_interface);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn token_info_aggregates_extensions(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(CONTRACT_URI))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Full::new(contract_addr, &alice.wallet);
+
+    let token_id = U256::ONE;
+    let amount = U256::from(10);
+
+    watch!(contract.setMetadata(
+        token_id,
+        "Gold".to_owned(),
+        "GLD".to_owned(),
+        18
+    ))?;
+    watch!(contract.setTokenUri(token_id, TOKEN_URI.to_owned()))?;
+    watch!(contract.mint(alice.address(), token_id, amount))?;
+
+    let info = contract.tokenInfo(token_id).call().await?.info;
+
+    assert_eq!(
+        Erc6909Full::TokenInfo {
+            name: "Gold".to_owned(),
+            symbol: "GLD".to_owned(),
+            decimals: 18,
+            totalSupply: amount,
+            cap: U256::ZERO,
+            paused: false,
+            uri: TOKEN_URI.to_owned(),
+        },
+        info
+    );
+
+    Ok(())
+}
@@ -0,0 +1,477 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err, deprecated)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256, U64};
+use alloy_sol_types::SolValue;
+use openzeppelin_stylus::utils::{
+    introspection::erc165::IErc165,
+    timelock::{self, ITimelock, Timelock},
+};
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_sol_types::sol,
+    block,
+    call::{call, Call},
+    evm, msg,
+    prelude::*,
+    storage::{
+        StorageAddress, StorageBool, StorageMap, StorageU256, StorageU64,
+    },
+};
+
+sol! {
+    /// Emitted when `proposer` creates proposal `proposal_id`, calling
+    /// `target` with `data`, voted on from `vote_start` to `vote_end`.
+    #[derive(Debug)]
+    event ProposalCreated(
+        bytes32 indexed proposal_id,
+        address indexed proposer,
+        address target,
+        bytes data,
+        string description,
+        uint64 vote_start,
+        uint64 vote_end
+    );
+
+    /// Emitted when `voter` casts `weight` votes of type `support` on
+    /// proposal `proposal_id`.
+    #[derive(Debug)]
+    event VoteCast(
+        address indexed voter,
+        bytes32 indexed proposal_id,
+        uint8 support,
+        uint256 weight
+    );
+
+    /// Emitted when proposal `proposal_id` is executed.
+    #[derive(Debug)]
+    event ProposalExecuted(bytes32 indexed proposal_id);
+}
+
+sol! {
+    /// Indicates that proposal `proposal_id` doesn't exist.
+    #[derive(Debug)]
+    error GovernorNonexistentProposal(bytes32 proposal_id);
+
+    /// Indicates that proposal `proposal_id` is in `current_state`, which
+    /// doesn't allow the operation.
+    #[derive(Debug)]
+    error GovernorUnexpectedProposalState(
+        bytes32 proposal_id,
+        uint8 current_state
+    );
+
+    /// Indicates that `voter` already voted on the proposal.
+    #[derive(Debug)]
+    error GovernorAlreadyCastVote(address voter);
+
+    /// Indicates that the vote type is neither against, for nor abstain.
+    #[derive(Debug)]
+    error GovernorInvalidVoteType();
+
+    /// Indicates that the votes couldn't be looked up on `token`.
+    #[derive(Debug)]
+    error GovernorVotesLookupFailed(address token);
+
+    /// Indicates that the call of an executed proposal to `target` failed.
+    #[derive(Debug)]
+    error GovernorFailedCall(address target);
+}
+
+sol_interface! {
+    interface IErc6909Votes {
+        function getPastVotes(address account, uint256 timepoint) external view returns (uint256);
+    }
+}
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    NonexistentProposal(GovernorNonexistentProposal),
+    UnexpectedProposalState(GovernorUnexpectedProposalState),
+    AlreadyCastVote(GovernorAlreadyCastVote),
+    InvalidVoteType(GovernorInvalidVoteType),
+    VotesLookupFailed(GovernorVotesLookupFailed),
+    FailedCall(GovernorFailedCall),
+    AlreadyQueued(timelock::TimelockAlreadyQueued),
+    NotQueued(timelock::TimelockNotQueued),
+    NotReady(timelock::TimelockNotReady),
+}
+
+impl From<timelock::Error> for Error {
+    fn from(value: timelock::Error) -> Self {
+        match value {
+            timelock::Error::AlreadyQueued(e) => Error::AlreadyQueued(e),
+            timelock::Error::NotQueued(e) => Error::NotQueued(e),
+            timelock::Error::NotReady(e) => Error::NotReady(e),
+        }
+    }
+}
+
+/// State of a proposal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum ProposalState {
+    /// Voting didn't start yet.
+    Pending,
+    /// Voting is open.
+    Active,
+    /// Voting ended without reaching the quorum or a majority.
+    Defeated,
+    /// Voting ended with the quorum and a majority, and the proposal can be
+    /// queued.
+    Succeeded,
+    /// The proposal is queued in the timelock.
+    Queued,
+    /// The proposal was executed.
+    Executed,
+}
+
+/// Type of a vote.
+const AGAINST: u8 = 0;
+const FOR: u8 = 1;
+const ABSTAIN: u8 = 2;
+
+/// State of a proposal.
+#[storage]
+struct Proposal {
+    proposer: StorageAddress,
+    vote_start: StorageU64,
+    vote_end: StorageU64,
+    against_votes: StorageU256,
+    for_votes: StorageU256,
+    abstain_votes: StorageU256,
+    executed: StorageBool,
+}
+
+/// Minimal governor voting with the aggregated votes of an ERC-6909 token
+/// using the votes extension, i.e. the votes of every voting id of the token
+/// weighted by their voting weight.
+///
+/// A proposal is a single call of `target` with `data`. Its votes are
+/// weighted by the voters' past votes at the proposal's snapshot, the
+/// `vote_start` timestamp, so tokens moved during the vote can't be counted
+/// twice. A proposal succeeds if its for votes exceed its against votes and
+/// its for and abstain votes reach the quorum. It is then queued in a
+/// [`Timelock`] and can be executed by anyone once the delay has passed.
+#[entrypoint]
+#[storage]
+struct Erc6909GovernorExample {
+    token: StorageAddress,
+    voting_delay: StorageU64,
+    voting_period: StorageU64,
+    quorum: StorageU256,
+    proposals: StorageMap<B256, Proposal>,
+    has_voted: StorageMap<B256, StorageMap<Address, StorageBool>>,
+    timelock: Timelock,
+}
+
+#[public]
+#[implements(ITimelock, IErc165)]
+impl Erc6909GovernorExample {
+    #[constructor]
+    fn constructor(
+        &mut self,
+        token: Address,
+        voting_delay: u64,
+        voting_period: u64,
+        quorum: U256,
+        timelock_delay: u64,
+    ) {
+        self.token.set(token);
+        self.voting_delay.set(U64::from(voting_delay));
+        self.voting_period.set(U64::from(voting_period));
+        self.quorum.set(quorum);
+        self.timelock._set_min_delay(U64::from(timelock_delay));
+    }
+
+    /// Returns the id of the proposal calling `target` with `data`, and
+    /// described by the hash `description_hash`.
+    fn hash_proposal(
+        &self,
+        target: Address,
+        data: Bytes,
+        description_hash: B256,
+    ) -> B256 {
+        keccak256((target, keccak256(&data), description_hash).abi_encode())
+    }
+
+    /// Creates a proposal calling `target` with `data`, and returns its id.
+    fn propose(
+        &mut self,
+        target: Address,
+        data: Bytes,
+        description: String,
+    ) -> Result<B256, Error> {
+        let proposal_id = self.hash_proposal(
+            target,
+            data.clone(),
+            keccak256(description.as_bytes()),
+        );
+
+        if !self.proposals.get(proposal_id).vote_start.get().is_zero() {
+            let current_state = self.state(proposal_id)?;
+            return Err(Error::UnexpectedProposalState(
+                GovernorUnexpectedProposalState { proposal_id, current_state },
+            ));
+        }
+
+        let vote_start =
+            block::timestamp() + self.voting_delay.get().to::<u64>();
+        let vote_end = vote_start + self.voting_period.get().to::<u64>();
+
+        let proposer = msg::sender();
+        let mut proposal = self.proposals.setter(proposal_id);
+        proposal.proposer.set(proposer);
+        proposal.vote_start.set(U64::from(vote_start));
+        proposal.vote_end.set(U64::from(vote_end));
+
+        evm::log(ProposalCreated {
+            proposal_id,
+            proposer,
+            target,
+            data: data.to_vec().into(),
+            description,
+            vote_start,
+            vote_end,
+        });
+
+        Ok(proposal_id)
+    }
+
+    /// Casts the caller's vote of type `support` (0 against, 1 for,
+    /// 2 abstain) on proposal `proposal_id`, and returns its weight.
+    fn cast_vote(
+        &mut self,
+        proposal_id: B256,
+        support: u8,
+    ) -> Result<U256, Error> {
+        self._require_state(proposal_id, ProposalState::Active)?;
+
+        let voter = msg::sender();
+        if self.has_voted.get(proposal_id).get(voter) {
+            return Err(Error::AlreadyCastVote(GovernorAlreadyCastVote {
+                voter,
+            }));
+        }
+
+        let token = self.token.get();
+        let snapshot =
+            U256::from(self.proposals.get(proposal_id).vote_start.get());
+        let weight = IErc6909Votes::new(token)
+            .get_past_votes(&*self, voter, snapshot)
+            .map_err(|_| {
+                Error::VotesLookupFailed(GovernorVotesLookupFailed { token })
+            })?;
+
+        let mut proposal = self.proposals.setter(proposal_id);
+        let votes = match support {
+            AGAINST => &mut proposal.against_votes,
+            FOR => &mut proposal.for_votes,
+            ABSTAIN => &mut proposal.abstain_votes,
+            _ => {
+                return Err(Error::InvalidVoteType(GovernorInvalidVoteType {}))
+            }
+        };
+        let total = votes.get() + weight;
+        votes.set(total);
+        self.has_voted.setter(proposal_id).setter(voter).set(true);
+
+        evm::log(VoteCast { voter, proposal_id, support, weight });
+
+        Ok(weight)
+    }
+
+    /// Queues the succeeded proposal calling `target` with `data`, and
+    /// returns the timestamp from which it can be executed.
+    fn queue(
+        &mut self,
+        target: Address,
+        data: Bytes,
+        description_hash: B256,
+    ) -> Result<u64, Error> {
+        let proposal_id = self.hash_proposal(target, data, description_hash);
+        self._require_state(proposal_id, ProposalState::Succeeded)?;
+
+        Ok(self.timelock.queue(proposal_id)?.to())
+    }
+
+    /// Executes the queued proposal calling `target` with `data`, once its
+    /// timelock delay has passed.
+    fn execute(
+        &mut self,
+        target: Address,
+        data: Bytes,
+        description_hash: B256,
+    ) -> Result<(), Error> {
+        let proposal_id =
+            self.hash_proposal(target, data.clone(), description_hash);
+        self._require_state(proposal_id, ProposalState::Queued)?;
+
+        self.timelock.execute(proposal_id)?;
+        self.proposals.setter(proposal_id).executed.set(true);
+
+        call(Call::new_in(self), target, &data)
+            .map_err(|_| Error::FailedCall(GovernorFailedCall { target }))?;
+
+        evm::log(ProposalExecuted { proposal_id });
+
+        Ok(())
+    }
+
+    /// Returns the state of proposal `proposal_id` (0 pending, 1 active,
+    /// 2 defeated, 3 succeeded, 4 queued, 5 executed).
+    fn state(&self, proposal_id: B256) -> Result<u8, Error> {
+        Ok(self._state(proposal_id)? as u8)
+    }
+
+    /// Returns the against, for and abstain votes of proposal `proposal_id`.
+    fn proposal_votes(&self, proposal_id: B256) -> (U256, U256, U256) {
+        let proposal = self.proposals.get(proposal_id);
+        (
+            proposal.against_votes.get(),
+            proposal.for_votes.get(),
+            proposal.abstain_votes.get(),
+        )
+    }
+
+    /// Returns the snapshot of proposal `proposal_id`, the timestamp at
+    /// which its votes are looked up.
+    fn proposal_snapshot(&self, proposal_id: B256) -> u64 {
+        self.proposals.get(proposal_id).vote_start.get().to()
+    }
+
+    /// Returns the timestamp at which voting on proposal `proposal_id`
+    /// ends.
+    fn proposal_deadline(&self, proposal_id: B256) -> u64 {
+        self.proposals.get(proposal_id).vote_end.get().to()
+    }
+
+    /// Returns whether `account` voted on proposal `proposal_id`.
+    fn has_voted(&self, proposal_id: B256, account: Address) -> bool {
+        self.has_voted.get(proposal_id).get(account)
+    }
+}
+
+impl Erc6909GovernorExample {
+    fn _state(&self, proposal_id: B256) -> Result<ProposalState, Error> {
+        let proposal = self.proposals.get(proposal_id);
+        let vote_start = proposal.vote_start.get();
+        if vote_start.is_zero() {
+            return Err(Error::NonexistentProposal(
+                GovernorNonexistentProposal { proposal_id },
+            ));
+        }
+
+        if proposal.executed.get() {
+            return Ok(ProposalState::Executed);
+        }
+        if self.timelock.is_operation_pending(proposal_id) {
+            return Ok(ProposalState::Queued);
+        }
+
+        let now = U64::from(block::timestamp());
+        if now <= vote_start {
+            return Ok(ProposalState::Pending);
+        }
+        if now <= proposal.vote_end.get() {
+            return Ok(ProposalState::Active);
+        }
+
+        let for_votes = proposal.for_votes.get();
+        let quorum_reached =
+            for_votes + proposal.abstain_votes.get() >= self.quorum.get();
+        if quorum_reached && for_votes > proposal.against_votes.get() {
+            Ok(ProposalState::Succeeded)
+        } else {
+            Ok(ProposalState::Defeated)
+        }
+    }
+
+    fn _require_state(
+        &self,
+        proposal_id: B256,
+        expected: ProposalState,
+    ) -> Result<(), Error> {
+        let current_state = self._state(proposal_id)?;
+        if current_state != expected {
+            return Err(Error::UnexpectedProposalState(
+                GovernorUnexpectedProposalState {
+                    proposal_id,
+                    current_state: current_state as u8,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[public]
+impl ITimelock for Erc6909GovernorExample {
+    fn min_delay(&self) -> U256 {
+        self.timelock.min_delay()
+    }
+
+    fn ready_at(&self, id: B256) -> U256 {
+        self.timelock.ready_at(id)
+    }
+
+    fn is_operation_pending(&self, id: B256) -> bool {
+        self.timelock.is_operation_pending(id)
+    }
+
+    fn is_operation_ready(&self, id: B256) -> bool {
+        self.timelock.is_operation_ready(id)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909GovernorExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc165>::interface_id() == interface_id
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909GovernorExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::event;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = Error::inner_types();
+        types.extend([
+            event::<ProposalCreated>(
+                "event ProposalCreated(bytes32 indexed proposal_id, address \
+                 indexed proposer, address target, bytes data, string \
+                 description, uint64 vote_start, uint64 vote_end)",
+            ),
+            event::<VoteCast>(
+                "event VoteCast(address indexed voter, bytes32 indexed \
+                 proposal_id, uint8 support, uint256 weight)",
+            ),
+            event::<ProposalExecuted>(
+                "event ProposalExecuted(bytes32 indexed proposal_id)",
+            ),
+            event::<timelock::OperationQueued>(
+                "event OperationQueued(bytes32 indexed id, uint64 ready_at)",
+            ),
+            event::<timelock::OperationExecuted>(
+                "event OperationExecuted(bytes32 indexed id)",
+            ),
+            event::<timelock::MinDelayChanged>(
+                "event MinDelayChanged(uint64 old_delay, uint64 new_delay)",
+            ),
+        ]);
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909GovernorExample>(
+    );
+}
@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Governor {
+        function hashProposal(address target, bytes calldata data, bytes32 description_hash) external view returns (bytes32 proposalId);
+        function propose(address target, bytes calldata data, string calldata description) external returns (bytes32 proposalId);
+        function castVote(bytes32 proposal_id, uint8 support) external returns (uint256 weight);
+        function queue(address target, bytes calldata data, bytes32 description_hash) external returns (uint64 readyAt);
+        function execute(address target, bytes calldata data, bytes32 description_hash) external;
+        function state(bytes32 proposal_id) external view returns (uint8 state);
+        function proposalVotes(bytes32 proposal_id) external view returns (uint256 againstVotes, uint256 forVotes, uint256 abstainVotes);
+        function proposalSnapshot(bytes32 proposal_id) external view returns (uint64 snapshot);
+        function proposalDeadline(bytes32 proposal_id) external view returns (uint64 deadline);
+        function hasVoted(bytes32 proposal_id, address account) external view returns (bool voted);
+        function readyAt(bytes32 id) external view returns (uint256 readyAt);
+
+        error GovernorNonexistentProposal(bytes32 proposal_id);
+        error GovernorUnexpectedProposalState(bytes32 proposal_id, uint8 current_state);
+        error GovernorAlreadyCastVote(address voter);
+        error GovernorInvalidVoteType();
+        error GovernorVotesLookupFailed(address token);
+        error GovernorFailedCall(address target);
+        error TimelockAlreadyQueued(bytes32 id);
+        error TimelockNotQueued(bytes32 id);
+        error TimelockNotReady(bytes32 id, uint64 ready_at);
+
+        #[derive(Debug, PartialEq)]
+        event ProposalCreated(bytes32 indexed proposal_id, address indexed proposer, address target, bytes data, string description, uint64 vote_start, uint64 vote_end);
+        #[derive(Debug, PartialEq)]
+        event VoteCast(address indexed voter, bytes32 indexed proposal_id, uint8 support, uint256 weight);
+        #[derive(Debug, PartialEq)]
+        event ProposalExecuted(bytes32 indexed proposal_id);
+        #[derive(Debug, PartialEq)]
+        event OperationQueued(bytes32 indexed id, uint64 ready_at);
+    }
+
+    #[sol(rpc)]
+    contract Erc6909Votes {
+        function mint(address to, uint256 id, uint256 amount) external;
+        function addVotingId(uint256 id, uint32 weight) external;
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool status);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function delegate(address delegatee) external;
+    }
+);
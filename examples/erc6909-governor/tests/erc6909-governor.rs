@@ -0,0 +1,410 @@
+#![cfg(feature = "e2e")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use abi::{Erc6909Governor, Erc6909Votes};
+use alloy::{
+    primitives::{keccak256, uint, Address, Bytes, B256, U256},
+    sol_types::SolCall,
+};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor, EventExt, Revert,
+};
+use eyre::Result;
+
+mod abi;
+
+const ID: U256 = uint!(1_U256);
+const ALICE_VOTES: U256 = uint!(100_U256);
+const BOB_VOTES: U256 = uint!(60_U256);
+const QUORUM: U256 = uint!(100_U256);
+const TREASURY: U256 = uint!(30_U256);
+
+const SHORT_DELAY: u64 = 1;
+const LONG_DELAY: u64 = 3600;
+const VOTING_PERIOD: u64 = 3;
+
+const PENDING: u8 = 0;
+const DEFEATED: u8 = 2;
+const QUEUED: u8 = 4;
+const EXECUTED: u8 = 5;
+
+const AGAINST: u8 = 0;
+const FOR: u8 = 1;
+
+const DESCRIPTION: &str = "Send the treasury to charlie";
+
+fn ctr(token: Address, voting_delay: u64, timelock_delay: u64) -> Constructor {
+    constructor!(token, voting_delay, VOTING_PERIOD, QUORUM, timelock_delay)
+}
+
+/// Deploys the votes token, registers [`ID`] as its only voting id, with a
+/// weight of one, and gives `alice` and `bob` self-delegated votes.
+async fn deploy_token(alice: &Account, bob: &Account) -> Result<Address> {
+    let token_addr = alice
+        .as_deployer()
+        .deploy_crate("erc6909-votes-example")
+        .await?
+        .contract_address;
+
+    let token = Erc6909Votes::new(token_addr, &alice.wallet);
+    watch!(token.addVotingId(ID, 1))?;
+    watch!(token.mint(alice.address(), ID, ALICE_VOTES))?;
+    watch!(token.mint(bob.address(), ID, BOB_VOTES))?;
+    watch!(token.delegate(alice.address()))?;
+
+    let token = Erc6909Votes::new(token_addr, &bob.wallet);
+    watch!(token.delegate(bob.address()))?;
+
+    Ok(token_addr)
+}
+
+/// Deploys the token and the governor, and funds the governor's treasury.
+async fn setup(
+    alice: &Account,
+    bob: &Account,
+    voting_delay: u64,
+    timelock_delay: u64,
+) -> Result<(Address, Address)> {
+    let token_addr = deploy_token(alice, bob).await?;
+    let governor_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(token_addr, voting_delay, timelock_delay))
+        .deploy()
+        .await?
+        .contract_address;
+
+    let token = Erc6909Votes::new(token_addr, &alice.wallet);
+    watch!(token.mint(governor_addr, ID, TREASURY))?;
+
+    Ok((governor_addr, token_addr))
+}
+
+/// Returns the calldata of the proposal, sending the treasury to `receiver`.
+fn transfer_calldata(receiver: Address) -> Bytes {
+    Erc6909Votes::transferCall { receiver, id: ID, amount: TREASURY }
+        .abi_encode()
+        .into()
+}
+
+fn description_hash() -> B256 {
+    keccak256(DESCRIPTION)
+}
+
+/// Waits until the wall clock is past `timestamp`, so that the next block
+/// is produced at a later timestamp.
+async fn wait_until_after(timestamp: u64) {
+    while SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_secs()
+        <= timestamp
+    {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+}
+
+// ============================================================================
+// Integration Tests: Governor voting with ERC-6909 votes
+// ============================================================================
+
+#[e2e::test]
+async fn proposal_lifecycle_executes_call(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (governor_addr, token_addr) =
+        setup(&alice, &bob, SHORT_DELAY, SHORT_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+    let governor_bob = Erc6909Governor::new(governor_addr, &bob.wallet);
+    let token = Erc6909Votes::new(token_addr, &alice.wallet);
+
+    let data = transfer_calldata(charlie.address());
+    let proposal_id = governor
+        .hashProposal(token_addr, data.clone(), description_hash())
+        .call()
+        .await?
+        .proposalId;
+
+    let receipt = receipt!(governor.propose(
+        token_addr,
+        data.clone(),
+        DESCRIPTION.to_string()
+    ))?;
+
+    let snapshot =
+        governor.proposalSnapshot(proposal_id).call().await?.snapshot;
+    let deadline =
+        governor.proposalDeadline(proposal_id).call().await?.deadline;
+    assert_eq!(snapshot + VOTING_PERIOD, deadline);
+    assert!(receipt.emits(Erc6909Governor::ProposalCreated {
+        proposal_id,
+        proposer: alice.address(),
+        target: token_addr,
+        data: data.clone(),
+        description: DESCRIPTION.to_string(),
+        vote_start: snapshot,
+        vote_end: deadline,
+    }));
+
+    wait_until_after(snapshot).await;
+
+    let receipt = receipt!(governor.castVote(proposal_id, FOR))?;
+    assert!(receipt.emits(Erc6909Governor::VoteCast {
+        voter: alice.address(),
+        proposal_id,
+        support: FOR,
+        weight: ALICE_VOTES,
+    }));
+
+    let receipt = receipt!(governor_bob.castVote(proposal_id, AGAINST))?;
+    assert!(receipt.emits(Erc6909Governor::VoteCast {
+        voter: bob.address(),
+        proposal_id,
+        support: AGAINST,
+        weight: BOB_VOTES,
+    }));
+
+    let Erc6909Governor::proposalVotesReturn {
+        againstVotes,
+        forVotes,
+        abstainVotes,
+    } = governor.proposalVotes(proposal_id).call().await?;
+    assert_eq!(BOB_VOTES, againstVotes);
+    assert_eq!(ALICE_VOTES, forVotes);
+    assert_eq!(U256::ZERO, abstainVotes);
+
+    wait_until_after(deadline).await;
+
+    watch!(governor.queue(token_addr, data.clone(), description_hash()))?;
+    assert_eq!(QUEUED, governor.state(proposal_id).call().await?.state);
+
+    let ready_at = governor.readyAt(proposal_id).call().await?.readyAt;
+    wait_until_after(ready_at.to::<u64>()).await;
+
+    let receipt =
+        receipt!(governor.execute(token_addr, data, description_hash()))?;
+    assert!(receipt.emits(Erc6909Governor::ProposalExecuted { proposal_id }));
+
+    assert_eq!(EXECUTED, governor.state(proposal_id).call().await?.state);
+    assert_eq!(
+        TREASURY,
+        token.balanceOf(charlie.address(), ID).call().await?.balance
+    );
+    assert_eq!(
+        U256::ZERO,
+        token.balanceOf(governor_addr, ID).call().await?.balance
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn votes_are_weighted_at_snapshot(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (governor_addr, token_addr) =
+        setup(&alice, &bob, SHORT_DELAY, SHORT_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+    let governor_bob = Erc6909Governor::new(governor_addr, &bob.wallet);
+    let token = Erc6909Votes::new(token_addr, &alice.wallet);
+
+    let data = transfer_calldata(charlie.address());
+    watch!(governor.propose(
+        token_addr,
+        data.clone(),
+        DESCRIPTION.to_string()
+    ))?;
+    let proposal_id = governor
+        .hashProposal(token_addr, data, description_hash())
+        .call()
+        .await?
+        .proposalId;
+
+    let snapshot =
+        governor.proposalSnapshot(proposal_id).call().await?.snapshot;
+    wait_until_after(snapshot).await;
+
+    // Alice votes, then moves her tokens to bob, who can't vote with them.
+    watch!(governor.castVote(proposal_id, FOR))?;
+    watch!(token.transfer(bob.address(), ID, ALICE_VOTES))?;
+
+    let receipt = receipt!(governor_bob.castVote(proposal_id, AGAINST))?;
+    assert!(receipt.emits(Erc6909Governor::VoteCast {
+        voter: bob.address(),
+        proposal_id,
+        support: AGAINST,
+        weight: BOB_VOTES,
+    }));
+
+    let err = send!(governor.castVote(proposal_id, FOR))
+        .expect_err("should not vote twice");
+    assert!(err.reverted_with(Erc6909Governor::GovernorAlreadyCastVote {
+        voter: alice.address(),
+    }));
+
+    let err = send!(Erc6909Governor::new(governor_addr, &charlie.wallet)
+        .castVote(proposal_id, 3))
+    .expect_err("should not cast an invalid vote type");
+    assert!(err.reverted_with(Erc6909Governor::GovernorInvalidVoteType {}));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cast_vote_reverts_when_pending(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (governor_addr, token_addr) =
+        setup(&alice, &bob, LONG_DELAY, SHORT_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+
+    let data = transfer_calldata(bob.address());
+    watch!(governor.propose(
+        token_addr,
+        data.clone(),
+        DESCRIPTION.to_string()
+    ))?;
+    let proposal_id = governor
+        .hashProposal(token_addr, data.clone(), description_hash())
+        .call()
+        .await?
+        .proposalId;
+
+    assert_eq!(PENDING, governor.state(proposal_id).call().await?.state);
+
+    let err = send!(governor.castVote(proposal_id, FOR))
+        .expect_err("should not vote before the snapshot");
+    assert!(err.reverted_with(
+        Erc6909Governor::GovernorUnexpectedProposalState {
+            proposal_id,
+            current_state: PENDING,
+        }
+    ));
+
+    let err =
+        send!(governor.propose(token_addr, data, DESCRIPTION.to_string()))
+            .expect_err("should not propose twice");
+    assert!(err.reverted_with(
+        Erc6909Governor::GovernorUnexpectedProposalState {
+            proposal_id,
+            current_state: PENDING,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cast_vote_reverts_when_nonexistent(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (governor_addr, _) =
+        setup(&alice, &bob, SHORT_DELAY, SHORT_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+
+    let proposal_id = B256::repeat_byte(1);
+    let err = send!(governor.castVote(proposal_id, FOR))
+        .expect_err("should not vote on a nonexistent proposal");
+    assert!(err.reverted_with(Erc6909Governor::GovernorNonexistentProposal {
+        proposal_id,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn queue_reverts_when_defeated(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (governor_addr, token_addr) =
+        setup(&alice, &bob, SHORT_DELAY, SHORT_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+    let governor_bob = Erc6909Governor::new(governor_addr, &bob.wallet);
+
+    let data = transfer_calldata(charlie.address());
+    watch!(governor.propose(
+        token_addr,
+        data.clone(),
+        DESCRIPTION.to_string()
+    ))?;
+    let proposal_id = governor
+        .hashProposal(token_addr, data.clone(), description_hash())
+        .call()
+        .await?
+        .proposalId;
+
+    let snapshot =
+        governor.proposalSnapshot(proposal_id).call().await?.snapshot;
+    wait_until_after(snapshot).await;
+
+    // Bob's votes alone don't reach the quorum.
+    watch!(governor_bob.castVote(proposal_id, FOR))?;
+
+    let deadline =
+        governor.proposalDeadline(proposal_id).call().await?.deadline;
+    wait_until_after(deadline).await;
+
+    let err = send!(governor.queue(token_addr, data, description_hash()))
+        .expect_err("should not queue a defeated proposal");
+    assert!(err.reverted_with(
+        Erc6909Governor::GovernorUnexpectedProposalState {
+            proposal_id,
+            current_state: DEFEATED,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn execute_reverts_before_timelock_delay(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (governor_addr, token_addr) =
+        setup(&alice, &bob, SHORT_DELAY, LONG_DELAY).await?;
+    let governor = Erc6909Governor::new(governor_addr, &alice.wallet);
+
+    let data = transfer_calldata(charlie.address());
+    watch!(governor.propose(
+        token_addr,
+        data.clone(),
+        DESCRIPTION.to_string()
+    ))?;
+    let proposal_id = governor
+        .hashProposal(token_addr, data.clone(), description_hash())
+        .call()
+        .await?
+        .proposalId;
+
+    let snapshot =
+        governor.proposalSnapshot(proposal_id).call().await?.snapshot;
+    wait_until_after(snapshot).await;
+
+    watch!(governor.castVote(proposal_id, FOR))?;
+
+    let deadline =
+        governor.proposalDeadline(proposal_id).call().await?.deadline;
+    wait_until_after(deadline).await;
+
+    watch!(governor.queue(token_addr, data.clone(), description_hash()))?;
+    let ready_at = governor.readyAt(proposal_id).call().await?.readyAt;
+
+    let err = send!(governor.execute(token_addr, data, description_hash()))
+        .expect_err("should not execute before the timelock delay");
+    assert!(err.reverted_with(Erc6909Governor::TimelockNotReady {
+        id: proposal_id,
+        ready_at: ready_at.to::<u64>(),
+    }));
+
+    Ok(())
+}
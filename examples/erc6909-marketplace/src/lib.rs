@@ -0,0 +1,131 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloy_primitives::{aliases::U96, Address, FixedBytes, U256};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::{
+        common::erc2981::{self, IErc2981},
+        erc6909::{
+            extensions::{
+                marketplace, Erc6909Marketplace, IErc6909Marketplace,
+            },
+            IErc6909,
+        },
+    },
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::prelude::*;
+
+const FEE_DENOMINATOR: u64 = 10_000;
+
+#[entrypoint]
+#[storage]
+struct Erc6909MarketplaceExample {
+    marketplace: Erc6909Marketplace,
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = marketplace::Error>,
+    IErc6909Marketplace<Error = marketplace::Error>,
+    IErc2981,
+    IErc165
+)]
+impl Erc6909MarketplaceExample {
+    #[constructor]
+    fn constructor(
+        &mut self,
+        royalty_receiver: Address,
+        royalty_fraction: U96,
+    ) -> Result<(), erc2981::Error> {
+        self.marketplace
+            .erc2981
+            .fee_denominator
+            .set(U96::from(FEE_DENOMINATOR));
+        self.marketplace
+            .erc2981
+            ._set_default_royalty(royalty_receiver, royalty_fraction)
+    }
+
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), marketplace::Error> {
+        self.marketplace._mint(to, id, amount)
+    }
+}
+
+#[public]
+impl IErc6909Marketplace for Erc6909MarketplaceExample {
+    type Error = marketplace::Error;
+
+    fn order(&self, order_id: U256) -> (Address, U256, U256, U256) {
+        self.marketplace.order(order_id)
+    }
+
+    fn list(
+        &mut self,
+        id: U256,
+        amount: U256,
+        price: U256,
+    ) -> Result<U256, Self::Error> {
+        self.marketplace.list(id, amount, price)
+    }
+
+    fn cancel(&mut self, order_id: U256) -> Result<(), Self::Error> {
+        self.marketplace.cancel(order_id)
+    }
+
+    #[payable]
+    fn fill(
+        &mut self,
+        order_id: U256,
+        amount: U256,
+    ) -> Result<(), Self::Error> {
+        self.marketplace.fill(order_id, amount)
+    }
+}
+
+delegate_ierc6909!(Erc6909MarketplaceExample => marketplace, marketplace::Error);
+
+#[public]
+impl IErc2981 for Erc6909MarketplaceExample {
+    fn royalty_info(
+        &self,
+        token_id: U256,
+        sale_price: U256,
+    ) -> (Address, U256) {
+        self.marketplace.royalty_info(token_id, sale_price)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909MarketplaceExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        self.marketplace.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909MarketplaceExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = marketplace::Error::inner_types();
+        types.extend(erc2981::Error::inner_types());
+        types.extend(Erc6909Marketplace::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909MarketplaceExample>(
+    );
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Marketplace {
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool status);
+        function mint(address to, uint256 id, uint256 amount) external;
+        function order(uint256 orderId) external view returns (address seller, uint256 id, uint256 amount, uint256 price);
+        function list(uint256 id, uint256 amount, uint256 price) external returns (uint256 orderId);
+        function cancel(uint256 orderId) external;
+        function fill(uint256 orderId, uint256 amount) external payable;
+        function royaltyInfo(uint256 tokenId, uint256 salePrice) external view returns (address receiver, uint256 royaltyAmount);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error Erc6909InsufficientBalance(address sender, uint256 balance, uint256 needed, uint256 id);
+        error ERC6909NonexistentOrder(uint256 orderId);
+        error ERC6909EmptyOrder();
+        error ERC6909InvalidFillAmount(uint256 orderId, uint256 available, uint256 amount);
+        error ERC6909UnauthorizedOrderCancellation(uint256 orderId, address account);
+        error ERC6909IncorrectOrderPayment(uint256 orderId, uint256 cost, uint256 value);
+
+        #[derive(Debug, PartialEq)]
+        event OrderListed(uint256 indexed orderId, address indexed seller, uint256 indexed id, uint256 amount, uint256 price);
+        #[derive(Debug, PartialEq)]
+        event OrderCancelled(uint256 indexed orderId);
+        #[derive(Debug, PartialEq)]
+        event OrderFilled(uint256 indexed orderId, address indexed buyer, uint256 amount, uint256 cost, uint256 royalty);
+    }
+);
@@ -0,0 +1,343 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909Marketplace;
+use alloy::primitives::{aliases::U96, uint, Address, U256};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor, EventExt, Revert,
+};
+
+mod abi;
+
+const ID: U256 = uint!(1_U256);
+const AMOUNT: U256 = uint!(10_U256);
+const PRICE: U256 = uint!(1000_U256);
+const FEE_DENOMINATOR: U256 = uint!(10000_U256);
+const ROYALTY_FRACTION: U96 = uint!(500_U96);
+
+fn ctr(royalty_receiver: Address) -> Constructor {
+    constructor!(royalty_receiver, ROYALTY_FRACTION)
+}
+
+fn royalty(cost: U256) -> U256 {
+    cost * U256::from(ROYALTY_FRACTION) / FEE_DENOMINATOR
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Marketplace Extension
+// ============================================================================
+
+#[e2e::test]
+async fn list_creates_order(
+    alice: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+
+    let receipt = receipt!(contract.list(ID, AMOUNT, PRICE))?;
+
+    assert!(receipt.emits(Erc6909Marketplace::OrderListed {
+        orderId: U256::ZERO,
+        seller: alice.address(),
+        id: ID,
+        amount: AMOUNT,
+        price: PRICE,
+    }));
+
+    let Erc6909Marketplace::orderReturn { seller, id, amount, price } =
+        contract.order(U256::ZERO).call().await?;
+    assert_eq!(alice.address(), seller);
+    assert_eq!(ID, id);
+    assert_eq!(AMOUNT, amount);
+    assert_eq!(PRICE, price);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn list_reverts_on_zero_amount(
+    alice: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+
+    let err = send!(contract.list(ID, U256::ZERO, PRICE))
+        .expect_err("should not list zero tokens");
+
+    assert!(err.reverted_with(Erc6909Marketplace::ERC6909EmptyOrder {}));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cancel_removes_order(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Marketplace::new(contract_addr, &bob.wallet);
+
+    watch!(contract_alice.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract_alice.list(ID, AMOUNT, PRICE))?;
+    let order_id = U256::ZERO;
+
+    let err = send!(contract_bob.cancel(order_id))
+        .expect_err("should not cancel another seller's order");
+    assert!(err.reverted_with(
+        Erc6909Marketplace::ERC6909UnauthorizedOrderCancellation {
+            orderId: order_id,
+            account: bob.address(),
+        }
+    ));
+
+    let receipt = receipt!(contract_alice.cancel(order_id))?;
+
+    assert!(
+        receipt.emits(Erc6909Marketplace::OrderCancelled { orderId: order_id })
+    );
+
+    let err = send!(contract_bob.fill(order_id, AMOUNT))
+        .expect_err("should not fill a cancelled order");
+    assert!(err.reverted_with(Erc6909Marketplace::ERC6909NonexistentOrder {
+        orderId: order_id,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn fill_transfers_tokens_and_splits_payment(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Marketplace::new(contract_addr, &bob.wallet);
+
+    watch!(contract_alice.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract_alice.list(ID, AMOUNT, PRICE))?;
+    let order_id = U256::ZERO;
+
+    let amount = uint!(4_U256);
+    let cost = PRICE * amount;
+    let royalty = royalty(cost);
+
+    let initial_seller_balance =
+        alice.wallet.get_balance(alice.address()).await?;
+    let initial_receiver_balance =
+        alice.wallet.get_balance(charlie.address()).await?;
+
+    let receipt = receipt!(contract_bob.fill(order_id, amount).value(cost))?;
+
+    assert!(receipt.emits(Erc6909Marketplace::OrderFilled {
+        orderId: order_id,
+        buyer: bob.address(),
+        amount,
+        cost,
+        royalty,
+    }));
+
+    let balance =
+        contract_alice.balanceOf(bob.address(), ID).call().await?.balance;
+    assert_eq!(amount, balance);
+
+    let balance =
+        contract_alice.balanceOf(alice.address(), ID).call().await?.balance;
+    assert_eq!(AMOUNT - amount, balance);
+
+    let seller_balance = alice.wallet.get_balance(alice.address()).await?;
+    assert_eq!(initial_seller_balance + cost - royalty, seller_balance);
+
+    let receiver_balance = alice.wallet.get_balance(charlie.address()).await?;
+    assert_eq!(initial_receiver_balance + royalty, receiver_balance);
+
+    let contract_balance = alice.wallet.get_balance(contract_addr).await?;
+    assert_eq!(U256::ZERO, contract_balance);
+
+    let remaining = contract_alice.order(order_id).call().await?.amount;
+    assert_eq!(AMOUNT - amount, remaining);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn fill_reverts_when_amount_exceeds_order(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Marketplace::new(contract_addr, &bob.wallet);
+
+    watch!(contract_alice.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract_alice.list(ID, AMOUNT, PRICE))?;
+    let order_id = U256::ZERO;
+
+    let amount = AMOUNT + U256::ONE;
+    let err = send!(contract_bob.fill(order_id, amount).value(PRICE * amount))
+        .expect_err("should not fill more than listed");
+
+    assert!(err.reverted_with(Erc6909Marketplace::ERC6909InvalidFillAmount {
+        orderId: order_id,
+        available: AMOUNT,
+        amount,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn fill_reverts_on_incorrect_payment(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Marketplace::new(contract_addr, &bob.wallet);
+
+    watch!(contract_alice.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract_alice.list(ID, AMOUNT, PRICE))?;
+    let order_id = U256::ZERO;
+
+    let err = send!(contract_bob.fill(order_id, AMOUNT).value(PRICE))
+        .expect_err("should not fill with an incorrect payment");
+
+    assert!(err.reverted_with(
+        Erc6909Marketplace::ERC6909IncorrectOrderPayment {
+            orderId: order_id,
+            cost: PRICE * AMOUNT,
+            value: PRICE,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn fill_reverts_when_seller_moved_tokens(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Marketplace::new(contract_addr, &bob.wallet);
+
+    watch!(contract_alice.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract_alice.list(ID, AMOUNT, PRICE))?;
+    watch!(contract_alice.transfer(charlie.address(), ID, AMOUNT))?;
+    let order_id = U256::ZERO;
+
+    let err = send!(contract_bob.fill(order_id, AMOUNT).value(PRICE * AMOUNT))
+        .expect_err("should not fill without the seller's tokens");
+
+    assert!(err.reverted_with(
+        Erc6909Marketplace::Erc6909InsufficientBalance {
+            sender: alice.address(),
+            balance: U256::ZERO,
+            needed: AMOUNT,
+            id: ID,
+        }
+    ));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-165 Support Interface
+// ============================================================================
+
+#[e2e::test]
+async fn supports_interface(
+    alice: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(charlie.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Marketplace::new(contract_addr, &alice.wallet);
+
+    let invalid_interface_id: u32 = 0xffffffff;
+    let supports_interface = contract
+        .supportsInterface(invalid_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(!supports_interface);
+
+    let marketplace_interface_id: u32 = 0x4402f975;
+    let supports_interface = contract
+        .supportsInterface(marketplace_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let erc6909_interface_id: u32 = 0xbd85b039;
+    let supports_interface = contract
+        .supportsInterface(erc6909_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let erc2981_interface_id: u32 = 0x2a55205a;
+    let supports_interface = contract
+        .supportsInterface(erc2981_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::erc6909::{
+        extensions::{permit, Erc6909Permit, IErc6909Permit},
+        Erc6909, IErc6909,
+    },
+    utils::{
+        cryptography::eip712::IEip712,
+        introspection::erc165::IErc165,
+        nonces::{INonces, Nonces},
+    },
+};
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+#[entrypoint]
+#[storage]
+struct Erc6909PermitExample {
+    erc6909: Erc6909,
+    nonces: Nonces,
+    permit: Erc6909Permit<Eip712>,
+}
+
+#[storage]
+struct Eip712;
+
+impl IEip712 for Eip712 {
+    const NAME: &'static str = "ERC-6909 Permit Example";
+    const VERSION: &'static str = "1";
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = permit::Error>,
+    INonces,
+    IErc6909Permit<Error = permit::Error>,
+    IErc165
+)]
+impl Erc6909PermitExample {
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), permit::Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+}
+
+#[public]
+impl INonces for Erc6909PermitExample {
+    fn nonces(&self, owner: Address) -> U256 {
+        self.nonces.nonces(owner)
+    }
+}
+
+#[public]
+impl IErc6909Permit for Erc6909PermitExample {
+    type Error = permit::Error;
+
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    fn domain_separator(&self) -> B256 {
+        self.permit.domain_separator()
+    }
+
+    fn permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error> {
+        self.permit.permit(
+            owner,
+            spender,
+            id,
+            amount,
+            deadline,
+            &signature,
+            &mut self.erc6909,
+            &mut self.nonces,
+        )
+    }
+
+    fn permit_transfer_from(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error> {
+        self.permit.permit_transfer_from(
+            owner,
+            to,
+            id,
+            amount,
+            deadline,
+            &signature,
+            &mut self.erc6909,
+            &mut self.nonces,
+        )
+    }
+}
+
+delegate_ierc6909!(Erc6909PermitExample => erc6909, permit::Error);
+
+#[public]
+impl IErc165 for Erc6909PermitExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909Permit>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909PermitExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = permit::Error::inner_types();
+        types.extend(Erc6909::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909PermitExample>();
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Permit {
+        function DOMAIN_SEPARATOR() external view returns (bytes32 domainSeparator);
+        function nonces(address owner) external view returns (uint256 nonce);
+        function permit(address owner, address spender, uint256 id, uint256 amount, uint256 deadline, bytes signature) external;
+        function permitTransferFrom(address owner, address to, uint256 id, uint256 amount, uint256 deadline, bytes signature) external;
+        function transferFrom(address sender, address receiver, uint256 id, uint256 amount) external returns (bool status);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function allowance(address owner, address spender, uint256 id) external view returns (uint256 allowance);
+        function mint(address to, uint256 id, uint256 amount) external;
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error Erc6909InsufficientBalance(address sender, uint256 balance, uint256 needed, uint256 id);
+        error ERC6909InvalidReceiver(address receiver);
+        error ERC6909ExpiredSignature(uint256 deadline);
+        error ERC6909InvalidPermitSigner(address signer, address owner);
+        error ERC6909InvalidSignatureLength(uint256 length);
+
+        #[derive(Debug, PartialEq)]
+        event TransferSingle(address indexed caller, address indexed from, address indexed to, uint256 id, uint256 amount);
+        #[derive(Debug, PartialEq)]
+        event Approval(address indexed owner, address indexed spender, uint256 indexed id, uint256 amount);
+    }
+);
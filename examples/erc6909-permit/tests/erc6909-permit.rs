@@ -0,0 +1,398 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909Permit;
+use alloy::{
+    eips::BlockId,
+    primitives::{keccak256, uint, Address, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::BlockTransactionsKind,
+    signers::Signature,
+    sol,
+    sol_types::SolType,
+};
+use e2e::{receipt, send, watch, Account, EventExt, Revert};
+use eyre::Result;
+
+mod abi;
+
+const TOKEN_ID: U256 = uint!(1_U256);
+const BALANCE: U256 = uint!(1000_U256);
+const AMOUNT: U256 = uint!(100_U256);
+
+const PERMIT_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"Permit(address owner,address spender,uint256 id,uint256 amount,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+type StructHashTuple = sol! {
+    tuple(bytes32, address, address, uint256, uint256, uint256, uint256)
+};
+
+/// Parameters of a permit.
+struct Permit {
+    owner: Address,
+    spender: Address,
+    id: U256,
+    amount: U256,
+    nonce: U256,
+    deadline: U256,
+}
+
+impl Permit {
+    fn new(owner: Address, spender: Address, now: U256) -> Self {
+        Self {
+            owner,
+            spender,
+            id: TOKEN_ID,
+            amount: AMOUNT,
+            nonce: U256::ZERO,
+            deadline: now + uint!(3600_U256),
+        }
+    }
+
+    fn struct_hash(&self) -> B256 {
+        keccak256(StructHashTuple::abi_encode(&(
+            PERMIT_TYPEHASH,
+            self.owner,
+            self.spender,
+            self.id,
+            self.amount,
+            self.nonce,
+            self.deadline,
+        )))
+    }
+}
+
+async fn deploy(account: &Account) -> Result<Address> {
+    let contract_addr = account.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909Permit::new(contract_addr, &account.wallet);
+    watch!(contract.mint(account.address(), TOKEN_ID, BALANCE))?;
+    Ok(contract_addr)
+}
+
+async fn block_timestamp(account: &Account) -> Result<U256> {
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("latest block should exist")
+        .header
+        .timestamp;
+
+    Ok(U256::from(timestamp))
+}
+
+/// Returns the EIP-712 hash of `permit` on the contract at `contract_addr`.
+async fn permit_hash(
+    account: &Account,
+    contract_addr: Address,
+    permit: &Permit,
+) -> Result<B256> {
+    let contract = Erc6909Permit::new(contract_addr, &account.wallet);
+    let domain_separator =
+        contract.DOMAIN_SEPARATOR().call().await?.domainSeparator;
+
+    Ok(openzeppelin_stylus::utils::cryptography::eip712::to_typed_data_hash(
+        &domain_separator,
+        &permit.struct_hash(),
+    ))
+}
+
+/// Returns the signature by `signer` of `permit`.
+async fn sign(
+    signer: &Account,
+    contract_addr: Address,
+    permit: &Permit,
+) -> Result<Signature> {
+    let hash = permit_hash(signer, contract_addr, permit).await?;
+    Ok(signer.sign_hash(&hash).await)
+}
+
+/// Returns the 65 bytes of `signature`, made of its `r`, `s` and `v` values.
+fn to_bytes(signature: &Signature) -> Bytes {
+    let mut bytes = Vec::with_capacity(65);
+    bytes.extend_from_slice(&signature.r().to_be_bytes::<32>());
+    bytes.extend_from_slice(&signature.s().to_be_bytes::<32>());
+    bytes.push(signature.v() as u8 + 27);
+    bytes.into()
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Permit Extension
+// ============================================================================
+
+#[e2e::test]
+async fn permit_sets_allowance(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    // The permit is relayed by Bob, who is also the spender.
+    let contract = Erc6909Permit::new(contract_addr, &bob.wallet);
+
+    let permit = Permit::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature = to_bytes(&sign(&alice, contract_addr, &permit).await?);
+
+    let receipt = receipt!(contract.permit(
+        permit.owner,
+        permit.spender,
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature
+    ))?;
+
+    assert!(receipt.emits(Erc6909Permit::Approval {
+        owner: alice.address(),
+        spender: bob.address(),
+        id: TOKEN_ID,
+        amount: AMOUNT,
+    }));
+
+    let allowance = contract
+        .allowance(alice.address(), bob.address(), TOKEN_ID)
+        .call()
+        .await?
+        .allowance;
+    assert_eq!(AMOUNT, allowance);
+
+    let nonce = contract.nonces(alice.address()).call().await?.nonce;
+    assert_eq!(uint!(1_U256), nonce);
+
+    watch!(contract.transferFrom(
+        alice.address(),
+        bob.address(),
+        TOKEN_ID,
+        AMOUNT
+    ))?;
+
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT, bob_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_transfer_from_works(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    // Charlie relays the permit, and sends the tokens to Bob in the same call.
+    let contract = Erc6909Permit::new(contract_addr, &charlie.wallet);
+
+    let permit = Permit::new(
+        alice.address(),
+        charlie.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature = to_bytes(&sign(&alice, contract_addr, &permit).await?);
+
+    let receipt = receipt!(contract.permitTransferFrom(
+        permit.owner,
+        bob.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature
+    ))?;
+
+    assert!(receipt.emits(Erc6909Permit::TransferSingle {
+        caller: charlie.address(),
+        from: alice.address(),
+        to: bob.address(),
+        id: TOKEN_ID,
+        amount: AMOUNT,
+    }));
+
+    let alice_balance =
+        contract.balanceOf(alice.address(), TOKEN_ID).call().await?.balance;
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(BALANCE - AMOUNT, alice_balance);
+    assert_eq!(AMOUNT, bob_balance);
+
+    let allowance = contract
+        .allowance(alice.address(), charlie.address(), TOKEN_ID)
+        .call()
+        .await?
+        .allowance;
+    assert_eq!(U256::ZERO, allowance);
+
+    let nonce = contract.nonces(alice.address()).call().await?.nonce;
+    assert_eq!(uint!(1_U256), nonce);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_transfer_from_reverts_when_replayed(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Permit::new(contract_addr, &bob.wallet);
+
+    let permit = Permit::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signed = sign(&alice, contract_addr, &permit).await?;
+    let signature = to_bytes(&signed);
+
+    watch!(contract.permitTransferFrom(
+        permit.owner,
+        bob.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature.clone()
+    ))?;
+
+    let err = send!(contract.permitTransferFrom(
+        permit.owner,
+        bob.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature
+    ))
+    .expect_err("should not replay the permit");
+
+    // The nonce was consumed, so the signature recovers another account.
+    let next = Permit { nonce: uint!(1_U256), ..permit };
+    let recovered = signed.recover_address_from_prehash(
+        &permit_hash(&alice, contract_addr, &next).await?,
+    )?;
+    assert!(err.reverted_with(Erc6909Permit::ERC6909InvalidPermitSigner {
+        signer: recovered,
+        owner: alice.address(),
+    }));
+
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT, bob_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_transfer_from_reverts_when_caller_is_not_spender(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    // The permit is made for Bob, but Charlie tries to use it.
+    let contract = Erc6909Permit::new(contract_addr, &charlie.wallet);
+
+    let permit = Permit::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signed = sign(&alice, contract_addr, &permit).await?;
+
+    let err = send!(contract.permitTransferFrom(
+        permit.owner,
+        charlie.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        to_bytes(&signed)
+    ))
+    .expect_err("should not let another spender use the permit");
+
+    // The signature is checked against a permit for Charlie, so it recovers
+    // another account.
+    let for_charlie = Permit { spender: charlie.address(), ..permit };
+    let recovered = signed.recover_address_from_prehash(
+        &permit_hash(&alice, contract_addr, &for_charlie).await?,
+    )?;
+    assert!(err.reverted_with(Erc6909Permit::ERC6909InvalidPermitSigner {
+        signer: recovered,
+        owner: alice.address(),
+    }));
+
+    let alice_balance =
+        contract.balanceOf(alice.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(BALANCE, alice_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_transfer_from_reverts_when_expired(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Permit::new(contract_addr, &bob.wallet);
+
+    let now = block_timestamp(&alice).await?;
+    let deadline = now - uint!(1_U256);
+    let permit =
+        Permit { deadline, ..Permit::new(alice.address(), bob.address(), now) };
+    let signature = to_bytes(&sign(&alice, contract_addr, &permit).await?);
+
+    let err = send!(contract.permitTransferFrom(
+        permit.owner,
+        bob.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature
+    ))
+    .expect_err("should not use an expired permit");
+
+    assert!(
+        err.reverted_with(Erc6909Permit::ERC6909ExpiredSignature { deadline })
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_transfer_from_reverts_when_balance_insufficient(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Permit::new(contract_addr, &bob.wallet);
+
+    let permit = Permit {
+        amount: BALANCE + uint!(1_U256),
+        ..Permit::new(
+            alice.address(),
+            bob.address(),
+            block_timestamp(&alice).await?,
+        )
+    };
+    let signature = to_bytes(&sign(&alice, contract_addr, &permit).await?);
+
+    let err = send!(contract.permitTransferFrom(
+        permit.owner,
+        bob.address(),
+        permit.id,
+        permit.amount,
+        permit.deadline,
+        signature
+    ))
+    .expect_err("should not transfer more than the balance");
+
+    assert!(err.reverted_with(Erc6909Permit::Erc6909InsufficientBalance {
+        sender: alice.address(),
+        balance: BALANCE,
+        needed: permit.amount,
+        id: TOKEN_ID,
+    }));
+
+    let nonce = contract.nonces(alice.address()).call().await?.nonce;
+    assert_eq!(U256::ZERO, nonce);
+
+    Ok(())
+}
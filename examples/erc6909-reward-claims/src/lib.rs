@@ -0,0 +1,125 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::erc6909::{
+        extensions::{
+            reward_claims, Erc6909RewardClaims, IErc6909RewardClaims,
+        },
+        Erc6909, IErc6909,
+    },
+    utils::{
+        cryptography::eip712::IEip712,
+        introspection::erc165::IErc165,
+        nonces::{INonces, Nonces},
+    },
+};
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+#[entrypoint]
+#[storage]
+struct Erc6909RewardClaimsExample {
+    erc6909: Erc6909,
+    nonces: Nonces,
+    reward_claims: Erc6909RewardClaims<Eip712>,
+}
+
+#[storage]
+struct Eip712;
+
+impl IEip712 for Eip712 {
+    const NAME: &'static str = "ERC-6909 Reward Claims Example";
+    const VERSION: &'static str = "1";
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = reward_claims::Error>,
+    INonces,
+    IErc6909RewardClaims<Error = reward_claims::Error>,
+    IErc165
+)]
+impl Erc6909RewardClaimsExample {
+    #[constructor]
+    fn constructor(&mut self, reward_signer: Address) {
+        self.reward_claims._set_reward_signer(reward_signer);
+    }
+}
+
+#[public]
+impl INonces for Erc6909RewardClaimsExample {
+    fn nonces(&self, owner: Address) -> U256 {
+        self.nonces.nonces(owner)
+    }
+}
+
+#[public]
+impl IErc6909RewardClaims for Erc6909RewardClaimsExample {
+    type Error = reward_claims::Error;
+
+    fn reward_signer(&self) -> Address {
+        self.reward_claims.reward_signer()
+    }
+
+    fn claim(
+        &mut self,
+        recipient: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        nonce: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error> {
+        self.reward_claims.claim(
+            recipient,
+            ids,
+            amounts,
+            nonce,
+            deadline,
+            &signature,
+            &mut self.erc6909,
+            &mut self.nonces,
+        )
+    }
+}
+
+delegate_ierc6909!(Erc6909RewardClaimsExample => erc6909, reward_claims::Error);
+
+#[public]
+impl IErc165 for Erc6909RewardClaimsExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909RewardClaims>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909RewardClaimsExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::{event, Events};
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = reward_claims::Error::inner_types();
+        types.extend(Erc6909::events());
+        types.push(event::<reward_claims::RewardSignerChanged>(
+            "event RewardSignerChanged(address indexed previous_signer, address indexed new_signer)",
+        ));
+        types.push(event::<reward_claims::RewardsClaimed>(
+            "event RewardsClaimed(address indexed recipient, uint256 nonce)",
+        ));
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<
+        Erc6909RewardClaimsExample,
+    >();
+}
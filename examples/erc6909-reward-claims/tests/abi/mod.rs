@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909RewardClaims {
+        function nonces(address owner) external view returns (uint256 nonce);
+        function rewardSigner() external view returns (address signer);
+        function claim(address recipient, uint256[] memory ids, uint256[] memory amounts, uint256 nonce, uint256 deadline, bytes signature) external;
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error ERC6909ExpiredSignature(uint256 deadline);
+        error ERC6909InvalidRewardSigner(address signer, address reward_signer);
+        error ERC6909InvalidSignatureLength(uint256 length);
+        error InvalidAccountNonce(address account, uint256 current_nonce);
+
+        #[derive(Debug, PartialEq)]
+        event TransferBatch(address indexed caller, address indexed from, address indexed to, uint256[] ids, uint256[] amounts);
+        #[derive(Debug, PartialEq)]
+        event RewardsClaimed(address indexed recipient, uint256 nonce);
+    }
+);
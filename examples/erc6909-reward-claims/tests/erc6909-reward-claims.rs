@@ -0,0 +1,344 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909RewardClaims;
+use alloy::{
+    eips::BlockId,
+    primitives::{keccak256, uint, Address, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::BlockTransactionsKind,
+    signers::Signature,
+    sol,
+    sol_types::SolType,
+};
+use e2e::{constructor, receipt, send, Account, Constructor, EventExt, Revert};
+use eyre::Result;
+use openzeppelin_stylus::utils::cryptography::eip712::to_typed_data_hash;
+
+mod abi;
+
+const TOKEN_ID: U256 = uint!(1_U256);
+const OTHER_TOKEN_ID: U256 = uint!(2_U256);
+const AMOUNT: U256 = uint!(100_U256);
+const OTHER_AMOUNT: U256 = uint!(250_U256);
+
+const DOMAIN_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+        .finalize();
+
+const REWARD_CLAIM_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"RewardClaim(address recipient,uint256[] ids,uint256[] amounts,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+type DomainSeparatorTuple = sol! {
+    tuple(bytes32, bytes32, bytes32, uint256, address)
+};
+
+type StructHashTuple = sol! {
+    tuple(bytes32, address, bytes32, bytes32, uint256, uint256)
+};
+
+/// Parameters of a reward claim.
+struct Claim {
+    recipient: Address,
+    ids: Vec<U256>,
+    amounts: Vec<U256>,
+    nonce: U256,
+    deadline: U256,
+}
+
+impl Claim {
+    fn new(recipient: Address, now: U256) -> Self {
+        Self {
+            recipient,
+            ids: vec![TOKEN_ID, OTHER_TOKEN_ID],
+            amounts: vec![AMOUNT, OTHER_AMOUNT],
+            nonce: U256::ZERO,
+            deadline: now + uint!(3600_U256),
+        }
+    }
+
+    fn struct_hash(&self) -> B256 {
+        keccak256(StructHashTuple::abi_encode(&(
+            REWARD_CLAIM_TYPEHASH,
+            self.recipient,
+            hash_words(&self.ids),
+            hash_words(&self.amounts),
+            self.nonce,
+            self.deadline,
+        )))
+    }
+}
+
+/// Returns the EIP-712 encoding of a `uint256[]` member, i.e. the hash of its
+/// concatenated words.
+fn hash_words(values: &[U256]) -> B256 {
+    let words: Vec<u8> =
+        values.iter().flat_map(|value| value.to_be_bytes::<32>()).collect();
+    keccak256(words)
+}
+
+fn ctr(reward_signer: Address) -> Constructor {
+    constructor!(reward_signer)
+}
+
+async fn deploy(account: &Account, reward_signer: Address) -> Result<Address> {
+    Ok(account
+        .as_deployer()
+        .with_constructor(ctr(reward_signer))
+        .deploy()
+        .await?
+        .contract_address)
+}
+
+async fn block_timestamp(account: &Account) -> Result<U256> {
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("latest block should exist")
+        .header
+        .timestamp;
+
+    Ok(U256::from(timestamp))
+}
+
+/// Returns the EIP-712 hash of `claim` on the contract at `contract_addr`.
+async fn claim_hash(
+    account: &Account,
+    contract_addr: Address,
+    claim: &Claim,
+) -> Result<B256> {
+    let chain_id = account.wallet.get_chain_id().await?;
+    let domain_separator = keccak256(DomainSeparatorTuple::abi_encode(&(
+        DOMAIN_TYPEHASH,
+        keccak256("ERC-6909 Reward Claims Example"),
+        keccak256("1"),
+        U256::from(chain_id),
+        contract_addr,
+    )));
+
+    Ok(to_typed_data_hash(&domain_separator, &claim.struct_hash()))
+}
+
+/// Returns the signature by `signer` of `claim`.
+async fn sign(
+    signer: &Account,
+    contract_addr: Address,
+    claim: &Claim,
+) -> Result<Signature> {
+    let hash = claim_hash(signer, contract_addr, claim).await?;
+    Ok(signer.sign_hash(&hash).await)
+}
+
+/// Returns the 65 bytes of `signature`, made of its `r`, `s` and `v` values.
+fn to_bytes(signature: &Signature) -> Bytes {
+    let mut bytes = Vec::with_capacity(65);
+    bytes.extend_from_slice(&signature.r().to_be_bytes::<32>());
+    bytes.extend_from_slice(&signature.s().to_be_bytes::<32>());
+    bytes.push(signature.v() as u8 + 27);
+    bytes.into()
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Reward Claims Extension
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &alice.wallet);
+
+    let signer = contract.rewardSigner().call().await?.signer;
+    assert_eq!(alice.address(), signer);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_mints_rewards(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &bob.wallet);
+
+    let claim = Claim::new(bob.address(), block_timestamp(&alice).await?);
+    let signature = to_bytes(&sign(&alice, contract_addr, &claim).await?);
+
+    let receipt = receipt!(contract.claim(
+        claim.recipient,
+        claim.ids.clone(),
+        claim.amounts.clone(),
+        claim.nonce,
+        claim.deadline,
+        signature
+    ))?;
+
+    assert!(receipt.emits(Erc6909RewardClaims::TransferBatch {
+        caller: bob.address(),
+        from: Address::ZERO,
+        to: bob.address(),
+        ids: claim.ids.clone(),
+        amounts: claim.amounts.clone(),
+    }));
+    assert!(receipt.emits(Erc6909RewardClaims::RewardsClaimed {
+        recipient: bob.address(),
+        nonce: U256::ZERO,
+    }));
+
+    let balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT, balance);
+    let balance =
+        contract.balanceOf(bob.address(), OTHER_TOKEN_ID).call().await?.balance;
+    assert_eq!(OTHER_AMOUNT, balance);
+
+    let nonce = contract.nonces(bob.address()).call().await?.nonce;
+    assert_eq!(uint!(1_U256), nonce);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_reverts_when_replayed(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &bob.wallet);
+
+    let claim = Claim::new(bob.address(), block_timestamp(&alice).await?);
+    let signature = to_bytes(&sign(&alice, contract_addr, &claim).await?);
+
+    receipt!(contract.claim(
+        claim.recipient,
+        claim.ids.clone(),
+        claim.amounts.clone(),
+        claim.nonce,
+        claim.deadline,
+        signature.clone()
+    ))?;
+
+    let err = send!(contract.claim(
+        claim.recipient,
+        claim.ids.clone(),
+        claim.amounts.clone(),
+        claim.nonce,
+        claim.deadline,
+        signature
+    ))
+    .expect_err("should not replay the claim");
+
+    assert!(err.reverted_with(Erc6909RewardClaims::InvalidAccountNonce {
+        account: bob.address(),
+        current_nonce: uint!(1_U256),
+    }));
+
+    let balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_reverts_when_amounts_tampered(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &bob.wallet);
+
+    let claim = Claim::new(bob.address(), block_timestamp(&alice).await?);
+    let signed = sign(&alice, contract_addr, &claim).await?;
+
+    let tampered = Claim { amounts: vec![AMOUNT, AMOUNT * AMOUNT], ..claim };
+    let err = send!(contract.claim(
+        tampered.recipient,
+        tampered.ids.clone(),
+        tampered.amounts.clone(),
+        tampered.nonce,
+        tampered.deadline,
+        to_bytes(&signed)
+    ))
+    .expect_err("should not mint amounts that weren't signed");
+
+    // The signature is checked against the tampered claim, so it recovers
+    // another account.
+    let recovered = signed.recover_address_from_prehash(
+        &claim_hash(&alice, contract_addr, &tampered).await?,
+    )?;
+    assert!(err.reverted_with(
+        Erc6909RewardClaims::ERC6909InvalidRewardSigner {
+            signer: recovered,
+            reward_signer: alice.address(),
+        }
+    ));
+
+    let balance =
+        contract.balanceOf(bob.address(), OTHER_TOKEN_ID).call().await?.balance;
+    assert_eq!(U256::ZERO, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_reverts_when_signer_is_not_reward_signer(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &bob.wallet);
+
+    // Bob signs his own rewards.
+    let claim = Claim::new(bob.address(), block_timestamp(&alice).await?);
+    let signature = to_bytes(&sign(&bob, contract_addr, &claim).await?);
+
+    let err = send!(contract.claim(
+        claim.recipient,
+        claim.ids.clone(),
+        claim.amounts.clone(),
+        claim.nonce,
+        claim.deadline,
+        signature
+    ))
+    .expect_err("should only accept claims of the reward signer");
+
+    assert!(err.reverted_with(
+        Erc6909RewardClaims::ERC6909InvalidRewardSigner {
+            signer: bob.address(),
+            reward_signer: alice.address(),
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_reverts_when_expired(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice, alice.address()).await?;
+    let contract = Erc6909RewardClaims::new(contract_addr, &bob.wallet);
+
+    let now = block_timestamp(&alice).await?;
+    let deadline = now - uint!(1_U256);
+    let claim = Claim { deadline, ..Claim::new(bob.address(), now) };
+    let signature = to_bytes(&sign(&alice, contract_addr, &claim).await?);
+
+    let err = send!(contract.claim(
+        claim.recipient,
+        claim.ids.clone(),
+        claim.amounts.clone(),
+        claim.nonce,
+        claim.deadline,
+        signature
+    ))
+    .expect_err("should not use an expired claim");
+
+    assert!(err.reverted_with(Erc6909RewardClaims::ERC6909ExpiredSignature {
+        deadline
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err, deprecated)]
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus::{
+    access::ownable::{self, IOwnable, Ownable},
+    token::erc6909::{
+        interface::Erc6909Interface,
+        utils::{safe_erc6909, ISafeErc6909, SafeErc6909},
+    },
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::{alloy_sol_types::sol, contract, evm, msg, prelude::*};
+
+sol! {
+    /// Emitted when `settler` settles a batch of `legs` transfers.
+    #[derive(Debug)]
+    event Settled(address indexed settler, uint256 legs);
+}
+
+sol_interface! {
+    interface IErc6909Authorization {
+        function checkAuthorization(address owner, address spender, uint256 id, uint256 amount) external view returns (bool);
+    }
+}
+
+/// A single transfer of a settlement: `amount` tokens of type `id` of
+/// `token`, moved from `sender` to `receiver`.
+type Leg = (Address, Address, Address, U256, U256);
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    FailedOperation(safe_erc6909::SafeErc6909FailedOperation),
+    UnauthorizedAccount(ownable::OwnableUnauthorizedAccount),
+    InvalidOwner(ownable::OwnableInvalidOwner),
+}
+
+impl From<safe_erc6909::Error> for Error {
+    fn from(value: safe_erc6909::Error) -> Self {
+        match value {
+            safe_erc6909::Error::SafeErc6909FailedOperation(e) => {
+                Error::FailedOperation(e)
+            }
+        }
+    }
+}
+
+impl From<ownable::Error> for Error {
+    fn from(value: ownable::Error) -> Self {
+        match value {
+            ownable::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            ownable::Error::InvalidOwner(e) => Error::InvalidOwner(e),
+        }
+    }
+}
+
+/// Settlement router moving tokens of several ERC-6909 contracts at once.
+///
+/// The router never holds tokens: each holder makes it an operator of the
+/// tokens it settles (see `setOperator`), and every leg is pulled straight
+/// from its sender to its receiver through [`SafeErc6909`]. A settlement is
+/// atomic, so if any leg fails, the whole batch is reverted.
+#[entrypoint]
+#[storage]
+struct Erc6909RouterExample {
+    ownable: Ownable,
+    safe_erc6909: SafeErc6909,
+}
+
+#[public]
+#[implements(IOwnable<Error = Error>, IErc165)]
+impl Erc6909RouterExample {
+    #[constructor]
+    fn constructor(&mut self, initial_owner: Address) -> Result<(), Error> {
+        Ok(self.ownable.constructor(initial_owner)?)
+    }
+
+    /// Settles `legs`, each being a `(token, sender, receiver, id, amount)`
+    /// transfer, in order.
+    fn settle(&mut self, legs: Vec<Leg>) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+
+        for &(token, sender, receiver, id, amount) in &legs {
+            self.safe_erc6909
+                .safe_transfer_from(token, sender, receiver, id, amount)?;
+        }
+
+        evm::log(Settled {
+            settler: msg::sender(),
+            legs: U256::from(legs.len()),
+        });
+
+        Ok(())
+    }
+
+    /// Returns the balances of `owner` for each `(token, id)` position.
+    fn balances_of(
+        &self,
+        owner: Address,
+        positions: Vec<(Address, U256)>,
+    ) -> Result<Vec<U256>, Error> {
+        positions
+            .into_iter()
+            .map(|(token, id)| {
+                Erc6909Interface::new(token)
+                    .balance_of(self, owner, id)
+                    .map_err(|_| {
+                        Error::FailedOperation(
+                            safe_erc6909::SafeErc6909FailedOperation { token },
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns, for each `(token, sender, receiver, id, amount)` leg,
+    /// whether the router is authorized to move it, so that settlers can
+    /// only submit the viable legs.
+    ///
+    /// Tokens must expose `checkAuthorization`, like the `erc6909` example.
+    fn viable_legs(&self, legs: Vec<Leg>) -> Result<Vec<bool>, Error> {
+        let router = contract::address();
+        legs.into_iter()
+            .map(|(token, sender, _, id, amount)| {
+                IErc6909Authorization::new(token)
+                    .check_authorization(self, sender, router, id, amount)
+                    .map_err(|_| {
+                        Error::FailedOperation(
+                            safe_erc6909::SafeErc6909FailedOperation { token },
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+#[public]
+impl IOwnable for Erc6909RouterExample {
+    type Error = Error;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn transfer_ownership(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<(), Self::Error> {
+        Ok(self.ownable.transfer_ownership(new_owner)?)
+    }
+
+    fn renounce_ownership(&mut self) -> Result<(), Self::Error> {
+        Ok(self.ownable.renounce_ownership()?)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909RouterExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        self.ownable.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909RouterExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::{event, Events};
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = Error::inner_types();
+        types.push(event::<Settled>(
+            "event Settled(address indexed settler, uint256 legs)",
+        ));
+        types.extend(Ownable::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909RouterExample>();
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Router {
+        struct Leg {
+            address token;
+            address sender;
+            address receiver;
+            uint256 id;
+            uint256 amount;
+        }
+
+        struct Position {
+            address token;
+            uint256 id;
+        }
+
+        function settle(Leg[] memory legs) external;
+        function balancesOf(address owner, Position[] memory positions) external view returns (uint256[] memory balances);
+        function viableLegs(Leg[] memory legs) external view returns (bool[] memory viable);
+        function owner() external view returns (address owner);
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error SafeErc6909FailedOperation(address token);
+        error OwnableUnauthorizedAccount(address account);
+        error OwnableInvalidOwner(address owner);
+
+        #[derive(Debug, PartialEq)]
+        event Settled(address indexed settler, uint256 legs);
+    }
+
+    #[sol(rpc)]
+    contract Erc6909 {
+        function setOperator(address spender, bool approved) external returns (bool status);
+        function approve(address spender, uint256 id, uint256 amount) external returns (bool status);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function mint(address to, uint256 id, uint256 amount) external;
+    }
+);
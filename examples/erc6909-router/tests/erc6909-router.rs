@@ -0,0 +1,326 @@
+#![cfg(feature = "e2e")]
+
+use abi::{
+    Erc6909,
+    Erc6909Router::{self, Leg, Position},
+};
+use alloy::primitives::{uint, Address, U256};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor,
+    ContractInitializationError, EventExt, Revert,
+};
+use eyre::Result;
+
+mod abi;
+
+const TOKEN_ID: U256 = uint!(1_U256);
+const OTHER_TOKEN_ID: U256 = uint!(2_U256);
+const AMOUNT: U256 = uint!(1000_U256);
+
+fn ctr(initial_owner: Address) -> Constructor {
+    constructor!(initial_owner)
+}
+
+async fn deploy_token(account: &Account) -> Result<Address> {
+    Ok(account
+        .as_deployer()
+        .deploy_crate("erc6909-example")
+        .await?
+        .contract_address)
+}
+
+async fn deploy_router(account: &Account) -> Result<Address> {
+    Ok(account
+        .as_deployer()
+        .with_constructor(ctr(account.address()))
+        .deploy()
+        .await?
+        .contract_address)
+}
+
+/// Deploys the router and two ERC-6909 tokens, mints `AMOUNT` of
+/// [`TOKEN_ID`] and [`OTHER_TOKEN_ID`] of both tokens to `holder`, and makes
+/// the router an operator of `holder` on both tokens.
+async fn setup(
+    owner: &Account,
+    holder: &Account,
+) -> Result<(Address, Address, Address)> {
+    let router_addr = deploy_router(owner).await?;
+    let token_a_addr = deploy_token(owner).await?;
+    let token_b_addr = deploy_token(owner).await?;
+
+    for token_addr in [token_a_addr, token_b_addr] {
+        let token = Erc6909::new(token_addr, &owner.wallet);
+        watch!(token.mint(holder.address(), TOKEN_ID, AMOUNT))?;
+        watch!(token.mint(holder.address(), OTHER_TOKEN_ID, AMOUNT))?;
+
+        let token = Erc6909::new(token_addr, &holder.wallet);
+        watch!(token.setOperator(router_addr, true))?;
+    }
+
+    Ok((router_addr, token_a_addr, token_b_addr))
+}
+
+async fn balance_of(
+    account: &Account,
+    token_addr: Address,
+    owner: Address,
+    id: U256,
+) -> Result<U256> {
+    let token = Erc6909::new(token_addr, &account.wallet);
+    Ok(token.balanceOf(owner, id).call().await?.balance)
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Settlement Router
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let router_addr = deploy_router(&alice).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    assert_eq!(alice.address(), router.owner().call().await?.owner);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_zero_address_for_owner(alice: Account) -> Result<()> {
+    let err = alice
+        .as_deployer()
+        .with_constructor(ctr(Address::ZERO))
+        .deploy()
+        .await
+        .expect_err("should not deploy due to `OwnableInvalidOwner`");
+
+    assert!(err.downcast_ref::<ContractInitializationError>().is_some());
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn settles_across_two_tokens(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (router_addr, token_a_addr, token_b_addr) = setup(&alice, &bob).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    let bob_addr = bob.address();
+    let charlie_addr = charlie.address();
+    let legs = vec![
+        Leg {
+            token: token_a_addr,
+            sender: bob_addr,
+            receiver: charlie_addr,
+            id: TOKEN_ID,
+            amount: uint!(100_U256),
+        },
+        Leg {
+            token: token_a_addr,
+            sender: bob_addr,
+            receiver: charlie_addr,
+            id: OTHER_TOKEN_ID,
+            amount: uint!(200_U256),
+        },
+        Leg {
+            token: token_b_addr,
+            sender: bob_addr,
+            receiver: charlie_addr,
+            id: TOKEN_ID,
+            amount: uint!(300_U256),
+        },
+    ];
+
+    let receipt = receipt!(router.settle(legs))?;
+
+    assert!(receipt.emits(Erc6909Router::Settled {
+        settler: alice.address(),
+        legs: uint!(3_U256),
+    }));
+
+    let expected = [
+        (token_a_addr, TOKEN_ID, uint!(100_U256)),
+        (token_a_addr, OTHER_TOKEN_ID, uint!(200_U256)),
+        (token_b_addr, TOKEN_ID, uint!(300_U256)),
+        (token_b_addr, OTHER_TOKEN_ID, U256::ZERO),
+    ];
+    for (token_addr, id, settled) in expected {
+        assert_eq!(
+            AMOUNT - settled,
+            balance_of(&alice, token_addr, bob_addr, id).await?
+        );
+        assert_eq!(
+            settled,
+            balance_of(&alice, token_addr, charlie_addr, id).await?
+        );
+    }
+
+    // The router only routes tokens, and never holds any.
+    assert_eq!(
+        U256::ZERO,
+        balance_of(&alice, token_a_addr, router_addr, TOKEN_ID).await?
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn balances_of_reads_every_position(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (router_addr, token_a_addr, token_b_addr) = setup(&alice, &bob).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    let positions = vec![
+        Position { token: token_a_addr, id: TOKEN_ID },
+        Position { token: token_b_addr, id: OTHER_TOKEN_ID },
+        Position { token: token_b_addr, id: uint!(3_U256) },
+    ];
+
+    let balances =
+        router.balancesOf(bob.address(), positions).call().await?.balances;
+
+    assert_eq!(vec![AMOUNT, AMOUNT, U256::ZERO], balances);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn viable_legs_checks_router_authorization(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (router_addr, token_a_addr, _) = setup(&alice, &bob).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    // Charlie only approves the router for part of her tokens.
+    let allowance = AMOUNT / uint!(2_U256);
+    let token = Erc6909::new(token_a_addr, &charlie.wallet);
+    watch!(token.approve(router_addr, TOKEN_ID, allowance))?;
+
+    let leg = |sender: Address, id: U256, amount: U256| Leg {
+        token: token_a_addr,
+        sender,
+        receiver: alice.address(),
+        id,
+        amount,
+    };
+    let legs = vec![
+        leg(bob.address(), TOKEN_ID, AMOUNT),
+        leg(charlie.address(), TOKEN_ID, allowance),
+        leg(charlie.address(), TOKEN_ID, allowance + uint!(1_U256)),
+        leg(charlie.address(), OTHER_TOKEN_ID, U256::ONE),
+    ];
+
+    let viable = router.viableLegs(legs).call().await?.viable;
+
+    assert_eq!(vec![true, true, false, false], viable);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn settle_reverts_when_not_owner(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (router_addr, token_a_addr, _) = setup(&alice, &bob).await?;
+    let router = Erc6909Router::new(router_addr, &bob.wallet);
+
+    let legs = vec![Leg {
+        token: token_a_addr,
+        sender: bob.address(),
+        receiver: alice.address(),
+        id: TOKEN_ID,
+        amount: AMOUNT,
+    }];
+
+    let err = send!(router.settle(legs))
+        .expect_err("should not settle when not owner");
+
+    assert!(err.reverted_with(Erc6909Router::OwnableUnauthorizedAccount {
+        account: bob.address(),
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn settle_reverts_when_router_is_not_operator(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let (router_addr, token_a_addr, token_b_addr) = setup(&alice, &bob).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    let token_b = Erc6909::new(token_b_addr, &bob.wallet);
+    watch!(token_b.setOperator(router_addr, false))?;
+
+    let legs = vec![
+        Leg {
+            token: token_a_addr,
+            sender: bob.address(),
+            receiver: charlie.address(),
+            id: TOKEN_ID,
+            amount: AMOUNT,
+        },
+        Leg {
+            token: token_b_addr,
+            sender: bob.address(),
+            receiver: charlie.address(),
+            id: TOKEN_ID,
+            amount: AMOUNT,
+        },
+    ];
+
+    let err = send!(router.settle(legs))
+        .expect_err("should not settle without operator approval");
+
+    assert!(err.reverted_with(Erc6909Router::SafeErc6909FailedOperation {
+        token: token_b_addr,
+    }));
+
+    // The whole settlement is reverted, including the first leg.
+    assert_eq!(
+        AMOUNT,
+        balance_of(&alice, token_a_addr, bob.address(), TOKEN_ID).await?
+    );
+    assert_eq!(
+        U256::ZERO,
+        balance_of(&alice, token_a_addr, charlie.address(), TOKEN_ID).await?
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn settle_reverts_when_token_has_no_code(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let router_addr = deploy_router(&alice).await?;
+    let router = Erc6909Router::new(router_addr, &alice.wallet);
+
+    let legs = vec![Leg {
+        token: bob.address(),
+        sender: alice.address(),
+        receiver: bob.address(),
+        id: TOKEN_ID,
+        amount: AMOUNT,
+    }];
+
+    let err = send!(router.settle(legs))
+        .expect_err("should not settle with a token without code");
+
+    assert!(err.reverted_with(Erc6909Router::SafeErc6909FailedOperation {
+        token: bob.address(),
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus::{
+    access::ownable::{self, IOwnable, Ownable},
+    delegate_ierc6909,
+    token::erc6909::{
+        self,
+        extensions::{sale, Erc6909Sale, IErc6909RefundableSale, IErc6909Sale},
+        IErc6909,
+    },
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::prelude::*;
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    InsufficientBalance(erc6909::Erc6909InsufficientBalance),
+    InsufficientPermission(erc6909::Erc6909InsufficientPermission),
+    InsufficientAllowance(erc6909::Erc6909InsufficientAllowance),
+    InvalidApprover(erc6909::ERC6909InvalidApprover),
+    InvalidSender(erc6909::ERC6909InvalidSender),
+    InvalidSpender(erc6909::ERC6909InvalidSpender),
+    InvalidApproverForId(erc6909::ERC6909InvalidApproverForId),
+    InvalidSpenderForId(erc6909::ERC6909InvalidSpenderForId),
+    InvalidReceiver(erc6909::ERC6909InvalidReceiver),
+    InvalidArrayLength(erc6909::ERC6909InvalidArrayLength),
+    SelfTarget(erc6909::ERC6909SelfTarget),
+    SaleNotActive(sale::ERC6909SaleNotActive),
+    IncorrectPayment(sale::ERC6909IncorrectPayment),
+    InvalidTreasury(sale::ERC6909InvalidTreasury),
+    TreasuryTransferFailed(sale::ERC6909TreasuryTransferFailed),
+    RefundUnavailable(sale::ERC6909RefundUnavailable),
+    NothingToRefund(sale::ERC6909NothingToRefund),
+    RefundTransferFailed(sale::ERC6909RefundTransferFailed),
+    ProceedsUnavailable(sale::ERC6909ProceedsUnavailable),
+    UnauthorizedAccount(ownable::OwnableUnauthorizedAccount),
+    InvalidOwner(ownable::OwnableInvalidOwner),
+}
+
+impl From<sale::Error> for Error {
+    fn from(value: sale::Error) -> Self {
+        match value {
+            sale::Error::InsufficientBalance(e) => {
+                Error::InsufficientBalance(e)
+            }
+            sale::Error::InsufficientPermission(e) => {
+                Error::InsufficientPermission(e)
+            }
+            sale::Error::InsufficientAllowance(e) => {
+                Error::InsufficientAllowance(e)
+            }
+            sale::Error::InvalidApprover(e) => Error::InvalidApprover(e),
+            sale::Error::InvalidSender(e) => Error::InvalidSender(e),
+            sale::Error::InvalidSpender(e) => Error::InvalidSpender(e),
+            sale::Error::InvalidApproverForId(e) => {
+                Error::InvalidApproverForId(e)
+            }
+            sale::Error::InvalidSpenderForId(e) => {
+                Error::InvalidSpenderForId(e)
+            }
+            sale::Error::InvalidReceiver(e) => Error::InvalidReceiver(e),
+            sale::Error::InvalidArrayLength(e) => Error::InvalidArrayLength(e),
+            sale::Error::SelfTarget(e) => Error::SelfTarget(e),
+            sale::Error::SaleNotActive(e) => Error::SaleNotActive(e),
+            sale::Error::IncorrectPayment(e) => Error::IncorrectPayment(e),
+            sale::Error::InvalidTreasury(e) => Error::InvalidTreasury(e),
+            sale::Error::TreasuryTransferFailed(e) => {
+                Error::TreasuryTransferFailed(e)
+            }
+            sale::Error::RefundUnavailable(e) => Error::RefundUnavailable(e),
+            sale::Error::NothingToRefund(e) => Error::NothingToRefund(e),
+            sale::Error::RefundTransferFailed(e) => {
+                Error::RefundTransferFailed(e)
+            }
+            sale::Error::ProceedsUnavailable(e) => {
+                Error::ProceedsUnavailable(e)
+            }
+        }
+    }
+}
+
+impl From<ownable::Error> for Error {
+    fn from(value: ownable::Error) -> Self {
+        match value {
+            ownable::Error::UnauthorizedAccount(e) => {
+                Error::UnauthorizedAccount(e)
+            }
+            ownable::Error::InvalidOwner(e) => Error::InvalidOwner(e),
+        }
+    }
+}
+
+#[entrypoint]
+#[storage]
+struct Erc6909SaleExample {
+    sale: Erc6909Sale,
+    ownable: Ownable,
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = Error>,
+    IErc6909Sale<Error = Error>,
+    IErc6909RefundableSale<Error = Error>,
+    IOwnable<Error = Error>,
+    IErc165
+)]
+impl Erc6909SaleExample {
+    #[constructor]
+    fn constructor(
+        &mut self,
+        initial_owner: Address,
+        treasury: Address,
+    ) -> Result<(), Error> {
+        self.ownable.constructor(initial_owner)?;
+        Ok(self.sale._set_treasury(treasury)?)
+    }
+
+    fn set_sale(
+        &mut self,
+        id: U256,
+        price: U256,
+        start: U64,
+        end: U64,
+    ) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        self.sale._set_sale(id, price, start, end);
+        Ok(())
+    }
+
+    fn set_treasury(&mut self, treasury: Address) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        Ok(self.sale._set_treasury(treasury)?)
+    }
+
+    fn set_funding_goal(&mut self, id: U256, goal: U256) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        self.sale._set_funding_goal(id, goal);
+        Ok(())
+    }
+}
+
+#[public]
+impl IErc6909Sale for Erc6909SaleExample {
+    type Error = Error;
+
+    fn price(&self, id: U256) -> U256 {
+        self.sale.price(id)
+    }
+
+    fn sale_start(&self, id: U256) -> U64 {
+        self.sale.sale_start(id)
+    }
+
+    fn sale_end(&self, id: U256) -> U64 {
+        self.sale.sale_end(id)
+    }
+
+    fn treasury(&self) -> Address {
+        self.sale.treasury()
+    }
+
+    #[payable]
+    fn buy(&mut self, id: U256, amount: U256) -> Result<(), Self::Error> {
+        Ok(self.sale.buy(id, amount)?)
+    }
+}
+
+#[public]
+impl IErc6909RefundableSale for Erc6909SaleExample {
+    type Error = Error;
+
+    fn funding_goal(&self, id: U256) -> U256 {
+        self.sale.funding_goal(id)
+    }
+
+    fn funds_raised(&self, id: U256) -> U256 {
+        self.sale.funds_raised(id)
+    }
+
+    fn contribution(&self, account: Address, id: U256) -> U256 {
+        self.sale.contribution(account, id)
+    }
+
+    fn claim_refund(&mut self, id: U256) -> Result<(), Self::Error> {
+        Ok(self.sale.claim_refund(id)?)
+    }
+
+    fn sweep_proceeds(&mut self, id: U256) -> Result<(), Self::Error> {
+        Ok(self.sale.sweep_proceeds(id)?)
+    }
+}
+
+delegate_ierc6909!(Erc6909SaleExample => sale, Error);
+
+#[public]
+impl IOwnable for Erc6909SaleExample {
+    type Error = Error;
+
+    fn owner(&self) -> Address {
+        self.ownable.owner()
+    }
+
+    fn transfer_ownership(
+        &mut self,
+        new_owner: Address,
+    ) -> Result<(), Self::Error> {
+        Ok(self.ownable.transfer_ownership(new_owner)?)
+    }
+
+    fn renounce_ownership(&mut self) -> Result<(), Self::Error> {
+        Ok(self.ownable.renounce_ownership()?)
+    }
+}
+
+#[public]
+impl IErc165 for Erc6909SaleExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        self.sale.supports_interface(interface_id)
+            || self.ownable.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909SaleExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = Error::inner_types();
+        types.extend(Erc6909Sale::events());
+        types.extend(Ownable::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909SaleExample>();
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Sale {
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function price(uint256 id) external view returns (uint256 price);
+        function saleStart(uint256 id) external view returns (uint64 start);
+        function saleEnd(uint256 id) external view returns (uint64 end);
+        function treasury() external view returns (address treasury);
+        function buy(uint256 id, uint256 amount) external payable;
+        function setSale(uint256 id, uint256 price, uint64 start, uint64 end) external;
+        function setTreasury(address treasury) external;
+        function fundingGoal(uint256 id) external view returns (uint256 goal);
+        function fundsRaised(uint256 id) external view returns (uint256 raised);
+        function contribution(address account, uint256 id) external view returns (uint256 contribution);
+        function claimRefund(uint256 id) external;
+        function sweepProceeds(uint256 id) external;
+        function setFundingGoal(uint256 id, uint256 goal) external;
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error ERC6909SaleNotActive(uint256 id);
+        error ERC6909IncorrectPayment(uint256 id, uint256 cost, uint256 value);
+        error ERC6909InvalidTreasury(address treasury);
+        error ERC6909RefundUnavailable(uint256 id);
+        error ERC6909NothingToRefund(address account, uint256 id);
+        error ERC6909ProceedsUnavailable(uint256 id);
+        error OwnableUnauthorizedAccount(address account);
+
+        #[derive(Debug, PartialEq)]
+        event SaleSet(uint256 indexed id, uint256 price, uint64 start, uint64 end);
+        #[derive(Debug, PartialEq)]
+        event TreasurySet(address indexed treasury);
+        #[derive(Debug, PartialEq)]
+        event Purchased(address indexed buyer, uint256 indexed id, uint256 amount, uint256 cost);
+        #[derive(Debug, PartialEq)]
+        event FundingGoalSet(uint256 indexed id, uint256 goal);
+        #[derive(Debug, PartialEq)]
+        event Refunded(address indexed buyer, uint256 indexed id, uint256 amount, uint256 refund);
+        #[derive(Debug, PartialEq)]
+        event ProceedsSwept(uint256 indexed id, address indexed treasury, uint256 amount);
+    }
+);
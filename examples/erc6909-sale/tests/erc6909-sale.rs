@@ -0,0 +1,561 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909Sale;
+use alloy::{
+    eips::BlockId,
+    primitives::{Address, U256},
+    providers::Provider,
+    rpc::types::BlockTransactionsKind,
+};
+use e2e::{
+    constructor, receipt, send, watch, Account, Constructor,
+    ContractInitializationError, EventExt, Revert,
+};
+
+mod abi;
+
+const PRICE: u64 = 1000;
+
+fn ctr(initial_owner: Address, treasury: Address) -> Constructor {
+    constructor!(initial_owner, treasury)
+}
+
+async fn block_timestamp(account: &Account) -> eyre::Result<u64> {
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("latest block should exist")
+        .header
+        .timestamp;
+
+    Ok(timestamp)
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Sale Extension
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let treasury = contract.treasury().call().await?.treasury;
+    assert_eq!(bob.address(), treasury);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_zero_address_for_treasury(alice: Account) -> eyre::Result<()> {
+    let err = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), Address::ZERO))
+        .deploy()
+        .await
+        .expect_err("should not deploy due to `ERC6909InvalidTreasury`");
+
+    assert!(err.downcast_ref::<ContractInitializationError>().is_some());
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_sale_emits_event(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let price = U256::from(PRICE);
+    let start = block_timestamp(&alice).await?;
+    let end = start + 3600;
+
+    let receipt = receipt!(contract.setSale(id, price, start, end))?;
+
+    assert!(receipt.emits(Erc6909Sale::SaleSet { id, price, start, end }));
+
+    assert_eq!(price, contract.price(id).call().await?.price);
+    assert_eq!(start, contract.saleStart(id).call().await?.start);
+    assert_eq!(end, contract.saleEnd(id).call().await?.end);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_sale_reverts_when_not_owner(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &bob.wallet);
+
+    let err =
+        send!(contract.setSale(U256::ONE, U256::from(PRICE), 0, u64::MAX))
+            .expect_err("should not set sale when not owner");
+
+    assert!(err.reverted_with(Erc6909Sale::OwnableUnauthorizedAccount {
+        account: bob.address(),
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_treasury(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), alice.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let receipt = receipt!(contract.setTreasury(bob.address()))?;
+
+    assert!(receipt.emits(Erc6909Sale::TreasurySet { treasury: bob.address() }));
+
+    let treasury = contract.treasury().call().await?.treasury;
+    assert_eq!(bob.address(), treasury);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn buy_mints_and_forwards_payment(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let amount = U256::from(3);
+    let cost = U256::from(PRICE) * amount;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+
+    let initial_treasury_balance =
+        alice.wallet.get_balance(bob.address()).await?;
+
+    let receipt = receipt!(contract.buy(id, amount).value(cost))?;
+
+    assert!(receipt.emits(Erc6909Sale::Purchased {
+        buyer: alice.address(),
+        id,
+        amount,
+        cost,
+    }));
+
+    let balance = contract.balanceOf(alice.address(), id).call().await?.balance;
+    assert_eq!(amount, balance);
+
+    let treasury_balance = alice.wallet.get_balance(bob.address()).await?;
+    assert_eq!(initial_treasury_balance + cost, treasury_balance);
+
+    let contract_balance = alice.wallet.get_balance(contract_addr).await?;
+    assert_eq!(U256::ZERO, contract_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn buy_reverts_on_incorrect_payment(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let amount = U256::from(3);
+    let value = U256::from(PRICE);
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+
+    let err = send!(contract.buy(id, amount).value(value))
+        .expect_err("should not buy with an incorrect payment");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909IncorrectPayment {
+        id,
+        cost: U256::from(PRICE) * amount,
+        value,
+    }));
+
+    let balance = contract.balanceOf(alice.address(), id).call().await?.balance;
+    assert_eq!(U256::ZERO, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn buy_reverts_when_sale_not_set(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+
+    let err = send!(contract.buy(id, U256::ONE))
+        .expect_err("should not buy tokens that are not on sale");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909SaleNotActive { id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn buy_reverts_before_sale_start(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let start = block_timestamp(&alice).await? + 3600;
+    watch!(contract.setSale(id, U256::from(PRICE), start, start + 3600))?;
+
+    let err = send!(contract.buy(id, U256::ONE).value(U256::from(PRICE)))
+        .expect_err("should not buy before the sale starts");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909SaleNotActive { id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn buy_reverts_after_sale_end(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let end = block_timestamp(&alice).await?;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, end))?;
+
+    let err = send!(contract.buy(id, U256::ONE).value(U256::from(PRICE)))
+        .expect_err("should not buy after the sale ends");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909SaleNotActive { id }));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Refundable Sale
+// ============================================================================
+
+const GOAL: u64 = 10 * PRICE;
+
+#[e2e::test]
+async fn set_funding_goal_emits_event(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let goal = U256::from(GOAL);
+
+    let receipt = receipt!(contract.setFundingGoal(id, goal))?;
+
+    assert!(receipt.emits(Erc6909Sale::FundingGoalSet { id, goal }));
+    assert_eq!(goal, contract.fundingGoal(id).call().await?.goal);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn refundable_buy_escrows_payment(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let amount = U256::from(3);
+    let cost = U256::from(PRICE) * amount;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+    watch!(contract.setFundingGoal(id, U256::from(GOAL)))?;
+
+    let initial_treasury_balance =
+        alice.wallet.get_balance(bob.address()).await?;
+
+    watch!(contract.buy(id, amount).value(cost))?;
+
+    let raised = contract.fundsRaised(id).call().await?.raised;
+    assert_eq!(cost, raised);
+
+    let contribution =
+        contract.contribution(alice.address(), id).call().await?.contribution;
+    assert_eq!(cost, contribution);
+
+    let contract_balance = alice.wallet.get_balance(contract_addr).await?;
+    assert_eq!(cost, contract_balance);
+
+    let treasury_balance = alice.wallet.get_balance(bob.address()).await?;
+    assert_eq!(initial_treasury_balance, treasury_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_refund_burns_tokens_and_refunds_payment(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), alice.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract_alice = Erc6909Sale::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Sale::new(contract_addr, &bob.wallet);
+
+    let id = U256::ONE;
+    let amount = U256::from(3);
+    let cost = U256::from(PRICE) * amount;
+    watch!(contract_alice.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+    watch!(contract_alice.setFundingGoal(id, U256::from(GOAL)))?;
+    watch!(contract_bob.buy(id, amount).value(cost))?;
+    watch!(contract_alice.setSale(id, U256::from(PRICE), 0, 0))?;
+
+    let receipt = receipt!(contract_bob.claimRefund(id))?;
+
+    assert!(receipt.emits(Erc6909Sale::Refunded {
+        buyer: bob.address(),
+        id,
+        amount,
+        refund: cost,
+    }));
+
+    let balance =
+        contract_bob.balanceOf(bob.address(), id).call().await?.balance;
+    assert_eq!(U256::ZERO, balance);
+
+    let raised = contract_bob.fundsRaised(id).call().await?.raised;
+    assert_eq!(U256::ZERO, raised);
+
+    let contract_balance = alice.wallet.get_balance(contract_addr).await?;
+    assert_eq!(U256::ZERO, contract_balance);
+
+    let err = send!(contract_bob.claimRefund(id))
+        .expect_err("should not refund twice");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909NothingToRefund {
+        account: bob.address(),
+        id,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn claim_refund_reverts_before_sale_end(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+    watch!(contract.setFundingGoal(id, U256::from(GOAL)))?;
+    watch!(contract.buy(id, U256::ONE).value(U256::from(PRICE)))?;
+
+    let err = send!(contract.claimRefund(id))
+        .expect_err("should not refund before the sale ends");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909RefundUnavailable { id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn sweep_proceeds_sends_escrow_to_treasury(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    let goal = U256::from(GOAL);
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+    watch!(contract.setFundingGoal(id, goal))?;
+    watch!(contract.buy(id, goal / U256::from(PRICE)).value(goal))?;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, 0))?;
+
+    let initial_treasury_balance =
+        alice.wallet.get_balance(bob.address()).await?;
+
+    let receipt = receipt!(contract.sweepProceeds(id))?;
+
+    assert!(receipt.emits(Erc6909Sale::ProceedsSwept {
+        id,
+        treasury: bob.address(),
+        amount: goal,
+    }));
+
+    let treasury_balance = alice.wallet.get_balance(bob.address()).await?;
+    assert_eq!(initial_treasury_balance + goal, treasury_balance);
+
+    let err = send!(contract.claimRefund(id))
+        .expect_err("should not refund when the goal is reached");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909RefundUnavailable { id }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn sweep_proceeds_reverts_when_goal_not_reached(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let id = U256::ONE;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, u64::MAX))?;
+    watch!(contract.setFundingGoal(id, U256::from(GOAL)))?;
+    watch!(contract.buy(id, U256::ONE).value(U256::from(PRICE)))?;
+    watch!(contract.setSale(id, U256::from(PRICE), 0, 0))?;
+
+    let err = send!(contract.sweepProceeds(id))
+        .expect_err("should not sweep when the goal is not reached");
+
+    assert!(err.reverted_with(Erc6909Sale::ERC6909ProceedsUnavailable { id }));
+
+    Ok(())
+}
+
+// ============================================================================
+// Integration Tests: ERC-165 Support Interface
+// ============================================================================
+
+#[e2e::test]
+async fn supports_interface(alice: Account, bob: Account) -> eyre::Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address(), bob.address()))
+        .deploy()
+        .await?
+        .contract_address;
+    let contract = Erc6909Sale::new(contract_addr, &alice.wallet);
+
+    let invalid_interface_id: u32 = 0xffffffff;
+    let supports_interface = contract
+        .supportsInterface(invalid_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(!supports_interface);
+
+    let erc6909_interface_id: u32 = 0xbd85b039;
+    let supports_interface = contract
+        .supportsInterface(erc6909_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let sale_interface_id: u32 = 0x105ea5ca;
+    let supports_interface =
+        contract.supportsInterface(sale_interface_id.into()).call().await?._0;
+
+    assert!(supports_interface);
+
+    let refundable_sale_interface_id: u32 = 0x786f8184;
+    let supports_interface = contract
+        .supportsInterface(refundable_sale_interface_id.into())
+        .call()
+        .await?
+        ._0;
+
+    assert!(supports_interface);
+
+    let erc165_interface_id: u32 = 0x01ffc9a7;
+    let supports_interface =
+        contract.supportsInterface(erc165_interface_id.into()).call().await?._0;
+
+    assert!(supports_interface);
+
+    Ok(())
+}
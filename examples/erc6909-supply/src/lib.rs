@@ -6,9 +6,9 @@ use alloc::vec::Vec;
 
 use alloy_primitives::{Address, FixedBytes, U256};
 use openzeppelin_stylus::{
+    delegate_ierc6909,
     token::erc6909::{
-        self,
-        extensions::{Erc6909Supply, IErc6909Supply},
+        extensions::{supply, Erc6909Supply, IErc6909Exists, IErc6909Supply},
         IErc6909,
     },
     utils::introspection::erc165::IErc165,
@@ -21,58 +21,7 @@ struct Erc6909SupplyExample {
     erc6909_supply: Erc6909Supply,
 }
 
-#[public]
-impl IErc6909 for Erc6909SupplyExample {
-    type Error = erc6909::Error;
-
-    fn transfer(
-        &mut self,
-        receiver: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909_supply.transfer(receiver, id, amount)
-    }
-
-    fn transfer_from(
-        &mut self,
-        sender: Address,
-        receiver: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909_supply.transfer_from(sender, receiver, id, amount)
-    }
-
-    fn approve(
-        &mut self,
-        spender: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909_supply.approve(spender, id, amount)
-    }
-
-    fn set_operator(
-        &mut self,
-        spender: Address,
-        approved: bool,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909_supply.set_operator(spender, approved)
-    }
-
-    fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.erc6909_supply.balance_of(owner, id)
-    }
-
-    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.erc6909_supply.allowance(owner, spender, id)
-    }
-
-    fn is_operator(&self, owner: Address, spender: Address) -> bool {
-        self.erc6909_supply.is_operator(owner, spender)
-    }
-}
+delegate_ierc6909!(Erc6909SupplyExample => erc6909_supply, supply::Error);
 
 #[public]
 impl IErc6909Supply for Erc6909SupplyExample {
@@ -81,6 +30,13 @@ impl IErc6909Supply for Erc6909SupplyExample {
     }
 }
 
+#[public]
+impl IErc6909Exists for Erc6909SupplyExample {
+    fn exists(&self, id: U256) -> bool {
+        self.erc6909_supply.exists(id)
+    }
+}
+
 #[public]
 impl IErc165 for Erc6909SupplyExample {
     fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
@@ -89,7 +45,12 @@ impl IErc165 for Erc6909SupplyExample {
 }
 
 #[public]
-#[implements(IErc6909<Error = erc6909::Error>, IErc6909Supply, IErc165)]
+#[implements(
+    IErc6909<Error = supply::Error>,
+    IErc6909Supply,
+    IErc6909Exists,
+    IErc165
+)]
 impl Erc6909SupplyExample {
     fn mint(
         &mut self,
@@ -126,4 +87,45 @@ impl Erc6909SupplyExample {
     ) -> Result<(), <Erc6909SupplyExample as IErc6909>::Error> {
         self.erc6909_supply._burn_batch(from, ids, amounts)
     }
+
+    fn cleanup(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        ids: Vec<U256>,
+    ) -> Result<bool, <Erc6909SupplyExample as IErc6909>::Error> {
+        Ok(self.erc6909_supply.as_mut().cleanup(owner, spender, ids)?)
+    }
+
+    fn check_authorization(
+        &self,
+        owner: Address,
+        spender: Address,
+        id: U256,
+        amount: U256,
+    ) -> bool {
+        self.erc6909_supply
+            .as_ref()
+            .check_authorization(owner, spender, id, amount)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909SupplyExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::{
+            token::erc6909::Erc6909, utils::abi::Events,
+        };
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = supply::Error::inner_types();
+        types.extend(Erc6909::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909SupplyExample>();
 }
@@ -6,5 +6,5 @@ pub extern "C" fn main() {}
 
 #[cfg(feature = "export-abi")]
 fn main() {
-    erc6909_supply_example::print_from_args();
+    erc6909_supply_example::print_abi_from_args();
 }
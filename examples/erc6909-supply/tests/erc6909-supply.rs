@@ -1,7 +1,8 @@
 #![cfg(feature = "e2e")]
 
 use abi::Erc6909Supply;
-use e2e::Account;
+use alloy::primitives::{uint, U256};
+use e2e::{receipt, Account};
 
 mod abi;
 
@@ -9,7 +10,64 @@ mod abi;
 // Integration Tests: ERC-6909 Supply Extension
 // ============================================================================
 
-// TODO
+#[e2e::test]
+async fn exists_tracks_minted_ids(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909Supply::new(contract_addr, &alice.wallet);
+    let id = uint!(1_U256);
+    let amount = uint!(10_U256);
+
+    assert!(!contract.exists(id).call().await?._0);
+
+    receipt!(contract.mint(alice.address(), id, amount))?;
+    assert!(contract.exists(id).call().await?._0);
+
+    receipt!(contract.burn(alice.address(), id, amount))?;
+    assert_eq!(U256::ZERO, contract.totalSupply(id).call().await?._0);
+    assert!(contract.exists(id).call().await?._0);
+    assert!(!contract.exists(id + U256::from(1)).call().await?._0);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn reaches_base_token_functions(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909Supply::new(contract_addr, &alice.wallet);
+    let id = uint!(1_U256);
+    let amount = uint!(10_U256);
+
+    receipt!(contract.mint(alice.address(), id, amount))?;
+    receipt!(contract.approve(bob.address(), id, amount))?;
+
+    let authorized = contract
+        .checkAuthorization(alice.address(), bob.address(), id, amount)
+        .call()
+        .await?
+        .authorized;
+    assert!(authorized);
+
+    receipt!(contract.cleanup(alice.address(), bob.address(), vec![id]))?;
+
+    let allowance = contract
+        .allowance(alice.address(), bob.address(), id)
+        .call()
+        .await?
+        .balance;
+    assert_eq!(U256::ZERO, allowance);
+
+    let authorized = contract
+        .checkAuthorization(alice.address(), bob.address(), id, amount)
+        .call()
+        .await?
+        .authorized;
+    assert!(!authorized);
+
+    Ok(())
+}
 
 // ============================================================================
 // Integration Tests: ERC-165 Support Interface
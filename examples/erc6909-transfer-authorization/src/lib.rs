@@ -0,0 +1,131 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::erc6909::{
+        extensions::{
+            transfer_authorization, Erc6909TransferAuthorization,
+            IErc6909TransferAuthorization,
+        },
+        Erc6909, IErc6909,
+    },
+    utils::{cryptography::eip712::IEip712, introspection::erc165::IErc165},
+};
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+#[entrypoint]
+#[storage]
+struct Erc6909TransferAuthorizationExample {
+    erc6909: Erc6909,
+    authorization: Erc6909TransferAuthorization<Eip712>,
+}
+
+#[storage]
+struct Eip712;
+
+impl IEip712 for Eip712 {
+    const NAME: &'static str = "ERC-6909 Transfer Authorization Example";
+    const VERSION: &'static str = "1";
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = transfer_authorization::Error>,
+    IErc6909TransferAuthorization<Error = transfer_authorization::Error>,
+    IErc165
+)]
+impl Erc6909TransferAuthorizationExample {
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), transfer_authorization::Error> {
+        Ok(self.erc6909._mint(to, id, amount)?)
+    }
+}
+
+#[public]
+impl IErc6909TransferAuthorization for Erc6909TransferAuthorizationExample {
+    type Error = transfer_authorization::Error;
+
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    fn domain_separator(&self) -> B256 {
+        self.authorization.domain_separator()
+    }
+
+    fn authorization_state(&self, authorizer: Address, nonce: B256) -> bool {
+        self.authorization.authorization_state(authorizer, nonce)
+    }
+
+    fn transfer_with_authorization(
+        &mut self,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        valid_after: U256,
+        valid_before: U256,
+        nonce: B256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error> {
+        self.authorization.transfer_with_authorization(
+            owner,
+            to,
+            id,
+            amount,
+            valid_after,
+            valid_before,
+            nonce,
+            &signature,
+            &mut self.erc6909,
+        )
+    }
+
+    fn cancel_authorization(
+        &mut self,
+        owner: Address,
+        nonce: B256,
+        signature: Bytes,
+    ) -> Result<(), Self::Error> {
+        self.authorization.cancel_authorization(owner, nonce, &signature)
+    }
+}
+
+delegate_ierc6909!(Erc6909TransferAuthorizationExample => erc6909, transfer_authorization::Error);
+
+#[public]
+impl IErc165 for Erc6909TransferAuthorizationExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909TransferAuthorization>::interface_id() == interface_id
+            || self.erc6909.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi
+    for Erc6909TransferAuthorizationExample
+{
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = transfer_authorization::Error::inner_types();
+        types.extend(Erc6909::events());
+        types.extend(Erc6909TransferAuthorization::<Eip712>::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<
+        Erc6909TransferAuthorizationExample,
+    >();
+}
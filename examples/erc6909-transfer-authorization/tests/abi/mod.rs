@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909TransferAuthorization {
+        function DOMAIN_SEPARATOR() external view returns (bytes32 domainSeparator);
+        function authorizationState(address authorizer, bytes32 nonce) external view returns (bool used);
+        function transferWithAuthorization(address owner, address to, uint256 id, uint256 amount, uint256 validAfter, uint256 validBefore, bytes32 nonce, bytes signature) external;
+        function cancelAuthorization(address owner, bytes32 nonce, bytes signature) external;
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function mint(address to, uint256 id, uint256 amount) external;
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error Erc6909InsufficientBalance(address sender, uint256 balance, uint256 needed, uint256 id);
+        error ERC6909InvalidReceiver(address receiver);
+        error ERC6909AuthorizationNotYetValid(uint256 validAfter);
+        error ERC6909AuthorizationExpired(uint256 validBefore);
+        error ERC6909AuthorizationUsed(address authorizer, bytes32 nonce);
+        error ERC6909InvalidAuthorizationSigner(address signer, address owner);
+        error ERC6909InvalidSignatureLength(uint256 length);
+
+        #[derive(Debug, PartialEq)]
+        event TransferSingle(address indexed caller, address indexed from, address indexed to, uint256 id, uint256 amount);
+        #[derive(Debug, PartialEq)]
+        event AuthorizationUsed(address indexed authorizer, bytes32 indexed nonce);
+        #[derive(Debug, PartialEq)]
+        event AuthorizationCanceled(address indexed authorizer, bytes32 indexed nonce);
+    }
+);
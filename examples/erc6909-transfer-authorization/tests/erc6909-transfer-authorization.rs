@@ -0,0 +1,497 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc6909TransferAuthorization;
+use alloy::{
+    eips::BlockId,
+    primitives::{keccak256, uint, Address, Bytes, B256, U256},
+    providers::Provider,
+    rpc::types::BlockTransactionsKind,
+    sol,
+    sol_types::SolType,
+};
+use e2e::{receipt, send, watch, Account, EventExt, Revert};
+use eyre::Result;
+
+mod abi;
+
+const TOKEN_ID: U256 = uint!(1_U256);
+const BALANCE: U256 = uint!(1000_U256);
+const AMOUNT: U256 = uint!(100_U256);
+const NONCE: B256 = B256::repeat_byte(1);
+
+const TRANSFER_WITH_AUTHORIZATION_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"TransferWithAuthorization(address owner,address to,uint256 id,uint256 amount,uint256 validAfter,uint256 validBefore,bytes32 nonce)")
+        .finalize();
+
+const CANCEL_AUTHORIZATION_TYPEHASH: [u8; 32] = keccak_const::Keccak256::new()
+    .update(b"CancelAuthorization(address owner,bytes32 nonce)")
+    .finalize();
+
+type StructHashTuple = sol! {
+    tuple(bytes32, address, address, uint256, uint256, uint256, uint256, bytes32)
+};
+
+type CancelStructHashTuple = sol! {
+    tuple(bytes32, address, bytes32)
+};
+
+fn cancel_struct_hash(owner: Address, nonce: B256) -> B256 {
+    keccak256(CancelStructHashTuple::abi_encode(&(
+        CANCEL_AUTHORIZATION_TYPEHASH,
+        owner,
+        nonce,
+    )))
+}
+
+/// Parameters of a `transferWithAuthorization` call.
+struct Authorization {
+    owner: Address,
+    to: Address,
+    id: U256,
+    amount: U256,
+    valid_after: U256,
+    valid_before: U256,
+    nonce: B256,
+}
+
+impl Authorization {
+    fn new(owner: Address, to: Address, now: U256) -> Self {
+        Self {
+            owner,
+            to,
+            id: TOKEN_ID,
+            amount: AMOUNT,
+            valid_after: U256::ZERO,
+            valid_before: now + uint!(3600_U256),
+            nonce: NONCE,
+        }
+    }
+
+    fn struct_hash(&self) -> B256 {
+        keccak256(StructHashTuple::abi_encode(&(
+            TRANSFER_WITH_AUTHORIZATION_TYPEHASH,
+            self.owner,
+            self.to,
+            self.id,
+            self.amount,
+            self.valid_after,
+            self.valid_before,
+            self.nonce,
+        )))
+    }
+}
+
+async fn deploy(account: &Account) -> Result<Address> {
+    let contract_addr = account.as_deployer().deploy().await?.contract_address;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &account.wallet);
+    watch!(contract.mint(account.address(), TOKEN_ID, BALANCE))?;
+    Ok(contract_addr)
+}
+
+async fn block_timestamp(account: &Account) -> Result<U256> {
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("latest block should exist")
+        .header
+        .timestamp;
+
+    Ok(U256::from(timestamp))
+}
+
+/// Returns the 65-byte signature by `signer` of the EIP-712 struct hashed as
+/// `struct_hash`, made of its `r`, `s` and `v` values.
+async fn sign(
+    signer: &Account,
+    contract_addr: Address,
+    struct_hash: B256,
+) -> Result<Bytes> {
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &signer.wallet);
+    let domain_separator =
+        contract.DOMAIN_SEPARATOR().call().await?.domainSeparator;
+
+    let hash =
+        openzeppelin_stylus::utils::cryptography::eip712::to_typed_data_hash(
+            &domain_separator,
+            &struct_hash,
+        );
+    let signature = signer.sign_hash(&hash).await;
+
+    let mut bytes = Vec::with_capacity(65);
+    bytes.extend_from_slice(&signature.r().to_be_bytes::<32>());
+    bytes.extend_from_slice(&signature.s().to_be_bytes::<32>());
+    bytes.push(signature.v() as u8 + 27);
+    Ok(bytes.into())
+}
+
+macro_rules! transfer_with_authorization {
+    ($contract:expr, $authorization:expr, $signature:expr) => {
+        $contract.transferWithAuthorization(
+            $authorization.owner,
+            $authorization.to,
+            $authorization.id,
+            $authorization.amount,
+            $authorization.valid_after,
+            $authorization.valid_before,
+            $authorization.nonce,
+            $signature,
+        )
+    };
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Transfer Authorization Extension
+// ============================================================================
+
+#[e2e::test]
+async fn transfer_with_authorization_works(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    // The authorization is relayed by Charlie, who doesn't own any token.
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &charlie.wallet);
+
+    let authorization = Authorization::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+
+    let receipt = receipt!(transfer_with_authorization!(
+        contract,
+        authorization,
+        signature
+    ))?;
+
+    assert!(receipt.emits(Erc6909TransferAuthorization::AuthorizationUsed {
+        authorizer: alice.address(),
+        nonce: NONCE,
+    }));
+    assert!(receipt.emits(Erc6909TransferAuthorization::TransferSingle {
+        caller: charlie.address(),
+        from: alice.address(),
+        to: bob.address(),
+        id: TOKEN_ID,
+        amount: AMOUNT,
+    }));
+
+    let alice_balance =
+        contract.balanceOf(alice.address(), TOKEN_ID).call().await?.balance;
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(BALANCE - AMOUNT, alice_balance);
+    assert_eq!(AMOUNT, bob_balance);
+
+    let used =
+        contract.authorizationState(alice.address(), NONCE).call().await?.used;
+    assert!(used);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_with_authorization_reverts_when_replayed(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let authorization = Authorization::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+
+    watch!(transfer_with_authorization!(
+        contract,
+        authorization,
+        signature.clone()
+    ))?;
+
+    let err =
+        send!(transfer_with_authorization!(contract, authorization, signature))
+            .expect_err("should not replay the authorization");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909AuthorizationUsed {
+            authorizer: alice.address(),
+            nonce: NONCE,
+        }
+    ));
+
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT, bob_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_with_authorization_accepts_nonces_in_any_order(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let now = block_timestamp(&alice).await?;
+    let first = Authorization::new(alice.address(), bob.address(), now);
+    let second = Authorization {
+        nonce: B256::repeat_byte(2),
+        ..Authorization::new(alice.address(), bob.address(), now)
+    };
+    let first_signature =
+        sign(&alice, contract_addr, first.struct_hash()).await?;
+    let second_signature =
+        sign(&alice, contract_addr, second.struct_hash()).await?;
+
+    watch!(transfer_with_authorization!(contract, second, second_signature))?;
+    watch!(transfer_with_authorization!(contract, first, first_signature))?;
+
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(AMOUNT + AMOUNT, bob_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_with_authorization_reverts_when_not_yet_valid(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let now = block_timestamp(&alice).await?;
+    let valid_after = now + uint!(3600_U256);
+    let authorization = Authorization {
+        valid_after,
+        valid_before: valid_after + uint!(3600_U256),
+        ..Authorization::new(alice.address(), bob.address(), now)
+    };
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+
+    let err =
+        send!(transfer_with_authorization!(contract, authorization, signature))
+            .expect_err("should not transfer before the time window");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909AuthorizationNotYetValid {
+            validAfter: valid_after,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_with_authorization_reverts_when_expired(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let now = block_timestamp(&alice).await?;
+    let valid_before = now - uint!(1_U256);
+    let authorization = Authorization {
+        valid_before,
+        ..Authorization::new(alice.address(), bob.address(), now)
+    };
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+
+    let err =
+        send!(transfer_with_authorization!(contract, authorization, signature))
+            .expect_err("should not transfer after the time window");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909AuthorizationExpired {
+            validBefore: valid_before,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_with_authorization_reverts_when_signer_is_not_owner(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let authorization = Authorization::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature =
+        sign(&bob, contract_addr, authorization.struct_hash()).await?;
+
+    let err =
+        send!(transfer_with_authorization!(contract, authorization, signature))
+            .expect_err("should not transfer with a signature of bob");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909InvalidAuthorizationSigner {
+            signer: bob.address(),
+            owner: alice.address(),
+        }
+    ));
+
+    let used =
+        contract.authorizationState(alice.address(), NONCE).call().await?.used;
+    assert!(!used);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cancel_authorization_works(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    // The cancellation is relayed by Bob.
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let authorization = Authorization::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+    let cancellation =
+        sign(&alice, contract_addr, cancel_struct_hash(alice.address(), NONCE))
+            .await?;
+
+    let receipt = receipt!(contract.cancelAuthorization(
+        alice.address(),
+        NONCE,
+        cancellation
+    ))?;
+
+    assert!(receipt.emits(
+        Erc6909TransferAuthorization::AuthorizationCanceled {
+            authorizer: alice.address(),
+            nonce: NONCE,
+        }
+    ));
+
+    let used =
+        contract.authorizationState(alice.address(), NONCE).call().await?.used;
+    assert!(used);
+
+    let err =
+        send!(transfer_with_authorization!(contract, authorization, signature))
+            .expect_err("should not use a canceled authorization");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909AuthorizationUsed {
+            authorizer: alice.address(),
+            nonce: NONCE,
+        }
+    ));
+
+    let bob_balance =
+        contract.balanceOf(bob.address(), TOKEN_ID).call().await?.balance;
+    assert_eq!(U256::ZERO, bob_balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cancel_authorization_reverts_when_already_used(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let authorization = Authorization::new(
+        alice.address(),
+        bob.address(),
+        block_timestamp(&alice).await?,
+    );
+    let signature =
+        sign(&alice, contract_addr, authorization.struct_hash()).await?;
+    watch!(transfer_with_authorization!(contract, authorization, signature))?;
+
+    let cancellation =
+        sign(&alice, contract_addr, cancel_struct_hash(alice.address(), NONCE))
+            .await?;
+
+    let err = send!(contract.cancelAuthorization(
+        alice.address(),
+        NONCE,
+        cancellation
+    ))
+    .expect_err("should not cancel a used authorization");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909AuthorizationUsed {
+            authorizer: alice.address(),
+            nonce: NONCE,
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cancel_authorization_reverts_when_signer_is_not_owner(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract =
+        Erc6909TransferAuthorization::new(contract_addr, &bob.wallet);
+
+    let cancellation =
+        sign(&bob, contract_addr, cancel_struct_hash(alice.address(), NONCE))
+            .await?;
+
+    let err = send!(contract.cancelAuthorization(
+        alice.address(),
+        NONCE,
+        cancellation
+    ))
+    .expect_err("should not cancel with a signature of bob");
+
+    assert!(err.reverted_with(
+        Erc6909TransferAuthorization::ERC6909InvalidAuthorizationSigner {
+            signer: bob.address(),
+            owner: alice.address(),
+        }
+    ));
+
+    let used =
+        contract.authorizationState(alice.address(), NONCE).call().await?.used;
+    assert!(!used);
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+#![allow(clippy::result_large_err)]
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use alloy_primitives::{aliases::U48, Address, FixedBytes, B256, U256, U32};
+use openzeppelin_stylus::{
+    delegate_ierc6909,
+    token::erc6909::{
+        extensions::{
+            votes, Erc6909Votes, IErc6909DelegateBySig, IErc6909Votes,
+        },
+        IErc6909,
+    },
+    utils::{
+        clock::IErc6372,
+        cryptography::eip712::IEip712,
+        introspection::erc165::IErc165,
+        nonces::{INonces, Nonces},
+    },
+};
+use stylus_sdk::prelude::*;
+
+#[entrypoint]
+#[storage]
+struct Erc6909VotesExample {
+    erc6909_votes: Erc6909Votes,
+    nonces: Nonces,
+    eip712: Eip712,
+}
+
+#[storage]
+struct Eip712;
+
+impl IEip712 for Eip712 {
+    const NAME: &'static str = "ERC-6909 Votes Example";
+    const VERSION: &'static str = "1";
+}
+
+#[public]
+#[implements(
+    IErc6909<Error = votes::Error>,
+    IErc6909Votes<Error = votes::Error>,
+    IErc6372,
+    INonces,
+    IErc6909DelegateBySig<Error = votes::Error>,
+    IErc165
+)]
+impl Erc6909VotesExample {
+    fn mint(
+        &mut self,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), votes::Error> {
+        self.erc6909_votes._mint(to, id, amount)
+    }
+
+    fn burn(
+        &mut self,
+        from: Address,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), votes::Error> {
+        self.erc6909_votes._burn(from, id, amount)
+    }
+
+    fn add_voting_id(
+        &mut self,
+        id: U256,
+        weight: U32,
+    ) -> Result<(), votes::Error> {
+        self.erc6909_votes._add_voting_id(id, weight)
+    }
+}
+
+#[public]
+impl IErc6909Votes for Erc6909VotesExample {
+    type Error = votes::Error;
+
+    fn get_votes(&self, account: Address, id: U256) -> U256 {
+        self.erc6909_votes.get_votes(account, id)
+    }
+
+    fn get_past_votes(
+        &self,
+        account: Address,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        self.erc6909_votes.get_past_votes(account, id, timepoint)
+    }
+
+    fn get_past_total_supply(
+        &self,
+        id: U256,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        self.erc6909_votes.get_past_total_supply(id, timepoint)
+    }
+
+    fn delegates(&self, account: Address) -> Address {
+        self.erc6909_votes.delegates(account)
+    }
+
+    fn delegate(&mut self, delegatee: Address) -> Result<(), Self::Error> {
+        self.erc6909_votes.delegate(delegatee)
+    }
+
+    fn voting_ids(&self) -> Vec<U256> {
+        self.erc6909_votes.voting_ids()
+    }
+
+    fn voting_weight(&self, id: U256) -> U32 {
+        self.erc6909_votes.voting_weight(id)
+    }
+
+    #[selector(name = "getVotes")]
+    fn get_weighted_votes(&self, account: Address) -> U256 {
+        self.erc6909_votes.get_weighted_votes(account)
+    }
+
+    #[selector(name = "getPastVotes")]
+    fn get_past_weighted_votes(
+        &self,
+        account: Address,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        self.erc6909_votes.get_past_weighted_votes(account, timepoint)
+    }
+
+    #[selector(name = "getPastTotalSupply")]
+    fn get_past_weighted_total_supply(
+        &self,
+        timepoint: U256,
+    ) -> Result<U256, Self::Error> {
+        self.erc6909_votes.get_past_weighted_total_supply(timepoint)
+    }
+}
+
+#[public]
+impl IErc6372 for Erc6909VotesExample {
+    fn clock(&self) -> U48 {
+        self.erc6909_votes.clock()
+    }
+
+    #[selector(name = "CLOCK_MODE")]
+    fn clock_mode(&self) -> String {
+        self.erc6909_votes.clock_mode()
+    }
+}
+
+#[public]
+impl INonces for Erc6909VotesExample {
+    fn nonces(&self, owner: Address) -> U256 {
+        self.nonces.nonces(owner)
+    }
+}
+
+#[public]
+impl IErc6909DelegateBySig for Erc6909VotesExample {
+    type Error = votes::Error;
+
+    #[selector(name = "DOMAIN_SEPARATOR")]
+    fn domain_separator(&self) -> B256 {
+        self.eip712.domain_separator_v4()
+    }
+
+    fn delegate_by_sig(
+        &mut self,
+        delegatee: Address,
+        nonce: U256,
+        expiry: U256,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<(), Self::Error> {
+        self.erc6909_votes.delegate_by_sig(
+            delegatee,
+            nonce,
+            expiry,
+            v,
+            r,
+            s,
+            &self.eip712,
+            &mut self.nonces,
+        )
+    }
+}
+
+delegate_ierc6909!(Erc6909VotesExample => erc6909_votes, votes::Error);
+
+#[public]
+impl IErc165 for Erc6909VotesExample {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        <Self as IErc6909DelegateBySig>::interface_id() == interface_id
+            || self.erc6909_votes.supports_interface(interface_id)
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909VotesExample {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
+
+        let mut types = votes::Error::inner_types();
+        types.extend(<Erc6909Votes>::events());
+        types
+    }
+}
+
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909VotesExample>();
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc6909Votes {
+        function mint(address to, uint256 id, uint256 amount) external;
+        function burn(address from, uint256 id, uint256 amount) external;
+        function addVotingId(uint256 id, uint32 weight) external;
+        function transfer(address receiver, uint256 id, uint256 amount) external returns (bool status);
+        function balanceOf(address owner, uint256 id) external view returns (uint256 balance);
+        function getVotes(address account, uint256 id) external view returns (uint256 votes);
+        function getPastVotes(address account, uint256 id, uint256 timepoint) external view returns (uint256 votes);
+        function getPastTotalSupply(uint256 id, uint256 timepoint) external view returns (uint256 totalSupply);
+        function delegates(address account) external view returns (address delegatee);
+        function delegate(address delegatee) external;
+        function votingIds() external view returns (uint256[] memory ids);
+        function votingWeight(uint256 id) external view returns (uint32 weight);
+        function clock() external view returns (uint48 clock);
+        function CLOCK_MODE() external view returns (string memory mode);
+        function DOMAIN_SEPARATOR() external view returns (bytes32 domainSeparator);
+        function nonces(address owner) external view returns (uint256 nonce);
+        function delegateBySig(address delegatee, uint256 nonce, uint256 expiry, uint8 v, bytes32 r, bytes32 s) external;
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+        error ERC5805FutureLookup(uint256 timepoint, uint48 clock);
+        error ERC6909ExceededSafeSupply(uint256 id, uint256 increased_supply, uint256 cap);
+        error ERC6909InvalidVotingId(uint256 id);
+        error ERC6909TooManyVotingIds(uint256 max);
+        error VotesExpiredSignature(uint256 expiry);
+        error InvalidAccountNonce(address account, uint256 current_nonce);
+
+        #[derive(Debug, PartialEq)]
+        event DelegateChanged(address indexed delegator, address indexed from_delegate, address indexed to_delegate);
+        #[derive(Debug, PartialEq)]
+        event DelegateVotesChanged(address indexed delegate, uint256 indexed id, uint256 previous_votes, uint256 new_votes);
+        #[derive(Debug, PartialEq)]
+        event VotingIdAdded(uint256 indexed id, uint32 weight);
+    }
+
+    // Overloads aggregating the votes of every voting id by weight.
+    #[sol(rpc)]
+    contract Erc6909WeightedVotes {
+        function getVotes(address account) external view returns (uint256 votes);
+        function getPastVotes(address account, uint256 timepoint) external view returns (uint256 votes);
+        function getPastTotalSupply(uint256 timepoint) external view returns (uint256 totalSupply);
+    }
+);
@@ -0,0 +1,470 @@
+#![cfg(feature = "e2e")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use abi::{Erc6909Votes, Erc6909WeightedVotes};
+use alloy::{
+    eips::BlockId,
+    primitives::{keccak256, uint, Address, B256, U256},
+    providers::Provider,
+    rpc::types::{BlockTransactionsKind, TransactionReceipt},
+    sol,
+    sol_types::SolType,
+};
+use e2e::{receipt, send, watch, Account, EventExt, Revert};
+use eyre::Result;
+
+mod abi;
+
+const ID: U256 = uint!(1_U256);
+const OTHER_ID: U256 = uint!(2_U256);
+const AMOUNT: U256 = uint!(100_U256);
+const WEIGHT: u32 = 1;
+const OTHER_WEIGHT: u32 = 10;
+
+// Saturday, 1 January 2000 00:00:00
+const EXPIRED_EXPIRY: U256 = uint!(946_684_800_U256);
+
+// Wednesday, 1 January 3000 00:00:00
+const FAIR_EXPIRY: U256 = uint!(32_503_680_000_U256);
+
+const DELEGATION_TYPEHASH: [u8; 32] = keccak_const::Keccak256::new()
+    .update(b"Delegation(address delegatee,uint256 nonce,uint256 expiry)")
+    .finalize();
+
+type DelegationStructHashTuple = sol! {
+    tuple(bytes32, address, uint256, uint256)
+};
+
+/// Signature of a delegation, as `(v, r, s)`.
+type Signature = (u8, B256, B256);
+
+async fn deploy(account: &Account) -> Result<Address> {
+    Ok(account.as_deployer().deploy().await?.contract_address)
+}
+
+/// Signs, as `signer`, the delegation of its votes to `delegatee` on
+/// `contract_addr`.
+async fn sign_delegation(
+    signer: &Account,
+    contract_addr: Address,
+    delegatee: Address,
+    nonce: U256,
+    expiry: U256,
+) -> Result<Signature> {
+    let contract = Erc6909Votes::new(contract_addr, &signer.wallet);
+    let domain_separator =
+        contract.DOMAIN_SEPARATOR().call().await?.domainSeparator;
+
+    let struct_hash = keccak256(DelegationStructHashTuple::abi_encode(&(
+        DELEGATION_TYPEHASH,
+        delegatee,
+        nonce,
+        expiry,
+    )));
+    let hash = B256::from_slice(
+        openzeppelin_stylus::utils::cryptography::eip712::to_typed_data_hash(
+            &domain_separator,
+            &struct_hash,
+        )
+        .as_slice(),
+    );
+
+    let signature = signer.sign_hash(&hash).await;
+    Ok((signature.v() as u8 + 27, signature.r().into(), signature.s().into()))
+}
+
+/// Returns the timestamp of the block including `receipt`.
+async fn receipt_timestamp(
+    account: &Account,
+    receipt: &TransactionReceipt,
+) -> Result<U256> {
+    let block_number =
+        receipt.block_number.expect("receipt should have a block number");
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::number(block_number), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("block should exist")
+        .header
+        .timestamp;
+
+    Ok(U256::from(timestamp))
+}
+
+/// Waits until the wall clock is past `timestamp`, so that the next block
+/// is produced at a later timestamp.
+async fn wait_until_after(timestamp: U256) {
+    let timestamp = timestamp.to::<u64>();
+    while SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_secs()
+        <= timestamp
+    {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+}
+
+// ============================================================================
+// Integration Tests: ERC-6909 Votes Extension
+// ============================================================================
+
+#[e2e::test]
+async fn add_voting_id_registers_id(alice: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    let receipt = receipt!(contract.addVotingId(ID, WEIGHT))?;
+
+    assert!(
+        receipt.emits(Erc6909Votes::VotingIdAdded { id: ID, weight: WEIGHT })
+    );
+    assert_eq!(vec![ID], contract.votingIds().call().await?.ids);
+    assert_eq!(WEIGHT, contract.votingWeight(ID).call().await?.weight);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn add_voting_id_reverts_when_minted(alice: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+
+    let err = send!(contract.addVotingId(ID, WEIGHT))
+        .expect_err("should not add an id with minted tokens");
+
+    assert!(err.reverted_with(Erc6909Votes::ERC6909InvalidVotingId { id: ID }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn delegate_moves_votes(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    watch!(contract.addVotingId(ID, WEIGHT))?;
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+
+    assert_eq!(
+        U256::ZERO,
+        contract.getVotes(alice.address(), ID).call().await?.votes
+    );
+
+    let receipt = receipt!(contract.delegate(bob.address()))?;
+
+    assert!(receipt.emits(Erc6909Votes::DelegateChanged {
+        delegator: alice.address(),
+        from_delegate: Address::ZERO,
+        to_delegate: bob.address(),
+    }));
+    assert!(receipt.emits(Erc6909Votes::DelegateVotesChanged {
+        delegate: bob.address(),
+        id: ID,
+        previous_votes: U256::ZERO,
+        new_votes: AMOUNT,
+    }));
+
+    assert_eq!(
+        bob.address(),
+        contract.delegates(alice.address()).call().await?.delegatee
+    );
+    assert_eq!(
+        AMOUNT,
+        contract.getVotes(bob.address(), ID).call().await?.votes
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_moves_votes_of_voting_ids_only(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909Votes::new(contract_addr, &bob.wallet);
+
+    watch!(contract.addVotingId(ID, WEIGHT))?;
+    watch!(contract.delegate(alice.address()))?;
+    watch!(contract_bob.delegate(bob.address()))?;
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+    watch!(contract.mint(alice.address(), OTHER_ID, AMOUNT))?;
+
+    let sent = uint!(40_U256);
+    let receipt = receipt!(contract.transfer(bob.address(), ID, sent))?;
+
+    assert!(receipt.emits(Erc6909Votes::DelegateVotesChanged {
+        delegate: alice.address(),
+        id: ID,
+        previous_votes: AMOUNT,
+        new_votes: AMOUNT - sent,
+    }));
+    assert!(receipt.emits(Erc6909Votes::DelegateVotesChanged {
+        delegate: bob.address(),
+        id: ID,
+        previous_votes: U256::ZERO,
+        new_votes: sent,
+    }));
+
+    watch!(contract.transfer(bob.address(), OTHER_ID, sent))?;
+
+    assert_eq!(
+        AMOUNT - sent,
+        contract.getVotes(alice.address(), ID).call().await?.votes
+    );
+    assert_eq!(sent, contract.getVotes(bob.address(), ID).call().await?.votes);
+    assert_eq!(
+        U256::ZERO,
+        contract.getVotes(bob.address(), OTHER_ID).call().await?.votes
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn weighted_votes_aggregate_voting_ids(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+    let weighted = Erc6909WeightedVotes::new(contract_addr, &alice.wallet);
+
+    watch!(contract.addVotingId(ID, WEIGHT))?;
+    watch!(contract.addVotingId(OTHER_ID, OTHER_WEIGHT))?;
+    watch!(contract.delegate(alice.address()))?;
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+    let receipt = receipt!(contract.mint(alice.address(), OTHER_ID, AMOUNT))?;
+    let minted_at = receipt_timestamp(&alice, &receipt).await?;
+
+    // 100 * 1 + 100 * 10.
+    let votes = uint!(1100_U256);
+    assert_eq!(votes, weighted.getVotes(alice.address()).call().await?.votes);
+    assert_eq!(
+        U256::ZERO,
+        weighted.getVotes(bob.address()).call().await?.votes
+    );
+
+    wait_until_after(minted_at).await;
+    watch!(contract.transfer(bob.address(), OTHER_ID, uint!(5_U256)))?;
+
+    // 100 * 1 + 95 * 10.
+    assert_eq!(
+        uint!(1050_U256),
+        weighted.getVotes(alice.address()).call().await?.votes
+    );
+    assert_eq!(
+        votes,
+        weighted.getPastVotes(alice.address(), minted_at).call().await?.votes
+    );
+    assert_eq!(
+        votes,
+        weighted.getPastTotalSupply(minted_at).call().await?.totalSupply
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn past_lookups_read_checkpoints(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    watch!(contract.addVotingId(ID, WEIGHT))?;
+    watch!(contract.delegate(alice.address()))?;
+    let receipt = receipt!(contract.mint(alice.address(), ID, AMOUNT))?;
+    let minted_at = receipt_timestamp(&alice, &receipt).await?;
+
+    wait_until_after(minted_at).await;
+
+    let burnt = uint!(40_U256);
+    watch!(contract.burn(alice.address(), ID, burnt))?;
+
+    let before_mint = minted_at - uint!(1_U256);
+    assert_eq!(
+        U256::ZERO,
+        contract
+            .getPastVotes(alice.address(), ID, before_mint)
+            .call()
+            .await?
+            .votes
+    );
+    assert_eq!(
+        U256::ZERO,
+        contract.getPastTotalSupply(ID, before_mint).call().await?.totalSupply
+    );
+
+    assert_eq!(
+        AMOUNT,
+        contract
+            .getPastVotes(alice.address(), ID, minted_at)
+            .call()
+            .await?
+            .votes
+    );
+    assert_eq!(
+        AMOUNT,
+        contract.getPastTotalSupply(ID, minted_at).call().await?.totalSupply
+    );
+
+    assert_eq!(
+        AMOUNT - burnt,
+        contract.getVotes(alice.address(), ID).call().await?.votes
+    );
+    assert_eq!(
+        U256::ZERO,
+        contract.getVotes(bob.address(), ID).call().await?.votes
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn clock_is_block_timestamp(alice: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    let receipt = receipt!(contract.addVotingId(ID, WEIGHT))?;
+    let timestamp = receipt_timestamp(&alice, &receipt).await?;
+
+    let clock = contract.clock().call().await?.clock;
+    assert!(U256::from(clock) >= timestamp);
+    assert_eq!("mode=timestamp", contract.CLOCK_MODE().call().await?.mode);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn delegate_by_sig_works(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &alice.wallet);
+
+    watch!(contract.addVotingId(ID, WEIGHT))?;
+    watch!(contract.mint(alice.address(), ID, AMOUNT))?;
+
+    let (v, r, s) = sign_delegation(
+        &alice,
+        contract_addr,
+        bob.address(),
+        U256::ZERO,
+        FAIR_EXPIRY,
+    )
+    .await?;
+
+    // Bob relays alice's delegation.
+    let contract_bob = Erc6909Votes::new(contract_addr, &bob.wallet);
+    let receipt = receipt!(contract_bob.delegateBySig(
+        bob.address(),
+        U256::ZERO,
+        FAIR_EXPIRY,
+        v,
+        r,
+        s
+    ))?;
+
+    assert!(receipt.emits(Erc6909Votes::DelegateChanged {
+        delegator: alice.address(),
+        from_delegate: Address::ZERO,
+        to_delegate: bob.address(),
+    }));
+    assert_eq!(
+        bob.address(),
+        contract.delegates(alice.address()).call().await?.delegatee
+    );
+    assert_eq!(
+        AMOUNT,
+        contract.getVotes(bob.address(), ID).call().await?.votes
+    );
+    assert_eq!(
+        uint!(1_U256),
+        contract.nonces(alice.address()).call().await?.nonce
+    );
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn delegate_by_sig_reverts_when_replayed(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &bob.wallet);
+
+    let (v, r, s) = sign_delegation(
+        &alice,
+        contract_addr,
+        bob.address(),
+        U256::ZERO,
+        FAIR_EXPIRY,
+    )
+    .await?;
+
+    watch!(contract.delegateBySig(
+        bob.address(),
+        U256::ZERO,
+        FAIR_EXPIRY,
+        v,
+        r,
+        s
+    ))?;
+
+    let err = send!(contract.delegateBySig(
+        bob.address(),
+        U256::ZERO,
+        FAIR_EXPIRY,
+        v,
+        r,
+        s
+    ))
+    .expect_err("should not replay a delegation");
+
+    assert!(err.reverted_with(Erc6909Votes::InvalidAccountNonce {
+        account: alice.address(),
+        current_nonce: uint!(1_U256),
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn delegate_by_sig_reverts_when_expired(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = deploy(&alice).await?;
+    let contract = Erc6909Votes::new(contract_addr, &bob.wallet);
+
+    let (v, r, s) = sign_delegation(
+        &alice,
+        contract_addr,
+        bob.address(),
+        U256::ZERO,
+        EXPIRED_EXPIRY,
+    )
+    .await?;
+
+    let err = send!(contract.delegateBySig(
+        bob.address(),
+        U256::ZERO,
+        EXPIRED_EXPIRY,
+        v,
+        r,
+        s
+    ))
+    .expect_err("should not delegate with an expired signature");
+
+    assert!(err.reverted_with(Erc6909Votes::VotesExpiredSignature {
+        expiry: EXPIRED_EXPIRY,
+    }));
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ use alloc::vec::Vec;
 
 use alloy_primitives::{Address, FixedBytes, U256};
 use openzeppelin_stylus::{
+    delegate_ierc6909,
     token::erc6909::{self, Erc6909, IErc6909},
     utils::introspection::erc165::IErc165,
 };
@@ -37,64 +38,58 @@ impl Erc6909Example {
     ) -> Result<(), <Erc6909Example as IErc6909>::Error> {
         self.erc6909._mint_batch(to, ids, amounts)
     }
-}
-
-#[public]
-impl IErc6909 for Erc6909Example {
-    type Error = erc6909::Error;
 
-    fn transfer(
+    fn set_operators(
         &mut self,
-        receiver: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909.transfer(receiver, id, amount)
+        spenders: Vec<Address>,
+        approvals: Vec<bool>,
+    ) -> Result<bool, <Erc6909Example as IErc6909>::Error> {
+        self.erc6909.set_operators(spenders, approvals)
     }
 
-    fn transfer_from(
+    fn cleanup(
         &mut self,
-        sender: Address,
-        receiver: Address,
-        id: U256,
-        amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909.transfer_from(sender, receiver, id, amount)
+        owner: Address,
+        spender: Address,
+        ids: Vec<U256>,
+    ) -> Result<bool, <Erc6909Example as IErc6909>::Error> {
+        self.erc6909.cleanup(owner, spender, ids)
     }
 
-    fn approve(
-        &mut self,
+    fn check_authorization(
+        &self,
+        owner: Address,
         spender: Address,
         id: U256,
         amount: U256,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909.approve(spender, id, amount)
+    ) -> bool {
+        self.erc6909.check_authorization(owner, spender, id, amount)
     }
+}
 
-    fn set_operator(
-        &mut self,
-        spender: Address,
-        approved: bool,
-    ) -> Result<bool, Self::Error> {
-        self.erc6909.set_operator(spender, approved)
-    }
+delegate_ierc6909!(Erc6909Example => erc6909, erc6909::Error);
 
-    fn balance_of(&self, owner: Address, id: U256) -> U256 {
-        self.erc6909.balance_of(owner, id)
+#[public]
+impl IErc165 for Erc6909Example {
+    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        self.erc6909.supports_interface(interface_id)
     }
+}
 
-    fn allowance(&self, owner: Address, spender: Address, id: U256) -> U256 {
-        self.erc6909.allowance(owner, spender, id)
-    }
+#[cfg(feature = "export-abi")]
+impl openzeppelin_stylus::utils::abi::ExportAbi for Erc6909Example {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        use openzeppelin_stylus::utils::abi::Events;
+        use stylus_sdk::abi::export::internal::InnerTypes;
 
-    fn is_operator(&self, owner: Address, spender: Address) -> bool {
-        self.erc6909.is_operator(owner, spender)
+        let mut types = erc6909::Error::inner_types();
+        types.extend(Erc6909::events());
+        types
     }
 }
 
-#[public]
-impl IErc165 for Erc6909Example {
-    fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
-        self.erc6909.supports_interface(interface_id)
-    }
+/// Prints the abi of the contract, declaring its custom errors and events.
+#[cfg(feature = "export-abi")]
+pub fn print_abi_from_args() {
+    openzeppelin_stylus::utils::abi::print_from_args::<Erc6909Example>();
 }
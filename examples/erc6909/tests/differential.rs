@@ -0,0 +1,610 @@
+#![cfg(feature = "differential")]
+//! Differential tests running the same calls against the `erc6909` example
+//! and OpenZeppelin's Solidity ERC-6909 token, and comparing their events,
+//! reverts, balances, allowances and operators.
+//!
+//! Build the Solidity token with `./scripts/oz-erc6909-bytecode.sh` first, or
+//! point `OZ_ERC6909_BYTECODE` to its bytecode.
+//!
+//! Known differences are translated to their Solidity counterpart before
+//! comparing:
+//!
+//! * Transfers emit `TransferSingle` instead of `Transfer`.
+//! * Insufficient balances and allowances revert with
+//!   `Erc6909InsufficientBalance` and `Erc6909InsufficientAllowance`, whose
+//!   selectors differ from the `ERC6909` prefixed ones.
+//! * Approvals and allowance spends revert with `ERC6909InvalidApproverForId`
+//!   and `ERC6909InvalidSpenderForId`, which also carry the token id.
+//!
+//! Differences in behavior are pinned by their own tests instead.
+
+use abi::Erc6909;
+use alloy::{
+    hex,
+    network::TransactionBuilder,
+    primitives::{fixed_bytes, Address, Bytes, FixedBytes, LogData, U256},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol,
+    sol_types::{SolCall, SolError, SolEvent},
+};
+use e2e::Account;
+use eyre::{ContextCompat, WrapErr};
+use openzeppelin_stylus::utils::test_utils::random_token_ids;
+
+mod abi;
+
+/// Bytecode of the Solidity token, relative to the root of the repository.
+const SOLIDITY_BYTECODE_PATH: &str = "target/oz-erc6909/ERC6909Mock.bin";
+
+sol! {
+    /// Errors of the Solidity token that the `erc6909` example names
+    /// differently.
+    #[derive(Debug)]
+    error ERC6909InsufficientBalance(
+        address sender,
+        uint256 balance,
+        uint256 needed,
+        uint256 id
+    );
+    #[derive(Debug)]
+    error ERC6909InsufficientAllowance(
+        address spender,
+        uint256 allowance,
+        uint256 needed,
+        uint256 id
+    );
+}
+
+/// Call made to both tokens.
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Mint { to: Address, id: U256, amount: U256 },
+    Transfer { receiver: Address, id: U256, amount: U256 },
+    TransferFrom { sender: Address, receiver: Address, id: U256, amount: U256 },
+    Approve { spender: Address, id: U256, amount: U256 },
+    SetOperator { spender: Address, approved: bool },
+}
+
+impl Op {
+    fn calldata(self) -> Vec<u8> {
+        match self {
+            Op::Mint { to, id, amount } => {
+                Erc6909::mintCall { to, id, amount }.abi_encode()
+            }
+            Op::Transfer { receiver, id, amount } => {
+                Erc6909::transferCall { receiver, id, amount }.abi_encode()
+            }
+            Op::TransferFrom { sender, receiver, id, amount } => {
+                Erc6909::transferFromCall { sender, receiver, id, amount }
+                    .abi_encode()
+            }
+            Op::Approve { spender, id, amount } => {
+                Erc6909::approveCall { spender, id, amount }.abi_encode()
+            }
+            Op::SetOperator { spender, approved } => {
+                Erc6909::setOperatorCall { spender, approved }.abi_encode()
+            }
+        }
+    }
+}
+
+/// Outcome of a call, as the Solidity token reports it.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    /// The call succeeded and emitted these logs.
+    Emitted(Vec<LogData>),
+    /// The call reverted with this data.
+    Reverted(Bytes),
+}
+
+impl Outcome {
+    /// Translates an outcome of the `erc6909` example into the one expected
+    /// from the Solidity token.
+    fn into_solidity(self) -> Self {
+        match self {
+            Outcome::Emitted(logs) => {
+                Outcome::Emitted(logs.into_iter().map(translate_log).collect())
+            }
+            Outcome::Reverted(data) => {
+                Outcome::Reverted(translate_revert(data))
+            }
+        }
+    }
+}
+
+/// Translates `TransferSingle` logs into `Transfer` logs.
+fn translate_log(log: LogData) -> LogData {
+    match abi::TransferSingle::decode_log_data(&log, true) {
+        Ok(event) => abi::Transfer {
+            caller: event.caller,
+            sender: event.from,
+            receiver: event.to,
+            id: event.id,
+            amount: event.amount,
+        }
+        .encode_log_data(),
+        Err(_) => log,
+    }
+}
+
+/// Translates revert data into the error the Solidity token reverts with.
+fn translate_revert(data: Bytes) -> Bytes {
+    if let Ok(err) = abi::Erc6909InsufficientBalance::abi_decode(&data, true) {
+        return ERC6909InsufficientBalance {
+            sender: err.sender,
+            balance: err.balance,
+            needed: err.needed,
+            id: err.id,
+        }
+        .abi_encode()
+        .into();
+    }
+    if let Ok(err) = abi::Erc6909InsufficientAllowance::abi_decode(&data, true)
+    {
+        return ERC6909InsufficientAllowance {
+            spender: err.spender,
+            allowance: err.allowance,
+            needed: err.needed,
+            id: err.id,
+        }
+        .abi_encode()
+        .into();
+    }
+    if let Ok(err) = abi::ERC6909InvalidApproverForId::abi_decode(&data, true) {
+        return abi::ERC6909InvalidApprover { approver: err.approver }
+            .abi_encode()
+            .into();
+    }
+    if let Ok(err) = abi::ERC6909InvalidSpenderForId::abi_decode(&data, true) {
+        return abi::ERC6909InvalidSpender { spender: err.spender }
+            .abi_encode()
+            .into();
+    }
+    data
+}
+
+/// The `erc6909` example and the Solidity token, deployed side by side.
+struct Tokens {
+    stylus: Address,
+    solidity: Address,
+}
+
+impl Tokens {
+    async fn deploy(deployer: &Account) -> eyre::Result<Self> {
+        let stylus = deployer.as_deployer().deploy().await?.contract_address;
+
+        let path = std::env::var("OZ_ERC6909_BYTECODE").unwrap_or_else(|_| {
+            format!(
+                "{}/../../{SOLIDITY_BYTECODE_PATH}",
+                env!("CARGO_MANIFEST_DIR")
+            )
+        });
+        let bytecode = std::fs::read_to_string(&path).wrap_err_with(|| {
+            format!(
+                "should read {path}, built by ./scripts/oz-erc6909-bytecode.sh"
+            )
+        })?;
+        let tx = TransactionRequest::default()
+            .with_deploy_code(hex::decode(bytecode.trim())?);
+        let solidity = deployer
+            .wallet
+            .send_transaction(tx)
+            .await?
+            .get_receipt()
+            .await?
+            .contract_address
+            .context("should deploy the Solidity token")?;
+
+        Ok(Self { stylus, solidity })
+    }
+
+    /// Makes `caller` call both tokens with `op`, asserts that they have the
+    /// same outcome, and returns it.
+    async fn run(&self, caller: &Account, op: Op) -> eyre::Result<Outcome> {
+        let stylus = execute(caller, self.stylus, op).await?.into_solidity();
+        let solidity = execute(caller, self.solidity, op).await?;
+        assert_eq!(stylus, solidity, "{op:?} should have the same outcome");
+        Ok(solidity)
+    }
+
+    /// Asserts that both tokens return the same data for `call`.
+    async fn assert_same_view(
+        &self,
+        account: &Account,
+        call: impl SolCall,
+    ) -> eyre::Result<()> {
+        let calldata = call.abi_encode();
+        let stylus = view(account, self.stylus, calldata.clone()).await?;
+        let solidity = view(account, self.solidity, calldata).await?;
+        assert_eq!(stylus, solidity);
+        Ok(())
+    }
+
+    /// Asserts that both tokens have the same balances, allowances and
+    /// operators for all pairs of `holders` and all `ids`.
+    async fn assert_same_state(
+        &self,
+        account: &Account,
+        holders: &[Address],
+        ids: &[U256],
+    ) -> eyre::Result<()> {
+        for &owner in holders {
+            for &spender in holders {
+                self.assert_same_view(
+                    account,
+                    Erc6909::isOperatorCall { owner, spender },
+                )
+                .await?;
+                for &id in ids {
+                    self.assert_same_view(
+                        account,
+                        Erc6909::allowanceCall { owner, spender, id },
+                    )
+                    .await?;
+                }
+            }
+            for &id in ids {
+                self.assert_same_view(
+                    account,
+                    Erc6909::balanceOfCall { owner, id },
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn execute(
+    caller: &Account,
+    token: Address,
+    op: Op,
+) -> eyre::Result<Outcome> {
+    let tx =
+        TransactionRequest::default().with_to(token).with_input(op.calldata());
+    match caller.wallet.send_transaction(tx).await {
+        Ok(pending) => {
+            let receipt = pending.get_receipt().await?;
+            eyre::ensure!(
+                receipt.status(),
+                "{op:?} should not revert on-chain"
+            );
+            Ok(Outcome::Emitted(
+                receipt
+                    .inner
+                    .logs()
+                    .iter()
+                    .map(|log| log.inner.data.clone())
+                    .collect(),
+            ))
+        }
+        Err(err) => {
+            let data = err
+                .as_error_resp()
+                .and_then(|payload| payload.data.clone())
+                .wrap_err_with(|| format!("{op:?} failed: {err}"))?;
+            Ok(Outcome::Reverted(
+                hex::decode(data.get().trim_matches('"'))?.into(),
+            ))
+        }
+    }
+}
+
+async fn view(
+    account: &Account,
+    token: Address,
+    calldata: Vec<u8>,
+) -> eyre::Result<Bytes> {
+    let tx = TransactionRequest::default().with_to(token).with_input(calldata);
+    Ok(account.wallet.call(&tx).await?)
+}
+
+// ============================================================================
+// Differential Tests: ERC-6909 Token
+// ============================================================================
+
+#[e2e::test]
+async fn transfers_match(alice: Account, bob: Account) -> eyre::Result<()> {
+    let tokens = Tokens::deploy(&alice).await?;
+    let ids = random_token_ids(2);
+    let (alice_addr, bob_addr) = (alice.address(), bob.address());
+
+    tokens
+        .run(
+            &alice,
+            Op::Mint { to: alice_addr, id: ids[0], amount: U256::from(1000) },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Transfer {
+                receiver: bob_addr,
+                id: ids[0],
+                amount: U256::from(400),
+            },
+        )
+        .await?;
+    tokens
+        .run(
+            &bob,
+            Op::Transfer {
+                receiver: alice_addr,
+                id: ids[0],
+                amount: U256::from(400),
+            },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Transfer { receiver: bob_addr, id: ids[1], amount: U256::ZERO },
+        )
+        .await?;
+
+    let outcome = tokens
+        .run(
+            &bob,
+            Op::Transfer {
+                receiver: alice_addr,
+                id: ids[0],
+                amount: U256::ONE,
+            },
+        )
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    let outcome = tokens
+        .run(
+            &alice,
+            Op::Transfer {
+                receiver: Address::ZERO,
+                id: ids[0],
+                amount: U256::ONE,
+            },
+        )
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    tokens.assert_same_state(&alice, &[alice_addr, bob_addr], &ids).await
+}
+
+#[e2e::test]
+async fn allowances_match(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let tokens = Tokens::deploy(&alice).await?;
+    let ids = random_token_ids(2);
+    let (alice_addr, bob_addr, charlie_addr) =
+        (alice.address(), bob.address(), charlie.address());
+
+    tokens
+        .run(
+            &alice,
+            Op::Mint { to: alice_addr, id: ids[0], amount: U256::from(1000) },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Approve {
+                spender: bob_addr,
+                id: ids[0],
+                amount: U256::from(300),
+            },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Approve {
+                spender: bob_addr,
+                id: ids[1],
+                amount: U256::from(50),
+            },
+        )
+        .await?;
+    tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[0],
+                amount: U256::from(100),
+            },
+        )
+        .await?;
+
+    let outcome = tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[0],
+                amount: U256::from(300),
+            },
+        )
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    let outcome = tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[1],
+                amount: U256::from(50),
+            },
+        )
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[0],
+                amount: U256::from(200),
+            },
+        )
+        .await?;
+
+    let outcome = tokens
+        .run(
+            &alice,
+            Op::Approve {
+                spender: Address::ZERO,
+                id: ids[0],
+                amount: U256::ONE,
+            },
+        )
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    tokens
+        .assert_same_state(&alice, &[alice_addr, bob_addr, charlie_addr], &ids)
+        .await
+}
+
+#[e2e::test]
+async fn operators_match(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let tokens = Tokens::deploy(&alice).await?;
+    let ids = random_token_ids(1);
+    let (alice_addr, bob_addr, charlie_addr) =
+        (alice.address(), bob.address(), charlie.address());
+
+    tokens
+        .run(
+            &alice,
+            Op::Mint { to: alice_addr, id: ids[0], amount: U256::from(1000) },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Approve {
+                spender: bob_addr,
+                id: ids[0],
+                amount: U256::from(100),
+            },
+        )
+        .await?;
+    tokens
+        .run(&alice, Op::SetOperator { spender: bob_addr, approved: true })
+        .await?;
+    tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[0],
+                amount: U256::from(500),
+            },
+        )
+        .await?;
+    tokens
+        .run(&alice, Op::SetOperator { spender: bob_addr, approved: false })
+        .await?;
+    tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: charlie_addr,
+                id: ids[0],
+                amount: U256::from(50),
+            },
+        )
+        .await?;
+
+    let outcome = tokens
+        .run(&alice, Op::SetOperator { spender: Address::ZERO, approved: true })
+        .await?;
+    assert!(matches!(outcome, Outcome::Reverted(_)));
+
+    tokens
+        .assert_same_state(&alice, &[alice_addr, bob_addr, charlie_addr], &ids)
+        .await
+}
+
+#[e2e::test]
+async fn supports_interface_matches(alice: Account) -> eyre::Result<()> {
+    let tokens = Tokens::deploy(&alice).await?;
+    let interface_ids: [FixedBytes<4>; 3] = [
+        fixed_bytes!("0x0f632fb3"),
+        fixed_bytes!("0x01ffc9a7"),
+        fixed_bytes!("0xffffffff"),
+    ];
+
+    for interface_id in interface_ids {
+        tokens
+            .assert_same_view(
+                &alice,
+                abi::IErc6909::supportsInterfaceCall {
+                    interfaceId: interface_id,
+                },
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn max_allowance_is_spent_unlike_solidity(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let tokens = Tokens::deploy(&alice).await?;
+    let ids = random_token_ids(1);
+    let (alice_addr, bob_addr) = (alice.address(), bob.address());
+
+    tokens
+        .run(
+            &alice,
+            Op::Mint { to: alice_addr, id: ids[0], amount: U256::from(1000) },
+        )
+        .await?;
+    tokens
+        .run(
+            &alice,
+            Op::Approve { spender: bob_addr, id: ids[0], amount: U256::MAX },
+        )
+        .await?;
+    tokens
+        .run(
+            &bob,
+            Op::TransferFrom {
+                sender: alice_addr,
+                receiver: bob_addr,
+                id: ids[0],
+                amount: U256::ONE,
+            },
+        )
+        .await?;
+
+    let stylus = Erc6909::new(tokens.stylus, &alice.wallet);
+    let Erc6909::allowanceReturn { balance: allowance } =
+        stylus.allowance(alice_addr, bob_addr, ids[0]).call().await?;
+    assert_eq!(U256::MAX - U256::ONE, allowance);
+
+    let solidity = Erc6909::new(tokens.solidity, &alice.wallet);
+    let Erc6909::allowanceReturn { balance: allowance } =
+        solidity.allowance(alice_addr, bob_addr, ids[0]).call().await?;
+    assert_eq!(U256::MAX, allowance);
+
+    Ok(())
+}
@@ -1,18 +1,23 @@
 #![cfg(feature = "e2e")]
 
 use abi::Erc6909;
-use alloy::primitives::{Address, U256};
-use e2e::{receipt, watch, Account, EventExt};
+use alloy::{
+    contract::Error,
+    primitives::{Address, U256},
+};
+use e2e::{receipt, send, watch, Account, EventExt, Revert};
+use openzeppelin_stylus::utils::test_utils::{random_token_ids, random_values};
 
 mod abi;
 
-fn random_values(size: usize) -> Vec<U256> {
-    (1..=size).map(U256::from).collect()
-}
+/// Number of token ids minted at once by the large batch tests.
+///
+/// See the `Erc6909Batch` benchmark for the gas used by batches of increasing
+/// sizes, and the largest batch that fits in a block.
+const LARGE_BATCH_SIZE: usize = 1_000;
 
-fn random_token_ids(size: usize) -> Vec<U256> {
-    (0..size).map(U256::from).collect()
-}
+/// Number of token ids whose minting exceeds the block gas limit of Nitro.
+const OVERSIZED_BATCH_SIZE: usize = 10_000;
 
 // ============================================================================
 // Integration Tests: ERC-6909 Token
@@ -42,7 +47,7 @@ async fn mint(alice: Account) -> eyre::Result<()> {
 
     let receipt = receipt!(contract.mint(alice_addr, token_id, amount))?;
 
-    assert!(receipt.emits(Erc6909::TransferSingle {
+    assert!(receipt.emits(abi::TransferSingle {
         caller: alice_addr,
         from: Address::ZERO,
         to: alice_addr,
@@ -77,7 +82,7 @@ async fn transfer_from(alice: Account, bob: Account) -> eyre::Result<()> {
         contract.transferFrom(alice_addr, bob_addr, token_id, value,)
     )?;
 
-    assert!(receipt.emits(Erc6909::TransferSingle {
+    assert!(receipt.emits(abi::TransferSingle {
         caller: alice_addr,
         from: alice_addr,
         to: bob_addr,
@@ -95,3 +100,327 @@ async fn transfer_from(alice: Account, bob: Account) -> eyre::Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// Integration Tests: Large Batches
+// ============================================================================
+
+#[e2e::test]
+async fn mint_batch_with_large_batch(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+    let token_ids = random_token_ids(LARGE_BATCH_SIZE);
+    let values = random_values(LARGE_BATCH_SIZE);
+
+    let receipt = receipt!(contract.mintBatch(
+        alice_addr,
+        token_ids.clone(),
+        values.clone()
+    ))?;
+
+    assert!(receipt.emits(abi::TransferBatch {
+        caller: alice_addr,
+        from: Address::ZERO,
+        to: alice_addr,
+        ids: token_ids.clone(),
+        amounts: values.clone(),
+    }));
+
+    for index in [0, LARGE_BATCH_SIZE / 2, LARGE_BATCH_SIZE - 1] {
+        let Erc6909::balanceOfReturn { balance } =
+            contract.balanceOf(alice_addr, token_ids[index]).call().await?;
+        assert_eq!(values[index], balance);
+    }
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn mint_batch_beyond_gas_limit_reverts_gracefully(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+    let token_ids = random_token_ids(OVERSIZED_BATCH_SIZE);
+    let values = random_values(OVERSIZED_BATCH_SIZE);
+
+    let err = send!(contract.mintBatch(
+        alice_addr,
+        token_ids.clone(),
+        values.clone()
+    ))
+    .expect_err("should not mint a batch exceeding the block gas limit");
+
+    // Running out of gas is reported as such by the node, whereas a trap of
+    // the contract would be reported as a bare `execution reverted`.
+    let Error::TransportError(err) = err else {
+        panic!("should be rejected by the node, got: {err}");
+    };
+    let payload = err.as_error_resp().expect("should contain payload");
+    assert!(
+        payload.message.contains("gas"),
+        "should run out of gas, got: {}",
+        payload.message
+    );
+
+    let Erc6909::balanceOfReturn { balance } =
+        contract.balanceOf(alice_addr, token_ids[0]).call().await?;
+    assert_eq!(U256::ZERO, balance);
+
+    // The contract stays usable once the oversized batch is rejected.
+    watch!(contract.mintBatch(
+        alice_addr,
+        token_ids[..LARGE_BATCH_SIZE].to_vec(),
+        values[..LARGE_BATCH_SIZE].to_vec()
+    ))?;
+
+    let Erc6909::balanceOfReturn { balance } =
+        contract.balanceOf(alice_addr, token_ids[0]).call().await?;
+    assert_eq!(values[0], balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_operators(
+    alice: Account,
+    bob: Account,
+    charlie: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let charlie_addr = charlie.address();
+
+    let receipt =
+        receipt!(contract
+            .setOperators(vec![bob_addr, charlie_addr], vec![true, false]))?;
+
+    assert!(receipt.emits(abi::OperatorSet {
+        owner: alice_addr,
+        spender: bob_addr,
+        approved: true
+    }));
+    assert!(receipt.emits(abi::OperatorSet {
+        owner: alice_addr,
+        spender: charlie_addr,
+        approved: false
+    }));
+
+    let Erc6909::isOperatorReturn { status } =
+        contract.isOperator(alice_addr, bob_addr).call().await?;
+    assert!(status);
+    let Erc6909::isOperatorReturn { status } =
+        contract.isOperator(alice_addr, charlie_addr).call().await?;
+    assert!(!status);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn set_operators_reverts_when_arrays_length_mismatch(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let err = send!(contract.setOperators(vec![bob.address()], vec![]))
+        .expect_err("should return `ERC6909InvalidArrayLength`");
+
+    assert!(err.reverted_with(abi::ERC6909InvalidArrayLength {
+        ids_length: U256::from(1),
+        values_length: U256::ZERO,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cleanup_clears_allowances(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let token_ids = random_token_ids(2);
+
+    watch!(contract.approve(bob_addr, token_ids[0], U256::from(10)))?;
+
+    let receipt =
+        receipt!(contract.cleanup(alice_addr, bob_addr, token_ids.clone()))?;
+
+    assert!(receipt.emits(abi::Approval {
+        owner: alice_addr,
+        spender: bob_addr,
+        id: token_ids[0],
+        amount: U256::ZERO,
+    }));
+
+    let Erc6909::allowanceReturn { balance } =
+        contract.allowance(alice_addr, bob_addr, token_ids[0]).call().await?;
+    assert_eq!(U256::ZERO, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn check_authorization_matches_transfer_from(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909::new(contract_addr, &bob.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let token_id = random_token_ids(1)[0];
+    let allowance = U256::from(10);
+
+    watch!(contract.mint(alice_addr, token_id, U256::from(100)))?;
+    watch!(contract.approve(bob_addr, token_id, allowance))?;
+
+    let Erc6909::checkAuthorizationReturn { authorized } = contract
+        .checkAuthorization(
+            alice_addr,
+            bob_addr,
+            token_id,
+            allowance + U256::ONE,
+        )
+        .call()
+        .await?;
+    assert!(!authorized);
+    send!(contract_bob.transferFrom(
+        alice_addr,
+        bob_addr,
+        token_id,
+        allowance + U256::ONE
+    ))
+    .expect_err("should not exceed Bob's allowance");
+
+    let Erc6909::checkAuthorizationReturn { authorized } = contract
+        .checkAuthorization(alice_addr, bob_addr, token_id, allowance)
+        .call()
+        .await?;
+    assert!(authorized);
+    watch!(
+        contract_bob.transferFrom(alice_addr, bob_addr, token_id, allowance)
+    )?;
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn transfer_from_does_not_decrement_allowance_of_operator(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909::new(contract_addr, &bob.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let token_id = random_token_ids(1)[0];
+    let allowance = U256::from(300);
+
+    watch!(contract.mint(alice_addr, token_id, U256::from(1000)))?;
+    watch!(contract.approve(bob_addr, token_id, allowance))?;
+    watch!(contract.setOperator(bob_addr, true))?;
+
+    let receipt = receipt!(contract_bob.transferFrom(
+        alice_addr,
+        bob_addr,
+        token_id,
+        U256::from(100)
+    ))?;
+    assert!(!receipt.emits(abi::Approval {
+        owner: alice_addr,
+        spender: bob_addr,
+        id: token_id,
+        amount: allowance - U256::from(100),
+    }));
+
+    let Erc6909::allowanceReturn { balance } =
+        contract.allowance(alice_addr, bob_addr, token_id).call().await?;
+    assert_eq!(allowance, balance);
+
+    watch!(contract_bob.transferFrom(
+        alice_addr,
+        bob_addr,
+        token_id,
+        U256::from(500)
+    ))?;
+
+    let Erc6909::allowanceReturn { balance } =
+        contract.allowance(alice_addr, bob_addr, token_id).call().await?;
+    assert_eq!(allowance, balance);
+
+    let Erc6909::balanceOfReturn { balance } =
+        contract.balanceOf(bob_addr, token_id).call().await?;
+    assert_eq!(U256::from(600), balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn cleanup_reverts_when_caller_not_authorized(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc6909::new(contract_addr, &bob.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let token_ids = random_token_ids(1);
+    let value = U256::from(10);
+
+    watch!(contract.approve(bob_addr, token_ids[0], value))?;
+
+    let err =
+        send!(contract_bob.cleanup(alice_addr, bob_addr, token_ids.clone()))
+            .expect_err("should return `ERC6909InvalidApprover`");
+
+    assert!(
+        err.reverted_with(abi::ERC6909InvalidApprover { approver: bob_addr })
+    );
+
+    let Erc6909::allowanceReturn { balance } =
+        contract.allowance(alice_addr, bob_addr, token_ids[0]).call().await?;
+    assert_eq!(value, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn approve_reverts_when_spender_is_zero_address(
+    alice: Account,
+) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.contract_address;
+    let contract = Erc6909::new(contract_addr, &alice.wallet);
+
+    let token_ids = random_token_ids(1);
+
+    let err =
+        send!(contract.approve(Address::ZERO, token_ids[0], U256::from(10)))
+            .expect_err("should return `ERC6909InvalidSpenderForId`");
+
+    assert!(err.reverted_with(abi::ERC6909InvalidSpenderForId {
+        spender: Address::ZERO,
+        id: token_ids[0],
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {ERC6909} from "@openzeppelin/contracts/token/ERC6909/draft-ERC6909.sol";
+
+/// OpenZeppelin's ERC-6909 token, with the unrestricted `mint` function of
+/// the `erc6909` example, so that differential tests can run the same calls
+/// against both.
+contract ERC6909Mock is ERC6909 {
+    function mint(address to, uint256 id, uint256 amount) external {
+        _mint(to, id, amount);
+    }
+}
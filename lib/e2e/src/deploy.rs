@@ -13,7 +13,11 @@ use eyre::{Context, ContextCompat};
 use regex::Regex;
 use stylus_sdk::{abi::Bytes, alloy_primitives, function_selector};
 
-use crate::{project::Crate, system::DEPLOYER_ADDRESS, Constructor, Receipt};
+use crate::{
+    project::{get_wasm, Crate},
+    system::DEPLOYER_ADDRESS,
+    Constructor, Receipt,
+};
 
 const CONTRACT_INITIALIZATION_ERROR_SELECTOR: [u8; 4] =
     function_selector!("ContractInitializationError", Address);
@@ -101,6 +105,19 @@ impl Deployer {
         self.deploy_wasm(&wasm_path).await
     }
 
+    /// Deploy and activate the contract implemented as `#[entrypoint]` in the
+    /// workspace crate named `name`.
+    ///
+    /// Useful when a contract under test depends on another contract of the
+    /// workspace, e.g. a factory and the implementation it clones.
+    ///
+    /// See [`Deployer::deploy_wasm()`] for more details.
+    pub async fn deploy_crate(self, name: &str) -> eyre::Result<Receipt> {
+        let wasm_path = get_wasm(name)?;
+
+        self.deploy_wasm(&wasm_path).await
+    }
+
     /// Deploy and activate the contract implemented as `#[entrypoint]` in the
     /// current crate.
     /// Consumes currently configured deployer.
@@ -0,0 +1,43 @@
+#!/bin/bash
+set -e
+
+# Compares the gas usage of the ERC-6909 benchmarks between a baseline
+# revision and the current working tree, and fails if a function regressed by
+# more than the threshold.
+#
+# Usage: ./scripts/bench-compare.sh [baseline-ref] [threshold-percent]
+
+MYDIR=$(realpath "$(dirname "$0")")
+cd "$MYDIR"
+cd ..
+
+BASELINE_REF=${1:-main}
+THRESHOLD=${2:-5}
+BENCHMARKS=erc6909,erc6909_supply
+mkdir -p ./target
+BASELINE_REPORT=$(realpath ./target)/gas-baseline.json
+
+# Build the baseline's wasm binaries in a separate worktree.
+BASELINE_DIR=$(mktemp -d)
+trap 'git worktree remove --force "$BASELINE_DIR"' EXIT
+git worktree add --detach "$BASELINE_DIR" "$BASELINE_REF"
+
+(
+  cd "$BASELINE_DIR"
+  cargo build --release --target wasm32-unknown-unknown \
+    -Z build-std=std,panic_abort \
+    -Z build-std-features=panic_immediate_abort \
+    -p erc6909-example -p erc6909-supply-example
+
+  for CRATE_NAME in erc6909_example erc6909_supply_example; do
+    wasm-opt --enable-bulk-memory -O3 \
+      -o ./target/wasm32-unknown-unknown/release/"$CRATE_NAME"_opt.wasm \
+      ./target/wasm32-unknown-unknown/release/"$CRATE_NAME".wasm
+  done
+)
+
+BENCH_ARTIFACTS_DIR="$BASELINE_DIR/target/wasm32-unknown-unknown/release" \
+  ./scripts/bench.sh --only "$BENCHMARKS" --save "$BASELINE_REPORT"
+
+./scripts/bench.sh --only "$BENCHMARKS" --baseline "$BASELINE_REPORT" \
+  --threshold "$THRESHOLD"
@@ -38,7 +38,7 @@ export DEPLOYER_ADDRESS=0xcEcba2F1DC234f70Dd89F2041029807F8D03A990
 
 # No need to compile benchmarks with `--release`
 # since this only runs the benchmarking code and the contracts have already been compiled with `--release`.
-cargo run -p benches
+cargo run -p benches -- "$@"
 echo "This benchmarks measure gas execution cost,
  the 21000 EVM base gas fee is omitted."
 echo
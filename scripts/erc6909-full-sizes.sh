@@ -0,0 +1,34 @@
+#!/bin/bash
+set -e
+
+# Prints the size of the optimized `erc6909-full` wasm binary for each
+# combination of its extension features.
+#
+# Usage: ./scripts/erc6909-full-sizes.sh [features...]
+# e.g. ./scripts/erc6909-full-sizes.sh "" supply "supply,metadata,uri"
+
+MYDIR=$(realpath "$(dirname "$0")")
+cd "$MYDIR"
+cd ..
+
+if [ "$#" -eq 0 ]; then
+  set -- "" supply metadata uri supply,metadata supply,uri metadata,uri \
+    supply,metadata,uri
+fi
+
+WASM_DIR=./target/wasm32-unknown-unknown/release
+
+for FEATURES in "$@"; do
+  cargo build --release --target wasm32-unknown-unknown \
+    -Z build-std=std,panic_abort \
+    -Z build-std-features=panic_immediate_abort \
+    -p erc6909-full-example --no-default-features --features "$FEATURES" \
+    --quiet
+
+  wasm-opt --enable-bulk-memory -O3 \
+    -o "$WASM_DIR"/erc6909_full_example_opt.wasm \
+    "$WASM_DIR"/erc6909_full_example.wasm
+
+  SIZE=$(wc -c <"$WASM_DIR"/erc6909_full_example_opt.wasm)
+  echo "[${FEATURES:-none}]: $SIZE bytes"
+done
@@ -0,0 +1,28 @@
+#!/bin/bash
+set -e
+
+# Compiles OpenZeppelin's Solidity ERC-6909 token, which the differential
+# tests of the `erc6909` example run against, to
+# `target/oz-erc6909/ERC6909Mock.bin`. Requires git and solc >= 0.8.24.
+#
+# Usage: ./scripts/oz-erc6909-bytecode.sh [openzeppelin-contracts tag]
+# e.g. ./scripts/oz-erc6909-bytecode.sh v5.3.0
+
+MYDIR=$(realpath "$(dirname "$0")")
+cd "$MYDIR"
+cd ..
+
+OZ_TAG=${1:-v5.3.0}
+OUT_DIR=./target/oz-erc6909
+OZ_DIR="$OUT_DIR/openzeppelin-contracts"
+MOCK=./examples/erc6909/tests/solidity/ERC6909Mock.sol
+
+rm -rf "$OZ_DIR"
+git clone --quiet --depth 1 --branch "$OZ_TAG" \
+  https://github.com/OpenZeppelin/openzeppelin-contracts.git "$OZ_DIR"
+
+solc "@openzeppelin/contracts/=$OZ_DIR/contracts/" \
+  --allow-paths "$OZ_DIR/contracts" \
+  --optimize --bin --overwrite -o "$OUT_DIR" "$MOCK"
+
+echo "$OUT_DIR/ERC6909Mock.bin"